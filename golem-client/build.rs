@@ -65,6 +65,10 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "OplogCursor",
                 "golem_common::model::public_oplog::OplogCursor",
             ),
+            (
+                "OplogDiff",
+                "golem_common::model::public_oplog::diff::OplogDiff",
+            ),
             ("OplogRegion", "golem_common::model::regions::OplogRegion"),
             (
                 "ProjectActions",
@@ -852,6 +852,119 @@ impl OplogEntry {
             _ => None,
         }
     }
+
+    pub fn update_owner(
+        &self,
+        project_id: &ProjectId,
+        created_by: &AccountId,
+    ) -> Option<OplogEntry> {
+        match self {
+            OplogEntry::Create {
+                timestamp,
+                worker_id,
+                component_version,
+                args,
+                env,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+                initial_active_plugins,
+                wasi_config_vars,
+                project_id: _,
+                created_by: _,
+            } => Some(OplogEntry::Create {
+                timestamp: *timestamp,
+                worker_id: worker_id.clone(),
+                component_version: *component_version,
+                args: args.clone(),
+                env: env.clone(),
+                project_id: project_id.clone(),
+                created_by: created_by.clone(),
+                parent: parent.clone(),
+                component_size: *component_size,
+                initial_total_linear_memory_size: *initial_total_linear_memory_size,
+                initial_active_plugins: initial_active_plugins.clone(),
+                wasi_config_vars: wasi_config_vars.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The payloads referenced by this entry, both the inlined and the externally stored ones
+    pub fn payloads(&self) -> Vec<&OplogPayload> {
+        match self {
+            OplogEntry::ImportedFunctionInvoked {
+                request, response, ..
+            } => vec![request, response],
+            OplogEntry::ExportedFunctionInvoked { request, .. } => vec![request],
+            OplogEntry::ExportedFunctionCompleted { response, .. } => vec![response],
+            OplogEntry::PendingUpdate {
+                description: UpdateDescription::SnapshotBased { payload, .. },
+                ..
+            } => vec![payload],
+            _ => vec![],
+        }
+    }
+
+    /// Replaces the payloads referenced by this entry, keeping everything else unchanged
+    pub fn map_payloads(self, mut f: impl FnMut(OplogPayload) -> OplogPayload) -> OplogEntry {
+        match self {
+            OplogEntry::ImportedFunctionInvoked {
+                timestamp,
+                function_name,
+                request,
+                response,
+                durable_function_type,
+            } => OplogEntry::ImportedFunctionInvoked {
+                timestamp,
+                function_name,
+                request: f(request),
+                response: f(response),
+                durable_function_type,
+            },
+            OplogEntry::ExportedFunctionInvoked {
+                timestamp,
+                function_name,
+                request,
+                idempotency_key,
+                trace_id,
+                trace_states,
+                invocation_context,
+            } => OplogEntry::ExportedFunctionInvoked {
+                timestamp,
+                function_name,
+                request: f(request),
+                idempotency_key,
+                trace_id,
+                trace_states,
+                invocation_context,
+            },
+            OplogEntry::ExportedFunctionCompleted {
+                timestamp,
+                response,
+                consumed_fuel,
+            } => OplogEntry::ExportedFunctionCompleted {
+                timestamp,
+                response: f(response),
+                consumed_fuel,
+            },
+            OplogEntry::PendingUpdate {
+                timestamp,
+                description:
+                    UpdateDescription::SnapshotBased {
+                        target_version,
+                        payload,
+                    },
+            } => OplogEntry::PendingUpdate {
+                timestamp,
+                description: UpdateDescription::SnapshotBased {
+                    target_version,
+                    payload: f(payload),
+                },
+            },
+            entry => entry,
+        }
+    }
 }
 
 /// Describes a pending update
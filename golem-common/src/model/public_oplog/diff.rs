@@ -0,0 +1,516 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::oplog::OplogIndex;
+use crate::model::public_oplog::PublicOplogEntry;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::mem::{discriminant, Discriminant};
+
+/// The result of aligning two oplogs
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct OplogDiff {
+    /// The first oplog index where the two oplogs differ, if any. The entries before it are
+    /// the same in both oplogs.
+    pub first_divergent_index: Option<OplogIndex>,
+    pub left_last_index: OplogIndex,
+    pub right_last_index: OplogIndex,
+    /// The differing entries, in the order of the aligned oplogs
+    pub entries: Vec<OplogEntryDiff>,
+    /// True if the comparison stopped at the requested number of differing entries
+    pub truncated: bool,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct OplogEntryDiff {
+    /// Missing if the entry only exists in the right oplog
+    pub left_index: Option<OplogIndex>,
+    /// Missing if the entry only exists in the left oplog
+    pub right_index: Option<OplogIndex>,
+    /// Missing if the entry only exists in the right oplog
+    pub left: Option<PublicOplogEntry>,
+    /// Missing if the entry only exists in the left oplog
+    pub right: Option<PublicOplogEntry>,
+    /// The differing fields of the two entries, empty if one of them is missing
+    pub fields: Vec<OplogFieldDiff>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct OplogFieldDiff {
+    /// Dot separated path of the field in the JSON representation of the entry,
+    /// for example `response.value.status` for a field of an imported function's result
+    pub path: String,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+}
+
+impl OplogDiff {
+    /// The number of differing entries returned when not specified otherwise
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    /// How far the two oplogs are searched for the next pair of matching entries,
+    /// after entries existing in only one of them
+    const MAX_REALIGNMENT_DISTANCE: usize = 64;
+
+    /// Compares two oplogs, both starting at the initial oplog index, returning at most `limit`
+    /// differing entries.
+    ///
+    /// Entries are matched by their kind and invoked function, such that entries existing in only
+    /// one of the oplogs are reported on their own, instead of making every later entry differ.
+    /// Timestamps are not compared, as entries written by separate executions never share them,
+    /// and neither is the worker id of the initial entries, which always differs between two workers.
+    pub fn new(
+        left: &[PublicOplogEntry],
+        right: &[PublicOplogEntry],
+        limit: usize,
+    ) -> Result<Self, String> {
+        let mut diffs = DiffCollector::new(limit);
+
+        let mut left_offset = 0;
+        let mut right_offset = 0;
+
+        while left_offset < left.len() || right_offset < right.len() {
+            let (left_count, right_count) = match (left.get(left_offset), right.get(right_offset)) {
+                (Some(left_entry), Some(right_entry))
+                    if alignment_key(left_entry) == alignment_key(right_entry) =>
+                {
+                    (1, 1)
+                }
+                // Without matching entries nearby, the two entries are compared to each other
+                (Some(_), Some(_)) => {
+                    Self::realign(left, right, left_offset, right_offset).unwrap_or((1, 1))
+                }
+                (Some(_), None) => (left.len() - left_offset, 0),
+                (None, _) => (0, right.len() - right_offset),
+            };
+
+            // The oplogs are aligned index by index up to the first divergence
+            let step_index = oplog_index(left_offset.min(right_offset));
+
+            if left_count == 1 && right_count == 1 {
+                let left_entry = &left[left_offset];
+                let right_entry = &right[right_offset];
+                let fields = diff_entries(left_entry, right_entry)?;
+                if !fields.is_empty()
+                    && !diffs.push(
+                        step_index,
+                        OplogEntryDiff {
+                            left_index: Some(oplog_index(left_offset)),
+                            right_index: Some(oplog_index(right_offset)),
+                            left: Some(left_entry.clone()),
+                            right: Some(right_entry.clone()),
+                            fields,
+                        },
+                    )
+                {
+                    break;
+                }
+            } else {
+                let left_only =
+                    (left_offset..left_offset + left_count).map(|offset| OplogEntryDiff {
+                        left_index: Some(oplog_index(offset)),
+                        right_index: None,
+                        left: Some(left[offset].clone()),
+                        right: None,
+                        fields: Vec::new(),
+                    });
+                let right_only =
+                    (right_offset..right_offset + right_count).map(|offset| OplogEntryDiff {
+                        left_index: None,
+                        right_index: Some(oplog_index(offset)),
+                        left: None,
+                        right: Some(right[offset].clone()),
+                        fields: Vec::new(),
+                    });
+
+                if !left_only
+                    .chain(right_only)
+                    .all(|diff| diffs.push(step_index, diff))
+                {
+                    break;
+                }
+            }
+
+            left_offset += left_count;
+            right_offset += right_count;
+        }
+
+        Ok(Self {
+            first_divergent_index: diffs.first_divergent_index,
+            left_last_index: OplogIndex::from_u64(left.len() as u64),
+            right_last_index: OplogIndex::from_u64(right.len() as u64),
+            entries: diffs.entries,
+            truncated: diffs.truncated,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first_divergent_index.is_none()
+    }
+
+    // The number of entries to skip in the left and right oplogs to get to the nearest pair of
+    // matching entries
+    fn realign(
+        left: &[PublicOplogEntry],
+        right: &[PublicOplogEntry],
+        left_offset: usize,
+        right_offset: usize,
+    ) -> Option<(usize, usize)> {
+        for distance in 1..=Self::MAX_REALIGNMENT_DISTANCE {
+            for left_skip in 0..=distance {
+                let right_skip = distance - left_skip;
+                if let (Some(left_entry), Some(right_entry)) = (
+                    left.get(left_offset + left_skip),
+                    right.get(right_offset + right_skip),
+                ) {
+                    if alignment_key(left_entry) == alignment_key(right_entry) {
+                        return Some((left_skip, right_skip));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+struct DiffCollector {
+    entries: Vec<OplogEntryDiff>,
+    first_divergent_index: Option<OplogIndex>,
+    limit: usize,
+    truncated: bool,
+}
+
+impl DiffCollector {
+    fn new(limit: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            first_divergent_index: None,
+            limit,
+            truncated: false,
+        }
+    }
+
+    // Returns false if the limit has been reached, and the diff is not collected
+    fn push(&mut self, step_index: OplogIndex, diff: OplogEntryDiff) -> bool {
+        self.first_divergent_index.get_or_insert(step_index);
+
+        if self.entries.len() == self.limit {
+            self.truncated = true;
+            false
+        } else {
+            self.entries.push(diff);
+            true
+        }
+    }
+}
+
+fn oplog_index(offset: usize) -> OplogIndex {
+    OplogIndex::from_u64(offset as u64 + 1)
+}
+
+// Entries written by the same step of two executions have the same kind, and invoke the same function
+fn alignment_key(entry: &PublicOplogEntry) -> (Discriminant<PublicOplogEntry>, Option<&str>) {
+    let function_name = match entry {
+        PublicOplogEntry::ImportedFunctionInvoked(params) => Some(params.function_name.as_str()),
+        PublicOplogEntry::ExportedFunctionInvoked(params) => Some(params.function_name.as_str()),
+        _ => None,
+    };
+    (discriminant(entry), function_name)
+}
+
+fn diff_entries(
+    left: &PublicOplogEntry,
+    right: &PublicOplogEntry,
+) -> Result<Vec<OplogFieldDiff>, String> {
+    let left = comparable_fields(left)?;
+    let right = comparable_fields(right)?;
+
+    let mut fields = Vec::new();
+    if left.get("type") == right.get("type") {
+        diff_objects("", &left, &right, &mut fields);
+    } else {
+        fields.push(OplogFieldDiff {
+            path: "type".to_string(),
+            left: left.get("type").cloned(),
+            right: right.get("type").cloned(),
+        });
+    }
+
+    Ok(fields)
+}
+
+fn comparable_fields(entry: &PublicOplogEntry) -> Result<Map<String, Value>, String> {
+    match serde_json::to_value(entry).map_err(|err| err.to_string())? {
+        Value::Object(mut fields) => {
+            fields.remove("timestamp");
+            if let PublicOplogEntry::Create(_) = entry {
+                fields.remove("workerId");
+            }
+            Ok(fields)
+        }
+        other => Err(format!(
+            "Unexpected JSON representation of an oplog entry: {other}"
+        )),
+    }
+}
+
+fn diff_objects(
+    path: &str,
+    left: &Map<String, Value>,
+    right: &Map<String, Value>,
+    fields: &mut Vec<OplogFieldDiff>,
+) {
+    let keys: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+
+    for key in keys {
+        let path = field_path(path, key);
+        match (left.get(key), right.get(key)) {
+            (Some(left), Some(right)) => diff_values(&path, left, right, fields),
+            (left, right) => fields.push(OplogFieldDiff {
+                path,
+                left: left.cloned(),
+                right: right.cloned(),
+            }),
+        }
+    }
+}
+
+// Objects of different variants and lists of different lengths are reported as a single difference,
+// as comparing them field by field or item by item would not be meaningful
+fn diff_values(path: &str, left: &Value, right: &Value, fields: &mut Vec<OplogFieldDiff>) {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) if left.get("type") == right.get("type") => {
+            diff_objects(path, left, right, fields)
+        }
+        (Value::Array(left), Value::Array(right)) if left.len() == right.len() => {
+            for (idx, (left, right)) in left.iter().zip(right).enumerate() {
+                diff_values(&field_path(path, &idx.to_string()), left, right, fields);
+            }
+        }
+        _ => {
+            if left != right {
+                fields.push(OplogFieldDiff {
+                    path: path.to_string(),
+                    left: Some(left.clone()),
+                    right: Some(right.clone()),
+                })
+            }
+        }
+    }
+}
+
+fn field_path(parent: &str, field: &str) -> String {
+    if parent.is_empty() {
+        field.to_string()
+    } else {
+        format!("{parent}.{field}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::public_oplog::{
+        CreateParameters, ImportedFunctionInvokedParameters, PublicDurableFunctionType,
+        TimestampParameter,
+    };
+    use crate::model::{AccountId, ComponentId, Empty, ProjectId, Timestamp, WorkerId};
+    use golem_wasm_ast::analysis::analysed_type::{field, record, str, u16};
+    use golem_wasm_rpc::ValueAndType;
+    use std::collections::BTreeMap;
+    use test_r::test;
+
+    #[test]
+    fn fork_with_the_same_history_has_no_diff() {
+        let component_id = ComponentId::new_v4();
+        let project_id = ProjectId::new_v4();
+
+        let left = vec![
+            create(&component_id, &project_id, "worker"),
+            imported_function_invoked(200),
+        ];
+        let right = vec![
+            create(&component_id, &project_id, "fork"),
+            imported_function_invoked(200),
+        ];
+
+        let diff = OplogDiff::new(&left, &right, OplogDiff::DEFAULT_LIMIT).unwrap();
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.first_divergent_index, None);
+        assert_eq!(diff.right_last_index, OplogIndex::from_u64(2));
+    }
+
+    #[test]
+    fn diff_reports_differing_payload_values() {
+        let component_id = ComponentId::new_v4();
+        let project_id = ProjectId::new_v4();
+
+        let left = vec![
+            create(&component_id, &project_id, "worker"),
+            imported_function_invoked(200),
+        ];
+        let right = vec![
+            create(&component_id, &project_id, "fork"),
+            imported_function_invoked(503),
+        ];
+
+        let diff = OplogDiff::new(&left, &right, OplogDiff::DEFAULT_LIMIT).unwrap();
+
+        assert_eq!(diff.first_divergent_index, Some(OplogIndex::from_u64(2)));
+        assert_eq!(diff.entries.len(), 1);
+        assert_eq!(
+            diff.entries[0].fields,
+            vec![OplogFieldDiff {
+                path: "response.value.status".to_string(),
+                left: Some(Value::from(200)),
+                right: Some(Value::from(503)),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_reports_entries_missing_from_one_of_the_oplogs() {
+        let component_id = ComponentId::new_v4();
+        let project_id = ProjectId::new_v4();
+
+        let left = vec![
+            create(&component_id, &project_id, "worker"),
+            imported_function_invoked(200),
+            imported_function_invoked(200),
+        ];
+        let right = vec![
+            create(&component_id, &project_id, "fork"),
+            suspend(),
+            imported_function_invoked(200),
+            imported_function_invoked(200),
+        ];
+
+        let diff = OplogDiff::new(&left, &right, OplogDiff::DEFAULT_LIMIT).unwrap();
+
+        // The extra entry does not make the following ones differ
+        assert_eq!(diff.first_divergent_index, Some(OplogIndex::from_u64(2)));
+        assert_eq!(diff.entries.len(), 1);
+        assert_eq!(diff.entries[0].left_index, None);
+        assert_eq!(diff.entries[0].right_index, Some(OplogIndex::from_u64(2)));
+        assert!(diff.entries[0].fields.is_empty());
+        assert!(!diff.truncated);
+    }
+
+    #[test]
+    fn diff_reports_different_kinds_without_matching_entries() {
+        let component_id = ComponentId::new_v4();
+        let project_id = ProjectId::new_v4();
+
+        let left = vec![
+            create(&component_id, &project_id, "worker"),
+            imported_function_invoked(200),
+        ];
+        let right = vec![create(&component_id, &project_id, "fork"), suspend()];
+
+        let diff = OplogDiff::new(&left, &right, OplogDiff::DEFAULT_LIMIT).unwrap();
+
+        assert_eq!(diff.first_divergent_index, Some(OplogIndex::from_u64(2)));
+        assert_eq!(
+            diff.entries[0].fields,
+            vec![OplogFieldDiff {
+                path: "type".to_string(),
+                left: Some(Value::from("ImportedFunctionInvoked")),
+                right: Some(Value::from("Suspend")),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_is_limited() {
+        let component_id = ComponentId::new_v4();
+        let project_id = ProjectId::new_v4();
+
+        let left = vec![create(&component_id, &project_id, "worker")];
+        let right = std::iter::once(create(&component_id, &project_id, "fork"))
+            .chain((0..10).map(|_| imported_function_invoked(200)))
+            .collect::<Vec<_>>();
+
+        let diff = OplogDiff::new(&left, &right, 3).unwrap();
+
+        assert_eq!(diff.first_divergent_index, Some(OplogIndex::from_u64(2)));
+        assert_eq!(
+            diff.entries
+                .iter()
+                .map(|entry| entry.right_index)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(OplogIndex::from_u64(2)),
+                Some(OplogIndex::from_u64(3)),
+                Some(OplogIndex::from_u64(4))
+            ]
+        );
+        assert!(diff.truncated);
+        assert_eq!(diff.right_last_index, OplogIndex::from_u64(11));
+    }
+
+    fn create(component_id: &ComponentId, project_id: &ProjectId, name: &str) -> PublicOplogEntry {
+        PublicOplogEntry::Create(CreateParameters {
+            timestamp: Timestamp::now_utc(),
+            worker_id: WorkerId {
+                component_id: component_id.clone(),
+                worker_name: name.to_string(),
+            },
+            component_version: 0,
+            args: vec![],
+            env: BTreeMap::new(),
+            project_id: project_id.clone(),
+            created_by: AccountId {
+                value: "account_id".to_string(),
+            },
+            wasi_config_vars: BTreeMap::new().into(),
+            parent: None,
+            component_size: 100,
+            initial_total_linear_memory_size: 100,
+            initial_active_plugins: BTreeSet::new(),
+        })
+    }
+
+    fn suspend() -> PublicOplogEntry {
+        PublicOplogEntry::Suspend(TimestampParameter {
+            timestamp: Timestamp::now_utc(),
+        })
+    }
+
+    fn imported_function_invoked(status: u16) -> PublicOplogEntry {
+        PublicOplogEntry::ImportedFunctionInvoked(ImportedFunctionInvokedParameters {
+            timestamp: Timestamp::now_utc(),
+            function_name: "http::types::future_incoming_response::get".to_string(),
+            request: ValueAndType::new(
+                golem_wasm_rpc::Value::String("https://example.com".to_string()),
+                str(),
+            ),
+            response: ValueAndType::new(
+                golem_wasm_rpc::Value::Record(vec![golem_wasm_rpc::Value::U16(status)]),
+                record(vec![field("status", u16())]),
+            ),
+            durable_function_type: PublicDurableFunctionType::ReadRemote(Empty {}),
+        })
+    }
+}
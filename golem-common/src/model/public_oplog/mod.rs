@@ -18,6 +18,8 @@ mod protobuf;
 #[cfg(test)]
 mod tests;
 
+pub mod diff;
+
 use super::plugin::PluginDefinition;
 use super::worker::WasiConfigVars;
 use crate::model::invocation_context::{AttributeValue, SpanId, TraceId};
@@ -14,19 +14,31 @@
 
 use crate::auth::AuthService;
 use crate::debug_session::DebugSessions;
+use crate::memory::WorkerMemories;
+use golem_worker_executor::services::oplog::OplogService;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AdditionalDeps {
     auth_service: Arc<dyn AuthService>,
     debug_session: Arc<dyn DebugSessions>,
+    // The oplog service of the regular executor, reading the oplogs without affecting the debug sessions
+    original_oplog_service: Arc<dyn OplogService>,
+    worker_memories: Arc<WorkerMemories>,
 }
 
 impl AdditionalDeps {
-    pub fn new(auth_service: Arc<dyn AuthService>, debug_session: Arc<dyn DebugSessions>) -> Self {
+    pub fn new(
+        auth_service: Arc<dyn AuthService>,
+        debug_session: Arc<dyn DebugSessions>,
+        original_oplog_service: Arc<dyn OplogService>,
+        worker_memories: Arc<WorkerMemories>,
+    ) -> Self {
         Self {
             auth_service,
             debug_session,
+            original_oplog_service,
+            worker_memories,
         }
     }
 
@@ -37,4 +49,12 @@ impl AdditionalDeps {
     pub fn debug_session(&self) -> Arc<dyn DebugSessions + Sync + Send> {
         self.debug_session.clone()
     }
+
+    pub fn original_oplog_service(&self) -> Arc<dyn OplogService> {
+        self.original_oplog_service.clone()
+    }
+
+    pub fn worker_memories(&self) -> Arc<WorkerMemories> {
+        self.worker_memories.clone()
+    }
 }
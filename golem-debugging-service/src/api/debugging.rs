@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::dap::run_dap_debug_websocket_session;
 use crate::jrpc::run_jrpc_debug_websocket_session;
 use crate::services::debug_service::DebugService;
 use golem_common::model::auth::AuthCtx;
@@ -72,4 +73,24 @@ impl DebuggingApi {
 
         Ok(upgraded)
     }
+
+    /// Start a new debugging session using the Debug Adapter Protocol
+    #[oai(path = "/dap", method = "get", operation_id = "debugger_start_dap")]
+    pub async fn get_dap_debugger(
+        &self,
+        websocket: WebSocket,
+        token: WrappedGolemSecuritySchema,
+    ) -> Result<BoxWebSocketUpgraded> {
+        let debug_service = self.debug_service.clone();
+        let auth_ctx = AuthCtx::new(token.0.secret());
+        let upgraded: BoxWebSocketUpgraded = websocket.on_upgrade(Box::new(|socket_stream| {
+            Box::pin(run_dap_debug_websocket_session(
+                socket_stream,
+                debug_service,
+                auth_ctx,
+            ))
+        }));
+
+        Ok(upgraded)
+    }
 }
@@ -0,0 +1,356 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::params::Breakpoint;
+use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_worker_executor::services::oplog::Oplog;
+use regex::Regex;
+use std::sync::Arc;
+
+// Outgoing HTTP requests are not recorded under the name of the interface they are made through,
+// but as the durable call awaiting their response
+const OUTGOING_HANDLER: &str = "http::outgoing_handler";
+pub(crate) const OUTGOING_HTTP_RESPONSE: &str = "http::types::future_incoming_response::get";
+
+#[derive(Debug, Clone)]
+pub struct BreakpointsInternal {
+    pub breakpoints: Vec<(Breakpoint, BreakpointMatcher)>,
+}
+
+impl BreakpointsInternal {
+    pub fn empty() -> BreakpointsInternal {
+        BreakpointsInternal {
+            breakpoints: Vec::new(),
+        }
+    }
+
+    pub fn from_breakpoints(breakpoints: Vec<Breakpoint>) -> Result<Self, String> {
+        let breakpoints = breakpoints
+            .into_iter()
+            .map(|breakpoint| {
+                let matcher = BreakpointMatcher::from_breakpoint(&breakpoint)?;
+                Ok((breakpoint, matcher))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self { breakpoints })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.breakpoints.is_empty()
+    }
+
+    pub fn breakpoints(&self) -> Vec<Breakpoint> {
+        self.breakpoints
+            .iter()
+            .map(|(breakpoint, _)| breakpoint.clone())
+            .collect()
+    }
+
+    pub fn first_match(&self, entry: &OplogEntry) -> Option<&Breakpoint> {
+        self.breakpoints
+            .iter()
+            .find(|(_, matcher)| matcher.matches(entry))
+            .map(|(breakpoint, _)| breakpoint)
+    }
+
+    // Finds the first entry after `after_index`, up to and including `until_index`, that hits a breakpoint
+    pub async fn find_first_hit(
+        &self,
+        oplog: Arc<dyn Oplog>,
+        after_index: OplogIndex,
+        until_index: OplogIndex,
+    ) -> Option<(OplogIndex, Breakpoint)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut index = after_index.next();
+
+        while index <= until_index {
+            let entry = oplog.read(index).await;
+
+            if let Some(breakpoint) = self.first_match(&entry) {
+                return Some((index, breakpoint.clone()));
+            }
+
+            index = index.next();
+        }
+
+        None
+    }
+
+    // Finds the last entry before `before_index`, down to and including `from_index`, that hits a breakpoint
+    pub async fn find_last_hit(
+        &self,
+        oplog: Arc<dyn Oplog>,
+        from_index: OplogIndex,
+        before_index: OplogIndex,
+    ) -> Option<(OplogIndex, Breakpoint)> {
+        if self.is_empty() || !before_index.is_defined() {
+            return None;
+        }
+
+        let mut index = before_index.previous();
+
+        while index >= from_index && index.is_defined() {
+            let entry = oplog.read(index).await;
+
+            if let Some(breakpoint) = self.first_match(&entry) {
+                return Some((index, breakpoint.clone()));
+            }
+
+            index = index.previous();
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BreakpointMatcher {
+    ImportedFunction(Vec<String>),
+    ExportedFunction(Option<String>),
+    LogMessage(Regex),
+    Error,
+}
+
+impl BreakpointMatcher {
+    pub fn from_breakpoint(breakpoint: &Breakpoint) -> Result<Self, String> {
+        match breakpoint {
+            Breakpoint::ImportedFunction { function_name } => {
+                if function_name.is_empty() {
+                    Err(
+                        "The function name of an imported function breakpoint cannot be empty"
+                            .to_string(),
+                    )
+                } else {
+                    Ok(BreakpointMatcher::ImportedFunction(
+                        imported_function_name_patterns(function_name),
+                    ))
+                }
+            }
+            Breakpoint::ExportedFunction { function_name } => {
+                Ok(BreakpointMatcher::ExportedFunction(function_name.clone()))
+            }
+            Breakpoint::LogMessage { pattern } => Regex::new(pattern)
+                .map(BreakpointMatcher::LogMessage)
+                .map_err(|err| format!("Invalid log message pattern {pattern}: {err}")),
+            Breakpoint::Error => Ok(BreakpointMatcher::Error),
+        }
+    }
+
+    pub fn matches(&self, entry: &OplogEntry) -> bool {
+        match (self, entry) {
+            (
+                BreakpointMatcher::ImportedFunction(expected),
+                OplogEntry::ImportedFunctionInvoked { function_name, .. },
+            ) => {
+                let function_name = normalize_imported_function_name(function_name);
+
+                expected.iter().any(|expected| {
+                    is_same_or_nested(&function_name, expected)
+                        || (expected == OUTGOING_HANDLER && function_name == OUTGOING_HTTP_RESPONSE)
+                })
+            }
+            (
+                BreakpointMatcher::ExportedFunction(expected),
+                OplogEntry::ExportedFunctionInvoked { function_name, .. },
+            ) => match expected {
+                Some(expected) => {
+                    function_name == expected
+                        || function_name.ends_with(&format!(".{{{expected}}}"))
+                }
+                None => true,
+            },
+            (BreakpointMatcher::LogMessage(pattern), OplogEntry::Log { message, .. }) => {
+                pattern.is_match(message)
+            }
+            (BreakpointMatcher::Error, OplogEntry::Error { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+// The oplog records imported functions as `namespace::package::interface::function`, while users
+// usually refer to them by their WIT name `namespace:package/interface`
+pub(crate) fn normalize_imported_function_name(name: &str) -> String {
+    name.split("::")
+        .map(|part| part.replace([':', '/'], "::"))
+        .collect::<Vec<_>>()
+        .join("::")
+        .replace('-', "_")
+}
+
+// The normalized names an imported function given by the user can be recorded under. The WASI
+// functions are recorded without their namespace, like `http::types::future_incoming_response::get`,
+// so a WIT name with a namespace also stands for the same name without it.
+pub(crate) fn imported_function_name_patterns(name: &str) -> Vec<String> {
+    match name.split_once(':') {
+        Some((_, rest)) if !rest.starts_with(':') => vec![
+            normalize_imported_function_name(name),
+            normalize_imported_function_name(rest),
+        ],
+        _ => vec![normalize_imported_function_name(name)],
+    }
+}
+
+fn is_same_or_nested(name: &str, prefix: &str) -> bool {
+    name == prefix
+        || name
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with("::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_common::model::invocation_context::TraceId;
+    use golem_common::model::oplog::{DurableFunctionType, LogLevel, OplogPayload, WorkerError};
+    use golem_common::model::{IdempotencyKey, Timestamp};
+    use test_r::test;
+
+    #[test]
+    fn imported_function_breakpoint_matches_interface() {
+        let breakpoints =
+            BreakpointsInternal::from_breakpoints(vec![Breakpoint::ImportedFunction {
+                function_name: "golem:rpc/wasm-rpc".to_string(),
+            }])
+            .unwrap();
+
+        assert!(breakpoints
+            .first_match(&imported_function_invoked(
+                "golem::rpc::wasm-rpc::invoke-and-await"
+            ))
+            .is_some());
+        assert!(breakpoints
+            .first_match(&imported_function_invoked(
+                "golem::rpc::wasm-rpc-other::invoke"
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn imported_function_breakpoint_matches_oplog_names() {
+        let breakpoints =
+            BreakpointsInternal::from_breakpoints(vec![Breakpoint::ImportedFunction {
+                function_name: "golem::rpc::wasm-rpc".to_string(),
+            }])
+            .unwrap();
+
+        assert!(breakpoints
+            .first_match(&imported_function_invoked(
+                "golem::rpc::wasm-rpc::invoke-and-await"
+            ))
+            .is_some());
+        assert!(breakpoints
+            .first_match(&imported_function_invoked(
+                "rpc::wasm-rpc::invoke-and-await"
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn imported_function_breakpoint_matches_outgoing_http_requests() {
+        let breakpoints =
+            BreakpointsInternal::from_breakpoints(vec![Breakpoint::ImportedFunction {
+                function_name: "wasi:http/outgoing-handler".to_string(),
+            }])
+            .unwrap();
+
+        assert!(breakpoints
+            .first_match(&imported_function_invoked(OUTGOING_HTTP_RESPONSE))
+            .is_some());
+        assert!(breakpoints
+            .first_match(&imported_function_invoked("wall_clock::now"))
+            .is_none());
+    }
+
+    #[test]
+    fn exported_function_breakpoint_matches_short_name() {
+        let breakpoints =
+            BreakpointsInternal::from_breakpoints(vec![Breakpoint::ExportedFunction {
+                function_name: Some("add-item".to_string()),
+            }])
+            .unwrap();
+
+        assert!(breakpoints
+            .first_match(&exported_function_invoked("golem:it/api.{add-item}"))
+            .is_some());
+        assert!(breakpoints
+            .first_match(&exported_function_invoked("golem:it/api.{checkout}"))
+            .is_none());
+    }
+
+    #[test]
+    fn log_message_and_error_breakpoints() {
+        let breakpoints = BreakpointsInternal::from_breakpoints(vec![
+            Breakpoint::LogMessage {
+                pattern: "^failed to .* order$".to_string(),
+            },
+            Breakpoint::Error,
+        ])
+        .unwrap();
+
+        let log = OplogEntry::Log {
+            timestamp: Timestamp::now_utc(),
+            level: LogLevel::Error,
+            context: "".to_string(),
+            message: "failed to place the order".to_string(),
+        };
+        let error = OplogEntry::Error {
+            timestamp: Timestamp::now_utc(),
+            error: WorkerError::Unknown("trap".to_string()),
+        };
+
+        assert_eq!(
+            breakpoints.first_match(&log),
+            Some(&Breakpoint::LogMessage {
+                pattern: "^failed to .* order$".to_string(),
+            })
+        );
+        assert_eq!(breakpoints.first_match(&error), Some(&Breakpoint::Error));
+    }
+
+    #[test]
+    fn invalid_log_message_pattern() {
+        let result = BreakpointsInternal::from_breakpoints(vec![Breakpoint::LogMessage {
+            pattern: "(".to_string(),
+        }]);
+
+        assert!(result.is_err());
+    }
+
+    fn imported_function_invoked(function_name: &str) -> OplogEntry {
+        OplogEntry::ImportedFunctionInvoked {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: OplogPayload::Inline(vec![]),
+            response: OplogPayload::Inline(vec![]),
+            durable_function_type: DurableFunctionType::ReadRemote,
+        }
+    }
+
+    fn exported_function_invoked(function_name: &str) -> OplogEntry {
+        OplogEntry::ExportedFunctionInvoked {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: OplogPayload::Inline(vec![]),
+            idempotency_key: IdempotencyKey::fresh(),
+            trace_id: TraceId::generate(),
+            trace_states: vec![],
+            invocation_context: vec![],
+        }
+    }
+}
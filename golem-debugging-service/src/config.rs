@@ -18,10 +18,10 @@ use golem_common::tracing::TracingConfig;
 use golem_service_base::clients::RemoteServiceConfig;
 use golem_service_base::config::BlobStorageConfig;
 use golem_worker_executor::services::golem_config::{
-    ActiveWorkersConfig, CompiledComponentServiceConfig, ComponentCacheConfig,
-    ComponentServiceConfig, ComponentServiceGrpcConfig, GolemConfig, IndexedStorageConfig,
-    KeyValueStorageConfig, Limits, MemoryConfig, OplogConfig, PluginServiceConfig,
-    ProjectServiceConfig, RdbmsConfig, ResourceLimitsConfig, SchedulerConfig,
+    ActiveWorkersConfig, CompiledComponentServiceConfig, CompiledComponentServiceDisabledConfig,
+    ComponentCacheConfig, ComponentServiceConfig, ComponentServiceGrpcConfig, GolemConfig,
+    IndexedStorageConfig, KeyValueStorageConfig, Limits, MemoryConfig, OplogConfig,
+    PluginServiceConfig, ProjectServiceConfig, RdbmsConfig, ResourceLimitsConfig, SchedulerConfig,
     ShardManagerServiceConfig, ShardManagerServiceSingleShardConfig, SuspendConfig,
     WorkerServiceGrpcConfig,
 };
@@ -39,7 +39,6 @@ pub struct DebugConfig {
     pub blob_storage: BlobStorageConfig,
     pub limits: Limits,
     pub retry: RetryConfig,
-    pub compiled_component_service: CompiledComponentServiceConfig,
     pub plugin_service: PluginServiceConfig,
     pub oplog: OplogConfig,
     pub suspend: SuspendConfig,
@@ -71,7 +70,9 @@ impl DebugConfig {
             blob_storage: self.blob_storage,
             limits: self.limits,
             retry: self.retry,
-            compiled_component_service: self.compiled_component_service,
+            compiled_component_service: CompiledComponentServiceConfig::Disabled(
+                CompiledComponentServiceDisabledConfig {},
+            ),
             plugin_service: self.plugin_service,
             oplog: self.oplog,
             suspend: self.suspend,
@@ -108,7 +109,6 @@ impl Default for DebugConfig {
             blob_storage: default_golem_config.blob_storage,
             limits: default_golem_config.limits,
             retry: default_golem_config.retry,
-            compiled_component_service: default_golem_config.compiled_component_service,
             plugin_service: default_golem_config.plugin_service,
             oplog: default_golem_config.oplog,
             suspend: default_golem_config.suspend,
@@ -0,0 +1,773 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// A front-end for the debugging service speaking the Debug Adapter Protocol, so workers can be
+// debugged from VS Code or any other DAP client. Each websocket text message carries one DAP message.
+pub mod oplog_view;
+pub mod protocol;
+pub mod variables;
+
+use crate::dap::oplog_view::{OplogView, OPLOG_SOURCE_REFERENCE};
+use crate::dap::protocol::*;
+use crate::dap::variables::Variables;
+use crate::model::params::{Breakpoint, LogNotification, PlaybackResult};
+use crate::services::debug_service::DebugService;
+use futures::{SinkExt, StreamExt};
+use golem_common::model::auth::{AuthCtx, Namespace};
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::{OwnedWorkerId, WorkerId};
+use golem_worker_executor::services::worker_event::WorkerEventReceiver;
+use poem::web::websocket::{CloseCode, Message, WebSocketStream};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::select;
+use tokio::sync::mpsc::{self, Sender};
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{debug, warn};
+
+// The oplog of the debugged worker is loaded in chunks of this size when attaching to it
+const OPLOG_CHUNK_SIZE: u64 = 1000;
+
+const ERROR_FILTER: &str = "error";
+const INVOCATION_FILTER: &str = "invocation";
+
+pub async fn run_dap_debug_websocket_session(
+    socket_stream: WebSocketStream,
+    debug_service: Arc<dyn DebugService>,
+    auth_ctx: AuthCtx,
+) {
+    let (mut sink, mut stream) = socket_stream.split();
+    let (sender, mut receiver) = mpsc::channel::<OutgoingMessage>(64);
+
+    // dedicated spawned future for sending outgoing messages to the client, numbering them on the way.
+    // Output events are emitted through the same channel while the session is being driven.
+    let sender_handle = tokio::spawn(async move {
+        let mut seq = 0;
+        while let Some(mut message) = receiver.recv().await {
+            seq += 1;
+            message.set_seq(seq);
+
+            debug!("Sending message on DAP debugging websocket: {message:?}");
+
+            let result = sink
+                .send(Message::Text(serde_json::to_string(&message).unwrap()))
+                .await;
+
+            if let Err(e) = result {
+                warn!("Error sending DAP message: {}", e);
+            }
+        }
+
+        let _ = sink
+            .send(Message::Close(Some((
+                CloseCode::Normal,
+                "Connection closed".to_string(),
+            ))))
+            .await;
+    });
+
+    let mut session = DapSession::new(debug_service, auth_ctx, sender.clone());
+
+    // drive the session using the incoming websocket messages
+    while let Some(Ok(msg)) = stream.next().await {
+        match msg {
+            Message::Text(text) => {
+                let request: Request = match serde_json::from_str(&text) {
+                    Ok(request) => request,
+                    Err(e) => {
+                        let response =
+                            Response::error(0, String::new(), format!("Invalid DAP message: {e}"));
+                        let _ = sender.send(OutgoingMessage::Response(response)).await;
+                        continue;
+                    }
+                };
+
+                debug!("Received request on DAP debugging websocket: {request:?}");
+
+                session.handle_request(request).await;
+            }
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+
+    // clean up after ourselves, dropping the remaining senders so the sending future finishes
+    session.terminate().await;
+    drop(sender);
+
+    let _ = sender_handle.await;
+}
+
+struct DapSessionData {
+    namespace: Namespace,
+    connected_worker: OwnedWorkerId,
+    oplog: OplogView,
+    current_index: OplogIndex,
+}
+
+struct DapSession {
+    debug_service: Arc<dyn DebugService>,
+    auth_ctx: AuthCtx,
+    active_session: Option<DapSessionData>,
+
+    // breakpoints may be set before attaching to a worker, they are applied once attached
+    function_breakpoints: Vec<Breakpoint>,
+    exception_breakpoints: Vec<Breakpoint>,
+
+    variables: Variables,
+
+    sender: Sender<OutgoingMessage>,
+    worker_events_processor_dropguard: Option<DropGuard>,
+}
+
+// The body of a successful response, and the events to emit after it
+struct DapOutcome {
+    body: Option<Value>,
+    events: Vec<Event>,
+}
+
+impl DapOutcome {
+    fn empty() -> Self {
+        Self {
+            body: None,
+            events: Vec::new(),
+        }
+    }
+
+    fn body<T: Serialize>(body: T) -> Self {
+        Self {
+            body: Some(serde_json::to_value(body).expect("serializing body failed")),
+            events: Vec::new(),
+        }
+    }
+
+    fn with_event(mut self, event: Event) -> Self {
+        self.events.push(event);
+        self
+    }
+}
+
+impl DapSession {
+    fn new(
+        debug_service: Arc<dyn DebugService>,
+        auth_ctx: AuthCtx,
+        sender: Sender<OutgoingMessage>,
+    ) -> Self {
+        Self {
+            debug_service,
+            auth_ctx,
+            active_session: None,
+            function_breakpoints: Vec::new(),
+            exception_breakpoints: Vec::new(),
+            variables: Variables::default(),
+            sender,
+            worker_events_processor_dropguard: None,
+        }
+    }
+
+    async fn terminate(self) {
+        if let Some(active_session) = self.active_session {
+            let result = self
+                .debug_service
+                .terminate_session(&active_session.connected_worker)
+                .await;
+            if let Err(e) = result {
+                warn!("Failed to terminate debugging session: {e}");
+            }
+        }
+    }
+
+    async fn handle_request(&mut self, request: Request) {
+        let outcome = if request.message_type == "request" {
+            self.handle_command(&request).await
+        } else {
+            Err(format!(
+                "Unexpected message type {}, only requests are accepted",
+                request.message_type
+            ))
+        };
+
+        match outcome {
+            Ok(outcome) => {
+                let response = Response {
+                    body: outcome.body,
+                    ..Response::success::<Value>(&request, None)
+                };
+                let _ = self.sender.send(OutgoingMessage::Response(response)).await;
+
+                for event in outcome.events {
+                    let _ = self.sender.send(OutgoingMessage::Event(event)).await;
+                }
+            }
+            Err(message) => {
+                let response = Response::error(request.seq, request.command.clone(), message);
+                let _ = self.sender.send(OutgoingMessage::Response(response)).await;
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, request: &Request) -> Result<DapOutcome, String> {
+        match request.command.as_str() {
+            "initialize" => Ok(DapOutcome::body(Capabilities {
+                supports_configuration_done_request: true,
+                supports_function_breakpoints: true,
+                supports_step_back: true,
+                exception_breakpoint_filters: vec![
+                    ExceptionBreakpointsFilter {
+                        filter: ERROR_FILTER.to_string(),
+                        label: "Errors".to_string(),
+                        description: "Stop at every error recorded in the oplog".to_string(),
+                    },
+                    ExceptionBreakpointsFilter {
+                        filter: INVOCATION_FILTER.to_string(),
+                        label: "Invocations".to_string(),
+                        description: "Stop at the start of every invocation".to_string(),
+                    },
+                ],
+            })),
+            "launch" => {
+                Err("Launching is not supported, attach to an existing worker instead".to_string())
+            }
+            "attach" => {
+                let arguments: AttachArguments = parse_arguments(request)?;
+                self.attach(arguments).await
+            }
+            "configurationDone" => {
+                // The worker is not played back yet when attaching to it, we report it as stopped
+                // at its first oplog entry
+                let current_index = self.active_session()?.current_index;
+                Ok(DapOutcome::empty().with_event(self.stopped_event(
+                    "entry",
+                    format!("Attached to the worker at oplog index {current_index}"),
+                )?))
+            }
+            "setFunctionBreakpoints" => {
+                let arguments: SetFunctionBreakpointsArguments = parse_arguments(request)?;
+
+                self.function_breakpoints = arguments
+                    .breakpoints
+                    .iter()
+                    .map(|breakpoint| parse_function_breakpoint(&breakpoint.name))
+                    .collect();
+
+                self.apply_breakpoints().await?;
+
+                Ok(DapOutcome::body(BreakpointsResponseBody {
+                    breakpoints: verified(self.function_breakpoints.len()),
+                }))
+            }
+            "setExceptionBreakpoints" => {
+                let arguments: SetExceptionBreakpointsArguments = parse_arguments(request)?;
+
+                self.exception_breakpoints = arguments
+                    .filters
+                    .iter()
+                    .filter_map(|filter| parse_exception_filter(filter))
+                    .collect();
+
+                self.apply_breakpoints().await?;
+
+                Ok(DapOutcome::body(BreakpointsResponseBody {
+                    breakpoints: verified(self.exception_breakpoints.len()),
+                }))
+            }
+            "setBreakpoints" => {
+                // Every source line of the oplog is an entry that was already recorded, so there is
+                // nothing to stop at that could not be expressed by the oplog index itself
+                let count = request
+                    .arguments
+                    .get("breakpoints")
+                    .and_then(|breakpoints| breakpoints.as_array())
+                    .map(|breakpoints| breakpoints.len())
+                    .unwrap_or(0);
+
+                Ok(DapOutcome::body(BreakpointsResponseBody {
+                    breakpoints: (0..count)
+                        .map(|_| BreakpointStatus {
+                            verified: false,
+                            message: Some(
+                                "Source breakpoints are not supported, use function breakpoints"
+                                    .to_string(),
+                            ),
+                        })
+                        .collect(),
+                }))
+            }
+            "threads" => {
+                let session = self.active_session()?;
+                Ok(DapOutcome::body(ThreadsResponseBody {
+                    threads: session.oplog.threads(session.current_index),
+                }))
+            }
+            "stackTrace" => {
+                let arguments: StackTraceArguments = parse_arguments(request)?;
+                let session = self.active_session()?;
+
+                let stack_frames = session
+                    .oplog
+                    .stack_frames(arguments.thread_id, session.current_index);
+                let total_frames = stack_frames.len();
+
+                let stack_frames = stack_frames
+                    .into_iter()
+                    .skip(arguments.start_frame.unwrap_or(0))
+                    .take(
+                        arguments
+                            .levels
+                            .filter(|levels| *levels > 0)
+                            .unwrap_or(total_frames),
+                    )
+                    .collect();
+
+                Ok(DapOutcome::body(StackTraceResponseBody {
+                    stack_frames,
+                    total_frames,
+                }))
+            }
+            "scopes" => {
+                let arguments: ScopesArguments = parse_arguments(request)?;
+                let session = self.active_session()?;
+
+                let variables = session.oplog.frame_variables(
+                    OplogIndex::from_u64(arguments.frame_id as u64),
+                    session.current_index,
+                );
+                let variables_reference = self.variables.add_container(variables);
+
+                Ok(DapOutcome::body(ScopesResponseBody {
+                    scopes: vec![Scope {
+                        name: "Oplog entry".to_string(),
+                        variables_reference,
+                        expensive: false,
+                    }],
+                }))
+            }
+            "variables" => {
+                let arguments: VariablesArguments = parse_arguments(request)?;
+
+                Ok(DapOutcome::body(VariablesResponseBody {
+                    variables: self
+                        .variables
+                        .variables(arguments.variables_reference)
+                        .unwrap_or_default(),
+                }))
+            }
+            "source" => {
+                let source_reference = request
+                    .arguments
+                    .get("sourceReference")
+                    .and_then(|source_reference| source_reference.as_i64());
+
+                if source_reference != Some(OPLOG_SOURCE_REFERENCE) {
+                    return Err("Unknown source".to_string());
+                }
+
+                Ok(DapOutcome::body(SourceResponseBody {
+                    content: self.active_session()?.oplog.source_content(),
+                    mime_type: "text/plain".to_string(),
+                }))
+            }
+            "continue" => {
+                let session = self.active_session()?;
+                let target_index = session.oplog.last_index();
+
+                let result = self
+                    .debug_service
+                    .playback(
+                        &session.connected_worker,
+                        &session.namespace.account_id,
+                        target_index,
+                        None,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                let stopped = self.stopped(result, "pause")?;
+
+                Ok(DapOutcome::body(ContinueResponseBody {
+                    all_threads_continued: true,
+                })
+                .with_event(stopped))
+            }
+            "next" | "stepIn" => {
+                let session = self.active_session()?;
+
+                let result = self
+                    .debug_service
+                    .step(&session.connected_worker, &session.namespace.account_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                Ok(DapOutcome::empty().with_event(self.stopped(result, "step")?))
+            }
+            "stepOut" => {
+                let session = self.active_session()?;
+
+                let result = self
+                    .debug_service
+                    .step_over_invocation(&session.connected_worker, &session.namespace.account_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                Ok(DapOutcome::empty().with_event(self.stopped(result, "step")?))
+            }
+            "stepBack" => {
+                let session = self.active_session()?;
+
+                if session.current_index <= OplogIndex::INITIAL {
+                    return Err("Cannot step back beyond the beginning of the oplog".to_string());
+                }
+
+                let result = self
+                    .debug_service
+                    .rewind(
+                        &session.connected_worker,
+                        &session.namespace.account_id,
+                        session.current_index.previous(),
+                        false,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                let result = PlaybackResult {
+                    worker_id: result.worker_id,
+                    current_index: result.current_index,
+                    message: result.message,
+                    incremental_playback: false,
+                    breakpoint: None,
+                };
+
+                Ok(DapOutcome::empty().with_event(self.stopped(result, "step")?))
+            }
+            "reverseContinue" => {
+                let session = self.active_session()?;
+
+                let result = self
+                    .debug_service
+                    .reverse_continue(&session.connected_worker, &session.namespace.account_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                Ok(DapOutcome::empty().with_event(self.stopped(result, "pause")?))
+            }
+            // Playback always runs until the next stop before responding, there is nothing to pause
+            "pause" => Ok(DapOutcome::empty()),
+            // The session is terminated once the client closes the connection
+            "disconnect" => Ok(DapOutcome::empty()),
+            command => Err(format!("Unsupported command: {command}")),
+        }
+    }
+
+    async fn attach(&mut self, arguments: AttachArguments) -> Result<DapOutcome, String> {
+        if self.active_session.is_some() {
+            return Err("Session is already attached to a worker".to_string());
+        }
+
+        let worker_id = WorkerId::from_str(&arguments.worker_id)?;
+
+        let (_, connected_worker, namespace, worker_event_receiver) = self
+            .debug_service
+            .connect(&self.auth_ctx, &worker_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.start_worker_event_processor(worker_event_receiver);
+
+        let mut entries = Vec::new();
+        let mut from_index = OplogIndex::INITIAL;
+
+        loop {
+            let chunk = self
+                .debug_service
+                .oplog(&connected_worker, from_index, OPLOG_CHUNK_SIZE)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let next_index = chunk.entries.last().map(|entry| entry.oplog_index.next());
+            entries.extend(chunk.entries);
+
+            match next_index {
+                Some(next_index) if next_index <= chunk.last_index => from_index = next_index,
+                _ => break,
+            }
+        }
+
+        let current_index = self
+            .debug_service
+            .current_oplog_index(&connected_worker)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.active_session = Some(DapSessionData {
+            namespace,
+            connected_worker,
+            oplog: OplogView::new(entries),
+            current_index,
+        });
+
+        self.apply_breakpoints().await?;
+
+        Ok(DapOutcome::empty().with_event(Event::new::<Value>("initialized", None)))
+    }
+
+    async fn apply_breakpoints(&self) -> Result<(), String> {
+        if let Some(session) = &self.active_session {
+            let breakpoints = self
+                .function_breakpoints
+                .iter()
+                .chain(self.exception_breakpoints.iter())
+                .cloned()
+                .collect();
+
+            self.debug_service
+                .set_breakpoints(&session.connected_worker, breakpoints)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    fn active_session(&self) -> Result<&DapSessionData, String> {
+        self.active_session
+            .as_ref()
+            .ok_or("The session is not attached to a worker".to_string())
+    }
+
+    // Moves the session to the index the worker stopped at, invalidating all variable references
+    fn stopped(&mut self, result: PlaybackResult, reason: &str) -> Result<Event, String> {
+        let session = self
+            .active_session
+            .as_mut()
+            .ok_or("The session is not attached to a worker".to_string())?;
+        session.current_index = result.current_index;
+
+        self.variables.clear();
+
+        let reason = match &result.breakpoint {
+            Some(Breakpoint::Error) => "exception",
+            Some(_) => "breakpoint",
+            None => reason,
+        };
+
+        self.stopped_event(reason, result.message)
+    }
+
+    fn stopped_event(&self, reason: &str, description: String) -> Result<Event, String> {
+        let session = self.active_session()?;
+
+        Ok(Event::new(
+            "stopped",
+            Some(StoppedEventBody {
+                reason: reason.to_string(),
+                description,
+                thread_id: session.oplog.thread_of(session.current_index),
+                all_threads_stopped: true,
+            }),
+        ))
+    }
+
+    // Forwards the logs of the replayed worker as output events
+    fn start_worker_event_processor(&mut self, worker_event_receiver: WorkerEventReceiver) {
+        let sender = self.sender.clone();
+
+        let token = CancellationToken::new();
+        let cloned_token = token.clone();
+
+        let mut worker_event_stream = worker_event_receiver.to_stream();
+
+        tokio::spawn(async move {
+            loop {
+                select! {
+                    _ = cloned_token.cancelled() => { break; }
+                    Some(event) = worker_event_stream.next() => {
+                        let output = match event {
+                            Ok(event) => LogNotification::from_internal_worker_event(event).map(to_output_event),
+                            Err(BroadcastStreamRecvError::Lagged(number_of_missed_messages)) => {
+                                Some(OutputEventBody {
+                                    category: "console".to_string(),
+                                    output: format!("{number_of_missed_messages} log messages were missed\n"),
+                                })
+                            }
+                        };
+
+                        if let Some(output) = output {
+                            let _ = sender.send(OutgoingMessage::Event(Event::new("output", Some(output)))).await;
+                        }
+                    }
+                }
+            }
+        });
+
+        // cancel spawned forwarding future when we are dropped
+        self.worker_events_processor_dropguard = Some(token.drop_guard());
+    }
+}
+
+fn to_output_event(notification: LogNotification) -> OutputEventBody {
+    match notification {
+        LogNotification::StdOut { message, .. } => OutputEventBody {
+            category: "stdout".to_string(),
+            output: message,
+        },
+        LogNotification::StdErr { message, .. } => OutputEventBody {
+            category: "stderr".to_string(),
+            output: message,
+        },
+        LogNotification::Log {
+            level,
+            context,
+            message,
+            ..
+        } => OutputEventBody {
+            category: "console".to_string(),
+            output: format!("[{level:?}] {context}: {message}\n"),
+        },
+    }
+}
+
+// Function breakpoints are named after the kind of breakpoint they map to, defaulting to imported functions:
+// `wasi:http/outgoing-handler`, `import:golem:rpc/wasm-rpc`, `export:add-item` or `log:<pattern>`.
+// A bare `export:` stops at every invocation.
+fn parse_function_breakpoint(name: &str) -> Breakpoint {
+    if let Some(function_name) = name.strip_prefix("export:") {
+        Breakpoint::ExportedFunction {
+            function_name: Some(function_name.trim())
+                .filter(|function_name| !function_name.is_empty())
+                .map(|function_name| function_name.to_string()),
+        }
+    } else if let Some(pattern) = name.strip_prefix("log:") {
+        Breakpoint::LogMessage {
+            pattern: pattern.to_string(),
+        }
+    } else {
+        Breakpoint::ImportedFunction {
+            function_name: name
+                .strip_prefix("import:")
+                .unwrap_or(name)
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+fn parse_exception_filter(filter: &str) -> Option<Breakpoint> {
+    match filter {
+        ERROR_FILTER => Some(Breakpoint::Error),
+        INVOCATION_FILTER => Some(Breakpoint::ExportedFunction {
+            function_name: None,
+        }),
+        _ => None,
+    }
+}
+
+fn verified(count: usize) -> Vec<BreakpointStatus> {
+    (0..count)
+        .map(|_| BreakpointStatus {
+            verified: true,
+            message: None,
+        })
+        .collect()
+}
+
+fn parse_arguments<T: DeserializeOwned>(request: &Request) -> Result<T, String> {
+    serde_json::from_value(request.arguments.clone())
+        .map_err(|e| format!("Invalid arguments for {}: {e}", request.command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_r::test;
+
+    #[test]
+    fn function_breakpoints_are_parsed_by_prefix() {
+        assert_eq!(
+            parse_function_breakpoint("wasi:http/outgoing-handler"),
+            Breakpoint::ImportedFunction {
+                function_name: "wasi:http/outgoing-handler".to_string()
+            }
+        );
+        assert_eq!(
+            parse_function_breakpoint("import:golem:rpc/wasm-rpc"),
+            Breakpoint::ImportedFunction {
+                function_name: "golem:rpc/wasm-rpc".to_string()
+            }
+        );
+        assert_eq!(
+            parse_function_breakpoint("export:add-item"),
+            Breakpoint::ExportedFunction {
+                function_name: Some("add-item".to_string())
+            }
+        );
+        assert_eq!(
+            parse_function_breakpoint("export:"),
+            Breakpoint::ExportedFunction {
+                function_name: None
+            }
+        );
+        assert_eq!(
+            parse_function_breakpoint("log:^checkout.*"),
+            Breakpoint::LogMessage {
+                pattern: "^checkout.*".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn outgoing_messages_are_tagged_with_their_type() {
+        let request: Request = serde_json::from_value(serde_json::json!({
+            "seq": 3,
+            "type": "request",
+            "command": "threads"
+        }))
+        .unwrap();
+
+        let mut response = OutgoingMessage::Response(Response::success(
+            &request,
+            Some(ThreadsResponseBody {
+                threads: vec![Thread {
+                    id: 1,
+                    name: "worker".to_string(),
+                }],
+            }),
+        ));
+        response.set_seq(7);
+
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "type": "response",
+                "seq": 7,
+                "request_seq": 3,
+                "success": true,
+                "command": "threads",
+                "body": { "threads": [{ "id": 1, "name": "worker" }] }
+            })
+        );
+
+        let event = OutgoingMessage::Event(Event::new::<Value>("initialized", None));
+
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({ "type": "event", "seq": 0, "event": "initialized" })
+        );
+    }
+}
@@ -0,0 +1,532 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::dap::protocol::{Source, StackFrame, Thread};
+use crate::dap::variables::VariableValue;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_service_base::model::PublicOplogEntryWithIndex;
+
+// Entries that do not belong to any invocation (such as the creation of the worker) are
+// shown on a thread of their own, identified by the index of the first oplog entry
+pub const WORKER_THREAD_ID: i64 = 1;
+
+pub const OPLOG_SOURCE_REFERENCE: i64 = 1;
+
+// A read-only view over the oplog of the debugged worker, mapping it to the concepts of DAP:
+// - every invocation is a thread, identified by the oplog index it started at
+// - the stack frames of a thread are the oplog regions (atomic regions and remote writes) open at
+//   the current index, nested in the invocation itself
+// - the oplog is a source file with one line per entry, so every frame has the line of its oplog index
+pub struct OplogView {
+    entries: Vec<PublicOplogEntryWithIndex>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Invocation {
+    start: OplogIndex,
+    end: Option<OplogIndex>,
+    function_name: String,
+}
+
+impl Invocation {
+    fn contains(&self, index: OplogIndex) -> bool {
+        self.start <= index && self.end.is_none_or(|end| index <= end)
+    }
+}
+
+impl OplogView {
+    pub fn new(entries: Vec<PublicOplogEntryWithIndex>) -> Self {
+        Self { entries }
+    }
+
+    pub fn last_index(&self) -> OplogIndex {
+        self.entries
+            .last()
+            .map(|entry| entry.oplog_index)
+            .unwrap_or(OplogIndex::NONE)
+    }
+
+    pub fn entry(&self, index: OplogIndex) -> Option<&PublicOplogEntry> {
+        self.entries
+            .binary_search_by_key(&index, |entry| entry.oplog_index)
+            .ok()
+            .map(|position| &self.entries[position].entry)
+    }
+
+    pub fn threads(&self, current_index: OplogIndex) -> Vec<Thread> {
+        let mut threads = vec![Thread {
+            id: WORKER_THREAD_ID,
+            name: "worker".to_string(),
+        }];
+
+        threads.extend(
+            self.invocations()
+                .into_iter()
+                .filter(|invocation| invocation.start <= current_index)
+                .map(|invocation| Thread {
+                    id: u64::from(invocation.start) as i64,
+                    name: format!("{} #{}", invocation.function_name, invocation.start),
+                }),
+        );
+
+        threads
+    }
+
+    // The thread the entry at the given index belongs to
+    pub fn thread_of(&self, index: OplogIndex) -> i64 {
+        self.invocations()
+            .into_iter()
+            .find(|invocation| invocation.contains(index))
+            .map(|invocation| u64::from(invocation.start) as i64)
+            .unwrap_or(WORKER_THREAD_ID)
+    }
+
+    pub fn stack_frames(&self, thread_id: i64, current_index: OplogIndex) -> Vec<StackFrame> {
+        if thread_id == WORKER_THREAD_ID {
+            let index = if current_index.is_defined()
+                && self.thread_of(current_index) == WORKER_THREAD_ID
+            {
+                current_index
+            } else {
+                OplogIndex::INITIAL
+            };
+
+            return self.frame(index).into_iter().collect();
+        }
+
+        let invocation = self
+            .invocations()
+            .into_iter()
+            .find(|invocation| u64::from(invocation.start) as i64 == thread_id);
+
+        match invocation {
+            Some(invocation) if invocation.contains(current_index) => {
+                let open_regions = self.open_regions(invocation.start, current_index);
+
+                let mut frames = Vec::new();
+
+                if current_index != invocation.start && !open_regions.contains(&current_index) {
+                    frames.extend(self.frame(current_index));
+                }
+
+                for region_start in open_regions.into_iter().rev() {
+                    frames.extend(self.frame(region_start));
+                }
+
+                frames.extend(self.frame(invocation.start));
+
+                frames
+            }
+            Some(invocation) => self.frame(invocation.start).into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // The variables of a stack frame are the decoded fields of the oplog entry it starts at.
+    // For invocations it includes their result, if they have been completed already
+    pub fn frame_variables(
+        &self,
+        index: OplogIndex,
+        current_index: OplogIndex,
+    ) -> Vec<(String, VariableValue)> {
+        match self.entry(index) {
+            Some(PublicOplogEntry::ImportedFunctionInvoked(params)) => vec![
+                (
+                    "function_name".to_string(),
+                    VariableValue::Text(params.function_name.clone()),
+                ),
+                (
+                    "request".to_string(),
+                    VariableValue::Value(params.request.clone()),
+                ),
+                (
+                    "response".to_string(),
+                    VariableValue::Value(params.response.clone()),
+                ),
+                (
+                    "durable_function_type".to_string(),
+                    VariableValue::Json(
+                        serde_json::to_value(&params.durable_function_type)
+                            .unwrap_or(serde_json::Value::Null),
+                    ),
+                ),
+            ],
+            Some(PublicOplogEntry::ExportedFunctionInvoked(params)) => {
+                let mut variables = vec![
+                    (
+                        "function_name".to_string(),
+                        VariableValue::Text(params.function_name.clone()),
+                    ),
+                    (
+                        "idempotency_key".to_string(),
+                        VariableValue::Text(params.idempotency_key.to_string()),
+                    ),
+                    (
+                        "params".to_string(),
+                        VariableValue::Group(
+                            params
+                                .request
+                                .iter()
+                                .enumerate()
+                                .map(|(idx, param)| {
+                                    (format!("[{idx}]"), VariableValue::Value(param.clone()))
+                                })
+                                .collect(),
+                        ),
+                    ),
+                ];
+
+                let completed = self
+                    .invocations()
+                    .into_iter()
+                    .find(|invocation| invocation.start == index)
+                    .and_then(|invocation| invocation.end)
+                    .filter(|end| *end <= current_index)
+                    .and_then(|end| self.entry(end));
+
+                if let Some(PublicOplogEntry::ExportedFunctionCompleted(completed)) = completed {
+                    variables.push(("result".to_string(), result_variable(&completed.response)));
+                }
+
+                variables
+            }
+            Some(PublicOplogEntry::ExportedFunctionCompleted(params)) => vec![
+                ("result".to_string(), result_variable(&params.response)),
+                (
+                    "consumed_fuel".to_string(),
+                    VariableValue::Text(params.consumed_fuel.to_string()),
+                ),
+            ],
+            Some(entry) => match serde_json::to_value(entry) {
+                Ok(serde_json::Value::Object(fields)) => fields
+                    .into_iter()
+                    .filter(|(name, _)| name != "type")
+                    .map(|(name, value)| (name, VariableValue::Json(value)))
+                    .collect(),
+                _ => Vec::new(),
+            },
+            None => Vec::new(),
+        }
+    }
+
+    // The text of the oplog source, having the description of the entry at index N on line N
+    pub fn source_content(&self) -> String {
+        let mut lines = Vec::new();
+        let mut next_line = OplogIndex::INITIAL;
+
+        for entry in &self.entries {
+            while next_line < entry.oplog_index {
+                lines.push(String::new());
+                next_line = next_line.next();
+            }
+            lines.push(describe_entry(&entry.entry));
+            next_line = next_line.next();
+        }
+
+        lines.join("\n")
+    }
+
+    fn frame(&self, index: OplogIndex) -> Option<StackFrame> {
+        self.entry(index).map(|entry| StackFrame {
+            id: u64::from(index) as i64,
+            name: describe_entry(entry),
+            source: oplog_source(),
+            line: u64::from(index) as i64,
+            column: 1,
+        })
+    }
+
+    fn invocations(&self) -> Vec<Invocation> {
+        let mut invocations: Vec<Invocation> = Vec::new();
+
+        for entry in &self.entries {
+            match &entry.entry {
+                PublicOplogEntry::ExportedFunctionInvoked(params) => {
+                    // an invocation that never completed, for example because it failed
+                    if let Some(last) = invocations.last_mut() {
+                        if last.end.is_none() {
+                            last.end = Some(entry.oplog_index.previous());
+                        }
+                    }
+
+                    invocations.push(Invocation {
+                        start: entry.oplog_index,
+                        end: None,
+                        function_name: params.function_name.clone(),
+                    });
+                }
+                PublicOplogEntry::ExportedFunctionCompleted(_) => {
+                    if let Some(last) = invocations.last_mut() {
+                        if last.end.is_none() {
+                            last.end = Some(entry.oplog_index);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        invocations
+    }
+
+    // Start indices of the regions open at `current_index`, from the outermost to the innermost one
+    fn open_regions(&self, after_index: OplogIndex, current_index: OplogIndex) -> Vec<OplogIndex> {
+        let mut open_regions = Vec::new();
+
+        for entry in &self.entries {
+            if entry.oplog_index <= after_index || entry.oplog_index > current_index {
+                continue;
+            }
+
+            match &entry.entry {
+                PublicOplogEntry::BeginAtomicRegion(_) | PublicOplogEntry::BeginRemoteWrite(_) => {
+                    open_regions.push(entry.oplog_index);
+                }
+                PublicOplogEntry::EndAtomicRegion(params)
+                | PublicOplogEntry::EndRemoteWrite(params) => {
+                    open_regions.retain(|start| *start != params.begin_index);
+                }
+                _ => {}
+            }
+        }
+
+        open_regions
+    }
+}
+
+pub fn oplog_source() -> Source {
+    Source {
+        name: "oplog".to_string(),
+        source_reference: OPLOG_SOURCE_REFERENCE,
+    }
+}
+
+pub fn describe_entry(entry: &PublicOplogEntry) -> String {
+    let entry_type = serde_json::to_value(entry)
+        .ok()
+        .and_then(|value| {
+            value
+                .get("type")
+                .and_then(|entry_type| entry_type.as_str())
+                .map(|entry_type| entry_type.to_string())
+        })
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let details = match entry {
+        PublicOplogEntry::ImportedFunctionInvoked(params) => Some(params.function_name.clone()),
+        PublicOplogEntry::ExportedFunctionInvoked(params) => Some(params.function_name.clone()),
+        PublicOplogEntry::Log(params) => Some(params.message.clone()),
+        PublicOplogEntry::Error(params) => Some(params.error.clone()),
+        _ => None,
+    };
+
+    match details {
+        // every entry has to stay on a single line of the oplog source
+        Some(details) => format!("{entry_type} {}", details.replace(['\r', '\n'], " ")),
+        None => entry_type,
+    }
+}
+
+fn result_variable(response: &Option<golem_wasm_rpc::ValueAndType>) -> VariableValue {
+    match response {
+        Some(response) => VariableValue::Value(response.clone()),
+        None => VariableValue::Text("()".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_common::model::invocation_context::TraceId;
+    use golem_common::model::oplog::LogLevel;
+    use golem_common::model::public_oplog::{
+        EndRegionParameters, ExportedFunctionCompletedParameters,
+        ExportedFunctionInvokedParameters, ImportedFunctionInvokedParameters, LogParameters,
+        PublicDurableFunctionType, TimestampParameter,
+    };
+    use golem_common::model::{Empty, IdempotencyKey, Timestamp};
+    use golem_wasm_rpc::IntoValueAndType;
+    use test_r::test;
+
+    #[test]
+    fn threads_are_the_invocations_started_until_the_current_index() {
+        let view = shopping_cart_view();
+
+        assert_eq!(
+            thread_ids(&view.threads(OplogIndex::from_u64(5))),
+            vec![WORKER_THREAD_ID, 2]
+        );
+        assert_eq!(
+            thread_ids(&view.threads(OplogIndex::from_u64(9))),
+            vec![WORKER_THREAD_ID, 2, 8]
+        );
+
+        assert_eq!(view.thread_of(OplogIndex::INITIAL), WORKER_THREAD_ID);
+        assert_eq!(view.thread_of(OplogIndex::from_u64(5)), 2);
+        assert_eq!(view.thread_of(OplogIndex::from_u64(7)), 2);
+        assert_eq!(view.thread_of(OplogIndex::from_u64(9)), 8);
+    }
+
+    #[test]
+    fn stack_frames_are_the_open_regions_of_the_invocation() {
+        let view = shopping_cart_view();
+
+        assert_eq!(
+            frame_ids(&view.stack_frames(2, OplogIndex::from_u64(5))),
+            vec![5, 4, 2]
+        );
+        assert_eq!(
+            frame_ids(&view.stack_frames(2, OplogIndex::from_u64(6))),
+            vec![6, 2]
+        );
+        assert_eq!(
+            frame_ids(&view.stack_frames(2, OplogIndex::from_u64(9))),
+            vec![2]
+        );
+        assert_eq!(
+            frame_ids(&view.stack_frames(WORKER_THREAD_ID, OplogIndex::from_u64(9))),
+            vec![1]
+        );
+
+        let frames = view.stack_frames(8, OplogIndex::from_u64(9));
+
+        assert_eq!(frame_ids(&frames), vec![9, 8]);
+        assert_eq!(frames[0].name, "Log item added");
+        assert_eq!(frames[0].line, 9);
+        assert_eq!(
+            frames[1].name,
+            "ExportedFunctionInvoked golem:it/api.{checkout}"
+        );
+    }
+
+    #[test]
+    fn invocation_variables_include_the_result_once_completed() {
+        let view = shopping_cart_view();
+
+        assert_eq!(
+            variable_names(&view.frame_variables(OplogIndex::from_u64(2), OplogIndex::from_u64(5))),
+            vec!["function_name", "idempotency_key", "params"]
+        );
+
+        let variables = view.frame_variables(OplogIndex::from_u64(2), OplogIndex::from_u64(7));
+
+        assert_eq!(
+            variable_names(&variables),
+            vec!["function_name", "idempotency_key", "params", "result"]
+        );
+        assert_eq!(
+            variables[3].1,
+            VariableValue::Value("done".into_value_and_type())
+        );
+
+        // other entries are shown by their JSON representation
+        let variables = view.frame_variables(OplogIndex::from_u64(6), OplogIndex::from_u64(7));
+        let mut names = variable_names(&variables);
+        names.sort();
+
+        assert_eq!(names, vec!["beginIndex", "timestamp"]);
+    }
+
+    #[test]
+    fn source_has_one_line_per_oplog_entry() {
+        let view = shopping_cart_view();
+
+        let content = view.source_content();
+        let lines = content.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "NoOp");
+        assert_eq!(
+            lines[2],
+            "ImportedFunctionInvoked golem::rpc::wasm-rpc::invoke"
+        );
+        assert_eq!(lines[8], "Log item added");
+    }
+
+    fn shopping_cart_view() -> OplogView {
+        OplogView::new(
+            vec![
+                PublicOplogEntry::NoOp(timestamp()),
+                exported_function_invoked("golem:it/api.{add-item}"),
+                imported_function_invoked("golem::rpc::wasm-rpc::invoke"),
+                PublicOplogEntry::BeginAtomicRegion(timestamp()),
+                imported_function_invoked("golem::rpc::wasm-rpc::invoke"),
+                PublicOplogEntry::EndAtomicRegion(EndRegionParameters {
+                    timestamp: Timestamp::now_utc(),
+                    begin_index: OplogIndex::from_u64(4),
+                }),
+                PublicOplogEntry::ExportedFunctionCompleted(ExportedFunctionCompletedParameters {
+                    timestamp: Timestamp::now_utc(),
+                    response: Some("done".into_value_and_type()),
+                    consumed_fuel: 0,
+                }),
+                exported_function_invoked("golem:it/api.{checkout}"),
+                PublicOplogEntry::Log(LogParameters {
+                    timestamp: Timestamp::now_utc(),
+                    level: LogLevel::Info,
+                    context: "checkout".to_string(),
+                    message: "item\nadded".to_string(),
+                }),
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, entry)| PublicOplogEntryWithIndex {
+                oplog_index: OplogIndex::from_u64(idx as u64 + 1),
+                entry,
+            })
+            .collect(),
+        )
+    }
+
+    fn timestamp() -> TimestampParameter {
+        TimestampParameter {
+            timestamp: Timestamp::now_utc(),
+        }
+    }
+
+    fn exported_function_invoked(function_name: &str) -> PublicOplogEntry {
+        PublicOplogEntry::ExportedFunctionInvoked(ExportedFunctionInvokedParameters {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: vec!["G1000".into_value_and_type()],
+            idempotency_key: IdempotencyKey::fresh(),
+            trace_id: TraceId::generate(),
+            trace_states: Vec::new(),
+            invocation_context: Vec::new(),
+        })
+    }
+
+    fn imported_function_invoked(function_name: &str) -> PublicOplogEntry {
+        PublicOplogEntry::ImportedFunctionInvoked(ImportedFunctionInvokedParameters {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: "request".into_value_and_type(),
+            response: "response".into_value_and_type(),
+            durable_function_type: PublicDurableFunctionType::WriteRemote(Empty {}),
+        })
+    }
+
+    fn thread_ids(threads: &[Thread]) -> Vec<i64> {
+        threads.iter().map(|thread| thread.id).collect()
+    }
+
+    fn frame_ids(frames: &[StackFrame]) -> Vec<i64> {
+        frames.iter().map(|frame| frame.id).collect()
+    }
+
+    fn variable_names(variables: &[(String, VariableValue)]) -> Vec<&str> {
+        variables.iter().map(|(name, _)| name.as_str()).collect()
+    }
+}
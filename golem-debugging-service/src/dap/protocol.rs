@@ -0,0 +1,262 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Messages of the Debug Adapter Protocol (https://microsoft.github.io/debug-adapter-protocol/specification),
+// limited to the requests, responses and events the debugging service supports
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Deserialize, Debug)]
+pub struct Request {
+    pub seq: i64,
+    #[serde(rename = "type")]
+    pub message_type: String,
+    pub command: String,
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+// The sequence numbers of the outgoing messages are assigned when they get sent
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OutgoingMessage {
+    Response(Response),
+    Event(Event),
+}
+
+impl OutgoingMessage {
+    pub fn set_seq(&mut self, seq: i64) {
+        match self {
+            OutgoingMessage::Response(response) => response.seq = seq,
+            OutgoingMessage::Event(event) => event.seq = seq,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct Response {
+    pub seq: i64,
+    pub request_seq: i64,
+    pub success: bool,
+    pub command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+impl Response {
+    pub fn success<T: Serialize>(request: &Request, body: Option<T>) -> Self {
+        Self {
+            seq: 0,
+            request_seq: request.seq,
+            success: true,
+            command: request.command.clone(),
+            message: None,
+            body: body.map(|body| serde_json::to_value(body).expect("serializing body failed")),
+        }
+    }
+
+    pub fn error(request_seq: i64, command: String, message: String) -> Self {
+        Self {
+            seq: 0,
+            request_seq,
+            success: false,
+            command,
+            message: Some(message),
+            body: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct Event {
+    pub seq: i64,
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+impl Event {
+    pub fn new<T: Serialize>(event: &str, body: Option<T>) -> Self {
+        Self {
+            seq: 0,
+            event: event.to_string(),
+            body: body.map(|body| serde_json::to_value(body).expect("serializing body failed")),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachArguments {
+    // `component-id:worker-name`, as accepted by `golem worker` commands
+    pub worker_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SetFunctionBreakpointsArguments {
+    pub breakpoints: Vec<FunctionBreakpoint>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FunctionBreakpoint {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SetExceptionBreakpointsArguments {
+    pub filters: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StackTraceArguments {
+    pub thread_id: i64,
+    pub start_frame: Option<usize>,
+    pub levels: Option<usize>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopesArguments {
+    pub frame_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VariablesArguments {
+    pub variables_reference: i64,
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    pub supports_configuration_done_request: bool,
+    pub supports_function_breakpoints: bool,
+    pub supports_step_back: bool,
+    pub exception_breakpoint_filters: Vec<ExceptionBreakpointsFilter>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ExceptionBreakpointsFilter {
+    pub filter: String,
+    pub label: String,
+    pub description: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointsResponseBody {
+    pub breakpoints: Vec<BreakpointStatus>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointStatus {
+    pub verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinueResponseBody {
+    pub all_threads_continued: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Thread {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ThreadsResponseBody {
+    pub threads: Vec<Thread>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StackFrame {
+    pub id: i64,
+    pub name: String,
+    pub source: Source,
+    pub line: i64,
+    pub column: i64,
+}
+
+// The oplog is exposed to the client as a single source, having one line per oplog entry
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Source {
+    pub name: String,
+    pub source_reference: i64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StackTraceResponseBody {
+    pub stack_frames: Vec<StackFrame>,
+    pub total_frames: usize,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceResponseBody {
+    pub content: String,
+    pub mime_type: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Scope {
+    pub name: String,
+    pub variables_reference: i64,
+    pub expensive: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ScopesResponseBody {
+    pub scopes: Vec<Scope>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    pub name: String,
+    pub value: String,
+    pub variables_reference: i64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct VariablesResponseBody {
+    pub variables: Vec<Variable>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StoppedEventBody {
+    pub reason: String,
+    pub description: String,
+    pub thread_id: i64,
+    pub all_threads_stopped: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct OutputEventBody {
+    pub category: String,
+    pub output: String,
+}
@@ -0,0 +1,264 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::dap::protocol::Variable;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{print_value_and_type, Value, ValueAndType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariableValue {
+    Text(String),
+    Value(ValueAndType),
+    Json(serde_json::Value),
+    Group(Vec<(String, VariableValue)>),
+}
+
+impl VariableValue {
+    fn display(&self) -> String {
+        match self {
+            VariableValue::Text(text) => text.clone(),
+            VariableValue::Value(value) => {
+                print_value_and_type(value).unwrap_or_else(|_| format!("{:?}", value.value))
+            }
+            VariableValue::Json(serde_json::Value::Array(items)) => {
+                format!("[{} items]", items.len())
+            }
+            VariableValue::Json(serde_json::Value::Object(_)) => "{...}".to_string(),
+            VariableValue::Json(value) => value.to_string(),
+            VariableValue::Group(children) => format!("[{} items]", children.len()),
+        }
+    }
+
+    fn children(&self) -> Vec<(String, VariableValue)> {
+        match self {
+            VariableValue::Text(_) => Vec::new(),
+            VariableValue::Value(value) => value_children(&value.value, &value.typ),
+            VariableValue::Json(serde_json::Value::Array(items)) => items
+                .iter()
+                .enumerate()
+                .map(|(idx, item)| (format!("[{idx}]"), VariableValue::Json(item.clone())))
+                .collect(),
+            VariableValue::Json(serde_json::Value::Object(fields)) => fields
+                .iter()
+                .map(|(name, field)| (name.clone(), VariableValue::Json(field.clone())))
+                .collect(),
+            VariableValue::Json(_) => Vec::new(),
+            VariableValue::Group(children) => children.clone(),
+        }
+    }
+}
+
+// The variables of the suspended worker, addressed by the DAP variable references.
+// References are only valid until the worker gets resumed, so they are allocated on demand
+// when the client expands a variable, and dropped on every stop
+#[derive(Debug, Default)]
+pub struct Variables {
+    containers: Vec<Vec<(String, VariableValue)>>,
+}
+
+impl Variables {
+    pub fn clear(&mut self) {
+        self.containers.clear();
+    }
+
+    pub fn add_container(&mut self, children: Vec<(String, VariableValue)>) -> i64 {
+        self.containers.push(children);
+        self.containers.len() as i64
+    }
+
+    pub fn variables(&mut self, variables_reference: i64) -> Option<Vec<Variable>> {
+        let children = self
+            .containers
+            .get((variables_reference - 1) as usize)?
+            .clone();
+
+        let variables = children
+            .into_iter()
+            .map(|(name, value)| {
+                let grand_children = value.children();
+
+                let variables_reference = if grand_children.is_empty() {
+                    0
+                } else {
+                    self.add_container(grand_children)
+                };
+
+                Variable {
+                    name,
+                    value: value.display(),
+                    variables_reference,
+                }
+            })
+            .collect();
+
+        Some(variables)
+    }
+}
+
+fn value_children(value: &Value, typ: &AnalysedType) -> Vec<(String, VariableValue)> {
+    match (value, typ) {
+        (Value::Record(fields), AnalysedType::Record(record)) => fields
+            .iter()
+            .zip(record.fields.iter())
+            .map(|(field, field_type)| {
+                (
+                    field_type.name.clone(),
+                    VariableValue::Value(ValueAndType::new(field.clone(), field_type.typ.clone())),
+                )
+            })
+            .collect(),
+        (Value::Tuple(items), AnalysedType::Tuple(tuple)) => items
+            .iter()
+            .zip(tuple.items.iter())
+            .enumerate()
+            .map(|(idx, (item, item_type))| {
+                (
+                    format!("[{idx}]"),
+                    VariableValue::Value(ValueAndType::new(item.clone(), item_type.clone())),
+                )
+            })
+            .collect(),
+        (Value::List(items), AnalysedType::List(list)) => items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                (
+                    format!("[{idx}]"),
+                    VariableValue::Value(ValueAndType::new(item.clone(), (*list.inner).clone())),
+                )
+            })
+            .collect(),
+        (Value::Option(Some(inner)), AnalysedType::Option(option)) => vec![(
+            "some".to_string(),
+            VariableValue::Value(ValueAndType::new(
+                (**inner).clone(),
+                (*option.inner).clone(),
+            )),
+        )],
+        (Value::Result(Ok(Some(ok))), AnalysedType::Result(result)) => match &result.ok {
+            Some(ok_type) => vec![(
+                "ok".to_string(),
+                VariableValue::Value(ValueAndType::new((**ok).clone(), (**ok_type).clone())),
+            )],
+            None => Vec::new(),
+        },
+        (Value::Result(Err(Some(err))), AnalysedType::Result(result)) => match &result.err {
+            Some(err_type) => vec![(
+                "err".to_string(),
+                VariableValue::Value(ValueAndType::new((**err).clone(), (**err_type).clone())),
+            )],
+            None => Vec::new(),
+        },
+        (
+            Value::Variant {
+                case_idx,
+                case_value: Some(case_value),
+            },
+            AnalysedType::Variant(variant),
+        ) => match variant.cases.get(*case_idx as usize) {
+            Some(case) => match &case.typ {
+                Some(case_type) => vec![(
+                    case.name.clone(),
+                    VariableValue::Value(ValueAndType::new(
+                        (**case_value).clone(),
+                        case_type.clone(),
+                    )),
+                )],
+                None => Vec::new(),
+            },
+            None => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_wasm_ast::analysis::analysed_type::{field, list, record, str, u32};
+    use golem_wasm_rpc::IntoValueAndType;
+    use test_r::test;
+
+    #[test]
+    fn variables_expand_records_and_lists() {
+        let mut variables = Variables::default();
+
+        let value = ValueAndType::new(
+            Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::List(vec![Value::U32(1), Value::U32(2)]),
+            ]),
+            record(vec![
+                field("product-id", str()),
+                field("quantities", list(u32())),
+            ]),
+        );
+
+        let reference = variables.add_container(vec![
+            (
+                "function_name".to_string(),
+                VariableValue::Text("golem:it/api.{add-item}".to_string()),
+            ),
+            ("request".to_string(), VariableValue::Value(value)),
+            (
+                "count".to_string(),
+                VariableValue::Value(10u64.into_value_and_type()),
+            ),
+        ]);
+
+        let top_level = variables.variables(reference).unwrap();
+
+        assert_eq!(top_level.len(), 3);
+        assert_eq!(top_level[0].value, "golem:it/api.{add-item}");
+        assert_eq!(top_level[0].variables_reference, 0);
+        assert_eq!(top_level[2].value, "10");
+        assert_eq!(top_level[2].variables_reference, 0);
+        assert_ne!(top_level[1].variables_reference, 0);
+
+        let fields = variables
+            .variables(top_level[1].variables_reference)
+            .unwrap();
+
+        assert_eq!(fields[0].name, "product-id");
+        assert_eq!(fields[0].value, "\"G1000\"");
+        assert_eq!(fields[1].name, "quantities");
+        assert_eq!(fields[1].value, "[1, 2]");
+
+        let items = variables.variables(fields[1].variables_reference).unwrap();
+
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| (item.name.as_str(), item.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("[0]", "1"), ("[1]", "2")]
+        );
+    }
+
+    #[test]
+    fn variables_are_dropped_on_clear() {
+        let mut variables = Variables::default();
+
+        let reference = variables.add_container(vec![(
+            "error".to_string(),
+            VariableValue::Json(serde_json::json!({ "message": "failed" })),
+        )]);
+
+        assert!(variables.variables(reference).is_some());
+
+        variables.clear();
+
+        assert!(variables.variables(reference).is_none());
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use crate::additional_deps::AdditionalDeps;
+use crate::debug_session::{DebugSessionId, DebugSessions};
+use crate::memory::WorkerMemories;
 use anyhow::Error;
 use async_trait::async_trait;
 use golem_common::model::invocation_context::{
@@ -66,12 +68,13 @@ use golem_worker_executor::workerctx::{
 use std::collections::HashSet;
 use std::sync::{Arc, RwLock, Weak};
 use wasmtime::component::{Component, Instance, Linker, Resource, ResourceAny};
-use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync};
+use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync, WasmBacktrace};
 use wasmtime_wasi::p2::WasiView;
 use wasmtime_wasi_http::WasiHttpView;
 
 pub struct DebugContext {
     pub durable_ctx: DurableWorkerCtx<Self>,
+    debug_session: Arc<dyn DebugSessions + Sync + Send>,
 }
 
 impl DurableWorkerCtxView<DebugContext> for DebugContext {
@@ -262,9 +265,21 @@ impl InvocationHooks for DebugContext {
     }
 
     async fn on_invocation_failure(&mut self, trap_type: &TrapType) -> RetryDecision {
+        if let Some(replay_divergence) = self.durable_ctx.replay_divergence() {
+            self.debug_session
+                .update_replay_divergence(
+                    DebugSessionId::new(self.durable_ctx.owned_worker_id().clone()),
+                    replay_divergence.clone(),
+                )
+                .await;
+        }
         self.durable_ctx.on_invocation_failure(trap_type).await
     }
 
+    fn on_guest_backtrace(&mut self, backtrace: &WasmBacktrace) {
+        self.durable_ctx.on_guest_backtrace(backtrace)
+    }
+
     async fn on_invocation_success(
         &mut self,
         full_function_name: &str,
@@ -378,17 +393,22 @@ impl FileSystemReading for DebugContext {
 impl ResourceLimiterAsync for DebugContext {
     async fn memory_growing(
         &mut self,
-        _current: usize,
+        current: usize,
         desired: usize,
         _maximum: Option<usize>,
     ) -> anyhow::Result<bool> {
         let current_known = self.durable_ctx.total_linear_memory_size();
         let delta = (desired as u64).saturating_sub(current_known);
-        if delta > 0 {
-            Ok(self.durable_ctx.increase_memory(delta).await?)
+        let allowed = if delta > 0 {
+            self.durable_ctx.increase_memory(delta).await?
         } else {
-            Ok(true)
+            true
+        };
+        if allowed && current == 0 {
+            // The memory is about to be created, on this thread
+            WorkerMemories::expect_memory_of(self.durable_ctx.owned_worker_id());
         }
+        Ok(allowed)
     }
 
     async fn table_growing(
@@ -578,7 +598,7 @@ impl WorkerCtx for DebugContext {
         rpc: Arc<dyn Rpc>,
         worker_proxy: Arc<dyn WorkerProxy>,
         component_service: Arc<dyn ComponentService>,
-        _extra_deps: Self::ExtraDeps,
+        extra_deps: Self::ExtraDeps,
         config: Arc<GolemConfig>,
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
@@ -588,7 +608,16 @@ impl WorkerCtx for DebugContext {
         _resource_limits: Arc<dyn ResourceLimits>,
         project_service: Arc<dyn ProjectService>,
     ) -> Result<Self, WorkerExecutorError> {
-        let golem_ctx = DurableWorkerCtx::create(
+        let debug_session = extra_deps.debug_session();
+        let verify_replay = debug_session
+            .get(&DebugSessionId::new(owned_worker_id.clone()))
+            .await
+            .is_some_and(|session_data| session_data.verify_replay);
+
+        // A new instance of the worker is about to be created, with new linear memories
+        extra_deps.worker_memories().forget(&owned_worker_id);
+
+        let mut golem_ctx = DurableWorkerCtx::create(
             owned_worker_id,
             promise_service,
             worker_service,
@@ -613,8 +642,13 @@ impl WorkerCtx for DebugContext {
             project_service,
         )
         .await?;
+        if verify_replay {
+            golem_ctx.set_replay_verification(true);
+        }
+
         Ok(Self {
             durable_ctx: golem_ctx,
+            debug_session,
         })
     }
 
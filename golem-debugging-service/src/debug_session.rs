@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::breakpoints::BreakpointsInternal;
 use crate::from_value::{
     Bucket, BucketAndKey, BucketAndKeys, BucketKeyValue, BucketKeyValues, Container,
     ContainerAndObject, ContainerAndObjects, ContainerCopyObjectInfo, ContainerObjectBeginEnd,
@@ -45,6 +46,7 @@ use golem_worker_executor::durable_host::serialized::{
 use golem_worker_executor::durable_host::wasm_rpc::serialized::{
     SerializableInvokeRequest, SerializableInvokeResult,
 };
+use golem_worker_executor::model::replay_divergence::ReplayDivergence;
 use golem_worker_executor::services::blob_store::ObjectMetadata;
 use golem_worker_executor::services::rpc::RpcError;
 use serde::Serialize;
@@ -79,6 +81,25 @@ pub trait DebugSessions: Send + Sync {
         debug_session_id: DebugSessionId,
         oplog_index: OplogIndex,
     ) -> Option<DebugSessionData>;
+
+    async fn update_breakpoints(
+        &self,
+        debug_session_id: DebugSessionId,
+        breakpoints: BreakpointsInternal,
+    ) -> Option<DebugSessionData>;
+
+    // Enables or disables replay verification, forgetting any previously detected divergence
+    async fn update_replay_verification(
+        &self,
+        debug_session_id: DebugSessionId,
+        verify_replay: bool,
+    ) -> Option<DebugSessionData>;
+
+    async fn update_replay_divergence(
+        &self,
+        debug_session_id: DebugSessionId,
+        replay_divergence: ReplayDivergence,
+    ) -> Option<DebugSessionData>;
 }
 pub struct DebugSessionsDefault {
     pub session: Arc<Mutex<HashMap<DebugSessionId, DebugSessionData>>>,
@@ -147,6 +168,52 @@ impl DebugSessions for DebugSessionsDefault {
             None
         }
     }
+
+    async fn update_breakpoints(
+        &self,
+        debug_session_id: DebugSessionId,
+        breakpoints: BreakpointsInternal,
+    ) -> Option<DebugSessionData> {
+        let mut session = self.session.lock().unwrap();
+        let session_data = session.get_mut(&debug_session_id);
+        if let Some(session_data) = session_data {
+            session_data.breakpoints = breakpoints;
+            Some(session_data.clone())
+        } else {
+            None
+        }
+    }
+
+    async fn update_replay_verification(
+        &self,
+        debug_session_id: DebugSessionId,
+        verify_replay: bool,
+    ) -> Option<DebugSessionData> {
+        let mut session = self.session.lock().unwrap();
+        let session_data = session.get_mut(&debug_session_id);
+        if let Some(session_data) = session_data {
+            session_data.verify_replay = verify_replay;
+            session_data.replay_divergence = None;
+            Some(session_data.clone())
+        } else {
+            None
+        }
+    }
+
+    async fn update_replay_divergence(
+        &self,
+        debug_session_id: DebugSessionId,
+        replay_divergence: ReplayDivergence,
+    ) -> Option<DebugSessionData> {
+        let mut session = self.session.lock().unwrap();
+        let session_data = session.get_mut(&debug_session_id);
+        if let Some(session_data) = session_data {
+            session_data.replay_divergence = Some(replay_divergence);
+            Some(session_data.clone())
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -157,6 +224,11 @@ pub struct DebugSessionData {
     // The current status of the oplog index being replayed and possibly
     // index of newly added oplog entries as part of going live in between host functions
     pub current_oplog_index: OplogIndex,
+    pub breakpoints: BreakpointsInternal,
+    // If true, the worker's replay is checked against the requests recorded in the oplog
+    pub verify_replay: bool,
+    // The first divergence of the replay from the oplog detected while verifying
+    pub replay_divergence: Option<ReplayDivergence>,
 }
 
 #[derive(Debug, Clone)]
@@ -754,7 +826,7 @@ fn convert_request_value_and_type_to_oplog_payload(
 }
 
 #[allow(clippy::type_complexity)]
-fn convert_response_value_and_type_to_oplog_payload(
+pub(crate) fn convert_response_value_and_type_to_oplog_payload(
     function_name: &str,
     value_and_type: &ValueAndType,
 ) -> Result<OplogPayload, String> {
@@ -1232,7 +1304,7 @@ fn create_uuid_payload(value_and_type: &ValueAndType) -> Result<OplogPayload, St
     create_oplog_payload(&payload)
 }
 
-fn create_oplog_payload<T: Encode>(payload: &T) -> Result<OplogPayload, String> {
+pub(crate) fn create_oplog_payload<T: Encode>(payload: &T) -> Result<OplogPayload, String> {
     let serialized = golem_common::serialization::serialize(payload).map_err(|e| e.to_string())?;
     Ok(OplogPayload::Inline(serialized.to_vec()))
 }
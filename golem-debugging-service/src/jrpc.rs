@@ -18,6 +18,7 @@ use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
 use axum_jrpc::{Id, JsonRpcRequest, JsonRpcResponse};
 use futures::{SinkExt, StreamExt};
 use golem_common::model::auth::{AuthCtx, Namespace};
+use golem_common::model::public_oplog::diff::OplogDiff;
 use golem_common::model::OwnedWorkerId;
 use golem_worker_executor::services::worker_event::WorkerEventReceiver;
 use poem::web::websocket::{CloseCode, Message, WebSocketStream};
@@ -279,6 +280,7 @@ impl JrpcSession {
                             &active_session_data.namespace.account_id,
                             params.target_index,
                             params.overrides,
+                            params.override_rules,
                             params.ensure_invocation_boundary.unwrap_or(true),
                         )
                         .await;
@@ -290,6 +292,224 @@ impl JrpcSession {
                     Err(inactive_session_error(&jrpc_id))
                 }
             }
+            "step" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .step(&owned_worker_id, &active_session_data.namespace.account_id)
+                        .await;
+
+                    self.ensure_pending_notifications_are_emitted().await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "step_over_invocation" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .step_over_invocation(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                        )
+                        .await;
+
+                    self.ensure_pending_notifications_are_emitted().await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "reverse_continue" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .reverse_continue(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                        )
+                        .await;
+
+                    self.ensure_pending_notifications_are_emitted().await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "set_breakpoints" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: SetBreakpointsParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .set_breakpoints(&owned_worker_id, params.breakpoints)
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "resources" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .resources(&owned_worker_id, &active_session_data.namespace.account_id)
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "file_system_node" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: FileSystemParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .file_system_node(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                            params.path,
+                        )
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "read_file" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: FileSystemParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .read_file(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                            params.path,
+                        )
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "memory" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .memory(&owned_worker_id, &active_session_data.namespace.account_id)
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "read_memory" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: ReadMemoryParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .read_memory(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                            params.offset,
+                            params.length,
+                        )
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "call_getter" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: CallGetterParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .call_getter(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                            params.function_name,
+                            params.function_params,
+                        )
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "time_travel_query" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: TimeTravelQueryParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .time_travel_query(
+                            &owned_worker_id,
+                            &active_session_data.namespace.account_id,
+                            params.oplog_indices,
+                            params.function_name,
+                            params.function_params,
+                        )
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+            "oplog" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let params: OplogParams = parse_params(&jrpc_id, request.params)?;
+
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .oplog(&owned_worker_id, params.from_index, params.count)
+                        .await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
             "rewind" => {
                 if let Some(active_session_data) = &self.active_session {
                     let params: RewindParams = parse_params(&jrpc_id, request.params)?;
@@ -333,6 +553,66 @@ impl JrpcSession {
                 }
             }
 
+            "diff_oplog" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let params: DiffOplogParams = parse_params(&jrpc_id, request.params)?;
+                    let result = self
+                        .debug_service
+                        .diff_oplog(
+                            &owned_worker_id,
+                            &params.other_worker_id,
+                            params
+                                .limit
+                                .map(|limit| limit as usize)
+                                .unwrap_or(OplogDiff::DEFAULT_LIMIT),
+                        )
+                        .await;
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+
+            "export_trace" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self.debug_service.export_trace(&owned_worker_id).await;
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+
+            // Does not need a session, the imported worker can be connected to afterwards
+            "import_trace" => {
+                let params: ImportTraceParams = parse_params(&jrpc_id, request.params)?;
+                let result = self
+                    .debug_service
+                    .import_trace(&self.auth_ctx, &params.worker_id, &params.trace)
+                    .await;
+                to_json_rpc_result(&jrpc_id, result)
+            }
+
+            "verify_replay" => {
+                if let Some(active_session_data) = &self.active_session {
+                    let owned_worker_id = active_session_data.connected_worker.clone();
+
+                    let result = self
+                        .debug_service
+                        .verify_replay(&owned_worker_id, &active_session_data.namespace.account_id)
+                        .await;
+
+                    self.ensure_pending_notifications_are_emitted().await;
+
+                    to_json_rpc_result(&jrpc_id, result)
+                } else {
+                    Err(inactive_session_error(&jrpc_id))
+                }
+            }
+
             method => Err(method_not_found_error(&jrpc_id, method)),
         }
     }
@@ -15,20 +15,27 @@
 pub mod additional_deps;
 pub mod api;
 pub mod auth;
+pub mod breakpoints;
 pub mod config;
+pub mod dap;
 pub mod debug_context;
 pub mod debug_session;
 pub mod from_value;
 pub mod jrpc;
+pub mod memory;
 pub mod model;
 pub mod oplog;
+pub mod override_rules;
 pub mod services;
+#[cfg(test)]
+mod test_utils;
 
 use crate::additional_deps::AdditionalDeps;
 use crate::auth::{AuthService, GrpcAuthService};
 use crate::config::DebugConfig;
 use crate::debug_context::DebugContext;
 use crate::debug_session::{DebugSessions, DebugSessionsDefault};
+use crate::memory::WorkerMemories;
 use crate::oplog::debug_oplog_service::DebugOplogService;
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
@@ -41,7 +48,9 @@ use golem_worker_executor::services::blob_store::BlobStoreService;
 use golem_worker_executor::services::component::ComponentService;
 use golem_worker_executor::services::events::Events;
 use golem_worker_executor::services::file_loader::FileLoader;
-use golem_worker_executor::services::golem_config::GolemConfig;
+use golem_worker_executor::services::golem_config::{
+    CompiledComponentServiceConfig, CompiledComponentServiceDisabledConfig, GolemConfig,
+};
 use golem_worker_executor::services::key_value::KeyValueService;
 use golem_worker_executor::services::oplog::plugin::OplogProcessorPlugin;
 use golem_worker_executor::services::oplog::OplogService;
@@ -61,7 +70,9 @@ use golem_worker_executor::services::worker_fork::DefaultWorkerFork;
 use golem_worker_executor::services::worker_proxy::WorkerProxy;
 use golem_worker_executor::services::{rdbms, resource_limits, All};
 use golem_worker_executor::wasi_host::create_linker;
-use golem_worker_executor::{create_worker_executor_impl, Bootstrap, RunDetails};
+use golem_worker_executor::{
+    create_default_wasmtime_config, create_worker_executor_impl, Bootstrap, RunDetails,
+};
 use humansize::ISizeFormatter;
 use poem::endpoint::PrometheusExporter;
 use poem::listener::{Acceptor, Listener};
@@ -74,13 +85,23 @@ use tokio::runtime::Handle;
 use tokio::task::JoinSet;
 use tracing::{debug, info, Instrument};
 use wasmtime::component::Linker;
-use wasmtime::Engine;
+use wasmtime::{Config, Engine};
 
 #[cfg(test)]
 test_r::enable!();
 
 pub struct ServerBootstrap {
     pub debug_config: DebugConfig,
+    pub worker_memories: Arc<WorkerMemories>,
+}
+
+impl ServerBootstrap {
+    pub fn new(debug_config: DebugConfig) -> Self {
+        Self {
+            debug_config,
+            worker_memories: Arc::new(WorkerMemories::new()),
+        }
+    }
 }
 
 #[async_trait]
@@ -168,7 +189,12 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             Arc::clone(&debug_sessions),
         ));
 
-        let addition_deps = AdditionalDeps::new(auth_service, debug_sessions);
+        let addition_deps = AdditionalDeps::new(
+            auth_service,
+            debug_sessions,
+            oplog_service.clone(),
+            self.worker_memories.clone(),
+        );
 
         let resource_limits = resource_limits::configured(&golem_config.resource_limits);
 
@@ -273,6 +299,10 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
         ))
     }
 
+    fn create_wasmtime_config(&self) -> Config {
+        create_debug_wasmtime_config(self.worker_memories.clone())
+    }
+
     fn create_wasmtime_linker(&self, engine: &Engine) -> anyhow::Result<Linker<DebugContext>> {
         create_debug_wasmtime_linker(engine)
     }
@@ -298,7 +328,7 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
 
 pub async fn run_debug_worker_executor<T: Bootstrap<DebugContext> + ?Sized>(
     bootstrap: &T,
-    golem_config: GolemConfig,
+    mut golem_config: GolemConfig,
     cors_origin_regex: &str,
     prometheus_registry: Registry,
     runtime: Handle,
@@ -306,6 +336,11 @@ pub async fn run_debug_worker_executor<T: Bootstrap<DebugContext> + ?Sized>(
 ) -> anyhow::Result<RunDetails> {
     debug!("Initializing debug worker executor");
 
+    // Components precompiled by the regular executors cannot be loaded by the debugging engine
+    // (see create_debug_wasmtime_config), and the ones it compiles must not replace them
+    golem_config.compiled_component_service =
+        CompiledComponentServiceConfig::Disabled(CompiledComponentServiceDisabledConfig {});
+
     let total_system_memory = golem_config.memory.total_system_memory();
     let system_memory = golem_config.memory.system_memory();
     let worker_memory = golem_config.memory.worker_memory();
@@ -347,6 +382,20 @@ fn get_durable_ctx(ctx: &mut DebugContext) -> &mut DurableWorkerCtx<DebugContext
     &mut ctx.durable_ctx
 }
 
+// The linear memories of the workers are created by the debugging service, so they can be read
+// while a worker is paused. They are heap allocated, which rules out reserving address space,
+// guard pages and copy-on-write initialization.
+pub fn create_debug_wasmtime_config(worker_memories: Arc<WorkerMemories>) -> Config {
+    let mut config = create_default_wasmtime_config();
+
+    config.with_host_memory(worker_memories);
+    config.memory_reservation(0);
+    config.memory_guard_size(0);
+    config.memory_init_cow(false);
+
+    config
+}
+
 pub fn create_debug_wasmtime_linker(engine: &Engine) -> anyhow::Result<Linker<DebugContext>> {
     let mut linker = create_linker(engine, get_durable_ctx)?;
     golem_api_1_x::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
@@ -410,16 +459,14 @@ pub async fn run(
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!("Golem Debug Worker Executor starting up...");
     let mut join_set = JoinSet::new();
-    ServerBootstrap {
-        debug_config: debug_config.clone(),
-    }
-    .run(
-        debug_config.into_golem_config(),
-        prometheus_registry,
-        runtime,
-        &mut join_set,
-    )
-    .await?;
+    ServerBootstrap::new(debug_config.clone())
+        .run(
+            debug_config.into_golem_config(),
+            prometheus_registry,
+            runtime,
+            &mut join_set,
+        )
+        .await?;
 
     while let Some(res) = join_set.join_next().await {
         res??
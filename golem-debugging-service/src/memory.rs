@@ -0,0 +1,348 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_common::model::OwnedWorkerId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::mem;
+use std::sync::{Arc, Mutex, Weak};
+use wasmtime::{LinearMemory, MemoryCreator, MemoryType};
+
+thread_local! {
+    // The worker whose store is creating a linear memory on the current thread. Wasmtime asks the
+    // store's resource limiter about the initial size of a memory right before creating it,
+    // on the same thread, which is the only point where the worker is known.
+    static CREATING_WORKER: RefCell<Option<OwnedWorkerId>> = const { RefCell::new(None) };
+}
+
+/// Creates the linear memories of the played back workers, keeping track of them so their
+/// contents can be read while a worker is paused.
+///
+/// The memories are plain heap allocations, so the engine using them must not reserve address
+/// space or guard pages for linear memories, and must not initialize them with copy-on-write.
+#[derive(Default)]
+pub struct WorkerMemories {
+    memories: Mutex<HashMap<OwnedWorkerId, Vec<Weak<Mutex<MemoryStorage>>>>>,
+}
+
+impl WorkerMemories {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the next linear memory created on the current thread as belonging to the given worker
+    pub fn expect_memory_of(owned_worker_id: &OwnedWorkerId) {
+        CREATING_WORKER.set(Some(owned_worker_id.clone()));
+    }
+
+    /// Forgets the memories of a previous instance of the worker
+    pub fn forget(&self, owned_worker_id: &OwnedWorkerId) {
+        self.memories.lock().unwrap().remove(owned_worker_id);
+    }
+
+    /// Size of the main linear memory of the worker (the first one created when instantiating
+    /// its component), if the worker is instantiated
+    pub fn main_memory_size(&self, owned_worker_id: &OwnedWorkerId) -> Option<u64> {
+        self.main_memory(owned_worker_id)
+            .map(|memory| memory.lock().unwrap().byte_len as u64)
+    }
+
+    /// Reads a range of the main linear memory of the worker.
+    ///
+    /// The contents are only consistent while the worker is not running.
+    pub fn read(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        offset: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, ReadMemoryError> {
+        let memory = self
+            .main_memory(owned_worker_id)
+            .ok_or(ReadMemoryError::NotInstantiated)?;
+        let memory = memory.lock().unwrap();
+
+        let size = memory.byte_len as u64;
+        match offset.checked_add(length) {
+            Some(end) if end <= size => Ok(memory.bytes()[offset as usize..end as usize].to_vec()),
+            _ => Err(ReadMemoryError::OutOfBounds {
+                offset,
+                length,
+                size,
+            }),
+        }
+    }
+
+    fn main_memory(&self, owned_worker_id: &OwnedWorkerId) -> Option<Arc<Mutex<MemoryStorage>>> {
+        self.memories
+            .lock()
+            .unwrap()
+            .get(owned_worker_id)
+            .and_then(|memories| memories.iter().find_map(|memory| memory.upgrade()))
+    }
+
+    fn register(&self, owned_worker_id: OwnedWorkerId, memory: &Arc<Mutex<MemoryStorage>>) {
+        let mut memories = self.memories.lock().unwrap();
+        let worker_memories = memories.entry(owned_worker_id).or_default();
+        worker_memories.retain(|memory| memory.strong_count() > 0);
+        worker_memories.push(Arc::downgrade(memory));
+    }
+}
+
+unsafe impl MemoryCreator for WorkerMemories {
+    fn new_memory(
+        &self,
+        _ty: MemoryType,
+        minimum: usize,
+        _maximum: Option<usize>,
+        reserved_size_in_bytes: Option<usize>,
+        guard_size_in_bytes: usize,
+    ) -> Result<Box<dyn LinearMemory>, String> {
+        if guard_size_in_bytes > 0 || reserved_size_in_bytes.is_some_and(|size| size > 0) {
+            return Err(
+                "Linear memories of played back workers cannot have guard pages or reserved address space"
+                    .to_string(),
+            );
+        }
+
+        let storage = Arc::new(Mutex::new(MemoryStorage::new(minimum)?));
+        if let Some(owned_worker_id) = CREATING_WORKER.take() {
+            self.register(owned_worker_id, &storage);
+        }
+
+        Ok(Box::new(WorkerMemory { storage }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadMemoryError {
+    NotInstantiated,
+    OutOfBounds { offset: u64, length: u64, size: u64 },
+}
+
+impl Display for ReadMemoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadMemoryError::NotInstantiated => {
+                write!(f, "The linear memory of the worker is not available")
+            }
+            ReadMemoryError::OutOfBounds {
+                offset,
+                length,
+                size,
+            } => write!(
+                f,
+                "Reading {length} bytes at offset {offset} is out of the bounds of the linear memory of {size} bytes"
+            ),
+        }
+    }
+}
+
+// Wasm code expects the base of a linear memory to be aligned to 16 bytes
+#[repr(C, align(16))]
+#[derive(Copy, Clone)]
+struct Align16(u128);
+
+struct MemoryStorage {
+    storage: Vec<Align16>,
+    byte_len: usize,
+}
+
+impl MemoryStorage {
+    fn new(byte_len: usize) -> Result<Self, String> {
+        let mut storage = Self {
+            storage: Vec::new(),
+            byte_len: 0,
+        };
+        storage.grow_to(byte_len)?;
+        Ok(storage)
+    }
+
+    fn grow_to(&mut self, new_byte_len: usize) -> Result<(), String> {
+        let new_len = new_byte_len.div_ceil(mem::size_of::<Align16>());
+        if new_len > self.storage.len() {
+            self.storage
+                .try_reserve(new_len - self.storage.len())
+                .map_err(|err| err.to_string())?;
+            self.storage.resize(new_len, Align16(0));
+        }
+        self.byte_len = new_byte_len;
+        Ok(())
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // The storage is initialized and at least byte_len bytes long
+        unsafe { std::slice::from_raw_parts(self.storage.as_ptr().cast::<u8>(), self.byte_len) }
+    }
+}
+
+struct WorkerMemory {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+unsafe impl LinearMemory for WorkerMemory {
+    fn byte_size(&self) -> usize {
+        self.storage.lock().unwrap().byte_len
+    }
+
+    fn byte_capacity(&self) -> usize {
+        self.storage.lock().unwrap().storage.capacity() * mem::size_of::<Align16>()
+    }
+
+    fn grow_to(&mut self, new_size: usize) -> anyhow::Result<()> {
+        self.storage
+            .lock()
+            .unwrap()
+            .grow_to(new_size)
+            .map_err(anyhow::Error::msg)
+    }
+
+    fn as_ptr(&self) -> *mut u8 {
+        self.storage
+            .lock()
+            .unwrap()
+            .storage
+            .as_mut_ptr()
+            .cast::<u8>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::memory::{ReadMemoryError, WorkerMemories};
+    use golem_common::model::{ComponentId, OwnedWorkerId, ProjectId, WorkerId};
+    use wasmtime::{LinearMemory, MemoryCreator, MemoryType};
+
+    const PAGE_SIZE: usize = 65536;
+
+    fn owned_worker_id(worker_name: &str) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            &ProjectId::new_v4(),
+            &WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: worker_name.to_string(),
+            },
+        )
+    }
+
+    fn new_memory(memories: &WorkerMemories, pages: usize) -> Box<dyn LinearMemory> {
+        memories
+            .new_memory(
+                MemoryType::new(pages as u32, None),
+                pages * PAGE_SIZE,
+                None,
+                Some(0),
+                0,
+            )
+            .unwrap()
+    }
+
+    fn write(memory: &dyn LinearMemory, offset: usize, bytes: &[u8]) {
+        assert!(offset + bytes.len() <= memory.byte_size());
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), memory.as_ptr().add(offset), bytes.len())
+        }
+    }
+
+    #[test]
+    fn reads_the_contents_of_the_main_memory() {
+        let memories = WorkerMemories::new();
+        let worker = owned_worker_id("worker-1");
+
+        WorkerMemories::expect_memory_of(&worker);
+        let mut main_memory = new_memory(&memories, 1);
+        // Memories created afterwards without a worker being expected are not tracked
+        let other_memory = new_memory(&memories, 1);
+
+        write(main_memory.as_ref(), 100, b"hello");
+        write(other_memory.as_ref(), 100, b"world");
+
+        main_memory.grow_to(2 * PAGE_SIZE).unwrap();
+        write(main_memory.as_ref(), PAGE_SIZE + 10, b"grown");
+
+        assert_eq!(
+            memories.main_memory_size(&worker),
+            Some(2 * PAGE_SIZE as u64)
+        );
+        assert_eq!(memories.read(&worker, 100, 5).unwrap(), b"hello".to_vec());
+        assert_eq!(memories.read(&worker, 98, 4).unwrap(), b"\0\0he".to_vec());
+        assert_eq!(
+            memories.read(&worker, (PAGE_SIZE + 10) as u64, 5).unwrap(),
+            b"grown".to_vec()
+        );
+        assert_eq!(
+            memories.read(&worker, 2 * PAGE_SIZE as u64 - 2, 2).unwrap(),
+            vec![0, 0]
+        );
+    }
+
+    #[test]
+    fn rejects_reads_out_of_bounds() {
+        let memories = WorkerMemories::new();
+        let worker = owned_worker_id("worker-1");
+
+        WorkerMemories::expect_memory_of(&worker);
+        let _memory = new_memory(&memories, 1);
+
+        let size = PAGE_SIZE as u64;
+        assert_eq!(
+            memories.read(&worker, size - 1, 2),
+            Err(ReadMemoryError::OutOfBounds {
+                offset: size - 1,
+                length: 2,
+                size
+            })
+        );
+        assert_eq!(
+            memories.read(&worker, u64::MAX, 2),
+            Err(ReadMemoryError::OutOfBounds {
+                offset: u64::MAX,
+                length: 2,
+                size
+            })
+        );
+        assert_eq!(memories.read(&worker, size, 0).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn forgets_the_memories_of_previous_instances() {
+        let memories = WorkerMemories::new();
+        let worker = owned_worker_id("worker-1");
+        let other_worker = owned_worker_id("worker-2");
+
+        WorkerMemories::expect_memory_of(&worker);
+        let memory = new_memory(&memories, 1);
+
+        assert_eq!(
+            memories.read(&other_worker, 0, 1),
+            Err(ReadMemoryError::NotInstantiated)
+        );
+
+        memories.forget(&worker);
+        assert_eq!(
+            memories.read(&worker, 0, 1),
+            Err(ReadMemoryError::NotInstantiated)
+        );
+
+        WorkerMemories::expect_memory_of(&worker);
+        let _memory = new_memory(&memories, 2);
+        drop(memory);
+        assert_eq!(
+            memories.main_memory_size(&worker),
+            Some(2 * PAGE_SIZE as u64)
+        );
+    }
+}
@@ -13,9 +13,15 @@
 // limitations under the License.
 
 use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::diff::OplogDiff;
 use golem_common::model::public_oplog::PublicOplogEntry;
-use golem_common::model::{LogLevel, Timestamp, WorkerId};
+use golem_common::model::{
+    ComponentFilePath, ComponentFilePermissions, LogLevel, PromiseId, Timestamp, WorkerId,
+};
+use golem_service_base::model::PublicOplogEntryWithIndex;
+use golem_wasm_rpc::ValueAndType;
 use golem_worker_executor::model::event::InternalWorkerEvent;
+use golem_worker_executor::model::replay_divergence::ReplayDivergence;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -27,6 +33,7 @@ pub struct ConnectParams {
 pub struct PlaybackParams {
     pub target_index: OplogIndex,
     pub overrides: Option<Vec<PlaybackOverride>>,
+    pub override_rules: Option<Vec<PlaybackOverrideRule>>,
     pub ensure_invocation_boundary: Option<bool>,
 }
 
@@ -36,6 +43,86 @@ pub struct PlaybackOverride {
     pub oplog: PublicOplogEntry,
 }
 
+// A rule replacing the results of the matching imported function calls during the playback,
+// without having to construct the overriding oplog entries one by one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum PlaybackOverrideRule {
+    // Returns the given value from every call to the imported host function, matched by its full name
+    ReturnValue {
+        function_name: String,
+        value: ValueAndType,
+    },
+    // Makes the nth (counting from 1) outgoing HTTP request of the worker fail with a WASI HTTP
+    // error code, such as `connection-refused` or `connection-timeout`
+    FailHttpRequest {
+        nth: u64,
+        error_code: String,
+    },
+    // Makes the nth (counting from 1) outgoing HTTP request of the worker respond with the given status,
+    // keeping the headers of the original response
+    HttpResponseStatus {
+        nth: u64,
+        status: u16,
+    },
+    // Reports the promise as pending for the first `polls` polls that completed it,
+    // delaying its completion (polls that were pending anyway are not counted)
+    DelayPromise {
+        promise_id: PromiseId,
+        polls: u64,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetBreakpointsParams {
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+// A predicate on the oplog entries, stopping the playback right after
+// the first entry that matches it has been replayed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum Breakpoint {
+    // Matches a call to an imported host function, either by its full name
+    // (`http::types::future_incoming_response::get`) or by its interface (`wasi:http/outgoing-handler`)
+    ImportedFunction { function_name: String },
+    // Matches the start of an invocation of the given exported function, or of any of them if not set
+    ExportedFunction { function_name: Option<String> },
+    // Matches a log entry with a message matching the regular expression
+    LogMessage { pattern: String },
+    Error,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FileSystemParams {
+    pub path: ComponentFilePath,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReadMemoryParams {
+    pub offset: u64,
+    pub length: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CallGetterParams {
+    pub function_name: String,
+    pub function_params: Vec<ValueAndType>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TimeTravelQueryParams {
+    pub oplog_indices: Vec<OplogIndex>,
+    pub function_name: String,
+    pub function_params: Vec<ValueAndType>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OplogParams {
+    pub from_index: OplogIndex,
+    pub count: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RewindParams {
     pub target_index: OplogIndex,
@@ -48,6 +135,21 @@ pub struct ForkParams {
     pub oplog_index_cut_off: OplogIndex,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DiffOplogParams {
+    pub other_worker_id: WorkerId,
+    // The maximum number of differing entries to return, 100 by default
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportTraceParams {
+    pub worker_id: WorkerId,
+    // Base64 encoded trace, as returned by `export_trace`
+    pub trace: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ConnectResult {
     pub worker_id: WorkerId,
@@ -60,6 +162,112 @@ pub struct PlaybackResult {
     pub current_index: OplogIndex,
     pub message: String,
     pub incremental_playback: bool,
+    pub breakpoint: Option<Breakpoint>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetBreakpointsResult {
+    pub worker_id: WorkerId,
+    pub breakpoints: Vec<Breakpoint>,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResourcesResult {
+    pub worker_id: WorkerId,
+    pub current_index: OplogIndex,
+    pub resources: Vec<OpenResource>,
+}
+
+// A resource that has been created and not yet dropped at the current oplog index.
+// Only resources that have been described are indexed by a name and parameters.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OpenResource {
+    pub id: u64,
+    pub created_at: OplogIndex,
+    pub resource_name: Option<String>,
+    pub resource_params: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum FileSystemNodeResult {
+    Directory { entries: Vec<FileSystemNode> },
+    File { node: FileSystemNode },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FileSystemNode {
+    pub name: String,
+    pub last_modified: Timestamp,
+    pub is_directory: bool,
+    pub size: Option<u64>,
+    pub permissions: Option<ComponentFilePermissions>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FileContentsResult {
+    pub path: ComponentFilePath,
+    // Base64 encoded contents of the file
+    pub contents: String,
+}
+
+// The layout of the linear memories of a component is known from the oplog, as the initial
+// memory and each growth of it is a separate range. Only the main memory (the one of the main
+// module of the component) can be read
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MemoryResult {
+    pub worker_id: WorkerId,
+    pub current_index: OplogIndex,
+    pub total_linear_memory_size: u64,
+    pub main_memory_size: u64,
+    pub ranges: Vec<MemoryRange>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MemoryContentsResult {
+    pub worker_id: WorkerId,
+    pub current_index: OplogIndex,
+    pub offset: u64,
+    // Base64 encoded contents of the main memory
+    pub contents: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MemoryRange {
+    pub allocated_at: OplogIndex,
+    pub start: u64,
+    pub end: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CallGetterResult {
+    pub worker_id: WorkerId,
+    pub current_index: OplogIndex,
+    pub result: Option<ValueAndType>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TimeTravelQueryResult {
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    // One entry for each requested index, in the order they were requested
+    pub results: Vec<TimeTravelQueryEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TimeTravelQueryEntry {
+    pub oplog_index: OplogIndex,
+    pub result: Option<ValueAndType>,
+    // Set if the function could not be evaluated at this index
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OplogResult {
+    pub worker_id: WorkerId,
+    pub entries: Vec<PublicOplogEntryWithIndex>,
+    pub last_index: OplogIndex,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -76,6 +284,35 @@ pub struct ForkResult {
     pub message: String,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DiffOplogResult {
+    pub worker_id: WorkerId,
+    pub other_worker_id: WorkerId,
+    pub diff: OplogDiff,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportTraceResult {
+    pub worker_id: WorkerId,
+    // Base64 encoded, self-contained recording of the worker that can be imported by `import_trace`
+    pub trace: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportTraceResult {
+    pub worker_id: WorkerId,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyReplayResult {
+    pub worker_id: WorkerId,
+    pub current_index: OplogIndex,
+    // The first point where the replay did not match the oplog, if any
+    pub divergence: Option<ReplayDivergence>,
+    pub message: String,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum LogNotification {
@@ -0,0 +1,579 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::breakpoints::{
+    imported_function_name_patterns, normalize_imported_function_name, OUTGOING_HTTP_RESPONSE,
+};
+use crate::debug_session::{
+    convert_response_value_and_type_to_oplog_payload, create_oplog_payload,
+};
+use crate::model::params::PlaybackOverrideRule;
+use bincode::Decode;
+use golem_common::model::oplog::{DurableFunctionType, OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::PromiseId;
+use golem_common::serialization::try_deserialize;
+use golem_worker_executor::durable_host::http::serialized::{
+    SerializableErrorCode, SerializableResponse, SerializableResponseHeaders,
+};
+use golem_worker_executor::durable_host::serialized::SerializableError;
+use golem_worker_executor::services::oplog::Oplog;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+const POLL_PROMISE: &str = "golem::api::poll_promise";
+
+// The serialized result of polling a promise, `none` while it is pending
+type PollPromiseResponse = Result<Option<Vec<u8>>, SerializableError>;
+
+#[derive(Debug, Clone)]
+pub struct PlaybackOverrideRulesInternal {
+    pub rules: Vec<PlaybackOverrideRule>,
+}
+
+impl PlaybackOverrideRulesInternal {
+    pub fn from_rules(rules: Vec<PlaybackOverrideRule>) -> Result<Self, String> {
+        for rule in &rules {
+            validate_rule(rule)?;
+        }
+
+        Ok(Self { rules })
+    }
+
+    // Generates the overrides of the imported function calls after `after_index`, up to and including
+    // `until_index`, that are matched by the rules. If more rules match the same call, the first one wins.
+    pub async fn expand(
+        &self,
+        oplog: Arc<dyn Oplog>,
+        after_index: OplogIndex,
+        until_index: OplogIndex,
+    ) -> Result<HashMap<OplogIndex, OplogEntry>, String> {
+        // Outgoing HTTP requests are counted from the beginning of the oplog
+        let calls = imported_function_calls(&oplog, until_index).await;
+
+        let mut overrides = HashMap::new();
+
+        for rule in &self.rules {
+            let rule_overrides = match rule {
+                PlaybackOverrideRule::ReturnValue {
+                    function_name,
+                    value,
+                } => {
+                    let expected = imported_function_name_patterns(function_name);
+                    let mut rule_overrides = Vec::new();
+
+                    for (index, entry, name) in &calls {
+                        if *index > after_index
+                            && expected.contains(&normalize_imported_function_name(name))
+                        {
+                            let response =
+                                convert_response_value_and_type_to_oplog_payload(name, value)?;
+                            rule_overrides.push((*index, with_response(entry, response)));
+                        }
+                    }
+
+                    rule_overrides
+                }
+                PlaybackOverrideRule::FailHttpRequest { nth, error_code } => {
+                    let (index, entry, _) =
+                        outgoing_http_response(&oplog, &calls, *nth, after_index, until_index)
+                            .await?;
+                    let response = SerializableResponse::HttpError(http_error_code(error_code)?);
+
+                    vec![(
+                        index,
+                        with_response(&entry, create_oplog_payload(&response)?),
+                    )]
+                }
+                PlaybackOverrideRule::HttpResponseStatus { nth, status } => {
+                    let (index, entry, original) =
+                        outgoing_http_response(&oplog, &calls, *nth, after_index, until_index)
+                            .await?;
+                    let headers = match original {
+                        SerializableResponse::HeadersReceived(original) => original.headers,
+                        _ => HashMap::new(),
+                    };
+                    let response =
+                        SerializableResponse::HeadersReceived(SerializableResponseHeaders {
+                            status: *status,
+                            headers,
+                        });
+
+                    vec![(
+                        index,
+                        with_response(&entry, create_oplog_payload(&response)?),
+                    )]
+                }
+                PlaybackOverrideRule::DelayPromise { promise_id, polls } => {
+                    let pending: PollPromiseResponse = Ok(None);
+                    let response = create_oplog_payload(&pending)?;
+                    let mut rule_overrides = Vec::new();
+                    let mut count = 0;
+
+                    for (index, entry, name) in &calls {
+                        if name != POLL_PROMISE || count >= *polls {
+                            continue;
+                        }
+
+                        let OplogEntry::ImportedFunctionInvoked {
+                            request,
+                            response: original,
+                            ..
+                        } = entry
+                        else {
+                            continue;
+                        };
+
+                        // Polls that were pending anyway are neither counted nor rewritten
+                        if decode_payload::<PromiseId>(&oplog, request).await? == *promise_id
+                            && matches!(
+                                decode_payload::<PollPromiseResponse>(&oplog, original).await?,
+                                Ok(Some(_))
+                            )
+                        {
+                            count += 1;
+
+                            if *index > after_index {
+                                rule_overrides
+                                    .push((*index, with_response(entry, response.clone())));
+                            }
+                        }
+                    }
+
+                    rule_overrides
+                }
+            };
+
+            for (index, entry) in rule_overrides {
+                overrides.entry(index).or_insert(entry);
+            }
+        }
+
+        Ok(overrides)
+    }
+}
+
+fn validate_rule(rule: &PlaybackOverrideRule) -> Result<(), String> {
+    match rule {
+        PlaybackOverrideRule::ReturnValue { function_name, .. } => {
+            if function_name.is_empty() {
+                Err("The function name of a return value override cannot be empty".to_string())
+            } else {
+                Ok(())
+            }
+        }
+        PlaybackOverrideRule::FailHttpRequest { nth, error_code } => {
+            validate_nth(*nth)?;
+            http_error_code(error_code).map(|_| ())
+        }
+        PlaybackOverrideRule::HttpResponseStatus { nth, status } => {
+            validate_nth(*nth)?;
+            if (100..=599).contains(status) {
+                Ok(())
+            } else {
+                Err(format!("Invalid HTTP response status {status}"))
+            }
+        }
+        PlaybackOverrideRule::DelayPromise { polls, .. } => {
+            if *polls == 0 {
+                Err("The number of delayed polls of a promise must be positive".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+fn validate_nth(nth: u64) -> Result<(), String> {
+    if nth == 0 {
+        Err("Outgoing HTTP requests are counted from 1".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+// The error codes of WASI HTTP that do not carry any additional information
+fn http_error_code(name: &str) -> Result<SerializableErrorCode, String> {
+    match name {
+        "dns-timeout" => Ok(SerializableErrorCode::DnsTimeout),
+        "destination-not-found" => Ok(SerializableErrorCode::DestinationNotFound),
+        "destination-unavailable" => Ok(SerializableErrorCode::DestinationUnavailable),
+        "destination-ip-prohibited" => Ok(SerializableErrorCode::DestinationIpProhibited),
+        "destination-ip-unroutable" => Ok(SerializableErrorCode::DestinationIpUnroutable),
+        "connection-refused" => Ok(SerializableErrorCode::ConnectionRefused),
+        "connection-terminated" => Ok(SerializableErrorCode::ConnectionTerminated),
+        "connection-timeout" => Ok(SerializableErrorCode::ConnectionTimeout),
+        "connection-read-timeout" => Ok(SerializableErrorCode::ConnectionReadTimeout),
+        "connection-write-timeout" => Ok(SerializableErrorCode::ConnectionWriteTimeout),
+        "connection-limit-reached" => Ok(SerializableErrorCode::ConnectionLimitReached),
+        "tls-protocol-error" => Ok(SerializableErrorCode::TlsProtocolError),
+        "tls-certificate-error" => Ok(SerializableErrorCode::TlsCertificateError),
+        "http-request-denied" => Ok(SerializableErrorCode::HttpRequestDenied),
+        "http-request-length-required" => Ok(SerializableErrorCode::HttpRequestLengthRequired),
+        "http-request-method-invalid" => Ok(SerializableErrorCode::HttpRequestMethodInvalid),
+        "http-request-uri-invalid" => Ok(SerializableErrorCode::HttpRequestUriInvalid),
+        "http-request-uri-too-long" => Ok(SerializableErrorCode::HttpRequestUriTooLong),
+        "http-response-incomplete" => Ok(SerializableErrorCode::HttpResponseIncomplete),
+        "http-response-timeout" => Ok(SerializableErrorCode::HttpResponseTimeout),
+        "http-upgrade-failed" => Ok(SerializableErrorCode::HttpUpgradeFailed),
+        "http-protocol-error" => Ok(SerializableErrorCode::HttpProtocolError),
+        "loop-detected" => Ok(SerializableErrorCode::LoopDetected),
+        "configuration-error" => Ok(SerializableErrorCode::ConfigurationError),
+        "internal-error" => Ok(SerializableErrorCode::InternalError(None)),
+        _ => Err(format!("Unsupported HTTP error code {name}")),
+    }
+}
+
+async fn imported_function_calls(
+    oplog: &Arc<dyn Oplog>,
+    until_index: OplogIndex,
+) -> Vec<(OplogIndex, OplogEntry, String)> {
+    let mut calls = Vec::new();
+    let mut index = OplogIndex::INITIAL;
+
+    while index <= until_index {
+        let entry = oplog.read(index).await;
+
+        if let OplogEntry::ImportedFunctionInvoked { function_name, .. } = &entry {
+            let function_name = function_name.clone();
+            calls.push((index, entry, function_name));
+        }
+
+        index = index.next();
+    }
+
+    calls
+}
+
+// Finds the entry which completed the nth outgoing HTTP request. Polling a request produces an entry
+// each time, all of them pointing to the index where the request has been started.
+async fn outgoing_http_response(
+    oplog: &Arc<dyn Oplog>,
+    calls: &[(OplogIndex, OplogEntry, String)],
+    nth: u64,
+    after_index: OplogIndex,
+    until_index: OplogIndex,
+) -> Result<(OplogIndex, OplogEntry, SerializableResponse), String> {
+    let mut requests: BTreeMap<OplogIndex, Option<(OplogIndex, OplogEntry, SerializableResponse)>> =
+        BTreeMap::new();
+
+    for (index, entry, name) in calls {
+        let OplogEntry::ImportedFunctionInvoked {
+            response,
+            durable_function_type,
+            ..
+        } = entry
+        else {
+            continue;
+        };
+
+        if name != OUTGOING_HTTP_RESPONSE {
+            continue;
+        }
+
+        let begin_index = match durable_function_type {
+            DurableFunctionType::WriteRemoteBatched(Some(begin_index)) => *begin_index,
+            _ => *index,
+        };
+
+        let completion = requests.entry(begin_index).or_default();
+        if completion.is_none() {
+            let response = decode_payload::<SerializableResponse>(oplog, response).await?;
+            if !matches!(response, SerializableResponse::Pending) {
+                *completion = Some((*index, entry.clone(), response));
+            }
+        }
+    }
+
+    match requests.into_values().nth(nth as usize - 1) {
+        Some(Some((index, _, _))) if index <= after_index => Err(format!(
+            "The response of outgoing HTTP request #{nth} at oplog index {index} has already been replayed"
+        )),
+        Some(Some(completion)) => Ok(completion),
+        Some(None) => Err(format!(
+            "Outgoing HTTP request #{nth} has not been completed up to oplog index {until_index}"
+        )),
+        None => Err(format!(
+            "Outgoing HTTP request #{nth} was not found up to oplog index {until_index}"
+        )),
+    }
+}
+
+async fn decode_payload<T: Decode<()>>(
+    oplog: &Arc<dyn Oplog>,
+    payload: &OplogPayload,
+) -> Result<T, String> {
+    let bytes = oplog.download_payload(payload).await?;
+    try_deserialize(&bytes)?.ok_or("Unexpected empty oplog payload".to_string())
+}
+
+fn with_response(entry: &OplogEntry, response: OplogPayload) -> OplogEntry {
+    match entry {
+        OplogEntry::ImportedFunctionInvoked {
+            timestamp,
+            function_name,
+            request,
+            durable_function_type,
+            ..
+        } => OplogEntry::ImportedFunctionInvoked {
+            timestamp: *timestamp,
+            function_name: function_name.clone(),
+            request: request.clone(),
+            response,
+            durable_function_type: durable_function_type.clone(),
+        },
+        _ => entry.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::EntriesOplog;
+    use golem_common::model::{ComponentId, Timestamp, WorkerId};
+    use golem_wasm_rpc::IntoValueAndType;
+    use test_r::test;
+
+    #[test]
+    async fn http_rules_override_the_completion_of_the_request() {
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            http_response(1, SerializableResponse::Pending),
+            http_response(1, ok_response(200)),
+            http_response(4, ok_response(200)),
+        ]));
+
+        let rules = PlaybackOverrideRulesInternal::from_rules(vec![
+            PlaybackOverrideRule::HttpResponseStatus {
+                nth: 1,
+                status: 503,
+            },
+            PlaybackOverrideRule::FailHttpRequest {
+                nth: 2,
+                error_code: "connection-refused".to_string(),
+            },
+        ])
+        .unwrap();
+
+        let overrides = rules
+            .expand(oplog, OplogIndex::INITIAL, OplogIndex::from_u64(4))
+            .await
+            .unwrap();
+
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(
+            response_of(&overrides[&OplogIndex::from_u64(3)]),
+            SerializableResponse::HeadersReceived(SerializableResponseHeaders {
+                status: 503,
+                headers: HashMap::from([("x-id".to_string(), b"1".to_vec())]),
+            })
+        );
+        assert_eq!(
+            response_of(&overrides[&OplogIndex::from_u64(4)]),
+            SerializableResponse::HttpError(SerializableErrorCode::ConnectionRefused)
+        );
+    }
+
+    #[test]
+    async fn http_rules_cannot_override_replayed_or_missing_requests() {
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            http_response(1, ok_response(200)),
+        ]));
+
+        let replayed = PlaybackOverrideRulesInternal::from_rules(vec![
+            PlaybackOverrideRule::HttpResponseStatus {
+                nth: 1,
+                status: 503,
+            },
+        ])
+        .unwrap()
+        .expand(
+            oplog.clone(),
+            OplogIndex::from_u64(2),
+            OplogIndex::from_u64(2),
+        )
+        .await;
+
+        let missing = PlaybackOverrideRulesInternal::from_rules(vec![
+            PlaybackOverrideRule::HttpResponseStatus {
+                nth: 2,
+                status: 503,
+            },
+        ])
+        .unwrap()
+        .expand(oplog, OplogIndex::INITIAL, OplogIndex::from_u64(2))
+        .await;
+
+        assert!(replayed.is_err());
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    async fn delay_promise_keeps_the_first_completed_polls_pending() {
+        let promise_id = PromiseId {
+            worker_id: WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "worker".to_string(),
+            },
+            oplog_idx: OplogIndex::from_u64(1),
+        };
+        let other_promise_id = PromiseId {
+            oplog_idx: OplogIndex::from_u64(2),
+            ..promise_id.clone()
+        };
+        let pending: PollPromiseResponse = Ok(None);
+        let completed: PollPromiseResponse = Ok(Some(vec![1, 2, 3]));
+
+        // The promise is polled until it completes, and then once more by a later invocation
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            poll_promise(&promise_id, &pending),
+            poll_promise(&other_promise_id, &completed),
+            poll_promise(&promise_id, &pending),
+            poll_promise(&promise_id, &completed),
+            poll_promise(&promise_id, &completed),
+            poll_promise(&promise_id, &completed),
+        ]));
+
+        let overrides =
+            PlaybackOverrideRulesInternal::from_rules(vec![PlaybackOverrideRule::DelayPromise {
+                promise_id: promise_id.clone(),
+                polls: 2,
+            }])
+            .unwrap()
+            .expand(oplog, OplogIndex::INITIAL, OplogIndex::from_u64(7))
+            .await
+            .unwrap();
+
+        let mut indices = overrides.keys().copied().collect::<Vec<_>>();
+        indices.sort();
+
+        assert_eq!(
+            indices,
+            vec![OplogIndex::from_u64(5), OplogIndex::from_u64(6)]
+        );
+        for index in indices {
+            assert_eq!(
+                response_of::<PollPromiseResponse>(&overrides[&index]),
+                Ok(None)
+            );
+        }
+    }
+
+    #[test]
+    async fn return_value_rule_matches_the_wit_name_of_the_function() {
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            imported_function_invoked("golem::api::update-worker"),
+            imported_function_invoked("golem::api::update-worker-later"),
+        ]));
+
+        let rules =
+            PlaybackOverrideRulesInternal::from_rules(vec![PlaybackOverrideRule::ReturnValue {
+                function_name: "golem:api/update-worker".to_string(),
+                value: Ok::<(), String>(()).into_value_and_type(),
+            }])
+            .unwrap();
+
+        let overrides = rules
+            .expand(oplog, OplogIndex::INITIAL, OplogIndex::from_u64(3))
+            .await
+            .unwrap();
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(
+            response_of::<Result<(), SerializableError>>(&overrides[&OplogIndex::from_u64(2)]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn invalid_rules() {
+        assert!(PlaybackOverrideRulesInternal::from_rules(vec![
+            PlaybackOverrideRule::FailHttpRequest {
+                nth: 1,
+                error_code: "server-on-fire".to_string(),
+            }
+        ])
+        .is_err());
+        assert!(PlaybackOverrideRulesInternal::from_rules(vec![
+            PlaybackOverrideRule::HttpResponseStatus {
+                nth: 0,
+                status: 503
+            }
+        ])
+        .is_err());
+        assert!(PlaybackOverrideRulesInternal::from_rules(vec![
+            PlaybackOverrideRule::HttpResponseStatus { nth: 1, status: 42 }
+        ])
+        .is_err());
+    }
+
+    fn ok_response(status: u16) -> SerializableResponse {
+        SerializableResponse::HeadersReceived(SerializableResponseHeaders {
+            status,
+            headers: HashMap::from([("x-id".to_string(), b"1".to_vec())]),
+        })
+    }
+
+    fn http_response(begin_index: u64, response: SerializableResponse) -> OplogEntry {
+        OplogEntry::ImportedFunctionInvoked {
+            timestamp: Timestamp::now_utc(),
+            function_name: OUTGOING_HTTP_RESPONSE.to_string(),
+            request: OplogPayload::Inline(vec![]),
+            response: create_oplog_payload(&response).unwrap(),
+            durable_function_type: DurableFunctionType::WriteRemoteBatched(Some(
+                OplogIndex::from_u64(begin_index),
+            )),
+        }
+    }
+
+    fn imported_function_invoked(function_name: &str) -> OplogEntry {
+        OplogEntry::ImportedFunctionInvoked {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: OplogPayload::Inline(vec![]),
+            response: OplogPayload::Inline(vec![]),
+            durable_function_type: DurableFunctionType::WriteRemote,
+        }
+    }
+
+    fn poll_promise(promise_id: &PromiseId, response: &PollPromiseResponse) -> OplogEntry {
+        OplogEntry::ImportedFunctionInvoked {
+            timestamp: Timestamp::now_utc(),
+            function_name: POLL_PROMISE.to_string(),
+            request: create_oplog_payload(promise_id).unwrap(),
+            response: create_oplog_payload(response).unwrap(),
+            durable_function_type: DurableFunctionType::ReadRemote,
+        }
+    }
+
+    fn response_of<T: Decode<()>>(entry: &OplogEntry) -> T {
+        match entry {
+            OplogEntry::ImportedFunctionInvoked {
+                response: OplogPayload::Inline(bytes),
+                ..
+            } => try_deserialize(bytes).unwrap().unwrap(),
+            _ => panic!("Unexpected override {entry:?}"),
+        }
+    }
+}
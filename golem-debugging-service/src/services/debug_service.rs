@@ -13,31 +13,58 @@
 // limitations under the License.
 
 use crate::auth::AuthService;
+use crate::breakpoints::BreakpointsInternal;
 use crate::debug_context::DebugContext;
 use crate::debug_session::PlaybackOverridesInternal;
 use crate::debug_session::{DebugSessionData, DebugSessionId, DebugSessions};
+use crate::memory::ReadMemoryError;
 use crate::model::params::*;
+use crate::override_rules::PlaybackOverrideRulesInternal;
 use async_trait::async_trait;
 use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
+use base64::Engine;
+use futures::StreamExt;
 use gethostname::gethostname;
 use golem_common::base_model::ProjectId;
 use golem_common::model::auth::ProjectAction;
 use golem_common::model::auth::{AuthCtx, Namespace};
+use golem_common::model::invocation_context::InvocationContextStack;
 use golem_common::model::oplog::{OplogEntry, OplogIndex};
-use golem_common::model::{AccountId, OwnedWorkerId, WorkerId, WorkerMetadata};
-use golem_service_base::error::worker_executor::InterruptKind;
-use golem_worker_executor::services::oplog::Oplog;
+use golem_common::model::public_oplog::diff::OplogDiff;
+use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::{
+    AccountId, ComponentFilePath, ComponentFileSystemNode, ComponentFileSystemNodeDetails,
+    GetFileSystemNodeResult, OwnedWorkerId, Timestamp, WorkerId, WorkerMetadata,
+};
+use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
+use golem_service_base::model::PublicOplogEntryWithIndex;
+use golem_wasm_rpc::{ValueAndType, WitValue};
+use golem_worker_executor::model::public_oplog::{
+    find_component_version_at, get_public_oplog_chunk,
+};
+use golem_worker_executor::model::worker_trace::{
+    export_worker_trace, import_worker_trace, WorkerTrace,
+};
+use golem_worker_executor::model::ReadFileResult;
+use golem_worker_executor::services::oplog::{Oplog, OplogService};
 use golem_worker_executor::services::worker_event::WorkerEventReceiver;
 use golem_worker_executor::services::{
-    All, HasConfig, HasExtraDeps, HasOplog, HasShardManagerService, HasShardService,
-    HasWorkerForkService, HasWorkerService,
+    All, HasComponentService, HasConfig, HasExtraDeps, HasFileLoader, HasOplog, HasOplogService,
+    HasPlugins, HasProjectService, HasShardManagerService, HasShardService, HasWorkerForkService,
+    HasWorkerProxy, HasWorkerService,
 };
 use golem_worker_executor::worker::Worker;
 use log::debug;
 use serde_json::Value;
+use std::cmp::min;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::sync::Arc;
-use tracing::{error, info};
+use std::time::{Duration, UNIX_EPOCH};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+const SCRATCH_WORKER_DELETE_ATTEMPTS: usize = 3;
 
 #[async_trait]
 pub trait DebugService: Send + Sync {
@@ -53,9 +80,92 @@ pub trait DebugService: Send + Sync {
         account_id: &AccountId,
         target_index: OplogIndex,
         overrides: Option<Vec<PlaybackOverride>>,
+        override_rules: Option<Vec<PlaybackOverrideRule>>,
         ensure_invocation_boundary: bool,
     ) -> Result<PlaybackResult, DebugServiceError>;
 
+    // Replays the next oplog entry
+    async fn step(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<PlaybackResult, DebugServiceError>;
+
+    // Replays until the end of the current (or the next) invocation, unless a breakpoint is hit before
+    async fn step_over_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<PlaybackResult, DebugServiceError>;
+
+    // Rewinds to the last entry before the current one hitting a breakpoint, or to the beginning of the oplog
+    async fn reverse_continue(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<PlaybackResult, DebugServiceError>;
+
+    // Replaces the breakpoints of the session, which apply to all subsequent playbacks
+    async fn set_breakpoints(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        breakpoints: Vec<Breakpoint>,
+    ) -> Result<SetBreakpointsResult, DebugServiceError>;
+
+    // Resources that are open at the current oplog index of a played back worker
+    async fn resources(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<ResourcesResult, DebugServiceError>;
+
+    async fn file_system_node(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        path: ComponentFilePath,
+    ) -> Result<FileSystemNodeResult, DebugServiceError>;
+
+    async fn read_file(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        path: ComponentFilePath,
+    ) -> Result<FileContentsResult, DebugServiceError>;
+
+    async fn memory(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<MemoryResult, DebugServiceError>;
+
+    // Reads a range of the main linear memory of a played back worker
+    async fn read_memory(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        offset: u64,
+        length: u64,
+    ) -> Result<MemoryContentsResult, DebugServiceError>;
+
+    // Calls an exported function on a scratch copy of the worker forked at the current oplog index,
+    // leaving both the debugged worker and the original one untouched
+    async fn call_getter(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        function_name: String,
+        function_params: Vec<ValueAndType>,
+    ) -> Result<CallGetterResult, DebugServiceError>;
+
+    // Entries of the original oplog of the debugged worker, in their public (decoded) form
+    async fn oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        from_index: OplogIndex,
+        count: u64,
+    ) -> Result<OplogResult, DebugServiceError>;
+
     async fn rewind(
         &self,
         owned_worker_id: &OwnedWorkerId,
@@ -72,6 +182,53 @@ pub trait DebugService: Send + Sync {
         oplog_index_cut_off: OplogIndex,
     ) -> Result<ForkResult, DebugServiceError>;
 
+    // Compares the original oplog of the debugged worker with the oplog of another worker of the
+    // same project, such as one forked from it, returning at most `limit` differing entries
+    async fn diff_oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        other_worker_id: &WorkerId,
+        limit: usize,
+    ) -> Result<DiffOplogResult, DebugServiceError>;
+
+    // Exports the original oplog of the debugged worker together with everything needed to replay it
+    async fn export_trace(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<ExportTraceResult, DebugServiceError>;
+
+    // Creates a new worker from an exported trace, which can then be connected to and played back
+    async fn import_trace(
+        &self,
+        auth_ctx: &AuthCtx,
+        worker_id: &WorkerId,
+        trace: &str,
+    ) -> Result<ImportTraceResult, DebugServiceError>;
+
+    // Replays the debugged worker from the beginning to the end of its oplog, checking that every
+    // host function call of the guest matches the recorded one by name and request. Playback
+    // overrides of the session are discarded, and the session stays at the index where the
+    // replay stopped.
+    async fn verify_replay(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<VerifyReplayResult, DebugServiceError>;
+
+    // Evaluates an exported function on temporary copies of the worker forked at each of the given
+    // indices of its original oplog, for example to see how a read-only export's result changed
+    // over time. The remote writes of the copies are blocked, so exports with side effects fail.
+    // Does not require the debugged worker to be played back, and failures of the individual
+    // invocations are reported per index.
+    async fn time_travel_query(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        oplog_indices: Vec<OplogIndex>,
+        function_name: String,
+        function_params: Vec<ValueAndType>,
+    ) -> Result<TimeTravelQueryResult, DebugServiceError>;
+
     async fn current_oplog_index(
         &self,
         worker_id: &OwnedWorkerId,
@@ -168,6 +325,7 @@ impl DebugServiceError {
 pub struct DebugServiceDefault {
     worker_auth_service: Arc<dyn AuthService>,
     debug_session: Arc<dyn DebugSessions>,
+    original_oplog_service: Arc<dyn OplogService>,
     all: All<DebugContext>,
 }
 
@@ -176,10 +334,12 @@ impl DebugServiceDefault {
         let extra_deps = all.extra_deps();
         let debug_session = extra_deps.debug_session();
         let worker_auth_service = extra_deps.auth_service();
+        let original_oplog_service = extra_deps.original_oplog_service();
 
         Self {
             worker_auth_service,
             debug_session,
+            original_oplog_service,
             all,
         }
     }
@@ -256,169 +416,26 @@ impl DebugServiceDefault {
         }
     }
 
-    pub async fn validate_playback_overrides(
-        worker_id: WorkerId,
-        current_index: OplogIndex,
-        overrides: Vec<PlaybackOverride>,
-    ) -> Result<PlaybackOverridesInternal, DebugServiceError> {
-        PlaybackOverridesInternal::from_playback_override(overrides, current_index).map_err(|err| {
-            DebugServiceError::ValidationFailed {
-                worker_id: Some(worker_id.clone()),
-                errors: vec![err],
-            }
-        })
-    }
-
-    pub async fn target_index_at_invocation_boundary(
-        worker_id: &WorkerId,
-        worker: &Arc<Worker<DebugContext>>,
-        target_oplog_index: OplogIndex,
-    ) -> Result<OplogIndex, DebugServiceError> {
-        // New target index to be calculated here
-        let oplog: Arc<dyn Oplog> = worker.oplog();
-
-        let original_current_oplog_index = oplog.current_oplog_index().await;
-
-        Self::get_target_oplog_index_at_invocation_boundary(
-            oplog,
-            target_oplog_index,
-            original_current_oplog_index,
-        )
-        .await
-        .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))
-    }
-
-    pub async fn get_target_oplog_index_at_invocation_boundary(
-        oplog: Arc<dyn Oplog>,
-        target_oplog_index: OplogIndex,
-        original_last_oplog_index: OplogIndex,
-    ) -> Result<OplogIndex, String> {
-        let mut new_target_oplog_index = target_oplog_index;
-
-        loop {
-            let entry = oplog.read(new_target_oplog_index).await;
-
-            match entry {
-                OplogEntry::ExportedFunctionCompleted { .. } => {
-                    return Ok(new_target_oplog_index);
-                }
-                _ => {
-                    if new_target_oplog_index == original_last_oplog_index {
-                        let error_message = format!(
-                            "Invocation boundary not found. Set an oplog index that is not in the middle of an incomplete invocation. \
-                        Last oplog index: {original_last_oplog_index}"
-                        );
-                        error!("{}", error_message);
-                        return Err(error_message);
-                    }
-
-                    new_target_oplog_index = new_target_oplog_index.next();
-                }
-            }
-        }
-    }
-}
-
-#[async_trait]
-impl DebugService for DebugServiceDefault {
-    async fn connect(
+    async fn get_session_data(
         &self,
-        auth_ctx: &AuthCtx,
-        worker_id: &WorkerId,
-    ) -> Result<(ConnectResult, OwnedWorkerId, Namespace, WorkerEventReceiver), DebugServiceError>
-    {
-        let namespace = self
-            .worker_auth_service
-            .is_authorized_by_component(
-                &worker_id.component_id,
-                ProjectAction::UpdateWorker,
-                auth_ctx,
-            )
-            .await
-            .map_err(|e| DebugServiceError::unauthorized(format!("Unauthorized: {e}")))?;
-
-        let owned_worker_id = OwnedWorkerId::new(&namespace.project_id, worker_id);
-
-        let debug_session_id = DebugSessionId::new(owned_worker_id.clone());
-
-        if self.debug_session.get(&debug_session_id).await.is_some() {
-            return Err(DebugServiceError::conflict(
-                worker_id.clone(),
-                "Worker is already being debugged".to_string(),
-            ));
-        }
-
-        // This simply migrates the worker to the debug mode, but it doesn't start the worker
-        let (worker_metadata, worker_event_receiver) = self
-            .connect_worker(
-                worker_id.clone(),
-                &namespace.account_id,
-                &namespace.project_id,
-            )
-            .await?;
-
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<DebugSessionData, DebugServiceError> {
         self.debug_session
-            .insert(
-                debug_session_id,
-                DebugSessionData {
-                    worker_metadata,
-                    target_oplog_index: None,
-                    playback_overrides: PlaybackOverridesInternal::empty(),
-                    current_oplog_index: OplogIndex::NONE,
-                },
-            )
-            .await;
-
-        let connect_result = ConnectResult {
-            worker_id: worker_id.clone(),
-            message: format!("Worker {worker_id} connected to namespace {namespace}"),
-        };
-
-        Ok((
-            connect_result,
-            owned_worker_id,
-            namespace,
-            worker_event_receiver,
-        ))
+            .get(&DebugSessionId::new(owned_worker_id.clone()))
+            .await
+            .ok_or(DebugServiceError::internal(
+                "No debug session found".to_string(),
+                Some(owned_worker_id.worker_id.clone()),
+            ))
     }
 
-    async fn playback(
+    async fn get_suspended_worker(
         &self,
         owned_worker_id: &OwnedWorkerId,
         account_id: &AccountId,
-        target_index: OplogIndex,
-        playback_overrides: Option<Vec<PlaybackOverride>>,
-        ensure_invocation_boundary: bool,
-    ) -> Result<PlaybackResult, DebugServiceError> {
-        if !target_index.is_defined() {
-            return Err(DebugServiceError::ValidationFailed {
-                worker_id: Some(owned_worker_id.worker_id.clone()),
-                errors: vec![format!(
-                    "Trying to rewind to an invalid oplog index {target_index}"
-                )],
-            });
-        }
-
-        let debug_session_id = DebugSessionId::new(owned_worker_id.clone());
-        let worker_id = owned_worker_id.worker_id.clone();
-
-        let session_data =
-            self.debug_session
-                .get(&debug_session_id)
-                .await
-                .ok_or(DebugServiceError::internal(
-                    "No debug session found".to_string(),
-                    Some(worker_id.clone()),
-                ))?;
-
-        let current_oplog_index = session_data.current_oplog_index;
-
-        debug!("Playback from current oplog index {current_oplog_index}");
-
-        // At this point, the worker do exist after the connect
-        // however, the debug session is updated with a different target index
-        // allowing replaying to (potentially) stop at this index
-        let worker = Worker::get_or_create_suspended(
+        session_data: &DebugSessionData,
+    ) -> Result<Arc<Worker<DebugContext>>, DebugServiceError> {
+        Worker::get_or_create_suspended(
             &self.all,
             account_id,
             owned_worker_id,
@@ -434,80 +451,1078 @@ impl DebugService for DebugServiceDefault {
             session_data.worker_metadata.parent.clone(),
         )
         .await
-        .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+        .map_err(|e| DebugServiceError::internal(e.to_string(), Some(owned_worker_id.worker_id())))
+    }
 
-        // We select a new target index based on the given target index
-        // such that it is always in an invocation boundary
-        let new_target_index = if ensure_invocation_boundary {
-            Self::target_index_at_invocation_boundary(&worker_id, &worker, target_index).await?
-        } else {
-            target_index
-        };
+    // Steps can only move forward within the oplog of the original worker
+    async fn next_step_index(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        current_oplog_index: OplogIndex,
+    ) -> Result<(OplogIndex, OplogIndex), DebugServiceError> {
+        let last_oplog_index = self
+            .all
+            .oplog_service()
+            .get_last_index(owned_worker_id)
+            .await;
 
-        if new_target_index < current_oplog_index {
-            return Err(DebugServiceError::internal(
-                format!(
-                    "Target oplog index {target_index} for playback is less than the existing target oplog index {current_oplog_index}. Use rewind instead"
-                ),
-                Some(debug_session_id.worker_id()),
-            ));
+        if current_oplog_index >= last_oplog_index {
+            Err(DebugServiceError::validation_failed(
+                vec![format!(
+                    "Cannot step beyond the last oplog index {last_oplog_index}"
+                )],
+                Some(owned_worker_id.worker_id()),
+            ))
+        } else {
+            Ok((current_oplog_index.next(), last_oplog_index))
         }
+    }
 
-        let playback_overrides_validated = if let Some(overrides) = playback_overrides {
-            Some(
-                Self::validate_playback_overrides(
-                    worker_id.clone(),
-                    current_oplog_index,
-                    overrides,
-                )
-                .await?,
+    // Replays the worker up to the target index, or up to the first entry on the way that hits one of
+    // the breakpoints of the session
+    async fn replay_until(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        worker: Arc<Worker<DebugContext>>,
+        current_oplog_index: OplogIndex,
+        target_index: OplogIndex,
+        playback_overrides: Option<PlaybackOverridesInternal>,
+    ) -> Result<PlaybackResult, DebugServiceError> {
+        let debug_session_id = DebugSessionId::new(owned_worker_id.clone());
+        let worker_id = owned_worker_id.worker_id();
+
+        // We update the session with the new target index
+        // before starting the worker. The overrides must be in place already
+        // when looking for breakpoints, as they are applied when reading the oplog.
+        let session_data = self
+            .debug_session
+            .update(debug_session_id.clone(), target_index, playback_overrides)
+            .await
+            .ok_or(DebugServiceError::internal(
+                "No debug session found".to_string(),
+                Some(worker_id.clone()),
+            ))?;
+
+        let last_oplog_index = self
+            .all
+            .oplog_service()
+            .get_last_index(owned_worker_id)
+            .await;
+
+        let breakpoint_hit = session_data
+            .breakpoints
+            .find_first_hit(
+                worker.oplog(),
+                current_oplog_index,
+                target_index.min(last_oplog_index),
+            )
+            .await;
+
+        if let Some((breakpoint_index, breakpoint)) = &breakpoint_hit {
+            debug!(
+                "Playback will stop at index {breakpoint_index} hitting breakpoint {breakpoint:?}"
+            );
+
+            self.debug_session
+                .update(debug_session_id.clone(), *breakpoint_index, None)
+                .await;
+        }
+
+        // this will fail if the worker is not currently running and do nothing.
+        // If this succeeded it means we continued from the previous oplog and only some of the log events are reemitted.
+        let incremental_playback = worker.resume_replay().await.is_ok();
+
+        // the worker was not running, we need to start it so it starts replaying
+        if !incremental_playback {
+            Worker::start_if_needed(worker.clone()).await.map_err(|e| {
+                DebugServiceError::internal(
+                    format!("Failed to start worker for resumption: {e}"),
+                    Some(worker_id.clone()),
+                )
+            })?;
+        }
+
+        // This might fail if we are replaying beyond the oplog index and trapping due to entering live mode, ignore.
+        let _ = worker.await_ready_to_process_commands().await;
+
+        let stopped_at_index = self
+            .debug_session
+            .get(&debug_session_id)
+            .await
+            .map(|d| d.current_oplog_index)
+            .unwrap_or(OplogIndex::INITIAL);
+
+        let breakpoint = breakpoint_hit.map(|(_, breakpoint)| breakpoint);
+
+        let message = if breakpoint.is_some() {
+            format!(
+                "Playback worker {worker_id} stopped at a breakpoint at index {stopped_at_index}"
+            )
+        } else {
+            format!("Playback worker {worker_id} stopped at index {stopped_at_index}")
+        };
+
+        Ok(PlaybackResult {
+            worker_id,
+            current_index: stopped_at_index,
+            incremental_playback,
+            message,
+            breakpoint,
+        })
+    }
+
+    // Inspecting the worker is only possible once it has been played back, as otherwise
+    // starting it would replay its whole oplog
+    async fn get_played_back_worker(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<(OplogIndex, Arc<Worker<DebugContext>>), DebugServiceError> {
+        let session_data = self.get_session_data(owned_worker_id).await?;
+
+        if session_data.target_oplog_index.is_none()
+            || !session_data.current_oplog_index.is_defined()
+        {
+            return Err(DebugServiceError::validation_failed(
+                vec!["The worker has to be played back before inspecting it".to_string()],
+                Some(owned_worker_id.worker_id()),
+            ));
+        }
+
+        let worker = self
+            .get_suspended_worker(owned_worker_id, account_id, &session_data)
+            .await?;
+
+        Ok((session_data.current_oplog_index, worker))
+    }
+
+    pub async fn get_open_resources(
+        oplog: Arc<dyn Oplog>,
+        current_oplog_index: OplogIndex,
+    ) -> Vec<OpenResource> {
+        let mut resources = BTreeMap::new();
+        let mut index = OplogIndex::INITIAL;
+
+        while index <= current_oplog_index {
+            match oplog.read(index).await {
+                OplogEntry::CreateResource { id, .. } => {
+                    resources.insert(
+                        id.0,
+                        OpenResource {
+                            id: id.0,
+                            created_at: index,
+                            resource_name: None,
+                            resource_params: None,
+                        },
+                    );
+                }
+                OplogEntry::DescribeResource {
+                    id,
+                    indexed_resource,
+                    ..
+                } => {
+                    if let Some(resource) = resources.get_mut(&id.0) {
+                        resource.resource_name = Some(indexed_resource.resource_name);
+                        resource.resource_params = Some(indexed_resource.resource_params);
+                    }
+                }
+                OplogEntry::DropResource { id, .. } => {
+                    resources.remove(&id.0);
+                }
+                _ => {}
+            }
+
+            index = index.next();
+        }
+
+        resources.into_values().collect()
+    }
+
+    pub async fn get_memory_ranges(
+        oplog: Arc<dyn Oplog>,
+        current_oplog_index: OplogIndex,
+    ) -> Vec<MemoryRange> {
+        let mut ranges: Vec<MemoryRange> = Vec::new();
+        let mut index = OplogIndex::INITIAL;
+
+        while index <= current_oplog_index {
+            let size = match oplog.read(index).await {
+                OplogEntry::Create {
+                    initial_total_linear_memory_size,
+                    ..
+                } => Some(initial_total_linear_memory_size),
+                OplogEntry::GrowMemory { delta, .. } => Some(delta),
+                _ => None,
+            };
+
+            if let Some(size) = size {
+                let start = ranges.last().map(|range| range.end).unwrap_or(0);
+                ranges.push(MemoryRange {
+                    allocated_at: index,
+                    start,
+                    end: start + size,
+                });
+            }
+
+            index = index.next();
+        }
+
+        ranges
+    }
+
+    // Whether an invocation has been started but not yet completed at the given index
+    pub async fn is_in_invocation(oplog: Arc<dyn Oplog>, current_oplog_index: OplogIndex) -> bool {
+        let mut in_invocation = false;
+        let mut index = OplogIndex::INITIAL;
+
+        while index <= current_oplog_index {
+            match oplog.read(index).await {
+                OplogEntry::ExportedFunctionInvoked { .. } => in_invocation = true,
+                OplogEntry::ExportedFunctionCompleted { .. } => in_invocation = false,
+                _ => {}
+            }
+
+            index = index.next();
+        }
+
+        in_invocation
+    }
+
+    pub async fn validate_playback_overrides(
+        worker_id: WorkerId,
+        current_index: OplogIndex,
+        overrides: Vec<PlaybackOverride>,
+    ) -> Result<PlaybackOverridesInternal, DebugServiceError> {
+        PlaybackOverridesInternal::from_playback_override(overrides, current_index).map_err(|err| {
+            DebugServiceError::ValidationFailed {
+                worker_id: Some(worker_id.clone()),
+                errors: vec![err],
+            }
+        })
+    }
+
+    // Reads the whole oplog of a worker in its public form, without going through the debug oplog
+    async fn read_public_oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<PublicOplogEntry>, String> {
+        let mut entries = Vec::new();
+        let mut next_oplog_index = OplogIndex::INITIAL;
+        let mut current_component_version = find_component_version_at(
+            self.original_oplog_service.clone(),
+            owned_worker_id,
+            next_oplog_index,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+        loop {
+            let chunk = get_public_oplog_chunk(
+                self.all.component_service(),
+                self.original_oplog_service.clone(),
+                self.all.plugins(),
+                self.all.project_service(),
+                owned_worker_id,
+                current_component_version,
+                next_oplog_index,
+                self.all.config().limits.max_oplog_query_pages_size,
+            )
+            .await?;
+
+            if chunk.entries.is_empty() {
+                break;
+            }
+
+            entries.extend(chunk.entries);
+
+            if chunk.next_oplog_index > chunk.last_index {
+                break;
+            }
+
+            next_oplog_index = chunk.next_oplog_index;
+            current_component_version = chunk.current_component_version;
+        }
+
+        Ok(entries)
+    }
+
+    // Invokes an exported function on a temporary copy of the worker forked at the given index,
+    // deleting the copy afterwards. The remote writes of the copy are blocked, so an invocation
+    // trying to perform one (such as an outgoing HTTP request or an RPC call) fails instead of
+    // repeating the side effects of the original worker.
+    async fn invoke_on_scratch_fork(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        session_data: &DebugSessionData,
+        oplog_index: OplogIndex,
+        function_name: String,
+        function_params: Vec<ValueAndType>,
+    ) -> Result<Option<ValueAndType>, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let scratch_worker_id = WorkerId {
+            component_id: worker_id.component_id.clone(),
+            worker_name: format!("{}-scratch-{}", worker_id.worker_name, Uuid::new_v4()),
+        };
+
+        info!("Forking worker {worker_id} to scratch worker {scratch_worker_id}");
+
+        let forked = self
+            .all
+            .worker_fork_service()
+            .fork_without_remote_writes(
+                account_id,
+                owned_worker_id,
+                &scratch_worker_id,
+                oplog_index,
+            )
+            .await;
+
+        // A failed fork may still have created the scratch worker
+        let result = match forked {
+            Ok(()) => self
+                .all
+                .worker_proxy()
+                .invoke_and_await(
+                    &OwnedWorkerId::new(&owned_worker_id.project_id, &scratch_worker_id),
+                    None,
+                    function_name,
+                    function_params
+                        .into_iter()
+                        .map(|param| WitValue::from(param.value))
+                        .collect(),
+                    worker_id.clone(),
+                    session_data.worker_metadata.args.clone(),
+                    session_data.worker_metadata.env.iter().cloned().collect(),
+                    session_data.worker_metadata.wasi_config_vars.clone(),
+                    InvocationContextStack::fresh(),
+                )
+                .await
+                .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone()))),
+            Err(e) => Err(DebugServiceError::internal(
+                e.to_string(),
+                Some(worker_id.clone()),
+            )),
+        };
+
+        let deleted = self.delete_scratch_worker(&scratch_worker_id).await;
+
+        match (result, deleted) {
+            (Ok(result), Ok(())) => Ok(result),
+            (Err(e), _) => Err(e),
+            (Ok(_), Err(e)) => Err(DebugServiceError::internal(
+                format!("Failed to delete scratch worker {scratch_worker_id}: {e}"),
+                Some(worker_id),
+            )),
+        }
+    }
+
+    // Deletes a scratch worker, retrying a few times, as a failure leaves it behind
+    async fn delete_scratch_worker(&self, scratch_worker_id: &WorkerId) -> Result<(), String> {
+        let mut attempt = 1;
+
+        loop {
+            match self.all.worker_proxy().delete(scratch_worker_id).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < SCRATCH_WORKER_DELETE_ATTEMPTS => {
+                    warn!(
+                        "Failed to delete scratch worker {scratch_worker_id} (attempt {attempt}): {e}"
+                    );
+                    tokio::time::sleep(Duration::from_millis(100 * attempt as u64)).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    error!("Failed to delete scratch worker {scratch_worker_id}: {e}");
+                    return Err(e.to_string());
+                }
+            }
+        }
+    }
+
+    // The ones of the given indices at which an invocation has been started but not yet completed,
+    // based on the entries of the oplog up to the largest of them
+    pub fn indices_in_invocation(
+        entries: &BTreeMap<OplogIndex, OplogEntry>,
+        oplog_indices: &[OplogIndex],
+    ) -> Vec<OplogIndex> {
+        let mut in_invocation_at = BTreeMap::new();
+        let mut in_invocation = false;
+
+        for (index, entry) in entries {
+            match entry {
+                OplogEntry::ExportedFunctionInvoked { .. } => in_invocation = true,
+                OplogEntry::ExportedFunctionCompleted { .. } => in_invocation = false,
+                _ => {}
+            }
+            in_invocation_at.insert(*index, in_invocation);
+        }
+
+        oplog_indices
+            .iter()
+            .filter(|index| in_invocation_at.get(*index).copied().unwrap_or(false))
+            .copied()
+            .collect()
+    }
+
+    // Overrides set explicitly for an index take precedence over the ones generated by the rules
+    pub async fn apply_playback_override_rules(
+        worker_id: WorkerId,
+        oplog: Arc<dyn Oplog>,
+        current_index: OplogIndex,
+        target_index: OplogIndex,
+        override_rules: Vec<PlaybackOverrideRule>,
+        overrides: Option<PlaybackOverridesInternal>,
+    ) -> Result<PlaybackOverridesInternal, DebugServiceError> {
+        let rule_overrides = PlaybackOverrideRulesInternal::from_rules(override_rules)
+            .map_err(|err| {
+                DebugServiceError::validation_failed(vec![err], Some(worker_id.clone()))
+            })?
+            .expand(oplog, current_index, target_index)
+            .await
+            .map_err(|err| {
+                DebugServiceError::validation_failed(vec![err], Some(worker_id.clone()))
+            })?;
+
+        let mut overrides = overrides.unwrap_or_else(PlaybackOverridesInternal::empty);
+        for (index, entry) in rule_overrides {
+            overrides.overrides.entry(index).or_insert(entry);
+        }
+
+        Ok(overrides)
+    }
+
+    pub async fn target_index_at_invocation_boundary(
+        worker_id: &WorkerId,
+        worker: &Arc<Worker<DebugContext>>,
+        target_oplog_index: OplogIndex,
+    ) -> Result<OplogIndex, DebugServiceError> {
+        // New target index to be calculated here
+        let oplog: Arc<dyn Oplog> = worker.oplog();
+
+        let original_current_oplog_index = oplog.current_oplog_index().await;
+
+        Self::get_target_oplog_index_at_invocation_boundary(
+            oplog,
+            target_oplog_index,
+            original_current_oplog_index,
+        )
+        .await
+        .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))
+    }
+
+    pub async fn get_target_oplog_index_at_invocation_boundary(
+        oplog: Arc<dyn Oplog>,
+        target_oplog_index: OplogIndex,
+        original_last_oplog_index: OplogIndex,
+    ) -> Result<OplogIndex, String> {
+        let mut new_target_oplog_index = target_oplog_index;
+
+        loop {
+            let entry = oplog.read(new_target_oplog_index).await;
+
+            match entry {
+                OplogEntry::ExportedFunctionCompleted { .. } => {
+                    return Ok(new_target_oplog_index);
+                }
+                _ => {
+                    if new_target_oplog_index == original_last_oplog_index {
+                        let error_message = format!(
+                            "Invocation boundary not found. Set an oplog index that is not in the middle of an incomplete invocation. \
+                        Last oplog index: {original_last_oplog_index}"
+                        );
+                        error!("{}", error_message);
+                        return Err(error_message);
+                    }
+
+                    new_target_oplog_index = new_target_oplog_index.next();
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DebugService for DebugServiceDefault {
+    async fn connect(
+        &self,
+        auth_ctx: &AuthCtx,
+        worker_id: &WorkerId,
+    ) -> Result<(ConnectResult, OwnedWorkerId, Namespace, WorkerEventReceiver), DebugServiceError>
+    {
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(
+                &worker_id.component_id,
+                ProjectAction::UpdateWorker,
+                auth_ctx,
+            )
+            .await
+            .map_err(|e| DebugServiceError::unauthorized(format!("Unauthorized: {e}")))?;
+
+        let owned_worker_id = OwnedWorkerId::new(&namespace.project_id, worker_id);
+
+        let debug_session_id = DebugSessionId::new(owned_worker_id.clone());
+
+        if self.debug_session.get(&debug_session_id).await.is_some() {
+            return Err(DebugServiceError::conflict(
+                worker_id.clone(),
+                "Worker is already being debugged".to_string(),
+            ));
+        }
+
+        // This simply migrates the worker to the debug mode, but it doesn't start the worker
+        let (worker_metadata, worker_event_receiver) = self
+            .connect_worker(
+                worker_id.clone(),
+                &namespace.account_id,
+                &namespace.project_id,
+            )
+            .await?;
+
+        self.debug_session
+            .insert(
+                debug_session_id,
+                DebugSessionData {
+                    worker_metadata,
+                    target_oplog_index: None,
+                    playback_overrides: PlaybackOverridesInternal::empty(),
+                    current_oplog_index: OplogIndex::NONE,
+                    breakpoints: BreakpointsInternal::empty(),
+                    verify_replay: false,
+                    replay_divergence: None,
+                },
+            )
+            .await;
+
+        let connect_result = ConnectResult {
+            worker_id: worker_id.clone(),
+            message: format!("Worker {worker_id} connected to namespace {namespace}"),
+        };
+
+        Ok((
+            connect_result,
+            owned_worker_id,
+            namespace,
+            worker_event_receiver,
+        ))
+    }
+
+    async fn playback(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        target_index: OplogIndex,
+        playback_overrides: Option<Vec<PlaybackOverride>>,
+        override_rules: Option<Vec<PlaybackOverrideRule>>,
+        ensure_invocation_boundary: bool,
+    ) -> Result<PlaybackResult, DebugServiceError> {
+        if !target_index.is_defined() {
+            return Err(DebugServiceError::ValidationFailed {
+                worker_id: Some(owned_worker_id.worker_id.clone()),
+                errors: vec![format!(
+                    "Trying to rewind to an invalid oplog index {target_index}"
+                )],
+            });
+        }
+
+        let worker_id = owned_worker_id.worker_id.clone();
+
+        let session_data = self.get_session_data(owned_worker_id).await?;
+
+        let current_oplog_index = session_data.current_oplog_index;
+
+        debug!("Playback from current oplog index {current_oplog_index}");
+
+        // At this point, the worker do exist after the connect
+        // however, the debug session is updated with a different target index
+        // allowing replaying to (potentially) stop at this index
+        let worker = self
+            .get_suspended_worker(owned_worker_id, account_id, &session_data)
+            .await?;
+
+        // We select a new target index based on the given target index
+        // such that it is always in an invocation boundary
+        let new_target_index = if ensure_invocation_boundary {
+            Self::target_index_at_invocation_boundary(&worker_id, &worker, target_index).await?
+        } else {
+            target_index
+        };
+
+        if new_target_index < current_oplog_index {
+            return Err(DebugServiceError::internal(
+                format!(
+                    "Target oplog index {target_index} for playback is less than the existing target oplog index {current_oplog_index}. Use rewind instead"
+                ),
+                Some(worker_id),
+            ));
+        }
+
+        let playback_overrides_validated = if let Some(overrides) = playback_overrides {
+            Some(
+                Self::validate_playback_overrides(
+                    worker_id.clone(),
+                    current_oplog_index,
+                    overrides,
+                )
+                .await?,
             )
         } else {
             None
         };
 
-        // We update the session with the new target index
-        // before starting the worker
-        self.debug_session
-            .update(
-                debug_session_id.clone(),
-                new_target_index,
-                playback_overrides_validated,
-            )
-            .await;
+        let playback_overrides_validated = if let Some(override_rules) = override_rules {
+            let last_oplog_index = self
+                .all
+                .oplog_service()
+                .get_last_index(owned_worker_id)
+                .await;
+
+            Some(
+                Self::apply_playback_override_rules(
+                    worker_id.clone(),
+                    worker.oplog(),
+                    current_oplog_index,
+                    new_target_index.min(last_oplog_index),
+                    override_rules,
+                    playback_overrides_validated,
+                )
+                .await?,
+            )
+        } else {
+            playback_overrides_validated
+        };
+
+        self.replay_until(
+            owned_worker_id,
+            worker,
+            current_oplog_index,
+            new_target_index,
+            playback_overrides_validated,
+        )
+        .await
+    }
+
+    async fn step(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<PlaybackResult, DebugServiceError> {
+        let session_data = self.get_session_data(owned_worker_id).await?;
+        let current_oplog_index = session_data.current_oplog_index;
+
+        let (target_index, _) = self
+            .next_step_index(owned_worker_id, current_oplog_index)
+            .await?;
+
+        debug!("Stepping from oplog index {current_oplog_index} to {target_index}");
+
+        let worker = self
+            .get_suspended_worker(owned_worker_id, account_id, &session_data)
+            .await?;
+
+        self.replay_until(
+            owned_worker_id,
+            worker,
+            current_oplog_index,
+            target_index,
+            None,
+        )
+        .await
+    }
+
+    async fn step_over_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<PlaybackResult, DebugServiceError> {
+        let session_data = self.get_session_data(owned_worker_id).await?;
+        let current_oplog_index = session_data.current_oplog_index;
+
+        let (next_index, last_oplog_index) = self
+            .next_step_index(owned_worker_id, current_oplog_index)
+            .await?;
+
+        let worker = self
+            .get_suspended_worker(owned_worker_id, account_id, &session_data)
+            .await?;
+
+        let target_index = Self::get_target_oplog_index_at_invocation_boundary(
+            worker.oplog(),
+            next_index,
+            last_oplog_index,
+        )
+        .await
+        .map_err(|e| {
+            DebugServiceError::validation_failed(vec![e], Some(owned_worker_id.worker_id()))
+        })?;
+
+        debug!(
+            "Stepping over the invocation from oplog index {current_oplog_index} to {target_index}"
+        );
+
+        self.replay_until(
+            owned_worker_id,
+            worker,
+            current_oplog_index,
+            target_index,
+            None,
+        )
+        .await
+    }
+
+    async fn reverse_continue(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<PlaybackResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let session_data = self.get_session_data(owned_worker_id).await?;
+        let current_oplog_index = session_data.current_oplog_index;
+
+        if current_oplog_index <= OplogIndex::INITIAL {
+            return Err(DebugServiceError::validation_failed(
+                vec!["Cannot rewind beyond the beginning of the oplog".to_string()],
+                Some(worker_id),
+            ));
+        }
+
+        let worker = self
+            .get_suspended_worker(owned_worker_id, account_id, &session_data)
+            .await?;
+
+        let breakpoint_hit = session_data
+            .breakpoints
+            .find_last_hit(worker.oplog(), OplogIndex::INITIAL, current_oplog_index)
+            .await;
+
+        let target_index = breakpoint_hit
+            .as_ref()
+            .map(|(index, _)| *index)
+            .unwrap_or(OplogIndex::INITIAL);
+
+        debug!("Reverse continuing from oplog index {current_oplog_index} to {target_index}");
+
+        let rewind_result = self
+            .rewind(owned_worker_id, account_id, target_index, false)
+            .await?;
+
+        let breakpoint = breakpoint_hit.map(|(_, breakpoint)| breakpoint);
+
+        let message = if breakpoint.is_some() {
+            format!(
+                "Rewound worker {worker_id} to a breakpoint at index {}",
+                rewind_result.current_index
+            )
+        } else {
+            format!(
+                "Rewound worker {worker_id} to index {}",
+                rewind_result.current_index
+            )
+        };
+
+        Ok(PlaybackResult {
+            worker_id,
+            current_index: rewind_result.current_index,
+            message,
+            incremental_playback: false,
+            breakpoint,
+        })
+    }
+
+    async fn set_breakpoints(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        breakpoints: Vec<Breakpoint>,
+    ) -> Result<SetBreakpointsResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let breakpoints_validated =
+            BreakpointsInternal::from_breakpoints(breakpoints).map_err(|err| {
+                DebugServiceError::validation_failed(vec![err], Some(worker_id.clone()))
+            })?;
+
+        let session_data = self
+            .debug_session
+            .update_breakpoints(
+                DebugSessionId::new(owned_worker_id.clone()),
+                breakpoints_validated,
+            )
+            .await
+            .ok_or(DebugServiceError::internal(
+                "No debug session found".to_string(),
+                Some(worker_id.clone()),
+            ))?;
+
+        let breakpoints = session_data.breakpoints.breakpoints();
+
+        Ok(SetBreakpointsResult {
+            message: format!(
+                "Set {} breakpoints for worker {worker_id}",
+                breakpoints.len()
+            ),
+            worker_id,
+            breakpoints,
+        })
+    }
+
+    async fn resources(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<ResourcesResult, DebugServiceError> {
+        let (current_index, worker) = self
+            .get_played_back_worker(owned_worker_id, account_id)
+            .await?;
+
+        let resources = Self::get_open_resources(worker.oplog(), current_index).await;
+
+        Ok(ResourcesResult {
+            worker_id: owned_worker_id.worker_id(),
+            current_index,
+            resources,
+        })
+    }
+
+    async fn file_system_node(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        path: ComponentFilePath,
+    ) -> Result<FileSystemNodeResult, DebugServiceError> {
+        let (_, worker) = self
+            .get_played_back_worker(owned_worker_id, account_id)
+            .await?;
+
+        let result = worker
+            .get_file_system_node(path.clone())
+            .await
+            .map_err(|e| {
+                DebugServiceError::internal(e.to_string(), Some(owned_worker_id.worker_id()))
+            })?;
+
+        match result {
+            GetFileSystemNodeResult::Ok(entries) => Ok(FileSystemNodeResult::Directory {
+                entries: entries.into_iter().map(to_file_system_node).collect(),
+            }),
+            GetFileSystemNodeResult::File(node) => Ok(FileSystemNodeResult::File {
+                node: to_file_system_node(node),
+            }),
+            GetFileSystemNodeResult::NotFound => Err(DebugServiceError::validation_failed(
+                vec![format!("Path {path} not found")],
+                Some(owned_worker_id.worker_id()),
+            )),
+        }
+    }
+
+    async fn read_file(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        path: ComponentFilePath,
+    ) -> Result<FileContentsResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let (_, worker) = self
+            .get_played_back_worker(owned_worker_id, account_id)
+            .await?;
+
+        let result = worker
+            .read_file(path.clone())
+            .await
+            .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
 
-        // this will fail if the worker is not currently running and do nothing.
-        // If this succeeded it means we continued from the previous oplog and only some of the log events are reemitted.
-        let incremental_playback = worker.resume_replay().await.is_ok();
+        match result {
+            ReadFileResult::Ok(mut stream) => {
+                let mut contents = Vec::new();
+                while let Some(chunk) = stream.next().await {
+                    let chunk = chunk.map_err(|e| {
+                        DebugServiceError::internal(e.to_string(), Some(worker_id.clone()))
+                    })?;
+                    contents.extend_from_slice(&chunk);
+                }
 
-        // the worker was not running, we need to start it so it starts replaying
-        if !incremental_playback {
-            Worker::start_if_needed(worker.clone()).await.map_err(|e| {
-                DebugServiceError::internal(
-                    format!("Failed to start worker for resumption: {e}"),
+                Ok(FileContentsResult {
+                    path,
+                    contents: base64::engine::general_purpose::STANDARD.encode(contents),
+                })
+            }
+            ReadFileResult::NotFound => Err(DebugServiceError::validation_failed(
+                vec![format!("File {path} not found")],
+                Some(worker_id),
+            )),
+            ReadFileResult::NotAFile => Err(DebugServiceError::validation_failed(
+                vec![format!("{path} is not a file")],
+                Some(worker_id),
+            )),
+        }
+    }
+
+    async fn memory(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<MemoryResult, DebugServiceError> {
+        let (current_index, worker) = self
+            .get_played_back_worker(owned_worker_id, account_id)
+            .await?;
+
+        let ranges = Self::get_memory_ranges(worker.oplog(), current_index).await;
+
+        let main_memory_size = self
+            .all
+            .extra_deps()
+            .worker_memories()
+            .main_memory_size(owned_worker_id)
+            .unwrap_or(0);
+
+        Ok(MemoryResult {
+            worker_id: owned_worker_id.worker_id(),
+            current_index,
+            total_linear_memory_size: ranges.last().map(|range| range.end).unwrap_or(0),
+            main_memory_size,
+            ranges,
+        })
+    }
+
+    async fn read_memory(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        offset: u64,
+        length: u64,
+    ) -> Result<MemoryContentsResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let (current_index, _worker) = self
+            .get_played_back_worker(owned_worker_id, account_id)
+            .await?;
+
+        let contents = self
+            .all
+            .extra_deps()
+            .worker_memories()
+            .read(owned_worker_id, offset, length)
+            .map_err(|err| match err {
+                ReadMemoryError::NotInstantiated => {
+                    DebugServiceError::internal(err.to_string(), Some(worker_id.clone()))
+                }
+                ReadMemoryError::OutOfBounds { .. } => DebugServiceError::validation_failed(
+                    vec![err.to_string()],
                     Some(worker_id.clone()),
-                )
+                ),
             })?;
+
+        Ok(MemoryContentsResult {
+            worker_id,
+            current_index,
+            offset,
+            contents: base64::engine::general_purpose::STANDARD.encode(contents),
+        })
+    }
+
+    async fn call_getter(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        function_name: String,
+        function_params: Vec<ValueAndType>,
+    ) -> Result<CallGetterResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+        let session_data = self.get_session_data(owned_worker_id).await?;
+
+        let (current_index, worker) = self
+            .get_played_back_worker(owned_worker_id, account_id)
+            .await?;
+
+        // A copy forked in the middle of an invocation would first finish it live,
+        // performing its side effects again
+        if Self::is_in_invocation(worker.oplog(), current_index).await {
+            return Err(DebugServiceError::validation_failed(
+                vec![format!(
+                    "Cannot call {function_name} in the middle of an invocation at index {current_index}"
+                )],
+                Some(worker_id),
+            ));
         }
 
-        // This might fail if we are replaying beyond the oplog index and trapping due to entering live mode, ignore.
-        let _ = worker.await_ready_to_process_commands().await;
+        let result = self
+            .invoke_on_scratch_fork(
+                owned_worker_id,
+                account_id,
+                &session_data,
+                current_index,
+                function_name,
+                function_params,
+            )
+            .await?;
 
-        let stopped_at_index = self
-            .debug_session
-            .get(&debug_session_id)
-            .await
-            .map(|d| d.current_oplog_index)
-            .unwrap_or(OplogIndex::INITIAL);
+        Ok(CallGetterResult {
+            worker_id,
+            current_index,
+            result,
+        })
+    }
 
-        Ok(PlaybackResult {
-            worker_id: owned_worker_id.worker_id.clone(),
-            current_index: stopped_at_index,
-            incremental_playback,
-            message: format!(
-                "Playback worker {} stopped at index {}",
-                owned_worker_id.worker_id, stopped_at_index
+    async fn oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        from_index: OplogIndex,
+        count: u64,
+    ) -> Result<OplogResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        // Only the workers connected to the session can be looked into
+        let _ = self.get_session_data(owned_worker_id).await?;
+
+        let from_index = if from_index.is_defined() {
+            from_index
+        } else {
+            OplogIndex::INITIAL
+        };
+
+        // Reading through the debug oplog service would move the session to the read entries
+        let initial_component_version = find_component_version_at(
+            self.original_oplog_service.clone(),
+            owned_worker_id,
+            from_index,
+        )
+        .await
+        .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+
+        let chunk = get_public_oplog_chunk(
+            self.all.component_service(),
+            self.original_oplog_service.clone(),
+            self.all.plugins(),
+            self.all.project_service(),
+            owned_worker_id,
+            initial_component_version,
+            from_index,
+            min(
+                count as usize,
+                self.all.config().limits.max_oplog_query_pages_size,
             ),
+        )
+        .await
+        .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+
+        let entries = chunk
+            .entries
+            .into_iter()
+            .enumerate()
+            .map(|(offset, entry)| PublicOplogEntryWithIndex {
+                oplog_index: OplogIndex::from_u64(
+                    u64::from(chunk.first_index_in_chunk) + offset as u64,
+                ),
+                entry,
+            })
+            .collect();
+
+        Ok(OplogResult {
+            worker_id,
+            entries,
+            last_index: chunk.last_index,
         })
     }
 
@@ -653,6 +1668,307 @@ impl DebugService for DebugServiceDefault {
         })
     }
 
+    async fn diff_oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        other_worker_id: &WorkerId,
+        limit: usize,
+    ) -> Result<DiffOplogResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let _ = self.get_session_data(owned_worker_id).await?;
+
+        let other_owned_worker_id =
+            OwnedWorkerId::new(&owned_worker_id.project_id, other_worker_id);
+
+        if !self
+            .original_oplog_service
+            .exists(&other_owned_worker_id)
+            .await
+        {
+            return Err(DebugServiceError::validation_failed(
+                vec![format!("Worker {other_worker_id} does not exist")],
+                Some(worker_id),
+            ));
+        }
+
+        let left = self
+            .read_public_oplog(owned_worker_id)
+            .await
+            .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+        let right = self
+            .read_public_oplog(&other_owned_worker_id)
+            .await
+            .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+
+        let diff = OplogDiff::new(&left, &right, limit)
+            .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+
+        Ok(DiffOplogResult {
+            worker_id,
+            other_worker_id: other_worker_id.clone(),
+            diff,
+        })
+    }
+
+    async fn export_trace(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<ExportTraceResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+
+        let _ = self.get_session_data(owned_worker_id).await?;
+
+        let trace = export_worker_trace(
+            self.all.component_service(),
+            self.original_oplog_service.clone(),
+            self.all.file_loader(),
+            owned_worker_id,
+        )
+        .await
+        .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+
+        let bytes = trace
+            .to_bytes()
+            .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+
+        Ok(ExportTraceResult {
+            worker_id,
+            trace: base64::engine::general_purpose::STANDARD.encode(bytes),
+        })
+    }
+
+    async fn import_trace(
+        &self,
+        auth_ctx: &AuthCtx,
+        worker_id: &WorkerId,
+        trace: &str,
+    ) -> Result<ImportTraceResult, DebugServiceError> {
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(
+                &worker_id.component_id,
+                ProjectAction::CreateWorker,
+                auth_ctx,
+            )
+            .await
+            .map_err(|e| DebugServiceError::unauthorized(format!("Unauthorized: {e}")))?;
+
+        let trace = base64::engine::general_purpose::STANDARD
+            .decode(trace)
+            .map_err(|e| format!("Invalid base64 encoding: {e}"))
+            .and_then(|bytes| WorkerTrace::from_bytes(&bytes))
+            .map_err(|e| {
+                DebugServiceError::validation_failed(
+                    vec![format!("Invalid worker trace: {e}")],
+                    Some(worker_id.clone()),
+                )
+            })?;
+
+        let source_worker_id = trace.worker_id.clone();
+        let owned_worker_id = OwnedWorkerId::new(&namespace.project_id, worker_id);
+
+        import_worker_trace(
+            self.all.component_service(),
+            self.original_oplog_service.clone(),
+            self.all.file_loader(),
+            &namespace.account_id,
+            &owned_worker_id,
+            trace,
+        )
+        .await
+        .map_err(|e| match e {
+            WorkerExecutorError::WorkerAlreadyExists { .. }
+            | WorkerExecutorError::InvalidRequest { .. } => {
+                DebugServiceError::validation_failed(vec![e.to_string()], Some(worker_id.clone()))
+            }
+            _ => DebugServiceError::internal(e.to_string(), Some(worker_id.clone())),
+        })?;
+
+        Ok(ImportTraceResult {
+            worker_id: worker_id.clone(),
+            message: format!(
+                "Imported the trace of worker {source_worker_id} as worker {worker_id}"
+            ),
+        })
+    }
+
+    async fn verify_replay(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+    ) -> Result<VerifyReplayResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+        let debug_session_id = DebugSessionId::new(owned_worker_id.clone());
+
+        info!("Verifying the replay of worker {worker_id}");
+
+        let session_data = self.get_session_data(owned_worker_id).await?;
+        let worker = self
+            .get_suspended_worker(owned_worker_id, account_id, &session_data)
+            .await?;
+
+        let last_oplog_index = self
+            .all
+            .oplog_service()
+            .get_last_index(owned_worker_id)
+            .await;
+
+        // The verification flag is read when the worker context gets created, so the worker
+        // always has to replay from the beginning
+        self.debug_session
+            .update_replay_verification(debug_session_id.clone(), true)
+            .await;
+        self.debug_session
+            .update(
+                debug_session_id.clone(),
+                last_oplog_index,
+                Some(PlaybackOverridesInternal::empty()),
+            )
+            .await;
+        self.debug_session
+            .update_oplog_index(debug_session_id.clone(), OplogIndex::NONE)
+            .await;
+
+        if let Some(mut receiver) = worker.set_interrupting(InterruptKind::Restart).await {
+            let _ = receiver.recv().await;
+        } else {
+            Worker::start_if_needed(worker.clone()).await.map_err(|e| {
+                DebugServiceError::internal(
+                    format!("Failed to start worker for replay verification: {e}"),
+                    Some(worker_id.clone()),
+                )
+            })?;
+        }
+
+        // This fails if the replay diverged, the divergence is reported through the session
+        let _ = worker.await_ready_to_process_commands().await;
+
+        let session_data = self.get_session_data(owned_worker_id).await?;
+        self.debug_session
+            .update_replay_verification(debug_session_id, false)
+            .await;
+
+        let current_index = session_data.current_oplog_index;
+        let divergence = session_data.replay_divergence;
+
+        let message = match &divergence {
+            Some(divergence) => divergence.to_string(),
+            None => format!(
+                "Replay of worker {worker_id} matched its oplog up to index {current_index}"
+            ),
+        };
+
+        Ok(VerifyReplayResult {
+            worker_id,
+            current_index,
+            divergence,
+            message,
+        })
+    }
+
+    async fn time_travel_query(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        account_id: &AccountId,
+        oplog_indices: Vec<OplogIndex>,
+        function_name: String,
+        function_params: Vec<ValueAndType>,
+    ) -> Result<TimeTravelQueryResult, DebugServiceError> {
+        let worker_id = owned_worker_id.worker_id();
+        let session_data = self.get_session_data(owned_worker_id).await?;
+
+        let Some(max_index) = oplog_indices.iter().max().copied() else {
+            return Err(DebugServiceError::validation_failed(
+                vec!["At least one oplog index is required".to_string()],
+                Some(worker_id),
+            ));
+        };
+
+        let last_oplog_index = self
+            .original_oplog_service
+            .get_last_index(owned_worker_id)
+            .await;
+
+        let out_of_range: Vec<String> = oplog_indices
+            .iter()
+            .filter(|index| **index == OplogIndex::NONE || **index > last_oplog_index)
+            .map(|index| {
+                format!("Oplog index {index} is out of range. Last oplog index: {last_oplog_index}")
+            })
+            .collect();
+
+        if !out_of_range.is_empty() {
+            return Err(DebugServiceError::validation_failed(
+                out_of_range,
+                Some(worker_id),
+            ));
+        }
+
+        // A copy forked in the middle of an invocation would first finish it live,
+        // performing its side effects again
+        let entries = self
+            .original_oplog_service
+            .read_prefix(owned_worker_id, max_index)
+            .await;
+
+        let in_invocation = Self::indices_in_invocation(&entries, &oplog_indices);
+
+        if !in_invocation.is_empty() {
+            return Err(DebugServiceError::validation_failed(
+                in_invocation
+                    .iter()
+                    .map(|index| {
+                        format!(
+                            "Cannot call {function_name} in the middle of an invocation at index {index}"
+                        )
+                    })
+                    .collect(),
+                Some(worker_id),
+            ));
+        }
+
+        info!(
+            "Evaluating {function_name} on worker {worker_id} at {} oplog indices",
+            oplog_indices.len()
+        );
+
+        let mut results = Vec::with_capacity(oplog_indices.len());
+
+        for oplog_index in oplog_indices {
+            let entry = match self
+                .invoke_on_scratch_fork(
+                    owned_worker_id,
+                    account_id,
+                    &session_data,
+                    oplog_index,
+                    function_name.clone(),
+                    function_params.clone(),
+                )
+                .await
+            {
+                Ok(result) => TimeTravelQueryEntry {
+                    oplog_index,
+                    result,
+                    error: None,
+                },
+                Err(e) => TimeTravelQueryEntry {
+                    oplog_index,
+                    result: None,
+                    error: Some(e.to_string()),
+                },
+            };
+
+            results.push(entry);
+        }
+
+        Ok(TimeTravelQueryResult {
+            worker_id,
+            function_name,
+            results,
+        })
+    }
+
     async fn current_oplog_index(
         &self,
         worker_id: &OwnedWorkerId,
@@ -692,13 +2008,42 @@ impl DebugService for DebugServiceDefault {
     }
 }
 
+fn to_file_system_node(node: ComponentFileSystemNode) -> FileSystemNode {
+    let last_modified = node
+        .last_modified
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| Timestamp::from(duration.as_millis() as u64))
+        .unwrap_or(Timestamp::from(0));
+
+    match node.details {
+        ComponentFileSystemNodeDetails::File { permissions, size } => FileSystemNode {
+            name: node.name,
+            last_modified,
+            is_directory: false,
+            size: Some(size),
+            permissions: Some(permissions),
+        },
+        ComponentFileSystemNodeDetails::Directory => FileSystemNode {
+            name: node.name,
+            last_modified,
+            is_directory: true,
+            size: None,
+            permissions: None,
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::EntriesOplog;
     use bytes::Bytes;
+    use golem_common::model::invocation_context::TraceId;
     use golem_common::model::oplog::OplogIndex;
-    use golem_common::model::oplog::{OplogEntry, OplogPayload};
-    use golem_common::model::Timestamp;
+    use golem_common::model::oplog::{
+        IndexedResourceKey, OplogEntry, OplogPayload, WorkerResourceId,
+    };
+    use golem_common::model::{IdempotencyKey, Timestamp};
     use golem_worker_executor::services::oplog::CommitLevel;
     use std::fmt::{Debug, Formatter};
     use std::time::Duration;
@@ -734,6 +2079,162 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    async fn test_get_open_resources() {
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::CreateResource {
+                timestamp: Timestamp::now_utc(),
+                id: WorkerResourceId(1),
+            },
+            OplogEntry::CreateResource {
+                timestamp: Timestamp::now_utc(),
+                id: WorkerResourceId(2),
+            },
+            OplogEntry::DescribeResource {
+                timestamp: Timestamp::now_utc(),
+                id: WorkerResourceId(2),
+                indexed_resource: IndexedResourceKey {
+                    resource_name: "counter".to_string(),
+                    resource_params: vec!["\"a\"".to_string()],
+                },
+            },
+            OplogEntry::DropResource {
+                timestamp: Timestamp::now_utc(),
+                id: WorkerResourceId(1),
+            },
+            OplogEntry::CreateResource {
+                timestamp: Timestamp::now_utc(),
+                id: WorkerResourceId(3),
+            },
+        ]));
+
+        let result =
+            DebugServiceDefault::get_open_resources(oplog.clone(), OplogIndex::from_u64(4)).await;
+
+        assert_eq!(
+            result,
+            vec![OpenResource {
+                id: 2,
+                created_at: OplogIndex::from_u64(2),
+                resource_name: Some("counter".to_string()),
+                resource_params: Some(vec!["\"a\"".to_string()]),
+            }]
+        );
+
+        let result = DebugServiceDefault::get_open_resources(oplog, OplogIndex::from_u64(2)).await;
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|resource| resource.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    async fn test_get_memory_ranges() {
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::GrowMemory {
+                timestamp: Timestamp::now_utc(),
+                delta: 65536,
+            },
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            OplogEntry::GrowMemory {
+                timestamp: Timestamp::now_utc(),
+                delta: 131072,
+            },
+        ]));
+
+        let result = DebugServiceDefault::get_memory_ranges(oplog, OplogIndex::from_u64(3)).await;
+
+        assert_eq!(
+            result,
+            vec![
+                MemoryRange {
+                    allocated_at: OplogIndex::from_u64(1),
+                    start: 0,
+                    end: 65536,
+                },
+                MemoryRange {
+                    allocated_at: OplogIndex::from_u64(3),
+                    start: 65536,
+                    end: 196608,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    async fn test_is_in_invocation() {
+        let oplog = Arc::new(EntriesOplog::new(vec![
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            OplogEntry::ExportedFunctionInvoked {
+                timestamp: Timestamp::now_utc(),
+                function_name: "golem:it/api.{get}".to_string(),
+                request: OplogPayload::Inline(vec![]),
+                idempotency_key: IdempotencyKey::fresh(),
+                trace_id: TraceId::generate(),
+                trace_states: vec![],
+                invocation_context: vec![],
+            },
+            OplogEntry::ExportedFunctionCompleted {
+                timestamp: Timestamp::now_utc(),
+                response: OplogPayload::Inline(vec![]),
+                consumed_fuel: 0,
+            },
+        ]));
+
+        assert!(
+            !DebugServiceDefault::is_in_invocation(oplog.clone(), OplogIndex::from_u64(1)).await
+        );
+        assert!(
+            DebugServiceDefault::is_in_invocation(oplog.clone(), OplogIndex::from_u64(2)).await
+        );
+        assert!(!DebugServiceDefault::is_in_invocation(oplog, OplogIndex::from_u64(3)).await);
+    }
+
+    #[test]
+    fn test_indices_in_invocation() {
+        let entries: BTreeMap<OplogIndex, OplogEntry> = vec![
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            OplogEntry::ExportedFunctionInvoked {
+                timestamp: Timestamp::now_utc(),
+                function_name: "golem:it/api.{get}".to_string(),
+                request: OplogPayload::Inline(vec![]),
+                idempotency_key: IdempotencyKey::fresh(),
+                trace_id: TraceId::generate(),
+                trace_states: vec![],
+                invocation_context: vec![],
+            },
+            OplogEntry::NoOp {
+                timestamp: Timestamp::now_utc(),
+            },
+            OplogEntry::ExportedFunctionCompleted {
+                timestamp: Timestamp::now_utc(),
+                response: OplogPayload::Inline(vec![]),
+                consumed_fuel: 0,
+            },
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| (OplogIndex::from_u64(i as u64 + 1), entry))
+        .collect();
+
+        let indices = (1..=4).map(OplogIndex::from_u64).collect::<Vec<_>>();
+
+        assert_eq!(
+            DebugServiceDefault::indices_in_invocation(&entries, &indices),
+            vec![OplogIndex::from_u64(2), OplogIndex::from_u64(3)]
+        );
+    }
+
     struct TestOplog {
         invocation_completion_index: u64,
     }
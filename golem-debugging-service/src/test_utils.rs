@@ -0,0 +1,79 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_worker_executor::services::oplog::{CommitLevel, Oplog};
+use std::fmt::{Debug, Formatter};
+use std::time::Duration;
+
+/// A read-only oplog serving a fixed list of entries, the first one being at the initial index
+pub struct EntriesOplog {
+    entries: Vec<OplogEntry>,
+}
+
+impl EntriesOplog {
+    pub fn new(entries: Vec<OplogEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+impl Debug for EntriesOplog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EntriesOplog")
+    }
+}
+
+#[async_trait]
+impl Oplog for EntriesOplog {
+    async fn add(&self, _entry: OplogEntry) {
+        unimplemented!()
+    }
+
+    async fn drop_prefix(&self, _last_dropped_id: OplogIndex) {
+        unimplemented!()
+    }
+
+    async fn commit(&self, _level: CommitLevel) {
+        unimplemented!()
+    }
+
+    async fn current_oplog_index(&self) -> OplogIndex {
+        OplogIndex::from_u64(self.entries.len() as u64)
+    }
+
+    async fn wait_for_replicas(&self, _replicas: u8, _timeout: Duration) -> bool {
+        unimplemented!()
+    }
+
+    async fn read(&self, oplog_index: OplogIndex) -> OplogEntry {
+        self.entries[u64::from(oplog_index) as usize - 1].clone()
+    }
+
+    async fn length(&self) -> u64 {
+        self.entries.len() as u64
+    }
+
+    async fn upload_payload(&self, _data: &[u8]) -> Result<OplogPayload, String> {
+        unimplemented!()
+    }
+
+    async fn download_payload(&self, payload: &OplogPayload) -> Result<Bytes, String> {
+        match payload {
+            OplogPayload::Inline(bytes) => Ok(Bytes::from(bytes.clone())),
+            OplogPayload::External { .. } => unimplemented!(),
+        }
+    }
+}
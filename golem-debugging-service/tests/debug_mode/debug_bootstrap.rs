@@ -7,8 +7,11 @@ use golem_debugging_service::additional_deps::AdditionalDeps;
 use golem_debugging_service::auth::AuthService;
 use golem_debugging_service::debug_context::DebugContext;
 use golem_debugging_service::debug_session::{DebugSessions, DebugSessionsDefault};
+use golem_debugging_service::memory::WorkerMemories;
 use golem_debugging_service::oplog::debug_oplog_service::DebugOplogService;
-use golem_debugging_service::{create_debug_wasmtime_linker, run_debug_worker_executor};
+use golem_debugging_service::{
+    create_debug_wasmtime_config, create_debug_wasmtime_linker, run_debug_worker_executor,
+};
 use golem_service_base::storage::blob::BlobStorage;
 use golem_test_framework::components::worker_executor::provided::ProvidedWorkerExecutor;
 use golem_worker_executor::services::active_workers::ActiveWorkers;
@@ -45,18 +48,20 @@ use std::sync::Arc;
 use tokio::runtime::Handle;
 use tokio::task::JoinSet;
 use wasmtime::component::Linker;
-use wasmtime::Engine;
+use wasmtime::{Config, Engine};
 
 // A test bootstrap which depends on the original
 // bootstrap (inner) as much as possible except for auth service
 pub struct TestDebuggingServerBootStrap {
     regular_worker_executor_context: RegularExecutorTestContext,
+    worker_memories: Arc<WorkerMemories>,
 }
 
 impl TestDebuggingServerBootStrap {
     pub fn new(regular_worker_executor_context: RegularExecutorTestContext) -> Self {
         Self {
             regular_worker_executor_context,
+            worker_memories: Arc::new(WorkerMemories::new()),
         }
     }
 }
@@ -155,7 +160,12 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             Arc::clone(&debug_sessions),
         ));
 
-        let addition_deps = AdditionalDeps::new(auth_service, debug_sessions);
+        let addition_deps = AdditionalDeps::new(
+            auth_service,
+            debug_sessions,
+            oplog_service.clone(),
+            self.worker_memories.clone(),
+        );
         let resource_limits = resource_limits::configured(&ResourceLimitsConfig::Disabled(
             ResourceLimitsDisabledConfig {},
         ));
@@ -258,6 +268,10 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
         ))
     }
 
+    fn create_wasmtime_config(&self) -> Config {
+        create_debug_wasmtime_config(self.worker_memories.clone())
+    }
+
     fn create_wasmtime_linker(&self, engine: &Engine) -> anyhow::Result<Linker<DebugContext>> {
         create_debug_wasmtime_linker(engine)
     }
@@ -3,9 +3,14 @@ use async_trait::async_trait;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::WorkerId;
 use golem_debugging_service::model::params::{
-    ConnectParams, ConnectResult, ForkParams, ForkResult, PlaybackOverride, PlaybackParams,
-    PlaybackResult, RewindParams, RewindResult,
+    Breakpoint, CallGetterParams, CallGetterResult, ConnectParams, ConnectResult, DiffOplogParams,
+    DiffOplogResult, ExportTraceResult, ForkParams, ForkResult, ImportTraceParams,
+    ImportTraceResult, MemoryContentsResult, MemoryResult, PlaybackOverride, PlaybackOverrideRule,
+    PlaybackParams, PlaybackResult, ReadMemoryParams, ResourcesResult, RewindParams, RewindResult,
+    SetBreakpointsParams, SetBreakpointsResult, TimeTravelQueryParams, TimeTravelQueryResult,
+    VerifyReplayResult,
 };
+use golem_wasm_rpc::ValueAndType;
 
 #[async_trait]
 pub trait TestDslDebugMode {
@@ -17,6 +22,46 @@ pub trait TestDslDebugMode {
         overrides: Option<Vec<PlaybackOverride>>,
     ) -> anyhow::Result<PlaybackResult>;
 
+    async fn playback_with_override_rules(
+        &mut self,
+        target_index: OplogIndex,
+        override_rules: Vec<PlaybackOverrideRule>,
+    ) -> anyhow::Result<PlaybackResult>;
+
+    async fn step(&mut self) -> anyhow::Result<PlaybackResult>;
+
+    async fn step_over_invocation(&mut self) -> anyhow::Result<PlaybackResult>;
+
+    async fn reverse_continue(&mut self) -> anyhow::Result<PlaybackResult>;
+
+    async fn set_breakpoints(
+        &mut self,
+        breakpoints: Vec<Breakpoint>,
+    ) -> anyhow::Result<SetBreakpointsResult>;
+
+    async fn resources(&mut self) -> anyhow::Result<ResourcesResult>;
+
+    async fn memory(&mut self) -> anyhow::Result<MemoryResult>;
+
+    async fn read_memory(
+        &mut self,
+        offset: u64,
+        length: u64,
+    ) -> anyhow::Result<MemoryContentsResult>;
+
+    async fn call_getter(
+        &mut self,
+        function_name: &str,
+        function_params: Vec<ValueAndType>,
+    ) -> anyhow::Result<CallGetterResult>;
+
+    async fn time_travel_query(
+        &mut self,
+        oplog_indices: Vec<OplogIndex>,
+        function_name: &str,
+        function_params: Vec<ValueAndType>,
+    ) -> anyhow::Result<TimeTravelQueryResult>;
+
     async fn rewind(&mut self, target_index: OplogIndex) -> anyhow::Result<RewindResult>;
 
     async fn fork(
@@ -25,6 +70,18 @@ pub trait TestDslDebugMode {
         oplog_index_cut_off: OplogIndex,
     ) -> anyhow::Result<ForkResult>;
 
+    async fn diff_oplog(&mut self, other_worker_id: &WorkerId) -> anyhow::Result<DiffOplogResult>;
+
+    async fn export_trace(&mut self) -> anyhow::Result<ExportTraceResult>;
+
+    async fn import_trace(
+        &mut self,
+        worker_id: &WorkerId,
+        trace: String,
+    ) -> anyhow::Result<ImportTraceResult>;
+
+    async fn verify_replay(&mut self) -> anyhow::Result<VerifyReplayResult>;
+
     async fn current_index(&mut self) -> anyhow::Result<OplogIndex>;
 }
 
@@ -54,6 +111,27 @@ impl TestDslDebugMode for DebugWorkerExecutorClient {
                 PlaybackParams {
                     target_index,
                     overrides,
+                    override_rules: None,
+                    ensure_invocation_boundary: None,
+                },
+            )
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn playback_with_override_rules(
+        &mut self,
+        target_index: OplogIndex,
+        override_rules: Vec<PlaybackOverrideRule>,
+    ) -> anyhow::Result<PlaybackResult> {
+        let id = self
+            .send_jrpc_msg(
+                "playback",
+                PlaybackParams {
+                    target_index,
+                    overrides: None,
+                    override_rules: Some(override_rules),
                     ensure_invocation_boundary: None,
                 },
             )
@@ -62,6 +140,97 @@ impl TestDslDebugMode for DebugWorkerExecutorClient {
         self.read_jrpc_response(id).await
     }
 
+    async fn step(&mut self) -> anyhow::Result<PlaybackResult> {
+        let id = self.send_jrpc_msg("step", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn step_over_invocation(&mut self) -> anyhow::Result<PlaybackResult> {
+        let id = self.send_jrpc_msg("step_over_invocation", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn reverse_continue(&mut self) -> anyhow::Result<PlaybackResult> {
+        let id = self.send_jrpc_msg("reverse_continue", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn set_breakpoints(
+        &mut self,
+        breakpoints: Vec<Breakpoint>,
+    ) -> anyhow::Result<SetBreakpointsResult> {
+        let id = self
+            .send_jrpc_msg("set_breakpoints", SetBreakpointsParams { breakpoints })
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn resources(&mut self) -> anyhow::Result<ResourcesResult> {
+        let id = self.send_jrpc_msg("resources", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn memory(&mut self) -> anyhow::Result<MemoryResult> {
+        let id = self.send_jrpc_msg("memory", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn read_memory(
+        &mut self,
+        offset: u64,
+        length: u64,
+    ) -> anyhow::Result<MemoryContentsResult> {
+        let id = self
+            .send_jrpc_msg("read_memory", ReadMemoryParams { offset, length })
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn call_getter(
+        &mut self,
+        function_name: &str,
+        function_params: Vec<ValueAndType>,
+    ) -> anyhow::Result<CallGetterResult> {
+        let id = self
+            .send_jrpc_msg(
+                "call_getter",
+                CallGetterParams {
+                    function_name: function_name.to_string(),
+                    function_params,
+                },
+            )
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn time_travel_query(
+        &mut self,
+        oplog_indices: Vec<OplogIndex>,
+        function_name: &str,
+        function_params: Vec<ValueAndType>,
+    ) -> anyhow::Result<TimeTravelQueryResult> {
+        let id = self
+            .send_jrpc_msg(
+                "time_travel_query",
+                TimeTravelQueryParams {
+                    oplog_indices,
+                    function_name: function_name.to_string(),
+                    function_params,
+                },
+            )
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
     async fn rewind(&mut self, target_index: OplogIndex) -> anyhow::Result<RewindResult> {
         let id = self
             .send_jrpc_msg(
@@ -94,6 +263,50 @@ impl TestDslDebugMode for DebugWorkerExecutorClient {
         self.read_jrpc_response(id).await
     }
 
+    async fn diff_oplog(&mut self, other_worker_id: &WorkerId) -> anyhow::Result<DiffOplogResult> {
+        let id = self
+            .send_jrpc_msg(
+                "diff_oplog",
+                DiffOplogParams {
+                    other_worker_id: other_worker_id.clone(),
+                    limit: None,
+                },
+            )
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn export_trace(&mut self) -> anyhow::Result<ExportTraceResult> {
+        let id = self.send_jrpc_msg("export_trace", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn import_trace(
+        &mut self,
+        worker_id: &WorkerId,
+        trace: String,
+    ) -> anyhow::Result<ImportTraceResult> {
+        let id = self
+            .send_jrpc_msg(
+                "import_trace",
+                ImportTraceParams {
+                    worker_id: worker_id.clone(),
+                    trace,
+                },
+            )
+            .await?;
+
+        self.read_jrpc_response(id).await
+    }
+
+    async fn verify_replay(&mut self) -> anyhow::Result<VerifyReplayResult> {
+        let id = self.send_jrpc_msg("verify_replay", ()).await?;
+
+        self.read_jrpc_response(id).await
+    }
+
     async fn current_index(&mut self) -> anyhow::Result<OplogIndex> {
         let id = self.send_jrpc_msg("current_oplog_index", ()).await?;
 
@@ -1,10 +1,12 @@
 use crate::debug_mode::debug_worker_executor::{DebugWorkerExecutorClient, UntypedJrpcMessage};
 use crate::regular_mode::regular_worker_executor::TestRegularWorkerExecutor;
 use crate::*;
+use base64::Engine;
+use golem_common::model::auth::TokenSecret;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::{ExportedFunctionCompletedParameters, PublicOplogEntry};
 use golem_common::model::{Timestamp, WorkerId};
-use golem_debugging_service::model::params::PlaybackOverride;
+use golem_debugging_service::model::params::{Breakpoint, PlaybackOverride};
 use golem_service_base::model::PublicOplogEntryWithIndex;
 use golem_test_framework::dsl::TestDsl;
 use golem_wasm_ast::analysis::analysed_type::{record, str, variant};
@@ -618,6 +620,356 @@ async fn test_playback_and_fork(
     assert!(!post_fork_invocation_in_forked.is_empty());
 }
 
+#[test]
+#[tracing::instrument]
+async fn test_diff_oplog_with_fork(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .try_start_worker(&component, "shopping-cart")
+        .await
+        .unwrap()
+        .unwrap();
+
+    run_shopping_cart_initialize_and_add(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let first_boundary = nth_invocation_boundary(&oplogs, 1);
+
+    debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    let target_worker_id = WorkerId {
+        component_id: worker_id.component_id.clone(),
+        worker_name: "forked-worker-to-diff".to_string(),
+    };
+
+    debug_executor
+        .fork(&target_worker_id, first_boundary)
+        .await
+        .expect("Failed to fork the worker");
+
+    let diff_result = debug_executor
+        .diff_oplog(&target_worker_id)
+        .await
+        .expect("Failed to diff the oplogs");
+
+    let unknown_worker_result = debug_executor
+        .diff_oplog(&WorkerId {
+            component_id: worker_id.component_id.clone(),
+            worker_name: "unknown-worker".to_string(),
+        })
+        .await;
+
+    drop(regular_worker_executor);
+
+    let first_divergent_entry = diff_result
+        .diff
+        .entries
+        .first()
+        .expect("Expected the oplogs to diverge");
+
+    assert_eq!(diff_result.worker_id, worker_id);
+    assert_eq!(diff_result.other_worker_id, target_worker_id);
+    assert_eq!(
+        diff_result.diff.first_divergent_index,
+        Some(first_boundary.next())
+    );
+    assert_eq!(
+        first_divergent_entry.left_index,
+        Some(first_boundary.next())
+    );
+    assert!(first_divergent_entry.left.is_some());
+    assert!(unknown_worker_result.is_err());
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_export_and_import_trace(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .try_start_worker(&component, "shopping-cart")
+        .await
+        .unwrap()
+        .unwrap();
+
+    run_shopping_cart_workflow(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let last_index = oplogs.last().unwrap().oplog_index;
+
+    debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    let export_result = debug_executor
+        .export_trace()
+        .await
+        .expect("Failed to export the trace");
+
+    let imported_worker_id = WorkerId {
+        component_id: worker_id.component_id.clone(),
+        worker_name: "imported-shopping-cart".to_string(),
+    };
+
+    let import_result = debug_executor
+        .import_trace(&imported_worker_id, export_result.trace.clone())
+        .await
+        .expect("Failed to import the trace");
+
+    let second_import_result = debug_executor
+        .import_trace(&imported_worker_id, export_result.trace.clone())
+        .await;
+
+    // The imported worker is played back in a new debug session
+    let mut imported_debug_executor = DebugWorkerExecutorClient::connect(
+        debug_context.http_port(),
+        TokenSecret::new(uuid::Uuid::new_v4()),
+    )
+    .await
+    .expect("Failed to open a new debug session");
+
+    imported_debug_executor
+        .connect(&imported_worker_id)
+        .await
+        .expect("Failed to connect to the imported worker in debug mode");
+
+    let playback_result = imported_debug_executor
+        .playback(last_index, None)
+        .await
+        .expect("Failed to playback the imported worker");
+
+    let diff_result = imported_debug_executor
+        .diff_oplog(&worker_id)
+        .await
+        .expect("Failed to diff the oplogs");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(export_result.worker_id, worker_id);
+    assert_eq!(import_result.worker_id, imported_worker_id);
+    assert!(second_import_result.is_err());
+    assert_eq!(playback_result.current_index, last_index);
+    assert_eq!(diff_result.diff.left_last_index, last_index);
+    assert_eq!(diff_result.diff.right_last_index, last_index);
+    // Only the create entries differ, in the id of the worker
+    assert!(diff_result
+        .diff
+        .entries
+        .iter()
+        .all(|entry| entry.left_index == Some(OplogIndex::INITIAL)));
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_verify_replay(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .try_start_worker(&component, "shopping-cart")
+        .await
+        .unwrap()
+        .unwrap();
+
+    run_shopping_cart_workflow(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let first_boundary = nth_invocation_boundary(&oplogs, 1);
+    let last_index = oplogs.last().unwrap().oplog_index;
+
+    debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    // Verification restarts a worker that has already been partially played back
+    debug_executor
+        .playback(first_boundary, None)
+        .await
+        .expect("Failed to playback the worker in debug mode");
+
+    let verify_result = debug_executor
+        .verify_replay()
+        .await
+        .expect("Failed to verify the replay");
+
+    let current_index = debug_executor
+        .current_index()
+        .await
+        .expect("Failed to get the current index");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(verify_result.worker_id, worker_id);
+    assert_eq!(verify_result.divergence, None);
+    assert_eq!(verify_result.current_index, last_index);
+    assert_eq!(current_index, last_index);
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_time_travel_query(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .try_start_worker(&component, "shopping-cart")
+        .await
+        .unwrap()
+        .unwrap();
+
+    run_shopping_cart_initialize_and_add(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let first_boundary = nth_invocation_boundary(&oplogs, 1);
+    let second_boundary = nth_invocation_boundary(&oplogs, 2);
+
+    debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    // Querying does not require playing back the worker
+    let query_result = debug_executor
+        .time_travel_query(
+            vec![first_boundary, second_boundary],
+            "golem:it/api.{get-cart-contents}",
+            vec![],
+        )
+        .await
+        .expect("Failed to run the time-travel query");
+
+    // Indices in the middle of an invocation are rejected
+    let in_invocation_result = debug_executor
+        .time_travel_query(
+            vec![first_boundary.next()],
+            "golem:it/api.{get-cart-contents}",
+            vec![],
+        )
+        .await;
+
+    let current_index = debug_executor
+        .current_index()
+        .await
+        .expect("Failed to get the current index");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(query_result.worker_id, worker_id);
+    assert_eq!(
+        query_result
+            .results
+            .iter()
+            .map(|entry| entry.oplog_index)
+            .collect::<Vec<_>>(),
+        vec![first_boundary, second_boundary]
+    );
+    assert!(query_result
+        .results
+        .iter()
+        .all(|entry| entry.error.is_none()));
+
+    let cart_sizes = query_result
+        .results
+        .into_iter()
+        .map(|entry| match entry.result.map(|result| result.value) {
+            Some(Value::List(items)) => items.len(),
+            other => panic!("Unexpected cart contents: {other:?}"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(cart_sizes, vec![0, 1]);
+    assert!(in_invocation_result.is_err());
+    assert_eq!(current_index, OplogIndex::NONE);
+}
+
 #[test]
 #[tracing::instrument]
 async fn test_playback_with_overrides(
@@ -723,6 +1075,316 @@ async fn test_playback_with_overrides(
     );
 }
 
+#[test]
+#[tracing::instrument]
+async fn test_playback_stops_at_breakpoint(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .try_start_worker(&component, "shopping-cart")
+        .await
+        .unwrap()
+        .unwrap();
+
+    let shopping_cart_execution_result =
+        run_shopping_cart_workflow(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let checkout_index = oplogs
+        .iter()
+        .find(|entry| match &entry.entry {
+            PublicOplogEntry::ExportedFunctionInvoked(invoked) => {
+                invoked.function_name == "golem:it/api.{checkout}"
+            }
+            _ => false,
+        })
+        .map(|entry| entry.oplog_index)
+        .expect("No checkout invocation found");
+
+    let _ = debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    let breakpoint = Breakpoint::ExportedFunction {
+        function_name: Some("checkout".to_string()),
+    };
+
+    let _ = debug_executor
+        .set_breakpoints(vec![breakpoint.clone()])
+        .await
+        .expect("Failed to set the breakpoints");
+
+    let playback_result = debug_executor
+        .playback(shopping_cart_execution_result.last_add_item_boundary, None)
+        .await
+        .expect("Failed to playback the worker in debug mode");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(playback_result.current_index, checkout_index);
+    assert_eq!(playback_result.breakpoint, Some(breakpoint));
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_step_and_step_over_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .start_worker(&component, "shopping-cart")
+        .await
+        .unwrap();
+
+    run_shopping_cart_initialize_and_add(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let _ = debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    let first_boundary = nth_invocation_boundary(&oplogs, 1);
+    let second_boundary = nth_invocation_boundary(&oplogs, 2);
+
+    let _ = debug_executor
+        .playback(first_boundary, None)
+        .await
+        .expect("Failed to playback the worker in debug mode");
+
+    let step_result = debug_executor
+        .step()
+        .await
+        .expect("Failed to step the worker in debug mode");
+
+    let step_over_result = debug_executor
+        .step_over_invocation()
+        .await
+        .expect("Failed to step over the invocation in debug mode");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(step_result.current_index, first_boundary.next());
+    assert_eq!(step_over_result.current_index, second_boundary);
+    assert_eq!(step_over_result.breakpoint, None);
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_reverse_continue_stops_at_previous_breakpoint(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .start_worker(&component, "shopping-cart")
+        .await
+        .unwrap();
+
+    run_shopping_cart_initialize_and_add(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let invocation_starts = oplogs
+        .iter()
+        .filter(|entry| matches!(entry.entry, PublicOplogEntry::ExportedFunctionInvoked(_)))
+        .map(|entry| entry.oplog_index)
+        .collect::<Vec<_>>();
+
+    let _ = debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    let second_boundary = nth_invocation_boundary(&oplogs, 2);
+
+    let _ = debug_executor
+        .playback(second_boundary, None)
+        .await
+        .expect("Failed to playback the worker in debug mode");
+
+    let breakpoint = Breakpoint::ExportedFunction {
+        function_name: None,
+    };
+
+    let _ = debug_executor
+        .set_breakpoints(vec![breakpoint.clone()])
+        .await
+        .expect("Failed to set the breakpoints");
+
+    let first_result = debug_executor
+        .reverse_continue()
+        .await
+        .expect("Failed to reverse continue the worker in debug mode");
+
+    let second_result = debug_executor
+        .reverse_continue()
+        .await
+        .expect("Failed to reverse continue the worker in debug mode");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(first_result.current_index, invocation_starts[1]);
+    assert_eq!(first_result.breakpoint, Some(breakpoint.clone()));
+    assert_eq!(second_result.current_index, invocation_starts[0]);
+    assert_eq!(second_result.breakpoint, Some(breakpoint));
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_inspect_played_back_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &RegularWorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context =
+        RegularExecutorTestContext::new(last_unique_id, &deps.admin().await.default_project_id);
+    let regular_worker_executor = start_regular_executor(deps, &context)
+        .await
+        .into_admin()
+        .await;
+
+    let debug_context = DebugExecutorTestContext::from(&context);
+    let mut debug_executor = start_debug_executor(deps, &debug_context).await;
+
+    let component = regular_worker_executor
+        .component("shopping-cart")
+        .store()
+        .await;
+
+    let worker_id = regular_worker_executor
+        .start_worker(&component, "shopping-cart")
+        .await
+        .unwrap();
+
+    run_shopping_cart_initialize_and_add(&regular_worker_executor, &worker_id).await;
+
+    let oplogs = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await
+        .unwrap();
+
+    let _ = debug_executor
+        .connect(&worker_id)
+        .await
+        .expect("Failed to connect to the worker in debug mode");
+
+    // Inspecting is not possible before playing back
+    assert!(debug_executor.resources().await.is_err());
+
+    let first_boundary = nth_invocation_boundary(&oplogs, 1);
+
+    let _ = debug_executor
+        .playback(first_boundary, None)
+        .await
+        .expect("Failed to playback the worker in debug mode");
+
+    let resources = debug_executor
+        .resources()
+        .await
+        .expect("Failed to get the resources");
+
+    let memory = debug_executor
+        .memory()
+        .await
+        .expect("Failed to get the memory");
+
+    let memory_contents = debug_executor
+        .read_memory(0, memory.main_memory_size)
+        .await
+        .expect("Failed to read the memory");
+
+    let out_of_bounds_read = debug_executor
+        .read_memory(memory.main_memory_size - 4, 8)
+        .await;
+
+    let cart_contents = debug_executor
+        .call_getter("golem:it/api.{get-cart-contents}", vec![])
+        .await
+        .expect("Failed to call the getter");
+
+    drop(regular_worker_executor);
+
+    assert_eq!(resources.current_index, first_boundary);
+    assert!(resources.resources.is_empty());
+    assert!(memory.total_linear_memory_size > 0);
+    assert!(memory.main_memory_size > 0);
+    assert!(memory.main_memory_size <= memory.total_linear_memory_size);
+    // The cart initialized by the first invocation is in the memory of the worker
+    let memory_contents = base64::engine::general_purpose::STANDARD
+        .decode(memory_contents.contents)
+        .expect("Failed to decode the memory contents");
+    assert_eq!(memory_contents.len() as u64, memory.main_memory_size);
+    assert!(memory_contents
+        .windows(b"test-user-1".len())
+        .any(|window| window == b"test-user-1"));
+    assert!(out_of_bounds_read.is_err());
+    assert_eq!(
+        cart_contents.result.map(|result| result.value),
+        Some(Value::List(vec![]))
+    );
+}
+
 fn nth_invocation_boundary(oplogs: &[PublicOplogEntryWithIndex], n: usize) -> OplogIndex {
     let index = oplogs
         .iter()
@@ -53,7 +53,7 @@ use std::collections::HashSet;
 use std::sync::{Arc, RwLock, Weak};
 use tracing::debug;
 use wasmtime::component::{Component, Instance, Linker, Resource, ResourceAny};
-use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync};
+use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync, WasmBacktrace};
 use wasmtime_wasi::p2::WasiView;
 use wasmtime_wasi_http::WasiHttpView;
 
@@ -572,6 +572,10 @@ impl InvocationHooks for TestWorkerCtx {
         self.durable_ctx.on_invocation_failure(trap_type).await
     }
 
+    fn on_guest_backtrace(&mut self, backtrace: &WasmBacktrace) {
+        self.durable_ctx.on_guest_backtrace(backtrace)
+    }
+
     async fn on_invocation_success(
         &mut self,
         full_function_name: &str,
@@ -1,9 +1,10 @@
 use async_trait::async_trait;
-use golem_api_grpc::proto::golem::common::AccountId;
+use golem_api_grpc::proto::golem::common::{AccountId, ResourceLimits};
 use golem_api_grpc::proto::golem::worker::UpdateMode;
 use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    fork_worker_response, revert_worker_response, ForkWorkerRequest, RevertWorkerRequest,
+    delete_worker_response, fork_worker_response, invoke_and_await_worker_response_typed,
+    revert_worker_response, DeleteWorkerRequest, ForkWorkerRequest, RevertWorkerRequest,
 };
 use golem_common::base_model::OplogIndex;
 use golem_common::model::invocation_context::InvocationContextStack;
@@ -11,7 +12,7 @@ use golem_common::model::{ComponentVersion, IdempotencyKey, OwnedWorkerId, Proje
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use golem_service_base::model::RevertWorkerTarget;
 use golem_test_framework::components::worker_executor::WorkerExecutor;
-use golem_wasm_rpc::{ValueAndType, WitValue};
+use golem_wasm_rpc::{Value, ValueAndType, WitValue};
 use golem_worker_executor::services::worker_proxy::{WorkerProxy, WorkerProxyError};
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
@@ -45,21 +46,67 @@ impl TestWorkerProxy {
 impl WorkerProxy for TestWorkerProxy {
     async fn invoke_and_await(
         &self,
-        _owned_worker_id: &OwnedWorkerId,
-        _idempotency_key: Option<IdempotencyKey>,
-        _function_name: String,
-        _function_params: Vec<WitValue>,
-        _caller_worker_id: WorkerId,
-        _caller_args: Vec<String>,
-        _caller_env: HashMap<String, String>,
-        _caller_wasi_config_vars: BTreeMap<String, String>,
-        _invocation_context_stack: InvocationContextStack,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: Option<IdempotencyKey>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        caller_worker_id: WorkerId,
+        caller_args: Vec<String>,
+        caller_env: HashMap<String, String>,
+        caller_wasi_config_vars: BTreeMap<String, String>,
+        invocation_context_stack: InvocationContextStack,
     ) -> Result<Option<ValueAndType>, WorkerProxyError> {
-        Err(WorkerProxyError::InternalError(
-            WorkerExecutorError::unknown(
-                "Not implemented in tests as debug service is not expected to call invoke and await through proxy",
-            )
-        ))
+        let result = self
+            .worker_executor
+            .client()
+            .await
+            .map_err(|e| WorkerProxyError::InternalError(WorkerExecutorError::from(e)))?
+            .invoke_and_await_worker_typed(workerexecutor::v1::InvokeAndAwaitWorkerRequest {
+                worker_id: Some(owned_worker_id.worker_id().into_target_worker_id().into()),
+                name: function_name,
+                input: function_params
+                    .into_iter()
+                    .map(|param| Value::from(param).into())
+                    .collect(),
+                idempotency_key: idempotency_key.map(|key| key.into()),
+                account_id: Some(AccountId {
+                    name: "test-account".to_string(),
+                }),
+                account_limits: Some(ResourceLimits {
+                    available_fuel: i64::MAX,
+                    max_memory_per_worker: i64::MAX,
+                }),
+                project_id: Some(owned_worker_id.project_id.clone().into()),
+                context: Some(golem_api_grpc::proto::golem::worker::InvocationContext {
+                    parent: Some(caller_worker_id.into()),
+                    args: caller_args,
+                    env: caller_env,
+                    wasi_config_vars: Some(caller_wasi_config_vars.into()),
+                    tracing: Some(invocation_context_stack.into()),
+                }),
+            })
+            .await?
+            .into_inner()
+            .result;
+
+        match result {
+            None => Err(WorkerProxyError::InternalError(
+                WorkerExecutorError::unknown("No result in invoke and await response"),
+            )),
+            Some(invoke_and_await_worker_response_typed::Result::Success(success)) => success
+                .output
+                .map(|output| {
+                    ValueAndType::try_from(output).map_err(|e| {
+                        WorkerProxyError::InternalError(WorkerExecutorError::unknown(format!(
+                            "Failed to parse invocation result value: {e}"
+                        )))
+                    })
+                })
+                .transpose(),
+            Some(invoke_and_await_worker_response_typed::Result::Failure(error)) => Err(
+                WorkerProxyError::InternalError(WorkerExecutorError::try_from(error).unwrap()),
+            ),
+        }
     }
 
     async fn invoke(
@@ -208,6 +255,35 @@ impl WorkerProxy for TestWorkerProxy {
             ),
         }
     }
+
+    async fn delete(&self, worker_id: &WorkerId) -> Result<(), WorkerProxyError> {
+        let project_id = self.project_resolver.get_worker_project(worker_id).await?;
+        let result = self
+            .worker_executor
+            .client()
+            .await
+            .map_err(|e| WorkerProxyError::InternalError(WorkerExecutorError::from(e)))?
+            .delete_worker(DeleteWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                account_id: Some(AccountId {
+                    name: "test-account".to_string(),
+                }),
+                project_id: Some(project_id.into()),
+            })
+            .await?
+            .into_inner()
+            .result;
+
+        match result {
+            None => Err(WorkerProxyError::InternalError(
+                WorkerExecutorError::unknown("No result in delete worker response"),
+            )),
+            Some(delete_worker_response::Result::Success(_)) => Ok(()),
+            Some(delete_worker_response::Result::Failure(error)) => Err(
+                WorkerProxyError::InternalError(WorkerExecutorError::try_from(error).unwrap()),
+            ),
+        }
+    }
 }
 
 #[async_trait]
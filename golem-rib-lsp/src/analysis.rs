@@ -0,0 +1,331 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::component_source::RibComponent;
+use crate::document::Document;
+use crate::protocol::{
+    CompletionItem, CompletionItemKind, Diagnostic, Hover, Location, Position, Range,
+};
+use rib::{
+    BuiltInFunction, Expr, ExprVisitor, FunctionDictionary, FunctionName, GetTypeHint,
+    InferredType, RibCompilationError, RibCompiler, RibCompilerConfig, SourcePosition, TypeName,
+};
+
+/// The result of parsing and type checking a Rib document.
+/// The fully inferred expression is available only if the document has no errors,
+/// and hence hover and go-to-definition work only on a valid Rib script.
+pub struct RibAnalysis {
+    pub document: Document,
+    pub inferred_expr: Option<Expr>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl RibAnalysis {
+    pub fn analyse(document: Document, compiler: &RibCompiler) -> RibAnalysis {
+        let expr = match Expr::from_text(&document.text) {
+            Ok(expr) => expr,
+            Err(error) => {
+                let diagnostic = internal::parse_error_diagnostic(&document, &error);
+
+                return RibAnalysis {
+                    document,
+                    inferred_expr: None,
+                    diagnostics: vec![diagnostic],
+                };
+            }
+        };
+
+        match compiler.infer_types(expr) {
+            Ok(inferred_expr) => RibAnalysis {
+                document,
+                inferred_expr: Some(inferred_expr.get_expr().clone()),
+                diagnostics: vec![],
+            },
+            Err(error) => {
+                let diagnostic = internal::compilation_error_diagnostic(&document, &error);
+
+                RibAnalysis {
+                    document,
+                    inferred_expr: None,
+                    diagnostics: vec![diagnostic],
+                }
+            }
+        }
+    }
+
+    // The type of the innermost expression at the position
+    pub fn hover(&self, position: &Position) -> Option<Hover> {
+        let expr = self.innermost_expr(position, |_| true)?;
+        let range = self.document.to_lsp_range(&expr.source_span());
+        let type_name = internal::type_name(&expr.inferred_type());
+
+        let value = match &expr {
+            Expr::Identifier { variable_id, .. } => {
+                format!("```rib\n{}: {}\n```", variable_id.name(), type_name)
+            }
+            _ => format!("```rib\n{type_name}\n```"),
+        };
+
+        Some(Hover::markdown(range, value))
+    }
+
+    // The `let` binding of the identifier at the position
+    pub fn definition(&self, position: &Position) -> Option<Location> {
+        let identifier = self.innermost_expr(
+            position,
+            |expr| matches!(expr, Expr::Identifier { variable_id, .. } if variable_id.is_local()),
+        )?;
+
+        let Expr::Identifier { variable_id, .. } = identifier else {
+            return None;
+        };
+
+        let mut expr = self.inferred_expr.clone()?;
+        let mut visitor = ExprVisitor::bottom_up(&mut expr);
+
+        while let Some(expr) = visitor.pop_front() {
+            if let Expr::Let {
+                variable_id: let_variable_id,
+                source_span,
+                ..
+            } = expr
+            {
+                if *let_variable_id == variable_id {
+                    return Some(Location {
+                        uri: self.document.uri.clone(),
+                        range: self.document.to_lsp_range(source_span),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    fn innermost_expr(
+        &self,
+        position: &Position,
+        predicate: impl Fn(&Expr) -> bool,
+    ) -> Option<Expr> {
+        let mut expr = self.inferred_expr.clone()?;
+        let mut visitor = ExprVisitor::bottom_up(&mut expr);
+
+        let mut innermost: Option<(Range, Expr)> = None;
+
+        while let Some(expr) = visitor.pop_front() {
+            let range = self.document.to_lsp_range(&expr.source_span());
+
+            if !range.contains(position) || !predicate(expr) {
+                continue;
+            }
+
+            let is_inner = innermost
+                .as_ref()
+                .map(|(innermost_range, _)| {
+                    innermost_range.contains(&range.start) && innermost_range.contains(&range.end)
+                })
+                .unwrap_or(true);
+
+            if is_inner {
+                innermost = Some((range, expr.clone()));
+            }
+        }
+
+        innermost.map(|(_, expr)| expr)
+    }
+}
+
+/// Completion of the worker functions of the components, and the built-in functions of Rib.
+pub fn completion_items(components: &[RibComponent]) -> Vec<CompletionItem> {
+    let mut completion_items = vec![];
+
+    for component in components {
+        let Ok(function_dictionary) = FunctionDictionary::from_exports(&component.exports) else {
+            continue;
+        };
+
+        for (function_name, function_type) in function_dictionary.name_and_types {
+            if matches!(
+                function_name,
+                FunctionName::Variant(_) | FunctionName::Enum(_)
+            ) {
+                continue;
+            }
+
+            let parameters = function_type
+                .parameter_types
+                .iter()
+                .map(internal::type_name)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let detail = match &function_type.return_type {
+                Some(return_type) => format!(
+                    "{}({}) -> {}",
+                    component.dependency_key.component_name,
+                    parameters,
+                    internal::type_name(return_type)
+                ),
+                None => format!(
+                    "{}({})",
+                    component.dependency_key.component_name, parameters
+                ),
+            };
+
+            completion_items.push(CompletionItem {
+                label: function_name.name(),
+                kind: CompletionItemKind::Function,
+                detail: Some(detail),
+            });
+        }
+    }
+
+    for function in BuiltInFunction::all() {
+        completion_items.push(CompletionItem {
+            label: function.qualified_name(),
+            kind: CompletionItemKind::Function,
+            detail: Some("built-in".to_string()),
+        });
+    }
+
+    completion_items
+}
+
+pub fn rib_compiler(components: &[RibComponent]) -> RibCompiler {
+    RibCompiler::new(RibCompilerConfig::new(
+        components
+            .iter()
+            .map(|component| component.to_component_dependency())
+            .collect(),
+        vec![],
+    ))
+}
+
+mod internal {
+    use super::*;
+
+    pub(crate) fn type_name(inferred_type: &InferredType) -> String {
+        TypeName::try_from(inferred_type.clone())
+            .map(|type_name| type_name.to_string())
+            .unwrap_or_else(|_| inferred_type.get_type_hint().to_string())
+    }
+
+    // Parse errors are only available as messages, with the position formatted as
+    // `Parse error at line: 1, column: 9`
+    pub(crate) fn parse_error_diagnostic(document: &Document, error: &str) -> Diagnostic {
+        let position = parse_error_position(error)
+            .map(|position| document.to_lsp_position(&position))
+            .unwrap_or(Position::new(0, 0));
+
+        Diagnostic::error(Range::new(position, document.end_position()), error)
+    }
+
+    pub(crate) fn compilation_error_diagnostic(
+        document: &Document,
+        error: &RibCompilationError,
+    ) -> Diagnostic {
+        match error {
+            RibCompilationError::RibTypeError(type_error) => {
+                let mut message = type_error.cause.clone();
+
+                for detail in &type_error.additional_error_details {
+                    message.push('\n');
+                    message.push_str(detail);
+                }
+
+                for help in &type_error.help_messages {
+                    message.push_str("\nhelp: ");
+                    message.push_str(help);
+                }
+
+                Diagnostic::error(document.to_lsp_range(&type_error.source_span), message)
+            }
+            error => Diagnostic::error(
+                Range::new(Position::new(0, 0), document.end_position()),
+                error.to_string(),
+            ),
+        }
+    }
+
+    fn parse_error_position(error: &str) -> Option<SourcePosition> {
+        let (_, rest) = error.split_once("line: ")?;
+        let (line, rest) = rest.split_once(',')?;
+        let (_, rest) = rest.split_once("column: ")?;
+
+        let column = rest
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>();
+
+        Some(SourcePosition::new(
+            line.trim().parse().ok()?,
+            column.parse().ok()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::analysis::RibAnalysis;
+    use crate::document::Document;
+    use crate::protocol::Position;
+    use rib::RibCompiler;
+
+    fn analyse(text: &str) -> RibAnalysis {
+        RibAnalysis::analyse(
+            Document::new("file:///test.rib", text),
+            &RibCompiler::default(),
+        )
+    }
+
+    #[test]
+    fn test_parse_error_diagnostic() {
+        let analysis = analyse("let x = ;\nx");
+
+        assert_eq!(analysis.diagnostics.len(), 1);
+        assert_eq!(analysis.diagnostics[0].range.start.line, 0);
+        assert!(analysis.inferred_expr.is_none());
+    }
+
+    #[test]
+    fn test_type_error_diagnostic() {
+        let analysis = analyse("let x: u32 = \"foo\";\nx");
+
+        assert_eq!(analysis.diagnostics.len(), 1);
+        assert!(analysis.inferred_expr.is_none());
+    }
+
+    #[test]
+    fn test_hover() {
+        let analysis = analyse("let x: u32 = 1;\nx");
+
+        assert!(analysis.diagnostics.is_empty());
+
+        let hover = analysis.hover(&Position::new(1, 0)).unwrap();
+        assert_eq!(hover.contents.value, "```rib\nx: u32\n```");
+    }
+
+    #[test]
+    fn test_definition() {
+        let analysis = analyse("let x: u32 = 1;\nlet y = x;\ny");
+
+        let location = analysis.definition(&Position::new(1, 8)).unwrap();
+        assert_eq!(location.range.start.line, 0);
+
+        let location = analysis.definition(&Position::new(2, 0)).unwrap();
+        assert_eq!(location.range.start.line, 1);
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Context as _};
+use golem_client::api::{ComponentClient, ComponentClientLive};
+use golem_client::{Context, Security};
+use golem_common::model::component_metadata::ComponentMetadata;
+use golem_wasm_ast::analysis::AnalysedExport;
+use rib::{ComponentDependency, ComponentDependencyKey};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use url::Url;
+use uuid::Uuid;
+
+/// Where the language server loads the metadata of the components from,
+/// that are available to call from the Rib scripts being edited.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentSource {
+    /// A WASM component on the local file system. The component name is the file name without extension.
+    Wasm { path: PathBuf },
+    /// The latest version of a component registered in a component service.
+    ComponentService {
+        url: Url,
+        component_id: Uuid,
+        token: Option<String>,
+    },
+}
+
+/// The metadata of a component needed to analyse Rib scripts.
+#[derive(Debug, Clone)]
+pub struct RibComponent {
+    pub dependency_key: ComponentDependencyKey,
+    pub exports: Vec<AnalysedExport>,
+}
+
+impl RibComponent {
+    pub fn to_component_dependency(&self) -> ComponentDependency {
+        ComponentDependency::new(self.dependency_key.clone(), self.exports.clone())
+    }
+}
+
+pub async fn load_components(sources: &[ComponentSource]) -> anyhow::Result<Vec<RibComponent>> {
+    let mut components = vec![];
+
+    for source in sources {
+        let component = match source {
+            ComponentSource::Wasm { path } => load_from_wasm(path)?,
+            ComponentSource::ComponentService {
+                url,
+                component_id,
+                token,
+            } => load_from_component_service(url, component_id, token.as_deref()).await?,
+        };
+
+        components.push(component);
+    }
+
+    Ok(components)
+}
+
+fn load_from_wasm(path: &Path) -> anyhow::Result<RibComponent> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read component {}", path.display()))?;
+
+    let metadata = ComponentMetadata::analyse_component(&bytes, HashMap::new(), vec![])
+        .map_err(|err| anyhow!("Failed to analyse component {}: {err}", path.display()))?;
+
+    let component_name = path
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| anyhow!("Invalid component path {}", path.display()))?;
+
+    // A local component is not registered anywhere, and hence its id is only
+    // derived from the path to keep it stable across restarts of the server
+    let component_id = Uuid::new_v5(&Uuid::NAMESPACE_URL, path.to_string_lossy().as_bytes());
+
+    Ok(RibComponent {
+        dependency_key: ComponentDependencyKey {
+            component_name,
+            component_id,
+            root_package_name: metadata.root_package_name,
+            root_package_version: metadata.root_package_version,
+        },
+        exports: metadata.exports,
+    })
+}
+
+async fn load_from_component_service(
+    url: &Url,
+    component_id: &Uuid,
+    token: Option<&str>,
+) -> anyhow::Result<RibComponent> {
+    let client = ComponentClientLive {
+        context: Context {
+            client: reqwest::Client::new(),
+            base_url: url.clone(),
+            security_token: token
+                .map(|token| Security::Bearer(token.to_string()))
+                .unwrap_or(Security::Empty),
+        },
+    };
+
+    let component = client
+        .get_latest_component_metadata(component_id)
+        .await
+        .map_err(|err| {
+            anyhow!("Failed to get the metadata of component {component_id}: {err:?}")
+        })?;
+
+    Ok(RibComponent {
+        dependency_key: ComponentDependencyKey {
+            component_name: component.component_name,
+            component_id: *component_id,
+            root_package_name: component.metadata.root_package_name,
+            root_package_version: component.metadata.root_package_version,
+        },
+        exports: component.metadata.exports,
+    })
+}
@@ -0,0 +1,111 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::protocol::{Position, Range};
+use rib::{SourcePosition, SourceSpan};
+
+// A Rib document opened in the editor.
+// The Rib parser tracks positions as one based lines and columns counted in characters,
+// while LSP positions are zero based lines and characters counted in UTF-16 code units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub uri: String,
+    pub text: String,
+}
+
+impl Document {
+    pub fn new(uri: impl AsRef<str>, text: impl AsRef<str>) -> Self {
+        Document {
+            uri: uri.as_ref().to_string(),
+            text: text.as_ref().to_string(),
+        }
+    }
+
+    pub fn to_lsp_position(&self, source_position: &SourcePosition) -> Position {
+        let line = (source_position.line - 1).max(0) as u32;
+        let column = (source_position.column - 1).max(0) as usize;
+
+        let character = self
+            .line(line)
+            .chars()
+            .take(column)
+            .map(|c| c.len_utf16() as u32)
+            .sum();
+
+        Position::new(line, character)
+    }
+
+    pub fn to_source_position(&self, position: &Position) -> SourcePosition {
+        let mut utf16_offset = 0;
+        let mut column = 0;
+
+        for c in self.line(position.line).chars() {
+            if utf16_offset >= position.character {
+                break;
+            }
+
+            utf16_offset += c.len_utf16() as u32;
+            column += 1;
+        }
+
+        SourcePosition::new(position.line as i32 + 1, column + 1)
+    }
+
+    pub fn to_lsp_range(&self, source_span: &SourceSpan) -> Range {
+        let start = SourcePosition::new(source_span.start_line(), source_span.start_column());
+        let end = SourcePosition::new(source_span.end_line(), source_span.end_column());
+
+        Range::new(self.to_lsp_position(&start), self.to_lsp_position(&end))
+    }
+
+    pub fn end_position(&self) -> Position {
+        let line = self.text.lines().count().saturating_sub(1) as u32;
+        let character = self.line(line).encode_utf16().count() as u32;
+
+        Position::new(line, character)
+    }
+
+    fn line(&self, line: u32) -> &str {
+        self.text.lines().nth(line as usize).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::document::Document;
+    use crate::protocol::Position;
+    use rib::SourcePosition;
+
+    #[test]
+    fn test_position_conversion() {
+        let document = Document::new("file:///test.rib", "let x = \"é😀\";\n  x");
+
+        let position = document.to_lsp_position(&SourcePosition::new(1, 12));
+        assert_eq!(position, Position::new(0, 12));
+
+        let position = document.to_lsp_position(&SourcePosition::new(2, 3));
+        assert_eq!(position, Position::new(1, 2));
+
+        let source_position = document.to_source_position(&Position::new(0, 12));
+        assert_eq!((source_position.line, source_position.column), (1, 12));
+    }
+
+    #[test]
+    fn test_end_position() {
+        let document = Document::new("file:///test.rib", "let x = 1;\nx + 1");
+        assert_eq!(document.end_position(), Position::new(1, 5));
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Parser;
+use golem_rib_lsp::{load_components, ComponentSource, RibLanguageServer};
+use std::path::PathBuf;
+use tracing_subscriber::EnvFilter;
+use url::Url;
+use uuid::Uuid;
+
+/// Language server for Rib, communicating over stdin and stdout
+#[derive(Parser, Debug)]
+#[command(name = "golem-rib-lsp", version)]
+struct Args {
+    /// Path to a WASM component whose exports can be called from the Rib scripts
+    #[arg(long = "component")]
+    components: Vec<PathBuf>,
+
+    /// URL of the component service to load the components from
+    #[arg(long)]
+    component_service_url: Option<Url>,
+
+    /// Id of a component in the component service whose exports can be called from the Rib scripts
+    #[arg(long = "component-id", requires = "component_service_url")]
+    component_ids: Vec<Uuid>,
+
+    /// Token to authenticate with the component service
+    #[arg(long, requires = "component_service_url")]
+    token: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // stdout is reserved for the protocol messages
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let args = Args::parse();
+
+    let mut sources = args
+        .components
+        .into_iter()
+        .map(|path| ComponentSource::Wasm { path })
+        .collect::<Vec<_>>();
+
+    if let Some(url) = args.component_service_url {
+        sources.extend(args.component_ids.into_iter().map(|component_id| {
+            ComponentSource::ComponentService {
+                url: url.clone(),
+                component_id,
+                token: args.token.clone(),
+            }
+        }));
+    }
+
+    let components = load_components(&sources).await?;
+
+    let mut server = RibLanguageServer::new(components);
+    server.run(&mut std::io::stdin().lock(), &mut std::io::stdout().lock())?;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+// The subset of the Language Server Protocol (LSP) types used by the Rib language server.
+// Positions are zero based, and the character offset is in UTF-16 code units as per the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+impl Position {
+    pub fn new(line: u32, character: u32) -> Self {
+        Position { line, character }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Range {
+    pub fn new(start: Position, end: Position) -> Self {
+        Range { start, end }
+    }
+
+    pub fn contains(&self, position: &Position) -> bool {
+        self.start <= *position && *position <= self.end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    pub uri: String,
+    pub range: Range,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+impl From<DiagnosticSeverity> for u8 {
+    fn from(value: DiagnosticSeverity) -> Self {
+        match value {
+            DiagnosticSeverity::Error => 1,
+            DiagnosticSeverity::Warning => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for DiagnosticSeverity {
+    type Error = String;
+
+    // `Self::Error` would be ambiguous with the `Error` severity
+    fn try_from(value: u8) -> Result<Self, String> {
+        match value {
+            1 => Ok(DiagnosticSeverity::Error),
+            2 => Ok(DiagnosticSeverity::Warning),
+            _ => Err(format!("Unsupported diagnostic severity {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: DiagnosticSeverity,
+    pub source: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(range: Range, message: impl AsRef<str>) -> Self {
+        Diagnostic {
+            range,
+            severity: DiagnosticSeverity::Error,
+            source: "rib".to_string(),
+            message: message.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublishDiagnosticsParams {
+    pub uri: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarkupContent {
+    pub kind: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hover {
+    pub contents: MarkupContent,
+    pub range: Range,
+}
+
+impl Hover {
+    pub fn markdown(range: Range, value: impl AsRef<str>) -> Self {
+        Hover {
+            contents: MarkupContent {
+                kind: "markdown".to_string(),
+                value: value.as_ref().to_string(),
+            },
+            range,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum CompletionItemKind {
+    Function,
+    Module,
+}
+
+impl From<CompletionItemKind> for u8 {
+    fn from(value: CompletionItemKind) -> Self {
+        match value {
+            CompletionItemKind::Function => 3,
+            CompletionItemKind::Module => 9,
+        }
+    }
+}
+
+impl TryFrom<u8> for CompletionItemKind {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            3 => Ok(CompletionItemKind::Function),
+            9 => Ok(CompletionItemKind::Module),
+            _ => Err(format!("Unsupported completion item kind {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: CompletionItemKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextDocumentIdentifier {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentItem {
+    pub uri: String,
+    pub language_id: String,
+    pub version: i32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocumentParams {
+    pub text_document: TextDocumentItem,
+}
+
+// The server only supports full document sync, and hence every change holds the whole text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextDocumentContentChangeEvent {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+    pub content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentPositionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+// JSON-RPC error codes used in responses
+pub mod error_codes {
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_REQUEST: i64 = -32600;
+}
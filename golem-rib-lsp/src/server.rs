@@ -0,0 +1,339 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::analysis::{completion_items, rib_compiler, RibAnalysis};
+use crate::component_source::RibComponent;
+use crate::document::Document;
+use crate::protocol::{
+    error_codes, CompletionItem, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, PublishDiagnosticsParams, TextDocumentPositionParams,
+};
+use crate::transport::{read_message, write_message};
+use rib::RibCompiler;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io;
+use std::io::{BufRead, Write};
+use tracing::{debug, warn};
+
+/// A language server for Rib, speaking the Language Server Protocol over any reader and writer
+/// (usually stdin and stdout). Documents are analysed as a whole on every change,
+/// against the components the server is started with.
+pub struct RibLanguageServer {
+    compiler: RibCompiler,
+    completion_items: Vec<CompletionItem>,
+    documents: HashMap<String, RibAnalysis>,
+    shutdown_requested: bool,
+}
+
+impl RibLanguageServer {
+    pub fn new(components: Vec<RibComponent>) -> Self {
+        RibLanguageServer {
+            compiler: rib_compiler(&components),
+            completion_items: completion_items(&components),
+            documents: HashMap::new(),
+            shutdown_requested: false,
+        }
+    }
+
+    // Runs until the client sends `exit` or closes the input stream
+    pub fn run(&mut self, reader: &mut impl BufRead, writer: &mut impl Write) -> io::Result<()> {
+        while let Some(message) = read_message(reader)? {
+            if message.get("method").and_then(Value::as_str) == Some("exit") {
+                debug!(
+                    "Exit requested, shutdown requested before: {}",
+                    self.shutdown_requested
+                );
+                break;
+            }
+
+            for response in self.handle_message(message) {
+                write_message(writer, &response)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Handles a request or notification, and returns the messages to send back to the client
+    pub fn handle_message(&mut self, message: Value) -> Vec<Value> {
+        let id = message.get("id").cloned();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return id
+                .map(|id| {
+                    vec![error_response(
+                        id,
+                        error_codes::INVALID_REQUEST,
+                        "Missing method",
+                    )]
+                })
+                .unwrap_or_default();
+        };
+
+        debug!("Received {method}");
+
+        match (method, id) {
+            ("initialize", Some(id)) => vec![response(id, self.capabilities())],
+            ("shutdown", Some(id)) => {
+                self.shutdown_requested = true;
+                vec![response(id, Value::Null)]
+            }
+            ("textDocument/didOpen", None) => {
+                match parse_params::<DidOpenTextDocumentParams>(params) {
+                    Ok(params) => self.update_document(Document::new(
+                        params.text_document.uri,
+                        params.text_document.text,
+                    )),
+                    Err(err) => invalid_notification(method, err),
+                }
+            }
+            ("textDocument/didChange", None) => {
+                match parse_params::<DidChangeTextDocumentParams>(params) {
+                    Ok(params) => match params.content_changes.into_iter().last() {
+                        Some(change) => self
+                            .update_document(Document::new(params.text_document.uri, change.text)),
+                        None => vec![],
+                    },
+                    Err(err) => invalid_notification(method, err),
+                }
+            }
+            ("textDocument/didClose", None) => {
+                match parse_params::<DidCloseTextDocumentParams>(params) {
+                    Ok(params) => {
+                        self.documents.remove(&params.text_document.uri);
+                        vec![publish_diagnostics(PublishDiagnosticsParams {
+                            uri: params.text_document.uri,
+                            diagnostics: vec![],
+                        })]
+                    }
+                    Err(err) => invalid_notification(method, err),
+                }
+            }
+            ("textDocument/hover", Some(id)) => {
+                self.handle_position_request(id, params, |analysis, params| {
+                    json!(analysis.hover(&params.position))
+                })
+            }
+            ("textDocument/definition", Some(id)) => {
+                self.handle_position_request(id, params, |analysis, params| {
+                    json!(analysis.definition(&params.position))
+                })
+            }
+            ("textDocument/completion", Some(id)) => {
+                vec![response(id, json!(self.completion_items))]
+            }
+            (_, Some(id)) => vec![error_response(
+                id,
+                error_codes::METHOD_NOT_FOUND,
+                format!("Method {method} is not supported"),
+            )],
+            // Any other notification, including `initialized`, requires no response
+            (_, None) => vec![],
+        }
+    }
+
+    fn capabilities(&self) -> Value {
+        json!({
+            "capabilities": {
+                "textDocumentSync": 1,
+                "hoverProvider": true,
+                "definitionProvider": true,
+                "completionProvider": {
+                    "triggerCharacters": ["."]
+                }
+            },
+            "serverInfo": {
+                "name": "golem-rib-lsp",
+                "version": env!("CARGO_PKG_VERSION")
+            }
+        })
+    }
+
+    fn update_document(&mut self, document: Document) -> Vec<Value> {
+        let uri = document.uri.clone();
+        let analysis = RibAnalysis::analyse(document, &self.compiler);
+
+        let notification = publish_diagnostics(PublishDiagnosticsParams {
+            uri: uri.clone(),
+            diagnostics: analysis.diagnostics.clone(),
+        });
+
+        self.documents.insert(uri, analysis);
+
+        vec![notification]
+    }
+
+    fn handle_position_request(
+        &self,
+        id: Value,
+        params: Value,
+        f: impl Fn(&RibAnalysis, &TextDocumentPositionParams) -> Value,
+    ) -> Vec<Value> {
+        match parse_params::<TextDocumentPositionParams>(params) {
+            Ok(params) => {
+                let result = self
+                    .documents
+                    .get(&params.text_document.uri)
+                    .map(|analysis| f(analysis, &params))
+                    .unwrap_or(Value::Null);
+
+                vec![response(id, result)]
+            }
+            Err(err) => vec![error_response(id, error_codes::INVALID_PARAMS, err)],
+        }
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, String> {
+    serde_json::from_value(params).map_err(|err| format!("Invalid params: {err}"))
+}
+
+fn invalid_notification(method: &str, err: String) -> Vec<Value> {
+    warn!("Ignoring {method}: {err}");
+    vec![]
+}
+
+fn response(id: Value, result: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "result": result
+    })
+}
+
+fn error_response(id: Value, code: i64, message: impl AsRef<str>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": code,
+            "message": message.as_ref()
+        }
+    })
+}
+
+fn publish_diagnostics(params: PublishDiagnosticsParams) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": params
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::server::RibLanguageServer;
+    use crate::transport::{read_message, write_message};
+    use serde_json::{json, Value};
+    use std::io::Cursor;
+
+    fn did_open(text: &str) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": {
+                    "uri": "file:///test.rib",
+                    "languageId": "rib",
+                    "version": 1,
+                    "text": text
+                }
+            }
+        })
+    }
+
+    fn position_request(id: u64, method: &str, line: u32, character: u32) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": {
+                "textDocument": { "uri": "file:///test.rib" },
+                "position": { "line": line, "character": character }
+            }
+        })
+    }
+
+    #[test]
+    fn test_diagnostics_on_open() {
+        let mut server = RibLanguageServer::new(vec![]);
+
+        let messages = server.handle_message(did_open("let x: u32 = \"foo\";\nx"));
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0]["method"], "textDocument/publishDiagnostics");
+        assert_eq!(
+            messages[0]["params"]["diagnostics"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(messages[0]["params"]["diagnostics"][0]["severity"], 1);
+    }
+
+    #[test]
+    fn test_hover_and_definition() {
+        let mut server = RibLanguageServer::new(vec![]);
+
+        let messages = server.handle_message(did_open("let x: u32 = 1;\nx"));
+        assert_eq!(messages[0]["params"]["diagnostics"], json!([]));
+
+        let messages = server.handle_message(position_request(1, "textDocument/hover", 1, 0));
+        assert_eq!(messages[0]["id"], 1);
+        assert_eq!(
+            messages[0]["result"]["contents"]["value"],
+            "```rib\nx: u32\n```"
+        );
+
+        let messages = server.handle_message(position_request(2, "textDocument/definition", 1, 0));
+        assert_eq!(messages[0]["result"]["uri"], "file:///test.rib");
+        assert_eq!(messages[0]["result"]["range"]["start"]["line"], 0);
+    }
+
+    #[test]
+    fn test_run_until_exit() {
+        let mut input = vec![];
+
+        for message in [
+            json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+            json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+            json!({"jsonrpc": "2.0", "id": 2, "method": "unknown"}),
+            json!({"jsonrpc": "2.0", "id": 3, "method": "shutdown"}),
+            json!({"jsonrpc": "2.0", "method": "exit"}),
+        ] {
+            write_message(&mut input, &message).unwrap();
+        }
+
+        let mut output = vec![];
+        RibLanguageServer::new(vec![])
+            .run(&mut Cursor::new(input), &mut output)
+            .unwrap();
+
+        let mut output = Cursor::new(output);
+        let initialize = read_message(&mut output).unwrap().unwrap();
+        let unknown = read_message(&mut output).unwrap().unwrap();
+        let shutdown = read_message(&mut output).unwrap().unwrap();
+
+        assert_eq!(initialize["result"]["capabilities"]["hoverProvider"], true);
+        assert_eq!(unknown["error"]["code"], -32601);
+        assert_eq!(shutdown["result"], Value::Null);
+        assert_eq!(read_message(&mut output).unwrap(), None);
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde_json::Value;
+use std::io;
+use std::io::{BufRead, Read, Write};
+
+// Every LSP message is a JSON-RPC message prefixed with headers,
+// of which only `Content-Length` is required. Example:
+//
+// Content-Length: 52\r\n
+// \r\n
+// {"jsonrpc":"2.0","id":1,"method":"shutdown"}
+//
+// Returns `None` once the client closes the input stream
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                let length = value.trim().parse::<usize>().map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Invalid Content-Length header {header}: {err}"),
+                    )
+                })?;
+
+                content_length = Some(length);
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Missing Content-Length header".to_string(),
+        )
+    })?;
+
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = serde_json::to_string(message)?;
+
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_write_and_read_message() {
+        let message = json!({"jsonrpc": "2.0", "id": 1, "method": "shutdown"});
+
+        let mut buffer = vec![];
+        write_message(&mut buffer, &message).unwrap();
+        write_message(&mut buffer, &message).unwrap();
+
+        let mut reader = Cursor::new(buffer);
+
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message.clone()));
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_message_without_content_length() {
+        let mut reader = Cursor::new(b"Content-Type: application/json\r\n\r\n{}".to_vec());
+
+        assert!(read_message(&mut reader).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Parser;
+use colored::Colorize;
+use golem_rib_repl::{load_component, RibTestComponent, RibTestRunner, RibTestSuite};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Runs the tests of Rib scripts, mocking the calls to component functions
+#[derive(Parser, Debug)]
+#[command(name = "golem-rib-test", version)]
+struct Args {
+    /// Test files to run
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Path to a WASM component whose exports can be called from the scripts, in addition to the ones in the test files
+    #[arg(long = "component")]
+    components: Vec<PathBuf>,
+
+    /// Runs only the tests whose name contains this
+    #[arg(long)]
+    filter: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<ExitCode> {
+    let args = Args::parse();
+
+    let mut passed = 0;
+    let mut failures = vec![];
+
+    for file in &args.files {
+        let suite = RibTestSuite::from_file(file)?;
+
+        let component_dependencies = suite
+            .components
+            .iter()
+            .cloned()
+            .chain(args.components.iter().cloned().map(RibTestComponent::wasm))
+            .map(|component| load_component(&component))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let runner = RibTestRunner::new(component_dependencies);
+
+        let tests = suite
+            .tests
+            .iter()
+            .filter(|test| match &args.filter {
+                Some(filter) => test.name.contains(filter.as_str()),
+                None => true,
+            })
+            .collect::<Vec<_>>();
+
+        println!("\nrunning {} tests from {}", tests.len(), file.display());
+
+        for test in tests {
+            let result = runner.run(test).await;
+
+            if result.passed() {
+                println!("test {} ... {}", result.name, "ok".green());
+                passed += 1;
+            } else {
+                println!("test {} ... {}", result.name, "FAILED".red());
+                failures.push((file, result));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:");
+
+        for (file, result) in &failures {
+            println!(
+                "\n---- {} ({}) ----\n{}",
+                result.name,
+                file.display(),
+                result.outcome
+            );
+        }
+    }
+
+    let status = if failures.is_empty() {
+        "ok".green()
+    } else {
+        "FAILED".red()
+    };
+
+    println!(
+        "\ntest result: {status}. {passed} passed; {} failed",
+        failures.len()
+    );
+
+    if failures.is_empty() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
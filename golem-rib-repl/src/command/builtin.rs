@@ -6,8 +6,9 @@ use crossterm::{
     terminal::{Clear as TermClear, ClearType},
 };
 use golem_wasm_ast::analysis::AnalysedType;
-use rib::{CompilerOutput, ComponentDependencies, Expr, RibCompilationError};
+use rib::{CompilerOutput, ComponentDependencies, Expr, RibCompilationError, RibFormatConfig};
 use std::io::stdout;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(about = "Display type of a rib expression")]
@@ -154,3 +155,125 @@ impl Command for Exports {
             .print_rib_compilation_error(&error)
     }
 }
+
+#[derive(Parser, Debug)]
+#[command(about = "Format a rib expression, or the rib script of the current session")]
+pub struct FormatInput {
+    /// Number of spaces per level of indentation
+    #[arg(long, default_value_t = 4)]
+    pub indent: usize,
+
+    /// Maximum width of a line
+    #[arg(long, default_value_t = 100)]
+    pub width: usize,
+
+    /// Rib expression. If not provided, the rib script of the current session is formatted.
+    /// Multiline rib expressions are not supported.
+    pub expr: Vec<String>,
+}
+
+pub struct Format;
+
+impl Command for Format {
+    type Input = FormatInput;
+    type Output = String;
+    type InputParseError = clap::Error;
+    type ExecutionError = RibCompilationError;
+
+    fn parse(
+        &self,
+        input: &str,
+        _repl_context: &ReplContext,
+    ) -> Result<Self::Input, Self::InputParseError> {
+        parse_with_clap::<FormatInput>(self.name().as_str(), input)
+    }
+
+    fn execute(
+        &self,
+        input: Self::Input,
+        repl_context: &mut ReplContext,
+    ) -> Result<Self::Output, Self::ExecutionError> {
+        let rib_script = if input.expr.is_empty() {
+            repl_context.get_rib_script().as_text()
+        } else {
+            input.expr.join(" ")
+        };
+
+        if rib_script.trim().is_empty() {
+            return Ok(String::new());
+        }
+
+        let config = RibFormatConfig {
+            indent_width: input.indent,
+            max_width: input.width,
+        };
+
+        rib::format_source(rib_script, &config).map_err(RibCompilationError::InvalidSyntax)
+    }
+
+    fn print_output(&self, output: Self::Output, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_rib_script(&output);
+    }
+
+    fn print_input_parse_error(&self, error: Self::InputParseError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_clap_parse_error(&error);
+    }
+
+    fn print_execution_error(&self, error: Self::ExecutionError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_rib_compilation_error(&error);
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Save the rib statements of the current session to a file, with the results of the function calls they made"
+)]
+pub struct SaveInput {
+    /// Path to the file. The saved script can be run using the non-interactive mode of the REPL.
+    #[arg(required = true)]
+    pub file: PathBuf,
+}
+
+pub struct Save;
+
+impl Command for Save {
+    type Input = SaveInput;
+    type Output = PathBuf;
+    type InputParseError = clap::Error;
+    type ExecutionError = std::io::Error;
+
+    fn parse(
+        &self,
+        input: &str,
+        _repl_context: &ReplContext,
+    ) -> Result<Self::Input, Self::InputParseError> {
+        parse_with_clap::<SaveInput>(self.name().as_str(), input)
+    }
+
+    fn execute(
+        &self,
+        input: Self::Input,
+        repl_context: &mut ReplContext,
+    ) -> Result<Self::Output, Self::ExecutionError> {
+        std::fs::write(&input.file, repl_context.get_session_script())?;
+        Ok(input.file)
+    }
+
+    fn print_output(&self, output: Self::Output, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_custom_message(&format!("Saved the session to {}", output.display()));
+    }
+
+    fn print_input_parse_error(&self, error: Self::InputParseError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_clap_parse_error(&error);
+    }
+
+    fn print_execution_error(&self, error: Self::ExecutionError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_custom_error(&format!("Failed to save the session: {error}"));
+    }
+}
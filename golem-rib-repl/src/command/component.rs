@@ -0,0 +1,69 @@
+use crate::parse_with_clap;
+use clap::Parser;
+use std::path::PathBuf;
+
+// Unlike the commands in the registry, the commands changing the components of the session
+// are executed by the REPL itself, as loading a component is asynchronous and affects
+// the compilation of the whole session.
+pub(crate) enum ComponentCommand {
+    Load(LoadInput),
+    Unload(UnloadInput),
+    Reload(ReloadInput),
+}
+
+impl ComponentCommand {
+    pub(crate) const NAMES: [&'static str; 3] = ["load", "unload", "reload"];
+
+    pub(crate) fn parse(command_name: &str, input: &str) -> Option<Result<Self, clap::Error>> {
+        let command = match command_name {
+            "load" => parse_with_clap::<LoadInput>(command_name, input).map(ComponentCommand::Load),
+            "unload" => {
+                parse_with_clap::<UnloadInput>(command_name, input).map(ComponentCommand::Unload)
+            }
+            "reload" => {
+                parse_with_clap::<ReloadInput>(command_name, input).map(ComponentCommand::Reload)
+            }
+            _ => return None,
+        };
+
+        Some(command)
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Load a component from a WASM file, or a component deployed in the component service"
+)]
+pub(crate) struct LoadInput {
+    /// Path to the WASM file of the component
+    #[arg(required_unless_present = "component", conflicts_with = "component")]
+    pub path: Option<PathBuf>,
+
+    /// Name of the component loaded from a file. Defaults to the file name without extension.
+    #[arg(long, requires = "path")]
+    pub name: Option<String>,
+
+    /// Name of the deployed component
+    #[arg(long)]
+    pub component: Option<String>,
+
+    /// Version of the deployed component. Defaults to the latest version.
+    #[arg(long, requires = "component")]
+    pub version: Option<u64>,
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Unload a component that is not used by the rib script of the current session")]
+pub(crate) struct UnloadInput {
+    /// Name of the component
+    pub component_name: String,
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Load the components again from where they were loaded from, picking up their changes"
+)]
+pub(crate) struct ReloadInput {
+    /// Name of the component. If not provided, all the components are reloaded.
+    pub component_name: Option<String>,
+}
@@ -1,10 +1,12 @@
 pub use clap_parser::*;
+pub(crate) use component::*;
 use convert_case::{Case, Casing};
 pub use registry::*;
 pub use untyped::*;
 
 mod builtin;
 mod clap_parser;
+mod component;
 mod registry;
 mod untyped;
 
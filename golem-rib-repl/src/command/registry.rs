@@ -1,4 +1,4 @@
-use crate::command::builtin::{Clear, Exports, TypeInfo};
+use crate::command::builtin::{Clear, Exports, Format, Save, TypeInfo};
 use crate::UntypedCommand;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -14,6 +14,8 @@ impl CommandRegistry {
         registry.register(TypeInfo);
         registry.register(Clear);
         registry.register(Exports);
+        registry.register(Format);
+        registry.register(Save);
         registry
     }
 
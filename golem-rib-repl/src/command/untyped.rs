@@ -1,11 +1,30 @@
 use crate::rib_context::ReplContext;
 use crate::Command;
+use std::fmt::{Display, Formatter};
 
 pub trait UntypedCommand {
-    fn run(&self, input: &str, repl_context: &mut ReplContext);
+    /// Parses and executes the command, printing its output or its error.
+    /// Returns an error if the input or the execution of the command failed, after printing it.
+    fn run(&self, input: &str, repl_context: &mut ReplContext) -> Result<(), CommandFailure>;
     fn command_name(&self) -> String;
 }
 
+/// The reason a command failed, its details being printed by the command itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandFailure {
+    InvalidInput,
+    ExecutionFailed,
+}
+
+impl Display for CommandFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandFailure::InvalidInput => write!(f, "invalid input"),
+            CommandFailure::ExecutionFailed => write!(f, "execution failed"),
+        }
+    }
+}
+
 impl<T> UntypedCommand for T
 where
     T: Command,
@@ -14,13 +33,22 @@ where
     T::InputParseError: 'static,
     T::ExecutionError: 'static,
 {
-    fn run(&self, input: &str, repl_context: &mut ReplContext) {
+    fn run(&self, input: &str, repl_context: &mut ReplContext) -> Result<(), CommandFailure> {
         match self.parse(input, repl_context) {
             Ok(input) => match self.execute(input, repl_context) {
-                Ok(output) => self.print_output(output, repl_context),
-                Err(e) => self.print_execution_error(e, repl_context),
+                Ok(output) => {
+                    self.print_output(output, repl_context);
+                    Ok(())
+                }
+                Err(e) => {
+                    self.print_execution_error(e, repl_context);
+                    Err(CommandFailure::ExecutionFailed)
+                }
             },
-            Err(e) => self.print_input_parse_error(e, repl_context),
+            Err(e) => {
+                self.print_input_parse_error(e, repl_context);
+                Err(CommandFailure::InvalidInput)
+            }
         }
     }
 
@@ -43,12 +43,19 @@ pub fn compile_rib_script(
     let byte_code = RibByteCode::from_expr(&inferred_expr)
         .map_err(|err| RibCompilationError::ByteCodeGenerationFail(Box::new(err)))?;
 
+    // A variable bound in the REPL is usually used only in the expressions entered later
+    let warnings = lint(inferred_expr.get_expr())
+        .into_iter()
+        .filter(|warning| warning.kind != RibWarningKind::UnusedVariable)
+        .collect();
+
     Ok(ReplCompilerOutput {
         rib_byte_code: byte_code,
         instance_variables,
         identifiers,
         variants,
         enums,
+        warnings,
     })
 }
 
@@ -59,6 +66,7 @@ pub struct ReplCompilerOutput {
     pub identifiers: Vec<VariableId>,
     pub variants: Vec<TypeVariant>,
     pub enums: Vec<TypeEnum>,
+    pub warnings: Vec<RibWarning>,
 }
 
 #[derive(Default, Clone)]
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::anyhow;
 use async_trait::async_trait;
 use rib::ComponentDependency;
 use std::path::Path;
@@ -26,9 +27,8 @@ pub trait RibDependencyManager {
     /// In the future, Rib may support multiple components.
     async fn get_dependencies(&self) -> anyhow::Result<ReplComponentDependencies>;
 
-    /// Deploys a specific component if the REPL was started with a reference to it.
-    ///
-    /// Currently, dynamic component loading is not supported.
+    /// Deploys a specific component, either because the REPL was started with a reference to it,
+    /// or because it is loaded using `:load <path>` (or reloaded using `:reload`) during the session.
     ///
     /// # Arguments
     ///
@@ -43,6 +43,24 @@ pub trait RibDependencyManager {
         source_path: &Path,
         component_name: String,
     ) -> anyhow::Result<ComponentDependency>;
+
+    /// Gets a component that is already deployed, when it is loaded using
+    /// `:load --component <name> [--version <version>]` during the session.
+    ///
+    /// # Arguments
+    ///
+    /// * `component_name` - The name of the deployed component.
+    /// * `version` - The version of the component. If `None`, the latest version is used.
+    async fn get_deployed_component(
+        &self,
+        component_name: String,
+        version: Option<u64>,
+    ) -> anyhow::Result<ComponentDependency> {
+        let _ = version;
+        Err(anyhow!(
+            "Loading the deployed component {component_name} is not supported"
+        ))
+    }
 }
 
 pub struct ReplComponentDependencies {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::repl_state::ReplState;
+use crate::repl_state::{ReplInvocation, ReplState};
 use async_trait::async_trait;
 use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::ValueAndType;
@@ -76,15 +76,17 @@ impl RibComponentFunctionInvoke for ReplRibFunctionInvoke {
         match self.get_cached_result(instruction_id) {
             Some(result) => Ok(result),
             None => {
+                let worker_name = worker_name.map(|x| x.0);
+
                 let rib_invocation_result = self
                     .repl_state
                     .worker_function_invoke()
                     .invoke(
                         component_dependency.component_id,
                         component_dependency.component_name.as_str(),
-                        worker_name.map(|x| x.0),
+                        worker_name.clone(),
                         function_name.0.as_str(),
-                        args.0,
+                        args.0.clone(),
                         return_type,
                     )
                     .await;
@@ -94,6 +96,13 @@ impl RibComponentFunctionInvoke for ReplRibFunctionInvoke {
                         self.repl_state
                             .update_cache(instruction_id.clone(), result.clone());
 
+                        self.repl_state.record_invocation(ReplInvocation {
+                            worker_name,
+                            function_name: function_name.0,
+                            args: args.0,
+                            result: result.clone(),
+                        });
+
                         Ok(result)
                     }
                     Err(err) => Err(err.into()),
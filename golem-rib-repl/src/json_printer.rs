@@ -0,0 +1,109 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ReplBootstrapError, ReplPrinter};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::json::ValueAndTypeJsonExtensions;
+use golem_wasm_rpc::print_value_and_type;
+use rib::{ComponentDependencies, RibCompilationError, RibResult, RibRuntimeError, RibWarning};
+use serde_json::{json, Value as JsonValue};
+
+/// A printer writing a JSON object per line to stdout, to be used when the output
+/// of the REPL is processed by other tools, such as when running a script non-interactively.
+///
+/// Results are written as `{"result": <value>}` and errors as `{"error": {"kind": <kind>, "message": <message>}}`.
+#[derive(Clone)]
+pub struct JsonReplPrinter;
+
+impl JsonReplPrinter {
+    fn print_error(kind: &str, message: String) {
+        print_json(json!({ "error": { "kind": kind, "message": message } }));
+    }
+}
+
+impl ReplPrinter for JsonReplPrinter {
+    fn print_bootstrap_error(&self, error: &ReplBootstrapError) {
+        JsonReplPrinter::print_error("bootstrap", error.to_string());
+    }
+
+    fn print_clap_parse_error(&self, error: &clap::Error) {
+        JsonReplPrinter::print_error("command", error.to_string());
+    }
+
+    fn print_custom_error(&self, error: &str) {
+        JsonReplPrinter::print_error("custom", error.to_string());
+    }
+
+    fn print_custom_message(&self, message: &str) {
+        print_json(json!({ "message": message }));
+    }
+
+    fn print_components_and_exports(&self, exports: &ComponentDependencies) {
+        let components = exports
+            .dependencies
+            .keys()
+            .map(|key| {
+                json!({
+                    "componentName": key.component_name,
+                    "componentId": key.component_id,
+                    "rootPackageName": key.root_package_name,
+                    "rootPackageVersion": key.root_package_version,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        print_json(json!({ "components": components }));
+    }
+
+    fn print_rib_compilation_error(&self, error: &RibCompilationError) {
+        JsonReplPrinter::print_error("compilation", error.to_string());
+    }
+
+    fn print_rib_result(&self, result: &RibResult) {
+        let value = match result {
+            RibResult::Unit => JsonValue::Null,
+            // Resource handles and other values that cannot be represented in JSON are written in the WAVE format
+            RibResult::Val(value_and_type) => value_and_type.to_json_value().unwrap_or_else(|_| {
+                JsonValue::String(
+                    print_value_and_type(value_and_type)
+                        .unwrap_or_else(|_| format!("{:?}", value_and_type.value)),
+                )
+            }),
+        };
+
+        print_json(json!({ "result": value }));
+    }
+
+    fn print_rib_script(&self, rib_script: &str) {
+        print_json(json!({ "script": rib_script }));
+    }
+
+    fn print_rib_warning(&self, warning: &RibWarning) {
+        print_json(json!({ "warning": warning.message }));
+    }
+
+    fn print_rib_runtime_error(&self, error: &RibRuntimeError) {
+        JsonReplPrinter::print_error("runtime", error.to_string());
+    }
+
+    fn print_wasm_value_type(&self, analysed_type: &AnalysedType) {
+        print_json(json!({
+            "type": wasm_wave::wasm::DisplayType(analysed_type).to_string()
+        }));
+    }
+}
+
+fn print_json(value: JsonValue) {
+    println!("{value}");
+}
@@ -17,18 +17,23 @@
 pub use command::*;
 pub use dependency_manager::*;
 pub use invoke::*;
+pub use json_printer::*;
 pub use raw::*;
 pub use repl_bootstrap_error::*;
 pub use repl_printer::*;
 pub use rib_context::*;
 pub use rib_execution_error::*;
 pub use rib_repl::*;
+pub use rib_script_error::*;
+pub use test_runner::*;
 
 mod command;
 mod compiler;
 mod dependency_manager;
 mod eval;
 mod invoke;
+mod json_printer;
+mod loaded_component;
 mod raw;
 mod repl_bootstrap_error;
 mod repl_printer;
@@ -37,6 +42,8 @@ mod rib_context;
 mod rib_edit;
 mod rib_execution_error;
 mod rib_repl;
+mod rib_script_error;
+mod test_runner;
 mod value_generator;
 mod worker_name_gen;
 
@@ -0,0 +1,94 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::dependency_manager::RibDependencyManager;
+use anyhow::anyhow;
+use rib::ComponentDependency;
+use std::path::PathBuf;
+
+/// A component of the REPL session, along with where it was loaded from,
+/// so that it can be loaded again when it changes
+#[derive(Clone)]
+pub(crate) struct LoadedComponent {
+    pub dependency: ComponentDependency,
+    pub source: LoadedComponentSource,
+}
+
+impl LoadedComponent {
+    pub fn name(&self) -> &str {
+        &self.dependency.component_dependency_key().component_name
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum LoadedComponentSource {
+    /// One of the components in the context the REPL was started in
+    Context { component_name: String },
+    File {
+        component_name: String,
+        path: PathBuf,
+    },
+    Deployed {
+        component_name: String,
+        version: Option<u64>,
+    },
+}
+
+impl LoadedComponentSource {
+    pub fn component_name(&self) -> &str {
+        match self {
+            LoadedComponentSource::Context { component_name }
+            | LoadedComponentSource::File { component_name, .. }
+            | LoadedComponentSource::Deployed { component_name, .. } => component_name,
+        }
+    }
+
+    pub async fn load(
+        &self,
+        dependency_manager: &(dyn RibDependencyManager + Sync + Send),
+    ) -> anyhow::Result<LoadedComponent> {
+        let dependency = match self {
+            LoadedComponentSource::Context { component_name } => dependency_manager
+                .get_dependencies()
+                .await?
+                .component_dependencies
+                .into_iter()
+                .find(|dependency| {
+                    &dependency.component_dependency_key().component_name == component_name
+                })
+                .ok_or_else(|| anyhow!("Component {component_name} is no longer in the context"))?,
+            LoadedComponentSource::File {
+                component_name,
+                path,
+            } => {
+                dependency_manager
+                    .add_component(path, component_name.clone())
+                    .await?
+            }
+            LoadedComponentSource::Deployed {
+                component_name,
+                version,
+            } => {
+                dependency_manager
+                    .get_deployed_component(component_name.clone(), *version)
+                    .await?
+            }
+        };
+
+        Ok(LoadedComponent {
+            dependency,
+            source: self.clone(),
+        })
+    }
+}
@@ -121,6 +121,14 @@ pub trait ReplPrinter {
         }
     }
 
+    fn print_rib_script(&self, rib_script: &str) {
+        println!("{}", rib_script.white());
+    }
+
+    fn print_rib_warning(&self, warning: &RibWarning) {
+        println!("{} {}", "[warning]".magenta(), warning.message.white());
+    }
+
     fn print_rib_runtime_error(&self, error: &RibRuntimeError) {
         println!("{} {}", "[runtime error]".red(), error.to_string().white());
     }
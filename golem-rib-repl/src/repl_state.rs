@@ -12,10 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::loaded_component::LoadedComponent;
 use crate::worker_name_gen::ReplWorkerNameGen;
 use crate::{RawRibScript, WorkerFunctionInvoke};
 use golem_wasm_rpc::ValueAndType;
-use rib::{InstructionId, RibCompiler};
+use rib::{InstructionId, RibCompiler, RibCompilerConfig};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock, RwLockReadGuard};
@@ -26,6 +27,9 @@ pub struct ReplState {
     invocation_results: InvocationResultCache,
     last_executed_instruction: RwLock<Option<InstructionId>>,
     rib_compiler: RwLock<RibCompiler>,
+    components: RwLock<Vec<LoadedComponent>>,
+    statements: RwLock<Vec<ReplStatement>>,
+    current_invocations: RwLock<Vec<ReplInvocation>>,
     history_file_path: PathBuf,
     worker_name_gen: RwLock<ReplWorkerNameGen>,
 }
@@ -74,8 +78,39 @@ impl ReplState {
         *self.last_executed_instruction.write().unwrap() = Some(instruction_id);
     }
 
+    // Forgets the results of the function calls made so far, and hence
+    // they are made again the next time the rib script of the session is evaluated
+    pub fn clear_invocation_results(&self) {
+        *self.invocation_results.results.write().unwrap() = HashMap::new();
+        *self.last_executed_instruction.write().unwrap() = None;
+    }
+
+    // Starts keeping track of the function calls made by a new statement
+    pub fn start_statement(&self) {
+        self.current_invocations.write().unwrap().clear();
+    }
+
+    pub fn record_invocation(&self, invocation: ReplInvocation) {
+        self.current_invocations.write().unwrap().push(invocation);
+    }
+
+    // Keeps the statement that was successfully evaluated, along with the function calls it made
+    pub fn record_statement(&self, rib: &str) {
+        let invocations = std::mem::take(&mut *self.current_invocations.write().unwrap());
+
+        self.statements.write().unwrap().push(ReplStatement {
+            rib: rib.to_string(),
+            invocations,
+        });
+    }
+
+    pub fn statements(&self) -> Vec<ReplStatement> {
+        self.statements.read().unwrap().clone()
+    }
+
     pub fn clear(&self) {
         *self.rib_script.write().unwrap() = RawRibScript::default();
+        self.statements.write().unwrap().clear();
         *self.invocation_results.results.write().unwrap() = HashMap::new();
         *self.last_executed_instruction.write().unwrap() = None;
     }
@@ -88,6 +123,16 @@ impl ReplState {
         self.rib_compiler.read().unwrap()
     }
 
+    pub fn components(&self) -> Vec<LoadedComponent> {
+        self.components.read().unwrap().clone()
+    }
+
+    // Replaces the components of the session, along with the compiler using them
+    pub fn set_components(&self, components: Vec<LoadedComponent>) {
+        *self.rib_compiler.write().unwrap() = rib_compiler(&components);
+        *self.components.write().unwrap() = components;
+    }
+
     pub fn current_rib_program(&self) -> String {
         self.rib_script.read().unwrap().as_text()
     }
@@ -102,7 +147,7 @@ impl ReplState {
 
     pub fn new(
         worker_function_invoke: Arc<dyn WorkerFunctionInvoke + Sync + Send>,
-        rib_compiler: RibCompiler,
+        components: Vec<LoadedComponent>,
         history_file: PathBuf,
     ) -> Self {
         Self {
@@ -112,13 +157,41 @@ impl ReplState {
                 results: RwLock::new(HashMap::new()),
             },
             last_executed_instruction: RwLock::new(None),
-            rib_compiler: RwLock::new(rib_compiler),
+            rib_compiler: RwLock::new(rib_compiler(&components)),
+            components: RwLock::new(components),
+            statements: RwLock::new(vec![]),
+            current_invocations: RwLock::new(vec![]),
             history_file_path: history_file,
             worker_name_gen: RwLock::new(ReplWorkerNameGen::new()),
         }
     }
 }
 
+/// A statement evaluated successfully in the REPL session
+#[derive(Clone, Debug)]
+pub struct ReplStatement {
+    pub rib: String,
+    /// The function calls made when the statement was evaluated for the first time
+    pub invocations: Vec<ReplInvocation>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReplInvocation {
+    pub worker_name: Option<String>,
+    pub function_name: String,
+    pub args: Vec<ValueAndType>,
+    pub result: Option<ValueAndType>,
+}
+
+fn rib_compiler(components: &[LoadedComponent]) -> RibCompiler {
+    let component_dependencies = components
+        .iter()
+        .map(|component| component.dependency.clone())
+        .collect();
+
+    RibCompiler::new(RibCompilerConfig::new(component_dependencies, vec![]))
+}
+
 #[derive(Debug)]
 pub struct InvocationResultCache {
     pub results: RwLock<HashMap<InstructionId, Option<ValueAndType>>>,
@@ -1,6 +1,7 @@
 use crate::repl_state::ReplState;
 use crate::rib_edit::RibEdit;
 use crate::{RawRibScript, ReplPrinter};
+use golem_wasm_rpc::{print_value_and_type, ValueAndType};
 use rib::RibCompiler;
 use rustyline::history::DefaultHistory;
 use rustyline::Editor;
@@ -39,6 +40,10 @@ impl<'a> ReplContext<'a> {
         self.editor.clear_history().unwrap();
     }
 
+    pub fn get_rib_script(&self) -> RawRibScript {
+        self.repl_state.rib_script().clone()
+    }
+
     pub fn get_new_rib_script(&self, rib: &str) -> RawRibScript {
         let rib_script = self.repl_state.rib_script();
         let result = &*rib_script;
@@ -47,7 +52,50 @@ impl<'a> ReplContext<'a> {
         result
     }
 
+    /// The statements evaluated successfully in the session so far, as a script that
+    /// can be run again, with the results of the function calls they made as comments
+    pub fn get_session_script(&self) -> String {
+        let mut script = String::new();
+
+        for statement in self.repl_state.statements() {
+            script.push_str(&statement.rib);
+            script.push('\n');
+
+            for invocation in statement.invocations {
+                let args = invocation
+                    .args
+                    .iter()
+                    .map(display_value)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let worker = invocation
+                    .worker_name
+                    .map(|worker_name| format!(" on {worker_name}"))
+                    .unwrap_or_default();
+
+                let result = invocation
+                    .result
+                    .as_ref()
+                    .map(|result| format!(" => {}", display_value(result)))
+                    .unwrap_or_default();
+
+                script.push_str(&format!(
+                    "// {}({args}){worker}{result}\n",
+                    invocation.function_name
+                ));
+            }
+        }
+
+        script
+    }
+
     pub fn get_rib_compiler(&self) -> RwLockReadGuard<RibCompiler> {
         self.repl_state.rib_compiler()
     }
 }
+
+// Values are printed in the WAVE format, except for resource handles that have no text representation
+fn display_value(value: &ValueAndType) -> String {
+    print_value_and_type(value).unwrap_or_else(|_| format!("{:?}", value.value))
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::command::ComponentCommand;
 use crate::compiler::{InstanceVariables, ReplCompilerOutput};
 use crate::value_generator::generate_value;
 use crate::CommandRegistry;
@@ -63,7 +64,11 @@ impl RibEdit {
                 "none", "ok", "err",
             ],
             std_function_names: vec!["instance"],
-            repl_commands: command_registry.get_commands(),
+            repl_commands: command_registry
+                .get_commands()
+                .into_iter()
+                .chain(ComponentCommand::NAMES.map(String::from))
+                .collect(),
         }
     }
     pub fn update_progression(&mut self, compiler_output: &ReplCompilerOutput) {
@@ -6,6 +6,8 @@ pub enum RibExecutionError {
     RibCompilationError(RibCompilationError),
     RibRuntimeError(RibRuntimeError),
     Custom(String),
+    // A command failed while running a script, after printing its error
+    CommandFailed(String),
 }
 
 impl std::error::Error for RibExecutionError {}
@@ -16,6 +18,7 @@ impl Display for RibExecutionError {
             RibExecutionError::RibCompilationError(err) => write!(f, "{err}"),
             RibExecutionError::RibRuntimeError(err) => write!(f, "{err}"),
             RibExecutionError::Custom(msg) => write!(f, "{msg}"),
+            RibExecutionError::CommandFailed(msg) => write!(f, "{msg}"),
         }
     }
 }
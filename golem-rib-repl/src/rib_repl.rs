@@ -12,20 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::command::{ComponentCommand, LoadInput, ReloadInput, UnloadInput};
 use crate::compiler::compile_rib_script;
 use crate::dependency_manager::RibDependencyManager;
 use crate::eval::eval;
 use crate::invoke::WorkerFunctionInvoke;
+use crate::loaded_component::{LoadedComponent, LoadedComponentSource};
 use crate::repl_printer::{DefaultReplResultPrinter, ReplPrinter};
 use crate::repl_state::ReplState;
 use crate::rib_context::ReplContext;
 use crate::rib_edit::RibEdit;
-use crate::{CommandRegistry, ReplBootstrapError, RibExecutionError, UntypedCommand};
+use crate::{
+    CommandRegistry, ReplBootstrapError, RibExecutionError, RibScriptError, UntypedCommand,
+};
 use colored::Colorize;
-use rib::{RibCompiler, RibCompilerConfig, RibResult};
+use rib::RibResult;
 use rustyline::error::ReadlineError;
 use rustyline::history::DefaultHistory;
 use rustyline::{Config, Editor};
+use std::io::BufRead;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -34,7 +39,8 @@ use std::sync::Arc;
 /// - `history_file`: Optional path to a file where the REPL history will be stored and loaded from.
 ///   If `None`, it will be loaded from `~/.rib_history`.
 /// - `dependency_manager`: This is responsible for how to load all the components or a specific
-///   custom component.
+///   custom component, both when bootstrapping and when the components are changed using
+///   `:load`, `:unload` and `:reload` during the session.
 /// - `worker_function_invoke`: An implementation of the `WorkerFunctionInvoke` trait,
 /// - `printer`: Optional custom printer for displaying results and errors in the REPL. If `None`
 ///   a default printer will be used.
@@ -55,6 +61,7 @@ pub struct RibReplConfig {
 /// The REPL environment for Rib, providing an interactive shell for executing Rib code.
 pub struct RibRepl {
     printer: Box<dyn ReplPrinter>,
+    dependency_manager: Arc<dyn RibDependencyManager + Sync + Send>,
     editor: Editor<RibEdit, DefaultHistory>,
     repl_state: Arc<ReplState>,
     prompt: String,
@@ -90,15 +97,19 @@ impl RibRepl {
             }
         }
 
-        let component_dependencies = match config.component_source {
+        let components = match config.component_source {
             Some(ref details) => {
-                let component_dependency = config
-                    .dependency_manager
-                    .add_component(&details.source_path, details.component_name.clone())
+                let source = LoadedComponentSource::File {
+                    component_name: details.component_name.clone(),
+                    path: details.source_path.clone(),
+                };
+
+                let component = source
+                    .load(config.dependency_manager.as_ref())
                     .await
                     .map_err(|err| ReplBootstrapError::ComponentLoadError(err.to_string()))?;
 
-                Ok(vec![component_dependency])
+                Ok(vec![component])
             }
             None => {
                 let dependencies = config.dependency_manager.get_dependencies().await;
@@ -111,7 +122,18 @@ impl RibRepl {
                             return Err(ReplBootstrapError::NoComponentsFound);
                         }
 
-                        Ok(component_dependencies)
+                        Ok(component_dependencies
+                            .into_iter()
+                            .map(|dependency| LoadedComponent {
+                                source: LoadedComponentSource::Context {
+                                    component_name: dependency
+                                        .component_dependency_key()
+                                        .component_name
+                                        .clone(),
+                                },
+                                dependency,
+                            })
+                            .collect())
                     }
                     Err(err) => Err(ReplBootstrapError::ComponentLoadError(format!(
                         "failed to register components: {err}"
@@ -124,7 +146,7 @@ impl RibRepl {
         // component dependency will not be required in the REPL state
         let repl_state = ReplState::new(
             config.worker_function_invoke,
-            RibCompiler::new(RibCompilerConfig::new(component_dependencies?, vec![])),
+            components?,
             history_file_path.clone(),
         );
 
@@ -132,6 +154,7 @@ impl RibRepl {
             printer: config
                 .printer
                 .unwrap_or_else(|| Box::new(DefaultReplResultPrinter)),
+            dependency_manager: config.dependency_manager,
             editor: rl,
             repl_state: Arc::new(repl_state),
             prompt: config
@@ -157,6 +180,56 @@ impl RibRepl {
     pub async fn execute(
         &mut self,
         script_or_command: &str,
+    ) -> Result<Option<RibResult>, RibExecutionError> {
+        self.execute_line(script_or_command, ExecutionMode::Interactive)
+            .await
+    }
+
+    /// Executes a rib script line by line, with the same semantics as entering the lines in the REPL,
+    /// and prints the result of each of them. Empty lines, and lines with only a comment, are skipped.
+    ///
+    /// This is the non-interactive mode of the REPL, which can be used for example to run a script
+    /// saved using `:save`. It stops at the first line that fails to compile or evaluate, or whose
+    /// command fails or has invalid arguments, returning its error.
+    pub async fn run_script(&mut self, script: impl BufRead) -> Result<(), RibScriptError> {
+        for (index, line) in script.lines().enumerate() {
+            let line_number = index + 1;
+
+            let line = line.map_err(|err| RibScriptError {
+                line: line_number,
+                error: RibExecutionError::Custom(format!("Failed to read the script: {err}")),
+            })?;
+
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            match self.execute_line(line, ExecutionMode::Script).await {
+                Ok(Some(result)) => self.printer.print_rib_result(&result),
+                Ok(None) => {}
+                Err(error) => {
+                    // Failed commands already printed their error
+                    if !matches!(error, RibExecutionError::CommandFailed(_)) {
+                        self.print_execution_error(&error);
+                    }
+
+                    return Err(RibScriptError {
+                        line: line_number,
+                        error,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn execute_line(
+        &mut self,
+        script_or_command: &str,
+        mode: ExecutionMode,
     ) -> Result<Option<RibResult>, RibExecutionError> {
         let script_or_command = CommandOrExpr::from_str(script_or_command, &self.command_registry)
             .map_err(RibExecutionError::Custom)?;
@@ -166,10 +239,31 @@ impl RibRepl {
                 let mut repl_context =
                     ReplContext::new(self.printer.as_ref(), &self.repl_state, &mut self.editor);
 
-                executor.run(args.as_str(), &mut repl_context);
+                let result = executor.run(args.as_str(), &mut repl_context);
 
-                Ok(None)
+                match (result, mode) {
+                    (Err(failure), ExecutionMode::Script) => Err(RibExecutionError::CommandFailed(
+                        format!("Command :{} failed: {failure}", executor.command_name()),
+                    )),
+                    _ => Ok(None),
+                }
             }
+            CommandOrExpr::ComponentCommand { name, command } => match command {
+                Ok(command) => {
+                    self.execute_component_command(command).await?;
+                    Ok(None)
+                }
+                Err(err) => {
+                    self.printer.print_clap_parse_error(&err);
+
+                    match mode {
+                        ExecutionMode::Interactive => Ok(None),
+                        ExecutionMode::Script => Err(RibExecutionError::CommandFailed(format!(
+                            "Command :{name} failed: invalid arguments"
+                        ))),
+                    }
+                }
+            },
             CommandOrExpr::RawExpr(script) => {
                 // If the script is empty, we do not execute it
                 if !script.is_empty() {
@@ -180,22 +274,44 @@ impl RibRepl {
                     // Add every rib script into the history (in memory) and save it
                     // regardless of whether it compiles or not
                     // History is never used for any progressive compilation or interpretation
-                    let _ = self.editor.add_history_entry(rib);
-                    let _ = self
-                        .editor
-                        .save_history(self.repl_state.history_file_path());
+                    if mode == ExecutionMode::Interactive {
+                        let _ = self.editor.add_history_entry(rib);
+                        let _ = self
+                            .editor
+                            .save_history(self.repl_state.history_file_path());
+                    }
+
+                    let rib_program = self.current_rib_program();
 
-                    match compile_rib_script(&self.current_rib_program(), self.repl_state.clone()) {
+                    match compile_rib_script(&rib_program, self.repl_state.clone()) {
                         Ok(compiler_output) => {
+                            // Warnings of the expressions entered before are already printed
+                            let first_line = rib_program[..rib_program.len() - rib.len()]
+                                .matches('\n')
+                                .count() as i32
+                                + 1;
+
+                            for warning in &compiler_output.warnings {
+                                if warning.source_span.start_line() >= first_line {
+                                    self.printer.print_rib_warning(warning);
+                                }
+                            }
+
                             let rib_edit = self.editor.helper_mut().unwrap();
 
                             rib_edit.update_progression(&compiler_output);
 
+                            self.repl_state.start_statement();
+
                             let result =
                                 eval(compiler_output.rib_byte_code, &self.repl_state).await;
 
                             match result {
-                                Ok(result) => Ok(Some(result)),
+                                Ok(result) => {
+                                    self.repl_state.record_statement(rib);
+
+                                    Ok(Some(result))
+                                }
                                 Err(err) => {
                                     self.repl_state.remove_last_rib_expression();
 
@@ -235,17 +351,7 @@ impl RibRepl {
 
                         Ok(None) => {}
 
-                        Err(err) => match err {
-                            RibExecutionError::RibRuntimeError(runtime_error) => {
-                                self.printer.print_rib_runtime_error(&runtime_error);
-                            }
-                            RibExecutionError::RibCompilationError(runtime_error) => {
-                                self.printer.print_rib_compilation_error(&runtime_error);
-                            }
-                            RibExecutionError::Custom(custom_error) => {
-                                self.printer.print_custom_error(&custom_error);
-                            }
-                        },
+                        Err(err) => self.print_execution_error(&err),
                     }
                 }
                 Err(ReadlineError::Eof) | Err(ReadlineError::Interrupted) => break,
@@ -254,9 +360,224 @@ impl RibRepl {
         }
     }
 
+    fn print_execution_error(&self, error: &RibExecutionError) {
+        match error {
+            RibExecutionError::RibRuntimeError(runtime_error) => {
+                self.printer.print_rib_runtime_error(runtime_error);
+            }
+            RibExecutionError::RibCompilationError(compilation_error) => {
+                self.printer.print_rib_compilation_error(compilation_error);
+            }
+            RibExecutionError::Custom(custom_error)
+            | RibExecutionError::CommandFailed(custom_error) => {
+                self.printer.print_custom_error(custom_error);
+            }
+        }
+    }
+
     fn current_rib_program(&self) -> String {
         self.repl_state.current_rib_program()
     }
+
+    async fn execute_component_command(
+        &mut self,
+        command: ComponentCommand,
+    ) -> Result<(), RibExecutionError> {
+        match command {
+            ComponentCommand::Load(input) => self.load_component(input).await,
+            ComponentCommand::Unload(input) => self.unload_component(input),
+            ComponentCommand::Reload(input) => self.reload_components(input).await,
+        }
+    }
+
+    async fn load_component(&mut self, input: LoadInput) -> Result<(), RibExecutionError> {
+        let source = match (input.component, input.path) {
+            (Some(component_name), _) => LoadedComponentSource::Deployed {
+                component_name,
+                version: input.version,
+            },
+            (None, Some(path)) => {
+                let component_name = match input.name {
+                    Some(name) => name,
+                    None => path
+                        .file_stem()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .ok_or_else(|| {
+                            RibExecutionError::Custom(format!(
+                                "Invalid component path {}",
+                                path.display()
+                            ))
+                        })?,
+                };
+
+                LoadedComponentSource::File {
+                    component_name,
+                    path,
+                }
+            }
+            (None, None) => {
+                return Err(RibExecutionError::Custom(
+                    "Expecting a path or the name of a deployed component".to_string(),
+                ))
+            }
+        };
+
+        let mut components = self.repl_state.components();
+
+        let is_loaded = |name: &str| components.iter().any(|component| component.name() == name);
+
+        if is_loaded(source.component_name()) {
+            return Err(RibExecutionError::Custom(format!(
+                "Component {} is already loaded, use `:reload {}` to load it again",
+                source.component_name(),
+                source.component_name()
+            )));
+        }
+
+        let component = source
+            .load(self.dependency_manager.as_ref())
+            .await
+            .map_err(|err| RibExecutionError::Custom(format!("Failed to load component: {err}")))?;
+
+        if is_loaded(component.name()) {
+            return Err(RibExecutionError::Custom(format!(
+                "Component {} is already loaded",
+                component.name()
+            )));
+        }
+
+        let message = format!("Loaded component {}", component.name());
+
+        components.push(component);
+
+        self.update_components(components)?;
+
+        self.printer.print_custom_message(&message);
+
+        Ok(())
+    }
+
+    fn unload_component(&mut self, input: UnloadInput) -> Result<(), RibExecutionError> {
+        let mut components = self.repl_state.components();
+
+        let index = components
+            .iter()
+            .position(|component| component.name() == input.component_name)
+            .ok_or_else(|| {
+                RibExecutionError::Custom(format!(
+                    "Component {} is not loaded",
+                    input.component_name
+                ))
+            })?;
+
+        components.remove(index);
+
+        self.update_components(components)?;
+
+        self.printer
+            .print_custom_message(&format!("Unloaded component {}", input.component_name));
+
+        Ok(())
+    }
+
+    async fn reload_components(&mut self, input: ReloadInput) -> Result<(), RibExecutionError> {
+        let mut components = self.repl_state.components();
+
+        if let Some(component_name) = &input.component_name {
+            if !components
+                .iter()
+                .any(|component| component.name() == component_name)
+            {
+                return Err(RibExecutionError::Custom(format!(
+                    "Component {component_name} is not loaded"
+                )));
+            }
+        }
+
+        let mut reloaded = vec![];
+
+        for component in components.iter_mut() {
+            if input
+                .component_name
+                .as_ref()
+                .is_some_and(|name| name != component.name())
+            {
+                continue;
+            }
+
+            *component = component
+                .source
+                .load(self.dependency_manager.as_ref())
+                .await
+                .map_err(|err| {
+                    RibExecutionError::Custom(format!(
+                        "Failed to reload component {}: {err}",
+                        component.name()
+                    ))
+                })?;
+
+            reloaded.push(component.name().to_string());
+        }
+
+        self.update_components(components)?;
+
+        self.printer
+            .print_custom_message(&format!("Reloaded component(s) {}", reloaded.join(", ")));
+
+        Ok(())
+    }
+
+    // Changes the components of the session, inferring the types of the rib script
+    // entered so far again. If the script no longer compiles (for example because
+    // it calls a function of a component that is unloaded) the change is reverted.
+    fn update_components(
+        &mut self,
+        components: Vec<LoadedComponent>,
+    ) -> Result<(), RibExecutionError> {
+        let rib_program = self.current_rib_program();
+
+        if rib_program.is_empty() {
+            self.repl_state.set_components(components);
+            return Ok(());
+        }
+
+        let previous_components = self.repl_state.components();
+
+        let previous_byte_code = compile_rib_script(&rib_program, self.repl_state.clone())
+            .ok()
+            .map(|compiler_output| compiler_output.rib_byte_code);
+
+        self.repl_state.set_components(components);
+
+        match compile_rib_script(&rib_program, self.repl_state.clone()) {
+            Ok(compiler_output) => {
+                // The results of the function calls made so far are cached by the instructions
+                // making them, and hence cannot be reused if the instructions changed
+                if previous_byte_code.as_ref() != Some(&compiler_output.rib_byte_code) {
+                    self.repl_state.clear_invocation_results();
+
+                    self.printer.print_custom_message(
+                        "The types in the rib script changed, and hence the function calls \
+                        made so far will be made again in the next evaluation",
+                    );
+                }
+
+                let rib_edit = self.editor.helper_mut().unwrap();
+
+                rib_edit.update_progression(&compiler_output);
+
+                Ok(())
+            }
+            Err(err) => {
+                self.repl_state.set_components(previous_components);
+
+                Err(RibExecutionError::Custom(format!(
+                    "The components were not changed, as the rib script of the session \
+                    would no longer compile: {err}"
+                )))
+            }
+        }
+    }
 }
 
 enum CommandOrExpr {
@@ -264,9 +585,21 @@ enum CommandOrExpr {
         args: String,
         executor: Arc<dyn UntypedCommand>,
     },
+    ComponentCommand {
+        name: String,
+        command: Result<ComponentCommand, clap::Error>,
+    },
     RawExpr(String),
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExecutionMode {
+    // Lines entered in the REPL, which are added to the history
+    Interactive,
+    // Lines of a script, which stops at the first failing command
+    Script,
+}
+
 impl CommandOrExpr {
     pub fn from_str(input: &str, command_registry: &CommandRegistry) -> Result<Self, String> {
         if input.starts_with(":") {
@@ -279,15 +612,19 @@ impl CommandOrExpr {
 
             let input_args = repl_input[1..].join(" ");
 
-            let command = command_registry
-                .get_command(command_name)
-                .map(|command| CommandOrExpr::Command {
+            if let Some(command) = command_registry.get_command(command_name) {
+                return Ok(CommandOrExpr::Command {
                     args: input_args,
                     executor: command,
-                })
-                .ok_or_else(|| format!("Command '{command_name}' not found"))?;
+                });
+            }
 
-            Ok(command)
+            ComponentCommand::parse(command_name, &input_args)
+                .map(|command| CommandOrExpr::ComponentCommand {
+                    name: command_name.to_string(),
+                    command,
+                })
+                .ok_or_else(|| format!("Command '{command_name}' not found"))
         } else {
             Ok(CommandOrExpr::RawExpr(input.trim().to_string()))
         }
@@ -311,3 +648,296 @@ fn get_default_history_file() -> PathBuf {
     path.push(".rib_history");
     path
 }
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{
+        ComponentSource, ReplComponentDependencies, RibDependencyManager, RibExecutionError,
+        RibRepl, RibReplConfig, RibScriptError, WorkerFunctionInvoke,
+    };
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+    use golem_wasm_ast::analysis::analysed_type::{str, u64};
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionResult, AnalysedInstance, AnalysedType,
+    };
+    use golem_wasm_rpc::{IntoValueAndType, ValueAndType};
+    use rib::{ComponentDependency, ComponentDependencyKey, RibResult};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    #[test]
+    async fn test_load_and_unload_component() {
+        let dependency_manager = Arc::new(TestDependencyManager::new());
+        dependency_manager.set_function("counter", "get-count", u64());
+        dependency_manager.set_function("greeter", "get-greeting", str());
+
+        let mut repl = bootstrap(dependency_manager, Arc::new(TestInvoke::default())).await;
+
+        assert!(repl.execute(r#"let w = instance("w")"#).await.is_ok());
+        assert!(repl.execute("w.get-greeting()").await.is_err());
+
+        repl.execute(":load ./greeter.wasm").await.unwrap();
+
+        assert_eq!(
+            repl.execute("w.get-greeting()").await.unwrap(),
+            Some(RibResult::Val("hello".into_value_and_type()))
+        );
+
+        let result = repl.execute(":unload greeter").await;
+
+        assert!(
+            matches!(result, Err(RibExecutionError::Custom(msg)) if msg.starts_with("The components were not changed"))
+        );
+
+        assert!(repl.execute(":unload counter").await.is_ok());
+        assert!(repl.execute("w.get-count()").await.is_err());
+    }
+
+    #[test]
+    async fn test_reload_component() {
+        let dependency_manager = Arc::new(TestDependencyManager::new());
+        dependency_manager.set_function("counter", "get-count", u64());
+
+        let invoke = Arc::new(TestInvoke::default());
+
+        let mut repl = bootstrap(dependency_manager.clone(), invoke.clone()).await;
+
+        repl.execute(r#"let w = instance("w")"#).await.unwrap();
+        repl.execute("let count = w.get-count()").await.unwrap();
+
+        assert_eq!(
+            repl.execute("count").await.unwrap(),
+            Some(RibResult::Val(1u64.into_value_and_type()))
+        );
+
+        // Without changes, the function calls made so far are not repeated
+        repl.execute(":reload").await.unwrap();
+        repl.execute("count").await.unwrap();
+        assert_eq!(invoke.calls.load(Ordering::SeqCst), 1);
+
+        dependency_manager.set_function("counter", "get-count", str());
+        repl.execute(":reload counter").await.unwrap();
+
+        assert_eq!(
+            repl.execute("count").await.unwrap(),
+            Some(RibResult::Val("hello".into_value_and_type()))
+        );
+        assert_eq!(invoke.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    async fn test_run_script() {
+        let dependency_manager = Arc::new(TestDependencyManager::new());
+        dependency_manager.set_function("counter", "get-count", u64());
+
+        let mut repl = bootstrap(dependency_manager, Arc::new(TestInvoke::default())).await;
+
+        let script = r#"
+            // Counting
+            let w = instance("w");
+
+            let count = w.get-count();
+            count
+            count + "1"
+            count
+        "#;
+
+        let result = repl.run_script(script.as_bytes()).await;
+
+        assert!(matches!(
+            result,
+            Err(RibScriptError {
+                line: 7,
+                error: RibExecutionError::RibCompilationError(_)
+            })
+        ));
+    }
+
+    #[test]
+    async fn test_run_script_stops_at_failing_command() {
+        let dependency_manager = Arc::new(TestDependencyManager::new());
+        dependency_manager.set_function("counter", "get-count", u64());
+
+        let mut repl = bootstrap(dependency_manager, Arc::new(TestInvoke::default())).await;
+
+        let script = r#"
+            let w = instance("w");
+            :type-info w.get-count()
+            :type-info w.get-count() + "1"
+            w.get-count()
+        "#;
+
+        let result = repl.run_script(script.as_bytes()).await;
+
+        assert!(matches!(
+            result,
+            Err(RibScriptError {
+                line: 4,
+                error: RibExecutionError::CommandFailed(_)
+            })
+        ));
+
+        let script = r#"
+            w.get-count()
+            :unload
+            w.get-count()
+        "#;
+
+        let result = repl.run_script(script.as_bytes()).await;
+
+        assert!(matches!(
+            result,
+            Err(RibScriptError {
+                line: 3,
+                error: RibExecutionError::CommandFailed(_)
+            })
+        ));
+
+        // In the interactive mode the commands print their errors without failing
+        assert!(matches!(repl.execute(":unload").await, Ok(None)));
+    }
+
+    #[test]
+    async fn test_save_session() {
+        let dependency_manager = Arc::new(TestDependencyManager::new());
+        dependency_manager.set_function("counter", "get-count", u64());
+
+        let mut repl = bootstrap(dependency_manager.clone(), Arc::new(TestInvoke::default())).await;
+
+        repl.execute(r#"let w = instance("w")"#).await.unwrap();
+        repl.execute("let count = w.get-count();").await.unwrap();
+        assert!(repl.execute("count + \"1\"").await.is_err());
+        repl.execute("count").await.unwrap();
+
+        let file = std::env::temp_dir().join(format!("rib-session-{}.rib", Uuid::new_v4()));
+
+        repl.execute(&format!(":save {}", file.display()))
+            .await
+            .unwrap();
+
+        let script = std::fs::read_to_string(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+
+        assert_eq!(
+            script,
+            "let w = instance(\"w\")\n\
+            let count = w.get-count()\n\
+            // golem:counter/api.{get-count}() on w => 1\n\
+            count\n"
+        );
+
+        let mut repl = bootstrap(dependency_manager, Arc::new(TestInvoke::default())).await;
+
+        assert!(repl.run_script(script.as_bytes()).await.is_ok());
+    }
+
+    async fn bootstrap(
+        dependency_manager: Arc<TestDependencyManager>,
+        worker_function_invoke: Arc<TestInvoke>,
+    ) -> RibRepl {
+        RibRepl::bootstrap(RibReplConfig {
+            history_file: Some(
+                std::env::temp_dir().join(format!("rib-history-{}", Uuid::new_v4())),
+            ),
+            dependency_manager,
+            worker_function_invoke,
+            printer: None,
+            component_source: Some(ComponentSource {
+                component_name: "counter".to_string(),
+                source_path: PathBuf::from("./counter.wasm"),
+            }),
+            prompt: None,
+            command_registry: None,
+        })
+        .await
+        .unwrap()
+    }
+
+    // Each component has a single function, which can be changed to test reloading
+    struct TestDependencyManager {
+        components: Mutex<HashMap<String, ComponentDependency>>,
+    }
+
+    impl TestDependencyManager {
+        fn new() -> Self {
+            TestDependencyManager {
+                components: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn set_function(&self, component_name: &str, function_name: &str, result: AnalysedType) {
+            let component = ComponentDependency::new(
+                ComponentDependencyKey {
+                    component_name: component_name.to_string(),
+                    component_id: Uuid::new_v5(&Uuid::NAMESPACE_OID, component_name.as_bytes()),
+                    root_package_name: None,
+                    root_package_version: None,
+                },
+                vec![AnalysedExport::Instance(AnalysedInstance {
+                    name: format!("golem:{component_name}/api"),
+                    functions: vec![AnalysedFunction {
+                        name: function_name.to_string(),
+                        parameters: vec![],
+                        result: Some(AnalysedFunctionResult { typ: result }),
+                    }],
+                })],
+            );
+
+            self.components
+                .lock()
+                .unwrap()
+                .insert(component_name.to_string(), component);
+        }
+    }
+
+    #[async_trait]
+    impl RibDependencyManager for TestDependencyManager {
+        async fn get_dependencies(&self) -> anyhow::Result<ReplComponentDependencies> {
+            Err(anyhow!("not supported"))
+        }
+
+        async fn add_component(
+            &self,
+            _source_path: &Path,
+            component_name: String,
+        ) -> anyhow::Result<ComponentDependency> {
+            self.components
+                .lock()
+                .unwrap()
+                .get(&component_name)
+                .cloned()
+                .ok_or_else(|| anyhow!("component {component_name} not found"))
+        }
+    }
+
+    #[derive(Default)]
+    struct TestInvoke {
+        calls: AtomicU64,
+    }
+
+    #[async_trait]
+    impl WorkerFunctionInvoke for TestInvoke {
+        async fn invoke(
+            &self,
+            _component_id: Uuid,
+            _component_name: &str,
+            _worker_name: Option<String>,
+            _function_name: &str,
+            _args: Vec<ValueAndType>,
+            return_type: Option<AnalysedType>,
+        ) -> anyhow::Result<Option<ValueAndType>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            match return_type {
+                Some(AnalysedType::U64(_)) => Ok(Some(1u64.into_value_and_type())),
+                _ => Ok(Some("hello".into_value_and_type())),
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::RibExecutionError;
+use std::fmt::{Display, Formatter};
+
+/// Error of running a rib script using the non-interactive mode of the REPL,
+/// along with the line of the script that failed
+#[derive(Debug)]
+pub struct RibScriptError {
+    pub line: usize,
+    pub error: RibExecutionError,
+}
+
+impl std::error::Error for RibScriptError {}
+
+impl Display for RibScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::test_runner::RibTestComponent;
+use anyhow::{anyhow, Context};
+use golem_wasm_ast::analysis::wit_parser::WitAnalysisContext;
+use golem_wasm_ast::analysis::AnalysedExport;
+use rib::{ComponentDependency, ComponentDependencyKey};
+use serde::Deserialize;
+use std::path::Path;
+use uuid::Uuid;
+
+// The part of the component metadata needed to compile Rib scripts against the component
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ComponentMetadata {
+    exports: Vec<AnalysedExport>,
+    root_package_name: Option<String>,
+    root_package_version: Option<String>,
+}
+
+pub fn load_component(component: &RibTestComponent) -> anyhow::Result<ComponentDependency> {
+    let (path, metadata) = match (&component.wasm, &component.metadata) {
+        (Some(path), _) => (path, analyse_wasm(path)?),
+        (None, Some(path)) => (path, read_metadata(path)?),
+        (None, None) => return Err(anyhow!("No WASM or metadata given for the component")),
+    };
+
+    let component_name = match &component.name {
+        Some(name) => name.clone(),
+        None => path
+            .file_stem()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| anyhow!("Invalid component path {}", path.display()))?,
+    };
+
+    // The functions of the component are never really invoked,
+    // and hence it is enough for the id to be stable across runs
+    let component_id = Uuid::new_v5(&Uuid::NAMESPACE_URL, path.to_string_lossy().as_bytes());
+
+    Ok(ComponentDependency::new(
+        ComponentDependencyKey {
+            component_name,
+            component_id,
+            root_package_name: metadata.root_package_name,
+            root_package_version: metadata.root_package_version,
+        },
+        metadata.exports,
+    ))
+}
+
+fn analyse_wasm(path: &Path) -> anyhow::Result<ComponentMetadata> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read component {}", path.display()))?;
+
+    let analysis = WitAnalysisContext::new(&bytes)
+        .map_err(|err| anyhow!("Failed to analyse component {}: {err:?}", path.display()))?;
+
+    let exports = analysis
+        .get_top_level_exports()
+        .map_err(|err| anyhow!("Failed to analyse component {}: {err:?}", path.display()))?;
+
+    let root_package = analysis.root_package_name();
+
+    Ok(ComponentMetadata {
+        exports,
+        root_package_name: root_package
+            .as_ref()
+            .map(|pkg| format!("{}:{}", pkg.namespace, pkg.name)),
+        root_package_version: root_package.and_then(|pkg| pkg.version.map(|v| v.to_string())),
+    })
+}
+
+fn read_metadata(path: &Path) -> anyhow::Result<ComponentMetadata> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read component metadata {}", path.display()))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid component metadata {}", path.display()))
+}
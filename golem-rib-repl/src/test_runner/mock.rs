@@ -0,0 +1,210 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::test_runner::RibTestMock;
+use async_trait::async_trait;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{parse_value_and_type, print_value_and_type, Value, ValueAndType};
+use rib::{
+    ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName, InstructionId,
+    RibComponentFunctionInvoke, RibFunctionInvokeResult,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Answers the calls of component functions made by a Rib script using mocks,
+/// keeping track of how many times each of them was called
+pub struct MockRibFunctionInvoke {
+    mocks: Vec<RibTestMock>,
+    call_counts: Mutex<Vec<u64>>,
+    last_resource_id: AtomicU64,
+}
+
+impl MockRibFunctionInvoke {
+    pub fn new(mocks: Vec<RibTestMock>) -> Self {
+        let call_counts = Mutex::new(vec![0; mocks.len()]);
+
+        MockRibFunctionInvoke {
+            mocks,
+            call_counts,
+            last_resource_id: AtomicU64::new(0),
+        }
+    }
+
+    /// The mocks that were not called the expected number of times
+    pub fn unmet_expectations(&self) -> Vec<String> {
+        let call_counts = self.call_counts.lock().unwrap();
+
+        self.mocks
+            .iter()
+            .zip(call_counts.iter())
+            .filter_map(|(mock, calls)| match mock.times {
+                Some(times) if times != *calls => Some(format!(
+                    "expected {} to be called {times} time(s), but it was called {calls} time(s)",
+                    mock.function
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn find_mock(
+        &self,
+        worker_name: Option<&str>,
+        function_name: &str,
+        args: &[ValueAndType],
+    ) -> Result<Option<usize>, String> {
+        for (index, mock) in self.mocks.iter().enumerate() {
+            let worker_matches = match &mock.worker {
+                Some(worker) => worker_name == Some(worker.as_str()),
+                None => true,
+            };
+
+            if worker_matches
+                && function_matches(&mock.function, function_name)
+                && args_match(mock, args)?
+            {
+                return Ok(Some(index));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn new_handle(
+        &self,
+        component_dependency: &ComponentDependencyKey,
+        worker_name: Option<&str>,
+    ) -> Value {
+        let resource_id = self.last_resource_id.fetch_add(1, Ordering::SeqCst) + 1;
+
+        Value::Handle {
+            uri: format!(
+                "urn:worker:{}/{}",
+                component_dependency.component_id,
+                worker_name.unwrap_or_default()
+            ),
+            resource_id,
+        }
+    }
+}
+
+#[async_trait]
+impl RibComponentFunctionInvoke for MockRibFunctionInvoke {
+    async fn invoke(
+        &self,
+        component_dependency: ComponentDependencyKey,
+        _instruction_id: &InstructionId,
+        worker_name: Option<EvaluatedWorkerName>,
+        function_name: EvaluatedFqFn,
+        args: EvaluatedFnArgs,
+        return_type: Option<AnalysedType>,
+    ) -> RibFunctionInvokeResult {
+        let worker_name = worker_name.map(|name| name.0);
+        let function_name = function_name.0;
+
+        let Some(index) = self.find_mock(worker_name.as_deref(), &function_name, &args.0)? else {
+            // Creating a resource doesn't need a mock, unless the test wants to check the calls
+            return match return_type {
+                Some(AnalysedType::Handle(handle)) => Ok(Some(ValueAndType::new(
+                    self.new_handle(&component_dependency, worker_name.as_deref()),
+                    AnalysedType::Handle(handle),
+                ))),
+                _ => Err(format!(
+                    "no mock for the call {function_name}({}) on worker {}",
+                    print_args(&args.0),
+                    worker_name.as_deref().unwrap_or("<unnamed>")
+                )
+                .into()),
+            };
+        };
+
+        self.call_counts.lock().unwrap()[index] += 1;
+
+        let mock = &self.mocks[index];
+
+        if let Some(error) = &mock.error {
+            return Err(error.clone().into());
+        }
+
+        match (return_type, &mock.result) {
+            (None, None) => Ok(None),
+            (None, Some(_)) => Err(format!(
+                "the mock of {} has a result, but {function_name} doesn't return a value",
+                mock.function
+            )
+            .into()),
+            (Some(typ), Some(result)) => {
+                parse_value_and_type(&typ, result).map(Some).map_err(|err| {
+                    format!("invalid result in the mock of {}: {err}", mock.function).into()
+                })
+            }
+            (Some(AnalysedType::Handle(handle)), None) => Ok(Some(ValueAndType::new(
+                self.new_handle(&component_dependency, worker_name.as_deref()),
+                AnalysedType::Handle(handle),
+            ))),
+            (Some(_), None) => Err(format!(
+                "the mock of {} has no result, but {function_name} returns a value",
+                mock.function
+            )
+            .into()),
+        }
+    }
+}
+
+// A mock can refer to a function with the part of the fully qualified name in the braces,
+// such as `add-item` in `golem:it/api.{add-item}`
+fn function_matches(mock_function: &str, function_name: &str) -> bool {
+    if mock_function == function_name {
+        return true;
+    }
+
+    match function_name.split_once(".{") {
+        Some((_, function)) => function.strip_suffix('}') == Some(mock_function),
+        None => false,
+    }
+}
+
+// The arguments of the mock are parsed using the types of the actual arguments
+fn args_match(mock: &RibTestMock, args: &[ValueAndType]) -> Result<bool, String> {
+    let Some(expected_args) = &mock.args else {
+        return Ok(true);
+    };
+
+    if expected_args.len() != args.len() {
+        return Ok(false);
+    }
+
+    for (expected, actual) in expected_args.iter().zip(args) {
+        let expected = parse_value_and_type(&actual.typ, expected).map_err(|err| {
+            format!(
+                "invalid argument {expected} in the mock of {}: {err}",
+                mock.function
+            )
+        })?;
+
+        if expected.value != actual.value {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn print_args(args: &[ValueAndType]) -> String {
+    args.iter()
+        .map(|arg| print_value_and_type(arg).unwrap_or_else(|_| format!("{:?}", arg.value)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
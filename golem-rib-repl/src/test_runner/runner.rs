@@ -0,0 +1,372 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::test_runner::{MockRibFunctionInvoke, RibTestCase, RibTestExpectation};
+use golem_wasm_rpc::parse_value_and_type;
+use rib::{
+    ComponentDependency, Expr, GenerateWorkerName, Interpreter, RibCompiler, RibCompilerConfig,
+    RibInput, RibResult, RibRuntimeError,
+};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Runs Rib tests against the given components, whose functions are mocked in each test
+pub struct RibTestRunner {
+    compiler: RibCompiler,
+}
+
+impl RibTestRunner {
+    pub fn new(component_dependencies: Vec<ComponentDependency>) -> Self {
+        RibTestRunner {
+            compiler: RibCompiler::new(RibCompilerConfig::new(component_dependencies, vec![])),
+        }
+    }
+
+    pub async fn run(&self, test: &RibTestCase) -> RibTestResult {
+        let outcome = match self.run_test(test).await {
+            Ok(()) => RibTestOutcome::Passed,
+            Err(reason) => RibTestOutcome::Failed { reason },
+        };
+
+        RibTestResult {
+            name: test.name.clone(),
+            outcome,
+        }
+    }
+
+    async fn run_test(&self, test: &RibTestCase) -> Result<(), String> {
+        let expr = Expr::from_text(&test.script).map_err(|err| format!("invalid script: {err}"))?;
+
+        let compiled = self
+            .compiler
+            .compile(expr)
+            .map_err(|err| format!("failed to compile the script: {err}"))?;
+
+        let mut input = HashMap::new();
+
+        for (name, value) in &test.input {
+            let typ = compiled
+                .rib_input_type_info
+                .get(name)
+                .ok_or_else(|| format!("input {name} is not used by the script"))?;
+
+            let value = parse_value_and_type(typ, value)
+                .map_err(|err| format!("invalid value of input {name}: {err}"))?;
+
+            input.insert(name.clone(), value);
+        }
+
+        let function_invoke = Arc::new(MockRibFunctionInvoke::new(test.mocks.clone()));
+
+        let mut interpreter = Interpreter::new(
+            RibInput::new(input),
+            function_invoke.clone(),
+            Arc::new(TestWorkerNameGenerator::default()),
+        );
+
+        let result = interpreter.run(compiled.byte_code).await;
+
+        check_expectation(&test.expect, result)?;
+
+        let unmet_expectations = function_invoke.unmet_expectations();
+
+        if unmet_expectations.is_empty() {
+            Ok(())
+        } else {
+            Err(unmet_expectations.join("\n"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RibTestResult {
+    pub name: String,
+    pub outcome: RibTestOutcome,
+}
+
+impl RibTestResult {
+    pub fn passed(&self) -> bool {
+        self.outcome == RibTestOutcome::Passed
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RibTestOutcome {
+    Passed,
+    Failed { reason: String },
+}
+
+impl Display for RibTestOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RibTestOutcome::Passed => write!(f, "ok"),
+            RibTestOutcome::Failed { reason } => write!(f, "{reason}"),
+        }
+    }
+}
+
+fn check_expectation(
+    expect: &RibTestExpectation,
+    result: Result<RibResult, RibRuntimeError>,
+) -> Result<(), String> {
+    match (result, &expect.error) {
+        (Ok(result), None) => match &expect.output {
+            Some(expected) => check_output(expected, &result),
+            None => Ok(()),
+        },
+        (Ok(result), Some(expected)) => Err(format!(
+            "expected the script to fail with `{expected}`, but it returned {result}"
+        )),
+        (Err(err), Some(expected)) if err.to_string().contains(expected) => Ok(()),
+        (Err(err), Some(expected)) => Err(format!(
+            "expected the script to fail with `{expected}`, but it failed with: {err}"
+        )),
+        (Err(err), None) => Err(format!("the script failed: {err}")),
+    }
+}
+
+// The expected output is parsed using the type of the actual result,
+// so that it can be compared regardless of how it is formatted
+fn check_output(expected: &str, result: &RibResult) -> Result<(), String> {
+    let matches = match result {
+        RibResult::Val(actual) => {
+            let expected = parse_value_and_type(&actual.typ, expected)
+                .map_err(|err| format!("invalid expected output {expected}: {err}"))?;
+
+            expected.value == actual.value
+        }
+        RibResult::Unit => expected.trim() == "()",
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(format!("expected the output {expected}, but got {result}"))
+    }
+}
+
+// Names the workers of `instance()` deterministically, so that mocks can refer to them
+#[derive(Default)]
+struct TestWorkerNameGenerator {
+    count: AtomicU64,
+}
+
+impl GenerateWorkerName for TestWorkerNameGenerator {
+    fn generate_worker_name(&self) -> String {
+        let count = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+        format!("worker-{count}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::test_runner::{RibTestCase, RibTestOutcome, RibTestRunner, RibTestSuite};
+    use golem_wasm_ast::analysis::analysed_type::{f64, str, u32};
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+        AnalysedInstance,
+    };
+    use rib::{ComponentDependency, ComponentDependencyKey};
+    use std::path::Path;
+    use uuid::Uuid;
+
+    const SCRIPT: &str = r#"
+        let worker = instance(request.user);
+        let price = worker.get-price(request.product);
+        worker.add-item(request.product, 2);
+        price
+    "#;
+
+    #[test]
+    async fn test_rib_test_passes() {
+        let result = run_test(
+            r#"
+            name: price of the item
+            mocks:
+              - function: get-price
+                worker: jon
+                args: ['"mug"']
+                result: '10.5'
+              - function: golem:it/api.{add-item}
+                args: ['"mug"', '2']
+                times: 1
+            expect:
+              output: '10.5'
+            "#,
+        )
+        .await;
+
+        assert_eq!(result, RibTestOutcome::Passed);
+    }
+
+    #[test]
+    async fn test_rib_test_fails_on_different_output() {
+        let result = run_test(
+            r#"
+            name: price of the item
+            mocks:
+              - function: get-price
+                result: '10.5'
+              - function: add-item
+            expect:
+              output: '11.0'
+            "#,
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            RibTestOutcome::Failed {
+                reason: "expected the output 11.0, but got 10.5".to_string()
+            }
+        );
+    }
+
+    #[test]
+    async fn test_rib_test_expects_error_of_mock() {
+        let result = run_test(
+            r#"
+            name: price service is down
+            mocks:
+              - function: get-price
+                error: service unavailable
+              - function: add-item
+                times: 0
+            expect:
+              error: service unavailable
+            "#,
+        )
+        .await;
+
+        assert_eq!(result, RibTestOutcome::Passed);
+    }
+
+    #[test]
+    async fn test_rib_test_fails_without_matching_mock() {
+        let result = run_test(
+            r#"
+            name: price of another item
+            mocks:
+              - function: get-price
+                args: ['"plate"']
+                result: '3.0'
+            "#,
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            RibTestOutcome::Failed {
+                reason: "the script failed: failed to invoke function golem:it/api.{get-price}: \
+                    no mock for the call golem:it/api.{get-price}(\"mug\") on worker jon"
+                    .to_string()
+            }
+        );
+    }
+
+    #[test]
+    async fn test_rib_test_fails_on_unmet_call_count() {
+        let result = run_test(
+            r#"
+            name: item is added twice
+            mocks:
+              - function: get-price
+                result: '10.5'
+              - function: add-item
+                times: 2
+            "#,
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            RibTestOutcome::Failed {
+                reason: "expected add-item to be called 2 time(s), but it was called 1 time(s)"
+                    .to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_rib_test_suite_validation() {
+        let result = RibTestSuite::from_yaml(
+            r#"
+            tests:
+              - name: no script
+            "#,
+            Path::new("."),
+        );
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Test `no script` must have either `script` or `script-file`"
+        );
+    }
+
+    // Runs the test with the given mocks and expectation, and the same script and input
+    async fn run_test(test: &str) -> RibTestOutcome {
+        let mut test: RibTestCase = serde_yaml::from_str(test).unwrap();
+        test.script = SCRIPT.to_string();
+        test.input.insert(
+            "request".to_string(),
+            r#"{user: "jon", product: "mug"}"#.to_string(),
+        );
+
+        let runner = RibTestRunner::new(vec![component()]);
+
+        runner.run(&test).await.outcome
+    }
+
+    fn component() -> ComponentDependency {
+        ComponentDependency::new(
+            ComponentDependencyKey {
+                component_name: "shopping-cart".to_string(),
+                component_id: Uuid::new_v4(),
+                root_package_name: None,
+                root_package_version: None,
+            },
+            vec![AnalysedExport::Instance(AnalysedInstance {
+                name: "golem:it/api".to_string(),
+                functions: vec![
+                    AnalysedFunction {
+                        name: "get-price".to_string(),
+                        parameters: vec![AnalysedFunctionParameter {
+                            name: "product-id".to_string(),
+                            typ: str(),
+                        }],
+                        result: Some(AnalysedFunctionResult { typ: f64() }),
+                    },
+                    AnalysedFunction {
+                        name: "add-item".to_string(),
+                        parameters: vec![
+                            AnalysedFunctionParameter {
+                                name: "product-id".to_string(),
+                                typ: str(),
+                            },
+                            AnalysedFunctionParameter {
+                                name: "quantity".to_string(),
+                                typ: u32(),
+                            },
+                        ],
+                        result: None,
+                    },
+                ],
+            })],
+        )
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// A file of Rib tests, written in YAML (or JSON).
+///
+/// ```yaml
+/// components:
+///   - wasm: ./shopping-cart.wasm
+/// tests:
+///   - name: returns the contents of the cart
+///     script: |
+///       let worker = instance(request.path.user);
+///       worker.get-cart-contents()
+///     input:
+///       request: '{path: {user: "jon"}}'
+///     mocks:
+///       - function: get-cart-contents
+///         worker: jon
+///         result: '[{product-id: "p1", name: "Mug", price: 10.0, quantity: 2}]'
+///     expect:
+///       output: '[{product-id: "p1", name: "Mug", price: 10.0, quantity: 2}]'
+/// ```
+///
+/// Values (inputs, arguments and results of the mocks, and the expected output) are written
+/// in the WAVE format, and are typed based on what the script is inferred to use.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RibTestSuite {
+    #[serde(default)]
+    pub components: Vec<RibTestComponent>,
+    pub tests: Vec<RibTestCase>,
+}
+
+impl RibTestSuite {
+    /// Loads a test file, resolving the paths in it relative to the directory of the file
+    pub fn from_file(path: &Path) -> anyhow::Result<RibTestSuite> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read test file {}", path.display()))?;
+
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+
+        RibTestSuite::from_yaml(&content, base_dir)
+            .with_context(|| format!("Invalid test file {}", path.display()))
+    }
+
+    pub fn from_yaml(content: &str, base_dir: &Path) -> anyhow::Result<RibTestSuite> {
+        let mut suite: RibTestSuite = serde_yaml::from_str(content)?;
+
+        for component in &mut suite.components {
+            component.resolve_paths(base_dir);
+            component.validate()?;
+        }
+
+        for test in &mut suite.tests {
+            test.load_script(base_dir)?;
+            test.expect.validate(&test.name)?;
+        }
+
+        Ok(suite)
+    }
+}
+
+/// A component whose functions the scripts can call, loaded either from the WASM file
+/// or from a JSON file with its metadata, as returned by the component service.
+/// Calls to its functions are never made, and have to be mocked in the tests.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RibTestComponent {
+    /// The name of the component, defaulting to the file name without extension
+    pub name: Option<String>,
+    pub wasm: Option<PathBuf>,
+    pub metadata: Option<PathBuf>,
+}
+
+impl RibTestComponent {
+    pub fn wasm(path: PathBuf) -> RibTestComponent {
+        RibTestComponent {
+            name: None,
+            wasm: Some(path),
+            metadata: None,
+        }
+    }
+
+    fn resolve_paths(&mut self, base_dir: &Path) {
+        self.wasm = self.wasm.take().map(|path| base_dir.join(path));
+        self.metadata = self.metadata.take().map(|path| base_dir.join(path));
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match (&self.wasm, &self.metadata) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(anyhow!(
+                "A component must have exactly one of `wasm` or `metadata`"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RibTestCase {
+    pub name: String,
+    /// The Rib script under test. Loaded from `script-file` if it is not inlined.
+    #[serde(default)]
+    pub script: String,
+    pub script_file: Option<PathBuf>,
+    /// Values of the global inputs of the script, such as `request`
+    #[serde(default)]
+    pub input: BTreeMap<String, String>,
+    #[serde(default)]
+    pub mocks: Vec<RibTestMock>,
+    #[serde(default)]
+    pub expect: RibTestExpectation,
+}
+
+impl RibTestCase {
+    fn load_script(&mut self, base_dir: &Path) -> anyhow::Result<()> {
+        match self.script_file.take() {
+            Some(_) if !self.script.is_empty() => Err(anyhow!(
+                "Test `{}` must have only one of `script` or `script-file`",
+                self.name
+            )),
+            Some(script_file) => {
+                let path = base_dir.join(script_file);
+                self.script = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read script {}", path.display()))?;
+                Ok(())
+            }
+            None if self.script.is_empty() => Err(anyhow!(
+                "Test `{}` must have either `script` or `script-file`",
+                self.name
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The response to calls of a component function.
+///
+/// The function is either the fully qualified name, such as `golem:it/api.{add-item}`,
+/// or the part of it in the braces, such as `add-item` or `cart.add-item`.
+/// If `worker` or `args` are given, only the calls with the same worker name and arguments
+/// are answered by the mock. The first mock matching a call is used.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RibTestMock {
+    pub function: String,
+    pub worker: Option<String>,
+    pub args: Option<Vec<String>>,
+    /// The result of the call, if the function returns a value.
+    /// Resource constructors return a new handle if it is not given.
+    pub result: Option<String>,
+    /// Makes the call fail with this error instead
+    pub error: Option<String>,
+    /// The number of times the mock is expected to be called
+    pub times: Option<u64>,
+}
+
+impl RibTestMock {
+    pub fn new(function: &str) -> RibTestMock {
+        RibTestMock {
+            function: function.to_string(),
+            worker: None,
+            args: None,
+            result: None,
+            error: None,
+            times: None,
+        }
+    }
+
+    pub fn with_worker(mut self, worker: &str) -> RibTestMock {
+        self.worker = Some(worker.to_string());
+        self
+    }
+
+    pub fn with_args(mut self, args: Vec<&str>) -> RibTestMock {
+        self.args = Some(args.into_iter().map(|arg| arg.to_string()).collect());
+        self
+    }
+
+    pub fn with_result(mut self, result: &str) -> RibTestMock {
+        self.result = Some(result.to_string());
+        self
+    }
+
+    pub fn with_error(mut self, error: &str) -> RibTestMock {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    pub fn with_times(mut self, times: u64) -> RibTestMock {
+        self.times = Some(times);
+        self
+    }
+}
+
+/// What the evaluation of the script is expected to result in.
+/// If nothing is given, the test only checks that the script doesn't fail.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RibTestExpectation {
+    /// The result of the script
+    pub output: Option<String>,
+    /// A part of the message of the error the script is expected to fail with
+    pub error: Option<String>,
+}
+
+impl RibTestExpectation {
+    fn validate(&self, test_name: &str) -> anyhow::Result<()> {
+        if self.output.is_some() && self.error.is_some() {
+            Err(anyhow!(
+                "Test `{test_name}` cannot expect both an output and an error"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{ComponentDependencyKey, DynamicParsedFunctionName, Expr};
+use crate::{BuiltInFunction, ComponentDependencyKey, DynamicParsedFunctionName, Expr};
 use crate::{FullyQualifiedResourceConstructor, VariableId};
 use std::fmt::Display;
 
@@ -30,6 +30,11 @@ pub enum CallType {
     VariantConstructor(String),
     EnumConstructor(String),
     InstanceCreation(InstanceCreationType),
+    // Calls to the pure functions that ship with Rib, such as `string.lowercase(x)`
+    BuiltIn(BuiltInFunction),
+    // Calls to the functions defined within the Rib script, either with `fn` or
+    // by binding a closure to a variable. Holds the variable-id of the binding.
+    Lambda(VariableId),
 }
 
 // InstanceIdentifier holds the variables that are used to identify a worker or resource instance.
@@ -164,6 +169,8 @@ impl Display for CallType {
                     write!(f, "{}", resource_name.resource_name)
                 }
             },
+            CallType::BuiltIn(function) => write!(f, "{function}"),
+            CallType::Lambda(variable_id) => write!(f, "{variable_id}"),
         }
     }
 }
@@ -171,8 +178,11 @@ impl Display for CallType {
 #[cfg(feature = "protobuf")]
 mod protobuf {
     use crate::call_type::{CallType, InstanceCreationType};
-    use crate::FullyQualifiedResourceConstructor;
-    use crate::{ComponentDependencyKey, DynamicParsedFunctionName, Expr, ParsedFunctionName};
+    use crate::{BuiltInFunction, FullyQualifiedResourceConstructor};
+    use crate::{
+        ComponentDependencyKey, DynamicParsedFunctionName, DynamicParsedFunctionReference, Expr,
+        ParsedFunctionName, ParsedFunctionSite,
+    };
     use golem_api_grpc::proto::golem::rib::WorkerInstance;
 
     impl TryFrom<golem_api_grpc::proto::golem::rib::ComponentDependencyKey> for ComponentDependencyKey {
@@ -303,6 +313,11 @@ mod protobuf {
                     let instance_creation = InstanceCreationType::try_from(*instance_creation)?;
                     Ok(CallType::InstanceCreation(instance_creation))
                 }
+                golem_api_grpc::proto::golem::rib::call_type::Name::BuiltIn(name) => {
+                    let function = BuiltInFunction::from_qualified_name(&name)
+                        .ok_or(format!("Unknown built-in function {name}"))?;
+                    Ok(CallType::BuiltIn(function))
+                }
             }
         }
     }
@@ -330,6 +345,24 @@ mod protobuf {
                         golem_api_grpc::proto::golem::rib::call_type::Name::EnumConstructor(name),
                     ),
                 },
+                CallType::BuiltIn(function) => golem_api_grpc::proto::golem::rib::CallType {
+                    name: Some(golem_api_grpc::proto::golem::rib::call_type::Name::BuiltIn(
+                        function.qualified_name(),
+                    )),
+                },
+                // A call to a lambda is encoded the way it was parsed,
+                // and gets bound to its definition again during type inference
+                CallType::Lambda(variable_id) => golem_api_grpc::proto::golem::rib::CallType {
+                    name: Some(golem_api_grpc::proto::golem::rib::call_type::Name::Parsed(
+                        DynamicParsedFunctionName {
+                            site: ParsedFunctionSite::Global,
+                            function: DynamicParsedFunctionReference::Function {
+                                function: variable_id.name(),
+                            },
+                        }
+                        .into(),
+                    )),
+                },
                 CallType::InstanceCreation(instance_creation) => {
                     match instance_creation {
                         InstanceCreationType::WitWorker { worker_name , component_info} => {
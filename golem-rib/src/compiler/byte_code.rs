@@ -39,6 +39,9 @@ pub enum RibByteCodeGenerationError {
     },
     UnresolvedWorkerName,
     UnresolvedResourceVariable,
+    UnresolvedLambda {
+        function: String,
+    },
 }
 
 impl std::error::Error for RibByteCodeGenerationError {}
@@ -70,6 +73,9 @@ impl Display for RibByteCodeGenerationError {
             RibByteCodeGenerationError::UnresolvedWorkerName => {
                 write!(f, "inline invocation of functions on a worker instance is currently not supported")
             }
+            RibByteCodeGenerationError::UnresolvedLambda { function } => {
+                write!(f, "Unresolved definition of the function: {function}")
+            }
             _ => {
                 write!(f, "inline invocation of methods on resource constructor instance is currently not supported")
             }
@@ -94,6 +100,11 @@ impl RibByteCode {
         let mut instructions = Vec::new();
         let mut stack: Vec<ExprState> = Vec::new();
         let mut instruction_id = InstructionId::init();
+        // The expressions are compiled from the end, and hence the labels marking
+        // the start of the lambdas are allocated beforehand to compile the calls to them
+        let lambda_labels = internal::allocate_lambda_labels(expr, &mut instruction_id);
+        // `expr?` returns early with a value of the type of the rib script
+        let return_type = expr.inferred_type();
         stack.push(ExprState::from_expr(expr));
 
         while let Some(remaining) = stack.pop() {
@@ -104,6 +115,8 @@ impl RibByteCode {
                         &mut stack,
                         &mut instructions,
                         &mut instruction_id,
+                        &lambda_labels,
+                        &return_type,
                     )?;
                 }
 
@@ -157,12 +170,12 @@ mod protobuf {
 mod internal {
     use crate::compiler::desugar::{desugar_pattern_match, desugar_range_selection};
     use crate::{
-        AnalysedTypeWithUnit, DynamicParsedFunctionReference, Expr, FunctionReferenceType,
-        InferredType, InstanceIdentifier, InstanceVariable, InstructionId, Range,
-        RibByteCodeGenerationError, RibIR, TypeInternal, VariableId,
+        AnalysedTypeWithUnit, DynamicParsedFunctionReference, Expr, ExprVisitor,
+        FunctionReferenceType, InferredType, InstanceIdentifier, InstanceVariable, InstructionId,
+        LambdaParameter, Range, RibByteCodeGenerationError, RibIR, TypeInternal, VariableId,
     };
     use golem_wasm_ast::analysis::{AnalysedType, TypeFlags};
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
 
     use crate::call_type::{CallType, InstanceCreationType};
     use crate::type_inference::{GetTypeHint, TypeHint};
@@ -170,11 +183,35 @@ mod internal {
     use golem_wasm_rpc::{IntoValueAndType, Value, ValueAndType};
     use std::ops::Deref;
 
+    pub(crate) fn allocate_lambda_labels(
+        expr: &Expr,
+        instruction_id: &mut InstructionId,
+    ) -> HashMap<VariableId, InstructionId> {
+        let mut lambda_labels = HashMap::new();
+        let mut expr = expr.clone();
+        let mut visitor = ExprVisitor::bottom_up(&mut expr);
+
+        while let Some(expr) = visitor.pop_front() {
+            if let Expr::Let {
+                variable_id, expr, ..
+            } = expr
+            {
+                if expr.is_lambda() {
+                    lambda_labels.insert(variable_id.clone(), instruction_id.increment_mut());
+                }
+            }
+        }
+
+        lambda_labels
+    }
+
     pub(crate) fn process_expr(
         expr: &Expr,
         stack: &mut Vec<ExprState>,
         instructions: &mut Vec<RibIR>,
         instruction_id: &mut InstructionId,
+        lambda_labels: &HashMap<VariableId, InstructionId>,
+        return_type: &InferredType,
     ) -> Result<(), RibByteCodeGenerationError> {
         match expr {
             Expr::Unwrap { expr, .. } => {
@@ -224,6 +261,11 @@ mod internal {
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::EqualTo);
             }
+            Expr::NotEqualTo { lhs, rhs, .. } => {
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::NotEqualTo);
+            }
             Expr::GreaterThan { lhs, rhs, .. } => {
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
@@ -291,6 +333,78 @@ mod internal {
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::Multiply(analysed_type));
             }
+            Expr::Modulo {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::Modulo(analysed_type));
+            }
+            Expr::BitwiseAnd {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::BitwiseAnd(analysed_type));
+            }
+            Expr::BitwiseOr {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::BitwiseOr(analysed_type));
+            }
+            Expr::BitwiseXor {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::BitwiseXor(analysed_type));
+            }
+            Expr::ShiftLeft {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::ShiftLeft(analysed_type));
+            }
+            Expr::ShiftRight {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::ShiftRight(analysed_type));
+            }
             Expr::And { lhs, rhs, .. } => {
                 // This optimization isn't optional, it's required for the correct functioning of the interpreter
                 let optimised_expr = Expr::cond(
@@ -347,9 +461,36 @@ mod internal {
             }
             Expr::Let {
                 variable_id, expr, ..
+            } => match expr.deref() {
+                Expr::Lambda {
+                    parameters, body, ..
+                } => {
+                    let entry_label = get_lambda_label(lambda_labels, variable_id)?;
+                    handle_lambda(instruction_id, stack, entry_label, parameters, body);
+                }
+                _ => {
+                    stack.push(ExprState::from_expr(expr.deref()));
+                    instructions.push(RibIR::AssignVar(variable_id.clone()));
+                }
+            },
+
+            // A lambda that is not bound to a name is never called, and
+            // the ones passed to higher order functions are already inlined
+            Expr::Lambda { .. } => {}
+
+            Expr::Propagate { expr: inner, .. } => {
+                let return_type = convert_to_analysed_type(expr, return_type)?;
+                stack.push(ExprState::from_expr(inner.deref()));
+                instructions.push(RibIR::Propagate(return_type));
+            }
+
+            Expr::TryCatch {
+                try_expr,
+                error_variable,
+                catch_expr,
+                ..
             } => {
-                stack.push(ExprState::from_expr(expr.deref()));
-                instructions.push(RibIR::AssignVar(variable_id.clone()));
+                handle_try_catch(instruction_id, try_expr, error_variable, catch_expr, stack);
             }
             Expr::PatternMatch {
                 predicate,
@@ -388,6 +529,13 @@ mod internal {
                         })?;
                     stack.push(ExprState::from_expr(&list_comprehension));
                 }
+                // Maps are lists of key-value tuples at runtime, and therefore
+                // selecting by key is decided at compile time based on the type
+                _ if expr.inferred_type().is_map() => {
+                    stack.push(ExprState::from_expr(index.deref()));
+                    stack.push(ExprState::from_expr(expr.deref()));
+                    instructions.push(RibIR::SelectKey);
+                }
                 _ => {
                     stack.push(ExprState::from_expr(index.deref()));
                     stack.push(ExprState::from_expr(expr.deref()));
@@ -708,6 +856,25 @@ mod internal {
                             convert_to_analysed_type(expr, inferred_type)?,
                         ));
                     }
+                    CallType::BuiltIn(function) => {
+                        for expr in args.iter().rev() {
+                            stack.push(ExprState::from_expr(expr));
+                        }
+
+                        instructions.push(RibIR::CallBuiltIn(
+                            *function,
+                            convert_to_analysed_type(expr, inferred_type)?,
+                        ));
+                    }
+                    CallType::Lambda(variable_id) => {
+                        let entry_label = get_lambda_label(lambda_labels, variable_id)?;
+
+                        for expr in args.iter().rev() {
+                            stack.push(ExprState::from_expr(expr));
+                        }
+
+                        instructions.push(RibIR::CallLambda(entry_label));
+                    }
                 }
             }
 
@@ -758,6 +925,36 @@ mod internal {
                 instructions.push(RibIR::Negate);
             }
 
+            // A map is a list of key-value tuples at runtime
+            Expr::Map {
+                entries,
+                inferred_type,
+                ..
+            } => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                let entry_type = match &analysed_type {
+                    AnalysedType::List(list_type) => list_type.inner.deref().clone(),
+                    _ => {
+                        return Err(RibByteCodeGenerationError::UnexpectedTypeError {
+                            expected: TypeHint::Map {
+                                key: None,
+                                value: None,
+                            },
+                            actual: inferred_type.get_type_hint(),
+                        });
+                    }
+                };
+
+                for (key, value) in entries.iter().rev() {
+                    stack.push(ExprState::from_expr(value));
+                    stack.push(ExprState::from_expr(key));
+                    stack.push(ExprState::from_ir(RibIR::PushTuple(entry_type.clone(), 2)));
+                }
+
+                instructions.push(RibIR::PushList(analysed_type, entries.len()));
+            }
+
             Expr::Tuple {
                 exprs,
                 inferred_type,
@@ -981,6 +1178,75 @@ mod internal {
         stack.push(ExprState::from_ir(RibIR::LoadVar(reduce_variable.clone())))
     }
 
+    fn get_lambda_label(
+        lambda_labels: &HashMap<VariableId, InstructionId>,
+        variable_id: &VariableId,
+    ) -> Result<InstructionId, RibByteCodeGenerationError> {
+        lambda_labels.get(variable_id).cloned().ok_or_else(|| {
+            RibByteCodeGenerationError::UnresolvedLambda {
+                function: variable_id.name(),
+            }
+        })
+    }
+
+    // The body of a lambda is skipped over where it is defined, and is reached only through
+    // `CallLambda`, which leaves the arguments on the stack with the first argument at the top
+    fn handle_lambda(
+        instruction_id: &mut InstructionId,
+        stack: &mut Vec<ExprState>,
+        entry_label: InstructionId,
+        parameters: &[LambdaParameter],
+        body: &Expr,
+    ) {
+        let exit_label = instruction_id.increment_mut();
+
+        stack.push(ExprState::from_ir(RibIR::Jump(exit_label.clone())));
+
+        stack.push(ExprState::from_ir(RibIR::Label(entry_label)));
+
+        for parameter in parameters {
+            stack.push(ExprState::from_ir(RibIR::AssignVar(
+                parameter.variable_id.clone(),
+            )));
+        }
+
+        stack.push(ExprState::from_expr(body));
+
+        stack.push(ExprState::from_ir(RibIR::Return));
+
+        stack.push(ExprState::from_ir(RibIR::Label(exit_label)));
+    }
+
+    fn handle_try_catch(
+        instruction_id: &mut InstructionId,
+        try_expr: &Expr,
+        error_variable: &VariableId,
+        catch_expr: &Expr,
+        stack: &mut Vec<ExprState>,
+    ) {
+        let catch_label = instruction_id.increment_mut();
+        let exit_label = instruction_id.increment_mut();
+
+        stack.push(ExprState::from_ir(RibIR::PushErrorHandler(
+            catch_label.clone(),
+        )));
+
+        stack.push(ExprState::from_expr(try_expr));
+
+        stack.push(ExprState::from_ir(RibIR::PopErrorHandler));
+
+        stack.push(ExprState::from_ir(RibIR::Jump(exit_label.clone())));
+
+        // The interpreter pushes the error to the stack before jumping to the catch block
+        stack.push(ExprState::from_ir(RibIR::Label(catch_label)));
+
+        stack.push(ExprState::from_ir(RibIR::AssignVar(error_variable.clone())));
+
+        stack.push(ExprState::from_expr(catch_expr));
+
+        stack.push(ExprState::from_ir(RibIR::Label(exit_label)));
+    }
+
     fn handle_if_condition(
         instruction_id: &mut InstructionId,
         if_expr: &Expr,
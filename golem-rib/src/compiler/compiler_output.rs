@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::compiler::worker_functions_in_rib::WorkerFunctionsInRib;
-use crate::{RibByteCode, RibInputTypeInfo, RibOutputTypeInfo};
+use crate::{RibByteCode, RibInputTypeInfo, RibOutputTypeInfo, RibWarning};
 
 #[derive(Debug, Clone)]
 pub struct CompilerOutput {
@@ -26,6 +26,9 @@ pub struct CompilerOutput {
     // At the API level, if we have access to expr, whenever this field is optional
     // we can compile the expression again and get the output type info
     pub rib_output_type_info: Option<RibOutputTypeInfo>,
+    // Warnings of the lint pass. These are not part of the proto conversions,
+    // as they are relevant only at the time of compilation.
+    pub warnings: Vec<RibWarning>,
 }
 
 #[cfg(feature = "protobuf")]
@@ -59,6 +62,7 @@ mod protobuf {
                 byte_code,
                 rib_input_type_info: rib_input,
                 rib_output_type_info,
+                warnings: vec![],
             })
         }
     }
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AnalysedTypeWithUnit, ComponentDependencyKey, ParsedFunctionSite, VariableId};
+use crate::{
+    AnalysedTypeWithUnit, BuiltInFunction, ComponentDependencyKey, ParsedFunctionSite, VariableId,
+};
 use bincode::{Decode, Encode};
 use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::ValueAndType;
@@ -36,7 +38,9 @@ pub enum RibIR {
     SelectField(String),
     SelectIndex(usize), // Kept for backward compatibility. Cannot read old SelectIndex(usize) as a SelectIndexV1
     SelectIndexV1,
+    SelectKey,
     EqualTo,
+    NotEqualTo,
     GreaterThan,
     And,
     Or,
@@ -64,6 +68,12 @@ pub enum RibIR {
     Minus(AnalysedType),
     Divide(AnalysedType),
     Multiply(AnalysedType),
+    Modulo(AnalysedType),
+    BitwiseAnd(AnalysedType),
+    BitwiseOr(AnalysedType),
+    BitwiseXor(AnalysedType),
+    ShiftLeft(AnalysedType),
+    ShiftRight(AnalysedType),
     Negate,
     ToIterator,
     CreateSink(AnalysedType),
@@ -72,6 +82,12 @@ pub enum RibIR {
     SinkToList,
     Length,
     GenerateWorkerName(Option<VariableId>),
+    CallBuiltIn(BuiltInFunction, AnalysedType), // Arguments are popped as per the arity of the function
+    CallLambda(InstructionId), // Pushes a call frame and jumps to the label marking the start of the function body
+    Return, // Pops the call frame and resumes from the instruction following the call
+    Propagate(AnalysedType), // Unwraps `some` or `ok`, otherwise returns the `none` or `err` value as the result of the rib script (of the given type)
+    PushErrorHandler(InstructionId), // Any catchable runtime error until the handler is popped jumps to the label marking the start of the catch block
+    PopErrorHandler,
 }
 
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
@@ -162,14 +178,14 @@ impl InstructionId {
 #[cfg(feature = "protobuf")]
 mod protobuf {
     use crate::{
-        AnalysedTypeWithUnit, ComponentDependencyKey, FunctionReferenceType, InstanceVariable,
-        InstructionId, ParsedFunctionSite, RibIR, VariableId,
+        AnalysedTypeWithUnit, BuiltInFunction, ComponentDependencyKey, FunctionReferenceType,
+        InstanceVariable, InstructionId, ParsedFunctionSite, RibIR, VariableId,
     };
     use golem_api_grpc::proto::golem::rib::rib_ir::Instruction;
     use golem_api_grpc::proto::golem::rib::{
         And, ConcatInstruction, CreateFunctionNameInstruction, EqualTo, GetTag, GreaterThan,
         GreaterThanOrEqualTo, InvokeFunctionInstruction, IsEmpty, JumpInstruction, LessThan,
-        LessThanOrEqualTo, Negate, Or, PushListInstruction, PushNoneInstruction,
+        LessThanOrEqualTo, Negate, NotEqualTo, Or, PushListInstruction, PushNoneInstruction,
         PushTupleInstruction, RibIr as ProtoRibIR, WitResource,
     };
     use golem_wasm_ast::analysis::{AnalysedType, TypeStr};
@@ -345,6 +361,36 @@ mod protobuf {
                         "Failed to convert CreateAndPushRecord".to_string()
                     })?))
                 }
+                Instruction::Modulo(value) => Ok(RibIR::Modulo(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert Modulo".to_string())?,
+                )),
+                Instruction::BitwiseAnd(value) => Ok(RibIR::BitwiseAnd(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert BitwiseAnd".to_string())?,
+                )),
+                Instruction::BitwiseOr(value) => Ok(RibIR::BitwiseOr(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert BitwiseOr".to_string())?,
+                )),
+                Instruction::BitwiseXor(value) => Ok(RibIR::BitwiseXor(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert BitwiseXor".to_string())?,
+                )),
+                Instruction::ShiftLeft(value) => Ok(RibIR::ShiftLeft(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert ShiftLeft".to_string())?,
+                )),
+                Instruction::ShiftRight(value) => Ok(RibIR::ShiftRight(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert ShiftRight".to_string())?,
+                )),
 
                 Instruction::UpdateRecord(value) => Ok(RibIR::UpdateRecord(value)),
                 Instruction::PushList(value) => Ok(RibIR::PushList(
@@ -385,7 +431,9 @@ mod protobuf {
                 Instruction::SelectField(value) => Ok(RibIR::SelectField(value)),
                 Instruction::SelectIndex(value) => Ok(RibIR::SelectIndex(value as usize)),
                 Instruction::SelectIndexV1(_) => Ok(RibIR::SelectIndexV1),
+                Instruction::SelectKey(_) => Ok(RibIR::SelectKey),
                 Instruction::EqualTo(_) => Ok(RibIR::EqualTo),
+                Instruction::NotEqualTo(_) => Ok(RibIR::NotEqualTo),
                 Instruction::GreaterThan(_) => Ok(RibIR::GreaterThan),
                 Instruction::LessThan(_) => Ok(RibIR::LessThan),
                 Instruction::GreaterThanOrEqualTo(_) => Ok(RibIR::GreaterThanOrEqualTo),
@@ -534,6 +582,35 @@ mod protobuf {
                 Instruction::AdvanceIterator(_) => Ok(RibIR::AdvanceIterator),
                 Instruction::SinkToList(_) => Ok(RibIR::SinkToList),
                 Instruction::PushToSink(_) => Ok(RibIR::PushToSink),
+                Instruction::CallBuiltIn(call_built_in) => {
+                    let function =
+                        BuiltInFunction::from_qualified_name(&call_built_in.function_name).ok_or(
+                            format!("Unknown built-in function {}", call_built_in.function_name),
+                        )?;
+
+                    let return_type = call_built_in
+                        .return_type
+                        .ok_or("Missing return_type for built-in function call".to_string())
+                        .and_then(|t| {
+                            (&t).try_into()
+                                .map_err(|_| "Failed to convert AnalysedType".to_string())
+                        })?;
+
+                    Ok(RibIR::CallBuiltIn(function, return_type))
+                }
+                Instruction::CallLambda(value) => Ok(RibIR::CallLambda(InstructionId::new(
+                    value.instruction_id as usize,
+                ))),
+                Instruction::Return(_) => Ok(RibIR::Return),
+                Instruction::Propagate(value) => Ok(RibIR::Propagate(
+                    (&value)
+                        .try_into()
+                        .map_err(|_| "Failed to convert Propagate".to_string())?,
+                )),
+                Instruction::PushErrorHandler(value) => Ok(RibIR::PushErrorHandler(
+                    InstructionId::new(value.instruction_id as usize),
+                )),
+                Instruction::PopErrorHandler(_) => Ok(RibIR::PopErrorHandler),
             }
         }
     }
@@ -565,6 +642,12 @@ mod protobuf {
                 RibIR::Minus(value) => Instruction::Minus((&value).into()),
                 RibIR::Multiply(value) => Instruction::Multiply((&value).into()),
                 RibIR::Divide(value) => Instruction::Divide((&value).into()),
+                RibIR::Modulo(value) => Instruction::Modulo((&value).into()),
+                RibIR::BitwiseAnd(value) => Instruction::BitwiseAnd((&value).into()),
+                RibIR::BitwiseOr(value) => Instruction::BitwiseOr((&value).into()),
+                RibIR::BitwiseXor(value) => Instruction::BitwiseXor((&value).into()),
+                RibIR::ShiftLeft(value) => Instruction::ShiftLeft((&value).into()),
+                RibIR::ShiftRight(value) => Instruction::ShiftRight((&value).into()),
                 RibIR::UpdateRecord(value) => Instruction::UpdateRecord(value),
                 RibIR::PushList(value, arg_size) => Instruction::PushList(PushListInstruction {
                     list_type: Some((&value).into()),
@@ -582,12 +665,16 @@ mod protobuf {
                 RibIR::SelectField(value) => Instruction::SelectField(value),
                 RibIR::SelectIndex(value) => Instruction::SelectIndex(value as u64),
                 RibIR::EqualTo => Instruction::EqualTo(EqualTo {}),
+                RibIR::NotEqualTo => Instruction::NotEqualTo(NotEqualTo {}),
                 RibIR::GreaterThan => Instruction::GreaterThan(GreaterThan {}),
                 RibIR::LessThan => Instruction::LessThan(LessThan {}),
                 RibIR::Length => Instruction::Length(golem_api_grpc::proto::golem::rib::Length {}),
                 RibIR::SelectIndexV1 => {
                     Instruction::SelectIndexV1(golem_api_grpc::proto::golem::rib::SelectIndexV1 {})
                 }
+                RibIR::SelectKey => {
+                    Instruction::SelectKey(golem_api_grpc::proto::golem::rib::SelectKey {})
+                }
                 RibIR::GreaterThanOrEqualTo => {
                     Instruction::GreaterThanOrEqualTo(GreaterThanOrEqualTo {})
                 }
@@ -715,6 +802,23 @@ mod protobuf {
                 RibIR::SinkToList => {
                     Instruction::SinkToList(golem_api_grpc::proto::golem::rib::SinkToList {})
                 }
+                RibIR::CallBuiltIn(function, return_type) => Instruction::CallBuiltIn(
+                    golem_api_grpc::proto::golem::rib::CallBuiltInInstruction {
+                        function_name: function.qualified_name(),
+                        return_type: Some((&return_type).into()),
+                    },
+                ),
+                RibIR::CallLambda(value) => Instruction::CallLambda(JumpInstruction {
+                    instruction_id: value.index as u64,
+                }),
+                RibIR::Return => Instruction::Return(golem_api_grpc::proto::golem::rib::Return {}),
+                RibIR::Propagate(value) => Instruction::Propagate((&value).into()),
+                RibIR::PushErrorHandler(value) => Instruction::PushErrorHandler(JumpInstruction {
+                    instruction_id: value.index as u64,
+                }),
+                RibIR::PopErrorHandler => Instruction::PopErrorHandler(
+                    golem_api_grpc::proto::golem::rib::PopErrorHandler {},
+                ),
             };
 
             Ok(ProtoRibIR {
@@ -20,8 +20,8 @@ pub use worker_functions_in_rib::*;
 
 use crate::rib_type_error::RibTypeError;
 use crate::{
-    ComponentDependencies, ComponentDependencyKey, Expr, GlobalVariableTypeSpec, InferredExpr,
-    RibInputTypeInfo, RibOutputTypeInfo,
+    lint, ComponentDependencies, ComponentDependencyKey, Expr, GlobalVariableTypeSpec,
+    InferredExpr, RibInputTypeInfo, RibOutputTypeInfo,
 };
 use golem_wasm_ast::analysis::{AnalysedExport, TypeEnum, TypeVariant};
 use std::error::Error;
@@ -109,11 +109,14 @@ impl RibCompiler {
 
         let byte_code = RibByteCode::from_expr(&inferred_expr)?;
 
+        let warnings = lint(inferred_expr.get_expr());
+
         Ok(CompilerOutput {
             worker_invoke_calls: function_calls_identified,
             byte_code,
             rib_input_type_info: global_input_type_info,
             rib_output_type_info: Some(output_type_info),
+            warnings,
         })
     }
 
@@ -174,6 +177,10 @@ impl ComponentDependency {
             component_exports,
         }
     }
+
+    pub fn component_dependency_key(&self) -> &ComponentDependencyKey {
+        &self.component_dependency_key
+    }
 }
 
 pub trait GenerateWorkerName {
@@ -895,6 +902,126 @@ mod compiler_error_tests {
         }
     }
 
+    mod warnings {
+        use test_r::test;
+
+        use crate::compiler::compiler_error_tests::test_utils;
+        use crate::{Expr, RibCompiler, RibCompilerConfig, RibWarningKind};
+
+        fn warnings(rib: &str) -> Vec<(RibWarningKind, String)> {
+            let expr = Expr::from_text(rib).unwrap();
+            let compiler =
+                RibCompiler::new(RibCompilerConfig::new(test_utils::get_metadata(), vec![]));
+
+            compiler
+                .compile(expr)
+                .unwrap()
+                .warnings
+                .into_iter()
+                .map(|warning| (warning.kind, warning.to_string()))
+                .collect()
+        }
+
+        #[test]
+        async fn test_no_warnings() {
+            let rib = r#"
+              let x: u32 = 1;
+              let worker = instance("my-worker");
+              let cart = worker.cart("foo");
+              let result = cart.get-cart-contents();
+              for item in [1, 2, 3] {
+                yield cart.get-cart-contents();
+              };
+              if x > 1 then result else []
+            "#;
+
+            assert_eq!(warnings(rib), vec![]);
+        }
+
+        #[test]
+        async fn test_unused_and_shadowed_variables() {
+            let rib = r#"
+              let x: u32 = 1;
+              let y: u32 = 2;
+              let x: u32 = 3;
+              x
+            "#;
+
+            assert_eq!(
+                warnings(rib),
+                vec![
+                    (
+                        RibWarningKind::UnusedVariable,
+                        "warning at line 2, column 15: unused variable `x`".to_string()
+                    ),
+                    (
+                        RibWarningKind::UnusedVariable,
+                        "warning at line 3, column 15: unused variable `y`".to_string()
+                    ),
+                    (
+                        RibWarningKind::ShadowedVariable,
+                        "warning at line 4, column 15: `x` shadows an earlier binding of the same name".to_string()
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        async fn test_constant_condition() {
+            let rib = r#"
+              let x: u32 = 1;
+              if !(1 == 2) || false then x else 2
+            "#;
+
+            assert_eq!(
+                warnings(rib),
+                vec![(
+                    RibWarningKind::ConstantCondition,
+                    "warning at line 3, column 15: condition is always true, and the else branch is never evaluated".to_string()
+                )]
+            );
+        }
+
+        #[test]
+        async fn test_unreachable_match_arm() {
+            let rib = r#"
+              let x: option<u32> = some(1);
+              match x {
+                some(1) => "one",
+                some(2) => "two",
+                some(1) => "another one",
+                some(_) => "some",
+                none => "none"
+              }
+            "#;
+
+            let warnings = warnings(rib);
+
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(warnings[0].0, RibWarningKind::UnreachableMatchArm);
+            assert!(warnings[0].1.contains("unreachable match arm `some(1)`"));
+            assert!(warnings[0].1.contains("already matched by arm 1"));
+        }
+
+        #[test]
+        async fn test_unbounded_worker_calls() {
+            let rib = r#"
+              let worker = instance("my-worker");
+              let cart = worker.cart("foo");
+              let ids: list<string> = request.body.ids;
+              for id in ids {
+                yield cart.get-cart-contents();
+              }
+            "#;
+
+            let warnings = warnings(rib);
+
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(warnings[0].0, RibWarningKind::UnboundedWorkerCalls);
+            assert!(warnings[0].1.contains("get-cart-contents"));
+        }
+    }
+
     mod test_utils {
         use crate::{ComponentDependency, ComponentDependencyKey};
         use golem_wasm_ast::analysis::analysed_type::{
@@ -195,6 +195,16 @@ impl TryFrom<&InferredType> for AnalysedTypeWithUnit {
                     name: None,
                 }),
             )),
+            // WIT has no map type, and a map is represented as a list of key-value tuples
+            TypeInternal::Map { key, value } => Ok(AnalysedTypeWithUnit::analysed_type(
+                AnalysedType::List(TypeList {
+                    inner: Box::new(AnalysedType::Tuple(TypeTuple {
+                        items: vec![key.try_into()?, value.try_into()?],
+                        name: None,
+                    })),
+                    name: None,
+                }),
+            )),
             TypeInternal::Result { ok, error } => Ok(AnalysedTypeWithUnit::analysed_type(
                 // In the case of result, there are instances users give just 1 value with zero function calls, we need to be flexible here
                 AnalysedType::Result(TypeResult {
@@ -80,6 +80,12 @@ pub enum Expr {
         inferred_type: InferredType,
         source_span: SourceSpan,
     },
+    Map {
+        entries: Vec<(Expr, Expr)>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
     Tuple {
         exprs: Vec<Expr>,
         type_annotation: Option<TypeName>,
@@ -197,6 +203,48 @@ pub enum Expr {
         inferred_type: InferredType,
         source_span: SourceSpan,
     },
+    Modulo {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    BitwiseAnd {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    BitwiseOr {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    BitwiseXor {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    ShiftLeft {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    ShiftRight {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
     EqualTo {
         lhs: Box<Expr>,
         rhs: Box<Expr>,
@@ -204,6 +252,13 @@ pub enum Expr {
         inferred_type: InferredType,
         source_span: SourceSpan,
     },
+    NotEqualTo {
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
     LessThan {
         lhs: Box<Expr>,
         rhs: Box<Expr>,
@@ -307,6 +362,34 @@ pub enum Expr {
         type_annotation: Option<TypeName>,
         source_span: SourceSpan,
     },
+    // A closure such as `|x| x + 1`. Functions declared with `fn` are
+    // let bindings of a lambda, and are invoked through `CallType::Lambda`
+    Lambda {
+        parameters: Vec<LambdaParameter>,
+        return_type: Option<TypeName>,
+        body: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    // `expr?` unwraps an `ok` or a `some` value, and otherwise returns early
+    // from the Rib script with the `err` or `none` value as the result
+    Propagate {
+        expr: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
+    // `try { .. } catch e { .. }` evaluates the catch block if evaluating the try block fails,
+    // with the error bound to `e` as a variant of the type `RibRuntimeError::error_type`
+    TryCatch {
+        try_expr: Box<Expr>,
+        error_variable: VariableId,
+        catch_expr: Box<Expr>,
+        type_annotation: Option<TypeName>,
+        inferred_type: InferredType,
+        source_span: SourceSpan,
+    },
 
     GenerateWorkerName {
         inferred_type: InferredType,
@@ -428,6 +511,7 @@ impl Expr {
                 | Expr::GreaterThanOrEqualTo { .. }
                 | Expr::LessThanOrEqualTo { .. }
                 | Expr::EqualTo { .. }
+                | Expr::NotEqualTo { .. }
                 | Expr::LessThan { .. }
         )
     }
@@ -550,6 +634,66 @@ impl Expr {
         }
     }
 
+    pub fn modulo(left: Expr, right: Expr) -> Self {
+        Expr::Modulo {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
+    pub fn bitwise_and(left: Expr, right: Expr) -> Self {
+        Expr::BitwiseAnd {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
+    pub fn bitwise_or(left: Expr, right: Expr) -> Self {
+        Expr::BitwiseOr {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
+    pub fn bitwise_xor(left: Expr, right: Expr) -> Self {
+        Expr::BitwiseXor {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
+    pub fn shift_left(left: Expr, right: Expr) -> Self {
+        Expr::ShiftLeft {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
+    pub fn shift_right(left: Expr, right: Expr) -> Self {
+        Expr::ShiftRight {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
     pub fn and_combine(conditions: Vec<Expr>) -> Option<Expr> {
         let mut cond: Option<Expr> = None;
 
@@ -650,6 +794,16 @@ impl Expr {
         }
     }
 
+    pub fn not_equal_to(left: Expr, right: Expr) -> Self {
+        Expr::NotEqualTo {
+            lhs: Box::new(left),
+            rhs: Box::new(right),
+            inferred_type: InferredType::bool(),
+            source_span: SourceSpan::default(),
+            type_annotation: None,
+        }
+    }
+
     pub fn err(expr: Expr, type_annotation: Option<TypeName>) -> Self {
         let inferred_type = expr.inferred_type();
         Expr::Result {
@@ -793,6 +947,55 @@ impl Expr {
         }
     }
 
+    // `fn name(parameters): return_type { body }` is a let binding of a lambda
+    pub fn function_definition(
+        name: impl AsRef<str>,
+        parameters: Vec<LambdaParameter>,
+        return_type: Option<TypeName>,
+        body: Expr,
+    ) -> Self {
+        Expr::let_binding(name, Expr::lambda(parameters, return_type, body), None)
+    }
+
+    pub fn lambda(
+        parameters: Vec<LambdaParameter>,
+        return_type: Option<TypeName>,
+        body: Expr,
+    ) -> Self {
+        Expr::Lambda {
+            parameters,
+            return_type,
+            body: Box::new(body),
+            type_annotation: None,
+            inferred_type: InferredType::unit(),
+            source_span: SourceSpan::default(),
+        }
+    }
+
+    pub fn is_lambda(&self) -> bool {
+        matches!(self, Expr::Lambda { .. })
+    }
+
+    pub fn propagate(expr: Expr) -> Self {
+        Expr::Propagate {
+            expr: Box::new(expr),
+            type_annotation: None,
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+        }
+    }
+
+    pub fn try_catch(try_expr: Expr, error_variable: impl AsRef<str>, catch_expr: Expr) -> Self {
+        Expr::TryCatch {
+            try_expr: Box::new(try_expr),
+            error_variable: VariableId::global(error_variable.as_ref().to_string()),
+            catch_expr: Box::new(catch_expr),
+            type_annotation: None,
+            inferred_type: InferredType::unknown(),
+            source_span: SourceSpan::default(),
+        }
+    }
+
     pub fn let_binding_with_variable_id(
         variable_id: VariableId,
         expr: Expr,
@@ -983,6 +1186,20 @@ impl Expr {
         }
     }
 
+    pub fn map(entries: Vec<(Expr, Expr)>, type_annotation: Option<TypeName>) -> Self {
+        let inferred_type = entries.first().map_or(
+            InferredType::map(InferredType::unknown(), InferredType::unknown()),
+            |(key, value)| InferredType::map(key.inferred_type(), value.inferred_type()),
+        );
+
+        Expr::Map {
+            entries,
+            type_annotation,
+            inferred_type,
+            source_span: SourceSpan::default(),
+        }
+    }
+
     pub fn select_field(
         expr: Expr,
         field: impl AsRef<str>,
@@ -1054,6 +1271,7 @@ impl Expr {
             | Expr::SelectIndex { inferred_type, .. }
             | Expr::Sequence { inferred_type, .. }
             | Expr::Record { inferred_type, .. }
+            | Expr::Map { inferred_type, .. }
             | Expr::Tuple { inferred_type, .. }
             | Expr::Literal { inferred_type, .. }
             | Expr::Number { inferred_type, .. }
@@ -1067,10 +1285,17 @@ impl Expr {
             | Expr::GreaterThanOrEqualTo { inferred_type, .. }
             | Expr::LessThanOrEqualTo { inferred_type, .. }
             | Expr::EqualTo { inferred_type, .. }
+            | Expr::NotEqualTo { inferred_type, .. }
             | Expr::Plus { inferred_type, .. }
             | Expr::Minus { inferred_type, .. }
             | Expr::Divide { inferred_type, .. }
             | Expr::Multiply { inferred_type, .. }
+            | Expr::Modulo { inferred_type, .. }
+            | Expr::BitwiseAnd { inferred_type, .. }
+            | Expr::BitwiseOr { inferred_type, .. }
+            | Expr::BitwiseXor { inferred_type, .. }
+            | Expr::ShiftLeft { inferred_type, .. }
+            | Expr::ShiftRight { inferred_type, .. }
             | Expr::LessThan { inferred_type, .. }
             | Expr::Cond { inferred_type, .. }
             | Expr::PatternMatch { inferred_type, .. }
@@ -1087,6 +1312,9 @@ impl Expr {
             | Expr::Range { inferred_type, .. }
             | Expr::InvokeMethodLazy { inferred_type, .. }
             | Expr::Length { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Propagate { inferred_type, .. }
+            | Expr::TryCatch { inferred_type, .. }
             | Expr::GenerateWorkerName { inferred_type, .. } => &mut *inferred_type,
         }
     }
@@ -1098,6 +1326,7 @@ impl Expr {
             | Expr::SelectIndex { inferred_type, .. }
             | Expr::Sequence { inferred_type, .. }
             | Expr::Record { inferred_type, .. }
+            | Expr::Map { inferred_type, .. }
             | Expr::Tuple { inferred_type, .. }
             | Expr::Literal { inferred_type, .. }
             | Expr::Number { inferred_type, .. }
@@ -1111,10 +1340,17 @@ impl Expr {
             | Expr::GreaterThanOrEqualTo { inferred_type, .. }
             | Expr::LessThanOrEqualTo { inferred_type, .. }
             | Expr::EqualTo { inferred_type, .. }
+            | Expr::NotEqualTo { inferred_type, .. }
             | Expr::Plus { inferred_type, .. }
             | Expr::Minus { inferred_type, .. }
             | Expr::Divide { inferred_type, .. }
             | Expr::Multiply { inferred_type, .. }
+            | Expr::Modulo { inferred_type, .. }
+            | Expr::BitwiseAnd { inferred_type, .. }
+            | Expr::BitwiseOr { inferred_type, .. }
+            | Expr::BitwiseXor { inferred_type, .. }
+            | Expr::ShiftLeft { inferred_type, .. }
+            | Expr::ShiftRight { inferred_type, .. }
             | Expr::LessThan { inferred_type, .. }
             | Expr::Cond { inferred_type, .. }
             | Expr::PatternMatch { inferred_type, .. }
@@ -1131,6 +1367,9 @@ impl Expr {
             | Expr::Range { inferred_type, .. }
             | Expr::InvokeMethodLazy { inferred_type, .. }
             | Expr::Length { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Propagate { inferred_type, .. }
+            | Expr::TryCatch { inferred_type, .. }
             | Expr::GenerateWorkerName { inferred_type, .. } => inferred_type.clone(),
         }
     }
@@ -1161,12 +1400,16 @@ impl Expr {
         type_spec: &Vec<GlobalVariableTypeSpec>,
     ) -> Result<(), RibTypeErrorInternal> {
         self.set_origin();
+        self.bind_variables_of_lambda();
+        self.bind_variables_of_try_catch();
         self.bind_global_variable_types(type_spec);
         self.bind_type_annotations();
         self.bind_variables_of_list_comprehension();
         self.bind_variables_of_list_reduce();
         self.bind_variables_of_pattern_match();
         self.bind_variables_of_let_assignment();
+        self.identify_higher_order_function_calls()?;
+        self.identify_built_in_function_calls()?;
         self.identify_instance_creation(component_dependency)?;
         self.ensure_stateful_instance();
         self.infer_variants(component_dependency);
@@ -1196,6 +1439,8 @@ impl Expr {
     // Not all phases of compilation will be part of this scan.
     // Example: function call argument inference based on the worker function hardly needs to be part of the scan.
     pub fn inference_scan(&mut self) -> Result<(), RibTypeErrorInternal> {
+        self.infer_built_in_function_calls()?;
+        self.infer_lambda_calls();
         self.infer_all_identifiers();
         self.push_types_down()?;
         self.infer_all_identifiers();
@@ -1222,6 +1467,17 @@ impl Expr {
         type_inference::bind_variables_of_let_assignment(self);
     }
 
+    // Parameters of lambdas are given variable-ids unique to each lambda, and this should
+    // happen before any other binding such that the parameters shadow the outer variables
+    pub fn bind_variables_of_lambda(&mut self) {
+        type_inference::bind_variables_of_lambda(self);
+    }
+
+    // The error variable of a catch block shadows the outer variables of the same name
+    pub fn bind_variables_of_try_catch(&mut self) {
+        type_inference::bind_variables_of_try_catch(self);
+    }
+
     pub fn bind_variables_of_list_comprehension(&mut self) {
         type_inference::bind_variables_of_list_comprehension(self);
     }
@@ -1230,6 +1486,25 @@ impl Expr {
         type_inference::bind_variables_of_list_reduce(self);
     }
 
+    pub fn identify_higher_order_function_calls(&mut self) -> Result<(), RibTypeErrorInternal> {
+        type_inference::identify_higher_order_function_calls(self)?;
+        Ok(())
+    }
+
+    pub fn infer_lambda_calls(&mut self) {
+        type_inference::infer_lambda_calls(self);
+    }
+
+    pub fn identify_built_in_function_calls(&mut self) -> Result<(), RibTypeErrorInternal> {
+        type_inference::identify_built_in_function_calls(self)?;
+        Ok(())
+    }
+
+    pub fn infer_built_in_function_calls(&mut self) -> Result<(), RibTypeErrorInternal> {
+        type_inference::infer_built_in_function_calls(self)?;
+        Ok(())
+    }
+
     pub fn identify_instance_creation(
         &mut self,
         component_dependency: &ComponentDependencies,
@@ -1295,6 +1570,7 @@ impl Expr {
             | Expr::SelectIndex { inferred_type, .. }
             | Expr::Sequence { inferred_type, .. }
             | Expr::Record { inferred_type, .. }
+            | Expr::Map { inferred_type, .. }
             | Expr::Tuple { inferred_type, .. }
             | Expr::Literal { inferred_type, .. }
             | Expr::Number { inferred_type, .. }
@@ -1307,10 +1583,17 @@ impl Expr {
             | Expr::GreaterThanOrEqualTo { inferred_type, .. }
             | Expr::LessThanOrEqualTo { inferred_type, .. }
             | Expr::EqualTo { inferred_type, .. }
+            | Expr::NotEqualTo { inferred_type, .. }
             | Expr::Plus { inferred_type, .. }
             | Expr::Minus { inferred_type, .. }
             | Expr::Divide { inferred_type, .. }
             | Expr::Multiply { inferred_type, .. }
+            | Expr::Modulo { inferred_type, .. }
+            | Expr::BitwiseAnd { inferred_type, .. }
+            | Expr::BitwiseOr { inferred_type, .. }
+            | Expr::BitwiseXor { inferred_type, .. }
+            | Expr::ShiftLeft { inferred_type, .. }
+            | Expr::ShiftRight { inferred_type, .. }
             | Expr::LessThan { inferred_type, .. }
             | Expr::Cond { inferred_type, .. }
             | Expr::PatternMatch { inferred_type, .. }
@@ -1326,6 +1609,9 @@ impl Expr {
             | Expr::InvokeMethodLazy { inferred_type, .. }
             | Expr::Range { inferred_type, .. }
             | Expr::Length { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Propagate { inferred_type, .. }
+            | Expr::TryCatch { inferred_type, .. }
             | Expr::GenerateWorkerName { inferred_type, .. }
             | Expr::Call { inferred_type, .. } => {
                 if !new_inferred_type.is_unknown() {
@@ -1347,6 +1633,7 @@ impl Expr {
             | Expr::SelectIndex { source_span, .. }
             | Expr::Sequence { source_span, .. }
             | Expr::Record { source_span, .. }
+            | Expr::Map { source_span, .. }
             | Expr::Tuple { source_span, .. }
             | Expr::Literal { source_span, .. }
             | Expr::Number { source_span, .. }
@@ -1359,11 +1646,18 @@ impl Expr {
             | Expr::GreaterThanOrEqualTo { source_span, .. }
             | Expr::LessThanOrEqualTo { source_span, .. }
             | Expr::EqualTo { source_span, .. }
+            | Expr::NotEqualTo { source_span, .. }
             | Expr::LessThan { source_span, .. }
             | Expr::Plus { source_span, .. }
             | Expr::Minus { source_span, .. }
             | Expr::Divide { source_span, .. }
             | Expr::Multiply { source_span, .. }
+            | Expr::Modulo { source_span, .. }
+            | Expr::BitwiseAnd { source_span, .. }
+            | Expr::BitwiseOr { source_span, .. }
+            | Expr::BitwiseXor { source_span, .. }
+            | Expr::ShiftLeft { source_span, .. }
+            | Expr::ShiftRight { source_span, .. }
             | Expr::Cond { source_span, .. }
             | Expr::PatternMatch { source_span, .. }
             | Expr::Option { source_span, .. }
@@ -1378,6 +1672,9 @@ impl Expr {
             | Expr::InvokeMethodLazy { source_span, .. }
             | Expr::Range { source_span, .. }
             | Expr::Length { source_span, .. }
+            | Expr::Lambda { source_span, .. }
+            | Expr::Propagate { source_span, .. }
+            | Expr::TryCatch { source_span, .. }
             | Expr::Call { source_span, .. }
             | Expr::GenerateWorkerName { source_span, .. } => source_span.clone(),
         }
@@ -1403,6 +1700,9 @@ impl Expr {
             | Expr::Record {
                 type_annotation, ..
             }
+            | Expr::Map {
+                type_annotation, ..
+            }
             | Expr::Tuple {
                 type_annotation, ..
             }
@@ -1439,6 +1739,9 @@ impl Expr {
             | Expr::EqualTo {
                 type_annotation, ..
             }
+            | Expr::NotEqualTo {
+                type_annotation, ..
+            }
             | Expr::LessThan {
                 type_annotation, ..
             }
@@ -1454,6 +1757,24 @@ impl Expr {
             | Expr::Multiply {
                 type_annotation, ..
             }
+            | Expr::Modulo {
+                type_annotation, ..
+            }
+            | Expr::BitwiseAnd {
+                type_annotation, ..
+            }
+            | Expr::BitwiseOr {
+                type_annotation, ..
+            }
+            | Expr::BitwiseXor {
+                type_annotation, ..
+            }
+            | Expr::ShiftLeft {
+                type_annotation, ..
+            }
+            | Expr::ShiftRight {
+                type_annotation, ..
+            }
             | Expr::Cond {
                 type_annotation, ..
             }
@@ -1496,6 +1817,15 @@ impl Expr {
             | Expr::Length {
                 type_annotation, ..
             }
+            | Expr::Lambda {
+                type_annotation, ..
+            }
+            | Expr::Propagate {
+                type_annotation, ..
+            }
+            | Expr::TryCatch {
+                type_annotation, ..
+            }
             | Expr::GenerateWorkerName {
                 type_annotation, ..
             }
@@ -1541,6 +1871,9 @@ impl Expr {
             | Expr::Record {
                 type_annotation, ..
             }
+            | Expr::Map {
+                type_annotation, ..
+            }
             | Expr::Tuple {
                 type_annotation, ..
             }
@@ -1577,6 +1910,9 @@ impl Expr {
             | Expr::EqualTo {
                 type_annotation, ..
             }
+            | Expr::NotEqualTo {
+                type_annotation, ..
+            }
             | Expr::LessThan {
                 type_annotation, ..
             }
@@ -1592,6 +1928,24 @@ impl Expr {
             | Expr::Multiply {
                 type_annotation, ..
             }
+            | Expr::Modulo {
+                type_annotation, ..
+            }
+            | Expr::BitwiseAnd {
+                type_annotation, ..
+            }
+            | Expr::BitwiseOr {
+                type_annotation, ..
+            }
+            | Expr::BitwiseXor {
+                type_annotation, ..
+            }
+            | Expr::ShiftLeft {
+                type_annotation, ..
+            }
+            | Expr::ShiftRight {
+                type_annotation, ..
+            }
             | Expr::Cond {
                 type_annotation, ..
             }
@@ -1634,6 +1988,15 @@ impl Expr {
             | Expr::Length {
                 type_annotation, ..
             }
+            | Expr::Lambda {
+                type_annotation, ..
+            }
+            | Expr::Propagate {
+                type_annotation, ..
+            }
+            | Expr::TryCatch {
+                type_annotation, ..
+            }
             | Expr::GenerateWorkerName {
                 type_annotation, ..
             }
@@ -1660,6 +2023,7 @@ impl Expr {
             | Expr::Sequence { source_span, .. }
             | Expr::Number { source_span, .. }
             | Expr::Record { source_span, .. }
+            | Expr::Map { source_span, .. }
             | Expr::Tuple { source_span, .. }
             | Expr::Literal { source_span, .. }
             | Expr::Flags { source_span, .. }
@@ -1671,11 +2035,18 @@ impl Expr {
             | Expr::GreaterThanOrEqualTo { source_span, .. }
             | Expr::LessThanOrEqualTo { source_span, .. }
             | Expr::EqualTo { source_span, .. }
+            | Expr::NotEqualTo { source_span, .. }
             | Expr::LessThan { source_span, .. }
             | Expr::Plus { source_span, .. }
             | Expr::Minus { source_span, .. }
             | Expr::Divide { source_span, .. }
             | Expr::Multiply { source_span, .. }
+            | Expr::Modulo { source_span, .. }
+            | Expr::BitwiseAnd { source_span, .. }
+            | Expr::BitwiseOr { source_span, .. }
+            | Expr::BitwiseXor { source_span, .. }
+            | Expr::ShiftLeft { source_span, .. }
+            | Expr::ShiftRight { source_span, .. }
             | Expr::Cond { source_span, .. }
             | Expr::PatternMatch { source_span, .. }
             | Expr::Option { source_span, .. }
@@ -1690,6 +2061,9 @@ impl Expr {
             | Expr::ListReduce { source_span, .. }
             | Expr::InvokeMethodLazy { source_span, .. }
             | Expr::Length { source_span, .. }
+            | Expr::Lambda { source_span, .. }
+            | Expr::Propagate { source_span, .. }
+            | Expr::TryCatch { source_span, .. }
             | Expr::GenerateWorkerName { source_span, .. }
             | Expr::Call { source_span, .. } => {
                 *source_span = new_source_span;
@@ -1713,6 +2087,7 @@ impl Expr {
             | Expr::SelectIndex { inferred_type, .. }
             | Expr::Sequence { inferred_type, .. }
             | Expr::Record { inferred_type, .. }
+            | Expr::Map { inferred_type, .. }
             | Expr::Tuple { inferred_type, .. }
             | Expr::Literal { inferred_type, .. }
             | Expr::Number { inferred_type, .. }
@@ -1725,11 +2100,18 @@ impl Expr {
             | Expr::GreaterThanOrEqualTo { inferred_type, .. }
             | Expr::LessThanOrEqualTo { inferred_type, .. }
             | Expr::EqualTo { inferred_type, .. }
+            | Expr::NotEqualTo { inferred_type, .. }
             | Expr::LessThan { inferred_type, .. }
             | Expr::Plus { inferred_type, .. }
             | Expr::Minus { inferred_type, .. }
             | Expr::Divide { inferred_type, .. }
             | Expr::Multiply { inferred_type, .. }
+            | Expr::Modulo { inferred_type, .. }
+            | Expr::BitwiseAnd { inferred_type, .. }
+            | Expr::BitwiseOr { inferred_type, .. }
+            | Expr::BitwiseXor { inferred_type, .. }
+            | Expr::ShiftLeft { inferred_type, .. }
+            | Expr::ShiftRight { inferred_type, .. }
             | Expr::Cond { inferred_type, .. }
             | Expr::PatternMatch { inferred_type, .. }
             | Expr::Option { inferred_type, .. }
@@ -1744,6 +2126,9 @@ impl Expr {
             | Expr::InvokeMethodLazy { inferred_type, .. }
             | Expr::Range { inferred_type, .. }
             | Expr::Length { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Propagate { inferred_type, .. }
+            | Expr::TryCatch { inferred_type, .. }
             | Expr::GenerateWorkerName { inferred_type, .. }
             | Expr::Call { inferred_type, .. } => {
                 *inferred_type = new_inferred_type;
@@ -1860,6 +2245,21 @@ impl Display for Number {
     }
 }
 
+#[derive(Debug, Hash, Clone, PartialEq, Eq, Ord, PartialOrd)]
+pub struct LambdaParameter {
+    pub variable_id: VariableId,
+    pub type_annotation: Option<TypeName>,
+}
+
+impl LambdaParameter {
+    pub fn new(name: impl AsRef<str>, type_annotation: Option<TypeName>) -> LambdaParameter {
+        LambdaParameter {
+            variable_id: VariableId::global(name.as_ref().to_string()),
+            type_annotation,
+        }
+    }
+}
+
 #[derive(Debug, Hash, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub struct MatchArm {
     pub arm_pattern: ArmPattern,
@@ -2138,6 +2538,12 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                 Expr::equal_to((*left).try_into()?, (*right).try_into()?)
             }
 
+            golem_api_grpc::proto::golem::rib::expr::Expr::NotEqualTo(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::not_equal_to((*left).try_into()?, (*right).try_into()?)
+            }
+
             golem_api_grpc::proto::golem::rib::expr::Expr::Add(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let right = expr.right.ok_or("Missing right expr")?;
@@ -2147,19 +2553,55 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
             golem_api_grpc::proto::golem::rib::expr::Expr::Subtract(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let right = expr.right.ok_or("Missing right expr")?;
-                Expr::plus((*left).try_into()?, (*right).try_into()?)
+                Expr::minus((*left).try_into()?, (*right).try_into()?)
             }
 
             golem_api_grpc::proto::golem::rib::expr::Expr::Divide(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let right = expr.right.ok_or("Missing right expr")?;
-                Expr::plus((*left).try_into()?, (*right).try_into()?)
+                Expr::divide((*left).try_into()?, (*right).try_into()?)
             }
 
             golem_api_grpc::proto::golem::rib::expr::Expr::Multiply(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let right = expr.right.ok_or("Missing right expr")?;
-                Expr::plus((*left).try_into()?, (*right).try_into()?)
+                Expr::multiply((*left).try_into()?, (*right).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::Modulo(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::modulo((*left).try_into()?, (*right).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::BitwiseAnd(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::bitwise_and((*left).try_into()?, (*right).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::BitwiseOr(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::bitwise_or((*left).try_into()?, (*right).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::BitwiseXor(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::bitwise_xor((*left).try_into()?, (*right).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::ShiftLeft(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::shift_left((*left).try_into()?, (*right).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::ShiftRight(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::shift_right((*left).try_into()?, (*right).try_into()?)
             }
 
             golem_api_grpc::proto::golem::rib::expr::Expr::Cond(expr) => {
@@ -2227,6 +2669,19 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                 Expr::record(values)
             }
 
+            golem_api_grpc::proto::golem::rib::expr::Expr::Map(
+                golem_api_grpc::proto::golem::rib::MapExpr { entries, type_name },
+            ) => {
+                let type_annotation = type_name.map(TypeName::try_from).transpose()?;
+                let mut values: Vec<(Expr, Expr)> = vec![];
+                for entry in entries.into_iter() {
+                    let key = entry.key.ok_or("Missing key expr")?;
+                    let value = entry.value.ok_or("Missing value expr")?;
+                    values.push((key.try_into()?, value.try_into()?));
+                }
+                Expr::map(values, type_annotation)
+            }
+
             golem_api_grpc::proto::golem::rib::expr::Expr::Flags(
                 golem_api_grpc::proto::golem::rib::FlagsExpr { values },
             ) => Expr::flags(values),
@@ -2357,6 +2812,33 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                     VariableId::list_comprehension_identifier(list_comprehension.iterated_variable);
                 Expr::list_comprehension(variable_id, iterable_expr, yield_expr)
             }
+            golem_api_grpc::proto::golem::rib::expr::Expr::Lambda(lambda) => {
+                let parameters = lambda
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| {
+                        let type_annotation =
+                            parameter.type_name.map(TypeName::try_from).transpose()?;
+                        Ok(LambdaParameter::new(parameter.name, type_annotation))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                let return_type = lambda.return_type.map(TypeName::try_from).transpose()?;
+                let body = lambda.body.ok_or("Missing lambda body")?;
+                Expr::lambda(parameters, return_type, (*body).try_into()?)
+            }
+            golem_api_grpc::proto::golem::rib::expr::Expr::Propagate(expr) => {
+                let expr = expr.expr.ok_or("Missing expr")?;
+                Expr::propagate((*expr).try_into()?)
+            }
+            golem_api_grpc::proto::golem::rib::expr::Expr::TryCatch(try_catch) => {
+                let try_expr = try_catch.try_expr.ok_or("Missing try expr")?;
+                let catch_expr = try_catch.catch_expr.ok_or("Missing catch expr")?;
+                Expr::try_catch(
+                    (*try_expr).try_into()?,
+                    try_catch.error_variable,
+                    (*catch_expr).try_into()?,
+                )
+            }
             golem_api_grpc::proto::golem::rib::expr::Expr::ListReduce(list_reduce) => {
                 let init_value_expr = list_reduce.init_value_expr.ok_or("Missing initial expr")?;
                 let init_value_expr = (*init_value_expr).try_into()?;
@@ -2431,6 +2913,11 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                                     type_annotation: None, // TODO
                                 }
                             }
+                            golem_api_grpc::proto::golem::rib::call_type::Name::BuiltIn(name) => {
+                                let function = crate::BuiltInFunction::from_qualified_name(&name)
+                                    .ok_or(format!("Unknown built-in function {name}"))?;
+                                Expr::call(CallType::BuiltIn(function), generic_type_parameter, params)
+                            }
                         }
                     }
                     (_, _) => Err("Missing both call type (and legacy invocation type)")?,
@@ -2648,6 +3135,24 @@ mod protobuf {
                         },
                     ))
                 }
+                Expr::Map {
+                    entries,
+                    type_annotation,
+                    ..
+                } => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Map(
+                    golem_api_grpc::proto::golem::rib::MapExpr {
+                        entries: entries
+                            .into_iter()
+                            .map(
+                                |(key, value)| golem_api_grpc::proto::golem::rib::MapEntryExpr {
+                                    key: Some(key.into()),
+                                    value: Some(value.into()),
+                                },
+                            )
+                            .collect(),
+                        type_name: type_annotation.map(|t| t.into()),
+                    },
+                )),
                 Expr::Tuple {
                     exprs: expressions, ..
                 } => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Tuple(
@@ -2774,6 +3279,54 @@ mod protobuf {
                         }),
                     ))
                 }
+                Expr::Modulo { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::Modulo(
+                        Box::new(golem_api_grpc::proto::golem::rib::ModuloExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
+                Expr::BitwiseAnd { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::BitwiseAnd(
+                        Box::new(golem_api_grpc::proto::golem::rib::BitwiseAndExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
+                Expr::BitwiseOr { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::BitwiseOr(
+                        Box::new(golem_api_grpc::proto::golem::rib::BitwiseOrExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
+                Expr::BitwiseXor { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::BitwiseXor(
+                        Box::new(golem_api_grpc::proto::golem::rib::BitwiseXorExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
+                Expr::ShiftLeft { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::ShiftLeft(
+                        Box::new(golem_api_grpc::proto::golem::rib::ShiftLeftExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
+                Expr::ShiftRight { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::ShiftRight(
+                        Box::new(golem_api_grpc::proto::golem::rib::ShiftRightExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
                 Expr::LessThanOrEqualTo { lhs, rhs, .. } => Some(
                     golem_api_grpc::proto::golem::rib::expr::Expr::LessThanOrEqual(Box::new(
                         golem_api_grpc::proto::golem::rib::LessThanOrEqualToExpr {
@@ -2790,6 +3343,14 @@ mod protobuf {
                         }),
                     ))
                 }
+                Expr::NotEqualTo { lhs, rhs, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::NotEqualTo(
+                        Box::new(golem_api_grpc::proto::golem::rib::NotEqualToExpr {
+                            left: Some(Box::new((*lhs).into())),
+                            right: Some(Box::new((*rhs).into())),
+                        }),
+                    ))
+                }
                 // Note: We were storing and retrieving (proto) condition expressions such that
                 // `cond` was written `lhs` and vice versa.
                 // This is probably difficult to fix to keep backward compatibility
@@ -2934,6 +3495,45 @@ mod protobuf {
                         yield_expr: Some(Box::new((*yield_expr).into())),
                     }),
                 )),
+                Expr::Lambda {
+                    parameters,
+                    return_type,
+                    body,
+                    ..
+                } => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Lambda(
+                    Box::new(golem_api_grpc::proto::golem::rib::LambdaExpr {
+                        parameters: parameters
+                            .into_iter()
+                            .map(
+                                |parameter| golem_api_grpc::proto::golem::rib::LambdaParameter {
+                                    name: parameter.variable_id.name(),
+                                    type_name: parameter.type_annotation.map(|t| t.into()),
+                                },
+                            )
+                            .collect(),
+                        body: Some(Box::new((*body).into())),
+                        return_type: return_type.map(|t| t.into()),
+                    }),
+                )),
+                Expr::Propagate { expr, .. } => {
+                    Some(golem_api_grpc::proto::golem::rib::expr::Expr::Propagate(
+                        Box::new(golem_api_grpc::proto::golem::rib::PropagateExpr {
+                            expr: Some(Box::new((*expr).into())),
+                        }),
+                    ))
+                }
+                Expr::TryCatch {
+                    try_expr,
+                    error_variable,
+                    catch_expr,
+                    ..
+                } => Some(golem_api_grpc::proto::golem::rib::expr::Expr::TryCatch(
+                    Box::new(golem_api_grpc::proto::golem::rib::TryCatchExpr {
+                        try_expr: Some(Box::new((*try_expr).into())),
+                        error_variable: error_variable.name(),
+                        catch_expr: Some(Box::new((*catch_expr).into())),
+                    }),
+                )),
                 Expr::InvokeMethodLazy {
                     lhs,
                     method,
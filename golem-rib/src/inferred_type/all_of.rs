@@ -1096,6 +1096,7 @@ pub fn get_merge_task<'a>(inferred_types: &'a [InferredType]) -> MergeTaskStack<
                     | TypeInternal::Instance { .. }
                     | TypeInternal::Unknown
                     | TypeInternal::Range { .. }
+                    | TypeInternal::Map { .. }
                     | TypeInternal::Str => {
                         if !init {
                             final_task_stack
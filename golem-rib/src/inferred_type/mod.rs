@@ -150,6 +150,7 @@ impl InferredType {
                 TypeInternal::Flags(_) => Err(format!("used as {}", "flags")),
                 TypeInternal::Enum(_) => Err(format!("used as {}", "enum")),
                 TypeInternal::Option(_) => Err(format!("used as {}", "option")),
+                TypeInternal::Map { .. } => Err(format!("used as {}", "map")),
                 TypeInternal::Result { .. } => Err(format!("used as {}", "result")),
                 TypeInternal::Variant(_) => Err(format!("used as {}", "variant")),
                 TypeInternal::Unknown => Err("found unknown".to_string()),
@@ -201,6 +202,7 @@ impl InferredType {
             TypeInternal::Flags(_) => false,
             TypeInternal::Enum(_) => false,
             TypeInternal::Option(_) => false,
+            TypeInternal::Map { .. } => false,
             TypeInternal::Result { .. } => false,
             TypeInternal::Variant(_) => false,
             TypeInternal::Resource { .. } => false,
@@ -277,6 +279,13 @@ impl InferredType {
         }
     }
 
+    pub fn map(key: InferredType, value: InferredType) -> InferredType {
+        InferredType {
+            inner: Box::new(TypeInternal::Map { key, value }),
+            origin: TypeOrigin::NoOrigin,
+        }
+    }
+
     pub fn new(inferred_type: TypeInternal, origin: TypeOrigin) -> InferredType {
         InferredType {
             inner: Box::new(inferred_type),
@@ -485,6 +494,30 @@ impl InferredType {
         matches!(self.inner.deref(), TypeInternal::AllOf(_))
     }
 
+    // The type if it is already resolved, leaving any conflicts to the type unification phase.
+    // A type merged from a default type (such as `s32` of a number literal) and a single other type
+    // is known to be the latter, as this is how the types are eventually unified
+    pub fn known_type(&self) -> Option<InferredType> {
+        match self.inner.deref() {
+            TypeInternal::Unknown => None,
+            TypeInternal::AllOf(types) => {
+                let non_default_types = InferredType::eliminate_default(types.iter().collect());
+
+                match non_default_types.first() {
+                    Some(first)
+                        if !first.is_unknown()
+                            && !first.is_all_of()
+                            && non_default_types.iter().all(|t| t == first) =>
+                    {
+                        Some((*first).clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => Some(self.clone()),
+        }
+    }
+
     pub fn is_number(&self) -> bool {
         matches!(
             self.inner.deref(),
@@ -505,6 +538,10 @@ impl InferredType {
         matches!(self.inner.deref(), TypeInternal::Str)
     }
 
+    pub fn is_map(&self) -> bool {
+        matches!(self.inner.deref(), TypeInternal::Map { .. })
+    }
+
     pub fn flatten_all_of_inferred_types(types: &Vec<InferredType>) -> Vec<InferredType> {
         flatten_all_of_list(types)
     }
@@ -37,6 +37,10 @@ pub enum TypeInternal {
     Flags(Vec<String>),
     Enum(Vec<String>),
     Option(InferredType),
+    Map {
+        key: InferredType,
+        value: InferredType,
+    },
     Result {
         ok: Option<InferredType>,
         error: Option<InferredType>,
@@ -157,6 +161,11 @@ impl Hash for TypeInternal {
                 sorted_types.hash(state);
             }
             TypeInternal::Unknown => 25.hash(state),
+            TypeInternal::Map { key, value } => {
+                26.hash(state);
+                key.hash(state);
+                value.hash(state);
+            }
         }
     }
 }
@@ -183,6 +192,16 @@ impl PartialEq for TypeInternal {
             (TypeInternal::Flags(vs1), TypeInternal::Flags(vs2)) => vs1 == vs2,
             (TypeInternal::Enum(vs1), TypeInternal::Enum(vs2)) => vs1 == vs2,
             (TypeInternal::Option(t1), TypeInternal::Option(t2)) => t1 == t2,
+            (
+                TypeInternal::Map {
+                    key: key1,
+                    value: value1,
+                },
+                TypeInternal::Map {
+                    key: key2,
+                    value: value2,
+                },
+            ) => key1 == key2 && value1 == value2,
             (
                 TypeInternal::Result {
                     ok: ok1,
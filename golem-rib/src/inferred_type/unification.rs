@@ -46,6 +46,13 @@ pub fn try_unify_type(
             Ok(InferredType::option(unified_inner_type).override_origin(inferred_type.origin()))
         }
 
+        TypeInternal::Map { key, value } => {
+            let unified_key = try_unify_type(key)?;
+            let unified_value = try_unify_type(value)?;
+            Ok(InferredType::map(unified_key, unified_value)
+                .override_origin(inferred_type.origin()))
+        }
+
         TypeInternal::Result { ok, error } => {
             let unified_ok = ok.as_ref().map(try_unify_type);
 
@@ -361,6 +368,35 @@ pub fn unify_both_inferred_types(
             TypeInternal::Option(unify_both_inferred_types(a_type, b_type)?),
         )),
 
+        (
+            TypeInternal::Map {
+                key: a_key,
+                value: a_value,
+            },
+            TypeInternal::Map {
+                key: b_key,
+                value: b_value,
+            },
+        ) => Ok(InferredType::map(
+            unify_both_inferred_types(a_key, b_key)?,
+            unify_both_inferred_types(a_value, b_value)?,
+        )),
+
+        // A map is represented as a list of key-value tuples in WIT,
+        // and therefore a list of tuples can be used wherever a map is expected and vice versa.
+        (TypeInternal::Map { key, value }, TypeInternal::List(entry))
+        | (TypeInternal::List(entry), TypeInternal::Map { key, value }) => {
+            unify_map_with_entries(key, value, entry).map_err(|_| {
+                UnificationFailureInternal::conflicting_types(
+                    vec![left_inferred_type.clone(), right_inferred_type.clone()],
+                    vec![format!(
+                    "a list can be used as a map only if its elements are key-value tuples. {}, {}",
+                    inferred_type_printable, other_printable
+                )],
+                )
+            })
+        }
+
         (TypeInternal::Option(a_type), _) => {
             let unified_left = a_type.unify()?;
             let unified_right = right_inferred_type.unify()?;
@@ -496,6 +532,25 @@ pub fn unify_both_inferred_types(
     }
 }
 
+fn unify_map_with_entries(
+    key: &InferredType,
+    value: &InferredType,
+    entry: &InferredType,
+) -> Result<InferredType, UnificationFailureInternal> {
+    let expected_entry = InferredType::tuple(vec![key.clone(), value.clone()]);
+    let unified_entry = unify_both_inferred_types(&expected_entry, entry)?;
+
+    match unified_entry.internal_type() {
+        TypeInternal::Tuple(types) if types.len() == 2 => {
+            Ok(InferredType::map(types[0].clone(), types[1].clone()))
+        }
+        _ => Err(UnificationFailureInternal::type_mismatch(
+            expected_entry,
+            entry.clone(),
+        )),
+    }
+}
+
 pub(crate) fn validate_unified_type(
     inferred_type: &InferredType,
 ) -> Result<Unified, UnificationFailureInternal> {
@@ -548,6 +603,14 @@ pub(crate) fn validate_unified_type(
             let result = validate_unified_type(inferred_type)?;
             Ok(Unified(InferredType::option(result.inferred_type())))
         }
+        TypeInternal::Map { key, value } => {
+            let key = validate_unified_type(key)?;
+            let value = validate_unified_type(value)?;
+            Ok(Unified(InferredType::map(
+                key.inferred_type(),
+                value.inferred_type(),
+            )))
+        }
         TypeInternal::Result { ok, error } => {
             // For Result, we try to be flexible with types
             // Example: Allow Rib script to simply return ok(x) as the final output, even if it doesn't know anything about error
@@ -0,0 +1,414 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interpreter::rib_runtime_error::{
+    arithmetic_error, built_in_function_error, cast_error_custom, type_mismatch_with_value,
+};
+use crate::{
+    internal_corrupted_state, BuiltInFunction, CoercedNumericValue, GetLiteralValue, GetTypeHint,
+    RibInterpreterResult, RibRuntimeError, TypeHint,
+};
+use base64::Engine;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, SecondsFormat, Utc};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{Value, ValueAndType};
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+// An f64 has no significant digits beyond this many decimals
+const MAX_FORMATTED_DECIMALS: u64 = 17;
+
+// Evaluates a built-in function given the arguments in the order they are declared.
+// The type inference phase guarantees the arguments conform to the signature of the function,
+// and the `return_type` is the fully resolved type of the result.
+pub(crate) fn evaluate_built_in_function(
+    function: &BuiltInFunction,
+    args: Vec<ValueAndType>,
+    return_type: &AnalysedType,
+) -> RibInterpreterResult<ValueAndType> {
+    let args = Args { function, args };
+
+    let value = match function {
+        BuiltInFunction::StringLowercase => Value::String(args.str(0)?.to_lowercase()),
+        BuiltInFunction::StringUppercase => Value::String(args.str(0)?.to_uppercase()),
+        BuiltInFunction::StringTrim => Value::String(args.str(0)?.trim().to_string()),
+        BuiltInFunction::StringLength => Value::U64(args.str(0)?.chars().count() as u64),
+        BuiltInFunction::StringIsEmpty => Value::Bool(args.str(0)?.is_empty()),
+        BuiltInFunction::StringContains => Value::Bool(args.str(0)?.contains(args.str(1)?)),
+        BuiltInFunction::StringStartsWith => Value::Bool(args.str(0)?.starts_with(args.str(1)?)),
+        BuiltInFunction::StringEndsWith => Value::Bool(args.str(0)?.ends_with(args.str(1)?)),
+        BuiltInFunction::StringReplace => {
+            Value::String(args.str(0)?.replace(args.str(1)?, args.str(2)?))
+        }
+        BuiltInFunction::StringSplit => {
+            let input = args.str(0)?;
+            let separator = args.str(1)?;
+
+            let parts = if separator.is_empty() {
+                input
+                    .chars()
+                    .map(|c| Value::String(c.to_string()))
+                    .collect()
+            } else {
+                input
+                    .split(separator)
+                    .map(|part| Value::String(part.to_string()))
+                    .collect()
+            };
+
+            Value::List(parts)
+        }
+        BuiltInFunction::StringJoin => {
+            let parts = args
+                .list(0)?
+                .iter()
+                .map(|value| match value {
+                    Value::String(part) => Ok(part.as_str()),
+                    other => Err(type_mismatch_with_value(vec![TypeHint::Str], other.clone())),
+                })
+                .collect::<RibInterpreterResult<Vec<_>>>()?;
+
+            Value::String(parts.join(args.str(1)?))
+        }
+        BuiltInFunction::StringSubstring => {
+            let chars = args.str(0)?.chars().collect::<Vec<_>>();
+            let start = (args.u64(1)? as usize).min(chars.len());
+            let end = (args.u64(2)? as usize).clamp(start, chars.len());
+
+            Value::String(chars[start..end].iter().collect())
+        }
+        BuiltInFunction::StringToInt => Value::Option(
+            args.str(0)?
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .map(|number| Box::new(Value::S64(number))),
+        ),
+        BuiltInFunction::StringToFloat => Value::Option(
+            args.str(0)?
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .map(|number| Box::new(Value::F64(number))),
+        ),
+        BuiltInFunction::StringFromNumber => Value::String(args.number(0)?.to_string()),
+        BuiltInFunction::StringFormatNumber => {
+            let number = to_f64(&args.number(0)?);
+            let decimals = args.u64(1)?;
+
+            if decimals > MAX_FORMATTED_DECIMALS {
+                return Err(args.error(&format!(
+                    "the number of decimals {decimals} is above the maximum of {MAX_FORMATTED_DECIMALS}"
+                )));
+            }
+
+            let decimals = decimals as usize;
+            Value::String(format!("{number:.decimals$}"))
+        }
+        BuiltInFunction::MathAbs => {
+            let result = match args.number(0)? {
+                CoercedNumericValue::PosInt(number) => CoercedNumericValue::PosInt(number),
+                CoercedNumericValue::NegInt(number) => number
+                    .checked_abs()
+                    .map(CoercedNumericValue::NegInt)
+                    .ok_or_else(|| arithmetic_error(&format!("overflow in abs of {number}")))?,
+                CoercedNumericValue::Float(number) => CoercedNumericValue::Float(number.abs()),
+            };
+
+            return cast_number(result, return_type);
+        }
+        BuiltInFunction::MathMin | BuiltInFunction::MathMax => {
+            let left = args.number(0)?;
+            let right = args.number(1)?;
+
+            let ordering = left
+                .partial_cmp(&right)
+                .ok_or_else(|| arithmetic_error(&format!("cannot compare {left} and {right}")))?;
+
+            let result = match (function, ordering) {
+                (BuiltInFunction::MathMin, Ordering::Greater) => right,
+                (BuiltInFunction::MathMax, Ordering::Less) => right,
+                _ => left,
+            };
+
+            return cast_number(result, return_type);
+        }
+        BuiltInFunction::MathFloor => Value::F64(to_f64(&args.number(0)?).floor()),
+        BuiltInFunction::MathCeil => Value::F64(to_f64(&args.number(0)?).ceil()),
+        BuiltInFunction::MathRound => Value::F64(to_f64(&args.number(0)?).round()),
+        BuiltInFunction::MathSqrt => Value::F64(to_f64(&args.number(0)?).sqrt()),
+        BuiltInFunction::MathPow => {
+            Value::F64(to_f64(&args.number(0)?).powf(to_f64(&args.number(1)?)))
+        }
+        BuiltInFunction::ListLength => Value::U64(args.list(0)?.len() as u64),
+        BuiltInFunction::ListIsEmpty => Value::Bool(args.list(0)?.is_empty()),
+        BuiltInFunction::ListContains => {
+            let element = &args.get(1)?.value;
+            Value::Bool(args.list(0)?.contains(element))
+        }
+        BuiltInFunction::ListReverse => Value::List(args.list(0)?.iter().rev().cloned().collect()),
+        BuiltInFunction::ListHead => Value::Option(args.list(0)?.first().cloned().map(Box::new)),
+        BuiltInFunction::ListLast => Value::Option(args.list(0)?.last().cloned().map(Box::new)),
+        BuiltInFunction::ListConcat => {
+            let mut items = args.list(0)?.clone();
+            items.extend(args.list(1)?.iter().cloned());
+            Value::List(items)
+        }
+        BuiltInFunction::ListFlatten => {
+            let mut items = vec![];
+            for list in args.list(0)? {
+                match list {
+                    Value::List(list) => items.extend(list.iter().cloned()),
+                    other => {
+                        return Err(type_mismatch_with_value(
+                            vec![TypeHint::List(None)],
+                            other.clone(),
+                        ))
+                    }
+                }
+            }
+            Value::List(items)
+        }
+        BuiltInFunction::OptionIsSome => Value::Bool(args.option(0)?.is_some()),
+        BuiltInFunction::OptionIsNone => Value::Bool(args.option(0)?.is_none()),
+        BuiltInFunction::OptionUnwrapOr => match args.option(0)? {
+            Some(value) => value.as_ref().clone(),
+            None => args.get(1)?.value.clone(),
+        },
+        BuiltInFunction::MapSize => Value::U64(args.map(0)?.len() as u64),
+        BuiltInFunction::MapContainsKey => {
+            let key = &args.get(1)?.value;
+            Value::Bool(args.map(0)?.iter().any(|(k, _)| *k == key))
+        }
+        BuiltInFunction::MapGet => {
+            let key = &args.get(1)?.value;
+            let value = args.map(0)?.into_iter().find(|(k, _)| *k == key);
+            Value::Option(value.map(|(_, v)| Box::new(v.clone())))
+        }
+        BuiltInFunction::MapKeys => {
+            Value::List(args.map(0)?.into_iter().map(|(k, _)| k.clone()).collect())
+        }
+        BuiltInFunction::MapValues => {
+            Value::List(args.map(0)?.into_iter().map(|(_, v)| v.clone()).collect())
+        }
+        BuiltInFunction::ResultIsOk => Value::Bool(args.result(0)?.is_ok()),
+        BuiltInFunction::ResultIsErr => Value::Bool(args.result(0)?.is_err()),
+        BuiltInFunction::ResultUnwrapOr => match args.result(0)? {
+            Ok(Some(value)) => value.as_ref().clone(),
+            _ => args.get(1)?.value.clone(),
+        },
+        BuiltInFunction::EncodingBase64Encode => {
+            Value::String(base64::engine::general_purpose::STANDARD.encode(args.str(0)?.as_bytes()))
+        }
+        BuiltInFunction::EncodingBase64Decode => {
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(args.str(0)?)
+                .map_err(|err| err.to_string())
+                .and_then(|bytes| String::from_utf8(bytes).map_err(|err| err.to_string()));
+
+            string_result(decoded)
+        }
+        BuiltInFunction::EncodingUrlEncode => {
+            Value::String(urlencoding::encode(args.str(0)?).into_owned())
+        }
+        BuiltInFunction::EncodingUrlDecode => {
+            let decoded = urlencoding::decode(args.str(0)?)
+                .map(|decoded| decoded.into_owned())
+                .map_err(|err| err.to_string());
+
+            string_result(decoded)
+        }
+        BuiltInFunction::EncodingHexEncode => Value::String(hex::encode(args.str(0)?.as_bytes())),
+        BuiltInFunction::EncodingSha256 => {
+            Value::String(hex::encode(Sha256::digest(args.str(0)?.as_bytes())))
+        }
+        BuiltInFunction::DateTimeNow => Value::U64(Utc::now().timestamp_millis().max(0) as u64),
+        BuiltInFunction::DateTimeFormat => {
+            let date_time = args.timestamp(0)?;
+            let format = args.str(1)?;
+
+            let items = StrftimeItems::new(format).collect::<Vec<_>>();
+
+            if items.iter().any(|item| matches!(item, Item::Error)) {
+                return Err(args.error(&format!("invalid format string `{format}`")));
+            }
+
+            let mut formatted = String::new();
+
+            write!(formatted, "{}", date_time.format_with_items(items.iter()))
+                .map_err(|_| args.error(&format!("cannot format timestamp with `{format}`")))?;
+
+            Value::String(formatted)
+        }
+        BuiltInFunction::DateTimeToIso8601 => Value::String(
+            args.timestamp(0)?
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+        ),
+        BuiltInFunction::DateTimeFromIso8601 => Value::Option(
+            DateTime::parse_from_rfc3339(args.str(0)?)
+                .ok()
+                .and_then(|date_time| u64::try_from(date_time.timestamp_millis()).ok())
+                .map(|millis| Box::new(Value::U64(millis))),
+        ),
+    };
+
+    Ok(ValueAndType::new(value, return_type.clone()))
+}
+
+struct Args<'a> {
+    function: &'a BuiltInFunction,
+    args: Vec<ValueAndType>,
+}
+
+impl Args<'_> {
+    fn get(&self, index: usize) -> RibInterpreterResult<&ValueAndType> {
+        self.args.get(index).ok_or_else(|| {
+            internal_corrupted_state!(
+                "missing argument {} to the built-in function {}",
+                index,
+                self.function
+            )
+        })
+    }
+
+    fn str(&self, index: usize) -> RibInterpreterResult<&str> {
+        match &self.get(index)?.value {
+            Value::String(value) => Ok(value.as_str()),
+            other => Err(type_mismatch_with_value(vec![TypeHint::Str], other.clone())),
+        }
+    }
+
+    fn u64(&self, index: usize) -> RibInterpreterResult<u64> {
+        match &self.get(index)?.value {
+            Value::U64(value) => Ok(*value),
+            Value::U32(value) => Ok(*value as u64),
+            other => Err(type_mismatch_with_value(
+                vec![TypeHint::Number],
+                other.clone(),
+            )),
+        }
+    }
+
+    fn number(&self, index: usize) -> RibInterpreterResult<CoercedNumericValue> {
+        let value = self.get(index)?;
+
+        value
+            .get_literal()
+            .and_then(|literal| literal.get_number())
+            .ok_or_else(|| type_mismatch_with_value(vec![TypeHint::Number], value.value.clone()))
+    }
+
+    fn list(&self, index: usize) -> RibInterpreterResult<&Vec<Value>> {
+        match &self.get(index)?.value {
+            Value::List(items) => Ok(items),
+            other => Err(type_mismatch_with_value(
+                vec![TypeHint::List(None)],
+                other.clone(),
+            )),
+        }
+    }
+
+    // A map is a list of key-value tuples at runtime
+    fn map(&self, index: usize) -> RibInterpreterResult<Vec<(&Value, &Value)>> {
+        let map_type_hint = || TypeHint::Map {
+            key: None,
+            value: None,
+        };
+
+        match &self.get(index)?.value {
+            Value::List(entries) => entries
+                .iter()
+                .map(|entry| match entry {
+                    Value::Tuple(items) if items.len() == 2 => Ok((&items[0], &items[1])),
+                    other => Err(type_mismatch_with_value(
+                        vec![map_type_hint()],
+                        other.clone(),
+                    )),
+                })
+                .collect(),
+            other => Err(type_mismatch_with_value(
+                vec![map_type_hint()],
+                other.clone(),
+            )),
+        }
+    }
+
+    fn option(&self, index: usize) -> RibInterpreterResult<&Option<Box<Value>>> {
+        match &self.get(index)?.value {
+            Value::Option(value) => Ok(value),
+            other => Err(type_mismatch_with_value(
+                vec![TypeHint::Option(None)],
+                other.clone(),
+            )),
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn result(
+        &self,
+        index: usize,
+    ) -> RibInterpreterResult<&Result<Option<Box<Value>>, Option<Box<Value>>>> {
+        match &self.get(index)?.value {
+            Value::Result(value) => Ok(value),
+            other => Err(type_mismatch_with_value(
+                vec![TypeHint::Result {
+                    ok: None,
+                    err: None,
+                }],
+                other.clone(),
+            )),
+        }
+    }
+
+    // Timestamps are represented as milliseconds since the unix epoch
+    fn timestamp(&self, index: usize) -> RibInterpreterResult<DateTime<Utc>> {
+        let millis = self.u64(index)?;
+
+        i64::try_from(millis)
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or_else(|| self.error(&format!("timestamp {millis} is out of range")))
+    }
+
+    fn error(&self, message: &str) -> RibRuntimeError {
+        built_in_function_error(&self.function.to_string(), message)
+    }
+}
+
+fn to_f64(number: &CoercedNumericValue) -> f64 {
+    match number {
+        CoercedNumericValue::PosInt(number) => *number as f64,
+        CoercedNumericValue::NegInt(number) => *number as f64,
+        CoercedNumericValue::Float(number) => *number,
+    }
+}
+
+fn cast_number(
+    number: CoercedNumericValue,
+    return_type: &AnalysedType,
+) -> RibInterpreterResult<ValueAndType> {
+    number
+        .cast_to(return_type)
+        .ok_or_else(|| cast_error_custom(number, return_type.get_type_hint()))
+}
+
+fn string_result(result: Result<String, String>) -> Value {
+    Value::Result(
+        result
+            .map(|value| Some(Box::new(Value::String(value))))
+            .map_err(|err| Some(Box::new(Value::String(err)))),
+    )
+}
@@ -26,6 +26,12 @@ impl RibByteCodeCursor {
         }
     }
 
+    // Unlike `move_to`, this moves to the exact position of an instruction
+    // and not to a label, and is used to resume from a call frame
+    pub fn move_to_position(&mut self, position: &InstructionId) {
+        self.position = position.index;
+    }
+
     pub fn last(&self) -> Option<&RibIR> {
         self.byte_code.instructions.last()
     }
@@ -0,0 +1,191 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interpreter::interpreter_stack_value::RibInterpreterStackValue;
+use crate::interpreter::rib_runtime_error::RibRuntimeError;
+use crate::interpreter::stack::InterpreterStack;
+use golem_wasm_rpc::Value;
+use std::time::{Duration, Instant};
+
+/// Limits on the resources a single evaluation of a Rib script can use.
+/// A limit that is not set is not enforced, and by default none of them are set.
+///
+/// The wall time is checked in between instructions, and hence a single call to a worker
+/// function that takes longer than the limit is not interrupted, but fails the script once it returns.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RibInterpreterLimits {
+    pub max_instructions: Option<u64>,
+    pub max_stack_size: Option<usize>,
+    // Approximate size in bytes of any single value the script creates
+    pub max_value_size: Option<usize>,
+    pub max_function_invocations: Option<u64>,
+    pub max_wall_time: Option<Duration>,
+}
+
+impl RibInterpreterLimits {
+    pub fn unlimited() -> Self {
+        RibInterpreterLimits::default()
+    }
+
+    pub fn with_max_instructions(mut self, max_instructions: u64) -> Self {
+        self.max_instructions = Some(max_instructions);
+        self
+    }
+
+    pub fn with_max_stack_size(mut self, max_stack_size: usize) -> Self {
+        self.max_stack_size = Some(max_stack_size);
+        self
+    }
+
+    pub fn with_max_value_size(mut self, max_value_size: usize) -> Self {
+        self.max_value_size = Some(max_value_size);
+        self
+    }
+
+    pub fn with_max_function_invocations(mut self, max_function_invocations: u64) -> Self {
+        self.max_function_invocations = Some(max_function_invocations);
+        self
+    }
+
+    pub fn with_max_wall_time(mut self, max_wall_time: Duration) -> Self {
+        self.max_wall_time = Some(max_wall_time);
+        self
+    }
+}
+
+/// The resources used by the last evaluation of a Rib script, regardless of whether it succeeded
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RibInterpreterUsage {
+    pub instructions: u64,
+    pub function_invocations: u64,
+    pub max_stack_size: usize,
+    pub wall_time: Duration,
+}
+
+// Keeps track of the usage during a single run of the interpreter, failing as soon as a limit is exceeded
+pub(crate) struct ResourceMeter {
+    limits: RibInterpreterLimits,
+    usage: RibInterpreterUsage,
+    started_at: Instant,
+}
+
+impl ResourceMeter {
+    pub(crate) fn new(limits: RibInterpreterLimits) -> Self {
+        ResourceMeter {
+            limits,
+            usage: RibInterpreterUsage::default(),
+            started_at: Instant::now(),
+        }
+    }
+
+    pub(crate) fn usage(&self) -> RibInterpreterUsage {
+        RibInterpreterUsage {
+            wall_time: self.started_at.elapsed(),
+            ..self.usage.clone()
+        }
+    }
+
+    pub(crate) fn record_instruction(&mut self) -> Result<(), RibRuntimeError> {
+        self.usage.instructions += 1;
+
+        if let Some(limit) = self.limits.max_instructions {
+            if self.usage.instructions > limit {
+                return Err(RibRuntimeError::InstructionLimitExceeded { limit });
+            }
+        }
+
+        self.check_wall_time()
+    }
+
+    pub(crate) fn record_function_invocation(&mut self) -> Result<(), RibRuntimeError> {
+        self.usage.function_invocations += 1;
+
+        match self.limits.max_function_invocations {
+            Some(limit) if self.usage.function_invocations > limit => {
+                Err(RibRuntimeError::InvocationLimitExceeded { limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_wall_time(&self) -> Result<(), RibRuntimeError> {
+        match self.limits.max_wall_time {
+            Some(limit) if self.started_at.elapsed() > limit => {
+                Err(RibRuntimeError::TimeLimitExceeded { limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // The size of the stack includes the call frames of the lambdas
+    pub(crate) fn check_stack(&mut self, stack: &InterpreterStack) -> Result<(), RibRuntimeError> {
+        let stack_size = stack.stack.len() + stack.call_frames.len();
+        self.usage.max_stack_size = self.usage.max_stack_size.max(stack_size);
+
+        match self.limits.max_stack_size {
+            Some(limit) if stack_size > limit => Err(RibRuntimeError::StackLimitExceeded { limit }),
+            _ => Ok(()),
+        }
+    }
+
+    // Only the value on the top of the stack is measured, which is the value
+    // created by the last instruction. Measuring a value is linear in its size,
+    // and hence it is done only if there is a limit to enforce.
+    pub(crate) fn check_top_value(&self, stack: &InterpreterStack) -> Result<(), RibRuntimeError> {
+        let Some(limit) = self.limits.max_value_size else {
+            return Ok(());
+        };
+
+        let size = match stack.stack.last() {
+            Some(RibInterpreterStackValue::Val(value_and_type)) => {
+                value_size(&value_and_type.value)
+            }
+            Some(RibInterpreterStackValue::Sink(values, _)) => values
+                .iter()
+                .map(|value_and_type| value_size(&value_and_type.value))
+                .sum(),
+            _ => return Ok(()),
+        };
+
+        if size > limit {
+            Err(RibRuntimeError::ValueSizeLimitExceeded { size, limit })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Approximate size of a value in bytes, counting the payload and not the memory layout
+fn value_size(value: &Value) -> usize {
+    match value {
+        Value::Bool(_) | Value::U8(_) | Value::S8(_) => 1,
+        Value::U16(_) | Value::S16(_) => 2,
+        Value::U32(_) | Value::S32(_) | Value::F32(_) | Value::Char(_) | Value::Enum(_) => 4,
+        Value::U64(_) | Value::S64(_) | Value::F64(_) => 8,
+        Value::String(string) => string.len(),
+        Value::List(values) | Value::Tuple(values) | Value::Record(values) => {
+            values.iter().map(value_size).sum()
+        }
+        Value::Variant { case_value, .. } => 4 + case_value.as_deref().map(value_size).unwrap_or(0),
+        Value::Flags(flags) => flags.len(),
+        Value::Option(value) => 1 + value.as_deref().map(value_size).unwrap_or(0),
+        Value::Result(result) => {
+            let value = match result {
+                Ok(value) | Err(value) => value,
+            };
+            1 + value.as_deref().map(value_size).unwrap_or(0)
+        }
+        Value::Handle { uri, .. } => uri.len() + 8,
+    }
+}
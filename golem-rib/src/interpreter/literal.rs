@@ -123,6 +123,61 @@ pub enum CoercedNumericValue {
 }
 
 impl CoercedNumericValue {
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, CoercedNumericValue::Float(_))
+    }
+
+    fn is_signed(&self) -> bool {
+        matches!(self, CoercedNumericValue::NegInt(_))
+    }
+
+    // Integer operations are carried out in i128 so that mixing signed and unsigned
+    // operands never overflows before the result is checked
+    fn as_integer(&self) -> Option<i128> {
+        match self {
+            CoercedNumericValue::PosInt(value) => Some(*value as i128),
+            CoercedNumericValue::NegInt(value) => Some(*value as i128),
+            CoercedNumericValue::Float(_) => None,
+        }
+    }
+
+    fn as_float(&self) -> f64 {
+        match self {
+            CoercedNumericValue::PosInt(value) => *value as f64,
+            CoercedNumericValue::NegInt(value) => *value as f64,
+            CoercedNumericValue::Float(value) => *value,
+        }
+    }
+
+    fn from_integer(value: i128, signed: bool) -> Result<CoercedNumericValue, String> {
+        match (i64::try_from(value), u64::try_from(value)) {
+            (Ok(value), _) if signed => Ok(CoercedNumericValue::NegInt(value)),
+            (_, Ok(value)) => Ok(CoercedNumericValue::PosInt(value)),
+            (Ok(value), _) => Ok(CoercedNumericValue::NegInt(value)),
+            _ => Err(format!(
+                "overflow: {value} is out of the range of 64 bit integers"
+            )),
+        }
+    }
+
+    fn integer_op(
+        &self,
+        rhs: &CoercedNumericValue,
+        op: &str,
+        f: impl Fn(i128, i128) -> Option<i128>,
+    ) -> Result<CoercedNumericValue, String> {
+        match (self.as_integer(), rhs.as_integer()) {
+            (Some(a), Some(b)) => f(a, b)
+                .ok_or(format!("overflow in operation `{a} {op} {b}`"))
+                .and_then(|result| {
+                    CoercedNumericValue::from_integer(result, self.is_signed() || rhs.is_signed())
+                }),
+            _ => Err(format!(
+                "operator `{op}` can only be applied to integers, found {self} and {rhs}"
+            )),
+        }
+    }
+
     pub fn is_zero(&self) -> bool {
         match self {
             CoercedNumericValue::PosInt(val) => *val == 0,
@@ -253,28 +308,20 @@ macro_rules! impl_ops {
             type Output = Result<Self, String>;
 
             fn $method(self, rhs: Self) -> Self::Output {
-                use CoercedNumericValue::*;
-                Ok(match (self, rhs) {
-                    (Float(a), Float(b)) => Float(a.$method(b)),
-                    (Float(a), PosInt(b)) => Float(a.$method(b as f64)),
-                    (Float(a), NegInt(b)) => Float(a.$method(b as f64)),
-                    (PosInt(a), Float(b)) => Float((a as f64).$method(b)),
-                    (NegInt(a), Float(b)) => Float((a as f64).$method(b)),
-                    (PosInt(a), PosInt(b)) => a.$checked_method(b).map(PosInt).ok_or(format!(
-                        "overflow in unsigned operation between {} and {}",
-                        a, b
-                    ))?,
-                    (NegInt(a), NegInt(b)) => a.$checked_method(b).map(NegInt).ok_or(format!(
-                        "overflow in signed operation between {} and {}",
-                        a, b
-                    ))?,
-                    (PosInt(a), NegInt(b)) => (a as i64).$checked_method(b).map(NegInt).ok_or(
-                        format!("overflow in signed operation between {} and {}", a, b),
-                    )?,
-                    (NegInt(a), PosInt(b)) => a.$checked_method(b as i64).map(NegInt).ok_or(
-                        format!("overflow in signed operation between {} and {}", a, b),
-                    )?,
-                })
+                match (self.as_integer(), rhs.as_integer()) {
+                    (Some(a), Some(b)) => a
+                        .$checked_method(b)
+                        .ok_or(format!("overflow in operation between {} and {}", a, b))
+                        .and_then(|result| {
+                            CoercedNumericValue::from_integer(
+                                result,
+                                self.is_signed() || rhs.is_signed(),
+                            )
+                        }),
+                    _ => Ok(CoercedNumericValue::Float(
+                        self.as_float().$method(rhs.as_float()),
+                    )),
+                }
             }
         }
     };
@@ -284,6 +331,46 @@ impl_ops!(Add, add, checked_add);
 impl_ops!(Sub, sub, checked_sub);
 impl_ops!(Mul, mul, checked_mul);
 impl_ops!(Div, div, checked_div);
+impl_ops!(Rem, rem, checked_rem);
+
+macro_rules! impl_bit_ops {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl std::ops::$trait for CoercedNumericValue {
+            type Output = Result<Self, String>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.integer_op(&rhs, stringify!($op), |a, b| Some(a $op b))
+            }
+        }
+    };
+}
+
+impl_bit_ops!(BitAnd, bitand, &);
+impl_bit_ops!(BitOr, bitor, |);
+impl_bit_ops!(BitXor, bitxor, ^);
+
+impl std::ops::Shl for CoercedNumericValue {
+    type Output = Result<Self, String>;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.integer_op(&rhs, "<<", |a, b| {
+            // The shifted value can't exceed i128, and anything beyond 64 bits is an overflow
+            let shift = u32::try_from(b).ok().filter(|shift| *shift < 64)?;
+            a.checked_shl(shift)
+        })
+    }
+}
+
+impl std::ops::Shr for CoercedNumericValue {
+    type Output = Result<Self, String>;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.integer_op(&rhs, ">>", |a, b| {
+            let shift = u32::try_from(b).ok().filter(|shift| *shift < 64)?;
+            a.checked_shr(shift)
+        })
+    }
+}
 
 // Auto-derived PartialOrd fails if types don't match
 // and therefore custom impl.
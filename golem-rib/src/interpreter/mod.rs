@@ -16,18 +16,21 @@ pub use env::*;
 pub use eval::*;
 pub use interpreter_input::*;
 pub use interpreter_result::*;
+pub use limits::*;
 pub use literal::*;
 pub use rib_function_invoke::*;
 pub use rib_interpreter::*;
 pub use rib_runtime_error::*;
 pub use stack::*;
 
+mod built_in_functions;
 mod env;
 mod eval;
 mod instruction_cursor;
 mod interpreter_input;
 mod interpreter_result;
 mod interpreter_stack_value;
+mod limits;
 mod literal;
 mod rib_function_invoke;
 mod rib_interpreter;
@@ -51,6 +54,23 @@ pub async fn interpret(
     interpreter.run(rib).await
 }
 
+// Same as `interpret`, failing the evaluation as soon as any of the limits is exceeded
+pub async fn interpret_with_limits(
+    rib: RibByteCode,
+    rib_input: RibInput,
+    function_invoke: Arc<dyn RibComponentFunctionInvoke + Sync + Send>,
+    generate_worker_name: Option<Arc<dyn GenerateWorkerName + Sync + Send>>,
+    limits: RibInterpreterLimits,
+) -> Result<RibResult, RibRuntimeError> {
+    let mut interpreter = Interpreter::new(
+        rib_input,
+        function_invoke,
+        generate_worker_name.unwrap_or_else(|| Arc::new(DefaultWorkerNameGenerator)),
+    )
+    .with_limits(limits);
+    interpreter.run(rib).await
+}
+
 // This function can be used for those the Rib Scripts
 // where there are no side effecting function calls.
 // It is recommended to use `interpret` over `interpret_pure` if you are unsure.
@@ -66,6 +86,21 @@ pub async fn interpret_pure(
     interpreter.run(rib.clone()).await
 }
 
+// Same as `interpret_pure`, failing the evaluation as soon as any of the limits is exceeded
+pub async fn interpret_pure_with_limits(
+    rib: RibByteCode,
+    rib_input: RibInput,
+    generate_worker_name: Option<Arc<dyn GenerateWorkerName + Sync + Send>>,
+    limits: RibInterpreterLimits,
+) -> Result<RibResult, RibRuntimeError> {
+    let mut interpreter = Interpreter::pure(
+        rib_input,
+        generate_worker_name.unwrap_or_else(|| Arc::new(DefaultWorkerNameGenerator)),
+    )
+    .with_limits(limits);
+    interpreter.run(rib).await
+}
+
 #[macro_export]
 macro_rules! internal_corrupted_state {
     ($fmt:expr) => {{
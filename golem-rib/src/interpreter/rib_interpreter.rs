@@ -15,6 +15,7 @@
 use super::interpreter_stack_value::RibInterpreterStackValue;
 use crate::interpreter::env::InterpreterEnv;
 use crate::interpreter::instruction_cursor::RibByteCodeCursor;
+use crate::interpreter::limits::{ResourceMeter, RibInterpreterLimits, RibInterpreterUsage};
 use crate::interpreter::rib_runtime_error::{
     arithmetic_error, no_result, throw_error, RibRuntimeError,
 };
@@ -29,6 +30,8 @@ pub struct Interpreter {
     pub input: RibInput,
     pub invoke: Arc<dyn RibComponentFunctionInvoke + Sync + Send>,
     pub generate_worker_name: Arc<dyn GenerateWorkerName + Sync + Send>,
+    pub limits: RibInterpreterLimits,
+    usage: RibInterpreterUsage,
 }
 
 impl Default for Interpreter {
@@ -37,6 +40,8 @@ impl Default for Interpreter {
             input: RibInput::default(),
             invoke: Arc::new(internal::NoopRibFunctionInvoke),
             generate_worker_name: Arc::new(DefaultWorkerNameGenerator),
+            limits: RibInterpreterLimits::default(),
+            usage: RibInterpreterUsage::default(),
         }
     }
 }
@@ -53,6 +58,8 @@ impl Interpreter {
             input: input.clone(),
             invoke,
             generate_worker_name,
+            limits: RibInterpreterLimits::default(),
+            usage: RibInterpreterUsage::default(),
         }
     }
 
@@ -66,297 +73,450 @@ impl Interpreter {
             input,
             invoke: Arc::new(internal::NoopRibFunctionInvoke),
             generate_worker_name,
+            limits: RibInterpreterLimits::default(),
+            usage: RibInterpreterUsage::default(),
         }
     }
 
+    pub fn with_limits(mut self, limits: RibInterpreterLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn override_rib_input(&mut self, rib_input: RibInput) {
         self.input = rib_input;
     }
 
+    // The resources used by the last run of the interpreter
+    pub fn usage(&self) -> &RibInterpreterUsage {
+        &self.usage
+    }
+
     pub async fn run(&mut self, instructions0: RibByteCode) -> Result<RibResult, RibRuntimeError> {
+        let mut meter = ResourceMeter::new(self.limits.clone());
+        let result = self.run_metered(instructions0, &mut meter).await;
+        self.usage = meter.usage();
+        result
+    }
+
+    async fn run_metered(
+        &mut self,
+        instructions0: RibByteCode,
+        meter: &mut ResourceMeter,
+    ) -> Result<RibResult, RibRuntimeError> {
         let mut byte_code_cursor = RibByteCodeCursor::from_rib_byte_code(instructions0);
         let mut stack = InterpreterStack::default();
 
         let mut interpreter_env = InterpreterEnv::from(&self.input, &self.invoke);
 
         while let Some(instruction) = byte_code_cursor.get_instruction() {
-            match instruction {
-                RibIR::GenerateWorkerName(instance_count) => {
-                    internal::run_generate_worker_name(
-                        instance_count,
-                        self,
-                        &mut stack,
-                        &mut interpreter_env,
-                    )?;
-                }
+            // Exceeding a limit is never caught by a try block
+            meter.record_instruction()?;
 
-                RibIR::PushLit(val) => {
-                    stack.push_val(val);
-                }
+            if let RibIR::InvokeFunction(..) = instruction {
+                meter.record_function_invocation()?;
+            }
 
-                RibIR::PushFlag(val) => {
-                    stack.push_val(val);
-                }
+            let creates_value = internal::creates_value(&instruction);
 
-                RibIR::CreateAndPushRecord(analysed_type) => {
-                    internal::run_create_record_instruction(analysed_type, &mut stack)?;
+            let result = self
+                .execute_instruction(
+                    instruction,
+                    &mut byte_code_cursor,
+                    &mut stack,
+                    &mut interpreter_env,
+                )
+                .await;
+
+            match result {
+                Ok(None) => {}
+                // `expr?` on a `none` or an `err` returns early from the rib script
+                Ok(Some(rib_result)) => return Ok(rib_result),
+                Err(error) => {
+                    internal::handle_runtime_error(error, &mut byte_code_cursor, &mut stack)?
                 }
+            }
 
-                RibIR::UpdateRecord(field_name) => {
-                    internal::run_update_record_instruction(field_name, &mut stack)?;
-                }
+            meter.check_stack(&stack)?;
 
-                RibIR::PushList(analysed_type, arg_size) => {
-                    internal::run_push_list_instruction(arg_size, analysed_type, &mut stack)?;
-                }
+            if creates_value {
+                meter.check_top_value(&stack)?;
+            }
+        }
 
-                RibIR::EqualTo => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left == right)?;
-                }
+        // The last instruction could have been a long-running function call
+        meter.check_wall_time()?;
 
-                RibIR::GreaterThan => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left > right)?;
-                }
+        match byte_code_cursor.last() {
+            Some(RibIR::AssignVar(_)) => Ok(RibResult::Unit),
+            _ => {
+                let stack_value = stack
+                    .pop()
+                    .unwrap_or_else(|| RibInterpreterStackValue::Unit);
 
-                RibIR::LessThan => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left < right)?;
-                }
+                let rib_result = RibResult::from_rib_interpreter_stack_value(&stack_value)
+                    .ok_or_else(no_result)?;
+                Ok(rib_result)
+            }
+        }
+    }
 
-                RibIR::GreaterThanOrEqualTo => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left >= right)?;
-                }
+    async fn execute_instruction(
+        &mut self,
+        instruction: RibIR,
+        byte_code_cursor: &mut RibByteCodeCursor,
+        stack: &mut InterpreterStack,
+        interpreter_env: &mut InterpreterEnv,
+    ) -> RibInterpreterResult<Option<RibResult>> {
+        match instruction {
+            RibIR::GenerateWorkerName(instance_count) => {
+                internal::run_generate_worker_name(instance_count, self, stack, interpreter_env)?;
+            }
 
-                RibIR::LessThanOrEqualTo => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left <= right)?;
-                }
-                RibIR::Plus(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            let result = left + right;
-                            result.map_err(|err| arithmetic_error(err.as_str()))
-                        },
-                        &analysed_type,
-                    )?;
-                }
-                RibIR::Minus(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            let result = left - right;
-                            result.map_err(|err| arithmetic_error(err.as_str()))
-                        },
-                        &analysed_type,
-                    )?;
-                }
-                RibIR::Divide(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            if right.is_zero() {
-                                Err(arithmetic_error(
-                                    format!("division by zero. left: {left}, right: {right}")
-                                        .as_str(),
-                                ))
-                            } else {
-                                (left / right).map_err(|err| arithmetic_error(err.as_str()))
-                            }
-                        },
-                        &analysed_type,
-                    )?;
-                }
-                RibIR::Multiply(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            let result = left * right;
-                            result.map_err(|err| arithmetic_error(err.as_str()))
-                        },
-                        &analysed_type,
-                    )?;
-                }
+            RibIR::PushLit(val) => {
+                stack.push_val(val);
+            }
 
-                RibIR::AssignVar(variable_id) => {
-                    internal::run_assign_var_instruction(
-                        variable_id,
-                        &mut stack,
-                        &mut interpreter_env,
-                    )?;
-                }
+            RibIR::PushFlag(val) => {
+                stack.push_val(val);
+            }
 
-                RibIR::LoadVar(variable_id) => {
-                    internal::run_load_var_instruction(
-                        variable_id,
-                        &mut stack,
-                        &mut interpreter_env,
-                    )?;
-                }
+            RibIR::CreateAndPushRecord(analysed_type) => {
+                internal::run_create_record_instruction(analysed_type, stack)?;
+            }
 
-                RibIR::IsEmpty => {
-                    internal::run_is_empty_instruction(&mut stack)?;
-                }
+            RibIR::UpdateRecord(field_name) => {
+                internal::run_update_record_instruction(field_name, stack)?;
+            }
 
-                RibIR::JumpIfFalse(instruction_id) => {
-                    internal::run_jump_if_false_instruction(
-                        instruction_id,
-                        &mut byte_code_cursor,
-                        &mut stack,
-                    )?;
-                }
+            RibIR::PushList(analysed_type, arg_size) => {
+                internal::run_push_list_instruction(arg_size, analysed_type, stack)?;
+            }
 
-                RibIR::SelectField(field_name) => {
-                    internal::run_select_field_instruction(field_name, &mut stack)?;
-                }
+            RibIR::EqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left == right)?;
+            }
 
-                RibIR::SelectIndex(index) => {
-                    internal::run_select_index_instruction(&mut stack, index)?;
-                }
+            RibIR::NotEqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left == right)?;
+                internal::run_negate_instruction(stack)?;
+            }
 
-                RibIR::SelectIndexV1 => {
-                    internal::run_select_index_v1_instruction(&mut stack)?;
-                }
+            RibIR::GreaterThan => {
+                internal::run_compare_instruction(stack, |left, right| left > right)?;
+            }
 
-                RibIR::CreateFunctionName(site, function_type) => {
-                    internal::run_create_function_name_instruction(
-                        site,
-                        function_type,
-                        &mut stack,
-                    )?;
-                }
+            RibIR::LessThan => {
+                internal::run_compare_instruction(stack, |left, right| left < right)?;
+            }
+
+            RibIR::GreaterThanOrEqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left >= right)?;
+            }
 
-                RibIR::InvokeFunction(
+            RibIR::LessThanOrEqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left <= right)?;
+            }
+            RibIR::Plus(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left + right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::Minus(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left - right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::Divide(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        if right.is_zero() {
+                            Err(arithmetic_error(
+                                format!("division by zero. left: {left}, right: {right}").as_str(),
+                            ))
+                        } else {
+                            (left / right).map_err(|err| arithmetic_error(err.as_str()))
+                        }
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::Multiply(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left * right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::Modulo(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        if right.is_zero() {
+                            Err(arithmetic_error(
+                                format!("modulo by zero. left: {left}, right: {right}").as_str(),
+                            ))
+                        } else {
+                            (left % right).map_err(|err| arithmetic_error(err.as_str()))
+                        }
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::BitwiseAnd(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left & right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::BitwiseOr(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left | right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::BitwiseXor(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left ^ right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::ShiftLeft(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left << right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+            RibIR::ShiftRight(analysed_type) => {
+                internal::run_math_instruction(
+                    stack,
+                    |left, right| {
+                        let result = left >> right;
+                        result.map_err(|err| arithmetic_error(err.as_str()))
+                    },
+                    &analysed_type,
+                )?;
+            }
+
+            RibIR::AssignVar(variable_id) => {
+                internal::run_assign_var_instruction(variable_id, stack, interpreter_env)?;
+            }
+
+            RibIR::LoadVar(variable_id) => {
+                internal::run_load_var_instruction(variable_id, stack, interpreter_env)?;
+            }
+
+            RibIR::IsEmpty => {
+                internal::run_is_empty_instruction(stack)?;
+            }
+
+            RibIR::JumpIfFalse(instruction_id) => {
+                internal::run_jump_if_false_instruction(instruction_id, byte_code_cursor, stack)?;
+            }
+
+            RibIR::SelectField(field_name) => {
+                internal::run_select_field_instruction(field_name, stack)?;
+            }
+
+            RibIR::SelectIndex(index) => {
+                internal::run_select_index_instruction(stack, index)?;
+            }
+
+            RibIR::SelectIndexV1 => {
+                internal::run_select_index_v1_instruction(stack)?;
+            }
+
+            RibIR::SelectKey => {
+                internal::run_select_key_instruction(stack)?;
+            }
+
+            RibIR::CreateFunctionName(site, function_type) => {
+                internal::run_create_function_name_instruction(site, function_type, stack)?;
+            }
+
+            RibIR::InvokeFunction(
+                component_info,
+                instance_variable,
+                arg_size,
+                expected_result_type,
+            ) => {
+                internal::run_invoke_function_instruction(
                     component_info,
-                    instance_variable,
+                    &byte_code_cursor.position(),
                     arg_size,
+                    instance_variable,
+                    stack,
+                    interpreter_env,
                     expected_result_type,
-                ) => {
-                    internal::run_invoke_function_instruction(
-                        component_info,
-                        &byte_code_cursor.position(),
-                        arg_size,
-                        instance_variable,
-                        &mut stack,
-                        &mut interpreter_env,
-                        expected_result_type,
-                    )
-                    .await?;
-                }
+                )
+                .await?;
+            }
 
-                RibIR::PushVariant(variant_name, analysed_type) => {
-                    internal::run_variant_construction_instruction(
-                        variant_name,
-                        analysed_type,
-                        &mut stack,
-                    )
+            RibIR::PushVariant(variant_name, analysed_type) => {
+                internal::run_variant_construction_instruction(variant_name, analysed_type, stack)
                     .await?;
-                }
+            }
 
-                RibIR::PushEnum(enum_name, analysed_type) => {
-                    internal::run_push_enum_instruction(&mut stack, enum_name, analysed_type)?;
-                }
+            RibIR::PushEnum(enum_name, analysed_type) => {
+                internal::run_push_enum_instruction(stack, enum_name, analysed_type)?;
+            }
 
-                RibIR::Throw(message) => {
-                    return Err(throw_error(message.as_str()));
-                }
+            RibIR::Throw(message) => {
+                return Err(throw_error(message.as_str()));
+            }
 
-                RibIR::GetTag => {
-                    internal::run_get_tag_instruction(&mut stack)?;
-                }
+            RibIR::GetTag => {
+                internal::run_get_tag_instruction(stack)?;
+            }
 
-                RibIR::Deconstruct => {
-                    internal::run_deconstruct_instruction(&mut stack)?;
-                }
+            RibIR::Deconstruct => {
+                internal::run_deconstruct_instruction(stack)?;
+            }
 
-                RibIR::Jump(instruction_id) => {
-                    byte_code_cursor.move_to(&instruction_id).ok_or_else(|| {
-                        internal_corrupted_state!(
-                            "internal error. Failed to move to label {}",
-                            instruction_id.index
-                        )
-                    })?;
-                }
+            RibIR::Jump(instruction_id) => {
+                byte_code_cursor.move_to(&instruction_id).ok_or_else(|| {
+                    internal_corrupted_state!(
+                        "internal error. Failed to move to label {}",
+                        instruction_id.index
+                    )
+                })?;
+            }
 
-                RibIR::PushSome(analysed_type) => {
-                    internal::run_create_some_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::PushNone(analysed_type) => {
-                    internal::run_create_none_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::PushOkResult(analysed_type) => {
-                    internal::run_create_ok_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::PushErrResult(analysed_type) => {
-                    internal::run_create_err_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::Concat(arg_size) => {
-                    internal::run_concat_instruction(&mut stack, arg_size)?;
-                }
-                RibIR::PushTuple(analysed_type, arg_size) => {
-                    internal::run_push_tuple_instruction(arg_size, analysed_type, &mut stack)?;
-                }
-                RibIR::Negate => {
-                    internal::run_negate_instruction(&mut stack)?;
-                }
+            RibIR::PushSome(analysed_type) => {
+                internal::run_create_some_instruction(stack, analysed_type)?;
+            }
+            RibIR::PushNone(analysed_type) => {
+                internal::run_create_none_instruction(stack, analysed_type)?;
+            }
+            RibIR::PushOkResult(analysed_type) => {
+                internal::run_create_ok_instruction(stack, analysed_type)?;
+            }
+            RibIR::PushErrResult(analysed_type) => {
+                internal::run_create_err_instruction(stack, analysed_type)?;
+            }
+            RibIR::Concat(arg_size) => {
+                internal::run_concat_instruction(stack, arg_size)?;
+            }
+            RibIR::PushTuple(analysed_type, arg_size) => {
+                internal::run_push_tuple_instruction(arg_size, analysed_type, stack)?;
+            }
+            RibIR::Negate => {
+                internal::run_negate_instruction(stack)?;
+            }
 
-                RibIR::Label(_) => {}
+            RibIR::Label(_) => {}
 
-                RibIR::And => {
-                    internal::run_and_instruction(&mut stack)?;
-                }
+            RibIR::And => {
+                internal::run_and_instruction(stack)?;
+            }
 
-                RibIR::Or => {
-                    internal::run_or_instruction(&mut stack)?;
-                }
-                RibIR::ToIterator => {
-                    internal::run_to_iterator(&mut stack)?;
-                }
-                RibIR::CreateSink(analysed_type) => {
-                    internal::run_create_sink_instruction(&mut stack, analysed_type)?
-                }
-                RibIR::AdvanceIterator => {
-                    internal::run_advance_iterator_instruction(&mut stack)?;
-                }
-                RibIR::PushToSink => {
-                    internal::run_push_to_sink_instruction(&mut stack)?;
-                }
+            RibIR::Or => {
+                internal::run_or_instruction(stack)?;
+            }
+            RibIR::ToIterator => {
+                internal::run_to_iterator(stack)?;
+            }
+            RibIR::CreateSink(analysed_type) => {
+                internal::run_create_sink_instruction(stack, analysed_type)?
+            }
+            RibIR::AdvanceIterator => {
+                internal::run_advance_iterator_instruction(stack)?;
+            }
+            RibIR::PushToSink => {
+                internal::run_push_to_sink_instruction(stack)?;
+            }
 
-                RibIR::SinkToList => {
-                    internal::run_sink_to_list_instruction(&mut stack)?;
-                }
+            RibIR::SinkToList => {
+                internal::run_sink_to_list_instruction(stack)?;
+            }
 
-                RibIR::Length => {
-                    internal::run_length_instruction(&mut stack)?;
-                }
+            RibIR::Length => {
+                internal::run_length_instruction(stack)?;
             }
-        }
 
-        match byte_code_cursor.last() {
-            Some(RibIR::AssignVar(_)) => Ok(RibResult::Unit),
-            _ => {
-                let stack_value = stack
-                    .pop()
-                    .unwrap_or_else(|| RibInterpreterStackValue::Unit);
+            RibIR::CallBuiltIn(function, return_type) => {
+                internal::run_call_built_in_instruction(function, return_type, stack)?;
+            }
 
-                let rib_result = RibResult::from_rib_interpreter_stack_value(&stack_value)
-                    .ok_or_else(no_result)?;
-                Ok(rib_result)
+            RibIR::CallLambda(instruction_id) => {
+                stack.push_call_frame(byte_code_cursor.position());
+                byte_code_cursor.move_to(&instruction_id).ok_or_else(|| {
+                    internal_corrupted_state!(
+                        "internal error. Failed to move to the function at label {}",
+                        instruction_id.index
+                    )
+                })?;
+            }
+
+            RibIR::Propagate(return_type) => {
+                return internal::run_propagate_instruction(stack, return_type);
+            }
+
+            RibIR::PushErrorHandler(instruction_id) => {
+                stack.push_error_handler(instruction_id);
+            }
+
+            RibIR::PopErrorHandler => {
+                stack.pop_error_handler().ok_or_else(|| {
+                    internal_corrupted_state!("internal error. No error handler to pop")
+                })?;
+            }
+
+            RibIR::Return => {
+                let call_frame = stack.pop_call_frame().ok_or_else(|| {
+                    internal_corrupted_state!("internal error. Return outside of a function")
+                })?;
+                byte_code_cursor.move_to_position(&call_frame.return_position);
             }
         }
+
+        Ok(None)
     }
 }
 
 mod internal {
+    use crate::interpreter::built_in_functions::evaluate_built_in_function;
     use crate::interpreter::env::{EnvironmentKey, InterpreterEnv};
     use crate::interpreter::interpreter_stack_value::RibInterpreterStackValue;
     use crate::interpreter::literal::LiteralValue;
     use crate::interpreter::stack::InterpreterStack;
     use crate::{
-        bail_corrupted_state, internal_corrupted_state, AnalysedTypeWithUnit, CoercedNumericValue,
-        ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName,
-        FunctionReferenceType, GetLiteralValue, InstanceVariable, InstructionId, Interpreter,
-        ParsedFunctionName, ParsedFunctionReference, ParsedFunctionSite,
-        RibComponentFunctionInvoke, RibFunctionInvokeResult, RibInterpreterResult, TypeHint,
-        VariableId,
+        bail_corrupted_state, internal_corrupted_state, AnalysedTypeWithUnit, BuiltInFunction,
+        CoercedNumericValue, ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn,
+        EvaluatedWorkerName, FunctionReferenceType, GetLiteralValue, InstanceVariable,
+        InstructionId, Interpreter, ParsedFunctionName, ParsedFunctionReference,
+        ParsedFunctionSite, RibComponentFunctionInvoke, RibFunctionInvokeResult, RibIR,
+        RibInterpreterResult, RibResult, TypeHint, VariableId,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use golem_wasm_ast::analysis::TypeResult;
@@ -364,10 +524,10 @@ mod internal {
 
     use crate::interpreter::instruction_cursor::RibByteCodeCursor;
     use crate::interpreter::rib_runtime_error::{
-        cast_error_custom, empty_stack, exhausted_iterator, field_not_found, function_invoke_fail,
-        index_out_of_bound, infinite_computation, input_not_found, instruction_jump_error,
-        insufficient_stack_items, invalid_type_with_stack_value, type_mismatch_with_type_hint,
-        RibRuntimeError,
+        arithmetic_error, cast_error_custom, empty_stack, exhausted_iterator, field_not_found,
+        function_invoke_fail, index_out_of_bound, infinite_computation, input_not_found,
+        instruction_jump_error, insufficient_stack_items, invalid_type_with_stack_value,
+        key_not_found, type_mismatch_with_type_hint, RibRuntimeError,
     };
     use crate::type_inference::GetTypeHint;
     use async_trait::async_trait;
@@ -376,6 +536,23 @@ mod internal {
 
     pub(crate) struct NoopRibFunctionInvoke;
 
+    // Instructions that can create a value larger than their operands,
+    // which is measured when there is a limit on the size of values
+    pub(crate) fn creates_value(instruction: &RibIR) -> bool {
+        matches!(
+            instruction,
+            RibIR::PushLit(_)
+                | RibIR::PushList(_, _)
+                | RibIR::PushTuple(_, _)
+                | RibIR::UpdateRecord(_)
+                | RibIR::Concat(_)
+                | RibIR::PushToSink
+                | RibIR::SinkToList
+                | RibIR::CallBuiltIn(_, _)
+                | RibIR::InvokeFunction(..)
+        )
+    }
+
     #[async_trait]
     impl RibComponentFunctionInvoke for NoopRibFunctionInvoke {
         async fn invoke(
@@ -391,6 +568,73 @@ mod internal {
         }
     }
 
+    // Catches the error if it is within a try block, unwinding the stack to the state
+    // at the beginning of the try block and jumping to the catch block with the error value
+    pub(crate) fn handle_runtime_error(
+        error: RibRuntimeError,
+        byte_code_cursor: &mut RibByteCodeCursor,
+        interpreter_stack: &mut InterpreterStack,
+    ) -> RibInterpreterResult<()> {
+        let Some(error_value) = error.to_error_value() else {
+            return Err(error);
+        };
+
+        let Some(error_handler) = interpreter_stack.pop_error_handler() else {
+            return Err(error);
+        };
+
+        interpreter_stack.unwind_to(&error_handler);
+        interpreter_stack.push_val(error_value);
+
+        byte_code_cursor
+            .move_to(&error_handler.catch_label)
+            .ok_or_else(|| {
+                internal_corrupted_state!(
+                    "internal error. Failed to move to the catch block at label {}",
+                    error_handler.catch_label.index
+                )
+            })?;
+
+        Ok(())
+    }
+
+    pub(crate) fn run_propagate_instruction(
+        interpreter_stack: &mut InterpreterStack,
+        return_type: AnalysedType,
+    ) -> RibInterpreterResult<Option<RibResult>> {
+        let value_and_type = interpreter_stack.try_pop_val()?;
+
+        match value_and_type {
+            ValueAndType {
+                value: Value::Option(Some(value)),
+                typ: AnalysedType::Option(option_type),
+            } => {
+                interpreter_stack.push_val(ValueAndType::new(*value, *option_type.inner));
+                Ok(None)
+            }
+            ValueAndType {
+                value: Value::Result(Ok(value)),
+                typ: AnalysedType::Result(result_type),
+            } => {
+                match (value, result_type.ok) {
+                    (Some(value), Some(ok_type)) => {
+                        interpreter_stack.push_val(ValueAndType::new(*value, *ok_type))
+                    }
+                    _ => interpreter_stack.push(RibInterpreterStackValue::Unit),
+                }
+                Ok(None)
+            }
+            ValueAndType {
+                value: value @ (Value::Option(None) | Value::Result(Err(_))),
+                ..
+            } => Ok(Some(RibResult::Val(ValueAndType::new(value, return_type)))),
+            value_and_type => Err(internal_corrupted_state!(
+                "internal error. Expected an option or a result to propagate, found {}",
+                value_and_type
+            )),
+        }
+    }
+
     pub(crate) fn run_is_empty_instruction(
         interpreter_stack: &mut InterpreterStack,
     ) -> RibInterpreterResult<()> {
@@ -666,6 +910,21 @@ mod internal {
         Ok(())
     }
 
+    pub(crate) fn run_call_built_in_instruction(
+        function: BuiltInFunction,
+        return_type: AnalysedType,
+        interpreter_stack: &mut InterpreterStack,
+    ) -> RibInterpreterResult<()> {
+        let arg_size = function.signature().arity();
+        let args = interpreter_stack.try_pop_n_val(arg_size)?;
+
+        let result = evaluate_built_in_function(&function, args, &return_type)?;
+
+        interpreter_stack.push_val(result);
+
+        Ok(())
+    }
+
     pub(crate) fn run_length_instruction(
         interpreter_stack: &mut InterpreterStack,
     ) -> RibInterpreterResult<()> {
@@ -915,9 +1174,26 @@ mod internal {
         let right = interpreter_stack.try_pop()?;
 
         let result = left.evaluate_math_op(&right, compare_fn)?;
-        let numerical_type = result
-            .cast_to(target_numerical_type)
-            .ok_or_else(|| cast_error_custom(result, target_numerical_type.get_type_hint()))?;
+        let numerical_type = result.cast_to(target_numerical_type).ok_or_else(|| {
+            // An integer that doesn't fit into the integer type of the operation is an overflow
+            if result.is_integer()
+                && matches!(
+                    target_numerical_type,
+                    AnalysedType::U8(_)
+                        | AnalysedType::U16(_)
+                        | AnalysedType::U32(_)
+                        | AnalysedType::U64(_)
+                        | AnalysedType::S8(_)
+                        | AnalysedType::S16(_)
+                        | AnalysedType::S32(_)
+                        | AnalysedType::S64(_)
+                )
+            {
+                arithmetic_error(format!("overflow: {result} is out of range").as_str())
+            } else {
+                cast_error_custom(result, target_numerical_type.get_type_hint())
+            }
+        })?;
 
         interpreter_stack.push_val(numerical_type);
 
@@ -1037,23 +1313,83 @@ mod internal {
         }
     }
 
-    pub(crate) fn run_select_index_instruction(
+    // A map is a list of key-value tuples at runtime, and the value
+    // of the first entry with a matching key is selected
+    pub(crate) fn run_select_key_instruction(
         interpreter_stack: &mut InterpreterStack,
-        index: usize,
     ) -> RibInterpreterResult<()> {
-        let stack_value = interpreter_stack.pop().ok_or_else(empty_stack)?;
+        let stack_map_value = interpreter_stack.pop().ok_or_else(empty_stack)?;
 
-        match stack_value {
+        let key = interpreter_stack
+            .pop()
+            .ok_or_else(empty_stack)?
+            .get_val()
+            .ok_or_else(|| internal_corrupted_state!("failed to get the key to select from map"))?;
+
+        match stack_map_value {
             RibInterpreterStackValue::Val(ValueAndType {
-                value: Value::List(items),
+                value: Value::List(entries),
                 typ: AnalysedType::List(typ),
             }) => {
-                let value = items
-                    .get(index)
-                    .ok_or_else(|| index_out_of_bound(index, items.len()))?
-                    .clone();
+                let AnalysedType::Tuple(entry_type) = typ.inner.as_ref() else {
+                    bail_corrupted_state!("map entries are expected to be tuples");
+                };
 
-                interpreter_stack.push_val(ValueAndType::new(value, (*typ.inner).clone()));
+                let (key_type, value_type) = match entry_type.items.as_slice() {
+                    [key_type, value_type] => (key_type, value_type),
+                    _ => bail_corrupted_state!("map entries are expected to be key-value pairs"),
+                };
+
+                let value = entries
+                    .iter()
+                    .find_map(|entry| match entry {
+                        Value::Tuple(items) if items.len() == 2 => {
+                            let entry_key = ValueAndType::new(items[0].clone(), key_type.clone());
+                            is_same_key(&entry_key, &key).then(|| items[1].clone())
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| key_not_found(&key.value))?;
+
+                interpreter_stack.push_val(ValueAndType::new(value, value_type.clone()));
+                Ok(())
+            }
+            result => Err(invalid_type_with_stack_value(
+                vec![TypeHint::Map {
+                    key: None,
+                    value: None,
+                }],
+                result,
+            )),
+        }
+    }
+
+    // Literal keys are compared by their literal value, such that
+    // a number key matches regardless of its precise numeric type
+    fn is_same_key(entry_key: &ValueAndType, key: &ValueAndType) -> bool {
+        match (entry_key.get_literal(), key.get_literal()) {
+            (Some(entry_key), Some(key)) => entry_key == key,
+            _ => entry_key.value == key.value,
+        }
+    }
+
+    pub(crate) fn run_select_index_instruction(
+        interpreter_stack: &mut InterpreterStack,
+        index: usize,
+    ) -> RibInterpreterResult<()> {
+        let stack_value = interpreter_stack.pop().ok_or_else(empty_stack)?;
+
+        match stack_value {
+            RibInterpreterStackValue::Val(ValueAndType {
+                value: Value::List(items),
+                typ: AnalysedType::List(typ),
+            }) => {
+                let value = items
+                    .get(index)
+                    .ok_or_else(|| index_out_of_bound(index, items.len()))?
+                    .clone();
+
+                interpreter_stack.push_val(ValueAndType::new(value, (*typ.inner).clone()));
                 Ok(())
             }
             RibInterpreterStackValue::Val(ValueAndType {
@@ -1635,6 +1971,7 @@ mod tests {
         bool, f32, field, list, r#enum, record, result, s32, str, tuple, u32, u64, u8,
     };
     use golem_wasm_rpc::{IntoValue, IntoValueAndType, Value, ValueAndType};
+    use std::time::Duration;
 
     #[test]
     async fn test_interpreter_for_literal() {
@@ -2142,6 +2479,187 @@ mod tests {
         );
     }
 
+    #[test]
+    async fn test_interpreter_built_in_string_functions() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let parts = string.split("Foo,Bar,Baz", ",");
+            let joined = string.join(list.reverse(parts), "-");
+            string.lowercase(joined)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap().value,
+            Value::String("baz-bar-foo".to_string())
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_built_in_generic_functions() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let xs: list<u32> = [3, 1, 2];
+            let first = option.unwrap-or(list.head(xs), 0);
+            math.max(first, 10)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(result.get_val().unwrap(), 10u32.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_built_in_encoding_and_datetime_functions() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let encoded = encoding.base64-encode("golem");
+            let timestamp: u64 = 0;
+            let iso = datetime.to-iso8601(timestamp);
+            "${encoded} ${iso}"
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap().value,
+            Value::String("Z29sZW0= 1970-01-01T00:00:00.000Z".to_string())
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_built_in_function_overflow() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: s8 = -128;
+            math.abs(x)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn test_interpreter_built_in_format_number_out_of_range() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let formatted = string.format-number(1.5, 2);
+            let out_of_range = string.format-number(1.5, 100000);
+            "${formatted} ${out_of_range}"
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
+
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::BuiltInFunctionError { .. })
+        ));
+    }
+
+    #[test]
+    async fn test_interpreter_function_definition() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let offset: u32 = 10;
+
+            fn add(x: u32, y: u32): u32 {
+              let sum = x + y;
+              sum + offset
+            };
+
+            let first = add(1, 2);
+            add(first, 3)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(result.get_val().unwrap(), 26u32.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_higher_order_functions_with_closures() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let xs: list<u32> = [1, 2, 3, 4];
+            let doubled = list.map(xs, |x| x * 2);
+            let large = list.filter(doubled, |x| x > 4);
+            list.fold(large, 0, |acc, x| acc + x)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(result.get_val().unwrap(), 14u32.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_filter_keeps_the_order_of_the_elements() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let xs: list<u32> = [5, 1, 4, 2, 3];
+            let large = list.filter(xs, |x| x > 2);
+            let more: list<list<u32>> = [[1], [], [2, 3]];
+            list.concat(large, list.flatten(more))
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            vec![5u32, 4, 3, 1, 2, 3].into_value_and_type()
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_higher_order_functions_with_named_functions() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let names = ["foo", "bar"];
+            let prefix = "hello";
+
+            fn greet(name: string): string {
+              "${prefix} ${name}"
+            };
+
+            list.map(names, greet)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            vec!["hello foo".to_string(), "hello bar".to_string()].into_value_and_type()
+        );
+    }
+
     #[test]
     async fn test_interpreter_with_variant_and_enum() {
         let test_deps = RibTestDeps::test_deps_with_global_functions();
@@ -2223,235 +2741,706 @@ mod tests {
           {res1: result1, res2: result2}
         "#;
 
-        let expr = Expr::from_text(expr).unwrap();
+        let expr = Expr::from_text(expr).unwrap();
+
+        let compiled = compiler.compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+        let expected_value = Value::Record(vec![3u32.into_value(), 7u64.into_value()]);
+
+        let expected_type = record(vec![field("res1", u32()), field("res2", u64())]);
+        assert_eq!(
+            result,
+            RibResult::Val(ValueAndType::new(expected_value, expected_type))
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_list_reduce() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+          let x: list<u8> = [1, 2];
+
+          reduce z, a in x from 0u8 {
+            yield z + a;
+          }
+
+          "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiler = RibCompiler::default();
+        let compiled = compiler.compile(expr).unwrap();
+        let result = interpreter
+            .run(compiled.byte_code)
+            .await
+            .unwrap()
+            .get_val()
+            .unwrap();
+
+        assert_eq!(result, 3u8.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_list_reduce_from_record() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+           let x = [{name: "foo", age: 1u64}, {name: "bar", age: 2u64}];
+
+           let names = for i in x {
+             yield i.name;
+           };
+
+          reduce z, a in names from "" {
+            let result = if z == "" then a else "${z}, ${a}";
+
+            yield result;
+          }
+
+          "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+
+        let compiler = RibCompiler::default();
+        let compiled = compiler.compile(expr).unwrap();
+
+        let result = interpreter
+            .run(compiled.byte_code)
+            .await
+            .unwrap()
+            .get_val()
+            .unwrap();
+
+        assert_eq!(result, "foo, bar".into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_list_reduce_text() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+           let x = ["foo", "bar"];
+
+          reduce z, a in x from "" {
+            let result = if z == "" then a else "${z}, ${a}";
+
+            yield result;
+          }
+
+          "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+
+        let compiler = RibCompiler::default();
+
+        let compiled = compiler.compile(expr).unwrap();
+
+        let result = interpreter
+            .run(compiled.byte_code)
+            .await
+            .unwrap()
+            .get_val()
+            .unwrap();
+
+        assert_eq!(result, "foo, bar".into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_list_reduce_empty() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+          let x: list<u8> = [];
+
+          reduce z, a in x from 0u8 {
+            yield z + a;
+          }
+
+          "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+
+        let compiler = RibCompiler::default();
+
+        let compiled = compiler.compile(expr).unwrap();
+
+        let result = interpreter
+            .run(compiled.byte_code)
+            .await
+            .unwrap()
+            .get_val()
+            .unwrap();
+
+        assert_eq!(result, 0u8.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_with_numbers_1() {
+        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
+
+        let mut interpreter = test_utils::interpreter_with_static_function_response(
+            &ValueAndType::new(Value::U64(2), u64()),
+            None,
+        );
+
+        // 1 is automatically inferred to be u32
+        let rib = r#"
+          let worker = instance("my-worker");
+          worker.foo(1)
+        "#;
+
+        let expr = Expr::from_text(rib).unwrap();
+
+        let compiler_config = RibCompilerConfig::new(component_metadata, vec![]);
+        let compiler = RibCompiler::new(compiler_config);
+
+        let compiled = compiler.compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            ValueAndType::new(Value::U64(2), u64())
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_with_numbers_2() {
+        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
+
+        let mut interpreter = test_utils::interpreter_with_static_function_response(
+            &ValueAndType::new(Value::U64(2), u64()),
+            None,
+        );
+
+        // 1 and 2 are automatically inferred to be u32
+        // since the type of z is inferred to be u32 as that being passed to a function
+        // that expects u32
+        let rib = r#"
+          let worker = instance("my-worker");
+          let z = 1 + 2;
+          worker.foo(z)
+        "#;
+
+        let expr = Expr::from_text(rib).unwrap();
+
+        let compiler_config = RibCompilerConfig::new(component_metadata, vec![]);
+        let compiler = RibCompiler::new(compiler_config);
+
+        let compiled = compiler.compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            ValueAndType::new(Value::U64(2), u64())
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_with_numbers_3() {
+        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
+
+        // This will cause a type inference error
+        // because the operands of the + operator are not of the same type
+        let rib = r#"
+          let worker = instance("my-worker");
+          let z = 1: u8 + 2;
+          worker.foo(z)
+        "#;
+
+        let expr = Expr::from_text(rib).unwrap();
+        let compiler = RibCompiler::new(RibCompilerConfig::new(component_metadata, vec![]));
+        let compile_result = compiler.compile(expr);
+        assert!(compile_result.is_err());
+    }
+
+    #[test]
+    async fn test_interpreter_with_numbers_4() {
+        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
+
+        // This will cause a type inference error
+        // because the operands of the + operator are supposed to be u32
+        // since z is u32
+        let rib = r#"
+          let worker = instance("my-worker");
+          let z = 1: u8 + 2: u8;
+          worker.foo(z)
+        "#;
+
+        let expr = Expr::from_text(rib).unwrap();
+        let compiler = RibCompiler::new(RibCompilerConfig::new(component_metadata, vec![]));
+        let compile_result = compiler.compile(expr);
+        assert!(compile_result.is_err());
+    }
+
+    #[test]
+    async fn test_interpreter_with_modulo_and_integer_division() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: u32 = 17;
+            let y: u32 = 5;
+            [x / y, x % y, x + y * 2 % 3]
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            vec![3u32, 2u32, 18u32].into_value_and_type()
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_with_bitwise_ops() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: u32 = 12;
+            let y: u32 = 10;
+            [x & y, x | y, x ^ y, x << 2, x >> 2]
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            vec![8u32, 14u32, 6u32, 48u32, 3u32].into_value_and_type()
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_with_not_equal_to() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: u32 = 1;
+            let y: u32 = 2;
+            x != y && x % 2 != 0
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(result.get_val().unwrap(), true.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_arithmetic_overflow() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: u8 = 200;
+            let y: u8 = 100;
+            x + y
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
+
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::ArithmeticError { .. })
+        ));
+    }
+
+    #[test]
+    async fn test_interpreter_shift_overflow() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: u32 = 1;
+            x << 32
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
+
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::ArithmeticError { .. })
+        ));
+    }
+
+    #[test]
+    async fn test_interpreter_modulo_by_zero() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let x: u32 = 1;
+            let y: u32 = 0;
+            x % y
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
+
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::ArithmeticError { .. })
+        ));
+    }
+
+    #[test]
+    async fn test_interpreter_map_literal_index() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let headers: map<string, string> = {"content-type": "application/json", "accept": "*/*"};
+            headers["accept"]
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap().value,
+            Value::String("*/*".to_string())
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_map_missing_key() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let headers: map<string, string> = {"accept": "*/*"};
+            headers["content-type"]
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
 
-        let compiled = compiler.compile(expr).unwrap();
+        assert!(matches!(result, Err(RibRuntimeError::KeyNotFound { .. })));
+    }
+
+    #[test]
+    async fn test_interpreter_map_built_in_functions() {
+        let mut interpreter = Interpreter::default();
+
+        let rib_expr = r#"
+            let query: map<string, u32> = {"limit": 10, "offset": 20};
+            let keys = string.join(map.keys(query), ",");
+            let limited = map.contains-key(query, "limit");
+            let page = option.unwrap-or(map.get(query, "page"), 1);
+            let total = list.length(map.values(query));
+            "${keys} ${limited} ${page} ${total}"
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
         let result = interpreter.run(compiled.byte_code).await.unwrap();
-        let expected_value = Value::Record(vec![3u32.into_value(), 7u64.into_value()]);
 
-        let expected_type = record(vec![field("res1", u32()), field("res2", u64())]);
         assert_eq!(
-            result,
-            RibResult::Val(ValueAndType::new(expected_value, expected_type))
+            result.get_val().unwrap().value,
+            Value::String("limit,offset true 1 2".to_string())
         );
     }
 
     #[test]
-    async fn test_interpreter_list_reduce() {
+    async fn test_interpreter_map_from_list_of_tuples() {
         let mut interpreter = Interpreter::default();
 
         let rib_expr = r#"
-          let x: list<u8> = [1, 2];
-
-          reduce z, a in x from 0u8 {
-            yield z + a;
-          }
-
-          "#;
+            let entries: list<tuple<string, u32>> = [("foo", 1), ("bar", 2)];
+            let dict: map<string, u32> = entries;
+            dict["bar"]
+        "#;
 
         let expr = Expr::from_text(rib_expr).unwrap();
-        let compiler = RibCompiler::default();
-        let compiled = compiler.compile(expr).unwrap();
-        let result = interpreter
-            .run(compiled.byte_code)
-            .await
-            .unwrap()
-            .get_val()
-            .unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
 
-        assert_eq!(result, 3u8.into_value_and_type());
+        assert_eq!(result.get_val().unwrap(), 2u32.into_value_and_type());
     }
 
     #[test]
-    async fn test_interpreter_list_reduce_from_record() {
+    async fn test_interpreter_propagate_ok() {
         let mut interpreter = Interpreter::default();
 
         let rib_expr = r#"
-           let x = [{name: "foo", age: 1u64}, {name: "bar", age: 2u64}];
+            let x: result<u32, string> = ok(1);
+            let y = x?;
+            ok(y + 1): result<u32, string>
+        "#;
 
-           let names = for i in x {
-             yield i.name;
-           };
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
 
-          reduce z, a in names from "" {
-            let result = if z == "" then a else "${z}, ${a}";
+        assert_eq!(
+            result.get_val().unwrap().value,
+            Value::Result(Ok(Some(Box::new(Value::U32(2)))))
+        );
+    }
 
-            yield result;
-          }
+    #[test]
+    async fn test_interpreter_propagate_err() {
+        let mut interpreter = Interpreter::default();
 
-          "#;
+        let rib_expr = r#"
+            let x: result<u32, string> = err("failed");
+            let y = x?;
+            ok(y + 1): result<u32, string>
+        "#;
 
         let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
 
-        let compiler = RibCompiler::default();
-        let compiled = compiler.compile(expr).unwrap();
-
-        let result = interpreter
-            .run(compiled.byte_code)
-            .await
-            .unwrap()
-            .get_val()
-            .unwrap();
-
-        assert_eq!(result, "foo, bar".into_value_and_type());
+        assert_eq!(
+            result.get_val().unwrap().value,
+            Value::Result(Err(Some(Box::new(Value::String("failed".to_string())))))
+        );
     }
 
     #[test]
-    async fn test_interpreter_list_reduce_text() {
+    async fn test_interpreter_propagate_none() {
         let mut interpreter = Interpreter::default();
 
         let rib_expr = r#"
-           let x = ["foo", "bar"];
+            let x: option<u32> = none;
+            let y = x?;
+            some(y + 1): option<u32>
+        "#;
 
-          reduce z, a in x from "" {
-            let result = if z == "" then a else "${z}, ${a}";
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
 
-            yield result;
-          }
+        assert_eq!(result.get_val().unwrap().value, Value::Option(None));
+    }
 
-          "#;
+    #[test]
+    async fn test_interpreter_propagate_invalid_return_type() {
+        let rib_expr = r#"
+            let x: result<u32, string> = ok(1);
+            x? + 1
+        "#;
 
         let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr);
 
-        let compiler = RibCompiler::default();
+        assert!(compiled.is_err());
+    }
 
-        let compiled = compiler.compile(expr).unwrap();
+    #[test]
+    async fn test_interpreter_try_catch_runtime_error() {
+        let mut interpreter = Interpreter::default();
 
-        let result = interpreter
-            .run(compiled.byte_code)
-            .await
-            .unwrap()
-            .get_val()
-            .unwrap();
+        let rib_expr = r#"
+            let headers: map<string, string> = {"accept": "*/*"};
+            try {
+              headers["content-type"]
+            } catch e {
+              match e {
+                runtime-error(message) => message,
+                _ => "unknown"
+              }
+            }
+        "#;
 
-        assert_eq!(result, "foo, bar".into_value_and_type());
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        let Value::String(message) = result.get_val().unwrap().value else {
+            panic!("expected a string");
+        };
+
+        assert!(message.starts_with("key not found"));
     }
 
     #[test]
-    async fn test_interpreter_list_reduce_empty() {
+    async fn test_interpreter_try_catch_within_function_call() {
         let mut interpreter = Interpreter::default();
 
         let rib_expr = r#"
-          let x: list<u8> = [];
+            let headers: map<string, string> = {"accept": "*/*"};
 
-          reduce z, a in x from 0u8 {
-            yield z + a;
-          }
+            fn lookup(key: string): string {
+              headers[key]
+            };
 
-          "#;
+            let found = try { lookup("accept") } catch e { "none" };
+            let missing = try { lookup("content-type") } catch e { "none" };
+            "${found} ${missing}"
+        "#;
 
         let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
 
-        let compiler = RibCompiler::default();
+        assert_eq!(
+            result.get_val().unwrap().value,
+            Value::String("*/* none".to_string())
+        );
+    }
 
-        let compiled = compiler.compile(expr).unwrap();
+    #[test]
+    async fn test_interpreter_usage() {
+        let mut interpreter = Interpreter::default();
 
-        let result = interpreter
-            .run(compiled.byte_code)
-            .await
-            .unwrap()
-            .get_val()
-            .unwrap();
+        let rib_expr = r#"
+            let x: list<u32> = [1, 2, 3];
+            for i in x {
+              yield i;
+            }
+        "#;
 
-        assert_eq!(result, 0u8.into_value_and_type());
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let instructions = compiled.byte_code.instructions.len() as u64;
+        interpreter.run(compiled.byte_code).await.unwrap();
+
+        let usage = interpreter.usage();
+
+        // The body of the loop is run once for each element
+        assert!(usage.instructions > instructions);
+        assert_eq!(usage.function_invocations, 0);
+        assert!(usage.max_stack_size >= 3);
     }
 
     #[test]
-    async fn test_interpreter_with_numbers_1() {
-        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
-
-        let mut interpreter = test_utils::interpreter_with_static_function_response(
-            &ValueAndType::new(Value::U64(2), u64()),
-            None,
-        );
+    async fn test_interpreter_instruction_limit() {
+        let mut interpreter = Interpreter::default()
+            .with_limits(RibInterpreterLimits::default().with_max_instructions(100));
 
-        // 1 is automatically inferred to be u32
-        let rib = r#"
-          let worker = instance("my-worker");
-          worker.foo(1)
+        let rib_expr = r#"
+            let range = 1:u32..1000:u32;
+            for i in range {
+              yield i;
+            }
         "#;
 
-        let expr = Expr::from_text(rib).unwrap();
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
 
-        let compiler_config = RibCompilerConfig::new(component_metadata, vec![]);
-        let compiler = RibCompiler::new(compiler_config);
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::InstructionLimitExceeded { limit: 100 })
+        ));
+        assert_eq!(interpreter.usage().instructions, 101);
+    }
 
-        let compiled = compiler.compile(expr).unwrap();
-        let result = interpreter.run(compiled.byte_code).await.unwrap();
+    #[test]
+    async fn test_interpreter_limit_not_caught() {
+        let mut interpreter = Interpreter::default()
+            .with_limits(RibInterpreterLimits::default().with_max_value_size(16));
+
+        let rib_expr = r#"
+            try {
+              let x: list<string> = ["foo", "bar", "baz", "qux", "quux", "corge"];
+              "${x[0]}"
+            } catch e {
+              "caught"
+            }
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
 
         assert_eq!(
-            result.get_val().unwrap(),
-            ValueAndType::new(Value::U64(2), u64())
+            result.unwrap_err().to_string(),
+            "value of size 21 bytes exceeded the size limit of 16 bytes"
         );
     }
 
     #[test]
-    async fn test_interpreter_with_numbers_2() {
-        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
-
-        let mut interpreter = test_utils::interpreter_with_static_function_response(
-            &ValueAndType::new(Value::U64(2), u64()),
-            None,
-        );
+    async fn test_interpreter_stack_limit() {
+        let mut interpreter = Interpreter::default()
+            .with_limits(RibInterpreterLimits::default().with_max_stack_size(4));
 
-        // 1 and 2 are automatically inferred to be u32
-        // since the type of z is inferred to be u32 as that being passed to a function
-        // that expects u32
-        let rib = r#"
-          let worker = instance("my-worker");
-          let z = 1 + 2;
-          worker.foo(z)
+        let rib_expr = r#"
+            let x: list<u32> = [1, 2, 3, 4, 5, 6];
+            x
         "#;
 
-        let expr = Expr::from_text(rib).unwrap();
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
 
-        let compiler_config = RibCompilerConfig::new(component_metadata, vec![]);
-        let compiler = RibCompiler::new(compiler_config);
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::StackLimitExceeded { limit: 4 })
+        ));
+    }
+
+    #[test]
+    async fn test_interpreter_function_invocation_limit() {
+        let test_deps = RibTestDeps::test_deps_with_global_functions();
+
+        let compiler = RibCompiler::new(RibCompilerConfig::new(
+            test_deps.component_dependencies.clone(),
+            vec![],
+        ));
+
+        let mut interpreter = test_deps
+            .interpreter
+            .with_limits(RibInterpreterLimits::default().with_max_function_invocations(1));
+
+        let expr = r#"
+          let a = instance();
+          let result1 = a.add-enum(x, x);
+          let result2 = a.add-enum(y, y);
+          {res1: result1, res2: result2}
+        "#;
 
+        let expr = Expr::from_text(expr).unwrap();
         let compiled = compiler.compile(expr).unwrap();
-        let result = interpreter.run(compiled.byte_code).await.unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
 
         assert_eq!(
-            result.get_val().unwrap(),
-            ValueAndType::new(Value::U64(2), u64())
+            result.unwrap_err().to_string(),
+            "exceeded the limit of 1 function invocations"
         );
+        assert_eq!(interpreter.usage().function_invocations, 2);
     }
 
     #[test]
-    async fn test_interpreter_with_numbers_3() {
-        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
+    async fn test_interpreter_time_limit() {
+        let mut interpreter = Interpreter::default()
+            .with_limits(RibInterpreterLimits::default().with_max_wall_time(Duration::ZERO));
 
-        // This will cause a type inference error
-        // because the operands of the + operator are not of the same type
-        let rib = r#"
-          let worker = instance("my-worker");
-          let z = 1: u8 + 2;
-          worker.foo(z)
-        "#;
+        let expr = Expr::from_text("1: u32").unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await;
 
-        let expr = Expr::from_text(rib).unwrap();
-        let compiler = RibCompiler::new(RibCompilerConfig::new(component_metadata, vec![]));
-        let compile_result = compiler.compile(expr);
-        assert!(compile_result.is_err());
+        assert!(matches!(
+            result,
+            Err(RibRuntimeError::TimeLimitExceeded { .. })
+        ));
     }
 
     #[test]
-    async fn test_interpreter_with_numbers_4() {
-        let component_metadata = test_utils::configurable_metadata("foo", vec![u32()], Some(u64()));
+    async fn test_interpreter_map_to_list_of_tuples() {
+        let mut interpreter = Interpreter::default();
 
-        // This will cause a type inference error
-        // because the operands of the + operator are supposed to be u32
-        // since z is u32
-        let rib = r#"
-          let worker = instance("my-worker");
-          let z = 1: u8 + 2: u8;
-          worker.foo(z)
+        let rib_expr = r#"
+            let dict: map<string, u32> = { "foo": 1, "bar": 2 };
+            let entries: list<tuple<string, u32>> = dict;
+            entries
         "#;
 
-        let expr = Expr::from_text(rib).unwrap();
-        let compiler = RibCompiler::new(RibCompilerConfig::new(component_metadata, vec![]));
-        let compile_result = compiler.compile(expr);
-        assert!(compile_result.is_err());
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let compiled = RibCompiler::default().compile(expr).unwrap();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(
+            result.get_val().unwrap(),
+            vec![("foo".to_string(), 1u32), ("bar".to_string(), 2u32)].into_value_and_type()
+        );
     }
 
     #[test]
@@ -5118,11 +6107,11 @@ mod tests {
             let invoke: Arc<dyn RibComponentFunctionInvoke + Send + Sync> =
                 Arc::new(NoopRibFunctionInvoke);
 
-            Interpreter {
-                input: input.unwrap_or_default(),
+            Interpreter::new(
+                input.unwrap_or_default(),
                 invoke,
-                generate_worker_name: Arc::new(DefaultWorkerNameGenerator),
-            }
+                Arc::new(DefaultWorkerNameGenerator),
+            )
         }
 
         // Interpreter which always returns a specific response
@@ -5134,11 +6123,11 @@ mod tests {
 
             let invoke = Arc::new(TestInvoke1 { value });
 
-            Interpreter {
-                input: input.unwrap_or_default(),
+            Interpreter::new(
+                input.unwrap_or_default(),
                 invoke,
-                generate_worker_name: Arc::new(DefaultWorkerNameGenerator),
-            }
+                Arc::new(DefaultWorkerNameGenerator),
+            )
         }
 
         // The interpreter that always returns a record value consisting of function name, worker name etc
@@ -5152,22 +6141,22 @@ mod tests {
             let invoke: Arc<dyn RibComponentFunctionInvoke + Send + Sync> =
                 Arc::new(ResourceFunctionsInvoke);
 
-            Interpreter {
-                input: rib_input.unwrap_or_default(),
+            Interpreter::new(
+                rib_input.unwrap_or_default(),
                 invoke,
-                generate_worker_name: Arc::new(DefaultWorkerNameGenerator),
-            }
+                Arc::new(DefaultWorkerNameGenerator),
+            )
         }
 
         // A simple interpreter that returns response based on the function
         pub(crate) fn interpreter_for_global_functions(input: Option<RibInput>) -> Interpreter {
             let invoke = Arc::new(TestInvoke3);
 
-            Interpreter {
-                input: input.unwrap_or_default(),
+            Interpreter::new(
+                input.unwrap_or_default(),
                 invoke,
-                generate_worker_name: Arc::new(DefaultWorkerNameGenerator),
-            }
+                Arc::new(DefaultWorkerNameGenerator),
+            )
         }
 
         struct TestInvoke1 {
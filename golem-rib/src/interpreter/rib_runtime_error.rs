@@ -14,14 +14,21 @@
 
 use crate::interpreter::interpreter_stack_value::RibInterpreterStackValue;
 use crate::{InstructionId, TypeHint};
+use golem_wasm_ast::analysis::analysed_type::{case, field, record, str, variant};
+use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::{Value, ValueAndType};
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum RibRuntimeError {
     ArithmeticError {
         message: String,
     },
+    BuiltInFunctionError {
+        function_name: String,
+        message: String,
+    },
     CastError {
         from: CastFrom,
         to: TypeHint,
@@ -42,6 +49,15 @@ pub enum RibRuntimeError {
         message: String,
     },
     InputNotFound(String),
+    InstructionLimitExceeded {
+        limit: u64,
+    },
+    InvocationLimitExceeded {
+        limit: u64,
+    },
+    KeyNotFound {
+        key: String,
+    },
     InvariantViolation(InvariantViolation),
     InvalidComparison {
         message: String,
@@ -49,15 +65,87 @@ pub enum RibRuntimeError {
         right: Option<ValueAndType>,
     },
     NoResult,
+    StackLimitExceeded {
+        limit: usize,
+    },
     ThrownError(String),
+    TimeLimitExceeded {
+        limit: Duration,
+    },
     TypeMismatch {
         expected: Vec<TypeHint>,
         found: InvalidItem,
     },
+    ValueSizeLimitExceeded {
+        size: usize,
+        limit: usize,
+    },
 }
 
 impl std::error::Error for RibRuntimeError {}
 
+impl RibRuntimeError {
+    // The type of the error bound to the variable of a `catch` block.
+    // Example: `try { x.checkout() } catch e { match e { function-invoke-error(err) => err.message, _ => "failed" } }`
+    pub fn error_type() -> AnalysedType {
+        variant(vec![
+            case(
+                "function-invoke-error",
+                record(vec![field("function-name", str()), field("message", str())]),
+            ),
+            case("thrown", str()),
+            case("runtime-error", str()),
+        ])
+        .named("rib-error")
+    }
+
+    // The value of the error that is bound to the variable of a `catch` block.
+    // A violation of an invariant implies a corrupted state of the interpreter, and is never caught.
+    // Neither is exceeding a resource limit, as the script would otherwise be able to recover and go on.
+    pub fn to_error_value(&self) -> Option<ValueAndType> {
+        if self.is_limit_exceeded() {
+            return None;
+        }
+
+        let (case_idx, case_value) = match self {
+            RibRuntimeError::InvariantViolation(_) => return None,
+            RibRuntimeError::FunctionInvokeError {
+                function_name,
+                error,
+            } => (
+                0,
+                Value::Record(vec![
+                    Value::String(function_name.clone()),
+                    Value::String(error.to_string()),
+                ]),
+            ),
+            RibRuntimeError::ThrownError(message) => (1, Value::String(message.clone())),
+            error => (2, Value::String(error.to_string())),
+        };
+
+        Some(ValueAndType::new(
+            Value::Variant {
+                case_idx,
+                case_value: Some(Box::new(case_value)),
+            },
+            RibRuntimeError::error_type(),
+        ))
+    }
+}
+
+impl RibRuntimeError {
+    pub fn is_limit_exceeded(&self) -> bool {
+        matches!(
+            self,
+            RibRuntimeError::InstructionLimitExceeded { .. }
+                | RibRuntimeError::InvocationLimitExceeded { .. }
+                | RibRuntimeError::StackLimitExceeded { .. }
+                | RibRuntimeError::TimeLimitExceeded { .. }
+                | RibRuntimeError::ValueSizeLimitExceeded { .. }
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CastFrom {
     FromValue(Value),
@@ -88,6 +176,13 @@ pub fn arithmetic_error(message: &str) -> RibRuntimeError {
     }
 }
 
+pub fn built_in_function_error(function_name: &str, message: &str) -> RibRuntimeError {
+    RibRuntimeError::BuiltInFunctionError {
+        function_name: function_name.to_string(),
+        message: message.to_string(),
+    }
+}
+
 pub fn cast_error(from: Value, to: TypeHint) -> RibRuntimeError {
     RibRuntimeError::CastError {
         from: CastFrom::FromValue(from),
@@ -126,6 +221,12 @@ pub fn function_invoke_fail(
     }
 }
 
+pub fn key_not_found(key: &Value) -> RibRuntimeError {
+    RibRuntimeError::KeyNotFound {
+        key: format!("{key:?}"),
+    }
+}
+
 pub fn index_out_of_bound(index: usize, size: usize) -> RibRuntimeError {
     RibRuntimeError::IndexOutOfBound { index, size }
 }
@@ -209,6 +310,9 @@ impl Display for RibRuntimeError {
             RibRuntimeError::FieldNotFound { field } => {
                 write!(f, "field not found: {field}")
             }
+            RibRuntimeError::KeyNotFound { key } => {
+                write!(f, "key not found: {key}")
+            }
             RibRuntimeError::InvariantViolation(violation) => {
                 write!(f, "internal error: {violation:?}")
             }
@@ -247,12 +351,36 @@ impl Display for RibRuntimeError {
             RibRuntimeError::ArithmeticError { message } => {
                 write!(f, "arithmetic error: {message}")
             }
+            RibRuntimeError::BuiltInFunctionError {
+                function_name,
+                message,
+            } => {
+                write!(f, "built-in function {function_name} failed: {message}")
+            }
             RibRuntimeError::FunctionInvokeError {
                 function_name,
                 error,
             } => {
                 write!(f, "failed to invoke function {function_name}: {error}")
             }
+            RibRuntimeError::InstructionLimitExceeded { limit } => {
+                write!(f, "exceeded the limit of {limit} instructions")
+            }
+            RibRuntimeError::InvocationLimitExceeded { limit } => {
+                write!(f, "exceeded the limit of {limit} function invocations")
+            }
+            RibRuntimeError::StackLimitExceeded { limit } => {
+                write!(f, "exceeded the stack size limit of {limit}")
+            }
+            RibRuntimeError::TimeLimitExceeded { limit } => {
+                write!(f, "exceeded the time limit of {} ms", limit.as_millis())
+            }
+            RibRuntimeError::ValueSizeLimitExceeded { size, limit } => {
+                write!(
+                    f,
+                    "value of size {size} bytes exceeded the size limit of {limit} bytes"
+                )
+            }
         }
     }
 }
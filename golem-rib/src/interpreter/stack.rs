@@ -16,7 +16,9 @@ use crate::interpreter::interpreter_stack_value::RibInterpreterStackValue;
 use crate::interpreter::rib_runtime_error::{
     empty_stack, insufficient_stack_items, type_mismatch_with_value,
 };
-use crate::{internal_corrupted_state, GetLiteralValue, RibInterpreterResult, TypeHint};
+use crate::{
+    internal_corrupted_state, GetLiteralValue, InstructionId, RibInterpreterResult, TypeHint,
+};
 use golem_wasm_ast::analysis::analysed_type::{list, option, record, str, tuple, variant};
 use golem_wasm_ast::analysis::{
     AnalysedType, NameOptionTypePair, NameTypePair, TypeEnum, TypeRecord, TypeResult,
@@ -26,6 +28,24 @@ use golem_wasm_rpc::{Value, ValueAndType};
 #[derive(Debug)]
 pub struct InterpreterStack {
     pub stack: Vec<RibInterpreterStackValue>,
+    pub call_frames: Vec<CallFrame>,
+    pub error_handlers: Vec<ErrorHandler>,
+}
+
+// A call frame is pushed on every call to a lambda defined in the Rib script,
+// and holds the position of the instruction to resume from once the call returns
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallFrame {
+    pub return_position: InstructionId,
+}
+
+// An error handler is pushed on entering a try block, and holds the label of the catch block,
+// along with the size of the stack and the call depth to unwind to when an error is caught
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorHandler {
+    pub catch_label: InstructionId,
+    pub stack_size: usize,
+    pub call_depth: usize,
 }
 
 impl Default for InterpreterStack {
@@ -36,7 +56,37 @@ impl Default for InterpreterStack {
 
 impl InterpreterStack {
     pub fn new() -> Self {
-        InterpreterStack { stack: Vec::new() }
+        InterpreterStack {
+            stack: Vec::new(),
+            call_frames: Vec::new(),
+            error_handlers: Vec::new(),
+        }
+    }
+
+    pub fn push_call_frame(&mut self, return_position: InstructionId) {
+        self.call_frames.push(CallFrame { return_position });
+    }
+
+    pub fn pop_call_frame(&mut self) -> Option<CallFrame> {
+        self.call_frames.pop()
+    }
+
+    pub fn push_error_handler(&mut self, catch_label: InstructionId) {
+        self.error_handlers.push(ErrorHandler {
+            catch_label,
+            stack_size: self.stack.len(),
+            call_depth: self.call_frames.len(),
+        });
+    }
+
+    pub fn pop_error_handler(&mut self) -> Option<ErrorHandler> {
+        self.error_handlers.pop()
+    }
+
+    // Discards the values and the call frames pushed after the error handler
+    pub fn unwind_to(&mut self, error_handler: &ErrorHandler) {
+        self.stack.truncate(error_handler.stack_size);
+        self.call_frames.truncate(error_handler.call_depth);
     }
 
     // Initialise a record in the stack
@@ -14,6 +14,8 @@
 
 #![allow(clippy::large_enum_variant)]
 #![allow(clippy::result_large_err)]
+// The combine parsers of Rib nest deeply enough to exceed the default limit when monomorphized
+#![recursion_limit = "256"]
 
 pub use call_type::*;
 pub use compiler::*;
@@ -24,6 +26,7 @@ pub use instance_type::*;
 pub use interpreter::*;
 pub use parser::type_name::TypeName;
 pub use registry::*;
+pub use rib_source_span::{SourcePosition, SourceSpan};
 pub use text::*;
 pub use type_checker::*;
 pub use type_inference::*;
@@ -29,14 +29,21 @@ where
         attempt(string(">=")).map(|_| BinaryOp::GreaterThanOrEqualTo),
         attempt(string("<=")).map(|_| BinaryOp::LessThanOrEqualTo),
         attempt(string("==")).map(|_| BinaryOp::EqualTo),
+        attempt(string("!=")).map(|_| BinaryOp::NotEqualTo),
+        attempt(string("<<")).map(|_| BinaryOp::ShiftLeft),
+        attempt(string(">>")).map(|_| BinaryOp::ShiftRight),
         string("<").map(|_| BinaryOp::LessThan),
         string(">").map(|_| BinaryOp::GreaterThan),
-        string("&&").map(|_| BinaryOp::And),
-        string("||").map(|_| BinaryOp::Or),
+        attempt(string("&&")).map(|_| BinaryOp::And),
+        attempt(string("||")).map(|_| BinaryOp::Or),
+        string("&").map(|_| BinaryOp::BitwiseAnd),
+        string("|").map(|_| BinaryOp::BitwiseOr),
+        string("^").map(|_| BinaryOp::BitwiseXor),
         string("+").map(|_| BinaryOp::Add),
         string("-").map(|_| BinaryOp::Subtract),
         string("*").map(|_| BinaryOp::Multiply),
         string("/").map(|_| BinaryOp::Divide),
+        string("%").map(|_| BinaryOp::Modulo),
     ))
 }
 
@@ -47,12 +54,41 @@ pub enum BinaryOp {
     LessThanOrEqualTo,
     GreaterThanOrEqualTo,
     EqualTo,
+    NotEqualTo,
     And,
     Or,
     Add,
     Subtract,
     Multiply,
     Divide,
+    Modulo,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BinaryOp {
+    // Higher binds tighter. All binary operators are left associative.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Or => 1,
+            BinaryOp::And => 2,
+            BinaryOp::GreaterThan
+            | BinaryOp::LessThan
+            | BinaryOp::LessThanOrEqualTo
+            | BinaryOp::GreaterThanOrEqualTo
+            | BinaryOp::EqualTo
+            | BinaryOp::NotEqualTo => 3,
+            BinaryOp::BitwiseOr => 4,
+            BinaryOp::BitwiseXor => 5,
+            BinaryOp::BitwiseAnd => 6,
+            BinaryOp::ShiftLeft | BinaryOp::ShiftRight => 7,
+            BinaryOp::Add | BinaryOp::Subtract => 8,
+            BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Modulo => 9,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -345,4 +381,109 @@ mod test {
             ]))
         );
     }
+
+    #[test]
+    fn test_not_equal_to() {
+        let input = "foo != bar";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::not_equal_to(
+                Expr::identifier_global("foo", None),
+                Expr::identifier_global("bar", None)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_modulo() {
+        let input = "foo % 4";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::modulo(
+                Expr::identifier_global("foo", None),
+                Expr::number(BigDecimal::from(4))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_bitwise_ops() {
+        let input = "foo & bar | baz ^ qux";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::bitwise_or(
+                Expr::bitwise_and(
+                    Expr::identifier_global("foo", None),
+                    Expr::identifier_global("bar", None)
+                ),
+                Expr::bitwise_xor(
+                    Expr::identifier_global("baz", None),
+                    Expr::identifier_global("qux", None)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_shift_ops() {
+        let input = "foo << 2 >> bar";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::shift_right(
+                Expr::shift_left(
+                    Expr::identifier_global("foo", None),
+                    Expr::number(BigDecimal::from(2))
+                ),
+                Expr::identifier_global("bar", None)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        let input = "foo + bar * baz - qux % 2";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::minus(
+                Expr::plus(
+                    Expr::identifier_global("foo", None),
+                    Expr::multiply(
+                        Expr::identifier_global("bar", None),
+                        Expr::identifier_global("baz", None)
+                    )
+                ),
+                Expr::modulo(
+                    Expr::identifier_global("qux", None),
+                    Expr::number(BigDecimal::from(2))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_logical_precedence() {
+        let input = "foo > 1 && bar != baz || qux";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::or(
+                Expr::and(
+                    Expr::greater_than(
+                        Expr::identifier_global("foo", None),
+                        Expr::number(BigDecimal::from(1))
+                    ),
+                    Expr::not_equal_to(
+                        Expr::identifier_global("bar", None),
+                        Expr::identifier_global("baz", None)
+                    )
+                ),
+                Expr::identifier_global("qux", None)
+            ))
+        );
+    }
 }
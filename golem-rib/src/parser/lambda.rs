@@ -0,0 +1,192 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::expr::{Expr, LambdaParameter};
+use crate::parser::block::block;
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+use crate::parser::rib_expr::rib_expr;
+use crate::parser::type_name::type_name;
+use crate::rib_source_span::GetSourcePosition;
+use combine::parser::char::{alpha_num, char, spaces, string};
+use combine::{attempt, between, not_followed_by, optional, sep_by, sep_by1, ParseError, Parser};
+
+// fn add(x: u32, y: u32): u32 { x + y }
+pub fn function_definition<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    attempt(
+        string("fn").skip(not_followed_by(alpha_num().or(char('-')).or(char('_'))).skip(spaces())),
+    )
+    .with(
+        (
+            identifier_text()
+                .message("Unable to parse function name")
+                .skip(spaces()),
+            between(
+                char('(').skip(spaces()),
+                char(')').skip(spaces()),
+                sep_by(lambda_parameter(), char(',').skip(spaces())),
+            ),
+            optional(char(':').skip(spaces()).with(type_name()).skip(spaces())),
+            between(char('{').skip(spaces()), char('}'), block().skip(spaces())),
+        )
+            .map(|(name, parameters, return_type, body)| {
+                Expr::function_definition(name, parameters, return_type, body)
+            }),
+    )
+}
+
+// |x, y: u32| x + y
+pub fn lambda<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    (
+        between(
+            char('|').skip(spaces()),
+            char('|').skip(spaces()),
+            sep_by1(lambda_parameter(), char(',').skip(spaces())),
+        ),
+        rib_expr(),
+    )
+        .map(|(parameters, body)| Expr::lambda(parameters, None, body))
+}
+
+fn lambda_parameter<Input>() -> impl Parser<Input, Output = LambdaParameter>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    (
+        identifier_text()
+            .message("Unable to parse function parameter")
+            .skip(spaces()),
+        optional(char(':').skip(spaces()).with(type_name()).skip(spaces())),
+    )
+        .map(|(name, type_annotation)| LambdaParameter::new(name, type_annotation))
+}
+
+#[cfg(test)]
+mod tests {
+    use bigdecimal::BigDecimal;
+    use test_r::test;
+
+    use crate::expr::{Expr, LambdaParameter};
+    use crate::parser::type_name::TypeName;
+
+    #[test]
+    fn test_function_definition() {
+        let input = "fn add(x: u32, y: u32): u32 { x + y }";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::function_definition(
+                "add",
+                vec![
+                    LambdaParameter::new("x", Some(TypeName::U32)),
+                    LambdaParameter::new("y", Some(TypeName::U32))
+                ],
+                Some(TypeName::U32),
+                Expr::plus(
+                    Expr::identifier_global("x", None),
+                    Expr::identifier_global("y", None)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_function_definition_with_block() {
+        let input = r#"
+          fn greet(name) {
+            let greeting = "hello";
+            "${greeting} ${name}"
+          };
+          greet("foo")
+        "#;
+
+        let result = Expr::from_text(input).unwrap();
+
+        let Expr::ExprBlock { exprs, .. } = result else {
+            panic!("expected an expression block");
+        };
+
+        assert_eq!(exprs.len(), 2);
+        assert!(matches!(&exprs[0], Expr::Let { expr, .. } if expr.is_lambda()));
+    }
+
+    #[test]
+    fn test_lambda() {
+        let input = "|x, y: u32| x * y";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::lambda(
+                vec![
+                    LambdaParameter::new("x", None),
+                    LambdaParameter::new("y", Some(TypeName::U32))
+                ],
+                None,
+                Expr::multiply(
+                    Expr::identifier_global("x", None),
+                    Expr::identifier_global("y", None)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lambda_as_argument() {
+        let input = "list.map(xs, |x| x + 1)";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::invoke_worker_function(
+                Expr::identifier_global("list", None),
+                "map".to_string(),
+                None,
+                vec![
+                    Expr::identifier_global("xs", None),
+                    Expr::lambda(
+                        vec![LambdaParameter::new("x", None)],
+                        None,
+                        Expr::plus(
+                            Expr::identifier_global("x", None),
+                            Expr::number(BigDecimal::from(1))
+                        )
+                    )
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_fn_is_a_keyword() {
+        let result = Expr::from_text("let fn = 1");
+        assert!(result.is_err());
+    }
+}
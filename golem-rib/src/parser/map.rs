@@ -0,0 +1,217 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::rib_expr::rib_expr;
+use crate::expr::Expr;
+use crate::parser::boolean::boolean_literal;
+use crate::parser::errors::RibParseError;
+use crate::parser::integer::integer;
+use crate::parser::literal::literal;
+use crate::rib_source_span::{GetSourcePosition, SourceSpan};
+use combine::{
+    between, choice, parser,
+    parser::char::{char as char_, spaces},
+    position, sep_by1, ParseError, Parser, Stream,
+};
+
+// A map literal such as `{"content-type": "application/json", "accept": "*/*"}`.
+// Unlike the keys of a record, which are identifiers, the keys of a map are
+// string literals (possibly interpolated), numbers or booleans.
+parser! {
+    pub fn map[Input]()(Input) -> Expr
+    where [
+        Input: Stream<Token = char>,
+        RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,
+        Input::Position: GetSourcePosition
+    ]
+    {
+       map_()
+    }
+}
+
+pub fn map_<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    between(
+        char_('{').skip(spaces().silent()),
+        char_('}').skip(spaces().silent()),
+        sep_by1(
+            entry().skip(spaces().silent()),
+            char_(',').skip(spaces().silent()),
+        ),
+    )
+    .and_then(|entries: Vec<(Expr, Expr)>| {
+        let duplicate_keys = find_duplicate_keys(&entries);
+
+        if !duplicate_keys.is_empty() {
+            Err(RibParseError::Message(format!(
+                "duplicate keys found in map: {}",
+                duplicate_keys.join(", ")
+            )))
+        } else {
+            Ok(Expr::map(entries, None))
+        }
+    })
+}
+
+fn find_duplicate_keys(entries: &[(Expr, Expr)]) -> Vec<String> {
+    let mut keys = std::collections::HashSet::new();
+    let mut duplicates = vec![];
+
+    for (key, _) in entries {
+        let key = key.to_string();
+
+        if !keys.insert(key.clone()) {
+            duplicates.push(key);
+        }
+    }
+
+    duplicates
+}
+
+fn entry_key<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    choice((literal(), boolean_literal(), integer()))
+}
+
+fn entry<Input>() -> impl Parser<Input, Output = (Expr, Expr)>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    (
+        position(),
+        entry_key(),
+        position(),
+        spaces().silent(),
+        char_(':').skip(spaces().silent()),
+        position(),
+        rib_expr(),
+        position(),
+    )
+        .map(
+            |(key_start, key, key_end, _, _, value_start, value, value_end)| {
+                let key_span = span::<Input>(key_start, key_end);
+                let value_span = span::<Input>(value_start, value_end);
+
+                (
+                    key.with_source_span(key_span),
+                    value.with_source_span(value_span),
+                )
+            },
+        )
+}
+
+fn span<Input>(start: Input::Position, end: Input::Position) -> SourceSpan
+where
+    Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
+{
+    SourceSpan::new(start.get_source_position(), end.get_source_position())
+}
+
+#[cfg(test)]
+mod tests {
+    use bigdecimal::BigDecimal;
+    use test_r::test;
+
+    use super::*;
+
+    #[test]
+    fn test_map_with_string_keys() {
+        let input = r#"{"content-type": "application/json", "accept": foo}"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::map(
+                vec![
+                    (
+                        Expr::literal("content-type"),
+                        Expr::literal("application/json")
+                    ),
+                    (
+                        Expr::literal("accept"),
+                        Expr::identifier_global("foo", None)
+                    )
+                ],
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_with_number_keys() {
+        let input = r#"{1: "one", 2: "two"}"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::map(
+                vec![
+                    (Expr::number(BigDecimal::from(1)), Expr::literal("one")),
+                    (Expr::number(BigDecimal::from(2)), Expr::literal("two"))
+                ],
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_with_type_annotation() {
+        let input = r#"{"foo": 1}: map<string, u32>"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::map(
+                vec![(Expr::literal("foo"), Expr::number(BigDecimal::from(1)))],
+                Some(crate::TypeName::Map {
+                    key: Box::new(crate::TypeName::Str),
+                    value: Box::new(crate::TypeName::U32),
+                })
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_with_duplicate_keys() {
+        let input = r#"{"foo": 1, "foo": 2}"#;
+        let result = Expr::from_text(input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_record_is_not_a_map() {
+        let input = r#"{foo: "bar"}"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::record(vec![(
+                "foo".to_string(),
+                Expr::literal("bar")
+            )]))
+        );
+    }
+}
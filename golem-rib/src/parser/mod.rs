@@ -27,10 +27,12 @@ mod flag;
 mod generic_type_parameter;
 mod identifier;
 mod integer;
+mod lambda;
 mod let_binding;
 mod list_aggregation;
 mod list_comprehension;
 pub(crate) mod literal;
+mod map;
 mod multi_line_code_block;
 mod not;
 mod optional;
@@ -40,5 +42,6 @@ mod record;
 mod result;
 pub(crate) mod rib_expr;
 mod sequence;
+mod try_catch;
 mod tuple;
 pub(crate) mod type_name;
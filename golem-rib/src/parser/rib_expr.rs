@@ -22,10 +22,12 @@ use crate::parser::errors::RibParseError;
 use crate::parser::flag::flag;
 use crate::parser::identifier::identifier;
 use crate::parser::integer::integer;
+use crate::parser::lambda::{function_definition, lambda};
 use crate::parser::let_binding::let_binding;
 use crate::parser::list_aggregation::list_aggregation;
 use crate::parser::list_comprehension::list_comprehension;
 use crate::parser::literal::literal;
+use crate::parser::map::map;
 use crate::parser::multi_line_code_block::multi_line_block;
 use crate::parser::not::not;
 use crate::parser::optional::option;
@@ -34,6 +36,7 @@ use crate::parser::range_type::{range_type, RangeType};
 use crate::parser::record::record;
 use crate::parser::result::result;
 use crate::parser::sequence::sequence;
+use crate::parser::try_catch::try_catch;
 use crate::parser::tuple::tuple;
 use crate::parser::type_name::type_name;
 use crate::rib_source_span::{GetSourcePosition, SourceSpan};
@@ -45,7 +48,8 @@ use combine::{attempt, choice, many, many1, optional, parser, position, Stream};
 use combine::{ParseError, Parser};
 use std::str::FromStr;
 
-// A rib expression := (simple_expr, rib_expr_rest*)
+// A rib expression := (operand, (binary_op, operand)*)
+// An operand := (simple_expr, rib_expr_rest*, `?`*)
 // A simple_expr never has any expression that starts with rib_expression
 // (ex: select_field, select_index, +, -, *,/, etc)
 parser! {
@@ -67,28 +71,60 @@ where
     spaces()
         .silent()
         .with(
-            (simple_expr(), rib_expr_rest()).and_then(|(expr, rest): (Expr, RibRest)| {
-                let with_index = fold_with_index_exprs(expr, rest.indices);
-
-                let with_selections = fold_with_selections_or_fractions(
-                    with_index,
-                    rest.selection_exprs_or_fraction,
-                )?;
-
-                let with_range = match rest.range_info {
-                    Some(range_info) => {
-                        match combine_with_range_info(with_selections, range_info) {
-                            Ok(expr) => expr,
-                            // Explicit pattern match to help with type inference for combine
-                            Err(e) => return Err(e),
-                        }
+            (operand(), many((binary_op().skip(spaces()), operand()))).map(
+                |(expr, binary_ops): (Expr, Vec<(BinaryOp, Expr)>)| {
+                    fold_with_binary_ops(expr, binary_ops)
+                },
+            ),
+        )
+        .skip(spaces().silent())
+}
+
+parser! {
+    fn operand[Input]()(Input) -> Expr
+    where [Input: Stream<Token = char>, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>, Input::Position: GetSourcePosition]
+    {
+        with_position(operand_())
+    }
+}
+
+// An operand of a binary operation := (simple_expr, rib_expr_rest, `?`*)
+fn operand_<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    (
+        simple_expr(),
+        rib_expr_rest(),
+        many(char('?').skip(spaces())),
+    )
+        .and_then(|(expr, rest, propagations): (Expr, RibRest, Vec<char>)| {
+            let with_index = fold_with_index_exprs(expr, rest.indices);
+
+            let with_selections =
+                fold_with_selections_or_fractions(with_index, rest.selection_exprs_or_fraction)?;
+
+            let with_range = match rest.range_info {
+                Some(range_info) => {
+                    match combine_with_range_info(with_selections, range_info) {
+                        Ok(expr) => expr,
+                        // Explicit pattern match to help with type inference for combine
+                        Err(e) => return Err(e),
                     }
-                    None => with_selections,
-                };
+                }
+                None => with_selections,
+            };
 
-                Ok(fold_with_binary_ops(with_range, rest.binary_ops))
-            }),
-        )
+            let with_propagations = propagations
+                .into_iter()
+                .fold(with_range, |expr, _| Expr::propagate(expr));
+
+            Ok(with_propagations)
+        })
         .skip(spaces().silent())
 }
 
@@ -115,6 +151,9 @@ where
                 list_comprehension(),
                 list_aggregation(),
                 pattern_match(),
+                function_definition(),
+                lambda(),
+                try_catch(),
                 let_binding(),
                 conditional(),
                 attempt(multi_line_block()),
@@ -168,7 +207,7 @@ where
     >,
     Input::Position: GetSourcePosition,
 {
-    choice((attempt(flag()), record()))
+    choice((attempt(flag()), attempt(map()), record()))
 }
 
 // A rib rest always a start with a proper delimiter (ex: ., [, etc)
@@ -182,7 +221,6 @@ struct RibRest {
     indices: IndexExprs,
     selection_exprs_or_fraction: Vec<WithIndex<SelectionOrFraction>>,
     range_info: Option<WithIndex<RangeInfo>>,
-    binary_ops: Vec<(BinaryOp, WithIndex<Expr>)>,
 }
 
 #[derive(Debug, Clone)]
@@ -255,34 +293,12 @@ where
                     })
                     .skip(spaces()),
             ),
-            many((
-                binary_op(),
-                (rib_expr(), select_index_expression().skip(spaces())),
-            ))
-            .map(|binary_math: Vec<(BinaryOp, (Expr, IndexExprs))>| {
-                binary_math
-                    .into_iter()
-                    .map(|(op, (expr, index_expr))| {
-                        (
-                            op,
-                            WithIndex {
-                                index_expr,
-                                base: expr,
-                                type_name: None,
-                            },
-                        )
-                    })
-                    .collect::<Vec<_>>()
-            }),
         )
-            .map(
-                |(indices, field_selection, range_info, binary_ops)| RibRest {
-                    indices,
-                    selection_exprs_or_fraction: field_selection,
-                    range_info,
-                    binary_ops,
-                },
-            ),
+            .map(|(indices, field_selection, range_info)| RibRest {
+                indices,
+                selection_exprs_or_fraction: field_selection,
+                range_info,
+            }),
     )
 }
 
@@ -441,24 +457,70 @@ where
         )
 }
 
-fn fold_with_binary_ops(base: Expr, binary_ops: Vec<(BinaryOp, WithIndex<Expr>)>) -> Expr {
-    binary_ops.into_iter().fold(base, |acc, (op, next)| {
-        let next = fold_with_index_exprs(next.base, next.index_expr);
-
-        match op {
-            BinaryOp::GreaterThan => Expr::greater_than(acc, next),
-            BinaryOp::LessThan => Expr::less_than(acc, next),
-            BinaryOp::LessThanOrEqualTo => Expr::less_than_or_equal_to(acc, next),
-            BinaryOp::GreaterThanOrEqualTo => Expr::greater_than_or_equal_to(acc, next),
-            BinaryOp::EqualTo => Expr::equal_to(acc, next),
-            BinaryOp::And => Expr::and(acc, next),
-            BinaryOp::Or => Expr::or(acc, next),
-            BinaryOp::Add => Expr::plus(acc, next),
-            BinaryOp::Subtract => Expr::minus(acc, next),
-            BinaryOp::Multiply => Expr::multiply(acc, next),
-            BinaryOp::Divide => Expr::divide(acc, next),
+// Operator precedence parsing over the flat sequence of operands and operators
+fn fold_with_binary_ops(base: Expr, binary_ops: Vec<(BinaryOp, Expr)>) -> Expr {
+    let mut binary_ops = binary_ops.into_iter().peekable();
+    fold_with_precedence(base, &mut binary_ops, 0)
+}
+
+fn fold_with_precedence(
+    lhs: Expr,
+    binary_ops: &mut std::iter::Peekable<std::vec::IntoIter<(BinaryOp, Expr)>>,
+    min_precedence: u8,
+) -> Expr {
+    let mut lhs = lhs;
+
+    while let Some((op, _)) = binary_ops.peek() {
+        let precedence = op.precedence();
+
+        if precedence < min_precedence {
+            break;
         }
-    })
+
+        let Some((op, mut rhs)) = binary_ops.next() else {
+            break;
+        };
+
+        while let Some((next_op, _)) = binary_ops.peek() {
+            if next_op.precedence() > precedence {
+                rhs = fold_with_precedence(rhs, binary_ops, precedence + 1);
+            } else {
+                break;
+            }
+        }
+
+        lhs = build_binary_op(op, lhs, rhs);
+    }
+
+    lhs
+}
+
+fn build_binary_op(op: BinaryOp, lhs: Expr, rhs: Expr) -> Expr {
+    let source_span = lhs.source_span().merge(rhs.source_span());
+
+    let mut expr = match op {
+        BinaryOp::GreaterThan => Expr::greater_than(lhs, rhs),
+        BinaryOp::LessThan => Expr::less_than(lhs, rhs),
+        BinaryOp::LessThanOrEqualTo => Expr::less_than_or_equal_to(lhs, rhs),
+        BinaryOp::GreaterThanOrEqualTo => Expr::greater_than_or_equal_to(lhs, rhs),
+        BinaryOp::EqualTo => Expr::equal_to(lhs, rhs),
+        BinaryOp::NotEqualTo => Expr::not_equal_to(lhs, rhs),
+        BinaryOp::And => Expr::and(lhs, rhs),
+        BinaryOp::Or => Expr::or(lhs, rhs),
+        BinaryOp::Add => Expr::plus(lhs, rhs),
+        BinaryOp::Subtract => Expr::minus(lhs, rhs),
+        BinaryOp::Multiply => Expr::multiply(lhs, rhs),
+        BinaryOp::Divide => Expr::divide(lhs, rhs),
+        BinaryOp::Modulo => Expr::modulo(lhs, rhs),
+        BinaryOp::BitwiseAnd => Expr::bitwise_and(lhs, rhs),
+        BinaryOp::BitwiseOr => Expr::bitwise_or(lhs, rhs),
+        BinaryOp::BitwiseXor => Expr::bitwise_xor(lhs, rhs),
+        BinaryOp::ShiftLeft => Expr::shift_left(lhs, rhs),
+        BinaryOp::ShiftRight => Expr::shift_right(lhs, rhs),
+    };
+
+    expr.with_source_span_mut(source_span);
+    expr
 }
 
 fn fold_with_index_exprs(base_expr: Expr, index_expr: IndexExprs) -> Expr {
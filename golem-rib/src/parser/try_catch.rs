@@ -0,0 +1,134 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::expr::Expr;
+use crate::parser::block::block;
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+use crate::rib_source_span::GetSourcePosition;
+use combine::parser::char::{alpha_num, char, spaces, string};
+use combine::{attempt, between, not_followed_by, ParseError, Parser};
+
+// try { foo(x) } catch e { "failed" }
+pub fn try_catch<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    attempt(
+        string("try").skip(not_followed_by(alpha_num().or(char('-')).or(char('_'))).skip(spaces())),
+    )
+    .with(
+        (
+            between(
+                char('{').skip(spaces()),
+                char('}').skip(spaces()),
+                block().skip(spaces()),
+            ),
+            string("catch")
+                .skip(not_followed_by(alpha_num().or(char('-')).or(char('_'))))
+                .skip(spaces())
+                .with(
+                    identifier_text()
+                        .message("Unable to parse the error variable of catch")
+                        .skip(spaces()),
+                ),
+            between(char('{').skip(spaces()), char('}'), block().skip(spaces())),
+        )
+            .map(|(try_expr, error_variable, catch_expr)| {
+                Expr::try_catch(try_expr, error_variable, catch_expr)
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::expr::Expr;
+
+    #[test]
+    fn test_try_catch() {
+        let input = "try { x } catch e { e }";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::try_catch(
+                Expr::identifier_global("x", None),
+                "e",
+                Expr::identifier_global("e", None)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_catch_with_block() {
+        let input = r#"
+          try {
+            let y = x;
+            y
+          } catch error {
+            "failed"
+          }
+        "#;
+
+        let result = Expr::from_text(input).unwrap();
+
+        let Expr::TryCatch {
+            try_expr,
+            error_variable,
+            catch_expr,
+            ..
+        } = result
+        else {
+            panic!("expected a try-catch expression");
+        };
+
+        assert!(matches!(*try_expr, Expr::ExprBlock { .. }));
+        assert_eq!(error_variable.name(), "error");
+        assert_eq!(*catch_expr, Expr::literal("failed"));
+    }
+
+    #[test]
+    fn test_propagate() {
+        let input = "x?";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::propagate(Expr::identifier_global("x", None)))
+        );
+    }
+
+    #[test]
+    fn test_propagate_selection() {
+        let input = "x.y? + 1";
+        let result = Expr::from_text(input).unwrap();
+
+        let Expr::Plus { lhs, .. } = result else {
+            panic!("expected a plus expression");
+        };
+
+        assert_eq!(
+            *lhs,
+            Expr::propagate(Expr::select_field(
+                Expr::identifier_global("x", None),
+                "y",
+                None
+            ))
+        );
+    }
+}
@@ -60,6 +60,10 @@ pub enum TypeName {
     Variant {
         cases: Vec<(String, Option<Box<TypeName>>)>,
     },
+    Map {
+        key: Box<TypeName>,
+        value: Box<TypeName>,
+    },
 }
 
 impl Display for TypeName {
@@ -90,6 +94,7 @@ impl Display for TypeName {
                 write!(f, ">")
             }
             TypeName::Option(inner_type) => write!(f, "option<{inner_type}>"),
+            TypeName::Map { key, value } => write!(f, "map<{key}, {value}>"),
             // https://component-model.bytecodealliance.org/design/wit.html#results
             TypeName::Result { ok, error } => match (ok, error) {
                 (Some(ok), Some(error)) => {
@@ -277,6 +282,9 @@ impl From<&TypeName> for InferredType {
                 InferredType::tuple(inner_types.iter().map(|t| t.into()).collect())
             }
             TypeName::Option(type_name) => InferredType::option(type_name.deref().into()),
+            TypeName::Map { key, value } => {
+                InferredType::map(key.deref().into(), value.deref().into())
+            }
             TypeName::Result { ok, error } => InferredType::result(
                 ok.as_deref().map(|x| x.into()),
                 error.as_deref().map(|x| x.into()),
@@ -342,6 +350,14 @@ impl TryFrom<InferredType> for TypeName {
                 let result = inferred_type.clone().try_into()?;
                 Ok(TypeName::Option(Box::new(result)))
             }
+            TypeInternal::Map { key, value } => {
+                let key = key.clone().try_into()?;
+                let value = value.clone().try_into()?;
+                Ok(TypeName::Map {
+                    key: Box::new(key),
+                    value: Box::new(value),
+                })
+            }
             TypeInternal::Result { ok, error } => {
                 let ok_unified = ok.as_ref().map(|ok| ok.clone().try_into()).transpose()?;
                 let err_unified = error
@@ -445,6 +461,31 @@ where
         .map(|inner_type| TypeName::Option(Box::new(inner_type)))
 }
 
+pub fn parse_map_type<Input>() -> impl Parser<Input, Output = TypeName>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    string("map")
+        .skip(spaces().silent())
+        .with(between(
+            char('<').skip(spaces().silent()),
+            char('>').skip(spaces().silent()),
+            (
+                type_name().skip(spaces().silent()),
+                char(',').skip(spaces().silent()),
+                type_name().skip(spaces().silent()),
+            ),
+        ))
+        .map(|(key, _, value)| TypeName::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+}
+
 enum ResultSuccess {
     NoType,
     WithType(TypeName),
@@ -536,6 +577,7 @@ where
         attempt(parse_tuple_type()),
         attempt(parse_option_type()),
         attempt(parse_result_type()),
+        attempt(parse_map_type()),
     )))
 }
 
@@ -551,8 +593,8 @@ parser! {
 mod protobuf {
     use golem_api_grpc::proto::golem::rib::type_name::Kind as InnerTypeName;
     use golem_api_grpc::proto::golem::rib::{
-        BasicTypeName, EnumType, FlagType, KeyValue, ListType, OptionType, RecordType, ResultType,
-        TupleType, TypeName as ProtoTypeName, VariantCase, VariantType,
+        BasicTypeName, EnumType, FlagType, KeyValue, ListType, MapType, OptionType, RecordType,
+        ResultType, TupleType, TypeName as ProtoTypeName, VariantCase, VariantType,
     };
     use std::ops::Deref;
 
@@ -583,6 +625,10 @@ mod protobuf {
                 TypeName::Option(type_name) => InnerTypeName::OptionType(Box::new(OptionType {
                     inner_type: Some(Box::new(type_name.deref().clone().into())),
                 })),
+                TypeName::Map { key, value } => InnerTypeName::MapType(Box::new(MapType {
+                    key_type: Some(Box::new(key.deref().clone().into())),
+                    value_type: Some(Box::new(value.deref().clone().into())),
+                })),
                 TypeName::Result { ok, error } => InnerTypeName::ResultType(Box::new(ResultType {
                     ok_type: ok.map(|ok| Box::new(ok.deref().clone().into())),
                     err_type: error.map(|error| Box::new(error.deref().clone().into())),
@@ -661,6 +707,16 @@ mod protobuf {
                         let option_type = proto_option_type.deref().clone().try_into()?;
                         Ok(TypeName::Option(Box::new(option_type)))
                     }
+                    InnerTypeName::MapType(map_type) => {
+                        let key = map_type.key_type.ok_or("No key type for map provided")?;
+                        let value = map_type
+                            .value_type
+                            .ok_or("No value type for map provided")?;
+                        Ok(TypeName::Map {
+                            key: Box::new(key.deref().clone().try_into()?),
+                            value: Box::new(value.deref().clone().try_into()?),
+                        })
+                    }
                     InnerTypeName::ResultType(result_type) => {
                         let ok = result_type
                             .ok_type
@@ -785,6 +841,24 @@ mod type_name_tests {
         );
     }
 
+    #[test]
+    fn test_map_type_name() {
+        parse_and_compare(
+            "map<string, u32>",
+            TypeName::Map {
+                key: Box::new(TypeName::Str),
+                value: Box::new(TypeName::U32),
+            },
+        );
+        parse_and_compare(
+            "map< string , list<string> >",
+            TypeName::Map {
+                key: Box::new(TypeName::Str),
+                value: Box::new(TypeName::List(Box::new(TypeName::Str))),
+            },
+        );
+    }
+
     #[test]
     fn test_nested_types() {
         parse_and_compare(
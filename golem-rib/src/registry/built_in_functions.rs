@@ -0,0 +1,505 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::TypeHint;
+use bincode::{Decode, Encode};
+use golem_wasm_ast::analysis::analysed_type::{
+    bool, f64, list, option, result, s64, str, u32, u64,
+};
+use golem_wasm_ast::analysis::AnalysedType;
+use std::fmt::{Display, Formatter};
+
+// Built-in functions are pure functions that ship with Rib itself, and unlike the functions
+// in FunctionDictionary, they don't belong to any wasm component.
+// They are grouped into modules and called as `<module>.<function>(args)`, for example `string.lowercase(x)`.
+// Type inference resolves them into `CallType::BuiltIn`, and they are compiled to `RibIR::CallBuiltIn`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Ord, PartialOrd, Encode, Decode)]
+pub enum BuiltInModule {
+    String,
+    Math,
+    List,
+    Option,
+    Map,
+    Result,
+    Encoding,
+    DateTime,
+}
+
+impl BuiltInModule {
+    pub fn all() -> &'static [BuiltInModule] {
+        &[
+            BuiltInModule::String,
+            BuiltInModule::Math,
+            BuiltInModule::List,
+            BuiltInModule::Option,
+            BuiltInModule::Map,
+            BuiltInModule::Result,
+            BuiltInModule::Encoding,
+            BuiltInModule::DateTime,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltInModule::String => "string",
+            BuiltInModule::Math => "math",
+            BuiltInModule::List => "list",
+            BuiltInModule::Option => "option",
+            BuiltInModule::Map => "map",
+            BuiltInModule::Result => "result",
+            BuiltInModule::Encoding => "encoding",
+            BuiltInModule::DateTime => "datetime",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<BuiltInModule> {
+        BuiltInModule::all()
+            .iter()
+            .find(|module| module.name() == name)
+            .copied()
+    }
+
+    pub fn functions(&self) -> Vec<BuiltInFunction> {
+        BuiltInFunction::all()
+            .iter()
+            .filter(|function| function.module() == *self)
+            .copied()
+            .collect()
+    }
+}
+
+impl Display for BuiltInModule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Ord, PartialOrd, Encode, Decode)]
+pub enum BuiltInFunction {
+    StringLowercase,
+    StringUppercase,
+    StringTrim,
+    StringLength,
+    StringIsEmpty,
+    StringContains,
+    StringStartsWith,
+    StringEndsWith,
+    StringReplace,
+    StringSplit,
+    StringJoin,
+    StringSubstring,
+    StringToInt,
+    StringToFloat,
+    StringFromNumber,
+    StringFormatNumber,
+    MathAbs,
+    MathMin,
+    MathMax,
+    MathFloor,
+    MathCeil,
+    MathRound,
+    MathSqrt,
+    MathPow,
+    ListLength,
+    ListIsEmpty,
+    ListContains,
+    ListReverse,
+    ListHead,
+    ListLast,
+    ListConcat,
+    ListFlatten,
+    OptionIsSome,
+    OptionIsNone,
+    OptionUnwrapOr,
+    MapSize,
+    MapContainsKey,
+    MapGet,
+    MapKeys,
+    MapValues,
+    ResultIsOk,
+    ResultIsErr,
+    ResultUnwrapOr,
+    EncodingBase64Encode,
+    EncodingBase64Decode,
+    EncodingUrlEncode,
+    EncodingUrlDecode,
+    EncodingHexEncode,
+    EncodingSha256,
+    DateTimeNow,
+    DateTimeFormat,
+    DateTimeToIso8601,
+    DateTimeFromIso8601,
+}
+
+impl BuiltInFunction {
+    pub fn all() -> &'static [BuiltInFunction] {
+        &[
+            BuiltInFunction::StringLowercase,
+            BuiltInFunction::StringUppercase,
+            BuiltInFunction::StringTrim,
+            BuiltInFunction::StringLength,
+            BuiltInFunction::StringIsEmpty,
+            BuiltInFunction::StringContains,
+            BuiltInFunction::StringStartsWith,
+            BuiltInFunction::StringEndsWith,
+            BuiltInFunction::StringReplace,
+            BuiltInFunction::StringSplit,
+            BuiltInFunction::StringJoin,
+            BuiltInFunction::StringSubstring,
+            BuiltInFunction::StringToInt,
+            BuiltInFunction::StringToFloat,
+            BuiltInFunction::StringFromNumber,
+            BuiltInFunction::StringFormatNumber,
+            BuiltInFunction::MathAbs,
+            BuiltInFunction::MathMin,
+            BuiltInFunction::MathMax,
+            BuiltInFunction::MathFloor,
+            BuiltInFunction::MathCeil,
+            BuiltInFunction::MathRound,
+            BuiltInFunction::MathSqrt,
+            BuiltInFunction::MathPow,
+            BuiltInFunction::ListLength,
+            BuiltInFunction::ListIsEmpty,
+            BuiltInFunction::ListContains,
+            BuiltInFunction::ListReverse,
+            BuiltInFunction::ListHead,
+            BuiltInFunction::ListLast,
+            BuiltInFunction::ListConcat,
+            BuiltInFunction::ListFlatten,
+            BuiltInFunction::OptionIsSome,
+            BuiltInFunction::OptionIsNone,
+            BuiltInFunction::OptionUnwrapOr,
+            BuiltInFunction::MapSize,
+            BuiltInFunction::MapContainsKey,
+            BuiltInFunction::MapGet,
+            BuiltInFunction::MapKeys,
+            BuiltInFunction::MapValues,
+            BuiltInFunction::ResultIsOk,
+            BuiltInFunction::ResultIsErr,
+            BuiltInFunction::ResultUnwrapOr,
+            BuiltInFunction::EncodingBase64Encode,
+            BuiltInFunction::EncodingBase64Decode,
+            BuiltInFunction::EncodingUrlEncode,
+            BuiltInFunction::EncodingUrlDecode,
+            BuiltInFunction::EncodingHexEncode,
+            BuiltInFunction::EncodingSha256,
+            BuiltInFunction::DateTimeNow,
+            BuiltInFunction::DateTimeFormat,
+            BuiltInFunction::DateTimeToIso8601,
+            BuiltInFunction::DateTimeFromIso8601,
+        ]
+    }
+
+    pub fn lookup(module: &str, function_name: &str) -> Option<BuiltInFunction> {
+        let module = BuiltInModule::from_name(module)?;
+
+        BuiltInFunction::all()
+            .iter()
+            .find(|function| function.module() == module && function.name() == function_name)
+            .copied()
+    }
+
+    // Parses the fully qualified name of a built-in function, such as `string.lowercase`
+    pub fn from_qualified_name(qualified_name: &str) -> Option<BuiltInFunction> {
+        let (module, function_name) = qualified_name.split_once('.')?;
+        BuiltInFunction::lookup(module, function_name)
+    }
+
+    pub fn module(&self) -> BuiltInModule {
+        match self {
+            BuiltInFunction::StringLowercase
+            | BuiltInFunction::StringUppercase
+            | BuiltInFunction::StringTrim
+            | BuiltInFunction::StringLength
+            | BuiltInFunction::StringIsEmpty
+            | BuiltInFunction::StringContains
+            | BuiltInFunction::StringStartsWith
+            | BuiltInFunction::StringEndsWith
+            | BuiltInFunction::StringReplace
+            | BuiltInFunction::StringSplit
+            | BuiltInFunction::StringJoin
+            | BuiltInFunction::StringSubstring
+            | BuiltInFunction::StringToInt
+            | BuiltInFunction::StringToFloat
+            | BuiltInFunction::StringFromNumber
+            | BuiltInFunction::StringFormatNumber => BuiltInModule::String,
+            BuiltInFunction::MathAbs
+            | BuiltInFunction::MathMin
+            | BuiltInFunction::MathMax
+            | BuiltInFunction::MathFloor
+            | BuiltInFunction::MathCeil
+            | BuiltInFunction::MathRound
+            | BuiltInFunction::MathSqrt
+            | BuiltInFunction::MathPow => BuiltInModule::Math,
+            BuiltInFunction::ListLength
+            | BuiltInFunction::ListIsEmpty
+            | BuiltInFunction::ListContains
+            | BuiltInFunction::ListReverse
+            | BuiltInFunction::ListHead
+            | BuiltInFunction::ListLast
+            | BuiltInFunction::ListConcat
+            | BuiltInFunction::ListFlatten => BuiltInModule::List,
+            BuiltInFunction::OptionIsSome
+            | BuiltInFunction::OptionIsNone
+            | BuiltInFunction::OptionUnwrapOr => BuiltInModule::Option,
+            BuiltInFunction::MapSize
+            | BuiltInFunction::MapContainsKey
+            | BuiltInFunction::MapGet
+            | BuiltInFunction::MapKeys
+            | BuiltInFunction::MapValues => BuiltInModule::Map,
+            BuiltInFunction::ResultIsOk
+            | BuiltInFunction::ResultIsErr
+            | BuiltInFunction::ResultUnwrapOr => BuiltInModule::Result,
+            BuiltInFunction::EncodingBase64Encode
+            | BuiltInFunction::EncodingBase64Decode
+            | BuiltInFunction::EncodingUrlEncode
+            | BuiltInFunction::EncodingUrlDecode
+            | BuiltInFunction::EncodingHexEncode
+            | BuiltInFunction::EncodingSha256 => BuiltInModule::Encoding,
+            BuiltInFunction::DateTimeNow
+            | BuiltInFunction::DateTimeFormat
+            | BuiltInFunction::DateTimeToIso8601
+            | BuiltInFunction::DateTimeFromIso8601 => BuiltInModule::DateTime,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltInFunction::StringLowercase => "lowercase",
+            BuiltInFunction::StringUppercase => "uppercase",
+            BuiltInFunction::StringTrim => "trim",
+            BuiltInFunction::StringLength => "length",
+            BuiltInFunction::StringIsEmpty => "is-empty",
+            BuiltInFunction::StringContains => "contains",
+            BuiltInFunction::StringStartsWith => "starts-with",
+            BuiltInFunction::StringEndsWith => "ends-with",
+            BuiltInFunction::StringReplace => "replace",
+            BuiltInFunction::StringSplit => "split",
+            BuiltInFunction::StringJoin => "join",
+            BuiltInFunction::StringSubstring => "substring",
+            BuiltInFunction::StringToInt => "to-int",
+            BuiltInFunction::StringToFloat => "to-float",
+            BuiltInFunction::StringFromNumber => "from-number",
+            BuiltInFunction::StringFormatNumber => "format-number",
+            BuiltInFunction::MathAbs => "abs",
+            BuiltInFunction::MathMin => "min",
+            BuiltInFunction::MathMax => "max",
+            BuiltInFunction::MathFloor => "floor",
+            BuiltInFunction::MathCeil => "ceil",
+            BuiltInFunction::MathRound => "round",
+            BuiltInFunction::MathSqrt => "sqrt",
+            BuiltInFunction::MathPow => "pow",
+            BuiltInFunction::ListLength => "length",
+            BuiltInFunction::ListIsEmpty => "is-empty",
+            BuiltInFunction::ListContains => "contains",
+            BuiltInFunction::ListReverse => "reverse",
+            BuiltInFunction::ListHead => "head",
+            BuiltInFunction::ListLast => "last",
+            BuiltInFunction::ListConcat => "concat",
+            BuiltInFunction::ListFlatten => "flatten",
+            BuiltInFunction::OptionIsSome => "is-some",
+            BuiltInFunction::OptionIsNone => "is-none",
+            BuiltInFunction::OptionUnwrapOr => "unwrap-or",
+            BuiltInFunction::MapSize => "size",
+            BuiltInFunction::MapContainsKey => "contains-key",
+            BuiltInFunction::MapGet => "get",
+            BuiltInFunction::MapKeys => "keys",
+            BuiltInFunction::MapValues => "values",
+            BuiltInFunction::ResultIsOk => "is-ok",
+            BuiltInFunction::ResultIsErr => "is-err",
+            BuiltInFunction::ResultUnwrapOr => "unwrap-or",
+            BuiltInFunction::EncodingBase64Encode => "base64-encode",
+            BuiltInFunction::EncodingBase64Decode => "base64-decode",
+            BuiltInFunction::EncodingUrlEncode => "url-encode",
+            BuiltInFunction::EncodingUrlDecode => "url-decode",
+            BuiltInFunction::EncodingHexEncode => "hex-encode",
+            BuiltInFunction::EncodingSha256 => "sha256",
+            BuiltInFunction::DateTimeNow => "now",
+            BuiltInFunction::DateTimeFormat => "format",
+            BuiltInFunction::DateTimeToIso8601 => "to-iso8601",
+            BuiltInFunction::DateTimeFromIso8601 => "from-iso8601",
+        }
+    }
+
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", self.module().name(), self.name())
+    }
+
+    pub fn signature(&self) -> BuiltInFunctionSignature {
+        use BuiltInType::*;
+
+        let number = || AnyOf(TypeHint::Number);
+        let any_list = || AnyOf(TypeHint::List(None));
+        let any_list_of_lists = || AnyOf(TypeHint::List(Some(Box::new(TypeHint::List(None)))));
+        let any_option = || AnyOf(TypeHint::Option(None));
+        let any_map = || {
+            AnyOf(TypeHint::Map {
+                key: None,
+                value: None,
+            })
+        };
+        let any_result = || {
+            AnyOf(TypeHint::Result {
+                ok: None,
+                err: None,
+            })
+        };
+
+        let (parameters, return_type) = match self {
+            BuiltInFunction::StringLowercase
+            | BuiltInFunction::StringUppercase
+            | BuiltInFunction::StringTrim => (vec![Type(str())], Type(str())),
+            BuiltInFunction::StringLength => (vec![Type(str())], Type(u64())),
+            BuiltInFunction::StringIsEmpty => (vec![Type(str())], Type(bool())),
+            BuiltInFunction::StringContains
+            | BuiltInFunction::StringStartsWith
+            | BuiltInFunction::StringEndsWith => (vec![Type(str()), Type(str())], Type(bool())),
+            BuiltInFunction::StringReplace => {
+                (vec![Type(str()), Type(str()), Type(str())], Type(str()))
+            }
+            BuiltInFunction::StringSplit => (vec![Type(str()), Type(str())], Type(list(str()))),
+            BuiltInFunction::StringJoin => (vec![Type(list(str())), Type(str())], Type(str())),
+            BuiltInFunction::StringSubstring => {
+                (vec![Type(str()), Type(u64()), Type(u64())], Type(str()))
+            }
+            BuiltInFunction::StringToInt => (vec![Type(str())], Type(option(s64()))),
+            BuiltInFunction::StringToFloat => (vec![Type(str())], Type(option(f64()))),
+            BuiltInFunction::StringFromNumber => (vec![number()], Type(str())),
+            BuiltInFunction::StringFormatNumber => (vec![number(), Type(u32())], Type(str())),
+            BuiltInFunction::MathAbs => (vec![number()], SameAs(0)),
+            BuiltInFunction::MathMin | BuiltInFunction::MathMax => {
+                (vec![number(), SameAs(0)], SameAs(0))
+            }
+            BuiltInFunction::MathFloor
+            | BuiltInFunction::MathCeil
+            | BuiltInFunction::MathRound
+            | BuiltInFunction::MathSqrt => (vec![number()], Type(f64())),
+            BuiltInFunction::MathPow => (vec![number(), number()], Type(f64())),
+            BuiltInFunction::ListLength => (vec![any_list()], Type(u64())),
+            BuiltInFunction::ListIsEmpty => (vec![any_list()], Type(bool())),
+            BuiltInFunction::ListContains => (vec![any_list(), InnerOf(0)], Type(bool())),
+            BuiltInFunction::ListReverse => (vec![any_list()], SameAs(0)),
+            BuiltInFunction::ListHead | BuiltInFunction::ListLast => {
+                (vec![any_list()], OptionOfInner(0))
+            }
+            BuiltInFunction::ListConcat => (vec![any_list(), SameAs(0)], SameAs(0)),
+            BuiltInFunction::ListFlatten => (vec![any_list_of_lists()], InnerOf(0)),
+            BuiltInFunction::OptionIsSome | BuiltInFunction::OptionIsNone => {
+                (vec![any_option()], Type(bool()))
+            }
+            BuiltInFunction::OptionUnwrapOr => (vec![any_option(), InnerOf(0)], InnerOf(0)),
+            BuiltInFunction::MapSize => (vec![any_map()], Type(u64())),
+            BuiltInFunction::MapContainsKey => (vec![any_map(), KeyOf(0)], Type(bool())),
+            BuiltInFunction::MapGet => (vec![any_map(), KeyOf(0)], OptionOfInner(0)),
+            BuiltInFunction::MapKeys => (vec![any_map()], KeysOf(0)),
+            BuiltInFunction::MapValues => (vec![any_map()], ValuesOf(0)),
+            BuiltInFunction::ResultIsOk | BuiltInFunction::ResultIsErr => {
+                (vec![any_result()], Type(bool()))
+            }
+            BuiltInFunction::ResultUnwrapOr => (vec![any_result(), InnerOf(0)], InnerOf(0)),
+            BuiltInFunction::EncodingBase64Encode
+            | BuiltInFunction::EncodingUrlEncode
+            | BuiltInFunction::EncodingHexEncode
+            | BuiltInFunction::EncodingSha256 => (vec![Type(str())], Type(str())),
+            BuiltInFunction::EncodingBase64Decode | BuiltInFunction::EncodingUrlDecode => {
+                (vec![Type(str())], Type(result(str(), str())))
+            }
+            BuiltInFunction::DateTimeNow => (vec![], Type(u64())),
+            BuiltInFunction::DateTimeFormat => (vec![Type(u64()), Type(str())], Type(str())),
+            BuiltInFunction::DateTimeToIso8601 => (vec![Type(u64())], Type(str())),
+            BuiltInFunction::DateTimeFromIso8601 => (vec![Type(str())], Type(option(u64()))),
+        };
+
+        BuiltInFunctionSignature {
+            parameters,
+            return_type,
+        }
+    }
+}
+
+impl Display for BuiltInFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.module().name(), self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltInFunctionSignature {
+    pub parameters: Vec<BuiltInType>,
+    pub return_type: BuiltInType,
+}
+
+impl BuiltInFunctionSignature {
+    pub fn arity(&self) -> usize {
+        self.parameters.len()
+    }
+}
+
+// The types in the signature of a built-in function.
+// Functions such as `list.head` are generic over the element type, and their types are
+// expressed relative to the types of the arguments, which are known only during type inference.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuiltInType {
+    // A fully known type
+    Type(AnalysedType),
+    // Any type of the given kind, such as any number or any list
+    AnyOf(TypeHint),
+    // The same type as the argument at the given position
+    SameAs(usize),
+    // The element type of a list, the inner type of an option, the `ok` type of a result,
+    // or the value type of a map, at the given argument position
+    InnerOf(usize),
+    // The key type of a map at the given argument position
+    KeyOf(usize),
+    // A list of the keys of a map at the given argument position
+    KeysOf(usize),
+    // A list of the values of a map at the given argument position
+    ValuesOf(usize),
+    // An option of the inner type (see `InnerOf`) of the argument at the given position
+    OptionOfInner(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{BuiltInFunction, BuiltInModule};
+
+    #[test]
+    fn test_built_in_function_names_are_unique_within_module() {
+        for module in BuiltInModule::all() {
+            let functions = module.functions();
+            for function in &functions {
+                let count = functions
+                    .iter()
+                    .filter(|f| f.name() == function.name())
+                    .count();
+                assert_eq!(count, 1, "duplicate built-in function {function}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_built_in_function_lookup() {
+        for function in BuiltInFunction::all() {
+            assert_eq!(
+                BuiltInFunction::from_qualified_name(&function.qualified_name()),
+                Some(*function)
+            );
+        }
+
+        assert_eq!(BuiltInFunction::lookup("string", "foo"), None);
+        assert_eq!(BuiltInFunction::lookup("foo", "lowercase"), None);
+    }
+}
@@ -75,7 +75,9 @@ impl ComponentDependencies {
                         })
                         .collect::<Vec<_>>();
 
-                    function_types_in_component.push((component_info.clone(), types));
+                    if !types.is_empty() {
+                        function_types_in_component.push((component_info.clone(), types));
+                    }
                 }
 
                 if function_types_in_component.is_empty() {
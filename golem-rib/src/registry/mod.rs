@@ -1,9 +1,11 @@
+pub use built_in_functions::*;
 pub use component_dependency_key::*;
 pub use function_dictionary::*;
 
 pub use component_dependencies::*;
 pub(crate) use raw_type_registry::*;
 
+mod built_in_functions;
 mod component_dependencies;
 mod component_dependency_key;
 mod function_dictionary;
@@ -77,6 +77,8 @@ impl FunctionTypeRegistry {
                 .types
                 .get(&RegistryKey::FunctionName(enum_name.clone())),
             CallType::InstanceCreation(_) => None,
+            CallType::BuiltIn(_) => None,
+            CallType::Lambda(_) => None,
         }
     }
 
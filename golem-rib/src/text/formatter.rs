@@ -0,0 +1,1523 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::expr::Expr;
+use crate::{ArmPattern, MatchArm, Range};
+use std::collections::VecDeque;
+
+/// Configuration of the Rib formatter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RibFormatConfig {
+    /// Number of spaces per level of indentation
+    pub indent_width: usize,
+    /// Records, lists, tuples, maps and arguments that don't fit within this width
+    /// are broken into one element per line. Lines that cannot be broken may still be longer.
+    pub max_width: usize,
+}
+
+impl Default for RibFormatConfig {
+    fn default() -> Self {
+        RibFormatConfig {
+            indent_width: 4,
+            max_width: 100,
+        }
+    }
+}
+
+// Formats the Rib source, keeping the comments and the blank lines between the statements.
+// Comments are kept in place when they are in between statements or match arms,
+// and any other comment is moved right after the statement it is in.
+pub fn format_source(source: &str, config: &RibFormatConfig) -> Result<String, String> {
+    let expr = Expr::from_text(source)?;
+    let mut formatter = Formatter::new(config, Some(SourceText::new(source)));
+
+    Ok(formatter.program(&expr))
+}
+
+pub fn format_expr(expr: &Expr, config: &RibFormatConfig) -> String {
+    Formatter::new(config, None).program(expr)
+}
+
+struct Formatter<'a> {
+    config: &'a RibFormatConfig,
+    source: Option<SourceText>,
+    // Comments that are not yet written, in the order of their position in the source
+    pending_comments: VecDeque<Comment>,
+}
+
+// A statement of a block, or an arm of a pattern match, along with its position in the source
+struct ListItem<'e> {
+    item: Item<'e>,
+    start: Option<usize>,
+    end: Option<usize>,
+}
+
+enum Item<'e> {
+    Expr(&'e Expr),
+    MatchArm(&'e MatchArm),
+}
+
+// An element of a record, list, tuple, map or argument list
+enum Element<'e> {
+    Expr(&'e Expr),
+    Field(&'e str, &'e Expr),
+    Entry(&'e Expr, &'e Expr),
+}
+
+impl<'a> Formatter<'a> {
+    fn new(config: &'a RibFormatConfig, source: Option<SourceText>) -> Self {
+        let pending_comments = source
+            .as_ref()
+            .map(|source| source.comments.iter().cloned().collect())
+            .unwrap_or_default();
+
+        Formatter {
+            config,
+            source,
+            pending_comments,
+        }
+    }
+
+    fn program(&mut self, expr: &Expr) -> String {
+        let items = self.block_items(expr);
+        let lines = self.items(items, 0, ";", false, Some(usize::MAX));
+
+        lines.join("\n")
+    }
+
+    fn expr(&mut self, expr: &Expr, indent: usize, column: usize) -> String {
+        if let Some(flat) = self.flat(expr) {
+            if column + flat.chars().count() <= self.config.max_width {
+                return flat;
+            }
+        }
+
+        let text = self.broken(expr, indent, column);
+        self.with_type_annotation(expr, text)
+    }
+
+    // Single line representation of the expression, unless it is always written in multiple lines
+    // (blocks, pattern matches and function definitions), or there are comments within it
+    fn flat(&self, expr: &Expr) -> Option<String> {
+        if self.has_comments_within(expr) {
+            return None;
+        }
+
+        let text = match expr {
+            Expr::Let {
+                variable_id,
+                type_annotation,
+                expr: value,
+                ..
+            } => {
+                if self.is_function_definition(expr) {
+                    return None;
+                }
+
+                format!(
+                    "let {}{} = {}",
+                    variable_id.name(),
+                    internal::type_annotation(type_annotation),
+                    self.flat(value)?
+                )
+            }
+            Expr::Literal { value, .. } => format!("\"{value}\""),
+            Expr::Identifier { variable_id, .. } => variable_id.name(),
+            Expr::GenerateWorkerName { .. } => String::new(),
+            Expr::Range { range, .. } => match range {
+                Range::Range { from, to } => format!("{}..{}", self.flat(from)?, self.flat(to)?),
+                Range::RangeInclusive { from, to } => {
+                    format!("{}..={}", self.flat(from)?, self.flat(to)?)
+                }
+                Range::RangeFrom { from } => format!("{}..", self.flat(from)?),
+            },
+            Expr::SelectField { expr, field, .. } => format!("{}.{}", self.flat(expr)?, field),
+            Expr::SelectIndex { expr, index, .. } => {
+                format!("{}[{}]", self.flat(expr)?, self.flat(index)?)
+            }
+            Expr::Sequence { exprs, .. } => format!("[{}]", self.flat_list(exprs)?),
+            Expr::Record { exprs, .. } => {
+                let fields = exprs
+                    .iter()
+                    .map(|(key, value)| Some(format!("{}: {}", key, self.flat(value)?)))
+                    .collect::<Option<Vec<_>>>()?;
+
+                format!("{{{}}}", fields.join(", "))
+            }
+            Expr::Map { entries, .. } => {
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| Some(format!("{}: {}", self.flat(key)?, self.flat(value)?)))
+                    .collect::<Option<Vec<_>>>()?;
+
+                format!("{{{}}}", entries.join(", "))
+            }
+            Expr::Tuple { exprs, .. } => format!("({})", self.flat_list(exprs)?),
+            Expr::Number { number, .. } => number.value.to_string(),
+            Expr::Flags { flags, .. } => format!("{{{}}}", flags.join(", ")),
+            Expr::Boolean { value, .. } => value.to_string(),
+            Expr::Concat { exprs, .. } => {
+                let mut text = String::from("\"");
+
+                for expr in exprs {
+                    match expr {
+                        Expr::Literal { value, .. } => text.push_str(value),
+                        expr => {
+                            text.push_str("${");
+                            text.push_str(&self.flat(expr)?);
+                            text.push('}');
+                        }
+                    }
+                }
+
+                text.push('"');
+                text
+            }
+            Expr::ExprBlock { .. } => return None,
+            Expr::Not { expr, .. } => format!("!{}", self.flat(expr)?),
+            Expr::Cond { cond, lhs, rhs, .. } => format!(
+                "if {} then {} else {}",
+                self.flat(cond)?,
+                self.flat(lhs)?,
+                self.flat(rhs)?
+            ),
+            Expr::PatternMatch { .. } => return None,
+            Expr::Option { expr, .. } => match expr {
+                Some(expr) => format!("some({})", self.flat(expr)?),
+                None => "none".to_string(),
+            },
+            Expr::Result { expr, .. } => match expr {
+                Ok(expr) => format!("ok({})", self.flat(expr)?),
+                Err(expr) => format!("err({})", self.flat(expr)?),
+            },
+            Expr::Call {
+                call_type,
+                generic_type_parameter,
+                args,
+                ..
+            } => {
+                let mut text = internal::call_name(call_type);
+
+                if let Some(type_parameter) = generic_type_parameter {
+                    text.push_str(&format!("[{}]", type_parameter.value));
+                }
+
+                if internal::has_argument_list(call_type, args) {
+                    text.push_str(&format!("({})", self.flat_list(args)?));
+                }
+
+                text
+            }
+            Expr::InvokeMethodLazy {
+                lhs,
+                method,
+                generic_type_parameter,
+                args,
+                ..
+            } => {
+                let mut text = format!("{}.{}", self.flat(lhs)?, method);
+
+                if let Some(type_parameter) = generic_type_parameter {
+                    text.push_str(&format!("[{}]", type_parameter.value));
+                }
+
+                text.push_str(&format!("({})", self.flat_list(args)?));
+                text
+            }
+            Expr::Unwrap { expr, .. } => format!("unwrap({})", self.flat(expr)?),
+            Expr::Throw { message, .. } => format!("throw({message})"),
+            Expr::GetTag { expr, .. } => format!("get_tag({})", self.flat(expr)?),
+            Expr::Length { expr, .. } => format!("len({})", self.flat(expr)?),
+            Expr::ListComprehension {
+                iterated_variable,
+                iterable_expr,
+                yield_expr,
+                ..
+            } => {
+                if yield_expr.is_block() {
+                    return None;
+                }
+
+                format!(
+                    "for {} in {} {{ yield {}; }}",
+                    iterated_variable.name(),
+                    self.flat(iterable_expr)?,
+                    self.flat(yield_expr)?
+                )
+            }
+            Expr::ListReduce {
+                reduce_variable,
+                iterated_variable,
+                iterable_expr,
+                init_value_expr,
+                yield_expr,
+                ..
+            } => {
+                if yield_expr.is_block() {
+                    return None;
+                }
+
+                format!(
+                    "reduce {}, {} in {} from {} {{ yield {}; }}",
+                    reduce_variable.name(),
+                    iterated_variable.name(),
+                    self.flat(iterable_expr)?,
+                    self.flat(init_value_expr)?,
+                    self.flat(yield_expr)?
+                )
+            }
+            Expr::Lambda {
+                parameters, body, ..
+            } => format!(
+                "|{}| {}",
+                internal::lambda_parameters(parameters),
+                self.flat(body)?
+            ),
+            Expr::Propagate { expr, .. } => format!("{}?", self.flat(expr)?),
+            Expr::TryCatch {
+                try_expr,
+                error_variable,
+                catch_expr,
+                ..
+            } => format!(
+                "try {{ {} }} catch {} {{ {} }}",
+                self.flat(try_expr)?,
+                error_variable.name(),
+                self.flat(catch_expr)?
+            ),
+            Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::And { lhs, rhs, .. }
+            | Expr::Or { lhs, rhs, .. }
+            | Expr::GreaterThanOrEqualTo { lhs, rhs, .. }
+            | Expr::LessThanOrEqualTo { lhs, rhs, .. }
+            | Expr::Plus { lhs, rhs, .. }
+            | Expr::Multiply { lhs, rhs, .. }
+            | Expr::Minus { lhs, rhs, .. }
+            | Expr::Divide { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::BitwiseAnd { lhs, rhs, .. }
+            | Expr::BitwiseOr { lhs, rhs, .. }
+            | Expr::BitwiseXor { lhs, rhs, .. }
+            | Expr::ShiftLeft { lhs, rhs, .. }
+            | Expr::ShiftRight { lhs, rhs, .. }
+            | Expr::EqualTo { lhs, rhs, .. }
+            | Expr::NotEqualTo { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. } => format!(
+                "{} {} {}",
+                self.flat(lhs)?,
+                internal::binary_operator(expr),
+                self.flat(rhs)?
+            ),
+        };
+
+        Some(self.with_type_annotation(expr, text))
+    }
+
+    fn flat_list(&self, exprs: &[Expr]) -> Option<String> {
+        let exprs = exprs
+            .iter()
+            .map(|expr| self.flat(expr))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(exprs.join(", "))
+    }
+
+    // Multi line representation of the expression, without its type annotation
+    fn broken(&mut self, expr: &Expr, indent: usize, column: usize) -> String {
+        let padding = self.indentation(indent);
+
+        match expr {
+            Expr::Let {
+                variable_id,
+                type_annotation,
+                expr: value,
+                ..
+            } => match value.as_ref() {
+                Expr::Lambda {
+                    parameters,
+                    return_type,
+                    body,
+                    ..
+                } if self.is_function_definition(expr) => {
+                    let items = self.block_items(body);
+                    let end = self.code_end(expr).map(|end| end.saturating_sub(1));
+                    let lines = self.items(items, indent + 1, ";", false, end);
+
+                    format!(
+                        "fn {}({}){} {{\n{}\n{}}}",
+                        variable_id.name(),
+                        internal::lambda_parameters(parameters),
+                        internal::type_annotation(return_type),
+                        lines.join("\n"),
+                        padding
+                    )
+                }
+                _ => {
+                    let prefix = format!(
+                        "let {}{} = ",
+                        variable_id.name(),
+                        internal::type_annotation(type_annotation)
+                    );
+                    let value = self.expr(value, indent, column + prefix.chars().count());
+
+                    format!("{prefix}{value}")
+                }
+            },
+            Expr::Range { range, .. } => match range {
+                Range::Range { from, to } => self.binary(from, "..", to, indent, column, false),
+                Range::RangeInclusive { from, to } => {
+                    self.binary(from, "..=", to, indent, column, false)
+                }
+                Range::RangeFrom { from } => format!("{}..", self.expr(from, indent, column)),
+            },
+            Expr::SelectField { expr, field, .. } => {
+                format!("{}.{}", self.expr(expr, indent, column), field)
+            }
+            Expr::SelectIndex { expr, index, .. } => {
+                let expr = self.expr(expr, indent, column);
+                let index = self.expr(index, indent, internal::end_column(&expr, column) + 1);
+
+                format!("{expr}[{index}]")
+            }
+            Expr::Sequence { exprs, .. } => {
+                let elements = exprs.iter().map(Element::Expr).collect();
+                self.delimited("[", "]", elements, indent)
+            }
+            Expr::Record { exprs, .. } => {
+                let elements = exprs
+                    .iter()
+                    .map(|(key, value)| Element::Field(key, value))
+                    .collect();
+                self.delimited("{", "}", elements, indent)
+            }
+            Expr::Map { entries, .. } => {
+                let elements = entries
+                    .iter()
+                    .map(|(key, value)| Element::Entry(key, value))
+                    .collect();
+                self.delimited("{", "}", elements, indent)
+            }
+            Expr::Tuple { exprs, .. } => {
+                let elements = exprs.iter().map(Element::Expr).collect();
+                self.delimited("(", ")", elements, indent)
+            }
+            Expr::Flags { flags, .. } => {
+                let flags = flags
+                    .iter()
+                    .map(|flag| format!("{}{}", self.indentation(indent + 1), flag))
+                    .collect::<Vec<_>>();
+
+                format!("{{\n{}\n{}}}", flags.join(",\n"), padding)
+            }
+            Expr::Concat { exprs, .. } => {
+                let mut text = String::from("\"");
+
+                for expr in exprs {
+                    match expr {
+                        Expr::Literal { value, .. } => text.push_str(value),
+                        Expr::ExprBlock { .. } => {
+                            let items = self.block_items(expr);
+                            let lines = self.items(items, indent + 1, ";", false, None);
+                            text.push_str(&format!("${{\n{}\n{}}}", lines.join("\n"), padding));
+                        }
+                        expr => {
+                            let column = internal::end_column(&text, column) + 2;
+                            text.push_str(&format!("${{{}}}", self.expr(expr, indent, column)));
+                        }
+                    }
+                }
+
+                text.push('"');
+                text
+            }
+            Expr::ExprBlock { .. } => {
+                let items = self.block_items(expr);
+                let end = self.code_end(expr).map(|end| end.saturating_sub(1));
+                let lines = self.items(items, indent + 1, ";", false, end);
+
+                format!("{{\n{}\n{}}}", lines.join("\n"), padding)
+            }
+            Expr::Not { expr, .. } => format!("!{}", self.expr(expr, indent, column + 1)),
+            Expr::Cond { cond, lhs, rhs, .. } => {
+                let inner_padding = self.indentation(indent + 1);
+                let cond = self.expr(cond, indent, column + 3);
+                let lhs = self.expr(lhs, indent + 1, inner_padding.len());
+
+                let rhs = match rhs.as_ref() {
+                    Expr::Cond { .. } => {
+                        format!(" {}", self.expr(rhs, indent, padding.len() + 5))
+                    }
+                    _ => format!(
+                        "\n{}{}",
+                        inner_padding,
+                        self.expr(rhs, indent + 1, inner_padding.len())
+                    ),
+                };
+
+                format!("if {cond} then\n{inner_padding}{lhs}\n{padding}else{rhs}")
+            }
+            Expr::PatternMatch {
+                predicate,
+                match_arms,
+                ..
+            } => {
+                let predicate_text = self.expr(predicate, indent, column + 6);
+                let items = self.match_arm_items(predicate, match_arms);
+                let end = self.code_end(expr).map(|end| end.saturating_sub(1));
+                let lines = self.items(items, indent + 1, ",", true, end);
+
+                format!(
+                    "match {} {{\n{}\n{}}}",
+                    predicate_text,
+                    lines.join("\n"),
+                    padding
+                )
+            }
+            Expr::Option { expr, .. } => match expr {
+                Some(expr) => self.wrapped("some", expr, indent, column),
+                None => "none".to_string(),
+            },
+            Expr::Result { expr, .. } => match expr {
+                Ok(expr) => self.wrapped("ok", expr, indent, column),
+                Err(expr) => self.wrapped("err", expr, indent, column),
+            },
+            Expr::Call {
+                call_type,
+                generic_type_parameter,
+                args,
+                ..
+            } => {
+                let mut text = internal::call_name(call_type);
+
+                if let Some(type_parameter) = generic_type_parameter {
+                    text.push_str(&format!("[{}]", type_parameter.value));
+                }
+
+                if internal::has_argument_list(call_type, args) {
+                    let elements = args.iter().map(Element::Expr).collect();
+                    text.push_str(&self.delimited("(", ")", elements, indent));
+                }
+
+                text
+            }
+            Expr::InvokeMethodLazy {
+                lhs,
+                method,
+                generic_type_parameter,
+                args,
+                ..
+            } => {
+                let mut text = format!("{}.{}", self.expr(lhs, indent, column), method);
+
+                if let Some(type_parameter) = generic_type_parameter {
+                    text.push_str(&format!("[{}]", type_parameter.value));
+                }
+
+                let elements = args.iter().map(Element::Expr).collect();
+                text.push_str(&self.delimited("(", ")", elements, indent));
+                text
+            }
+            Expr::Unwrap { expr, .. } => self.wrapped("unwrap", expr, indent, column),
+            Expr::GetTag { expr, .. } => self.wrapped("get_tag", expr, indent, column),
+            Expr::Length { expr, .. } => self.wrapped("len", expr, indent, column),
+            Expr::ListComprehension {
+                iterated_variable,
+                iterable_expr,
+                yield_expr,
+                ..
+            } => {
+                let prefix = format!("for {} in ", iterated_variable.name());
+                let iterable = self.expr(iterable_expr, indent, column + prefix.len());
+                let body = self.yield_block(yield_expr, indent + 1);
+
+                format!("{prefix}{iterable} {{\n{body}\n{padding}}}")
+            }
+            Expr::ListReduce {
+                reduce_variable,
+                iterated_variable,
+                iterable_expr,
+                init_value_expr,
+                yield_expr,
+                ..
+            } => {
+                let prefix = format!(
+                    "reduce {}, {} in ",
+                    reduce_variable.name(),
+                    iterated_variable.name()
+                );
+                let iterable = self.expr(iterable_expr, indent, column + prefix.len());
+                let init_value_column = internal::end_column(&iterable, column + prefix.len()) + 6;
+                let init_value = self.expr(init_value_expr, indent, init_value_column);
+                let body = self.yield_block(yield_expr, indent + 1);
+
+                format!("{prefix}{iterable} from {init_value} {{\n{body}\n{padding}}}")
+            }
+            Expr::Lambda {
+                parameters, body, ..
+            } => {
+                let prefix = format!("|{}| ", internal::lambda_parameters(parameters));
+                let body = self.expr(body, indent, column + prefix.len());
+
+                format!("{prefix}{body}")
+            }
+            Expr::Propagate { expr, .. } => format!("{}?", self.expr(expr, indent, column)),
+            Expr::TryCatch {
+                try_expr,
+                error_variable,
+                catch_expr,
+                ..
+            } => {
+                let try_items = self.block_items(try_expr);
+                let try_end = self.raw_end(try_expr);
+                let try_lines = self.items(try_items, indent + 1, ";", false, try_end);
+
+                let catch_items = self.block_items(catch_expr);
+                let catch_end = self.raw_end(catch_expr);
+                let catch_lines = self.items(catch_items, indent + 1, ";", false, catch_end);
+
+                format!(
+                    "try {{\n{}\n{}}} catch {} {{\n{}\n{}}}",
+                    try_lines.join("\n"),
+                    padding,
+                    error_variable.name(),
+                    catch_lines.join("\n"),
+                    padding
+                )
+            }
+            Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::And { lhs, rhs, .. }
+            | Expr::Or { lhs, rhs, .. }
+            | Expr::GreaterThanOrEqualTo { lhs, rhs, .. }
+            | Expr::LessThanOrEqualTo { lhs, rhs, .. }
+            | Expr::Plus { lhs, rhs, .. }
+            | Expr::Multiply { lhs, rhs, .. }
+            | Expr::Minus { lhs, rhs, .. }
+            | Expr::Divide { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::BitwiseAnd { lhs, rhs, .. }
+            | Expr::BitwiseOr { lhs, rhs, .. }
+            | Expr::BitwiseXor { lhs, rhs, .. }
+            | Expr::ShiftLeft { lhs, rhs, .. }
+            | Expr::ShiftRight { lhs, rhs, .. }
+            | Expr::EqualTo { lhs, rhs, .. }
+            | Expr::NotEqualTo { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. } => {
+                let operator = internal::binary_operator(expr);
+                self.binary(lhs, operator, rhs, indent, column, true)
+            }
+            Expr::Literal { .. }
+            | Expr::Identifier { .. }
+            | Expr::Number { .. }
+            | Expr::Boolean { .. }
+            | Expr::Throw { .. }
+            | Expr::GenerateWorkerName { .. } => {
+                let text = self.flat(expr).unwrap_or_default();
+                internal::without_type_annotation(expr, text)
+            }
+        }
+    }
+
+    fn binary(
+        &mut self,
+        lhs: &Expr,
+        operator: &str,
+        rhs: &Expr,
+        indent: usize,
+        column: usize,
+        spaced: bool,
+    ) -> String {
+        let separator = if spaced { " " } else { "" };
+        let lhs = self.expr(lhs, indent, column);
+        let rhs_column = internal::end_column(&lhs, column) + operator.len() + 2 * separator.len();
+        let rhs = self.expr(rhs, indent, rhs_column);
+
+        format!("{lhs}{separator}{operator}{separator}{rhs}")
+    }
+
+    fn wrapped(&mut self, name: &str, expr: &Expr, indent: usize, column: usize) -> String {
+        let expr = self.expr(expr, indent, column + name.len() + 1);
+        format!("{name}({expr})")
+    }
+
+    // One element per line, which is how records, lists, tuples, maps
+    // and arguments are written when they don't fit in a line
+    fn delimited(
+        &mut self,
+        open: &str,
+        close: &str,
+        elements: Vec<Element>,
+        indent: usize,
+    ) -> String {
+        if elements.is_empty() {
+            return format!("{open}{close}");
+        }
+
+        let padding = self.indentation(indent);
+        let inner_padding = self.indentation(indent + 1);
+        let count = elements.len();
+
+        let mut lines = vec![open.to_string()];
+
+        for (index, element) in elements.into_iter().enumerate() {
+            let text = match element {
+                Element::Expr(expr) => self.expr(expr, indent + 1, inner_padding.len()),
+                Element::Field(key, value) => {
+                    let column = inner_padding.len() + key.chars().count() + 2;
+                    format!("{}: {}", key, self.expr(value, indent + 1, column))
+                }
+                Element::Entry(key, value) => {
+                    let key = self.expr(key, indent + 1, inner_padding.len());
+                    let column = internal::end_column(&key, inner_padding.len()) + 2;
+                    format!("{}: {}", key, self.expr(value, indent + 1, column))
+                }
+            };
+
+            let separator = if index + 1 < count { "," } else { "" };
+            lines.push(format!("{inner_padding}{text}{separator}"));
+        }
+
+        lines.push(format!("{padding}{close}"));
+        lines.join("\n")
+    }
+
+    // The statements of a list comprehension or reduce, with the last one being yielded
+    fn yield_block(&mut self, yield_expr: &Expr, indent: usize) -> String {
+        let padding = self.indentation(indent);
+
+        let exprs = match yield_expr {
+            Expr::ExprBlock { exprs, .. } => exprs.iter().collect::<Vec<_>>(),
+            expr => vec![expr],
+        };
+
+        let Some((last, statements)) = exprs.split_last() else {
+            return String::new();
+        };
+
+        let mut lines = statements
+            .iter()
+            .map(|statement| {
+                let statement = self.expr(statement, indent, padding.len());
+                format!("{padding}{statement};")
+            })
+            .collect::<Vec<_>>();
+
+        let last = self.expr(last, indent, padding.len() + 6);
+        lines.push(format!("{padding}yield {last};"));
+
+        lines.join("\n")
+    }
+
+    // Writes the items one per line, along with the comments before, within and right after each of them
+    fn items(
+        &mut self,
+        items: Vec<ListItem>,
+        indent: usize,
+        separator: &str,
+        trailing_separator: bool,
+        end: Option<usize>,
+    ) -> Vec<String> {
+        let padding = self.indentation(indent);
+        let count = items.len();
+
+        let mut lines = vec![];
+        let mut previous_end = None;
+
+        for (index, list_item) in items.into_iter().enumerate() {
+            let leading_comments = self.take_comments_before(list_item.start);
+
+            let first_start = leading_comments
+                .first()
+                .map(|comment| comment.start)
+                .or(list_item.start);
+
+            if let (Some(previous_end), Some(first_start)) = (previous_end, first_start) {
+                if self.has_blank_line(previous_end, first_start) {
+                    lines.push(String::new());
+                }
+            }
+
+            for comment in leading_comments {
+                lines.push(format!("{padding}{}", comment.text));
+            }
+
+            let text = match list_item.item {
+                Item::Expr(expr) => self.expr(expr, indent, padding.len()),
+                Item::MatchArm(match_arm) => self.match_arm(match_arm, indent, padding.len()),
+            };
+
+            let inner_comments = self.take_comments_before(list_item.end);
+
+            let mut line = format!("{padding}{text}");
+
+            if index + 1 < count || trailing_separator {
+                line.push_str(separator);
+            }
+
+            for comment in self.take_trailing_comments(list_item.end) {
+                line.push(' ');
+                line.push_str(&comment.text);
+            }
+
+            lines.push(line);
+
+            for comment in inner_comments {
+                lines.push(format!("{padding}{}", comment.text));
+            }
+
+            previous_end = list_item.end;
+        }
+
+        for comment in self.take_comments_before(end) {
+            lines.push(format!("{padding}{}", comment.text));
+        }
+
+        lines
+    }
+
+    fn match_arm(&mut self, match_arm: &MatchArm, indent: usize, column: usize) -> String {
+        let pattern = self.arm_pattern(&match_arm.arm_pattern);
+        let column = column + pattern.chars().count() + 4;
+        let resolution = self.expr(&match_arm.arm_resolution_expr, indent, column);
+
+        format!("{pattern} => {resolution}")
+    }
+
+    fn arm_pattern(&self, arm_pattern: &ArmPattern) -> String {
+        match arm_pattern {
+            ArmPattern::WildCard => "_".to_string(),
+            ArmPattern::As(name, pattern) => format!("{} @ {}", name, self.arm_pattern(pattern)),
+            ArmPattern::Constructor(name, patterns) => {
+                if patterns.is_empty() && name == "none" {
+                    name.clone()
+                } else {
+                    format!("{}({})", name, self.arm_patterns(patterns))
+                }
+            }
+            ArmPattern::TupleConstructor(patterns) => format!("({})", self.arm_patterns(patterns)),
+            ArmPattern::ListConstructor(patterns) => format!("[{}]", self.arm_patterns(patterns)),
+            ArmPattern::RecordConstructor(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(key, pattern)| format!("{}: {}", key, self.arm_pattern(pattern)))
+                    .collect::<Vec<_>>();
+
+                format!("{{{}}}", fields.join(", "))
+            }
+            ArmPattern::Literal(expr) => match expr.as_ref() {
+                Expr::Identifier { variable_id, .. } => variable_id.name(),
+                expr => self.flat(expr).unwrap_or_default(),
+            },
+        }
+    }
+
+    fn arm_patterns(&self, patterns: &[ArmPattern]) -> String {
+        patterns
+            .iter()
+            .map(|pattern| self.arm_pattern(pattern))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn block_items<'e>(&self, expr: &'e Expr) -> Vec<ListItem<'e>> {
+        match expr {
+            Expr::ExprBlock { exprs, .. } => {
+                exprs.iter().map(|expr| self.expr_item(expr)).collect()
+            }
+            expr => vec![self.expr_item(expr)],
+        }
+    }
+
+    fn expr_item<'e>(&self, expr: &'e Expr) -> ListItem<'e> {
+        ListItem {
+            item: Item::Expr(expr),
+            start: self.code_start(expr),
+            end: self.code_end(expr),
+        }
+    }
+
+    // Match arms have no source span of their own, and hence an arm is located by scanning
+    // from the `{` after the predicate, or from the `,` after the previous arm
+    fn match_arm_items<'e>(
+        &self,
+        predicate: &Expr,
+        match_arms: &'e [MatchArm],
+    ) -> Vec<ListItem<'e>> {
+        let mut cursor = self
+            .code_end(predicate)
+            .and_then(|end| self.after_token(end, '{'));
+
+        match_arms
+            .iter()
+            .map(|match_arm| {
+                let start = cursor.and_then(|cursor| {
+                    self.source.as_ref().map(|source| source.code_start(cursor))
+                });
+                let end = self.code_end(&match_arm.arm_resolution_expr);
+
+                cursor = end.and_then(|end| self.after_token(end, ','));
+
+                ListItem {
+                    item: Item::MatchArm(match_arm),
+                    start,
+                    end,
+                }
+            })
+            .collect()
+    }
+
+    fn after_token(&self, offset: usize, token: char) -> Option<usize> {
+        let source = self.source.as_ref()?;
+        let offset = source.code_start(offset);
+
+        (source.chars.get(offset) == Some(&token)).then_some(offset + 1)
+    }
+
+    // Functions defined with `fn` and lambdas bound with `let` are the same expression,
+    // and the source tells which one was used. Without the source, `fn` is preferred.
+    fn is_function_definition(&self, expr: &Expr) -> bool {
+        let Expr::Let {
+            type_annotation: None,
+            expr: value,
+            ..
+        } = expr
+        else {
+            return false;
+        };
+
+        let Expr::Lambda {
+            parameters,
+            return_type,
+            ..
+        } = value.as_ref()
+        else {
+            return false;
+        };
+
+        // Only functions can have a return type, or no parameters
+        if return_type.is_some() || parameters.is_empty() {
+            return true;
+        }
+
+        match (&self.source, self.code_start(expr)) {
+            (Some(source), Some(start)) => source.chars[start..].starts_with(&['f', 'n']),
+            _ => true,
+        }
+    }
+
+    fn with_type_annotation(&self, expr: &Expr, text: String) -> String {
+        match expr {
+            Expr::Let { .. } | Expr::Lambda { .. } => text,
+            expr => match expr.type_annotation() {
+                Some(type_name) => format!("{text}: {type_name}"),
+                None => text,
+            },
+        }
+    }
+
+    fn indentation(&self, indent: usize) -> String {
+        " ".repeat(indent * self.config.indent_width)
+    }
+
+    fn code_start(&self, expr: &Expr) -> Option<usize> {
+        let source = self.source.as_ref()?;
+        let span = expr.source_span();
+
+        source
+            .offset(span.start_line(), span.start_column())
+            .map(|offset| source.code_start(offset))
+    }
+
+    fn code_end(&self, expr: &Expr) -> Option<usize> {
+        let source = self.source.as_ref()?;
+
+        self.raw_end(expr).map(|offset| source.code_end(offset))
+    }
+
+    fn raw_end(&self, expr: &Expr) -> Option<usize> {
+        let source = self.source.as_ref()?;
+        let span = expr.source_span();
+
+        source.offset(span.end_line(), span.end_column())
+    }
+
+    fn has_comments_within(&self, expr: &Expr) -> bool {
+        match (self.code_start(expr), self.code_end(expr)) {
+            (Some(start), Some(end)) => self
+                .pending_comments
+                .iter()
+                .any(|comment| comment.start >= start && comment.start < end),
+            _ => false,
+        }
+    }
+
+    fn has_blank_line(&self, from: usize, to: usize) -> bool {
+        match &self.source {
+            Some(source) if from < to && to <= source.chars.len() => {
+                source.chars[from..to]
+                    .iter()
+                    .filter(|c| **c == '\n')
+                    .count()
+                    > 1
+            }
+            _ => false,
+        }
+    }
+
+    fn take_comments_before(&mut self, offset: Option<usize>) -> Vec<Comment> {
+        let mut comments = vec![];
+
+        if let Some(offset) = offset {
+            while let Some(comment) = self.pending_comments.front() {
+                if comment.start >= offset {
+                    break;
+                }
+
+                comments.extend(self.pending_comments.pop_front());
+            }
+        }
+
+        comments
+    }
+
+    // Comments after the end of the code, that are on the same line
+    fn take_trailing_comments(&mut self, end: Option<usize>) -> Vec<Comment> {
+        let (Some(source), Some(end)) = (&self.source, end) else {
+            return vec![];
+        };
+
+        let line = source.line_of(end.saturating_sub(1));
+        let mut comments = vec![];
+
+        while let Some(comment) = self.pending_comments.front() {
+            if comment.start < end || source.line_of(comment.start) != line {
+                break;
+            }
+
+            comments.extend(self.pending_comments.pop_front());
+        }
+
+        comments
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Comment {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+// The source of a Rib script, indexed by characters, which is how the parser counts columns
+struct SourceText {
+    chars: Vec<char>,
+    line_starts: Vec<usize>,
+    comments: Vec<Comment>,
+}
+
+impl SourceText {
+    fn new(source: &str) -> Self {
+        let chars = source.chars().collect::<Vec<_>>();
+
+        let mut line_starts = vec![0];
+
+        for (index, c) in chars.iter().enumerate() {
+            if *c == '\n' {
+                line_starts.push(index + 1);
+            }
+        }
+
+        let comments = internal::scan_comments(&chars);
+
+        SourceText {
+            chars,
+            line_starts,
+            comments,
+        }
+    }
+
+    // Lines and columns in source spans are one based, and a tab advances the column to the next tab stop
+    fn offset(&self, line: i32, column: i32) -> Option<usize> {
+        if line < 1 || column < 1 {
+            return None;
+        }
+
+        let mut offset = *self.line_starts.get(line as usize - 1)?;
+        let mut current_column = 1;
+
+        while current_column < column as usize {
+            match self.chars.get(offset) {
+                Some('\t') => current_column += 8 - ((current_column - 1) % 8),
+                Some('\n') | None => return None,
+                Some(_) => current_column += 1,
+            }
+
+            offset += 1;
+        }
+
+        Some(offset)
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        }
+    }
+
+    // The offset of the first character of code at or after the offset
+    fn code_start(&self, offset: usize) -> usize {
+        let mut offset = offset;
+
+        loop {
+            while offset < self.chars.len() && self.chars[offset].is_whitespace() {
+                offset += 1;
+            }
+
+            match self.comments.iter().find(|comment| comment.start == offset) {
+                Some(comment) => offset = comment.end,
+                None => return offset,
+            }
+        }
+    }
+
+    // The offset right after the last character of code before the offset
+    fn code_end(&self, offset: usize) -> usize {
+        let mut offset = offset;
+
+        loop {
+            while offset > 0 && self.chars[offset - 1].is_whitespace() {
+                offset -= 1;
+            }
+
+            match self.comments.iter().find(|comment| comment.end == offset) {
+                Some(comment) => offset = comment.start,
+                None => return offset,
+            }
+        }
+    }
+}
+
+mod internal {
+    use crate::text::formatter::Comment;
+    use crate::{CallType, Expr, InstanceCreationType, LambdaParameter, TypeName};
+
+    // Finds the line and block comments, skipping the string literals
+    // while still looking into the code interpolated in them
+    pub(crate) fn scan_comments(chars: &[char]) -> Vec<Comment> {
+        enum Context {
+            Code { open_braces: usize },
+            Text,
+        }
+
+        let mut comments = vec![];
+        let mut contexts = vec![Context::Code { open_braces: 0 }];
+        let mut index = 0;
+
+        while index < chars.len() {
+            let c = chars[index];
+            let next = chars.get(index + 1).copied();
+
+            match contexts.last_mut() {
+                Some(Context::Text) => match (c, next) {
+                    ('"', _) => {
+                        contexts.pop();
+                    }
+                    ('$', Some('{')) => {
+                        contexts.push(Context::Code { open_braces: 0 });
+                        index += 1;
+                    }
+                    _ => {}
+                },
+                Some(Context::Code { open_braces }) => match (c, next) {
+                    ('"', _) => contexts.push(Context::Text),
+                    ('{', _) => *open_braces += 1,
+                    ('}', _) if *open_braces > 0 => *open_braces -= 1,
+                    ('}', _) => {
+                        if contexts.len() > 1 {
+                            contexts.pop();
+                        }
+                    }
+                    ('/', Some('/')) => {
+                        let end = chars[index..]
+                            .iter()
+                            .position(|c| *c == '\n')
+                            .map(|position| index + position)
+                            .unwrap_or(chars.len());
+
+                        comments.push(comment(chars, index, end));
+                        index = end;
+                        continue;
+                    }
+                    ('/', Some('*')) => {
+                        let end = chars[index + 2..]
+                            .windows(2)
+                            .position(|window| window == ['*', '/'])
+                            .map(|position| index + 2 + position + 2)
+                            .unwrap_or(chars.len());
+
+                        comments.push(comment(chars, index, end));
+                        index = end;
+                        continue;
+                    }
+                    _ => {}
+                },
+                None => {}
+            }
+
+            index += 1;
+        }
+
+        comments
+    }
+
+    fn comment(chars: &[char], start: usize, end: usize) -> Comment {
+        Comment {
+            start,
+            end,
+            text: chars[start..end]
+                .iter()
+                .collect::<String>()
+                .trim_end()
+                .to_string(),
+        }
+    }
+
+    pub(crate) fn type_annotation(type_name: &Option<TypeName>) -> String {
+        type_name
+            .as_ref()
+            .map(|type_name| format!(": {type_name}"))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn without_type_annotation(expr: &Expr, text: String) -> String {
+        match expr.type_annotation() {
+            Some(type_name) => text
+                .strip_suffix(&format!(": {type_name}"))
+                .map(|text| text.to_string())
+                .unwrap_or(text),
+            None => text,
+        }
+    }
+
+    pub(crate) fn lambda_parameters(parameters: &[LambdaParameter]) -> String {
+        parameters
+            .iter()
+            .map(|parameter| {
+                format!(
+                    "{}{}",
+                    parameter.variable_id.name(),
+                    type_annotation(&parameter.type_annotation)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub(crate) fn call_name(call_type: &CallType) -> String {
+        match call_type {
+            CallType::Function { function_name, .. } => function_name.to_string(),
+            CallType::VariantConstructor(name) => name.to_string(),
+            CallType::EnumConstructor(name) => name.to_string(),
+            CallType::InstanceCreation(instance) => match instance {
+                InstanceCreationType::WitWorker { .. } => "instance".to_string(),
+                InstanceCreationType::WitResource { resource_name, .. } => {
+                    resource_name.resource_name.to_string()
+                }
+            },
+            CallType::BuiltIn(function) => function.to_string(),
+            CallType::Lambda(variable_id) => variable_id.name(),
+        }
+    }
+
+    pub(crate) fn has_argument_list(call_type: &CallType, args: &[Expr]) -> bool {
+        match call_type {
+            CallType::VariantConstructor(_) => !args.is_empty(),
+            CallType::EnumConstructor(_) => false,
+            _ => true,
+        }
+    }
+
+    pub(crate) fn binary_operator(expr: &Expr) -> &'static str {
+        match expr {
+            Expr::GreaterThan { .. } => ">",
+            Expr::And { .. } => "&&",
+            Expr::Or { .. } => "||",
+            Expr::GreaterThanOrEqualTo { .. } => ">=",
+            Expr::LessThanOrEqualTo { .. } => "<=",
+            Expr::Plus { .. } => "+",
+            Expr::Multiply { .. } => "*",
+            Expr::Minus { .. } => "-",
+            Expr::Divide { .. } => "/",
+            Expr::Modulo { .. } => "%",
+            Expr::BitwiseAnd { .. } => "&",
+            Expr::BitwiseOr { .. } => "|",
+            Expr::BitwiseXor { .. } => "^",
+            Expr::ShiftLeft { .. } => "<<",
+            Expr::ShiftRight { .. } => ">>",
+            Expr::EqualTo { .. } => "==",
+            Expr::NotEqualTo { .. } => "!=",
+            Expr::LessThan { .. } => "<",
+            _ => "",
+        }
+    }
+
+    // The column at which the (possibly multi line) text ends, if it starts at the column
+    pub(crate) fn end_column(text: &str, column: usize) -> usize {
+        match text.rsplit_once('\n') {
+            Some((_, last_line)) => last_line.chars().count(),
+            None => column + text.chars().count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::text::formatter::{format_expr, format_source, RibFormatConfig};
+    use crate::Expr;
+    use proptest::prelude::*;
+
+    fn config(indent_width: usize, max_width: usize) -> RibFormatConfig {
+        RibFormatConfig {
+            indent_width,
+            max_width,
+        }
+    }
+
+    #[test]
+    fn test_format_statements_with_comments() {
+        let source = r#"
+            // the cart of the user
+            let cart = {user-id: "foo",  items: [1, 2, 3]}; // inline
+
+            /* the total */
+            let total = list.length(cart.items);
+
+
+            total
+        "#;
+
+        let result = format_source(source, &RibFormatConfig::default()).unwrap();
+
+        let expected = r#"// the cart of the user
+let cart = {user-id: "foo", items: [1, 2, 3]}; // inline
+
+/* the total */
+let total = list.length(cart.items);
+
+total"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_long_record() {
+        let source = r#"let request = {path: "/api/v1/users", method: "GET", headers: ["content-type", "accept"]}; request"#;
+
+        let result = format_source(source, &config(2, 40)).unwrap();
+
+        let expected = r#"let request = {
+  path: "/api/v1/users",
+  method: "GET",
+  headers: ["content-type", "accept"]
+};
+request"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_match_arms_with_comments() {
+        let source = r#"match x {
+            // present
+            some(y) => y,
+            none => 0 // absent
+        }"#;
+
+        let result = format_source(source, &RibFormatConfig::default()).unwrap();
+
+        let expected = r#"match x {
+    // present
+    some(y) => y,
+    none => 0, // absent
+}"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_if_else_chain() {
+        let source = r#"if x > 10 then "large" else if x > 5 then "medium" else "small""#;
+
+        let result = format_source(source, &config(4, 30)).unwrap();
+
+        let expected = r#"if x > 10 then
+    "large"
+else if x > 5 then
+    "medium"
+else
+    "small""#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_functions_and_lambdas() {
+        let source =
+            r#"fn add(x: u32, y: u32): u32 { x + y }; let double = |x| x * 2; double(add(1, 2))"#;
+
+        let result = format_source(source, &RibFormatConfig::default()).unwrap();
+
+        let expected = r#"fn add(x: u32, y: u32): u32 {
+    x + y
+};
+let double = |x| x * 2;
+double(add(1, 2))"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_expr_without_source() {
+        let expr = Expr::from_text(r#"let xs = [1, 2]; for x in xs { yield x * 2; }"#).unwrap();
+
+        let result = format_expr(&expr, &RibFormatConfig::default());
+
+        let expected = r#"let xs = [1, 2];
+for x in xs {
+    yield x * 2;
+}"#;
+
+        assert_eq!(result, expected);
+    }
+
+    fn identifier() -> impl Strategy<Value = String> {
+        prop::sample::select(vec!["foo", "bar", "baz", "qux"]).prop_map(String::from)
+    }
+
+    const FIELD_NAMES: [&str; 4] = ["name", "user-id", "items", "total"];
+
+    fn field_name() -> impl Strategy<Value = String> {
+        prop::sample::select(FIELD_NAMES.to_vec()).prop_map(String::from)
+    }
+
+    fn comment() -> impl Strategy<Value = String> {
+        prop_oneof![
+            "[a-z ]{0,20}".prop_map(|text| format!("// {text}\n")),
+            "[a-z ]{0,20}".prop_map(|text| format!("/* {text} */ ")),
+            Just(String::new()),
+            Just("\n\n".to_string()),
+        ]
+    }
+
+    fn rib_expr() -> impl Strategy<Value = String> {
+        let leaf = prop_oneof![
+            identifier(),
+            any::<u16>().prop_map(|number| number.to_string()),
+            "[a-z ]{0,12}".prop_map(|text| format!("\"{text}\"")),
+            prop::bool::ANY.prop_map(|value| value.to_string()),
+            (identifier(), field_name()).prop_map(|(name, field)| format!("{name}.{field}")),
+        ];
+
+        leaf.prop_recursive(4, 48, 6, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 1..4).prop_map(|values| {
+                    let fields = FIELD_NAMES
+                        .iter()
+                        .zip(values)
+                        .map(|(name, value)| format!("{name}: {value}"))
+                        .collect::<Vec<_>>();
+                    format!("{{{}}}", fields.join(", "))
+                }),
+                prop::collection::vec(inner.clone(), 0..5)
+                    .prop_map(|exprs| format!("[{}]", exprs.join(", "))),
+                prop::collection::vec(inner.clone(), 2..4)
+                    .prop_map(|exprs| format!("({})", exprs.join(", "))),
+                prop::collection::vec(inner.clone(), 1..3).prop_map(|values| {
+                    let entries = values
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, value)| format!("\"key-{index}\": {value}"))
+                        .collect::<Vec<_>>();
+                    format!("{{{}}}", entries.join(", "))
+                }),
+                inner.clone().prop_map(|expr| format!("some({expr})")),
+                inner.clone().prop_map(|expr| format!("ok({expr})")),
+                (inner.clone(), inner.clone(), inner.clone())
+                    .prop_map(|(cond, lhs, rhs)| format!("if {cond} then {lhs} else {rhs}")),
+                (
+                    inner.clone(),
+                    prop::sample::select(vec!["+", "-", "*", "==", "<", "&&"]),
+                    inner.clone()
+                )
+                    .prop_map(|(lhs, operator, rhs)| format!("({lhs} {operator} {rhs})")),
+                (inner.clone(), comment(), inner.clone(), inner.clone()).prop_map(
+                    |(predicate, comment, some, none)| format!(
+                        "match {predicate} {{ {comment}some(x) => {some}, none => {none} }}"
+                    )
+                ),
+                (identifier(), prop::collection::vec(inner.clone(), 0..3))
+                    .prop_map(|(name, args)| format!("{name}({})", args.join(", "))),
+                (identifier(), inner.clone())
+                    .prop_map(|(name, expr)| format!("for x in {name} {{ yield {expr}; }}")),
+                inner.prop_map(|expr| format!("|x| {expr}")),
+            ]
+        })
+    }
+
+    fn rib_program() -> impl Strategy<Value = String> {
+        (
+            prop::collection::vec((comment(), rib_expr()), 0..4),
+            comment(),
+            rib_expr(),
+        )
+            .prop_map(|(statements, comment, last)| {
+                let mut program = String::new();
+
+                for (index, (comment, expr)) in statements.into_iter().enumerate() {
+                    program.push_str(&format!("{comment}let v{index} = {expr};\n"));
+                }
+
+                program.push_str(&format!("{comment}{last}"));
+                program
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_formatted_source(
+            source in rib_program(),
+            indent_width in 1usize..8,
+            max_width in 10usize..120
+        ) {
+            let config = config(indent_width, max_width);
+            // The generated programs are not always valid, such as a tuple right after `yield`
+            let parsed = Expr::from_text(&source);
+            prop_assume!(parsed.is_ok());
+            let expr = parsed.unwrap();
+
+            let formatted = format_source(&source, &config).unwrap();
+            prop_assert_eq!(Expr::from_text(&formatted), Ok(expr.clone()));
+            prop_assert_eq!(formatted.matches("//").count(), source.matches("//").count());
+            prop_assert_eq!(formatted.matches("/*").count(), source.matches("/*").count());
+
+            let reformatted = format_source(&formatted, &config).unwrap();
+            prop_assert_eq!(&reformatted, &formatted);
+
+            let formatted_expr = format_expr(&expr, &config);
+            prop_assert_eq!(Expr::from_text(&formatted_expr), Ok(expr));
+        }
+    }
+}
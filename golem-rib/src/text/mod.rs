@@ -15,33 +15,47 @@
 use crate::expr::Expr;
 use crate::ArmPattern;
 
+mod formatter;
 mod writer;
 
+pub use crate::text::formatter::RibFormatConfig;
 use crate::text::writer::WriterError;
 
 pub fn from_string(input: impl AsRef<str>) -> Result<Expr, String> {
-    let trimmed = input.as_ref().trim();
-
-    // This check is kept for backward compatibility to support rib programs that were wrapped in `${..}`
-    // Rib's grammar doesn't support wrapping the expressions in`${}` anymore, and therefore
-    // we unwrap before calling Expr::from_text
-    if trimmed.starts_with("${") && trimmed.ends_with("}") {
-        let trimmed_open = trimmed.strip_prefix("${").unwrap();
-        let trimmed_closing = trimmed_open.strip_suffix('}').unwrap();
-        Expr::from_text(trimmed_closing)
-    } else {
-        Expr::from_text(input.as_ref())
-    }
+    Expr::from_text(without_interpolation(input.as_ref()))
 }
 
 pub fn to_string(expr: &Expr) -> Result<String, WriterError> {
     writer::write_expr(expr)
 }
 
+/// Formats a Rib script over multiple lines, with its comments preserved
+pub fn format_source(input: impl AsRef<str>, config: &RibFormatConfig) -> Result<String, String> {
+    formatter::format_source(without_interpolation(input.as_ref()), config)
+}
+
+pub fn format_expr(expr: &Expr, config: &RibFormatConfig) -> String {
+    formatter::format_expr(expr, config)
+}
+
 pub fn to_string_arm_pattern(arm_pattern: &ArmPattern) -> Result<String, WriterError> {
     writer::write_arm_pattern(arm_pattern)
 }
 
+// This check is kept for backward compatibility to support rib programs that were wrapped in `${..}`
+// Rib's grammar doesn't support wrapping the expressions in`${}` anymore, and therefore
+// we unwrap before calling Expr::from_text
+fn without_interpolation(input: &str) -> &str {
+    let trimmed = input.trim();
+
+    if trimmed.starts_with("${") && trimmed.ends_with("}") {
+        let trimmed_open = trimmed.strip_prefix("${").unwrap();
+        trimmed_open.strip_suffix('}').unwrap()
+    } else {
+        input
+    }
+}
+
 #[cfg(test)]
 mod interpolation_tests {
     use test_r::test;
@@ -121,6 +135,24 @@ mod record_tests {
         assert_eq!((expr_str, input_expr), (expected_str, output_expr));
     }
 
+    #[test]
+    fn test_round_trip_read_write_map() {
+        let input_expr = Expr::map(
+            vec![
+                (Expr::literal("content-type"), Expr::literal("text/plain")),
+                (
+                    Expr::literal("accept"),
+                    Expr::identifier_global("request", None),
+                ),
+            ],
+            None,
+        );
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = r#"{"content-type": "text/plain", "accept": request}"#.to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+
     #[test]
     fn test_round_trip_read_write_record_of_number() {
         let input_expr = Expr::record(vec![
@@ -698,6 +730,34 @@ mod sequence_tests {
         assert_eq!((expr_str, input_expr), (expected_str, output_expr));
     }
 
+    #[test]
+    fn test_round_trip_read_write_sequence_of_integer_op() {
+        let input_expr = Expr::sequence(
+            vec![
+                Expr::modulo(
+                    Expr::identifier_global("x", None),
+                    Expr::number(BigDecimal::from(2)),
+                ),
+                Expr::bitwise_or(
+                    Expr::shift_left(
+                        Expr::identifier_global("x", None),
+                        Expr::number(BigDecimal::from(4)),
+                    ),
+                    Expr::identifier_global("y", None),
+                ),
+                Expr::not_equal_to(
+                    Expr::identifier_global("x", None),
+                    Expr::identifier_global("y", None),
+                ),
+            ],
+            None,
+        );
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "[x % 2, x << 4 | y, x != y]".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+
     #[test]
     fn test_round_trip_read_write_sequence_of_if_condition() {
         let input_expr = Expr::sequence(
@@ -1259,6 +1319,78 @@ mod let_tests {
     }
 }
 
+#[cfg(test)]
+mod lambda_tests {
+    use test_r::test;
+
+    use crate::expr::{Expr, LambdaParameter};
+    use crate::parser::type_name::TypeName;
+    use crate::text::{from_string, to_string};
+
+    #[test]
+    fn test_round_trip_function_definition() {
+        let input_expr = Expr::function_definition(
+            "add",
+            vec![
+                LambdaParameter::new("x", Some(TypeName::U32)),
+                LambdaParameter::new("y", None),
+            ],
+            Some(TypeName::U32),
+            Expr::plus(
+                Expr::identifier_global("x", None),
+                Expr::identifier_global("y", None),
+            ),
+        );
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "fn add(x: u32, y): u32 { x + y }".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+
+    #[test]
+    fn test_round_trip_lambda() {
+        let input_expr = Expr::lambda(
+            vec![LambdaParameter::new("x", Some(TypeName::Str))],
+            None,
+            Expr::identifier_global("x", None),
+        );
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "|x: string| x".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+}
+
+#[cfg(test)]
+mod error_handling_tests {
+    use test_r::test;
+
+    use crate::expr::Expr;
+    use crate::text::{from_string, to_string};
+
+    #[test]
+    fn test_round_trip_propagate() {
+        let input_expr = Expr::propagate(Expr::identifier_global("x", None));
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "x?".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+
+    #[test]
+    fn test_round_trip_try_catch() {
+        let input_expr = Expr::try_catch(
+            Expr::identifier_global("x", None),
+            "e",
+            Expr::literal("failed"),
+        );
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "try { x } catch e { \"failed\" }".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+}
+
 #[cfg(test)]
 mod selection_tests {
     use bigdecimal::BigDecimal;
@@ -118,16 +118,40 @@ impl<W: Write> Writer<W> {
                 type_annotation,
                 expr,
                 ..
-            } => {
-                self.write_str("let ")?;
-                self.write_str(variable_id.name())?;
-                if let Some(type_name) = type_annotation {
-                    self.write_str(": ")?;
-                    self.write_display(type_name)?;
-                };
-                self.write_str(" = ")?;
-                self.write_expr(expr)
-            }
+            } => match (type_annotation, expr.as_ref()) {
+                (
+                    None,
+                    Expr::Lambda {
+                        parameters,
+                        return_type,
+                        body,
+                        ..
+                    },
+                ) => {
+                    self.write_str("fn ")?;
+                    self.write_str(variable_id.name())?;
+                    self.write_str("(")?;
+                    internal::write_lambda_parameters(self, parameters)?;
+                    self.write_str(")")?;
+                    if let Some(type_name) = return_type {
+                        self.write_str(": ")?;
+                        self.write_display(type_name)?;
+                    }
+                    self.write_str(" { ")?;
+                    self.write_expr(body)?;
+                    self.write_str(" }")
+                }
+                _ => {
+                    self.write_str("let ")?;
+                    self.write_str(variable_id.name())?;
+                    if let Some(type_name) = type_annotation {
+                        self.write_str(": ")?;
+                        self.write_display(type_name)?;
+                    };
+                    self.write_str(" = ")?;
+                    self.write_expr(expr)
+                }
+            },
             Expr::SelectField {
                 expr,
                 field,
@@ -197,6 +221,30 @@ impl<W: Write> Writer<W> {
                 }
                 self.write_display("}")
             }
+            Expr::Map {
+                entries,
+                type_annotation,
+                ..
+            } => {
+                self.write_display("{")?;
+                for (idx, (key, value)) in entries.iter().enumerate() {
+                    if idx != 0 {
+                        self.write_display(",")?;
+                        self.write_display(" ")?;
+                    }
+                    self.write_expr(key)?;
+                    self.write_display(":")?;
+                    self.write_display(" ")?;
+                    self.write_expr(value)?;
+                }
+                self.write_display("}")?;
+                if let Some(type_name) = type_annotation {
+                    self.write_str(": ")?;
+                    self.write_display(type_name)
+                } else {
+                    Ok(())
+                }
+            }
             Expr::Tuple { exprs, .. } => {
                 self.write_display("(")?;
                 for (idx, expr) in exprs.iter().enumerate() {
@@ -276,6 +324,36 @@ impl<W: Write> Writer<W> {
                 self.write_str(" * ")?;
                 self.write_expr(rhs)
             }
+            Expr::Modulo { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" % ")?;
+                self.write_expr(rhs)
+            }
+            Expr::BitwiseAnd { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" & ")?;
+                self.write_expr(rhs)
+            }
+            Expr::BitwiseOr { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" | ")?;
+                self.write_expr(rhs)
+            }
+            Expr::BitwiseXor { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" ^ ")?;
+                self.write_expr(rhs)
+            }
+            Expr::ShiftLeft { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" << ")?;
+                self.write_expr(rhs)
+            }
+            Expr::ShiftRight { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" >> ")?;
+                self.write_expr(rhs)
+            }
             Expr::GreaterThanOrEqualTo { lhs, rhs, .. } => {
                 self.write_expr(lhs)?;
                 self.write_str(" >= ")?;
@@ -291,6 +369,11 @@ impl<W: Write> Writer<W> {
                 self.write_str(" == ")?;
                 self.write_expr(rhs)
             }
+            Expr::NotEqualTo { lhs, rhs, .. } => {
+                self.write_expr(lhs)?;
+                self.write_str(" != ")?;
+                self.write_expr(rhs)
+            }
             Expr::LessThan { lhs, rhs, .. } => {
                 self.write_expr(lhs)?;
                 self.write_str(" < ")?;
@@ -379,6 +462,8 @@ impl<W: Write> Writer<W> {
                             resource_name.resource_name.to_string()
                         }
                     },
+                    CallType::BuiltIn(function) => function.to_string(),
+                    CallType::Lambda(variable_id) => variable_id.name(),
                 };
 
                 self.write_str(function_name)?;
@@ -390,7 +475,10 @@ impl<W: Write> Writer<W> {
                 }
 
                 match call_type {
-                    CallType::Function { .. } | CallType::InstanceCreation(_) => {
+                    CallType::Function { .. }
+                    | CallType::InstanceCreation(_)
+                    | CallType::BuiltIn(_)
+                    | CallType::Lambda(_) => {
                         self.write_display("(")?;
                         for (idx, param) in args.iter().enumerate() {
                             if idx != 0 {
@@ -427,6 +515,35 @@ impl<W: Write> Writer<W> {
                 self.write_str(")")
             }
 
+            Expr::Lambda {
+                parameters, body, ..
+            } => {
+                self.write_str("|")?;
+                internal::write_lambda_parameters(self, parameters)?;
+                self.write_str("| ")?;
+                self.write_expr(body)
+            }
+
+            Expr::Propagate { expr, .. } => {
+                self.write_expr(expr)?;
+                self.write_str("?")
+            }
+
+            Expr::TryCatch {
+                try_expr,
+                error_variable,
+                catch_expr,
+                ..
+            } => {
+                self.write_str("try { ")?;
+                self.write_expr(try_expr)?;
+                self.write_str(" } catch ")?;
+                self.write_str(error_variable.name())?;
+                self.write_str(" { ")?;
+                self.write_expr(catch_expr)?;
+                self.write_str(" }")
+            }
+
             Expr::Length { expr, .. } => {
                 self.write_str("len(")?;
                 self.write_expr(expr)?;
@@ -532,7 +649,7 @@ impl<W: Write> Writer<W> {
 }
 
 mod internal {
-    use crate::expr::{ArmPattern, Expr};
+    use crate::expr::{ArmPattern, Expr, LambdaParameter};
     use crate::text::writer::{Writer, WriterError};
 
     pub(crate) enum ExprType<'a> {
@@ -569,6 +686,27 @@ mod internal {
         }
     }
 
+    pub(crate) fn write_lambda_parameters<W>(
+        writer: &mut Writer<W>,
+        parameters: &[LambdaParameter],
+    ) -> Result<(), WriterError>
+    where
+        W: std::io::Write,
+    {
+        for (idx, parameter) in parameters.iter().enumerate() {
+            if idx != 0 {
+                writer.write_str(", ")?;
+            }
+            writer.write_str(parameter.variable_id.name())?;
+            if let Some(type_name) = &parameter.type_annotation {
+                writer.write_str(": ")?;
+                writer.write_display(type_name)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn get_expr_type(expr: &Expr) -> ExprType {
         match expr {
             Expr::Literal { value, .. } => ExprType::Text(value),
@@ -35,7 +35,7 @@ pub fn check_invalid_function_args(
         } = &expr
         {
             match call_type {
-                CallType::InstanceCreation(_) => {}
+                CallType::InstanceCreation(_) | CallType::BuiltIn(_) | CallType::Lambda(_) => {}
                 call_type => get_missing_record_keys(call_type, args, component_dependency, expr)?,
             }
         }
@@ -0,0 +1,109 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CustomError, Expr, ExprVisitor};
+
+// `expr?` returns early from the rib script with the `none` or the `err` value of the expr,
+// and therefore the script itself should return an option or a result (with the same error type)
+pub fn check_invalid_propagation(expr: &mut Expr) -> Result<(), CustomError> {
+    let return_type = expr.inferred_type();
+
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_back() {
+        if let Expr::Propagate {
+            expr, source_span, ..
+        } = expr
+        {
+            internal::check_propagation(expr, source_span, &return_type)?;
+        }
+    }
+
+    Ok(())
+}
+
+mod internal {
+    use crate::rib_source_span::SourceSpan;
+    use crate::type_refinement::precise_types::{ErrType, OkType, OptionalType};
+    use crate::type_refinement::TypeRefinement;
+    use crate::{CustomError, Expr, InferredType, TypeName};
+    use golem_wasm_ast::analysis::AnalysedType;
+
+    pub(crate) fn check_propagation(
+        expr: &Expr,
+        source_span: &SourceSpan,
+        return_type: &InferredType,
+    ) -> Result<(), CustomError> {
+        let operand_type = expr.inferred_type();
+
+        if OptionalType::refine(&operand_type).is_some() {
+            if OptionalType::refine(return_type).is_none() {
+                return Err(CustomError::new(
+                    source_span.clone(),
+                    "`?` on an option can only be used in a rib script that returns an option",
+                )
+                .with_help_message(format!("the rib script returns {}", type_name(return_type))));
+            }
+        } else if OkType::refine(&operand_type).is_some() {
+            let Some(return_err_type) = ErrType::refine(return_type) else {
+                return Err(CustomError::new(
+                    source_span.clone(),
+                    "`?` on a result can only be used in a rib script that returns a result",
+                )
+                .with_help_message(format!("the rib script returns {}", type_name(return_type))));
+            };
+
+            let operand_err_type = ErrType::refine(&operand_type)
+                .map(|err_type| err_type.inner_type())
+                .unwrap_or(InferredType::unknown());
+
+            let return_err_type = return_err_type.inner_type();
+
+            if let (Ok(operand_err), Ok(return_err)) = (
+                AnalysedType::try_from(&operand_err_type),
+                AnalysedType::try_from(&return_err_type),
+            ) {
+                if operand_err != return_err {
+                    return Err(CustomError::new(
+                        source_span.clone(),
+                        format!(
+                            "the error type of `?`, {}, is not the error type of the rib script, {}",
+                            type_name(&operand_err_type),
+                            type_name(&return_err_type)
+                        ),
+                    )
+                    .with_help_message(
+                        "convert the error before using `?`, for example using a pattern match",
+                    ));
+                }
+            }
+        } else {
+            return Err(CustomError::new(
+                source_span.clone(),
+                format!(
+                    "`?` can only be used on an option or a result, found {}",
+                    type_name(&operand_type)
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn type_name(inferred_type: &InferredType) -> String {
+        TypeName::try_from(inferred_type.clone())
+            .map(|t| t.to_string())
+            .unwrap_or_else(|_| "unknown".to_string())
+    }
+}
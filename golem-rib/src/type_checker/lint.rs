@@ -0,0 +1,364 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::rib_source_span::SourceSpan;
+use crate::{Expr, ExprVisitor};
+use std::fmt;
+use std::fmt::Display;
+
+/// A warning found by the lint pass. Unlike type errors, warnings never fail the compilation
+/// of a Rib script, and they are reported along with the compiled output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RibWarning {
+    pub kind: RibWarningKind,
+    pub source_span: SourceSpan,
+    pub message: String,
+}
+
+impl RibWarning {
+    fn new(kind: RibWarningKind, source_span: SourceSpan, message: impl AsRef<str>) -> Self {
+        RibWarning {
+            kind,
+            source_span,
+            message: message.as_ref().to_string(),
+        }
+    }
+}
+
+impl Display for RibWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "warning at line {}, column {}: {}",
+            self.source_span.start_line(),
+            self.source_span.start_column(),
+            self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RibWarningKind {
+    UnusedVariable,
+    ShadowedVariable,
+    UnreachableMatchArm,
+    ConstantCondition,
+    UnboundedWorkerCalls,
+}
+
+// Lints a fully inferred expression, where every variable is already bound to its definition
+pub fn lint(expr: &Expr) -> Vec<RibWarning> {
+    let mut expr = expr.clone();
+    let mut warnings = vec![];
+
+    let used_variables = internal::used_variables(&mut expr);
+    let mut let_bindings = vec![];
+
+    let mut visitor = ExprVisitor::bottom_up(&mut expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        match expr {
+            Expr::Let {
+                variable_id,
+                source_span,
+                ..
+            } if variable_id.is_local() => {
+                let name = variable_id.name();
+
+                if !used_variables.contains(variable_id) {
+                    warnings.push(RibWarning::new(
+                        RibWarningKind::UnusedVariable,
+                        source_span.clone(),
+                        format!("unused variable `{name}`"),
+                    ));
+                }
+
+                if let_bindings.contains(&name) {
+                    warnings.push(RibWarning::new(
+                        RibWarningKind::ShadowedVariable,
+                        source_span.clone(),
+                        format!("`{name}` shadows an earlier binding of the same name"),
+                    ));
+                } else {
+                    let_bindings.push(name);
+                }
+            }
+
+            Expr::Cond {
+                cond, source_span, ..
+            } => {
+                if let Some(value) = internal::constant_condition(cond) {
+                    let never_evaluated = if value { "else" } else { "then" };
+
+                    warnings.push(RibWarning::new(
+                        RibWarningKind::ConstantCondition,
+                        source_span.clone(),
+                        format!(
+                            "condition is always {value}, and the {never_evaluated} branch is never evaluated"
+                        ),
+                    ));
+                }
+            }
+
+            Expr::PatternMatch {
+                match_arms,
+                source_span,
+                ..
+            } => {
+                for (index, earlier_index) in internal::unreachable_arms(match_arms) {
+                    warnings.push(RibWarning::new(
+                        RibWarningKind::UnreachableMatchArm,
+                        match_arms[index].arm_resolution_expr.source_span(),
+                        format!(
+                            "unreachable match arm `{}` in the match at line {}, as it is already matched by arm {}",
+                            match_arms[index].arm_pattern,
+                            source_span.start_line(),
+                            earlier_index + 1
+                        ),
+                    ));
+                }
+            }
+
+            Expr::ListComprehension {
+                iterable_expr,
+                yield_expr,
+                source_span,
+                ..
+            }
+            | Expr::ListReduce {
+                iterable_expr,
+                yield_expr,
+                source_span,
+                ..
+            } => {
+                if !internal::is_bounded(iterable_expr) {
+                    let worker_calls = internal::worker_calls(yield_expr);
+
+                    if !worker_calls.is_empty() {
+                        warnings.push(RibWarning::new(
+                            RibWarningKind::UnboundedWorkerCalls,
+                            source_span.clone(),
+                            format!(
+                                "worker function {} called for each element of a list whose size is not known statically",
+                                worker_calls
+                                    .iter()
+                                    .map(|name| format!("`{name}`"))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    warnings.sort_by_key(|warning| {
+        (
+            warning.source_span.start_line(),
+            warning.source_span.start_column(),
+        )
+    });
+
+    warnings
+}
+
+mod internal {
+    use crate::{
+        ArmPattern, CallType, Expr, ExprVisitor, InstanceCreationType, InstanceIdentifier,
+        MatchArm, Range, VariableId,
+    };
+    use std::collections::HashSet;
+
+    pub(crate) fn used_variables(expr: &mut Expr) -> HashSet<VariableId> {
+        let mut used = HashSet::new();
+        let mut visitor = ExprVisitor::bottom_up(expr);
+
+        while let Some(expr) = visitor.pop_front() {
+            match expr {
+                Expr::Identifier { variable_id, .. } => {
+                    used.insert(variable_id.clone());
+                }
+                Expr::GenerateWorkerName {
+                    variable_id: Some(variable_id),
+                    ..
+                } => {
+                    used.insert(variable_id.clone());
+                }
+                Expr::Call { call_type, .. } => match call_type {
+                    CallType::Lambda(variable_id) => {
+                        used.insert(variable_id.clone());
+                    }
+                    CallType::Function {
+                        instance_identifier: Some(instance_identifier),
+                        ..
+                    } => {
+                        insert_instance_variable(instance_identifier, &mut used);
+                    }
+                    CallType::InstanceCreation(InstanceCreationType::WitResource {
+                        module: Some(module),
+                        ..
+                    }) => {
+                        insert_instance_variable(module, &mut used);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        used
+    }
+
+    fn insert_instance_variable(
+        instance_identifier: &InstanceIdentifier,
+        used: &mut HashSet<VariableId>,
+    ) {
+        match instance_identifier {
+            InstanceIdentifier::WitWorker {
+                variable_id: Some(variable_id),
+                ..
+            }
+            | InstanceIdentifier::WitResource {
+                variable_id: Some(variable_id),
+                ..
+            } => {
+                used.insert(variable_id.clone());
+            }
+            _ => {}
+        }
+    }
+
+    // The value of a condition that does not depend on any variable
+    pub(crate) fn constant_condition(expr: &Expr) -> Option<bool> {
+        match expr {
+            Expr::Boolean { value, .. } => Some(*value),
+            // A parenthesised condition
+            Expr::ExprBlock { exprs, .. } | Expr::Tuple { exprs, .. } if exprs.len() == 1 => {
+                constant_condition(&exprs[0])
+            }
+            Expr::Not { expr, .. } => constant_condition(expr).map(|value| !value),
+            Expr::And { lhs, rhs, .. } => {
+                match (constant_condition(lhs), constant_condition(rhs)) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }
+            }
+            Expr::Or { lhs, rhs, .. } => match (constant_condition(lhs), constant_condition(rhs)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            Expr::EqualTo { lhs, rhs, .. } => literals_equal(lhs, rhs),
+            Expr::NotEqualTo { lhs, rhs, .. } => literals_equal(lhs, rhs).map(|value| !value),
+            _ => None,
+        }
+    }
+
+    fn literals_equal(lhs: &Expr, rhs: &Expr) -> Option<bool> {
+        match (lhs, rhs) {
+            (Expr::Number { number: lhs, .. }, Expr::Number { number: rhs, .. }) => {
+                Some(lhs == rhs)
+            }
+            (Expr::Literal { value: lhs, .. }, Expr::Literal { value: rhs, .. }) => {
+                Some(lhs == rhs)
+            }
+            _ => constant_condition(lhs)
+                .zip(constant_condition(rhs))
+                .map(|(lhs, rhs)| lhs == rhs),
+        }
+    }
+
+    // Arms whose pattern is the same as the pattern of an earlier arm (ignoring `@` bindings),
+    // along with the index of that earlier arm. An arm that follows a wildcard is already a
+    // type error, and is not reported here.
+    pub(crate) fn unreachable_arms(match_arms: &[MatchArm]) -> Vec<(usize, usize)> {
+        let patterns = match_arms
+            .iter()
+            .map(|arm| strip_bindings(&arm.arm_pattern).to_string())
+            .collect::<Vec<_>>();
+
+        let mut unreachable = vec![];
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            if let Some(earlier_index) = patterns[..index].iter().position(|p| p == pattern) {
+                unreachable.push((index, earlier_index));
+            }
+        }
+
+        unreachable
+    }
+
+    fn strip_bindings(pattern: &ArmPattern) -> ArmPattern {
+        match pattern {
+            ArmPattern::As(_, pattern) => strip_bindings(pattern),
+            ArmPattern::Constructor(name, patterns) => {
+                ArmPattern::Constructor(name.clone(), patterns.iter().map(strip_bindings).collect())
+            }
+            ArmPattern::TupleConstructor(patterns) => {
+                ArmPattern::TupleConstructor(patterns.iter().map(strip_bindings).collect())
+            }
+            ArmPattern::ListConstructor(patterns) => {
+                ArmPattern::ListConstructor(patterns.iter().map(strip_bindings).collect())
+            }
+            ArmPattern::RecordConstructor(fields) => ArmPattern::RecordConstructor(
+                fields
+                    .iter()
+                    .map(|(name, pattern)| (name.clone(), strip_bindings(pattern)))
+                    .collect(),
+            ),
+            ArmPattern::WildCard | ArmPattern::Literal(_) => pattern.clone(),
+        }
+    }
+
+    // Whether the number of elements of an iterable is known from the Rib script itself
+    pub(crate) fn is_bounded(iterable_expr: &Expr) -> bool {
+        match iterable_expr {
+            Expr::Sequence { .. } => true,
+            Expr::Range { range, .. } => match range {
+                Range::Range { from, to } | Range::RangeInclusive { from, to } => {
+                    matches!(from.as_ref(), Expr::Number { .. })
+                        && matches!(to.as_ref(), Expr::Number { .. })
+                }
+                Range::RangeFrom { .. } => false,
+            },
+            _ => false,
+        }
+    }
+
+    pub(crate) fn worker_calls(expr: &Expr) -> Vec<String> {
+        let mut expr = expr.clone();
+        let mut worker_calls = vec![];
+        let mut visitor = ExprVisitor::bottom_up(&mut expr);
+
+        while let Some(expr) = visitor.pop_front() {
+            if let Expr::Call {
+                call_type: CallType::Function { function_name, .. },
+                ..
+            } = expr
+            {
+                let function_name = function_name.to_string();
+
+                if !worker_calls.contains(&function_name) {
+                    worker_calls.push(function_name);
+                }
+            }
+        }
+
+        worker_calls
+    }
+}
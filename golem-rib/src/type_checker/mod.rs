@@ -15,11 +15,14 @@
 pub(crate) use exhaustive_pattern_match::*;
 pub(crate) use unresolved_types::*;
 
+pub use lint::*;
 pub use path::*;
 mod exhaustive_pattern_match;
 mod invalid_function_args;
 mod invalid_function_calls;
+mod invalid_propagation;
 mod invalid_worker_name;
+mod lint;
 mod missing_fields;
 mod path;
 mod unresolved_types;
@@ -28,6 +31,7 @@ use crate::rib_type_error::RibTypeErrorInternal;
 use crate::type_checker::exhaustive_pattern_match::check_exhaustive_pattern_match;
 use crate::type_checker::invalid_function_args::check_invalid_function_args;
 use crate::type_checker::invalid_function_calls::check_invalid_function_calls;
+use crate::type_checker::invalid_propagation::check_invalid_propagation;
 use crate::type_checker::invalid_worker_name::check_invalid_worker_name;
 use crate::{ComponentDependencies, Expr};
 
@@ -40,5 +44,6 @@ pub fn type_check(
     check_invalid_worker_name(expr)?;
     check_exhaustive_pattern_match(expr, component_dependency)?;
     check_invalid_function_calls(expr)?;
+    check_invalid_propagation(expr)?;
     Ok(())
 }
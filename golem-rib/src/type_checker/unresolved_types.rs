@@ -115,6 +115,20 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                     return Err(UnResolvedTypesError::from(expr.source_span()));
                 }
             }
+            Expr::Map {
+                entries,
+                inferred_type,
+                ..
+            } => {
+                for (key, value) in entries {
+                    queue.push_back(key);
+                    queue.push_back(value);
+                }
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
             Expr::Tuple { exprs, .. } => {
                 internal::unresolved_types_in_tuple(exprs)?;
             }
@@ -235,6 +249,78 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                     return Err(UnResolvedTypesError::from(expr.source_span()));
                 }
             }
+            Expr::Modulo {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
+            Expr::BitwiseAnd {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
+            Expr::BitwiseOr {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
+            Expr::BitwiseXor {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
+            Expr::ShiftLeft {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
+            Expr::ShiftRight {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
             Expr::Divide {
                 lhs,
                 rhs,
@@ -295,6 +381,18 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                     return Err(UnResolvedTypesError::from(expr.source_span()));
                 }
             }
+            Expr::NotEqualTo {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                internal::unresolved_type_for_binary_op(lhs, rhs)?;
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
             Expr::LessThan {
                 lhs,
                 rhs,
@@ -395,6 +493,12 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                             )
                         }
                     },
+                    CallType::BuiltIn(function) => {
+                        format!("cannot determine the return type of the built-in function `{function}`")
+                    }
+                    CallType::Lambda(variable_id) => {
+                        format!("cannot determine the return type of the function `{variable_id}`")
+                    }
                 };
 
                 if inferred_type.is_unknown() {
@@ -431,6 +535,31 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                 }
             }
 
+            Expr::Lambda { body, .. } => {
+                queue.push_back(body);
+            }
+
+            Expr::Propagate {
+                expr,
+                inferred_type,
+                ..
+            } => {
+                queue.push_back(expr);
+
+                if inferred_type.is_unknown() {
+                    return Err(UnResolvedTypesError::from(expr.source_span()));
+                }
+            }
+
+            Expr::TryCatch {
+                try_expr,
+                catch_expr,
+                ..
+            } => {
+                queue.push_back(try_expr);
+                queue.push_back(catch_expr);
+            }
+
             Expr::ListReduce {
                 iterable_expr,
                 init_value_expr,
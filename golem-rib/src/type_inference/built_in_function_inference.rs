@@ -0,0 +1,358 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::call_type::CallType;
+use crate::{Expr, ExprVisitor, FunctionCallError};
+use std::collections::VecDeque;
+
+// Calls to built-in functions such as `string.lowercase(x)` are parsed as lazy method invocations
+// on a global identifier named after the module. These are converted to `Expr::Call` with `CallType::BuiltIn`.
+// This phase should run after the let bindings are given variable-ids, such that a local variable
+// that happens to share the name of a module is never mistaken for the module.
+pub fn identify_built_in_function_calls(expr: &mut Expr) -> Result<(), FunctionCallError> {
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        if let Some(call) = internal::get_built_in_function_call(expr)? {
+            *expr = call;
+        }
+
+        expr.visit_expr_nodes_lazy(&mut queue);
+    }
+
+    Ok(())
+}
+
+// Built-in functions can be generic (Example: `list.head`), and therefore the types
+// of the arguments and the result are refined along with the rest of the inference scan.
+pub fn infer_built_in_function_calls(expr: &mut Expr) -> Result<(), FunctionCallError> {
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        if let Expr::Call {
+            call_type: CallType::BuiltIn(function),
+            args,
+            inferred_type,
+            ..
+        } = expr
+        {
+            internal::infer_built_in_function_call(function, args, inferred_type)?;
+        }
+    }
+
+    Ok(())
+}
+
+mod internal {
+    use crate::call_type::CallType;
+    use crate::inferred_type::TypeOrigin;
+    use crate::type_inference::GetTypeHint;
+    use crate::{
+        ActualType, BuiltInFunction, BuiltInModule, BuiltInType, ExpectedType, Expr,
+        FunctionCallError, InferredType, TypeHint, TypeInternal, TypeMismatchError,
+    };
+
+    pub(crate) fn get_built_in_function_call(
+        expr: &Expr,
+    ) -> Result<Option<Expr>, FunctionCallError> {
+        let Expr::InvokeMethodLazy {
+            lhs,
+            method,
+            generic_type_parameter,
+            args,
+            type_annotation,
+            inferred_type,
+            source_span,
+        } = expr
+        else {
+            return Ok(None);
+        };
+
+        let module = match lhs.as_ref() {
+            Expr::Identifier {
+                variable_id,
+                inferred_type,
+                ..
+            } if variable_id.is_global() && inferred_type.is_unknown() => {
+                match BuiltInModule::from_name(variable_id.name().as_str()) {
+                    Some(module) => module,
+                    None => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+
+        let qualified_name = format!("{module}.{method}");
+
+        let function = BuiltInFunction::lookup(module.name(), method).ok_or_else(|| {
+            let available = module
+                .functions()
+                .iter()
+                .map(|function| function.name())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            FunctionCallError::invalid_function_call(
+                &qualified_name,
+                source_span.clone(),
+                format!(
+                    "unknown built-in function. available functions in `{module}`: {available}"
+                ),
+            )
+        })?;
+
+        if let Some(generic_type_parameter) = generic_type_parameter {
+            return Err(FunctionCallError::invalid_generic_type_parameter(
+                &generic_type_parameter.value,
+                "built-in functions do not accept type parameters",
+                source_span.clone(),
+            ));
+        }
+
+        let arity = function.signature().arity();
+
+        if arity != args.len() {
+            return Err(FunctionCallError::ArgumentSizeMisMatch {
+                function_name: qualified_name,
+                source_span: source_span.clone(),
+                expected: arity,
+                provided: args.len(),
+            });
+        }
+
+        Ok(Some(Expr::Call {
+            call_type: CallType::BuiltIn(function),
+            generic_type_parameter: None,
+            args: args.clone(),
+            type_annotation: type_annotation.clone(),
+            inferred_type: inferred_type.clone(),
+            source_span: source_span.clone(),
+        }))
+    }
+
+    pub(crate) fn infer_built_in_function_call(
+        function: &BuiltInFunction,
+        args: &mut [Expr],
+        inferred_type: &mut InferredType,
+    ) -> Result<(), FunctionCallError> {
+        let signature = function.signature();
+
+        let arg_types = args
+            .iter()
+            .map(|arg| arg.inferred_type())
+            .collect::<Vec<_>>();
+
+        for (arg, parameter) in args.iter_mut().zip(&signature.parameters) {
+            match parameter {
+                BuiltInType::AnyOf(type_hint) => check_type_kind(function, arg, type_hint)?,
+                parameter => {
+                    if let Some(parameter_type) = resolve_type(parameter, &arg_types) {
+                        arg.add_infer_type_mut(
+                            parameter_type.add_origin(TypeOrigin::Declared(arg.source_span())),
+                        );
+                    }
+                }
+            }
+        }
+
+        // Arguments that are expected to have the same type refine each other
+        for (index, parameter) in signature.parameters.iter().enumerate() {
+            if let BuiltInType::SameAs(other) = parameter {
+                if let Some(arg_type) = arg_types[index].known_type() {
+                    refine_arg_type(args, *other, arg_type);
+                }
+            }
+        }
+
+        if let Some(result_type) = resolve_type(&signature.return_type, &arg_types) {
+            *inferred_type = inferred_type.merge(result_type);
+        }
+
+        // The expected type of the call flows back to the argument it is derived from
+        if let BuiltInType::SameAs(index) = signature.return_type {
+            if let Some(result_type) = inferred_type.known_type() {
+                refine_arg_type(args, index, result_type);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn refine_arg_type(args: &mut [Expr], index: usize, inferred_type: InferredType) {
+        if let Some(arg) = args.get_mut(index) {
+            arg.add_infer_type_mut(inferred_type);
+        }
+    }
+
+    fn resolve_type(
+        built_in_type: &BuiltInType,
+        arg_types: &[InferredType],
+    ) -> Option<InferredType> {
+        match built_in_type {
+            BuiltInType::Type(analysed_type) => Some(InferredType::from(analysed_type)),
+            BuiltInType::AnyOf(_) => None,
+            BuiltInType::SameAs(index) => arg_types.get(*index).and_then(InferredType::known_type),
+            BuiltInType::InnerOf(index) => arg_types.get(*index).and_then(inner_type),
+            BuiltInType::OptionOfInner(index) => arg_types
+                .get(*index)
+                .and_then(inner_type)
+                .map(InferredType::option),
+            BuiltInType::KeyOf(index) => arg_types.get(*index).and_then(key_type),
+            BuiltInType::KeysOf(index) => arg_types
+                .get(*index)
+                .and_then(key_type)
+                .map(InferredType::list),
+            BuiltInType::ValuesOf(index) => arg_types
+                .get(*index)
+                .and_then(inner_type)
+                .map(InferredType::list),
+        }
+    }
+
+    fn inner_type(inferred_type: &InferredType) -> Option<InferredType> {
+        let inner = match inferred_type.internal_type() {
+            TypeInternal::List(inner) | TypeInternal::Option(inner) => inner.clone(),
+            TypeInternal::Result { ok: Some(ok), .. } => ok.clone(),
+            TypeInternal::Map { value, .. } => value.clone(),
+            _ => return None,
+        };
+
+        inner.known_type()
+    }
+
+    fn key_type(inferred_type: &InferredType) -> Option<InferredType> {
+        match inferred_type.internal_type() {
+            TypeInternal::Map { key, .. } => key.known_type(),
+            _ => None,
+        }
+    }
+
+    fn check_type_kind(
+        function: &BuiltInFunction,
+        arg: &Expr,
+        expected: &TypeHint,
+    ) -> Result<(), FunctionCallError> {
+        let actual = arg.inferred_type();
+
+        if actual.is_unknown() || actual.is_all_of() {
+            return Ok(());
+        }
+
+        if actual.get_type_hint().get_type_kind() == expected.get_type_kind() {
+            Ok(())
+        } else {
+            Err(FunctionCallError::TypeMisMatch {
+                function_name: function.to_string(),
+                argument_source_span: arg.source_span(),
+                error: TypeMismatchError {
+                    source_span: arg.source_span(),
+                    expected_type: ExpectedType::Hint(expected.clone()),
+                    actual_type: ActualType::Inferred(actual),
+                    field_path: Default::default(),
+                    additional_error_detail: vec![],
+                },
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::call_type::CallType;
+    use crate::{BuiltInFunction, Expr, InferredType, RibCompiler};
+
+    #[test]
+    fn test_identify_built_in_function_call() {
+        let rib_expr = r#"
+          let x = "Foo";
+          string.lowercase(x)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+
+        let Expr::ExprBlock { exprs, .. } = inferred_expr.get_expr() else {
+            panic!("expected an expression block");
+        };
+
+        match exprs.last().unwrap() {
+            Expr::Call {
+                call_type: CallType::BuiltIn(function),
+                inferred_type,
+                ..
+            } => {
+                assert_eq!(function, &BuiltInFunction::StringLowercase);
+                assert_eq!(inferred_type, &InferredType::string());
+            }
+            expr => panic!("expected a built-in function call, found {expr}"),
+        }
+    }
+
+    #[test]
+    fn test_local_variable_shadows_built_in_module() {
+        let rib_expr = r#"
+          let string = "foo";
+          string.lowercase("x")
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let result = RibCompiler::default().infer_types(expr);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generic_built_in_function_result_type() {
+        let rib_expr = r#"
+          let xs: list<u32> = [1, 2, 3];
+          let first = list.head(xs);
+          option.unwrap-or(first, 0)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+
+        assert_eq!(
+            inferred_expr.get_expr().inferred_type(),
+            InferredType::u32()
+        );
+    }
+
+    #[test]
+    fn test_unknown_built_in_function() {
+        let expr = Expr::from_text(r#"string.shout("foo")"#).unwrap();
+        let error = RibCompiler::default().infer_types(expr).unwrap_err();
+
+        assert!(error.to_string().contains("string.shout"));
+    }
+
+    #[test]
+    fn test_built_in_function_argument_size_mismatch() {
+        let expr = Expr::from_text(r#"string.contains("foo")"#).unwrap();
+        let result = RibCompiler::default().infer_types(expr);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_built_in_function_argument_kind_mismatch() {
+        let expr = Expr::from_text(r#"list.length("foo")"#).unwrap();
+        let result = RibCompiler::default().infer_types(expr);
+
+        assert!(result.is_err());
+    }
+}
@@ -139,6 +139,11 @@ mod internal {
                 }
             }
 
+            // Built-in functions can be generic over their arguments,
+            // and are therefore inferred as part of the inference scan.
+            // The same applies to lambdas whose parameter types are inferred from their usages.
+            CallType::BuiltIn(_) | CallType::Lambda(_) => Ok(()),
+
             CallType::VariantConstructor(variant_name) => {
                 let function_name = FunctionName::Variant(variant_name.clone());
                 infer_args_and_result_type(
@@ -93,11 +93,26 @@ fn enqueue_expr_top_down(expr: &mut Expr, queue: &mut VecDeque<&mut Expr>) {
             Expr::Record { exprs, .. } => {
                 stack.extend(exprs.iter_mut().map(|(_, expr)| &mut **expr as *mut Expr))
             }
+            Expr::Map { entries, .. } => stack.extend(
+                entries
+                    .iter_mut()
+                    .flat_map(|(key, value)| [key as *mut Expr, value as *mut Expr]),
+            ),
             Expr::Tuple { exprs, .. } => stack.extend(exprs.iter_mut().map(|x| x as *mut Expr)),
             Expr::Concat { exprs, .. } => stack.extend(exprs.iter_mut().map(|x| x as *mut Expr)),
             Expr::ExprBlock { exprs, .. } => stack.extend(exprs.iter_mut().map(|x| x as *mut Expr)),
             Expr::Not { expr, .. } => stack.push_front(&mut **expr),
             Expr::Length { expr, .. } => stack.push_front(&mut **expr),
+            Expr::Lambda { body, .. } => stack.push_front(&mut **body),
+            Expr::Propagate { expr, .. } => stack.push_front(&mut **expr),
+            Expr::TryCatch {
+                try_expr,
+                catch_expr,
+                ..
+            } => {
+                stack.push_front(&mut **try_expr);
+                stack.push_front(&mut **catch_expr);
+            }
             Expr::GreaterThan { lhs, rhs, .. } => {
                 stack.push_front(&mut **lhs);
                 stack.push_front(&mut **rhs);
@@ -114,6 +129,10 @@ fn enqueue_expr_top_down(expr: &mut Expr, queue: &mut VecDeque<&mut Expr>) {
                 stack.push_front(&mut **lhs);
                 stack.push_front(&mut **rhs);
             }
+            Expr::NotEqualTo { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
             Expr::Plus { lhs, rhs, .. } => {
                 stack.push_front(&mut **lhs);
                 stack.push_front(&mut **rhs);
@@ -130,6 +149,30 @@ fn enqueue_expr_top_down(expr: &mut Expr, queue: &mut VecDeque<&mut Expr>) {
                 stack.push_front(&mut **lhs);
                 stack.push_front(&mut **rhs);
             }
+            Expr::Modulo { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
+            Expr::BitwiseAnd { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
+            Expr::BitwiseOr { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
+            Expr::BitwiseXor { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
+            Expr::ShiftLeft { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
+            Expr::ShiftRight { lhs, rhs, .. } => {
+                stack.push_front(&mut **lhs);
+                stack.push_front(&mut **rhs);
+            }
             Expr::LessThan { lhs, rhs, .. } => {
                 stack.push_front(&mut **lhs);
                 stack.push_front(&mut **rhs);
@@ -282,11 +325,26 @@ fn enqueue_expr_bottom_up(expr: &mut Expr, queue: &mut VecDeque<&mut Expr>) {
             Expr::Record { exprs, .. } => {
                 stack.extend(exprs.iter_mut().map(|(_, expr)| &mut **expr as *mut Expr))
             }
+            Expr::Map { entries, .. } => stack.extend(
+                entries
+                    .iter_mut()
+                    .flat_map(|(key, value)| [key as *mut Expr, value as *mut Expr]),
+            ),
             Expr::Tuple { exprs, .. } => stack.extend(exprs.iter_mut().map(|x| x as *mut Expr)),
             Expr::Concat { exprs, .. } => stack.extend(exprs.iter_mut().map(|x| x as *mut Expr)),
             Expr::ExprBlock { exprs, .. } => stack.extend(exprs.iter_mut().map(|x| x as *mut Expr)),
             Expr::Not { expr, .. } => stack.push_back(&mut **expr),
             Expr::Length { expr, .. } => stack.push_back(&mut **expr),
+            Expr::Lambda { body, .. } => stack.push_back(&mut **body),
+            Expr::Propagate { expr, .. } => stack.push_back(&mut **expr),
+            Expr::TryCatch {
+                try_expr,
+                catch_expr,
+                ..
+            } => {
+                stack.push_back(&mut **try_expr);
+                stack.push_back(&mut **catch_expr);
+            }
             Expr::GreaterThan { lhs, rhs, .. } => {
                 stack.push_back(&mut **lhs);
                 stack.push_back(&mut **rhs);
@@ -303,6 +361,10 @@ fn enqueue_expr_bottom_up(expr: &mut Expr, queue: &mut VecDeque<&mut Expr>) {
                 stack.push_back(&mut **lhs);
                 stack.push_back(&mut **rhs);
             }
+            Expr::NotEqualTo { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
             Expr::Plus { lhs, rhs, .. } => {
                 stack.push_back(&mut **lhs);
                 stack.push_back(&mut **rhs);
@@ -319,6 +381,30 @@ fn enqueue_expr_bottom_up(expr: &mut Expr, queue: &mut VecDeque<&mut Expr>) {
                 stack.push_back(&mut **lhs);
                 stack.push_back(&mut **rhs);
             }
+            Expr::Modulo { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
+            Expr::BitwiseAnd { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
+            Expr::BitwiseOr { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
+            Expr::BitwiseXor { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
+            Expr::ShiftLeft { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
+            Expr::ShiftRight { lhs, rhs, .. } => {
+                stack.push_back(&mut **lhs);
+                stack.push_back(&mut **rhs);
+            }
             Expr::LessThan { lhs, rhs, .. } => {
                 stack.push_back(&mut **lhs);
                 stack.push_back(&mut **rhs);
@@ -458,11 +544,24 @@ pub fn visit_expr_nodes_lazy<'a>(expr: &'a mut Expr, queue: &mut VecDeque<&'a mu
         }
         Expr::Sequence { exprs, .. } => queue.extend(exprs.iter_mut()),
         Expr::Record { exprs, .. } => queue.extend(exprs.iter_mut().map(|(_, expr)| &mut **expr)),
+        Expr::Map { entries, .. } => {
+            queue.extend(entries.iter_mut().flat_map(|(key, value)| [key, value]))
+        }
         Expr::Tuple { exprs, .. } => queue.extend(exprs.iter_mut()),
         Expr::Concat { exprs, .. } => queue.extend(exprs.iter_mut()),
         Expr::ExprBlock { exprs, .. } => queue.extend(exprs.iter_mut()), // let x = 1, y = call(x);
         Expr::Not { expr, .. } => queue.push_back(&mut *expr),
         Expr::Length { expr, .. } => queue.push_back(&mut *expr),
+        Expr::Lambda { body, .. } => queue.push_back(&mut *body),
+        Expr::Propagate { expr, .. } => queue.push_back(&mut *expr),
+        Expr::TryCatch {
+            try_expr,
+            catch_expr,
+            ..
+        } => {
+            queue.push_back(&mut *try_expr);
+            queue.push_back(&mut *catch_expr);
+        }
         Expr::GreaterThan { lhs, rhs, .. } => {
             queue.push_back(&mut *lhs);
             queue.push_back(&mut *rhs);
@@ -479,6 +578,10 @@ pub fn visit_expr_nodes_lazy<'a>(expr: &'a mut Expr, queue: &mut VecDeque<&'a mu
             queue.push_back(&mut *lhs);
             queue.push_back(&mut *rhs);
         }
+        Expr::NotEqualTo { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
         Expr::Plus { lhs, rhs, .. } => {
             queue.push_back(&mut *lhs);
             queue.push_back(&mut *rhs);
@@ -495,6 +598,30 @@ pub fn visit_expr_nodes_lazy<'a>(expr: &'a mut Expr, queue: &mut VecDeque<&'a mu
             queue.push_back(&mut *lhs);
             queue.push_back(&mut *rhs);
         }
+        Expr::Modulo { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
+        Expr::BitwiseAnd { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
+        Expr::BitwiseOr { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
+        Expr::BitwiseXor { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
+        Expr::ShiftLeft { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
+        Expr::ShiftRight { lhs, rhs, .. } => {
+            queue.push_back(&mut *lhs);
+            queue.push_back(&mut *rhs);
+        }
         Expr::LessThan { lhs, rhs, .. } => {
             queue.push_back(&mut *lhs);
             queue.push_back(&mut *rhs);
@@ -644,6 +771,8 @@ mod internal {
 
             CallType::VariantConstructor(_) => (None, None),
             CallType::EnumConstructor(_) => (None, None),
+            CallType::BuiltIn(_) => (None, None),
+            CallType::Lambda(_) => (None, None),
         }
     }
 }
@@ -184,6 +184,8 @@ mod internal {
             }
             CallType::VariantConstructor(_) => Ok(None),
             CallType::EnumConstructor(_) => Ok(None),
+            CallType::BuiltIn(_) => Ok(None),
+            CallType::Lambda(_) => Ok(None),
         }
     }
 }
@@ -144,6 +144,27 @@ fn compare_inferred_types_internal(left: &TypeInternal, right: &TypeInternal, bo
             compare_inferred_types_internal(left.internal_type(), right.internal_type(), true)
         }
 
+        (
+            TypeInternal::Map {
+                key: left_key,
+                value: left_value,
+            },
+            TypeInternal::Map {
+                key: right_key,
+                value: right_value,
+            },
+        ) => {
+            compare_inferred_types_internal(
+                left_key.internal_type(),
+                right_key.internal_type(),
+                true,
+            ) && compare_inferred_types_internal(
+                left_value.internal_type(),
+                right_value.internal_type(),
+                true,
+            )
+        }
+
         (
             TypeInternal::Result {
                 ok: left_ok,
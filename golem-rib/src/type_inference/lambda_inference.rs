@@ -0,0 +1,557 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::call_type::CallType;
+use crate::{Expr, ExprVisitor, FunctionCallError, InferredType, VariableId};
+use std::collections::{HashMap, VecDeque};
+
+// Higher-order functions such as `list.map(xs, |x| x + 1)` are parsed as lazy method invocations
+// on the global identifier `list`, and they are desugared into list comprehensions and list reductions.
+// The lambda passed to them is inlined, while a function declared with `fn` is called for every element.
+// This phase should run after the variables are bound, and before the built-in function calls are identified.
+pub fn identify_higher_order_function_calls(expr: &mut Expr) -> Result<(), FunctionCallError> {
+    let lambda_arities = internal::get_lambda_arities(expr);
+    let mut fresh_variable_index = 0;
+
+    {
+        let mut queue = VecDeque::new();
+        queue.push_back(&mut *expr);
+
+        while let Some(expr) = queue.pop_back() {
+            if let Some(desugared) = internal::desugar_higher_order_function_call(
+                expr,
+                &lambda_arities,
+                &mut fresh_variable_index,
+            )? {
+                *expr = desugared;
+            }
+
+            expr.visit_expr_nodes_lazy(&mut queue);
+        }
+    }
+
+    internal::check_lambda_usages(expr, &lambda_arities)
+}
+
+// The types of the parameters and the return type of a lambda are inferred from its body
+// as well as from all of its calls, and are propagated to each other in every cycle of the scan.
+pub fn infer_lambda_calls(expr: &mut Expr) {
+    let mut parameters = HashMap::new();
+    let mut parameter_types: HashMap<VariableId, InferredType> = HashMap::new();
+    let mut body_types = HashMap::new();
+
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        match expr {
+            Expr::Let {
+                variable_id, expr, ..
+            } => {
+                if let Expr::Lambda {
+                    parameters: lambda_parameters,
+                    body,
+                    ..
+                } = expr.as_ref()
+                {
+                    let parameter_ids = lambda_parameters
+                        .iter()
+                        .map(|parameter| parameter.variable_id.clone())
+                        .collect::<Vec<_>>();
+
+                    parameters.insert(variable_id.clone(), parameter_ids);
+                    body_types.insert(variable_id.clone(), body.inferred_type());
+                }
+            }
+
+            Expr::Identifier {
+                variable_id,
+                inferred_type,
+                ..
+            } if variable_id.is_lambda_parameter() => {
+                internal::merge_known_type(&mut parameter_types, variable_id, inferred_type);
+            }
+
+            _ => {}
+        }
+    }
+
+    let mut argument_types = HashMap::new();
+    let mut call_types = HashMap::new();
+
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        if let Expr::Call {
+            call_type: CallType::Lambda(variable_id),
+            args,
+            inferred_type,
+            ..
+        } = expr
+        {
+            let Some(parameter_ids) = parameters.get(variable_id) else {
+                continue;
+            };
+
+            for (arg, parameter_id) in args.iter_mut().zip(parameter_ids) {
+                if let Some(parameter_type) = parameter_types.get(parameter_id) {
+                    arg.add_infer_type_mut(parameter_type.clone());
+                }
+
+                internal::merge_known_type(&mut argument_types, parameter_id, &arg.inferred_type());
+            }
+
+            if let Some(body_type) = body_types
+                .get(variable_id)
+                .and_then(InferredType::known_type)
+            {
+                *inferred_type = inferred_type.merge(body_type);
+            }
+
+            internal::merge_known_type(&mut call_types, variable_id, inferred_type);
+        }
+    }
+
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        match expr {
+            Expr::Identifier { variable_id, .. } if variable_id.is_lambda_parameter() => {
+                if let Some(argument_type) = argument_types.get(variable_id).cloned() {
+                    expr.add_infer_type_mut(argument_type);
+                }
+            }
+
+            Expr::Let {
+                variable_id, expr, ..
+            } => {
+                if let (Expr::Lambda { body, .. }, Some(call_type)) =
+                    (expr.as_mut(), call_types.get(variable_id))
+                {
+                    body.add_infer_type_mut(call_type.clone());
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+mod internal {
+    use crate::call_type::CallType;
+    use crate::{BuiltInFunction, Expr, ExprVisitor, FunctionCallError, InferredType, VariableId};
+    use std::collections::{HashMap, VecDeque};
+
+    pub(crate) fn get_lambda_arities(expr: &mut Expr) -> HashMap<VariableId, usize> {
+        let mut lambda_arities = HashMap::new();
+        let mut visitor = ExprVisitor::bottom_up(expr);
+
+        while let Some(expr) = visitor.pop_front() {
+            if let Expr::Let {
+                variable_id, expr, ..
+            } = expr
+            {
+                if let Expr::Lambda { parameters, .. } = expr.as_ref() {
+                    lambda_arities.insert(variable_id.clone(), parameters.len());
+                }
+            }
+        }
+
+        lambda_arities
+    }
+
+    pub(crate) fn desugar_higher_order_function_call(
+        expr: &Expr,
+        lambda_arities: &HashMap<VariableId, usize>,
+        fresh_variable_index: &mut usize,
+    ) -> Result<Option<Expr>, FunctionCallError> {
+        let Expr::InvokeMethodLazy {
+            lhs,
+            method,
+            args,
+            inferred_type,
+            source_span,
+            ..
+        } = expr
+        else {
+            return Ok(None);
+        };
+
+        match lhs.as_ref() {
+            Expr::Identifier {
+                variable_id,
+                inferred_type,
+                ..
+            } if variable_id.is_global()
+                && variable_id.name() == "list"
+                && inferred_type.is_unknown() => {}
+            _ => return Ok(None),
+        }
+
+        let function_name = format!("list.{method}");
+
+        let expected_arity = match method.as_str() {
+            "map" | "filter" => 2,
+            "fold" => 3,
+            _ => return Ok(None),
+        };
+
+        if args.len() != expected_arity {
+            return Err(FunctionCallError::ArgumentSizeMisMatch {
+                function_name,
+                source_span: source_span.clone(),
+                expected: expected_arity,
+                provided: args.len(),
+            });
+        }
+
+        *fresh_variable_index += 1;
+
+        let item =
+            VariableId::list_comprehension_identifier(format!("_item{fresh_variable_index}"));
+        let accumulator = VariableId::list_reduce_identifier(format!("_acc{fresh_variable_index}"));
+
+        let mut desugared = match method.as_str() {
+            // list.map(xs, f) is `for item in xs { yield f(item); }`
+            "map" => {
+                let yield_expr =
+                    apply(&function_name, &args[1], vec![item.clone()], lambda_arities)?;
+
+                Expr::list_comprehension(item, args[0].clone(), yield_expr)
+            }
+
+            // list.filter(xs, f) is `list.flatten(for item in xs { yield if f(item) then [item] else []; })`,
+            // which unlike a reduce appending to the accumulator doesn't copy it at each step
+            "filter" => {
+                let predicate =
+                    apply(&function_name, &args[1], vec![item.clone()], lambda_arities)?;
+
+                let yield_expr = Expr::cond(
+                    predicate,
+                    Expr::sequence(
+                        vec![Expr::identifier_with_variable_id(item.clone(), None)],
+                        None,
+                    ),
+                    Expr::sequence(vec![], None),
+                );
+
+                Expr::call(
+                    CallType::BuiltIn(BuiltInFunction::ListFlatten),
+                    None,
+                    vec![Expr::list_comprehension(item, args[0].clone(), yield_expr)],
+                )
+            }
+
+            // list.fold(xs, init, f) is `reduce acc, item in xs from init { yield f(acc, item); }`
+            _ => {
+                let yield_expr = apply(
+                    &function_name,
+                    &args[2],
+                    vec![accumulator.clone(), item.clone()],
+                    lambda_arities,
+                )?;
+
+                Expr::list_reduce(
+                    accumulator,
+                    item,
+                    args[0].clone(),
+                    args[1].clone(),
+                    yield_expr,
+                )
+            }
+        };
+
+        desugared.add_infer_type_mut(inferred_type.clone());
+        desugared.with_source_span_mut(source_span.clone());
+
+        Ok(Some(desugared))
+    }
+
+    // Applying a lambda inlines its body with the parameters replaced by the given variables,
+    // while applying a function declared with `fn` results in a call to it
+    fn apply(
+        function_name: &str,
+        function: &Expr,
+        variables: Vec<VariableId>,
+        lambda_arities: &HashMap<VariableId, usize>,
+    ) -> Result<Expr, FunctionCallError> {
+        match function {
+            Expr::Lambda {
+                parameters,
+                body,
+                source_span,
+                ..
+            } => {
+                if parameters.len() != variables.len() {
+                    return Err(FunctionCallError::ArgumentSizeMisMatch {
+                        function_name: format!("the function passed to {function_name}"),
+                        source_span: source_span.clone(),
+                        expected: variables.len(),
+                        provided: parameters.len(),
+                    });
+                }
+
+                let mut body = body.as_ref().clone();
+
+                for (parameter, variable) in parameters.iter().zip(variables) {
+                    rename_identifiers(&mut body, &parameter.variable_id, &variable);
+                }
+
+                Ok(body)
+            }
+
+            Expr::Identifier {
+                variable_id,
+                source_span,
+                ..
+            } if lambda_arities.contains_key(variable_id) => {
+                let arity = lambda_arities[variable_id];
+
+                if arity != variables.len() {
+                    return Err(FunctionCallError::ArgumentSizeMisMatch {
+                        function_name: variable_id.name(),
+                        source_span: source_span.clone(),
+                        expected: arity,
+                        provided: variables.len(),
+                    });
+                }
+
+                let args = variables
+                    .into_iter()
+                    .map(|variable| Expr::identifier_with_variable_id(variable, None))
+                    .collect();
+
+                Ok(
+                    Expr::call(CallType::Lambda(variable_id.clone()), None, args)
+                        .with_source_span(source_span.clone()),
+                )
+            }
+
+            expr => Err(FunctionCallError::invalid_function_call(
+                function_name,
+                expr.source_span(),
+                format!("expected a function or a closure such as `|x| x`, found `{expr}`"),
+            )),
+        }
+    }
+
+    fn rename_identifiers(expr: &mut Expr, from: &VariableId, to: &VariableId) {
+        let mut visitor = ExprVisitor::bottom_up(expr);
+
+        while let Some(expr) = visitor.pop_front() {
+            if let Expr::Identifier { variable_id, .. } = expr {
+                if variable_id == from {
+                    *variable_id = to.clone();
+                }
+            }
+        }
+    }
+
+    // Lambdas are not values. They can only be bound to a name to be called later,
+    // or be passed to the higher-order built-in functions, which are desugared by now.
+    pub(crate) fn check_lambda_usages(
+        expr: &mut Expr,
+        lambda_arities: &HashMap<VariableId, usize>,
+    ) -> Result<(), FunctionCallError> {
+        let mut queue = VecDeque::new();
+        queue.push_back(expr);
+
+        while let Some(expr) = queue.pop_back() {
+            if let Expr::Call {
+                call_type: CallType::Lambda(variable_id),
+                args,
+                source_span,
+                ..
+            } = &*expr
+            {
+                let arity = lambda_arities.get(variable_id).copied().unwrap_or_default();
+
+                if arity != args.len() {
+                    return Err(FunctionCallError::ArgumentSizeMisMatch {
+                        function_name: variable_id.name(),
+                        source_span: source_span.clone(),
+                        expected: arity,
+                        provided: args.len(),
+                    });
+                }
+            }
+
+            let is_function_binding = matches!(
+                &*expr,
+                Expr::Let { variable_id, .. } if lambda_arities.contains_key(variable_id)
+            );
+
+            if is_function_binding {
+                if let Expr::Let { expr, .. } = expr {
+                    if let Expr::Lambda { body, .. } = &mut **expr {
+                        queue.push_back(body);
+                    }
+                }
+
+                continue;
+            }
+
+            match expr {
+                Expr::Lambda { source_span, .. } => {
+                    return Err(FunctionCallError::invalid_function_call(
+                        "closure",
+                        source_span.clone(),
+                        "a closure can only be bound to a variable, or passed to `list.map`, `list.filter` or `list.fold`",
+                    ));
+                }
+
+                Expr::Identifier {
+                    variable_id,
+                    source_span,
+                    ..
+                } if lambda_arities.contains_key(variable_id) => {
+                    return Err(FunctionCallError::invalid_function_call(
+                        &variable_id.name(),
+                        source_span.clone(),
+                        "a function can only be called, or passed to `list.map`, `list.filter` or `list.fold`",
+                    ));
+                }
+
+                expr => expr.visit_expr_nodes_lazy(&mut queue),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn merge_known_type(
+        types: &mut HashMap<VariableId, InferredType>,
+        variable_id: &VariableId,
+        inferred_type: &InferredType,
+    ) {
+        if let Some(inferred_type) = inferred_type.known_type() {
+            types
+                .entry(variable_id.clone())
+                .and_modify(|existing| *existing = existing.merge(inferred_type.clone()))
+                .or_insert(inferred_type);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::call_type::CallType;
+    use crate::{Expr, InferredType, RibCompiler};
+
+    #[test]
+    fn test_infer_function_definition() {
+        let rib_expr = r#"
+          fn add(x: u32, y) { x + y };
+          add(1, 2)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+
+        let Expr::ExprBlock { exprs, .. } = inferred_expr.get_expr() else {
+            panic!("expected an expression block");
+        };
+
+        match exprs.last().unwrap() {
+            Expr::Call {
+                call_type: CallType::Lambda(variable_id),
+                inferred_type,
+                ..
+            } => {
+                assert_eq!(variable_id.name(), "add");
+                assert_eq!(inferred_type, &InferredType::u32());
+            }
+            expr => panic!("expected a call to a lambda, found {expr}"),
+        }
+    }
+
+    #[test]
+    fn test_infer_return_type_from_call_site() {
+        let rib_expr = r#"
+          fn identity(x) { x };
+          let result: string = identity("foo");
+          result
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+
+        assert_eq!(
+            inferred_expr.get_expr().inferred_type(),
+            InferredType::string()
+        );
+    }
+
+    #[test]
+    fn test_infer_higher_order_function_calls() {
+        let rib_expr = r#"
+          let xs: list<u32> = [1, 2, 3];
+          let ys = list.map(xs, |x| x * 2);
+          list.fold(ys, 0, |acc, y| acc + y)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+
+        assert_eq!(
+            inferred_expr.get_expr().inferred_type(),
+            InferredType::u32()
+        );
+    }
+
+    #[test]
+    fn test_infer_filtered_list() {
+        let rib_expr = r#"
+          let xs: list<u32> = [1, 2, 3];
+          list.filter(xs, |x| x > 1)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+
+        assert_eq!(
+            inferred_expr.get_expr().inferred_type(),
+            InferredType::list(InferredType::u32())
+        );
+    }
+
+    #[test]
+    fn test_lambda_argument_size_mismatch() {
+        let rib_expr = r#"
+          fn add(x: u32, y: u32) { x + y };
+          add(1)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let result = RibCompiler::default().infer_types(expr);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_closure_is_not_a_value() {
+        let rib_expr = r#"
+          let xs: list<u32> = [1, 2, 3];
+          let f = |x| x + 1;
+          let g = f;
+          list.map(xs, g)
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+        let result = RibCompiler::default().infer_types(expr);
+
+        assert!(result.is_err());
+    }
+}
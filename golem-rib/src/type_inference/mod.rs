@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use built_in_function_inference::*;
 pub use call_arguments_inference::*;
 pub use enum_inference::*;
 pub use errors::*;
@@ -24,6 +25,7 @@ pub use index_selection_type_binding::*;
 pub use inference_fix_point::*;
 pub use inferred_expr::*;
 pub use instance_type_binding::*;
+pub use lambda_inference::*;
 pub use rib_input_type::*;
 pub use rib_output_type::*;
 pub use stateful_instance::*;
@@ -37,6 +39,7 @@ pub use variable_binding::*;
 pub use variant_inference::*;
 pub use worker_function_invocation::*;
 
+mod built_in_function_inference;
 mod call_arguments_inference;
 mod enum_inference;
 mod errors;
@@ -49,6 +52,7 @@ mod index_selection_type_binding;
 mod inference_fix_point;
 mod inferred_expr;
 mod instance_type_binding;
+mod lambda_inference;
 mod rib_input_type;
 mod rib_output_type;
 mod stateful_instance;
@@ -2294,6 +2298,27 @@ mod tests {
         assert_eq!(inferred_expr.get_expr(), &expected);
     }
 
+    #[test]
+    fn test_inference_list_aggregation_from_empty_list() {
+        let rib_expr = r#"
+           let ages: list<u64> = [1, 2, 3];
+           reduce z, a in ages from [] {
+              yield [a];
+           }
+        "#;
+
+        let expr = Expr::from_text(rib_expr).unwrap();
+
+        let compiler = RibCompiler::default();
+
+        let inferred_expr = compiler.infer_types(expr).unwrap();
+
+        assert_eq!(
+            inferred_expr.get_expr().inferred_type(),
+            InferredType::list(InferredType::u64())
+        );
+    }
+
     #[test]
     fn test_inference_list_comprehension() {
         let rib_expr = r#"
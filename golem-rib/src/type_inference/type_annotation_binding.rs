@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Expr, InferredType};
+use crate::{Expr, ExprVisitor, InferredType, RibRuntimeError};
 use std::collections::VecDeque;
 
 pub fn bind_type_annotations(expr: &mut Expr) {
@@ -37,6 +37,73 @@ pub fn bind_type_annotations(expr: &mut Expr) {
                 queue.push_back(rhs);
             }
 
+            // The parameters and the return type of a lambda are annotations
+            // on the identifiers and the body of the lambda respectively
+            Expr::Lambda {
+                parameters,
+                return_type,
+                body,
+                source_span,
+                ..
+            } => {
+                for parameter in parameters.iter() {
+                    if let Some(type_annotation) = &parameter.type_annotation {
+                        let new_inferred_type =
+                            InferredType::from(type_annotation).declared_at(source_span.clone());
+
+                        let mut visitor = ExprVisitor::bottom_up(body);
+
+                        while let Some(expr) = visitor.pop_front() {
+                            if let Expr::Identifier { variable_id, .. } = expr {
+                                if variable_id == &parameter.variable_id {
+                                    expr.with_inferred_type_mut(new_inferred_type.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(return_type) = return_type {
+                    let new_inferred_type =
+                        InferredType::from(&*return_type).declared_at(source_span.clone());
+
+                    body.with_inferred_type_mut(new_inferred_type)
+                }
+
+                queue.push_back(body);
+            }
+
+            // The error variable of a catch block is always a rib-error
+            Expr::TryCatch {
+                try_expr,
+                error_variable,
+                catch_expr,
+                type_annotation,
+                inferred_type,
+                source_span,
+            } => {
+                let error_type = InferredType::from(&RibRuntimeError::error_type())
+                    .declared_at(source_span.clone());
+
+                let mut visitor = ExprVisitor::bottom_up(catch_expr);
+
+                while let Some(expr) = visitor.pop_front() {
+                    if let Expr::Identifier { variable_id, .. } = expr {
+                        if variable_id == error_variable {
+                            expr.with_inferred_type_mut(error_type.clone());
+                        }
+                    }
+                }
+
+                if let Some(type_annotation) = type_annotation {
+                    *inferred_type =
+                        InferredType::from(&*type_annotation).declared_at(source_span.clone());
+                }
+
+                queue.push_back(try_expr);
+                queue.push_back(catch_expr);
+            }
+
             expr => {
                 let source_span = expr.source_span();
                 let type_annotation = expr.type_annotation();
@@ -38,6 +38,10 @@ pub enum TypeHint {
     List(Option<Box<TypeHint>>),
     Boolean,
     Option(Option<Box<TypeHint>>),
+    Map {
+        key: Option<Box<TypeHint>>,
+        value: Option<Box<TypeHint>>,
+    },
     Enum(Option<Vec<String>>),
     Char,
     Result {
@@ -64,6 +68,7 @@ impl TypeHint {
             TypeHint::List(_) => "list".to_string(),
             TypeHint::Boolean => "boolean".to_string(),
             TypeHint::Option(_) => "option".to_string(),
+            TypeHint::Map { .. } => "map".to_string(),
             TypeHint::Enum(_) => "enum".to_string(),
             TypeHint::Char => "char".to_string(),
             TypeHint::Result { .. } => "result".to_string(),
@@ -130,6 +135,21 @@ impl fmt::Display for TypeHint {
                 write!(f, "{}", inner.deref())?;
                 write!(f, ">")
             }
+            TypeHint::Map { key, value } => {
+                write!(f, "map<")?;
+                if let Some(key) = key {
+                    write!(f, "{key}")?;
+                } else {
+                    write!(f, "_")?;
+                }
+                write!(f, ", ")?;
+                if let Some(value) = value {
+                    write!(f, "{value}")?;
+                } else {
+                    write!(f, "_")?;
+                }
+                write!(f, ">")
+            }
             TypeHint::Enum(None) => write!(f, "enum"),
             TypeHint::Enum(Some(enums)) => {
                 write!(f, "enum{{")?;
@@ -298,6 +318,10 @@ impl GetTypeHint for InferredType {
                 TypeHint::Enum(Some(enums.iter().map(|s| s.to_string()).collect()))
             }
             TypeInternal::Option(inner) => TypeHint::Option(Some(Box::new(inner.get_type_hint()))),
+            TypeInternal::Map { key, value } => TypeHint::Map {
+                key: Some(Box::new(key.get_type_hint())),
+                value: Some(Box::new(value.get_type_hint())),
+            },
             TypeInternal::Result { ok, error } => TypeHint::Result {
                 ok: ok.as_ref().map(|tpe| Box::new(tpe.get_type_hint())),
                 err: error.as_ref().map(|tpe| Box::new(tpe.get_type_hint())),
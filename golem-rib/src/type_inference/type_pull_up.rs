@@ -14,7 +14,9 @@
 
 use crate::rib_type_error::RibTypeErrorInternal;
 use crate::type_inference::type_hint::TypeHint;
-use crate::type_refinement::precise_types::{ListType, RecordType};
+use crate::type_refinement::precise_types::{
+    ListType, MapType, OkType, OptionalType, RecordType, TupleType,
+};
 use crate::type_refinement::TypeRefinement;
 use crate::{
     ActualType, ExpectedType, GetTypeHint, InferredType, MatchArm, Path, Range, TypeMismatchError,
@@ -127,6 +129,7 @@ pub fn type_pull_up(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
             Expr::GreaterThanOrEqualTo { .. } => {}
             Expr::LessThanOrEqualTo { .. } => {}
             Expr::EqualTo { .. } => {}
+            Expr::NotEqualTo { .. } => {}
             Expr::LessThan { .. } => {}
 
             Expr::Plus {
@@ -161,6 +164,66 @@ pub fn type_pull_up(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                     .merge(lhs.inferred_type())
                     .merge(rhs.inferred_type());
             }
+            Expr::Modulo {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(lhs.inferred_type())
+                    .merge(rhs.inferred_type());
+            }
+            Expr::BitwiseAnd {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(lhs.inferred_type())
+                    .merge(rhs.inferred_type());
+            }
+            Expr::BitwiseOr {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(lhs.inferred_type())
+                    .merge(rhs.inferred_type());
+            }
+            Expr::BitwiseXor {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(lhs.inferred_type())
+                    .merge(rhs.inferred_type());
+            }
+            Expr::ShiftLeft {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(lhs.inferred_type())
+                    .merge(rhs.inferred_type());
+            }
+            Expr::ShiftRight {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(lhs.inferred_type())
+                    .merge(rhs.inferred_type());
+            }
 
             Expr::Divide {
                 lhs,
@@ -189,6 +252,12 @@ pub fn type_pull_up(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                 ..
             } => handle_record(exprs, inferred_type),
 
+            Expr::Map {
+                entries,
+                inferred_type,
+                ..
+            } => handle_map(entries, inferred_type),
+
             Expr::Literal { .. } => {}
             Expr::Number { .. } => {}
             Expr::Boolean { .. } => {}
@@ -203,6 +272,23 @@ pub fn type_pull_up(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                 *inferred_type = inferred_type.merge(expr.inferred_type());
             }
             Expr::Length { .. } => {}
+            // A lambda is not a value, and the type of its body is pulled up only into the calls to it
+            Expr::Lambda { .. } => {}
+            Expr::Propagate {
+                expr,
+                inferred_type,
+                ..
+            } => handle_propagate(expr, inferred_type),
+            Expr::TryCatch {
+                try_expr,
+                catch_expr,
+                inferred_type,
+                ..
+            } => {
+                *inferred_type = inferred_type
+                    .merge(try_expr.inferred_type())
+                    .merge(catch_expr.inferred_type());
+            }
             Expr::Throw { .. } => {}
             Expr::GenerateWorkerName { .. } => {}
             Expr::ListComprehension {
@@ -221,12 +307,16 @@ pub fn type_pull_up(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                 *inferred_type = inferred_type.merge(expr.inferred_type());
             }
 
+            // The initial value can be an empty list, whose type is known only from the yield expression
             Expr::ListReduce {
                 init_value_expr,
+                yield_expr,
                 inferred_type,
                 ..
             } => {
-                *inferred_type = inferred_type.merge(init_value_expr.inferred_type());
+                *inferred_type = inferred_type
+                    .merge(init_value_expr.inferred_type())
+                    .merge(yield_expr.inferred_type());
             }
 
             Expr::Range {
@@ -364,6 +454,26 @@ fn handle_record(current_expr_list: &[(String, Box<Expr>)], record_type: &mut In
     *record_type = record_type.merge(InferredType::record(field_and_types));
 }
 
+fn handle_map(entries: &[(Expr, Expr)], map_type: &mut InferredType) {
+    if let Some((key, value)) = entries.first() {
+        *map_type = map_type.merge(InferredType::map(
+            key.inferred_type(),
+            value.inferred_type(),
+        ));
+    }
+}
+
+// The type of `expr?` is the type of the `some` value or the `ok` value of the expr
+fn handle_propagate(expr: &Expr, inferred_type: &mut InferredType) {
+    let expr_type = expr.inferred_type();
+
+    if let Some(optional_type) = OptionalType::refine(&expr_type) {
+        *inferred_type = inferred_type.merge(optional_type.inner_type());
+    } else if let Some(ok_type) = OkType::refine(&expr_type) {
+        *inferred_type = inferred_type.merge(ok_type.inner_type());
+    }
+}
+
 fn handle_range(range: &Range, inferred_type: &mut InferredType) {
     match range {
         Range::Range { from, to } => {
@@ -423,6 +533,11 @@ fn get_inferred_type_of_selection_dynamic(
     let select_from_type = select_from.inferred_type();
     let select_index_type = index.inferred_type();
 
+    // Selecting from a map by key results in the type of its values
+    if let Some(refined_map) = MapType::refine(&select_from_type) {
+        return Ok(refined_map.inner_type());
+    }
+
     let refined_list = ListType::refine(&select_from_type).ok_or({
         TypeMismatchError {
             source_span: select_from.source_span(),
@@ -440,6 +555,15 @@ fn get_inferred_type_of_selection_dynamic(
 
     let list_type = refined_list.inner_type();
 
+    // A list of key-value tuples selected by a string key is used as a map
+    if select_index_type.is_string() {
+        if let Some(refined_entry) = TupleType::refine(&list_type) {
+            if let [_, value_type] = refined_entry.inner_types().0.as_slice() {
+                return Ok(value_type.clone());
+            }
+        }
+    }
+
     if select_index_type.contains_only_number() {
         Ok(list_type)
     } else {
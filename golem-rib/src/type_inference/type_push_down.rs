@@ -16,6 +16,8 @@ use crate::rib_type_error::RibTypeErrorInternal;
 use crate::type_inference::type_push_down::internal::{
     handle_list_comprehension, handle_list_reduce,
 };
+use crate::type_refinement::precise_types::MapType;
+use crate::type_refinement::TypeRefinement;
 use crate::{Expr, ExprVisitor, InferredType, MatchArm, TypeInternal};
 use std::ops::Deref;
 
@@ -57,6 +59,14 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                     TypeInternal::Range { .. } => {
                         expr.add_infer_type_mut(inferred_type.clone());
                     }
+                    // Selecting a value from a map by its key
+                    _ if index_expr_type.is_string()
+                        || MapType::refine(&expr.inferred_type()).is_some() =>
+                    {
+                        let new_inferred_type =
+                            InferredType::map(index_expr_type.clone(), field_type);
+                        expr.add_infer_type_mut(new_inferred_type);
+                    }
                     _ => {
                         // Similar to selectIndex
                         let new_inferred_type = InferredType::list(field_type);
@@ -77,6 +87,22 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
 
                 cond.add_infer_type_mut(InferredType::bool());
             }
+            Expr::TryCatch {
+                try_expr,
+                catch_expr,
+                inferred_type,
+                ..
+            } => {
+                try_expr.add_infer_type_mut(inferred_type.clone());
+                catch_expr.add_infer_type_mut(inferred_type.clone());
+            }
+            Expr::Propagate {
+                expr,
+                inferred_type,
+                ..
+            } => {
+                internal::handle_propagate(expr, inferred_type);
+            }
             Expr::Not {
                 expr,
                 inferred_type,
@@ -148,6 +174,14 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                 internal::handle_record(exprs, &source_span, inferred_type)?;
             }
 
+            Expr::Map {
+                entries,
+                inferred_type,
+                ..
+            } => {
+                internal::handle_map(entries, inferred_type);
+            }
+
             Expr::Call {
                 call_type,
                 args,
@@ -238,6 +272,72 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                 lhs.add_infer_type_mut(inferred_type.clone());
                 rhs.add_infer_type_mut(inferred_type.clone());
             }
+            Expr::Modulo {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                lhs.add_infer_type_mut(rhs.inferred_type());
+                rhs.add_infer_type_mut(lhs.inferred_type());
+                lhs.add_infer_type_mut(inferred_type.clone());
+                rhs.add_infer_type_mut(inferred_type.clone());
+            }
+            Expr::BitwiseAnd {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                lhs.add_infer_type_mut(rhs.inferred_type());
+                rhs.add_infer_type_mut(lhs.inferred_type());
+                lhs.add_infer_type_mut(inferred_type.clone());
+                rhs.add_infer_type_mut(inferred_type.clone());
+            }
+            Expr::BitwiseOr {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                lhs.add_infer_type_mut(rhs.inferred_type());
+                rhs.add_infer_type_mut(lhs.inferred_type());
+                lhs.add_infer_type_mut(inferred_type.clone());
+                rhs.add_infer_type_mut(inferred_type.clone());
+            }
+            Expr::BitwiseXor {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                lhs.add_infer_type_mut(rhs.inferred_type());
+                rhs.add_infer_type_mut(lhs.inferred_type());
+                lhs.add_infer_type_mut(inferred_type.clone());
+                rhs.add_infer_type_mut(inferred_type.clone());
+            }
+            Expr::ShiftLeft {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                lhs.add_infer_type_mut(rhs.inferred_type());
+                rhs.add_infer_type_mut(lhs.inferred_type());
+                lhs.add_infer_type_mut(inferred_type.clone());
+                rhs.add_infer_type_mut(inferred_type.clone());
+            }
+            Expr::ShiftRight {
+                lhs,
+                rhs,
+                inferred_type,
+                ..
+            } => {
+                lhs.add_infer_type_mut(rhs.inferred_type());
+                rhs.add_infer_type_mut(lhs.inferred_type());
+                lhs.add_infer_type_mut(inferred_type.clone());
+                rhs.add_infer_type_mut(inferred_type.clone());
+            }
 
             _ => {}
         }
@@ -480,14 +580,18 @@ mod internal {
         source_span: &SourceSpan,
         outer_inferred_type: &InferredType,
     ) -> Result<(), RibTypeErrorInternal> {
-        let refined_list_type = ListType::refine(outer_inferred_type).ok_or_else(|| {
-            get_compilation_error_for_ambiguity(
+        // A list of key-value tuples can be used as a map
+        let inner_type = if let Some(refined_list_type) = ListType::refine(outer_inferred_type) {
+            refined_list_type.inner_type()
+        } else if let Some(refined_map_type) = MapType::refine(outer_inferred_type) {
+            InferredType::tuple(refined_map_type.inner_types().0)
+        } else {
+            return Err(get_compilation_error_for_ambiguity(
                 outer_inferred_type,
                 source_span,
                 &TypeHint::List(None),
-            )
-        })?;
-        let inner_type = refined_list_type.inner_type();
+            ));
+        };
 
         for expr in inner_expressions.iter_mut() {
             expr.add_infer_type_mut(inner_type.clone());
@@ -538,6 +642,44 @@ mod internal {
         Ok(())
     }
 
+    // The expected type of a map literal can be a map or a list of key-value tuples.
+    // Any other type is left to be reported as a conflict during type unification.
+    pub(crate) fn handle_map(entries: &mut [(Expr, Expr)], outer_inferred_type: &InferredType) {
+        let entry_types = if let Some(refined_map) = MapType::refine(outer_inferred_type) {
+            Some(refined_map.inner_types())
+        } else {
+            ListType::refine(outer_inferred_type)
+                .and_then(|refined_list| TupleType::refine(&refined_list.inner_type()))
+                .map(|refined_tuple| refined_tuple.inner_types())
+        };
+
+        if let Some(entry_types) = entry_types {
+            if let [key_type, value_type] = entry_types.0.as_slice() {
+                for (key, value) in entries.iter_mut() {
+                    key.add_infer_type_mut(key_type.clone());
+                    value.add_infer_type_mut(value_type.clone());
+                }
+            }
+        }
+    }
+
+    // Only the `some` or the `ok` value of `expr?` is known from the outer type,
+    // and therefore the type is pushed down only if the expr is already known to be an option or a result
+    pub(crate) fn handle_propagate(expr: &mut Expr, outer_inferred_type: &InferredType) {
+        match expr.inferred_type().internal_type() {
+            TypeInternal::Option(_) => {
+                expr.add_infer_type_mut(InferredType::option(outer_inferred_type.clone()));
+            }
+            TypeInternal::Result { error, .. } => {
+                expr.add_infer_type_mut(InferredType::result(
+                    Some(outer_inferred_type.clone()),
+                    error.clone(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
     pub(crate) fn handle_call<'a>(
         call_type: &'a mut CallType,
         expressions: &'a mut Vec<Expr>,
@@ -34,6 +34,7 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), TypeUnificationError> {
             Expr::GreaterThanOrEqualTo { .. } => {}
             Expr::LessThanOrEqualTo { .. } => {}
             Expr::EqualTo { .. } => {}
+            Expr::NotEqualTo { .. } => {}
             Expr::LessThan { .. } => {}
             Expr::InvokeMethodLazy { .. } => {}
             sub_expr => {
@@ -12,37 +12,178 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{ArmPattern, Expr, ExprVisitor, MatchArm, MatchIdentifier, VariableId};
-use std::collections::HashMap;
+use crate::call_type::CallType;
+use crate::{
+    ArmPattern, DynamicParsedFunctionName, DynamicParsedFunctionReference, Expr, ExprVisitor,
+    LambdaParameter, MatchArm, MatchIdentifier, ParsedFunctionSite, VariableId,
+};
+use std::collections::{HashMap, HashSet};
 
 // This function will assign ids to variables declared with `let` expressions,
 // and propagate these ids to the usage sites (`Expr::Identifier` nodes).
+// Calls to a name that is bound to a lambda (Example: `fn add(x, y) { x + y }; add(1, 2)`)
+// are converted to `CallType::Lambda` referring to the variable-id of the binding.
 pub fn bind_variables_of_let_assignment(expr: &mut Expr) {
     let mut identifier_id_state = IdentifierVariableIdState::new();
+    let mut lambda_bindings = HashSet::new();
     let mut visitor = ExprVisitor::bottom_up(expr);
 
     // Start from the end
     while let Some(expr) = visitor.pop_front() {
         match expr {
-            Expr::Let { variable_id, .. } => {
+            Expr::Let {
+                variable_id, expr, ..
+            } => {
                 let field_name = variable_id.name();
                 identifier_id_state.update_variable_id(&field_name); // Increment the variable_id
                 if let Some(latest_variable_id) = identifier_id_state.lookup(&field_name) {
                     *variable_id = latest_variable_id.clone();
                 }
+
+                if expr.is_lambda() {
+                    lambda_bindings.insert(variable_id.clone());
+                }
             }
 
-            Expr::Identifier { variable_id, .. } if !variable_id.is_match_binding() => {
+            Expr::Identifier { variable_id, .. }
+                if !variable_id.is_match_binding()
+                    && !variable_id.is_lambda_parameter()
+                    && !variable_id.is_catch_variable() =>
+            {
                 let field_name = variable_id.name();
                 if let Some(latest_variable_id) = identifier_id_state.lookup(&field_name) {
                     *variable_id = latest_variable_id.clone();
                 }
             }
+
+            Expr::Call { call_type, .. } => {
+                if let Some(function_name) = lambda_call_name(call_type) {
+                    if let Some(latest_variable_id) = identifier_id_state.lookup(&function_name) {
+                        if lambda_bindings.contains(&latest_variable_id) {
+                            *call_type = CallType::Lambda(latest_variable_id);
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
 }
 
+// Parameters of a lambda are given ids that are unique to the lambda, and the identifiers
+// in its body referring to them are updated, unless a `let` within the body shadows them.
+// Lambdas are visited bottom up, such that a nested lambda binds its parameters first,
+// and the enclosing lambda only binds the identifiers that are still unbound.
+pub fn bind_variables_of_lambda(expr: &mut Expr) {
+    let mut index = 0;
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        if let Expr::Lambda {
+            parameters, body, ..
+        } = expr
+        {
+            index += 1;
+
+            for parameter in parameters.iter_mut() {
+                parameter.variable_id =
+                    VariableId::lambda_parameter(parameter.variable_id.name(), index);
+            }
+
+            process_lambda_body(parameters, body);
+        }
+    }
+}
+
+fn process_lambda_body(parameters: &[LambdaParameter], body: &mut Expr) {
+    let mut shadowed_let_binding = vec![];
+    let mut visitor = ExprVisitor::bottom_up(body);
+
+    while let Some(expr) = visitor.pop_front() {
+        match expr {
+            Expr::Let { variable_id, .. } => {
+                shadowed_let_binding.push(variable_id.name());
+            }
+            Expr::Identifier { variable_id, .. } if variable_id.is_global() => {
+                let identifier_name = variable_id.name();
+                if shadowed_let_binding.contains(&identifier_name) {
+                    continue;
+                }
+
+                if let Some(parameter) = parameters
+                    .iter()
+                    .find(|parameter| parameter.variable_id.name() == identifier_name)
+                {
+                    *variable_id = parameter.variable_id.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// The error variable of a `try { .. } catch e { .. }` is given an id that is unique to the try-catch,
+// and the identifiers in the catch block referring to it are updated, unless a `let` shadows them.
+pub fn bind_variables_of_try_catch(expr: &mut Expr) {
+    let mut index = 0;
+    let mut visitor = ExprVisitor::bottom_up(expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        if let Expr::TryCatch {
+            error_variable,
+            catch_expr,
+            ..
+        } = expr
+        {
+            index += 1;
+
+            *error_variable = VariableId::catch_variable(error_variable.name(), index);
+
+            process_catch_expr(error_variable, catch_expr);
+        }
+    }
+}
+
+fn process_catch_expr(error_variable: &VariableId, catch_expr: &mut Expr) {
+    let mut shadowed_let_binding = vec![];
+    let mut visitor = ExprVisitor::bottom_up(catch_expr);
+
+    while let Some(expr) = visitor.pop_front() {
+        match expr {
+            Expr::Let { variable_id, .. } => {
+                shadowed_let_binding.push(variable_id.name());
+            }
+            Expr::Identifier { variable_id, .. } if variable_id.is_global() => {
+                let identifier_name = variable_id.name();
+
+                if identifier_name == error_variable.name()
+                    && !shadowed_let_binding.contains(&identifier_name)
+                {
+                    *variable_id = error_variable.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Only the calls to a plain global function name can refer to a lambda
+fn lambda_call_name(call_type: &CallType) -> Option<String> {
+    match call_type {
+        CallType::Function {
+            function_name:
+                DynamicParsedFunctionName {
+                    site: ParsedFunctionSite::Global,
+                    function: DynamicParsedFunctionReference::Function { function },
+                },
+            instance_identifier: None,
+            ..
+        } => Some(function.clone()),
+        CallType::Lambda(variable_id) => Some(variable_id.name()),
+        _ => None,
+    }
+}
+
 pub fn bind_variables_of_list_comprehension(expr: &mut Expr) {
     let mut visitor = ExprVisitor::top_down(expr);
 
@@ -114,7 +255,9 @@ fn bind_variables_in_pattern_match_internal(
             Expr::Let { variable_id, .. } => {
                 shadowed_let_binding.push(variable_id.name());
             }
-            Expr::Identifier { variable_id, .. } => {
+            Expr::Identifier { variable_id, .. }
+                if !variable_id.is_lambda_parameter() && !variable_id.is_catch_variable() =>
+            {
                 let identifier_name = variable_id.name();
                 if let Some(x) = match_identifiers.iter().find(|x| x.name == identifier_name) {
                     if !shadowed_let_binding.contains(&identifier_name) {
@@ -220,7 +363,10 @@ fn process_yield_expr_in_comprehension(variable: &mut VariableId, yield_expr: &m
 
     while let Some(expr) = visitor.pop_front() {
         if let Expr::Identifier { variable_id, .. } = expr {
-            if variable.name() == variable_id.name() {
+            if variable.name() == variable_id.name()
+                && !variable_id.is_lambda_parameter()
+                && !variable_id.is_catch_variable()
+            {
                 *variable_id = variable.clone();
             }
         }
@@ -236,6 +382,10 @@ fn process_yield_expr_in_reduce(
 
     while let Some(expr) = visitor.pop_front() {
         if let Expr::Identifier { variable_id, .. } = expr {
+            if variable_id.is_lambda_parameter() || variable_id.is_catch_variable() {
+                continue;
+            }
+
             if iterated_variable_id.name() == variable_id.name() {
                 *variable_id = iterated_variable_id.clone();
             } else if reduce_variable.name() == variable_id.name() {
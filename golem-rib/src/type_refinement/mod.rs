@@ -121,12 +121,38 @@ impl TypeRefinement for ErrType {
 
 impl TypeRefinement for ListType {
     fn refine(inferred_type: &InferredType) -> Option<RefinedType<Self>> {
-        internal::refine_inferred_type(inferred_type, &|inferred_type| {
-            if let TypeInternal::List(inferred_type) = inferred_type.internal_type() {
-                Some(ListType(inferred_type.clone()))
-            } else {
-                None
+        let used_as_map = internal::is_used_as_list_and_map(inferred_type);
+
+        internal::refine_inferred_type(inferred_type, &|inferred_type| match inferred_type
+            .internal_type()
+        {
+            TypeInternal::List(inferred_type) => Some(ListType(inferred_type.clone())),
+            TypeInternal::Map { key, value } if used_as_map => {
+                Some(ListType(InferredType::tuple(vec![
+                    key.clone(),
+                    value.clone(),
+                ])))
             }
+            _ => None,
+        })
+    }
+}
+
+impl TypeRefinement for MapType {
+    fn refine(inferred_type: &InferredType) -> Option<RefinedType<Self>> {
+        let used_as_list = internal::is_used_as_list_and_map(inferred_type);
+
+        internal::refine_inferred_type(inferred_type, &|inferred_type| match inferred_type
+            .internal_type()
+        {
+            TypeInternal::Map { key, value } => Some(MapType(key.clone(), value.clone())),
+            TypeInternal::List(entry) if used_as_list => match entry.internal_type() {
+                TypeInternal::Tuple(types) if types.len() == 2 => {
+                    Some(MapType(types[0].clone(), types[1].clone()))
+                }
+                _ => None,
+            },
+            _ => None,
         })
     }
 }
@@ -255,6 +281,22 @@ mod internal {
     use crate::type_refinement::RefinedType;
     use crate::{InferredType, TypeInternal};
 
+    // A map is a list of key-value tuples in WIT, and hence an expression can be used
+    // as both, in which case it is refined as either of them
+    pub(crate) fn is_used_as_list_and_map(inferred_type: &InferredType) -> bool {
+        match inferred_type.internal_type() {
+            TypeInternal::AllOf(types) => {
+                types
+                    .iter()
+                    .any(|typ| matches!(typ.internal_type(), TypeInternal::List(_)))
+                    && types
+                        .iter()
+                        .any(|typ| matches!(typ.internal_type(), TypeInternal::Map { .. }))
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn refine_inferred_type<F, A>(
         inferred_type: &InferredType,
         select: &F,
@@ -30,6 +30,9 @@ pub struct ErrType(pub Option<InferredType>);
 #[derive(Clone, PartialEq, Debug)]
 pub struct ListType(pub InferredType);
 
+#[derive(Clone, PartialEq, Debug)]
+pub struct MapType(pub InferredType, pub InferredType);
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct TupleType(pub Vec<InferredType>);
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::type_refinement::precise_types::{
-    ErrType, ListType, OkType, OptionalType, RangeType, RecordType, TupleType, VariantType,
+    ErrType, ListType, MapType, OkType, OptionalType, RangeType, RecordType, TupleType, VariantType,
 };
 use crate::InferredType;
 
@@ -45,6 +45,13 @@ impl ExtractInnerType for ListType {
     }
 }
 
+// The inner type of a map is the type of its values
+impl ExtractInnerType for MapType {
+    fn inner_type(&self) -> InferredType {
+        self.1.clone()
+    }
+}
+
 impl ExtractInnerType for RangeType {
     fn inner_type(&self) -> InferredType {
         self.0.clone()
@@ -64,6 +71,12 @@ impl ExtractInnerTypes for RangeType {
     }
 }
 
+impl ExtractInnerTypes for MapType {
+    fn inner_types(&self) -> Vec<InferredType> {
+        vec![self.0.clone(), self.1.clone()]
+    }
+}
+
 impl ExtractInnerTypes for TupleType {
     fn inner_types(&self) -> Vec<InferredType> {
         self.0.clone()
@@ -25,6 +25,8 @@ pub enum VariableId {
     MatchIdentifier(MatchIdentifier),
     ListComprehension(ListComprehensionIdentifier),
     ListReduce(ListAggregationIdentifier),
+    LambdaParameter(LambdaParameterIdentifier),
+    CatchVariable(CatchVariableIdentifier),
 }
 
 impl VariableId {
@@ -41,6 +43,8 @@ impl VariableId {
             VariableId::MatchIdentifier(m) => format!("{}-{}", m.name, m.match_arm_index),
             VariableId::ListComprehension(l) => l.name.clone(),
             VariableId::ListReduce(r) => r.name.clone(),
+            VariableId::LambdaParameter(l) => format!("{}-{}", l.name, l.lambda_index),
+            VariableId::CatchVariable(c) => format!("{}-{}", c.name, c.try_index),
         };
 
         VariableId::global(format!("__instance_{variable_string}"))
@@ -57,6 +61,20 @@ impl VariableId {
         })
     }
 
+    pub fn catch_variable(name: impl AsRef<str>, try_index: usize) -> VariableId {
+        VariableId::CatchVariable(CatchVariableIdentifier {
+            name: name.as_ref().to_string(),
+            try_index,
+        })
+    }
+
+    pub fn lambda_parameter(name: impl AsRef<str>, lambda_index: usize) -> VariableId {
+        VariableId::LambdaParameter(LambdaParameterIdentifier {
+            name: name.as_ref().to_string(),
+            lambda_index,
+        })
+    }
+
     pub fn match_identifier(name: String, match_arm_index: usize) -> VariableId {
         VariableId::MatchIdentifier(MatchIdentifier {
             name,
@@ -71,6 +89,8 @@ impl VariableId {
             VariableId::MatchIdentifier(m) => m.name.clone(),
             VariableId::ListComprehension(l) => l.name.clone(),
             VariableId::ListReduce(r) => r.name.clone(),
+            VariableId::LambdaParameter(l) => l.name.clone(),
+            VariableId::CatchVariable(c) => c.name.clone(),
         }
     }
 
@@ -81,6 +101,8 @@ impl VariableId {
             VariableId::MatchIdentifier(_) => false,
             VariableId::ListComprehension(_) => false,
             VariableId::ListReduce(_) => false,
+            VariableId::LambdaParameter(_) => false,
+            VariableId::CatchVariable(_) => false,
         }
     }
 
@@ -91,9 +113,19 @@ impl VariableId {
             VariableId::MatchIdentifier(_) => false,
             VariableId::ListComprehension(_) => false,
             VariableId::ListReduce(_) => false,
+            VariableId::LambdaParameter(_) => false,
+            VariableId::CatchVariable(_) => false,
         }
     }
 
+    pub fn is_lambda_parameter(&self) -> bool {
+        matches!(self, VariableId::LambdaParameter(_))
+    }
+
+    pub fn is_catch_variable(&self) -> bool {
+        matches!(self, VariableId::CatchVariable(_))
+    }
+
     pub fn is_match_binding(&self) -> bool {
         match self {
             VariableId::Global(_) => false,
@@ -101,6 +133,8 @@ impl VariableId {
             VariableId::MatchIdentifier(_) => true,
             VariableId::ListComprehension(_) => false,
             VariableId::ListReduce(_) => false,
+            VariableId::LambdaParameter(_) => false,
+            VariableId::CatchVariable(_) => false,
         }
     }
 
@@ -132,6 +166,8 @@ impl VariableId {
             VariableId::MatchIdentifier(m) => VariableId::MatchIdentifier(m.clone()),
             VariableId::ListComprehension(l) => VariableId::ListComprehension(l.clone()),
             VariableId::ListReduce(l) => VariableId::ListReduce(l.clone()),
+            VariableId::LambdaParameter(l) => VariableId::LambdaParameter(l.clone()),
+            VariableId::CatchVariable(c) => VariableId::CatchVariable(c.clone()),
         }
     }
 }
@@ -158,6 +194,26 @@ pub struct MatchIdentifier {
     pub match_arm_index: usize, // Every match arm across the program is identified by a non-sharing index value. Within a match arm the identifier names cannot be reused
 }
 
+// Parameters of every lambda across the program are identified by a non-sharing index of the lambda,
+// such that parameters of different lambdas never conflict during type inference
+#[derive(
+    Hash, Eq, Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode, Ord, PartialOrd,
+)]
+pub struct LambdaParameterIdentifier {
+    pub name: String,
+    pub lambda_index: usize,
+}
+
+// The error variable of every `catch` block across the program is identified by a non-sharing
+// index of the try-catch expression, such that nested try-catch expressions never conflict
+#[derive(
+    Hash, Eq, Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode, Ord, PartialOrd,
+)]
+pub struct CatchVariableIdentifier {
+    pub name: String,
+    pub try_index: usize,
+}
+
 impl MatchIdentifier {
     pub fn new(name: String, match_arm_index: usize) -> MatchIdentifier {
         MatchIdentifier {
@@ -175,6 +231,8 @@ impl Display for VariableId {
             VariableId::MatchIdentifier(m) => write!(f, "{}", m.name),
             VariableId::ListComprehension(l) => write!(f, "{}", l.name),
             VariableId::ListReduce(r) => write!(f, "{}", r.name),
+            VariableId::LambdaParameter(l) => write!(f, "{}", l.name),
+            VariableId::CatchVariable(c) => write!(f, "{}", c.name),
         }
     }
 }
@@ -221,6 +279,22 @@ mod protobuf {
                         name: list_aggregation.name,
                     },
                 )),
+                golem_api_grpc::proto::golem::rib::variable_id::VariableId::LambdaParameterIdentifier(
+                    lambda_parameter,
+                ) => Ok(VariableId::LambdaParameter(
+                    crate::LambdaParameterIdentifier {
+                        name: lambda_parameter.name,
+                        lambda_index: lambda_parameter.lambda_index as usize,
+                    },
+                )),
+                golem_api_grpc::proto::golem::rib::variable_id::VariableId::CatchVariableIdentifier(
+                    catch_variable,
+                ) => Ok(VariableId::CatchVariable(
+                    crate::CatchVariableIdentifier {
+                        name: catch_variable.name,
+                        try_index: catch_variable.try_index as usize,
+                    },
+                )),
             }
         }
     }
@@ -273,6 +347,26 @@ mod protobuf {
                         ),
                     ),
                 },
+                VariableId::LambdaParameter(l) => ProtoVariableId {
+                    variable_id: Some(
+                        golem_api_grpc::proto::golem::rib::variable_id::VariableId::LambdaParameterIdentifier(
+                            golem_api_grpc::proto::golem::rib::LambdaParameterIdentifier {
+                                name: l.name,
+                                lambda_index: l.lambda_index as u32,
+                            },
+                        ),
+                    ),
+                },
+                VariableId::CatchVariable(c) => ProtoVariableId {
+                    variable_id: Some(
+                        golem_api_grpc::proto::golem::rib::variable_id::VariableId::CatchVariableIdentifier(
+                            golem_api_grpc::proto::golem::rib::CatchVariableIdentifier {
+                                name: c.name,
+                                try_index: c.try_index as u32,
+                            },
+                        ),
+                    ),
+                },
             }
         }
     }
@@ -21,3 +21,112 @@ pub fn proto_project_id_string(
         .and_then(|v| TryInto::<ProjectId>::try_into(v).ok())
         .map(|v| v.to_string())
 }
+
+/// Size of the chunks worker traces are streamed in between the worker service and the
+/// executors, staying well below tonic's default 4 MiB limit of a single decoded message
+pub const WORKER_TRACE_CHUNK_SIZE: usize = 1024 * 1024;
+
+pub fn export_worker_trace_response_chunks(
+    trace: &[u8],
+) -> Vec<golem_api_grpc::proto::golem::workerexecutor::v1::ExportWorkerTraceResponse> {
+    use golem_api_grpc::proto::golem::workerexecutor::v1::{
+        export_worker_trace_response, ExportWorkerTraceResponse,
+    };
+
+    trace
+        .chunks(WORKER_TRACE_CHUNK_SIZE)
+        .map(|chunk| ExportWorkerTraceResponse {
+            result: Some(export_worker_trace_response::Result::Success(
+                chunk.to_vec(),
+            )),
+        })
+        .collect()
+}
+
+pub fn import_worker_trace_request_chunks(
+    header: golem_api_grpc::proto::golem::workerexecutor::v1::ImportWorkerTraceRequestHeader,
+    trace: &[u8],
+) -> Vec<golem_api_grpc::proto::golem::workerexecutor::v1::ImportWorkerTraceRequest> {
+    use golem_api_grpc::proto::golem::workerexecutor::v1::{
+        import_worker_trace_request, ImportWorkerTraceRequest,
+    };
+
+    std::iter::once(ImportWorkerTraceRequest {
+        data: Some(import_worker_trace_request::Data::Header(header)),
+    })
+    .chain(
+        trace
+            .chunks(WORKER_TRACE_CHUNK_SIZE)
+            .map(|chunk| ImportWorkerTraceRequest {
+                data: Some(import_worker_trace_request::Data::Chunk(chunk.to_vec())),
+            }),
+    )
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_api_grpc::proto::golem::workerexecutor::v1::{
+        export_worker_trace_response, import_worker_trace_request, ImportWorkerTraceRequestHeader,
+    };
+    use test_r::test;
+
+    const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+    fn large_trace() -> Vec<u8> {
+        (0..9 * 1024 * 1024 + 17)
+            .map(|i: usize| (i % 251) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn large_exported_traces_are_split_into_small_chunks() {
+        let trace = large_trace();
+
+        let chunks = export_worker_trace_response_chunks(&trace);
+
+        let mut reassembled = Vec::new();
+        for chunk in chunks {
+            match chunk.result {
+                Some(export_worker_trace_response::Result::Success(bytes)) => {
+                    assert!(bytes.len() < DEFAULT_MAX_DECODING_MESSAGE_SIZE);
+                    reassembled.extend(bytes);
+                }
+                other => panic!("Unexpected chunk: {other:?}"),
+            }
+        }
+        assert_eq!(reassembled, trace);
+    }
+
+    #[test]
+    fn large_imported_traces_are_split_into_small_chunks() {
+        let trace = large_trace();
+
+        let chunks = import_worker_trace_request_chunks(
+            ImportWorkerTraceRequestHeader {
+                worker_id: None,
+                account_id: None,
+                project_id: None,
+            },
+            &trace,
+        );
+
+        assert!(matches!(
+            chunks.first().and_then(|chunk| chunk.data.as_ref()),
+            Some(import_worker_trace_request::Data::Header(_))
+        ));
+
+        let mut reassembled = Vec::new();
+        for chunk in chunks.into_iter().skip(1) {
+            match chunk.data {
+                Some(import_worker_trace_request::Data::Chunk(bytes)) => {
+                    assert!(bytes.len() < DEFAULT_MAX_DECODING_MESSAGE_SIZE);
+                    reassembled.extend(bytes);
+                }
+                other => panic!("Unexpected chunk: {other:?}"),
+            }
+        }
+        assert_eq!(reassembled, trace);
+    }
+}
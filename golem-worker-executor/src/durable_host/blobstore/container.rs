@@ -89,7 +89,9 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .persist(self, (container_name, name, start, end), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(self, (container_name, name, start, end))
+                .await
         };
         match result {
             Ok(get_data) => {
@@ -140,7 +142,9 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .persist(self, (container_name, name, len), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(self, (container_name, name, data.len() as u64))
+                .await
         };
 
         match result {
@@ -176,7 +180,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, container_name, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, container_name).await
         };
 
         match result {
@@ -221,7 +225,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .persist(self, (container_name, name), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (container_name, name)).await
         };
 
         match result {
@@ -260,7 +264,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .persist(self, (container_name, names), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (container_name, names)).await
         };
 
         match result {
@@ -299,7 +303,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .persist(self, (container_name, name), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (container_name, name)).await
         };
 
         match result {
@@ -339,7 +343,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .persist(self, (container_name, name), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (container_name, name)).await
         };
 
         match result {
@@ -380,7 +384,7 @@ impl<Ctx: WorkerCtx> HostContainer for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, container_name, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, container_name).await
         }?;
 
         Ok(Ok(()))
@@ -50,7 +50,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .map(|r| r.unwrap());
             durability.persist(self, name.clone(), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, name.clone()).await
         };
 
         match result {
@@ -85,7 +85,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, name.clone(), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, name.clone()).await
         };
 
         match result {
@@ -118,7 +118,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, name.clone(), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, name.clone()).await
         };
 
         match result {
@@ -147,7 +147,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, name, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, name).await
         };
 
         match result {
@@ -189,7 +189,12 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(
+                    self,
+                    (src.container, src.object, dest.container, dest.object),
+                )
+                .await
         };
 
         match result {
@@ -231,7 +236,12 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(
+                    self,
+                    (src.container, src.object, dest.container, dest.object),
+                )
+                .await
         };
 
         match result {
@@ -33,7 +33,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::get_environment(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 
@@ -50,7 +50,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::get_arguments(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 
@@ -67,7 +67,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::initial_cwd(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 }
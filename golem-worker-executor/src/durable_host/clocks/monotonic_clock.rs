@@ -35,7 +35,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::now(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 
@@ -52,7 +52,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::resolution(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 
@@ -78,7 +78,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 let result = Host::now(&mut self.as_wasi_view()).await;
                 durability.persist(self, duration, result).await
             } else {
-                durability.replay(self).await
+                durability.replay(self, duration).await
             }
         }?;
 
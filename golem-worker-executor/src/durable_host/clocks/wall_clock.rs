@@ -32,7 +32,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::now(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 
@@ -49,7 +49,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::resolution(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 }
@@ -14,6 +14,7 @@
 
 use crate::durable_host::DurableWorkerCtx;
 use crate::metrics::wasm::record_host_function_call;
+use crate::model::replay_divergence::{ReplayDivergence, ReplayedInvocation};
 use crate::preview2::golem::durability::durability;
 use crate::preview2::golem::durability::durability::PersistedTypedDurableFunctionInvocation;
 use crate::services::oplog::{CommitLevel, OplogOps};
@@ -37,13 +38,18 @@ pub struct DurableExecutionState {
     pub is_live: bool,
     pub persistence_level: PersistenceLevel,
     pub snapshotting_mode: Option<PersistenceLevel>,
+    /// Whether replayed invocations are checked against their recorded requests
+    pub verify_replay: bool,
 }
 
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct PersistedDurableFunctionInvocation {
+    oplog_index: OplogIndex,
     timestamp: Timestamp,
     function_name: String,
+    /// The serialized request, only loaded when replay verification is enabled
+    request: Option<Vec<u8>>,
     response: Vec<u8>,
     function_type: DurableFunctionType,
     oplog_entry_version: OplogEntryVersion,
@@ -113,6 +119,10 @@ pub trait DurabilityHost {
     async fn read_persisted_durable_function_invocation(
         &mut self,
     ) -> Result<PersistedDurableFunctionInvocation, WorkerExecutorError>;
+
+    /// Records that the replay diverged from the oplog. Only the first divergence is kept, and
+    /// once recorded, every subsequent durable function fails.
+    fn record_replay_divergence(&mut self, divergence: ReplayDivergence);
 }
 
 impl From<durability::DurableFunctionType> for DurableFunctionType {
@@ -332,6 +342,11 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
         &mut self,
         function_type: &DurableFunctionType,
     ) -> Result<OplogIndex, WorkerExecutorError> {
+        if let Some(divergence) = &self.state.replay_divergence {
+            // Some host functions convert replay failures into guest-level errors, so a detected
+            // divergence must also fail every durable function following it
+            return Err(WorkerExecutorError::runtime(divergence.to_string()));
+        }
         self.process_pending_replay_events().await?;
         let oplog_index = self.state.begin_function(function_type).await?;
         Ok(oplog_index)
@@ -358,6 +373,7 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
             is_live: self.state.is_live(),
             persistence_level: self.state.persistence_level,
             snapshotting_mode: self.state.snapshotting_mode,
+            verify_replay: self.state.verify_replay,
         }
     }
 
@@ -408,7 +424,7 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
                 "Trying to replay an durable invocation in a PersistNothing block",
             ))
         } else {
-            let (_, oplog_entry) = crate::get_oplog_entry!(
+            let (oplog_index, oplog_entry) = crate::get_oplog_entry!(
                 self.state.replay_state,
                 OplogEntry::ImportedFunctionInvoked
             )?;
@@ -430,15 +446,37 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
                 OplogEntry::ImportedFunctionInvoked {
                     timestamp,
                     function_name,
+                    request,
                     durable_function_type,
                     ..
-                } => Ok(PersistedDurableFunctionInvocation {
-                    timestamp,
-                    function_name,
-                    response: bytes.to_vec(),
-                    function_type: durable_function_type,
-                    oplog_entry_version: OplogEntryVersion::V2,
-                }),
+                } => {
+                    let request = if self.state.verify_replay {
+                        Some(
+                            self.state
+                                .oplog
+                                .download_payload(&request)
+                                .await
+                                .map_err(|err| {
+                                    WorkerExecutorError::unexpected_oplog_entry(
+                                        "ImportedFunctionInvoked request payload",
+                                        err,
+                                    )
+                                })?
+                                .to_vec(),
+                        )
+                    } else {
+                        None
+                    };
+                    Ok(PersistedDurableFunctionInvocation {
+                        oplog_index,
+                        timestamp,
+                        function_name,
+                        request,
+                        response: bytes.to_vec(),
+                        function_type: durable_function_type,
+                        oplog_entry_version: OplogEntryVersion::V2,
+                    })
+                }
                 _ => Err(WorkerExecutorError::unexpected_oplog_entry(
                     "ImportedFunctionInvoked",
                     format!("{oplog_entry:?}"),
@@ -446,6 +484,12 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
             }
         }
     }
+
+    fn record_replay_divergence(&mut self, divergence: ReplayDivergence) {
+        if self.state.replay_divergence.is_none() {
+            self.state.replay_divergence = Some(divergence);
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -581,14 +625,23 @@ impl<SOk, SErr> Durability<SOk, SErr> {
         Ok(())
     }
 
+    /// Reads the recorded result of the durable function from the oplog.
+    ///
+    /// The `input` must be the same the function would pass to `persist` in live mode, so
+    /// it can be compared with the recorded request when replay verification is enabled.
     pub async fn replay_raw(
         &self,
         ctx: &mut impl DurabilityHost,
+        input: impl Debug + Encode + Send + Sync,
     ) -> Result<(Bytes, OplogEntryVersion), WorkerExecutorError> {
         let oplog_entry = ctx.read_persisted_durable_function_invocation().await?;
 
         let function_name = self.function_name();
-        Self::validate_oplog_entry(&oplog_entry, &function_name)?;
+        if self.durable_execution_state.verify_replay {
+            Self::verify_oplog_entry(ctx, &oplog_entry, function_name, input)?;
+        } else {
+            Self::validate_oplog_entry(&oplog_entry, &function_name)?;
+        }
 
         ctx.end_durable_function(&self.function_type, self.begin_index, false)
             .await?;
@@ -599,12 +652,13 @@ impl<SOk, SErr> Durability<SOk, SErr> {
     pub async fn replay_serializable(
         &self,
         ctx: &mut impl DurabilityHost,
+        input: impl Debug + Encode + Send + Sync,
     ) -> Result<Result<SOk, SErr>, WorkerExecutorError>
     where
         SOk: Decode<()>,
         SErr: Decode<()>,
     {
-        let (bytes, _) = self.replay_raw(ctx).await?;
+        let (bytes, _) = self.replay_raw(ctx, input).await?;
         let result: Result<SOk, SErr> = try_deserialize(&bytes)
             .map_err(|err| {
                 WorkerExecutorError::unexpected_oplog_entry("ImportedFunctionInvoked payload", err)
@@ -613,14 +667,18 @@ impl<SOk, SErr> Durability<SOk, SErr> {
         Ok(result)
     }
 
-    pub async fn replay<Ok, Err>(&self, ctx: &mut impl DurabilityHost) -> Result<Ok, Err>
+    pub async fn replay<Ok, Err>(
+        &self,
+        ctx: &mut impl DurabilityHost,
+        input: impl Debug + Encode + Send + Sync,
+    ) -> Result<Ok, Err>
     where
         Ok: From<SOk>,
         Err: From<SErr> + From<WorkerExecutorError>,
         SErr: Debug + Encode + Decode<()> + From<WorkerExecutorError> + Send + Sync,
         SOk: Debug + Encode + Decode<()> + Send + Sync,
     {
-        Self::replay_serializable(self, ctx)
+        Self::replay_serializable(self, ctx, input)
             .await?
             .map(|sok| sok.into())
             .map_err(|serr| serr.into())
@@ -652,6 +710,44 @@ impl<SOk, SErr> Durability<SOk, SErr> {
             Ok(())
         }
     }
+
+    fn verify_oplog_entry(
+        ctx: &mut impl DurabilityHost,
+        oplog_entry: &PersistedDurableFunctionInvocation,
+        function_name: String,
+        input: impl Debug + Encode,
+    ) -> Result<(), WorkerExecutorError> {
+        let request = serialize(&input)
+            .map_err(|err| {
+                WorkerExecutorError::runtime(format!(
+                    "failed to serialize input ({input:?}) for verifying durable function invocation: {err}"
+                ))
+            })?
+            .to_vec();
+        let expected = ReplayedInvocation {
+            function_name: oplog_entry.function_name.clone(),
+            request: oplog_entry.request.clone().unwrap_or_default(),
+        };
+        let actual = ReplayedInvocation {
+            function_name,
+            request,
+        };
+
+        match ReplayDivergence::check(
+            oplog_entry.oplog_index,
+            expected,
+            actual,
+            format!("{input:?}"),
+        ) {
+            Some(divergence) => {
+                error!("{divergence}");
+                let err = WorkerExecutorError::runtime(divergence.to_string());
+                ctx.record_replay_divergence(divergence);
+                Err(err)
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 pub enum LazyInitializedPollableEntry {
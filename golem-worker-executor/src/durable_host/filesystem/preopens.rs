@@ -41,7 +41,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                     .collect::<Vec<_>>());
                 durability.persist(self, (), result).await
             } else {
-                durability.replay(self).await
+                durability.replay(self, ()).await
             }
         }?;
 
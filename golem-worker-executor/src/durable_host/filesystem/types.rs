@@ -215,7 +215,10 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
                 .await
         } else {
             durability
-                .replay::<SerializableFileTimes, anyhow::Error>(self)
+                .replay::<SerializableFileTimes, anyhow::Error>(
+                    self,
+                    path.to_string_lossy().to_string(),
+                )
                 .await
         }
         .map_err(FsError::trap)?;
@@ -276,7 +279,10 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
                 .await
         } else {
             durability
-                .replay::<SerializableFileTimes, anyhow::Error>(self)
+                .replay::<SerializableFileTimes, anyhow::Error>(
+                    self,
+                    full_path.to_string_lossy().to_string(),
+                )
                 .await
         }
         .map_err(FsError::trap)?;
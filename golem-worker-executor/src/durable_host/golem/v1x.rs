@@ -124,7 +124,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await?
         } else {
             durability
-                .replay::<PromiseId, WorkerExecutorError>(self)
+                .replay::<PromiseId, WorkerExecutorError>(self, ())
                 .await?
         };
 
@@ -163,8 +163,8 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         )
         .await?;
 
+        let promise_id: PromiseId = promise_id.into();
         let result = if durability.is_live() {
-            let promise_id: PromiseId = promise_id.into();
             let result = self
                 .public_state
                 .promise_service
@@ -172,7 +172,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, promise_id, result.clone()).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, promise_id).await
         };
 
         Ok(result?)
@@ -201,7 +201,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
 
             durability.persist(self, promise_id, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, promise_id).await
         }?;
         Ok(result)
     }
@@ -229,7 +229,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             };
             durability.persist(self, promise_id, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, promise_id).await
         }
     }
 
@@ -502,7 +502,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result: Result<(u64, u64), anyhow::Error> = Ok(uuid.as_u64_pair());
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }?;
         let uuid = Uuid::from_u64_pair(hi, lo);
         Ok(uuid.into())
@@ -544,7 +544,9 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .persist(self, (worker_id, target_version, mode), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(self, (worker_id, target_version, mode))
+                .await
         }?;
 
         Ok(())
@@ -611,7 +613,12 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 )
                 .await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(
+                    self,
+                    (source_worker_id, target_worker_id, oplog_idx_cut_off),
+                )
+                .await
         }?;
 
         Ok(())
@@ -630,10 +637,10 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         )
         .await?;
 
-        if durability.is_live() {
-            let worker_id: WorkerId = worker_id.into();
-            let revert_target: golem_service_base::model::RevertWorkerTarget = revert_target.into();
+        let worker_id: WorkerId = worker_id.into();
+        let revert_target: golem_service_base::model::RevertWorkerTarget = revert_target.into();
 
+        if durability.is_live() {
             let result = self
                 .worker_proxy()
                 .revert(&worker_id, revert_target.clone())
@@ -642,7 +649,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .persist(self, (worker_id, revert_target), result)
                 .await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (worker_id, revert_target)).await
         }?;
 
         Ok(())
@@ -671,7 +678,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, component_slug, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, component_slug).await
         }?;
 
         Ok(result.map(golem_api_1_x::host::ComponentId::from))
@@ -739,7 +746,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .persist(self, (component_slug, worker_name), worker_id)
                 .await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (component_slug, worker_name)).await
         }?;
 
         Ok(result.map(|w| w.into()))
@@ -781,7 +788,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
 
             Ok(durability.persist(self, new_name, fork_result).await?)
         } else {
-            durability.replay(self).await
+            durability.replay(self, new_name).await
         }
     }
 }
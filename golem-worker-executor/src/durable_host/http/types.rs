@@ -472,7 +472,7 @@ impl<Ctx: WorkerCtx> HostFutureTrailers for DurableWorkerCtx<Ctx> {
                     .await;
                 result
             } else {
-                let serialized = durability.replay(self).await;
+                let serialized = durability.replay(self, request).await;
                 match serialized {
                     Ok(Some(Ok(Ok(None)))) => Ok(Some(Ok(Ok(None)))),
                     Ok(Some(Ok(Ok(Some(serialized_trailers))))) => {
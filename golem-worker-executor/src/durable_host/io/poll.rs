@@ -60,7 +60,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 result
             }
         } else {
-            durability.replay(self).await
+            durability.replay(self, in_.len()).await
         };
 
         match is_suspend_for_sleep(&result) {
@@ -53,7 +53,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 let result = HostInputStream::read(&mut self.as_wasi_view().0, self_, len).await;
                 durability.persist(self, request, result).await
             } else {
-                durability.replay(self).await
+                let request = get_http_stream_request(self, handle)?;
+                durability.replay(self, request).await
             };
 
             end_http_request_if_closed(self, handle, &result).await?;
@@ -86,7 +87,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                     HostInputStream::blocking_read(&mut self.as_wasi_view().0, self_, len).await;
                 durability.persist(self, request, result).await
             } else {
-                durability.replay(self).await
+                let request = get_http_stream_request(self, handle)?;
+                durability.replay(self, request).await
             };
 
             end_http_request_if_closed(self, handle, &result).await?;
@@ -114,7 +116,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 let result = HostInputStream::skip(&mut self.as_wasi_view().0, self_, len).await;
                 durability.persist(self, request, result).await
             } else {
-                durability.replay(self).await
+                let request = get_http_stream_request(self, handle)?;
+                durability.replay(self, request).await
             };
 
             end_http_request_if_closed(self, handle, &result).await?;
@@ -148,7 +151,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                     HostInputStream::blocking_skip(&mut self.as_wasi_view().0, self_, len).await;
                 durability.persist(self, request, result).await
             } else {
-                durability.replay(self).await
+                let request = get_http_stream_request(self, handle)?;
+                durability.replay(self, request).await
             };
             end_http_request_if_closed(self, handle, &result).await?;
             result
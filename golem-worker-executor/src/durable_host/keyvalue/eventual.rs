@@ -55,7 +55,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, (bucket, key), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (bucket, key)).await
         };
 
         match result {
@@ -116,7 +116,9 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(self, (bucket, key, outgoing_value.len() as u64))
+                .await
         };
 
         match result {
@@ -161,7 +163,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (bucket, key)).await
         };
 
         match result {
@@ -206,7 +208,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (bucket, key)).await
         };
 
         match result {
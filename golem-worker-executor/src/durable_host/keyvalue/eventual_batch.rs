@@ -55,7 +55,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (bucket, keys)).await
         };
 
         match result {
@@ -114,7 +114,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, bucket, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, bucket).await
         }?;
 
         Ok(Ok(keys))
@@ -170,7 +170,18 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability
+                .replay(
+                    self,
+                    (
+                        bucket,
+                        key_values
+                            .iter()
+                            .map(|(k, v)| (k.clone(), v.len() as u64))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+                .await
         };
 
         match result {
@@ -215,7 +226,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, (bucket, keys)).await
         };
 
         match result {
@@ -40,6 +40,7 @@ use crate::durable_host::replay_state::ReplayState;
 use crate::durable_host::serialized::SerializableError;
 use crate::metrics::wasm::{record_number_of_replayed_functions, record_resume_worker};
 use crate::model::event::InternalWorkerEvent;
+use crate::model::replay_divergence::ReplayDivergence;
 use crate::model::{
     CurrentResourceLimits, ExecutionStatus, InvocationContext, LastError, ReadFileResult, TrapType,
     WorkerConfig,
@@ -116,9 +117,9 @@ use std::vec;
 use tempfile::TempDir;
 use tokio::sync::RwLock as TRwLock;
 use tokio_util::codec::{BytesCodec, FramedRead};
-use tracing::{debug, info, span, warn, Instrument, Level};
+use tracing::{debug, error, info, span, warn, Instrument, Level};
 use wasmtime::component::{Instance, Resource, ResourceAny};
-use wasmtime::{AsContext, AsContextMut};
+use wasmtime::{AsContext, AsContextMut, WasmBacktrace};
 use wasmtime_wasi::p2::bindings::filesystem::preopens::Descriptor;
 use wasmtime_wasi::p2::{FsResult, Stderr, Stdout, WasiCtx, WasiImpl, WasiView};
 use wasmtime_wasi::{I32Exit, IoCtx, IoImpl, IoView, ResourceTable, ResourceTableError};
@@ -265,6 +266,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 worker_config.created_by.clone(),
                 worker_config.initial_wasi_config_vars,
                 wasi_config_vars,
+                worker_config.block_remote_writes,
             )
             .await,
             temp_dir,
@@ -404,6 +406,17 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         self.state.total_linear_memory_size
     }
 
+    /// Enables or disables checking replayed durable function invocations against the requests
+    /// recorded in the oplog, overriding the `oplog.verify_replay` configuration.
+    pub fn set_replay_verification(&mut self, enabled: bool) {
+        self.state.verify_replay = enabled;
+    }
+
+    /// Gets the first detected divergence of the replay from the oplog
+    pub fn replay_divergence(&self) -> Option<&ReplayDivergence> {
+        self.state.replay_divergence.as_ref()
+    }
+
     pub async fn increase_memory(&mut self, delta: u64) -> anyhow::Result<bool> {
         if self.state.is_replay() {
             // The increased amount was already recorded in live mode, so our worker
@@ -557,7 +570,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                         .await;
                     durability.persist(self, (), result).await
                 } else {
-                    durability.replay(self).await
+                    durability.replay(self, ()).await
                 }?;
 
                 Ok(worker_id)
@@ -1118,6 +1131,15 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         decision
     }
 
+    fn on_guest_backtrace(&mut self, backtrace: &WasmBacktrace) {
+        if let Some(divergence) = &mut self.state.replay_divergence {
+            if divergence.guest_backtrace.is_none() {
+                divergence.guest_backtrace = Some(backtrace.to_string());
+                error!("{divergence}");
+            }
+        }
+    }
+
     async fn on_invocation_success(
         &mut self,
         full_function_name: &str,
@@ -2393,6 +2415,13 @@ struct PrivateDurableWorkerState {
     initial_wasi_config_vars: BTreeMap<String, String>,
     /// The current config vars of the worker, taking into account component version, etc.
     wasi_config_vars: RwLock<BTreeMap<String, String>>,
+
+    /// If true, replayed durable function invocations are checked against their recorded requests
+    verify_replay: bool,
+    /// The first detected divergence of the replay from the oplog, if any
+    replay_divergence: Option<ReplayDivergence>,
+    /// If true, remote writes fail in live mode
+    block_remote_writes: bool,
 }
 
 impl PrivateDurableWorkerState {
@@ -2425,6 +2454,7 @@ impl PrivateDurableWorkerState {
         created_by: AccountId,
         initial_wasi_config_vars: BTreeMap<String, String>,
         wasi_config_vars: BTreeMap<String, String>,
+        block_remote_writes: bool,
     ) -> Self {
         let replay_state = ReplayState::new(
             owned_worker_id.clone(),
@@ -2436,6 +2466,7 @@ impl PrivateDurableWorkerState {
         .await;
         let invocation_context = InvocationContext::new(None);
         let current_span_id = invocation_context.root.span_id().clone();
+        let verify_replay = config.oplog.verify_replay;
         Self {
             oplog_service,
             oplog: oplog.clone(),
@@ -2476,6 +2507,9 @@ impl PrivateDurableWorkerState {
             created_by,
             initial_wasi_config_vars,
             wasi_config_vars: RwLock::new(wasi_config_vars),
+            verify_replay,
+            replay_divergence: None,
+            block_remote_writes,
         }
     }
 
@@ -2483,6 +2517,17 @@ impl PrivateDurableWorkerState {
         &mut self,
         function_type: &DurableFunctionType,
     ) -> Result<OplogIndex, WorkerExecutorError> {
+        if self.block_remote_writes
+            && self.is_live()
+            && (*function_type == DurableFunctionType::WriteRemote
+                || matches!(*function_type, DurableFunctionType::WriteRemoteBatched(_)))
+        {
+            return Err(WorkerExecutorError::runtime(format!(
+                "Remote writes are blocked for worker {}",
+                self.owned_worker_id.worker_id
+            )));
+        }
+
         if (*function_type == DurableFunctionType::WriteRemote && !self.assume_idempotence)
             || matches!(
                 *function_type,
@@ -32,7 +32,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::get_insecure_random_bytes(&mut self.as_wasi_view(), len).await;
             durability.persist(self, len, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, len).await
         }
     }
 
@@ -49,7 +49,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::get_insecure_random_u64(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 }
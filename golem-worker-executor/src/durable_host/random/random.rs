@@ -31,7 +31,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::get_random_bytes(&mut self.as_wasi_view(), len).await;
             durability.persist(self, len, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, len).await
         }
     }
 
@@ -47,7 +47,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = Host::get_random_u64(&mut self.as_wasi_view()).await;
             durability.persist(self, (), result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, ()).await
         }
     }
 }
@@ -129,7 +129,8 @@ where
         let (input, result) = db_connection_execute(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
-        durability.replay(ctx).await
+        let input = db_connection_query_stream(statement, params, ctx, entry).ok();
+        durability.replay(ctx, input).await
     };
 
     Ok(result.map_err(|e| e.into()))
@@ -162,7 +163,8 @@ where
         let (input, result) = db_connection_query(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
-        durability.replay(ctx).await
+        let input = db_connection_query_stream(statement, params, ctx, entry).ok();
+        durability.replay(ctx, input).await
     };
 
     match result {
@@ -204,7 +206,8 @@ where
         let input = result.clone().ok();
         durability.persist(ctx, input, result).await
     } else {
-        durability.replay(ctx).await
+        let input = db_connection_query_stream(statement, params, ctx, entry).ok();
+        durability.replay(ctx, input).await
     };
     match result {
         Ok(request) => {
@@ -289,7 +292,7 @@ where
         };
         durability.persist(ctx, (), result).await
     } else {
-        durability.replay(ctx).await
+        durability.replay(ctx, ()).await
     };
 
     match result {
@@ -336,7 +339,7 @@ where
         };
         durability.persist(ctx, (), result).await
     } else {
-        durability.replay(ctx).await
+        durability.replay(ctx, ()).await
     };
 
     match result {
@@ -412,7 +415,8 @@ where
         let (input, result) = db_transaction_query(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
-        durability.replay(ctx).await
+        let input = db_transaction_query_stream(statement, params, ctx, entry).ok();
+        durability.replay(ctx, input).await
     };
 
     match result {
@@ -452,7 +456,8 @@ where
         let (input, result) = db_transaction_execute(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
-        durability.replay(ctx).await
+        let input = db_transaction_query_stream(statement, params, ctx, entry).ok();
+        durability.replay(ctx, input).await
     };
 
     Ok(result.map_err(|e| e.into()))
@@ -486,7 +491,8 @@ where
         let input = result.clone().ok();
         durability.persist(ctx, input, result).await
     } else {
-        durability.replay(ctx).await
+        let input = db_transaction_query_stream(statement, params, ctx, entry).ok();
+        durability.replay(ctx, input).await
     };
     match result {
         Ok(request) => {
@@ -526,7 +532,7 @@ where
         let result = db_transaction_rollback(ctx, entry).await;
         durability.persist(ctx, (), result).await
     } else {
-        durability.replay(ctx).await
+        durability.replay(ctx, ()).await
     };
 
     ctx.end_durable_function(
@@ -562,7 +568,7 @@ where
         let result = db_transaction_commit(ctx, entry).await;
         durability.persist(ctx, (), result).await
     } else {
-        durability.replay(ctx).await
+        durability.replay(ctx, ()).await
     };
 
     ctx.end_durable_function(
@@ -74,7 +74,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = resolve_and_drain_addresses(self, network, name.clone()).await;
             durability.persist(self, name, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, name).await
         };
 
         let stream = ResolveAddressStream::Done(Ok(addresses?.into_iter()));
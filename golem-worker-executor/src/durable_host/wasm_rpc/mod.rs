@@ -125,8 +125,9 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                 .await?;
             uuid
         } else {
-            let (high_bits, low_bits) =
-                durability.replay::<(u64, u64), anyhow::Error>(self).await?;
+            let (high_bits, low_bits) = durability
+                .replay::<(u64, u64), anyhow::Error>(self, ())
+                .await?;
             Uuid::from_u64_pair(high_bits, low_bits)
         };
         let idempotency_key = IdempotencyKey::from_uuid(uuid);
@@ -142,20 +143,20 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
             DurableFunctionType::WriteRemote,
         )
         .await?;
+        let input = SerializableInvokeRequest {
+            remote_worker_id: remote_worker_id.worker_id(),
+            idempotency_key: idempotency_key.clone(),
+            function_name: function_name.clone(),
+            function_params: try_get_typed_parameters(
+                self.state.component_service.clone(),
+                &remote_worker_id.project_id,
+                &remote_worker_id.worker_id.component_id,
+                &function_name,
+                &function_params,
+            )
+            .await,
+        };
         let result: Result<Option<WitValue>, RpcError> = if durability.is_live() {
-            let input = SerializableInvokeRequest {
-                remote_worker_id: remote_worker_id.worker_id(),
-                idempotency_key: idempotency_key.clone(),
-                function_name: function_name.clone(),
-                function_params: try_get_typed_parameters(
-                    self.state.component_service.clone(),
-                    &remote_worker_id.project_id,
-                    &remote_worker_id.worker_id.component_id,
-                    &function_name,
-                    &function_params,
-                )
-                .await,
-            };
             let stack = self
                 .state
                 .invocation_context
@@ -180,7 +181,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                 .await?;
             result.map(|value_and_type| value_and_type.map(WitValue::from))
         } else {
-            let (bytes, _oplog_entry_version) = durability.replay_raw(self).await?;
+            let (bytes, _oplog_entry_version) = durability.replay_raw(self, input).await?;
             let typed_value: Result<
                 Result<Option<ValueAndType>, SerializableError>,
                 WorkerExecutorError,
@@ -262,8 +263,9 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                 .await?;
             uuid
         } else {
-            let (high_bits, low_bits) =
-                durability.replay::<(u64, u64), anyhow::Error>(self).await?;
+            let (high_bits, low_bits) = durability
+                .replay::<(u64, u64), anyhow::Error>(self, ())
+                .await?;
             Uuid::from_u64_pair(high_bits, low_bits)
         };
 
@@ -280,20 +282,20 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
             DurableFunctionType::WriteRemote,
         )
         .await?;
+        let input = SerializableInvokeRequest {
+            remote_worker_id: remote_worker_id.worker_id(),
+            idempotency_key: idempotency_key.clone(),
+            function_name: function_name.clone(),
+            function_params: try_get_typed_parameters(
+                self.state.component_service.clone(),
+                &remote_worker_id.project_id,
+                &remote_worker_id.worker_id.component_id,
+                &function_name,
+                &function_params,
+            )
+            .await,
+        };
         let result: Result<(), RpcError> = if durability.is_live() {
-            let input = SerializableInvokeRequest {
-                remote_worker_id: remote_worker_id.worker_id(),
-                idempotency_key: idempotency_key.clone(),
-                function_name: function_name.clone(),
-                function_params: try_get_typed_parameters(
-                    self.state.component_service.clone(),
-                    &remote_worker_id.project_id,
-                    &remote_worker_id.worker_id.component_id,
-                    &function_name,
-                    &function_params,
-                )
-                .await,
-            };
             let stack = self
                 .state
                 .invocation_context
@@ -315,7 +317,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                 .await;
             durability.persist(self, input, result).await
         } else {
-            durability.replay(self).await
+            durability.replay(self, input).await
         };
 
         self.finish_span(span.span_id()).await?;
@@ -373,8 +375,9 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                 .await?;
             uuid
         } else {
-            let (high_bits, low_bits) =
-                durability.replay::<(u64, u64), anyhow::Error>(self).await?;
+            let (high_bits, low_bits) = durability
+                .replay::<(u64, u64), anyhow::Error>(self, ())
+                .await?;
             Uuid::from_u64_pair(high_bits, low_bits)
         };
         let idempotency_key = IdempotencyKey::from_uuid(uuid);
@@ -490,38 +493,38 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         )
         .await?;
 
-        let schedule_id = if durability.is_live() {
-            let entry = self.table().get(&this)?;
-            let payload = entry.payload.downcast_ref::<WasmRpcEntryPayload>().unwrap();
-            let remote_worker_id = payload.remote_worker_id().clone();
+        let entry = self.table().get(&this)?;
+        let payload = entry.payload.downcast_ref::<WasmRpcEntryPayload>().unwrap();
+        let remote_worker_id = payload.remote_worker_id().clone();
 
-            Self::add_self_parameter_if_needed(&mut function_params, payload);
+        Self::add_self_parameter_if_needed(&mut function_params, payload);
 
-            let current_idempotency_key = self
-                .state
-                .get_current_idempotency_key()
-                .expect("Expected to get an idempotency key as we are inside an invocation");
-
-            let current_oplog_index = self.state.current_oplog_index().await;
-
-            let idempotency_key =
-                IdempotencyKey::derived(&current_idempotency_key, current_oplog_index);
-
-            let serializable_input = SerializableScheduleInvocationRequest {
-                remote_worker_id: remote_worker_id.worker_id(),
-                idempotency_key: idempotency_key.clone(),
-                function_name: function_name.clone(),
-                function_params: try_get_typed_parameters(
-                    self.state.component_service.clone(),
-                    &remote_worker_id.project_id,
-                    &remote_worker_id.worker_id.component_id,
-                    &function_name,
-                    &function_params,
-                )
-                .await,
-                datetime: <SerializableDateTime as From<DateTime<Utc>>>::from(datetime.into()),
-            };
+        let current_idempotency_key = self
+            .state
+            .get_current_idempotency_key()
+            .expect("Expected to get an idempotency key as we are inside an invocation");
+
+        let current_oplog_index = self.state.current_oplog_index().await;
+
+        let idempotency_key =
+            IdempotencyKey::derived(&current_idempotency_key, current_oplog_index);
+
+        let serializable_input = SerializableScheduleInvocationRequest {
+            remote_worker_id: remote_worker_id.worker_id(),
+            idempotency_key: idempotency_key.clone(),
+            function_name: function_name.clone(),
+            function_params: try_get_typed_parameters(
+                self.state.component_service.clone(),
+                &remote_worker_id.project_id,
+                &remote_worker_id.worker_id.component_id,
+                &function_name,
+                &function_params,
+            )
+            .await,
+            datetime: <SerializableDateTime as From<DateTime<Utc>>>::from(datetime.into()),
+        };
 
+        let schedule_id = if durability.is_live() {
             let stack = self
                 .state
                 .invocation_context
@@ -554,7 +557,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
             serializable_schedule_id
         } else {
             durability
-                .replay::<SerializableScheduleId, WorkerExecutorError>(self)
+                .replay::<SerializableScheduleId, WorkerExecutorError>(self, serializable_input)
                 .await?
         };
 
@@ -1081,7 +1084,9 @@ impl<Ctx: WorkerCtx> HostCancellationToken for DurableWorkerCtx<Ctx> {
                 .persist_serializable(self, schedule_id, Ok(()))
                 .await?;
         } else {
-            durability.replay::<(), WorkerExecutorError>(self).await?;
+            durability
+                .replay::<(), WorkerExecutorError>(self, schedule_id)
+                .await?;
         };
 
         Ok(())
@@ -19,15 +19,17 @@ use crate::model::event::InternalWorkerEvent;
 use crate::model::public_oplog::{
     find_component_version_at, get_public_oplog_chunk, search_public_oplog,
 };
+use crate::model::worker_trace::{export_worker_trace, import_worker_trace, WorkerTrace};
 use crate::model::{LastError, ReadFileResult};
 use crate::services::events::Event;
+use crate::services::oplog::{CommitLevel, Oplog};
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
-    All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplogService, HasPlugins,
-    HasProjectService, HasPromiseService, HasRunningWorkerEnumerationService,
-    HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerService,
-    UsesAllDeps,
+    All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasFileLoader, HasOplog,
+    HasOplogService, HasPlugins, HasProjectService, HasPromiseService,
+    HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService,
+    HasWorkerEnumerationService, HasWorkerService, UsesAllDeps,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -40,14 +42,15 @@ use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::Wo
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
     ActivatePluginRequest, ActivatePluginResponse, CancelInvocationRequest,
     CancelInvocationResponse, ConnectWorkerRequest, DeactivatePluginRequest,
-    DeactivatePluginResponse, DeleteWorkerRequest, ForkWorkerRequest, ForkWorkerResponse,
-    GetFileContentsRequest, GetFileContentsResponse, GetFileSystemNodeRequest,
-    GetFileSystemNodeResponse, GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
-    GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    InvokeAndAwaitWorkerJsonRequest, InvokeAndAwaitWorkerRequest,
-    InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess, InvokeJsonWorkerRequest,
-    InvokeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest,
-    SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    DeactivatePluginResponse, DeleteWorkerRequest, ExportWorkerTraceRequest,
+    ExportWorkerTraceResponse, ForkWorkerRequest, ForkWorkerResponse, GetFileContentsRequest,
+    GetFileContentsResponse, GetFileSystemNodeRequest, GetFileSystemNodeResponse, GetOplogRequest,
+    GetOplogResponse, GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataResponse,
+    GetWorkersMetadataRequest, GetWorkersMetadataResponse, ImportWorkerTraceRequest,
+    ImportWorkerTraceRequestHeader, ImportWorkerTraceResponse, InvokeAndAwaitWorkerJsonRequest,
+    InvokeAndAwaitWorkerRequest, InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess,
+    InvokeJsonWorkerRequest, InvokeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse,
+    SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
 };
 use golem_common::grpc::{
     proto_account_id_string, proto_component_id_string, proto_idempotency_key_string,
@@ -64,6 +67,7 @@ use golem_common::model::{
 };
 use golem_common::{model as common_model, recorded_grpc_api_request};
 use golem_service_base::error::worker_executor::*;
+use golem_service_base::grpc::export_worker_trace_response_chunks;
 use golem_wasm_rpc::protobuf::Val;
 use golem_wasm_rpc::ValueAndType;
 use std::cmp::min;
@@ -76,7 +80,7 @@ use std::task::{Context, Poll};
 use tokio;
 use tokio::sync::broadcast::error::RecvError;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 use tracing::info_span;
 use tracing::{debug, info, warn, Instrument};
 use uuid::Uuid;
@@ -1684,6 +1688,71 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn export_worker_trace_internal(
+        &self,
+        request: ExportWorkerTraceRequest,
+    ) -> Result<Vec<u8>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        if self.worker_service().get(&owned_worker_id).await.is_none() {
+            return Err(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ));
+        }
+
+        // Making sure everything the worker did so far is in the exported oplog
+        let worker = Worker::get_or_create_suspended(
+            self,
+            &account_id,
+            &owned_worker_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await?;
+        worker.oplog().commit(CommitLevel::Always).await;
+
+        let trace = export_worker_trace(
+            self.component_service(),
+            self.oplog_service(),
+            self.file_loader(),
+            &owned_worker_id,
+        )
+        .await?;
+
+        trace.to_bytes().map_err(WorkerExecutorError::unknown)
+    }
+
+    async fn import_worker_trace_internal(
+        &self,
+        header: ImportWorkerTraceRequestHeader,
+        trace: Vec<u8>,
+    ) -> Result<(), WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&header, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&header, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let trace = WorkerTrace::from_bytes(&trace).map_err(|err| {
+            WorkerExecutorError::invalid_request(format!("Invalid worker trace: {err}"))
+        })?;
+
+        import_worker_trace(
+            self.component_service(),
+            self.oplog_service(),
+            self.file_loader(),
+            &account_id,
+            &owned_worker_id,
+            trace,
+        )
+        .await
+    }
+
     fn create_proto_metadata(
         metadata: WorkerMetadata,
         last_error_and_retry_count: Option<LastError>,
@@ -2775,6 +2844,107 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             ),
         }
     }
+
+    type ExportWorkerTraceStream =
+        Pin<Box<dyn Stream<Item = Result<ExportWorkerTraceResponse, Status>> + Send + 'static>>;
+
+    async fn export_worker_trace(
+        &self,
+        request: Request<ExportWorkerTraceRequest>,
+    ) -> ResponseResult<Self::ExportWorkerTraceStream> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "export_worker_trace",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .export_worker_trace_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        let stream: Self::ExportWorkerTraceStream = match result {
+            Ok(trace) => record.succeed(Box::pin(tokio_stream::iter(
+                export_worker_trace_response_chunks(&trace)
+                    .into_iter()
+                    .map(Ok),
+            ))),
+            Err(err) => {
+                let res = ExportWorkerTraceResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::export_worker_trace_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                };
+
+                let err_stream: Self::ExportWorkerTraceStream =
+                    Box::pin(tokio_stream::iter(vec![Ok(res)]));
+
+                record.fail(err_stream, &err)
+            }
+        };
+        Ok(Response::new(stream))
+    }
+
+    async fn import_worker_trace(
+        &self,
+        request: Request<Streaming<ImportWorkerTraceRequest>>,
+    ) -> Result<Response<ImportWorkerTraceResponse>, Status> {
+        let mut messages = request.into_inner();
+        let mut header = None;
+        let mut trace = Vec::new();
+
+        while let Some(message) = messages.message().await? {
+            match message.data {
+                Some(golem::workerexecutor::v1::import_worker_trace_request::Data::Header(h)) => {
+                    header = Some(h)
+                }
+                Some(golem::workerexecutor::v1::import_worker_trace_request::Data::Chunk(
+                    chunk,
+                )) => trace.extend(chunk),
+                None => {}
+            }
+        }
+
+        let record = recorded_grpc_api_request!(
+            "import_worker_trace",
+            worker_id = proto_worker_id_string(
+                &header.as_ref().and_then(|header| header.worker_id.clone())
+            ),
+        );
+
+        let result = match header {
+            Some(header) => {
+                self.import_worker_trace_internal(header, trace)
+                    .instrument(record.span.clone())
+                    .await
+            }
+            None => Err(WorkerExecutorError::invalid_request(
+                "Missing header of the imported worker trace",
+            )),
+        };
+
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(ImportWorkerTraceResponse {
+                result: Some(
+                    golem::workerexecutor::v1::import_worker_trace_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ImportWorkerTraceResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::import_worker_trace_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
 }
 
 pub fn authorised_grpc_request<T>(request: T, access_token: &Uuid) -> Request<T> {
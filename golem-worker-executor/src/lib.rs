@@ -207,16 +207,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
     /// Can be overridden to customize the wasmtime configuration
     fn create_wasmtime_config(&self) -> Config {
-        let mut config = Config::default();
-
-        config.wasm_multi_value(true);
-        config.async_support(true);
-        config.wasm_component_model(true);
-        config.epoch_interruption(true);
-        config.consume_fuel(true);
-        config.wasm_backtrace_details(WasmBacktraceDetails::Enable);
-
-        config
+        create_default_wasmtime_config()
     }
 
     /// This method is responsible for linking all the host function implementations the worker
@@ -273,6 +264,21 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
     }
 }
 
+/// The wasmtime configuration used by the default implementation of
+/// `Bootstrap::create_wasmtime_config`
+pub fn create_default_wasmtime_config() -> Config {
+    let mut config = Config::default();
+
+    config.wasm_multi_value(true);
+    config.async_support(true);
+    config.wasm_component_model(true);
+    config.epoch_interruption(true);
+    config.consume_fuel(true);
+    config.wasm_backtrace_details(WasmBacktraceDetails::Enable);
+
+    config
+}
+
 pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Sized>(
     golem_config: GolemConfig,
     bootstrap: &A,
@@ -36,6 +36,8 @@ use wasmtime::Trap;
 
 pub mod event;
 pub mod public_oplog;
+pub mod replay_divergence;
+pub mod worker_trace;
 
 pub trait ShardAssignmentCheck {
     fn check_worker(&self, worker_id: &WorkerId) -> Result<(), WorkerExecutorError>;
@@ -55,6 +57,11 @@ impl ShardAssignmentCheck for ShardAssignment {
     }
 }
 
+/// Environment variable which, when set to `true`, makes every remote write of the worker (such as
+/// outgoing HTTP requests, RPC calls, or writes to key-value and blob stores) fail in live mode.
+/// Used for scratch copies of workers, which must not cause any side effects when invoked.
+pub const BLOCK_REMOTE_WRITES_ENV_VAR: &str = "GOLEM_BLOCK_REMOTE_WRITES";
+
 /// Worker-specific configuration. These values are used to initialize the worker, and they can
 /// be different for each worker.
 #[derive(Clone, Debug)]
@@ -66,6 +73,7 @@ pub struct WorkerConfig {
     pub component_version_for_replay: u64,
     pub created_by: AccountId,
     pub initial_wasi_config_vars: BTreeMap<String, String>,
+    pub block_remote_writes: bool,
 }
 
 impl WorkerConfig {
@@ -83,6 +91,9 @@ impl WorkerConfig {
         let worker_name = worker_id.worker_name.clone();
         let component_id = worker_id.component_id;
         let component_version = target_component_version.to_string();
+        let block_remote_writes = worker_env
+            .iter()
+            .any(|(key, value)| key == BLOCK_REMOTE_WRITES_ENV_VAR && value == "true");
         worker_env.retain(|(key, _)| {
             key != "GOLEM_WORKER_NAME"
                 && key != "GOLEM_COMPONENT_ID"
@@ -99,6 +110,7 @@ impl WorkerConfig {
             component_version_for_replay,
             created_by,
             initial_wasi_config_vars,
+            block_remote_writes,
         }
     }
 }
@@ -933,4 +945,42 @@ mod tests {
         assert_eq!(w, None);
         assert_eq!(a, Some(vec![s("00"), s("0")]));
     }
+
+    fn worker_config(env: Vec<(String, String)>) -> WorkerConfig {
+        WorkerConfig::new(
+            WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "worker".to_string(),
+            },
+            0,
+            vec![],
+            env,
+            DeletedRegions::new(),
+            0,
+            0,
+            AccountId {
+                value: "account".to_string(),
+            },
+            BTreeMap::new(),
+        )
+    }
+
+    #[test]
+    fn remote_writes_are_blocked_by_env_var() {
+        assert!(!worker_config(vec![]).block_remote_writes);
+        assert!(
+            !worker_config(vec![(
+                BLOCK_REMOTE_WRITES_ENV_VAR.to_string(),
+                "false".to_string()
+            )])
+            .block_remote_writes
+        );
+        assert!(
+            worker_config(vec![(
+                BLOCK_REMOTE_WRITES_ENV_VAR.to_string(),
+                "true".to_string()
+            )])
+            .block_remote_writes
+        );
+    }
 }
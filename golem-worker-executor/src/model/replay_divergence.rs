@@ -0,0 +1,157 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_common::model::oplog::OplogIndex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Describes the first point where a replaying worker stopped matching its oplog.
+///
+/// Only detected when replay verification is enabled, in which case every replayed
+/// `ImportedFunctionInvoked` entry is compared with the host function call the guest is
+/// performing, both by its name and by its serialized request. Invocations persisted by the
+/// guest itself through the `golem:durability` interface are not verified.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayDivergence {
+    pub oplog_index: OplogIndex,
+    /// The invocation recorded in the oplog
+    pub expected: ReplayedInvocation,
+    /// The invocation the guest performed during replay
+    pub actual: ReplayedInvocation,
+    /// Debug representation of the request of the actual invocation
+    pub actual_input: String,
+    /// The guest's WebAssembly backtrace at the point of divergence, if it was available
+    pub guest_backtrace: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayedInvocation {
+    pub function_name: String,
+    /// The request of the invocation, serialized the same way it is stored in the oplog
+    pub request: Vec<u8>,
+}
+
+impl ReplayDivergence {
+    /// Returns the divergence if the expected and actual invocations are not the same
+    pub fn check(
+        oplog_index: OplogIndex,
+        expected: ReplayedInvocation,
+        actual: ReplayedInvocation,
+        actual_input: String,
+    ) -> Option<Self> {
+        if expected == actual {
+            None
+        } else {
+            Some(Self {
+                oplog_index,
+                expected,
+                actual,
+                actual_input,
+                guest_backtrace: None,
+            })
+        }
+    }
+}
+
+impl Display for ReplayDivergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.expected.function_name != self.actual.function_name {
+            write!(
+                f,
+                "Replay diverged from the oplog at index {}: expected a call to {}, but the guest called {} with {}",
+                self.oplog_index,
+                self.expected.function_name,
+                self.actual.function_name,
+                self.actual_input
+            )?;
+        } else {
+            let first_difference = self
+                .expected
+                .request
+                .iter()
+                .zip(self.actual.request.iter())
+                .position(|(expected, actual)| expected != actual)
+                .unwrap_or(self.expected.request.len().min(self.actual.request.len()));
+            write!(
+                f,
+                "Replay diverged from the oplog at index {}: the guest called {} with a different request ({}); the serialized requests first differ at byte {} (expected {} bytes, got {} bytes)",
+                self.oplog_index,
+                self.actual.function_name,
+                self.actual_input,
+                first_difference,
+                self.expected.request.len(),
+                self.actual.request.len()
+            )?;
+        }
+        if let Some(backtrace) = &self.guest_backtrace {
+            write!(f, "\nGuest backtrace:\n{backtrace}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_r::test;
+
+    fn invocation(function_name: &str, request: &[u8]) -> ReplayedInvocation {
+        ReplayedInvocation {
+            function_name: function_name.to_string(),
+            request: request.to_vec(),
+        }
+    }
+
+    #[test]
+    fn matching_invocations_do_not_diverge() {
+        let divergence = ReplayDivergence::check(
+            OplogIndex::from_u64(5),
+            invocation("golem::api::get-self-metadata", &[1, 2, 3]),
+            invocation("golem::api::get-self-metadata", &[1, 2, 3]),
+            "()".to_string(),
+        );
+        assert_eq!(divergence, None);
+    }
+
+    #[test]
+    fn different_function_names_are_reported() {
+        let divergence = ReplayDivergence::check(
+            OplogIndex::from_u64(5),
+            invocation("wasi:clocks/wall-clock::now", &[0]),
+            invocation("wasi:random/random::get-random-bytes", &[0]),
+            "16".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            divergence.to_string(),
+            "Replay diverged from the oplog at index 5: expected a call to wasi:clocks/wall-clock::now, but the guest called wasi:random/random::get-random-bytes with 16"
+        );
+    }
+
+    #[test]
+    fn different_requests_are_reported_with_backtrace() {
+        let mut divergence = ReplayDivergence::check(
+            OplogIndex::from_u64(12),
+            invocation("golem blobstore::container::get_data", &[1, 2, 3, 4]),
+            invocation("golem blobstore::container::get_data", &[1, 2, 7]),
+            "(\"container\", \"key\")".to_string(),
+        )
+        .unwrap();
+        divergence.guest_backtrace = Some("0: guest::run".to_string());
+        assert_eq!(
+            divergence.to_string(),
+            "Replay diverged from the oplog at index 12: the guest called golem blobstore::container::get_data with a different request ((\"container\", \"key\")); the serialized requests first differ at byte 2 (expected 4 bytes, got 3 bytes)\nGuest backtrace:\n0: guest::run"
+        );
+    }
+}
@@ -0,0 +1,435 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::ExecutionStatus;
+use crate::services::component::ComponentService;
+use crate::services::file_loader::FileLoader;
+use crate::services::oplog::{CommitLevel, Oplog, OplogService};
+use bincode::{Decode, Encode};
+use bytes::Bytes;
+use golem_common::model::component::{ComponentOwner, VersionedComponentId};
+use golem_common::model::component_metadata::ComponentMetadata;
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload, PayloadId};
+use golem_common::model::{
+    AccountId, ComponentFilePath, ComponentFilePermissions, ComponentType, ComponentVersion,
+    InitialComponentFile, OwnedWorkerId, Timestamp, WorkerId, WorkerMetadata, WorkerStatusRecord,
+};
+use golem_common::serialization::{deserialize, serialize};
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::{Component, ComponentName};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+const WORKER_TRACE_PAGE_SIZE: u64 = 1000;
+
+/// A self-contained recording of a worker's execution, portable between Golem deployments.
+///
+/// Next to the whole oplog it contains everything needed to replay it: the externally stored
+/// oplog payloads, and the binary and initial files of every component version the worker used.
+/// The arguments, environment and config vars of the worker are part of the oplog's create entry.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct WorkerTrace {
+    pub worker_id: WorkerId,
+    /// All the oplog entries, starting from `OplogIndex::INITIAL`
+    pub entries: Vec<OplogEntry>,
+    pub payloads: Vec<WorkerTracePayload>,
+    pub components: Vec<WorkerTraceComponent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct WorkerTracePayload {
+    pub payload_id: PayloadId,
+    pub md5_hash: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct WorkerTraceComponent {
+    pub version: ComponentVersion,
+    pub name: String,
+    pub component_type: ComponentType,
+    pub metadata: ComponentMetadata,
+    pub env: HashMap<String, String>,
+    pub files: Vec<WorkerTraceFile>,
+    pub binary: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct WorkerTraceFile {
+    pub path: String,
+    pub read_only: bool,
+    pub content: Vec<u8>,
+}
+
+impl WorkerTrace {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        serialize(self).map(|bytes| bytes.to_vec())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        deserialize(bytes)
+    }
+
+    fn component_type_at(&self, version: ComponentVersion) -> Option<ComponentType> {
+        self.components
+            .iter()
+            .find(|component| component.version == version)
+            .map(|component| component.component_type)
+    }
+}
+
+pub async fn export_worker_trace(
+    component_service: Arc<dyn ComponentService>,
+    oplog_service: Arc<dyn OplogService>,
+    file_loader: Arc<FileLoader>,
+    owned_worker_id: &OwnedWorkerId,
+) -> Result<WorkerTrace, WorkerExecutorError> {
+    let worker_id = owned_worker_id.worker_id();
+    let project_id = owned_worker_id.project_id();
+
+    if !oplog_service.exists(owned_worker_id).await {
+        return Err(WorkerExecutorError::worker_not_found(worker_id));
+    }
+
+    let last_index = oplog_service.get_last_index(owned_worker_id).await;
+
+    let mut entries = Vec::new();
+    let mut payloads = Vec::new();
+    let mut component_versions = BTreeSet::new();
+    let mut next_index = OplogIndex::INITIAL;
+
+    while next_index <= last_index {
+        let page = oplog_service
+            .read(owned_worker_id, next_index, WORKER_TRACE_PAGE_SIZE)
+            .await;
+
+        if page.is_empty() {
+            break;
+        }
+
+        for (index, entry) in page {
+            for payload in entry.payloads() {
+                if let OplogPayload::External {
+                    payload_id,
+                    md5_hash,
+                } = payload
+                {
+                    let data = oplog_service
+                        .download_payload(owned_worker_id, payload)
+                        .await
+                        .map_err(WorkerExecutorError::unknown)?;
+
+                    payloads.push(WorkerTracePayload {
+                        payload_id: payload_id.clone(),
+                        md5_hash: md5_hash.clone(),
+                        data: data.to_vec(),
+                    });
+                }
+            }
+
+            if let Some(version) = entry.specifies_component_version() {
+                component_versions.insert(version);
+            }
+
+            entries.push(entry);
+            next_index = index.next();
+        }
+    }
+
+    let mut components = Vec::new();
+    for version in component_versions {
+        let metadata = component_service
+            .get_metadata(&project_id, &worker_id.component_id, Some(version))
+            .await?;
+        let binary = component_service
+            .get_binary(&project_id, &worker_id.component_id, version)
+            .await?;
+
+        let mut files = Vec::new();
+        for file in &metadata.files {
+            let content = file_loader.get_bytes(&project_id, &file.key).await?;
+            files.push(WorkerTraceFile {
+                path: file.path.to_string(),
+                read_only: file.is_read_only(),
+                content: content.to_vec(),
+            });
+        }
+
+        components.push(WorkerTraceComponent {
+            version,
+            name: metadata.component_name.0,
+            component_type: metadata.component_type,
+            metadata: metadata.metadata,
+            env: metadata.env,
+            files,
+            binary,
+        });
+    }
+
+    Ok(WorkerTrace {
+        worker_id,
+        entries,
+        payloads,
+        components,
+    })
+}
+
+/// Recreates the worker recorded in the trace as a suspended worker with the given id, owned by the
+/// given account. The trace must come from a worker of the same component.
+pub async fn import_worker_trace(
+    component_service: Arc<dyn ComponentService>,
+    oplog_service: Arc<dyn OplogService>,
+    file_loader: Arc<FileLoader>,
+    account_id: &AccountId,
+    owned_worker_id: &OwnedWorkerId,
+    trace: WorkerTrace,
+) -> Result<(), WorkerExecutorError> {
+    let worker_id = owned_worker_id.worker_id();
+    let project_id = owned_worker_id.project_id();
+
+    if trace.worker_id.component_id != worker_id.component_id {
+        return Err(WorkerExecutorError::invalid_request(format!(
+            "The trace of worker {} cannot be imported as a worker of component {}",
+            trace.worker_id, worker_id.component_id
+        )));
+    }
+
+    if oplog_service.exists(owned_worker_id).await {
+        return Err(WorkerExecutorError::worker_already_exists(worker_id));
+    }
+
+    let (args, env, wasi_config_vars, component_type) = match trace.entries.first() {
+        Some(OplogEntry::Create {
+            args,
+            env,
+            wasi_config_vars,
+            component_version,
+            ..
+        }) => (
+            args.clone(),
+            env.clone(),
+            wasi_config_vars.clone(),
+            trace.component_type_at(*component_version).ok_or_else(|| {
+                WorkerExecutorError::invalid_request(format!(
+                    "The trace does not contain version {component_version} of the component"
+                ))
+            })?,
+        ),
+        _ => {
+            return Err(WorkerExecutorError::invalid_request(
+                "The trace must start with the create entry of the worker",
+            ))
+        }
+    };
+
+    let recorded_payloads = trace
+        .payloads
+        .iter()
+        .map(|payload| payload.payload_id.0)
+        .collect::<HashSet<_>>();
+    for entry in &trace.entries {
+        for payload in entry.payloads() {
+            if let OplogPayload::External { payload_id, .. } = payload {
+                if !recorded_payloads.contains(&payload_id.0) {
+                    return Err(WorkerExecutorError::invalid_request(format!(
+                        "The trace does not contain the oplog payload {payload_id}"
+                    )));
+                }
+            }
+        }
+    }
+
+    for component in trace.components {
+        let mut files = Vec::new();
+        for file in component.files {
+            let key = file_loader
+                .put(&project_id, Bytes::from(file.content))
+                .await?;
+            let path = ComponentFilePath::from_abs_str(&file.path)
+                .map_err(WorkerExecutorError::invalid_request)?;
+            let permissions = if file.read_only {
+                ComponentFilePermissions::ReadOnly
+            } else {
+                ComponentFilePermissions::ReadWrite
+            };
+
+            files.push(InitialComponentFile {
+                key,
+                path,
+                permissions,
+            });
+        }
+
+        component_service
+            .import(
+                Component {
+                    owner: ComponentOwner {
+                        account_id: account_id.clone(),
+                        project_id: project_id.clone(),
+                    },
+                    versioned_component_id: VersionedComponentId {
+                        component_id: worker_id.component_id.clone(),
+                        version: component.version,
+                    },
+                    component_name: ComponentName(component.name),
+                    component_size: component.binary.len() as u64,
+                    metadata: component.metadata,
+                    created_at: chrono::Utc::now(),
+                    component_type: component.component_type,
+                    files,
+                    installed_plugins: vec![],
+                    env: component.env,
+                },
+                component.binary,
+            )
+            .await?;
+    }
+
+    // Payloads are stored per worker, so they get new references in the imported oplog
+    let mut uploaded_payloads = HashMap::new();
+    for payload in trace.payloads {
+        let uploaded = oplog_service
+            .upload_payload(owned_worker_id, &payload.data)
+            .await
+            .map_err(WorkerExecutorError::unknown)?;
+        uploaded_payloads.insert(payload.payload_id.0, uploaded);
+    }
+
+    let mut entries = trace.entries.into_iter().map(|entry| {
+        entry.map_payloads(|payload| {
+            let uploaded = match &payload {
+                OplogPayload::External { payload_id, .. } => {
+                    uploaded_payloads.get(&payload_id.0).cloned()
+                }
+                OplogPayload::Inline(_) => None,
+            };
+            uploaded.unwrap_or(payload)
+        })
+    });
+
+    let initial_entry = entries
+        .next()
+        .and_then(|entry| entry.update_worker_id(&worker_id))
+        .and_then(|entry| entry.update_owner(&project_id, account_id))
+        .ok_or(WorkerExecutorError::unknown(
+            "Failed to update the owner of the imported worker in its create entry",
+        ))?;
+
+    let oplog = oplog_service
+        .create(
+            owned_worker_id,
+            initial_entry,
+            WorkerMetadata {
+                worker_id: worker_id.clone(),
+                args,
+                env,
+                project_id: project_id.clone(),
+                created_by: account_id.clone(),
+                wasi_config_vars,
+                created_at: Timestamp::now_utc(),
+                parent: None,
+                last_known_status: WorkerStatusRecord::default(),
+            },
+            Arc::new(RwLock::new(ExecutionStatus::Suspended {
+                last_known_status: WorkerStatusRecord::default(),
+                component_type,
+                timestamp: Timestamp::now_utc(),
+            })),
+        )
+        .await;
+
+    for entry in entries {
+        oplog.add(entry).await;
+    }
+
+    oplog.commit(CommitLevel::Always).await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_common::model::invocation_context::TraceId;
+    use golem_common::model::oplog::DurableFunctionType;
+    use golem_common::model::{ComponentId, IdempotencyKey};
+    use test_r::test;
+    use uuid::Uuid;
+
+    #[test]
+    fn worker_trace_roundtrip() {
+        let payload_id = PayloadId::new();
+        let trace = WorkerTrace {
+            worker_id: WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "worker-1".to_string(),
+            },
+            entries: vec![
+                OplogEntry::ExportedFunctionInvoked {
+                    timestamp: Timestamp::now_utc(),
+                    function_name: "golem:it/api.{add-item}".to_string(),
+                    request: OplogPayload::Inline(vec![1, 2, 3]),
+                    idempotency_key: IdempotencyKey::fresh(),
+                    trace_id: TraceId::generate(),
+                    trace_states: vec![],
+                    invocation_context: vec![],
+                },
+                OplogEntry::ImportedFunctionInvoked {
+                    timestamp: Timestamp::now_utc(),
+                    function_name: "http::types::future_incoming_response::get".to_string(),
+                    request: OplogPayload::Inline(vec![]),
+                    response: OplogPayload::External {
+                        payload_id: payload_id.clone(),
+                        md5_hash: vec![4, 5, 6],
+                    },
+                    durable_function_type: DurableFunctionType::ReadRemote,
+                },
+            ],
+            payloads: vec![WorkerTracePayload {
+                payload_id,
+                md5_hash: vec![4, 5, 6],
+                data: vec![7; 1024],
+            }],
+            components: vec![WorkerTraceComponent {
+                version: 0,
+                name: "shopping-cart".to_string(),
+                component_type: ComponentType::Durable,
+                metadata: ComponentMetadata {
+                    exports: vec![],
+                    producers: vec![],
+                    memories: vec![],
+                    binary_wit: Default::default(),
+                    root_package_name: None,
+                    root_package_version: None,
+                    dynamic_linking: HashMap::new(),
+                    agent_types: vec![],
+                },
+                env: HashMap::from([("KEY".to_string(), "value".to_string())]),
+                files: vec![WorkerTraceFile {
+                    path: "/files/config.json".to_string(),
+                    read_only: true,
+                    content: b"{}".to_vec(),
+                }],
+                binary: vec![0, 97, 115, 109],
+            }],
+        };
+
+        let bytes = trace.to_bytes().unwrap();
+        let decoded = WorkerTrace::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded, trace);
+        assert_eq!(decoded.component_type_at(0), Some(ComponentType::Durable));
+        assert_eq!(decoded.component_type_at(1), None);
+    }
+}
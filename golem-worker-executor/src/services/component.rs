@@ -56,6 +56,23 @@ pub trait ComponentService: Send + Sync {
         component_reference: String,
         resolving_component: ComponentOwner,
     ) -> Result<Option<ComponentId>, WorkerExecutorError>;
+
+    /// Gets the original, uncompiled binary of a specific component version
+    async fn get_binary(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+        component_version: ComponentVersion,
+    ) -> Result<Vec<u8>, WorkerExecutorError>;
+
+    /// Makes a component version coming from an imported worker trace available for the executor.
+    /// Only the local file system implementation stores new components, the others require the
+    /// component version to already exist.
+    async fn import(
+        &self,
+        component: golem_service_base::model::Component,
+        binary: Vec<u8>,
+    ) -> Result<(), WorkerExecutorError>;
 }
 
 pub fn configured(
@@ -451,6 +468,97 @@ mod filesystem {
                 .get(&component_reference)
                 .cloned())
         }
+
+        async fn get_binary(
+            &self,
+            _project_id: &ProjectId,
+            component_id: &ComponentId,
+            component_version: ComponentVersion,
+        ) -> Result<Vec<u8>, WorkerExecutorError> {
+            let key = ComponentKey {
+                component_id: component_id.clone(),
+                component_version,
+            };
+            let metadata = self.index.read().await.metadata.get(&key).cloned();
+
+            let metadata = if let Some(metadata) = metadata {
+                metadata
+            } else {
+                self.refresh_index().await?;
+                let metadata = self.index.read().await.metadata.get(&key).cloned();
+                metadata.ok_or(WorkerExecutorError::unknown(format!(
+                    "No such component found: {component_id}/{component_version}"
+                )))?
+            };
+
+            Ok(tokio::fs::read(self.root.join(metadata.wasm_filename)).await?)
+        }
+
+        async fn import(
+            &self,
+            component: golem_service_base::model::Component,
+            binary: Vec<u8>,
+        ) -> Result<(), WorkerExecutorError> {
+            let component_id = component.versioned_component_id.component_id.clone();
+            let component_version = component.versioned_component_id.version;
+            let key = ComponentKey {
+                component_id: component_id.clone(),
+                component_version,
+            };
+
+            self.refresh_index().await?;
+            let existing_wasm_filename = self
+                .index
+                .read()
+                .await
+                .metadata
+                .get(&key)
+                .map(|metadata| metadata.wasm_filename.clone());
+            if let Some(existing_wasm_filename) = existing_wasm_filename {
+                let existing_binary =
+                    tokio::fs::read(self.root.join(existing_wasm_filename)).await?;
+                return if existing_binary == binary {
+                    debug!("Component {component_id}/{component_version} is already in the local component store");
+                    Ok(())
+                } else {
+                    Err(WorkerExecutorError::invalid_request(format!(
+                        "Component {component_id}/{component_version} in the local component store is different from the imported one"
+                    )))
+                };
+            }
+
+            let wasm_filename = format!("{component_id}-{component_version}.wasm");
+            tokio::fs::write(self.root.join(&wasm_filename), &binary).await?;
+
+            let metadata = LocalFileSystemComponentMetadata {
+                account_id: component.owner.account_id,
+                project_id: component.owner.project_id,
+                component_id: component_id.clone(),
+                version: component_version,
+                size: binary.len() as u64,
+                memories: component.metadata.memories,
+                exports: component.metadata.exports,
+                component_type: component.component_type,
+                files: component.files,
+                component_name: component.component_name.0,
+                wasm_filename,
+                dynamic_linking: component.metadata.dynamic_linking,
+                env: component.env,
+            };
+            let metadata = serde_json::to_string(&metadata).map_err(|e| {
+                WorkerExecutorError::unknown(format!(
+                    "Failed to serialize properties of component {component_id}/{component_version}: {e}"
+                ))
+            })?;
+            tokio::fs::write(
+                self.root
+                    .join(format!("{component_id}-{component_version}.json")),
+                metadata,
+            )
+            .await?;
+
+            self.refresh_index().await
+        }
     }
 
     struct ComponentMetadataIndex {
@@ -860,6 +968,68 @@ mod grpc {
                 })
                 .await
         }
+
+        async fn get_binary(
+            &self,
+            _project_id: &ProjectId,
+            component_id: &ComponentId,
+            component_version: ComponentVersion,
+        ) -> Result<Vec<u8>, WorkerExecutorError> {
+            download_via_grpc(
+                &self.component_client,
+                &self.access_token,
+                &self.retry_config,
+                component_id,
+                component_version,
+            )
+            .await
+        }
+
+        async fn import(
+            &self,
+            component: golem_service_base::model::Component,
+            binary: Vec<u8>,
+        ) -> Result<(), WorkerExecutorError> {
+            // Components cannot be uploaded through the executor, so the imported version
+            // has to be the one already known by the component service
+            let component_id = &component.versioned_component_id.component_id;
+            let component_version = component.versioned_component_id.version;
+
+            let existing = self
+                .get_metadata(
+                    &component.owner.project_id,
+                    component_id,
+                    Some(component_version),
+                )
+                .await
+                .map_err(|err| {
+                    WorkerExecutorError::invalid_request(format!(
+                        "Component {component_id}/{component_version} is not available in the component service: {err}"
+                    ))
+                })?;
+
+            let different_from_imported = || {
+                WorkerExecutorError::invalid_request(format!(
+                    "Component {component_id}/{component_version} in the component service is different from the imported one"
+                ))
+            };
+
+            if existing.component_size != binary.len() as u64 {
+                return Err(different_from_imported());
+            }
+
+            // Replaying the imported oplog against a different binary of the same size would
+            // silently diverge, so the contents have to match as well
+            let existing_binary = self
+                .get_binary(&component.owner.project_id, component_id, component_version)
+                .await?;
+
+            if existing_binary != binary {
+                Err(different_from_imported())
+            } else {
+                Ok(())
+            }
+        }
     }
 
     async fn download_via_grpc(
@@ -14,9 +14,12 @@
 
 use anyhow::anyhow;
 use async_lock::Mutex;
+use bytes::Bytes;
 use futures::TryStreamExt;
 use golem_common::model::{InitialComponentFileKey, ProjectId};
+use golem_common::widen_infallible;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::replayable_stream::ReplayableStream;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
 use std::collections::HashMap;
 use std::path::Path;
@@ -100,6 +103,48 @@ impl FileLoader {
             })
     }
 
+    /// Reads the whole content of an initial component file
+    pub async fn get_bytes(
+        &self,
+        project_id: &ProjectId,
+        key: &InitialComponentFileKey,
+    ) -> Result<Bytes, WorkerExecutorError> {
+        let chunks = self
+            .initial_component_files_service
+            .get(project_id, key)
+            .await
+            .map_err(|e| WorkerExecutorError::initial_file_download_failed(key.to_string(), e))?
+            .ok_or_else(|| {
+                WorkerExecutorError::initial_file_download_failed(
+                    key.to_string(),
+                    "File not found".to_string(),
+                )
+            })?
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(|e| WorkerExecutorError::initial_file_download_failed(key.to_string(), e))?;
+
+        Ok(Bytes::from(chunks.concat()))
+    }
+
+    /// Stores a new initial component file, returning its content based key
+    pub async fn put(
+        &self,
+        project_id: &ProjectId,
+        data: Bytes,
+    ) -> Result<InitialComponentFileKey, WorkerExecutorError> {
+        let stream = data
+            .map_item(|i| i.map_err(widen_infallible))
+            .map_error(widen_infallible);
+
+        self.initial_component_files_service
+            .put_if_not_exists(project_id, stream)
+            .await
+            .map_err(|e| {
+                WorkerExecutorError::unknown(format!("Failed to store initial component file: {e}"))
+            })
+    }
+
     async fn get_read_only_to_impl(
         &self,
         project_id: &ProjectId,
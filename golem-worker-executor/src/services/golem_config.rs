@@ -234,6 +234,7 @@ pub struct OplogConfig {
     pub entry_count_limit: u64,
     #[serde(with = "humantime_serde")]
     pub archive_interval: Duration,
+    pub verify_replay: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -568,6 +569,7 @@ impl Default for OplogConfig {
             blob_storage_layers: 1,
             entry_count_limit: 1024,
             archive_interval: Duration::from_secs(60 * 60 * 24), // 24 hours
+            verify_replay: false,
         }
     }
 }
@@ -15,7 +15,7 @@
 use super::file_loader::FileLoader;
 use crate::durable_host::serialized::SerializableError;
 use crate::metrics::workers::record_worker_call;
-use crate::model::ExecutionStatus;
+use crate::model::{ExecutionStatus, BLOCK_REMOTE_WRITES_ENV_VAR};
 use crate::preview2::golem_api_1_x::host::ForkResult;
 use crate::services::events::Events;
 use crate::services::oplog::plugin::OplogProcessorPlugin;
@@ -39,7 +39,7 @@ use crate::services::{rdbms, HasOplog, HasRdbmsService, HasWorkerForkService};
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
-use golem_common::model::oplog::{DurableFunctionType, OplogIndex, OplogIndexRange};
+use golem_common::model::oplog::{DurableFunctionType, OplogEntry, OplogIndex, OplogIndexRange};
 use golem_common::model::{AccountId, ProjectId, Timestamp, WorkerMetadata, WorkerStatusRecord};
 use golem_common::model::{OwnedWorkerId, WorkerId};
 use golem_common::serialization::serialize;
@@ -65,6 +65,16 @@ pub trait WorkerForkService: Send + Sync {
         target_worker_id: &WorkerId,
         oplog_index_cut_off: OplogIndex,
     ) -> Result<(), WorkerExecutorError>;
+
+    /// Forks a worker whose remote writes fail once it runs live, such that invoking the
+    /// forked worker cannot cause side effects outside of it
+    async fn fork_without_remote_writes(
+        &self,
+        fork_account_id: &AccountId,
+        source_worker_id: &OwnedWorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cut_off: OplogIndex,
+    ) -> Result<(), WorkerExecutorError>;
 }
 
 pub struct DefaultWorkerFork<Ctx: WorkerCtx> {
@@ -403,12 +413,47 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
         Ok((owned_source_worker_id, owned_target_worker_id))
     }
 
+    async fn fork_and_resume(
+        &self,
+        fork_account_id: &AccountId,
+        source_worker_id: &OwnedWorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cut_off: OplogIndex,
+        block_remote_writes: bool,
+    ) -> Result<(), WorkerExecutorError> {
+        let new_oplog = self
+            .copy_source_oplog(
+                fork_account_id,
+                source_worker_id,
+                target_worker_id,
+                oplog_index_cut_off,
+                block_remote_writes,
+            )
+            .await?;
+
+        new_oplog.commit(CommitLevel::Always).await;
+
+        // We go through worker proxy to resume the worker
+        // as we need to make sure as it may live in another worker executor,
+        // depending on sharding.
+        // This will replay until the fork point in the forked worker
+        self.worker_proxy
+            .resume(target_worker_id, true)
+            .await
+            .map_err(|err| {
+                WorkerExecutorError::failed_to_resume_worker(target_worker_id.clone(), err.into())
+            })?;
+
+        Ok(())
+    }
+
     async fn copy_source_oplog(
         &self,
         fork_account_id: &AccountId,
         source_worker_id: &OwnedWorkerId,
         target_worker_id: &WorkerId,
         oplog_index_cut_off: OplogIndex,
+        block_remote_writes: bool,
     ) -> Result<Arc<dyn Oplog>, WorkerExecutorError> {
         record_worker_call("fork");
 
@@ -438,11 +483,17 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
 
         let source_worker_metadata = source_worker_instance.get_metadata()?;
 
+        let mut target_env = source_worker_metadata.env.clone();
+        if block_remote_writes {
+            target_env.retain(|(key, _)| key != BLOCK_REMOTE_WRITES_ENV_VAR);
+            target_env.push((BLOCK_REMOTE_WRITES_ENV_VAR.to_string(), "true".to_string()));
+        }
+
         let target_worker_metadata = WorkerMetadata {
             worker_id: target_worker_id.clone(),
             created_by: fork_account_id.clone(),
             project_id,
-            env: source_worker_metadata.env.clone(),
+            env: target_env.clone(),
             args: source_worker_metadata.args.clone(),
             wasi_config_vars: source_worker_metadata.wasi_config_vars.clone(),
             created_at: Timestamp::now_utc(),
@@ -457,12 +508,16 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
         let initial_oplog_entry = source_oplog.read(OplogIndex::INITIAL).await;
 
         // Update the oplog initial entry with the new worker
-        let target_initial_oplog_entry = initial_oplog_entry
+        let mut target_initial_oplog_entry = initial_oplog_entry
             .update_worker_id(&target_worker_id)
             .ok_or(WorkerExecutorError::unknown(
                 "Failed to update worker id in oplog entry",
             ))?;
 
+        if let OplogEntry::Create { env, .. } = &mut target_initial_oplog_entry {
+            *env = target_env;
+        }
+
         let new_oplog = self
             .oplog_service
             .create(
@@ -497,29 +552,14 @@ impl<Ctx: WorkerCtx> WorkerForkService for DefaultWorkerFork<Ctx> {
         target_worker_id: &WorkerId,
         oplog_index_cut_off: OplogIndex,
     ) -> Result<(), WorkerExecutorError> {
-        let new_oplog = self
-            .copy_source_oplog(
-                fork_account_id,
-                source_worker_id,
-                target_worker_id,
-                oplog_index_cut_off,
-            )
-            .await?;
-
-        new_oplog.commit(CommitLevel::Always).await;
-
-        // We go through worker proxy to resume the worker
-        // as we need to make sure as it may live in another worker executor,
-        // depending on sharding.
-        // This will replay until the fork point in the forked worker
-        self.worker_proxy
-            .resume(target_worker_id, true)
-            .await
-            .map_err(|err| {
-                WorkerExecutorError::failed_to_resume_worker(target_worker_id.clone(), err.into())
-            })?;
-
-        Ok(())
+        self.fork_and_resume(
+            fork_account_id,
+            source_worker_id,
+            target_worker_id,
+            oplog_index_cut_off,
+            false,
+        )
+        .await
     }
 
     async fn fork_and_write_fork_result(
@@ -535,6 +575,7 @@ impl<Ctx: WorkerCtx> WorkerForkService for DefaultWorkerFork<Ctx> {
                 source_worker_id,
                 target_worker_id,
                 oplog_index_cut_off,
+                false,
             )
             .await?;
 
@@ -579,4 +620,21 @@ impl<Ctx: WorkerCtx> WorkerForkService for DefaultWorkerFork<Ctx> {
 
         Ok(())
     }
+
+    async fn fork_without_remote_writes(
+        &self,
+        fork_account_id: &AccountId,
+        source_worker_id: &OwnedWorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cut_off: OplogIndex,
+    ) -> Result<(), WorkerExecutorError> {
+        self.fork_and_resume(
+            fork_account_id,
+            source_worker_id,
+            target_worker_id,
+            oplog_index_cut_off,
+            true,
+        )
+        .await
+    }
 }
@@ -17,11 +17,11 @@ use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    fork_worker_response, invoke_and_await_typed_response, invoke_response, resume_worker_response,
-    revert_worker_response, update_worker_response, worker_error, ForkWorkerRequest,
-    InvokeAndAwaitRequest, InvokeAndAwaitTypedResponse, InvokeRequest, InvokeResponse,
-    ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    delete_worker_response, fork_worker_response, invoke_and_await_typed_response, invoke_response,
+    resume_worker_response, revert_worker_response, update_worker_response, worker_error,
+    DeleteWorkerRequest, ForkWorkerRequest, InvokeAndAwaitRequest, InvokeAndAwaitTypedResponse,
+    InvokeRequest, InvokeResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
+    RevertWorkerResponse, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
 };
 use golem_api_grpc::proto::golem::worker::{InvokeParameters, UpdateMode};
 use golem_common::client::{GrpcClient, GrpcClientConfig};
@@ -91,6 +91,8 @@ pub trait WorkerProxy: Send + Sync {
         worker_id: &WorkerId,
         target: RevertWorkerTarget,
     ) -> Result<(), WorkerProxyError>;
+
+    async fn delete(&self, worker_id: &WorkerId) -> Result<(), WorkerProxyError>;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
@@ -463,4 +465,29 @@ impl WorkerProxy for RemoteWorkerProxy {
             )),
         }
     }
+
+    async fn delete(&self, worker_id: &WorkerId) -> Result<(), WorkerProxyError> {
+        debug!("Deleting remote worker");
+
+        let response = self
+            .client
+            .call("delete_worker", move |client| {
+                Box::pin(client.delete_worker(authorised_grpc_request(
+                    DeleteWorkerRequest {
+                        worker_id: Some(worker_id.clone().into()),
+                    },
+                    &self.access_token,
+                )))
+            })
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(delete_worker_response::Result::Success(_)) => Ok(()),
+            Some(delete_worker_response::Result::Error(error)) => Err(error.into()),
+            None => Err(WorkerProxyError::InternalError(
+                WorkerExecutorError::unknown("Empty response through the worker API".to_string()),
+            )),
+        }
+    }
 }
@@ -28,7 +28,7 @@ use golem_wasm_rpc::Value;
 use rib::{ParsedFunctionName, ParsedFunctionReference};
 use tracing::{debug, error, Instrument};
 use wasmtime::component::{Func, Val};
-use wasmtime::{AsContextMut, StoreContextMut};
+use wasmtime::{AsContextMut, StoreContextMut, WasmBacktrace};
 use wasmtime_wasi_http::bindings::Proxy;
 use wasmtime_wasi_http::WasiHttpView;
 
@@ -527,6 +527,7 @@ async fn invoke<Ctx: WorkerCtx>(
         }
         Err(err) => {
             println!("err: {err:?}");
+            observe_guest_backtrace(&mut store, &err);
             Ok(InvokeResult::from_error::<Ctx>(consumed_fuel, &err))
         }
     }
@@ -625,7 +626,10 @@ async fn invoke_http_handler<Ctx: WorkerCtx>(
 
     match res_or_error {
         Ok(resp) => Ok(InvokeResult::from_success(consumed_fuel, Some(resp))),
-        Err(e) => Ok(InvokeResult::from_error::<Ctx>(consumed_fuel, &e)),
+        Err(e) => {
+            observe_guest_backtrace(&mut store.as_context_mut(), &e);
+            Ok(InvokeResult::from_error::<Ctx>(consumed_fuel, &e))
+        }
     }
 }
 
@@ -669,13 +673,26 @@ async fn drop_resource<Ctx: WorkerCtx>(
 
         match result {
             Ok(_) => Ok(InvokeResult::from_success(consumed_fuel, None)),
-            Err(err) => Ok(InvokeResult::from_error::<Ctx>(consumed_fuel, &err)),
+            Err(err) => {
+                observe_guest_backtrace(&mut store, &err);
+                Ok(InvokeResult::from_error::<Ctx>(consumed_fuel, &err))
+            }
         }
     } else {
         Ok(InvokeResult::from_success(0, None))
     }
 }
 
+/// Passes the guest's backtrace to the worker context if the failed invocation captured one
+fn observe_guest_backtrace<Ctx: WorkerCtx>(
+    store: &mut StoreContextMut<'_, Ctx>,
+    error: &anyhow::Error,
+) {
+    if let Some(backtrace) = error.downcast_ref::<WasmBacktrace>() {
+        store.data_mut().on_guest_backtrace(backtrace);
+    }
+}
+
 async fn call_exported_function<Ctx: WorkerCtx>(
     store: &mut impl AsContextMut<Data = Ctx>,
     function: Func,
@@ -56,7 +56,7 @@ use golem_wasm_rpc::{Value, ValueAndType};
 use std::collections::HashSet;
 use std::sync::{Arc, RwLock, Weak};
 use wasmtime::component::{Component, Instance, Linker};
-use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync};
+use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync, WasmBacktrace};
 use wasmtime_wasi::p2::WasiView;
 use wasmtime_wasi_http::WasiHttpView;
 
@@ -297,6 +297,10 @@ pub trait InvocationHooks {
     /// Called when a worker invocation fails
     async fn on_invocation_failure(&mut self, trap_type: &TrapType) -> RetryDecision;
 
+    /// Called with the guest's WebAssembly backtrace when a failed worker invocation captured one,
+    /// before `on_invocation_failure`
+    fn on_guest_backtrace(&mut self, backtrace: &WasmBacktrace);
+
     /// Called when a worker invocation succeeds
     /// Arguments:
     /// - `full_function_name`: The full name of the function being invoked (including the exported interface name if any)
@@ -0,0 +1,14 @@
+CREATE TABLE api_deployment_traffic_splits
+(
+    namespace      text    NOT NULL,
+    site           text    NOT NULL,
+    definition_id  text    NOT NULL,
+    canary_version text    NOT NULL,
+    percentage     integer NOT NULL,
+    header_name    text,
+    header_value   text,
+    cookie_name    text,
+    cookie_value   text,
+    sticky_key     text,
+    PRIMARY KEY (namespace, site, definition_id)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE api_deployment_rib_limits
+(
+    namespace                text   NOT NULL,
+    site                     text   NOT NULL,
+    max_instructions         bigint,
+    max_stack_size           bigint,
+    max_value_size           bigint,
+    max_function_invocations bigint,
+    max_wall_time_millis     bigint,
+    PRIMARY KEY (namespace, site)
+);
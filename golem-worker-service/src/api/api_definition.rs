@@ -328,6 +328,72 @@ impl ApiDefinitionApi {
             .map(Json)
     }
 
+    /// Export an API definition in OpenAPI format
+    ///
+    /// Renders an API definition, selected by its API definition ID and version, as an OpenAPI 3 document.
+    /// Request and response schemas are derived from the types inferred for the Rib scripts of each route.
+    #[oai(
+        path = "/:project_id/:id/:version/export",
+        method = "get",
+        operation_id = "export_open_api"
+    )]
+    async fn export_open_api(
+        &self,
+        project_id: Path<ProjectId>,
+        id: Path<ApiDefinitionId>,
+        version: Path<ApiVersion>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<OpenApiHttpApiDefinition>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "export_open_api",
+            api_definition_id = id.0.to_string(),
+            version = version.0.to_string(),
+            project_id = project_id.0.to_string()
+        );
+
+        let response = self
+            .export_open_api_internal(project_id.0, id.0, version.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn export_open_api_internal(
+        &self,
+        project_id: ProjectId,
+        id: ApiDefinitionId,
+        version: ApiVersion,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<OpenApiHttpApiDefinition>, ApiEndpointError> {
+        let auth_ctx = AuthCtx::new(token.secret());
+        let namespace = self
+            .auth_service
+            .authorize_project_action(&project_id, ProjectAction::ViewApiDefinition, &auth_ctx)
+            .await?;
+
+        let conversion_context = self
+            .definition_service
+            .conversion_context(&namespace, &auth_ctx);
+
+        let data = self
+            .definition_service
+            .get(&id, &version, &namespace, &auth_ctx)
+            .await?;
+
+        let data = data.ok_or(ApiEndpointError::not_found(safe(format!(
+            "Can't find api definition with id {id}, and version {version} in project {project_id}"
+        ))))?;
+
+        OpenApiHttpApiDefinition::from_compiled_http_api_definition(&data, &conversion_context)
+            .await
+            .map_err(|err| {
+                error!("Failed to export API definition to OpenAPI {}", err);
+                ApiEndpointError::internal(safe(err))
+            })
+            .map(Json)
+    }
+
     /// List API definitions
     ///
     /// Lists all API definitions associated with the project.
@@ -13,14 +13,18 @@
 // limitations under the License.
 
 use crate::api::common::ApiEndpointError;
+use crate::gateway_api_definition::http::OpenApiHttpApiDefinition;
 use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
 use crate::gateway_api_deployment;
 use crate::gateway_api_deployment::ApiSiteString;
+use crate::gateway_execution::gateway_response_cache::GatewayResponseCache;
 use crate::model::ApiDeployment;
 use crate::model::ApiDeploymentRequest;
+use crate::model::ApiDeploymentRibLimits;
+use crate::model::ApiDeploymentTrafficSplit;
 use crate::service::api_domain::RegisterDomainRoute;
 use crate::service::auth::AuthService;
-use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
+use crate::service::gateway::api_definition::{ApiDefinitionIdWithVersion, ApiDefinitionService};
 use crate::service::gateway::api_deployment::{ApiDeploymentError, ApiDeploymentService};
 use golem_common::model::auth::ProjectAction;
 use golem_common::model::auth::{AuthCtx, Namespace};
@@ -32,25 +36,31 @@ use poem_openapi::param::{Path, Query};
 use poem_openapi::payload::Json;
 use poem_openapi::*;
 use std::sync::Arc;
-use tracing::Instrument;
+use tracing::{error, Instrument};
 
 pub struct ApiDeploymentApi {
     deployment_service: Arc<dyn ApiDeploymentService>,
+    definition_service: Arc<dyn ApiDefinitionService>,
     auth_service: Arc<dyn AuthService>,
     domain_route: Arc<dyn RegisterDomainRoute>,
+    response_cache: Arc<dyn GatewayResponseCache>,
 }
 
 #[OpenApi(prefix_path = "/v1/api/deployments", tag = ApiTags::ApiDeployment)]
 impl ApiDeploymentApi {
     pub fn new(
         deployment_service: Arc<dyn ApiDeploymentService>,
+        definition_service: Arc<dyn ApiDefinitionService>,
         auth_service: Arc<dyn AuthService>,
         domain_route: Arc<dyn RegisterDomainRoute>,
+        response_cache: Arc<dyn GatewayResponseCache>,
     ) -> Self {
         Self {
             deployment_service,
+            definition_service,
             auth_service,
             domain_route,
+            response_cache,
         }
     }
 
@@ -121,11 +131,30 @@ impl ApiDeploymentApi {
             .get_by_site(&namespace, &ApiSiteString(payload.site.to_string()))
             .await?;
 
-        let deployment = data
-            .map(|d| d.into())
-            .ok_or(ApiEndpointError::not_found(safe(
-                "API Deployment not found".to_string(),
-            )))?;
+        let mut deployment: ApiDeployment =
+            data.map(|d| d.into())
+                .ok_or(ApiEndpointError::not_found(safe(
+                    "API Deployment not found".to_string(),
+                )))?;
+
+        deployment.warnings = self
+            .deployment_service
+            .get_definitions_by_site(&namespace, &ApiSiteString(payload.site.to_string()))
+            .await?
+            .iter()
+            .filter(|definition| {
+                api_definition_infos
+                    .iter()
+                    .any(|key| key.id == definition.id && key.version == definition.version)
+            })
+            .flat_map(|definition| {
+                definition.routes.iter().flat_map(move |route| {
+                    route.rib_warnings().into_iter().map(move |warning| {
+                        format!("{}/{} {}", definition.id, definition.version, warning)
+                    })
+                })
+            })
+            .collect();
 
         Ok(Json(deployment))
     }
@@ -228,6 +257,427 @@ impl ApiDeploymentApi {
         Ok(Json(api_deployment.into()))
     }
 
+    /// Export API deployment in OpenAPI format
+    ///
+    /// Renders all the API definitions deployed to a site (specific host and subdomain) as a single OpenAPI 3 document.
+    #[oai(
+        path = "/:site/export",
+        method = "get",
+        operation_id = "export_deployment_open_api"
+    )]
+    async fn export_open_api(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<OpenApiHttpApiDefinition>, ApiEndpointError> {
+        let record = recorded_http_api_request!("export_deployment_open_api", site = site.0);
+        let response = self
+            .export_open_api_internal(&project_id.0, site.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn export_open_api_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<OpenApiHttpApiDefinition>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::ViewApiDeployment, &auth_ctx)
+            .await?;
+
+        let definitions = self
+            .deployment_service
+            .get_definitions_by_site(&namespace, &site)
+            .await?;
+
+        if definitions.is_empty() {
+            return Err(ApiEndpointError::not_found(safe(
+                "API deployment not found".to_string(),
+            )));
+        }
+
+        let conversion_context = self
+            .definition_service
+            .conversion_context(&namespace, &auth_ctx);
+
+        OpenApiHttpApiDefinition::from_api_deployment(&site, &definitions, &conversion_context)
+            .await
+            .map_err(|err| {
+                error!("Failed to export API deployment to OpenAPI {}", err);
+                ApiEndpointError::internal(safe(err))
+            })
+            .map(Json)
+    }
+
+    /// Invalidate cached responses of an API deployment
+    ///
+    /// Removes the responses cached by the gateway for a site. If `key` is set, only the responses
+    /// cached under that cache key are removed, otherwise all the cached responses of the site are removed.
+    #[oai(
+        path = "/:site/cache",
+        method = "delete",
+        operation_id = "invalidate_response_cache"
+    )]
+    async fn invalidate_response_cache(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        key: Query<Option<String>>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!("invalidate_response_cache", site = site.0);
+        let response = self
+            .invalidate_response_cache_internal(&project_id.0, site.0, key.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn invalidate_response_cache_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        key: Option<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::UpsertApiDeployment, &auth_ctx)
+            .await?;
+
+        self.deployment_service
+            .get_by_site(&namespace, &site)
+            .await?
+            .ok_or(ApiEndpointError::not_found(safe(
+                "API deployment not found".to_string(),
+            )))?;
+
+        self.response_cache
+            .invalidate(&site, key.as_deref())
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to invalidate the response cache of {}: {}",
+                    site, err
+                );
+                ApiEndpointError::internal(safe(err.to_safe_string()))
+            })?;
+
+        Ok(Json("Response cache invalidated".to_string()))
+    }
+
+    /// Set the traffic split of an API definition deployed to a site
+    ///
+    /// Routes a percentage of the requests served by the deployed version of an API definition to another (canary) version of the same definition.
+    /// Requests with the configured header or cookie value are always routed to the canary version, and if a sticky key is set,
+    /// all the requests with the same sticky key are routed to the same version. Replaces any existing traffic split of the API definition.
+    #[oai(
+        path = "/:site/traffic-splits",
+        method = "put",
+        operation_id = "set_traffic_split"
+    )]
+    async fn set_traffic_split(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        payload: Json<ApiDeploymentTrafficSplit>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ApiDeploymentTrafficSplit>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "set_traffic_split",
+            site = site.0.clone(),
+            api_definition_id = payload.0.api_definition_id.to_string()
+        );
+        let response = self
+            .set_traffic_split_internal(&project_id.0, site.0, payload.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn set_traffic_split_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        payload: ApiDeploymentTrafficSplit,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ApiDeploymentTrafficSplit>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::UpsertApiDeployment, &auth_ctx)
+            .await?;
+
+        let traffic_split: gateway_api_deployment::TrafficSplit = payload
+            .try_into()
+            .map_err(|err: String| ApiEndpointError::bad_request(safe(err)))?;
+
+        self.deployment_service
+            .set_traffic_split(&namespace, &site, traffic_split.clone(), &auth_ctx)
+            .await?;
+
+        Ok(Json(traffic_split.into()))
+    }
+
+    /// List the traffic splits of an API deployment
+    ///
+    /// Lists the traffic splits of the API definitions deployed to a site (specific host and subdomain).
+    #[oai(
+        path = "/:site/traffic-splits",
+        method = "get",
+        operation_id = "list_traffic_splits"
+    )]
+    async fn list_traffic_splits(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<Vec<ApiDeploymentTrafficSplit>>, ApiEndpointError> {
+        let record = recorded_http_api_request!("list_traffic_splits", site = site.0);
+        let response = self
+            .list_traffic_splits_internal(&project_id.0, site.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn list_traffic_splits_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<Vec<ApiDeploymentTrafficSplit>>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::ViewApiDeployment, &auth_ctx)
+            .await?;
+
+        let traffic_splits = self
+            .deployment_service
+            .get_traffic_splits(&namespace, &site)
+            .await?;
+
+        Ok(Json(traffic_splits.into_iter().map(|x| x.into()).collect()))
+    }
+
+    /// Delete the traffic split of an API definition deployed to a site
+    ///
+    /// Stops routing requests to the canary version, such that all the requests are served by the deployed version of the API definition.
+    #[oai(
+        path = "/:site/traffic-splits",
+        method = "delete",
+        operation_id = "delete_traffic_split"
+    )]
+    async fn delete_traffic_split(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        #[oai(name = "api-definition-id")] api_definition_id: Query<ApiDefinitionId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "delete_traffic_split",
+            site = site.0.clone(),
+            api_definition_id = api_definition_id.0.to_string()
+        );
+        let response = self
+            .delete_traffic_split_internal(&project_id.0, site.0, api_definition_id.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn delete_traffic_split_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        api_definition_id: ApiDefinitionId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::UpsertApiDeployment, &auth_ctx)
+            .await?;
+
+        self.deployment_service
+            .delete_traffic_split(&namespace, &site, &api_definition_id, &auth_ctx)
+            .await?;
+
+        Ok(Json("Traffic split deleted".to_string()))
+    }
+
+    /// Set the Rib limits of an API deployment
+    ///
+    /// Limits the resources (instructions, stack size, value size, worker function invocations and wall time)
+    /// used by every Rib script evaluated to serve the requests of a site. A script exceeding any of the limits fails the request.
+    /// Replaces any existing limits of the site.
+    #[oai(
+        path = "/:site/rib-limits",
+        method = "put",
+        operation_id = "set_rib_limits"
+    )]
+    async fn set_rib_limits(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        payload: Json<ApiDeploymentRibLimits>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ApiDeploymentRibLimits>, ApiEndpointError> {
+        let record = recorded_http_api_request!("set_rib_limits", site = site.0.clone());
+        let response = self
+            .set_rib_limits_internal(&project_id.0, site.0, payload.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn set_rib_limits_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        payload: ApiDeploymentRibLimits,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ApiDeploymentRibLimits>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::UpsertApiDeployment, &auth_ctx)
+            .await?;
+
+        let limits: rib::RibInterpreterLimits = payload.into();
+
+        self.deployment_service
+            .set_rib_limits(&namespace, &site, limits.clone())
+            .await?;
+
+        Ok(Json(limits.into()))
+    }
+
+    /// Get the Rib limits of an API deployment
+    ///
+    /// Gets the limits on the resources used by the Rib scripts serving the requests of a site.
+    #[oai(
+        path = "/:site/rib-limits",
+        method = "get",
+        operation_id = "get_rib_limits"
+    )]
+    async fn get_rib_limits(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ApiDeploymentRibLimits>, ApiEndpointError> {
+        let record = recorded_http_api_request!("get_rib_limits", site = site.0.clone());
+        let response = self
+            .get_rib_limits_internal(&project_id.0, site.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_rib_limits_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ApiDeploymentRibLimits>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::ViewApiDeployment, &auth_ctx)
+            .await?;
+
+        let limits = self
+            .deployment_service
+            .get_rib_limits(&namespace, &site)
+            .await?
+            .ok_or(ApiDeploymentError::RibLimitsNotFound(namespace, site))?;
+
+        Ok(Json(limits.into()))
+    }
+
+    /// Delete the Rib limits of an API deployment
+    ///
+    /// Removes the limits on the resources used by the Rib scripts serving the requests of a site.
+    #[oai(
+        path = "/:site/rib-limits",
+        method = "delete",
+        operation_id = "delete_rib_limits"
+    )]
+    async fn delete_rib_limits(
+        &self,
+        #[oai(name = "project-id")] project_id: Query<ProjectId>,
+        site: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!("delete_rib_limits", site = site.0.clone());
+        let response = self
+            .delete_rib_limits_internal(&project_id.0, site.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn delete_rib_limits_internal(
+        &self,
+        project_id: &ProjectId,
+        site: String,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let token = token.secret();
+        let site = ApiSiteString(site);
+        let auth_ctx = AuthCtx::new(token);
+
+        let namespace = self
+            .auth_service
+            .authorize_project_action(project_id, ProjectAction::UpsertApiDeployment, &auth_ctx)
+            .await?;
+
+        self.deployment_service
+            .delete_rib_limits(&namespace, &site)
+            .await?;
+
+        Ok(Json("Rib limits deleted".to_string()))
+    }
+
     /// Delete API deployment by site
     ///
     /// Deletes an API deployment by the host name (optionally with a subdomain) it is deployed to.
@@ -242,6 +242,9 @@ impl From<ApiDeploymentError> for ApiEndpointError {
             ApiDeploymentError::ComponentConstraintCreateError(_) => {
                 ApiEndpointError::bad_request(value)
             }
+            ApiDeploymentError::TrafficSplitNotFound(_, _, _) => ApiEndpointError::not_found(value),
+            ApiDeploymentError::InvalidTrafficSplit(_) => ApiEndpointError::bad_request(value),
+            ApiDeploymentError::RibLimitsNotFound(_, _) => ApiEndpointError::not_found(value),
         }
     }
 }
@@ -21,6 +21,7 @@ use crate::gateway_execution::file_server_binding_handler::FileServerBindingHand
 use crate::gateway_execution::gateway_http_input_executor::{
     DefaultGatewayInputExecutor, GatewayHttpInputExecutor,
 };
+use crate::gateway_execution::gateway_response_cache::GatewayResponseCache;
 use crate::gateway_execution::gateway_session::GatewaySession;
 use crate::gateway_execution::http_handler_binding_handler::HttpHandlerBindingHandler;
 use crate::gateway_execution::GatewayWorkerRequestExecutor;
@@ -40,6 +41,7 @@ impl CustomHttpRequestApi {
         file_server_binding_handler: Arc<dyn FileServerBindingHandler>,
         http_handler_binding_handler: Arc<dyn HttpHandlerBindingHandler>,
         gateway_session_store: Arc<dyn GatewaySession>,
+        response_cache: Arc<dyn GatewayResponseCache>,
     ) -> Self {
         let evaluator = Arc::new(DefaultRibInterpreter::from_worker_request_executor(
             worker_request_executor_service.clone(),
@@ -55,6 +57,7 @@ impl CustomHttpRequestApi {
             api_definition_lookup_service,
             gateway_session_store,
             identity_provider: Arc::new(DefaultIdentityProvider),
+            response_cache,
         });
 
         Self {
@@ -19,10 +19,13 @@ use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
 use crate::gateway_api_deployment::ApiSite;
 use crate::gateway_binding::{
     FileServerBinding, FileServerBindingCompiled, GatewayBinding, GatewayBindingCompiled,
-    HttpHandlerBinding, HttpHandlerBindingCompiled, StaticBinding, WorkerBinding,
-    WorkerBindingCompiled,
+    HttpHandlerBinding, HttpHandlerBindingCompiled, ResponseCachePolicy,
+    ResponseCachePolicyCompiled, StaticBinding, WorkerBinding, WorkerBindingCompiled,
+};
+use crate::gateway_middleware::{
+    CorsPreflightExpr, HttpCors, HttpMiddleware, HttpMiddlewares, HttpTransform,
+    HttpTransformMiddleware, SecurityHeaders, StrictTransportSecurity,
 };
-use crate::gateway_middleware::{CorsPreflightExpr, HttpCors, HttpMiddleware, HttpMiddlewares};
 use crate::gateway_security::{
     Provider, SecurityScheme, SecuritySchemeIdentifier, SecuritySchemeReference,
     SecuritySchemeWithProviderMetadata,
@@ -36,6 +39,7 @@ use poem_openapi::*;
 use rib::{RibInputTypeInfo, RibOutputTypeInfo};
 use serde::{Deserialize, Serialize};
 use std::result::Result;
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
@@ -231,6 +235,7 @@ pub struct RouteRequestData {
     pub path: String,
     pub binding: GatewayBindingData,
     pub security: Option<String>,
+    pub transform: Option<HttpTransformData>,
 }
 
 impl RouteRequestData {
@@ -245,11 +250,14 @@ impl RouteRequestData {
             security_scheme_identifier: SecuritySchemeIdentifier::new(s),
         });
 
+        let transform = self.transform.map(HttpTransform::try_from).transpose()?;
+
         Ok(RouteRequest {
             method: self.method,
             path,
             binding,
             security,
+            transform,
         })
     }
 }
@@ -261,6 +269,7 @@ pub struct RouteResponseData {
     pub method: MethodPattern,
     pub path: String,
     pub security: Option<String>,
+    pub transform: Option<HttpTransformData>,
     pub binding: GatewayBindingResponseData,
 }
 
@@ -271,6 +280,11 @@ impl RouteResponseData {
     ) -> Result<Self, String> {
         let method = value.method;
         let path = value.path.to_string();
+        let transform = value
+            .middlewares
+            .as_ref()
+            .and_then(|middlewares| middlewares.get_transform_middleware())
+            .map(HttpTransformData::from);
         let security = value.middlewares.and_then(|middlewares| {
             middlewares
                 .get_http_authentication_middleware()
@@ -287,6 +301,7 @@ impl RouteResponseData {
             method,
             path,
             security,
+            transform,
             binding: GatewayBindingResponseData::from_gateway_binding_compiled(
                 value.binding,
                 conversion_ctx,
@@ -338,6 +353,8 @@ pub struct GatewayBindingData {
     pub response: Option<String>,
     // For binding type - worker/default
     pub invocation_context: Option<String>,
+    // For binding type - worker/default
+    pub response_cache: Option<ResponseCachePolicyData>,
 }
 
 impl GatewayBindingData {
@@ -347,6 +364,12 @@ impl GatewayBindingData {
     ) -> Result<GatewayBinding, String> {
         let v = self.binding_type.clone();
 
+        if self.response_cache.is_some() && !matches!(v, Some(GatewayBindingType::Default) | None) {
+            return Err(
+                "Response cache is supported only for the default binding type".to_string(),
+            );
+        }
+
         match v {
             Some(GatewayBindingType::Default) | Some(GatewayBindingType::FileServer) | None => {
                 let response = self.response.ok_or("Missing response field in binding")?;
@@ -393,6 +416,11 @@ impl GatewayBindingData {
                         file_server_worker_binding,
                     )))
                 } else {
+                    let response_cache_policy = self
+                        .response_cache
+                        .map(ResponseCachePolicy::try_from)
+                        .transpose()?;
+
                     let default_worker_binding = WorkerBinding {
                         component_id: VersionedComponentId {
                             component_id: component_view.id,
@@ -401,6 +429,7 @@ impl GatewayBindingData {
                         idempotency_key,
                         response_mapping: response,
                         invocation_context,
+                        response_cache_policy,
                     };
 
                     Ok(GatewayBinding::Default(Box::new(default_worker_binding)))
@@ -460,18 +489,66 @@ impl GatewayBindingData {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ResponseCachePolicyData {
+    /// Number of seconds a successful response is served from the cache
+    pub ttl_seconds: u64,
+    /// Rib expression over `request.path`, `request.query` and `request.headers` computing the cache key.
+    /// If not provided, the request path along with its query string is used as the key.
+    pub key: Option<String>,
+    /// Request headers whose values are part of the cache key, in addition to `key`
+    pub vary: Option<Vec<String>>,
+}
+
+impl TryFrom<ResponseCachePolicyData> for ResponseCachePolicy {
+    type Error = String;
+
+    fn try_from(value: ResponseCachePolicyData) -> Result<Self, Self::Error> {
+        let cache_key = value
+            .key
+            .map(|key| rib::from_string(key).map_err(|e| e.to_string()))
+            .transpose()?;
+
+        Ok(ResponseCachePolicy::new(
+            Duration::from_secs(value.ttl_seconds),
+            cache_key,
+            value.vary.unwrap_or_default(),
+        ))
+    }
+}
+
+impl From<ResponseCachePolicyCompiled> for ResponseCachePolicyData {
+    fn from(value: ResponseCachePolicyCompiled) -> Self {
+        ResponseCachePolicyData {
+            ttl_seconds: value.ttl.as_secs(),
+            key: value
+                .cache_key_compiled
+                .map(|compiled| compiled.cache_key.to_string()),
+            vary: if value.vary.is_empty() {
+                None
+            } else {
+                Some(value.vary)
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
 pub struct MiddlewareData {
     pub cors: Option<HttpCors>,
     pub auth: Option<SecuritySchemeReferenceData>,
+    pub transform: Option<HttpTransformData>,
 }
 
 impl From<HttpMiddlewares> for MiddlewareData {
     fn from(value: HttpMiddlewares) -> Self {
         let mut cors = None;
         let mut auth = None;
+        let mut transform = None;
 
         for i in value.0.iter() {
             match i {
@@ -482,10 +559,72 @@ impl From<HttpMiddlewares> for MiddlewareData {
                     );
                     auth = Some(security_scheme_reference)
                 }
+                HttpMiddleware::Transform(transform0) => {
+                    transform = Some(HttpTransformData::from(transform0.as_ref().clone()))
+                }
             }
         }
 
-        MiddlewareData { cors, auth }
+        MiddlewareData {
+            cors,
+            auth,
+            transform,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct HttpTransformData {
+    /// Rib expression over `request`, evaluated before the binding. It results in a record with any of
+    /// `headers`, `remove-headers`, `path`, `body`, `redirect` and `redirect-status`
+    pub request: Option<String>,
+    /// Rib expression over `request` and `response` (`status` and `headers`), evaluated after the binding.
+    /// It results in a record with any of `headers`, `remove-headers`, `status` and `body`
+    pub response: Option<String>,
+    pub strict_transport_security: Option<StrictTransportSecurity>,
+    /// Content-Security-Policy header added to the responses
+    pub content_security_policy: Option<String>,
+}
+
+impl TryFrom<HttpTransformData> for HttpTransform {
+    type Error = String;
+
+    fn try_from(value: HttpTransformData) -> Result<Self, Self::Error> {
+        let request = value
+            .request
+            .map(|request| rib::from_string(request).map_err(|e| e.to_string()))
+            .transpose()?;
+
+        let response = value
+            .response
+            .map(|response| rib::from_string(response).map_err(|e| e.to_string()))
+            .transpose()?;
+
+        Ok(HttpTransform {
+            request,
+            response,
+            security_headers: SecurityHeaders {
+                strict_transport_security: value.strict_transport_security,
+                content_security_policy: value.content_security_policy,
+            },
+        })
+    }
+}
+
+impl From<HttpTransformMiddleware> for HttpTransformData {
+    fn from(value: HttpTransformMiddleware) -> Self {
+        HttpTransformData {
+            request: value
+                .request_transform
+                .map(|compiled| compiled.transform.to_string()),
+            response: value
+                .response_transform
+                .map(|compiled| compiled.transform.to_string()),
+            strict_transport_security: value.security_headers.strict_transport_security,
+            content_security_policy: value.security_headers.content_security_policy,
+        }
     }
 }
 
@@ -545,6 +684,7 @@ pub struct GatewayBindingResponseData {
     pub idempotency_key_input: Option<RibInputTypeInfo>, // If bindingType is Default or FilerServer
     pub cors_preflight: Option<HttpCors>, // If bindingType is CorsPreflight (internally, a static binding)
     pub response_mapping_output: Option<RibOutputTypeInfo>, // If bindingType is Default or FileServer
+    pub response_cache: Option<ResponseCachePolicyData>,    // If bindingType is Default
 }
 
 impl GatewayBindingResponseData {
@@ -601,6 +741,7 @@ impl GatewayBindingResponseData {
                     idempotency_key_input: None,
                     cors_preflight: static_binding.get_cors_preflight(),
                     response_mapping_output: None,
+                    response_cache: None,
                 })
             }
         }
@@ -646,6 +787,7 @@ impl GatewayBindingResponseData {
                 .map(|idempotency_key_compiled| idempotency_key_compiled.rib_input),
             cors_preflight: None,
             response_mapping_output: worker_binding.response_compiled.rib_output,
+            response_cache: None,
         })
     }
 
@@ -686,6 +828,9 @@ impl GatewayBindingResponseData {
                 .map(|idempotency_key_compiled| idempotency_key_compiled.rib_input),
             cors_preflight: None,
             response_mapping_output: worker_binding.response_compiled.rib_output,
+            response_cache: worker_binding
+                .response_cache_policy_compiled
+                .map(ResponseCachePolicyData::from),
         })
     }
 
@@ -722,6 +867,7 @@ impl GatewayBindingResponseData {
                 .map(|idempotency_key_compiled| idempotency_key_compiled.rib_input),
             cors_preflight: None,
             response_mapping_output: None,
+            response_cache: None,
         })
     }
 }
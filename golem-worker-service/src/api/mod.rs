@@ -58,8 +58,10 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
             ),
             ApiDeploymentApi::new(
                 services.deployment_service.clone(),
+                services.definition_service.clone(),
                 services.worker_auth_service.clone(),
                 services.domain_route.clone(),
+                services.gateway_response_cache.clone(),
             ),
             ApiCertificateApi::new(services.certificate_service.clone()),
             ApiDomainApi::new(services.domain_service.clone()),
@@ -77,5 +79,6 @@ pub fn custom_http_request_api(services: &Services) -> CustomHttpRequestApi {
         services.file_server_binding_handler.clone(),
         services.http_handler_binding_handler.clone(),
         services.gateway_session_store.clone(),
+        services.gateway_response_cache.clone(),
     )
 }
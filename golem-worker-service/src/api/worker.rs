@@ -24,6 +24,7 @@ use golem_common::model::auth::AuthCtx;
 use golem_common::model::auth::{ProjectAction, TokenSecret};
 use golem_common::model::error::{ErrorBody, ErrorsBody};
 use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::diff::OplogDiff;
 use golem_common::model::public_oplog::OplogCursor;
 use golem_common::model::worker::WorkerCreationRequest;
 use golem_common::model::{
@@ -968,6 +969,186 @@ impl WorkerApi {
         }
     }
 
+    /// Compare the oplog of a worker with the oplog of another worker
+    ///
+    /// Aligns the two oplogs by the kind of their entries and returns the first index where they
+    /// diverge, together with at most `limit` (by default 100) differing entries. Useful for
+    /// comparing a worker with its fork.
+    /// The other worker belongs to the same component, unless `other_component_id` is set.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/oplog/diff",
+        method = "get",
+        operation_id = "diff_oplogs"
+    )]
+    async fn diff_oplogs(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        other_worker_name: Query<String>,
+        other_component_id: Query<Option<ComponentId>>,
+        limit: Query<Option<u64>>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<OplogDiff>> {
+        let worker_id = validated_worker_id(component_id.0.clone(), worker_name.0)?;
+        let other_worker_id = validated_worker_id(
+            other_component_id.0.unwrap_or(component_id.0),
+            other_worker_name.0,
+        )?;
+
+        let record = recorded_http_api_request!(
+            "diff_oplogs",
+            worker_id = worker_id.to_string(),
+            other_worker_id = other_worker_id.to_string()
+        );
+
+        let response = self
+            .diff_oplogs_internal(
+                worker_id,
+                other_worker_id,
+                limit
+                    .0
+                    .map(|limit| limit as usize)
+                    .unwrap_or(OplogDiff::DEFAULT_LIMIT),
+                token,
+            )
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn diff_oplogs_internal(
+        &self,
+        worker_id: WorkerId,
+        other_worker_id: WorkerId,
+        limit: usize,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<OplogDiff>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+        let other_namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(
+                &other_worker_id.component_id,
+                ProjectAction::ViewWorker,
+                &auth,
+            )
+            .await?;
+
+        let response = self
+            .worker_service
+            .diff_oplogs(
+                &worker_id,
+                namespace,
+                &other_worker_id,
+                other_namespace,
+                limit,
+            )
+            .await?;
+
+        Ok(Json(response))
+    }
+
+    /// Export the trace of a worker
+    ///
+    /// Returns a self-contained archive of the worker's execution: its whole oplog with all the
+    /// payloads, and the binary and initial files of every component version it used. The archive
+    /// can be imported into another deployment, for example to replay it in the debugger.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/trace",
+        method = "get",
+        operation_id = "export_worker_trace"
+    )]
+    async fn export_worker_trace(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Binary<Vec<u8>>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("export_worker_trace", worker_id = worker_id.to_string());
+
+        let response = self
+            .export_worker_trace_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn export_worker_trace_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Binary<Vec<u8>>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let trace = self
+            .worker_service
+            .export_worker_trace(&worker_id, namespace)
+            .await?;
+
+        Ok(Binary(trace))
+    }
+
+    /// Import the trace of a worker
+    ///
+    /// Creates a new, suspended worker from an archive exported by `export_worker_trace`. The
+    /// archive must come from a worker of the same component, and the component versions it
+    /// refers to must be available for the worker executor.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/trace",
+        method = "post",
+        operation_id = "import_worker_trace"
+    )]
+    async fn import_worker_trace(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        trace: Binary<Vec<u8>>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ImportWorkerTraceResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("import_worker_trace", worker_id = worker_id.to_string());
+
+        let response = self
+            .import_worker_trace_internal(worker_id, trace.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn import_worker_trace_internal(
+        &self,
+        worker_id: WorkerId,
+        trace: Vec<u8>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ImportWorkerTraceResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::CreateWorker, &auth)
+            .await?;
+
+        self.worker_service
+            .import_worker_trace(&worker_id, trace, namespace)
+            .await?;
+
+        Ok(Json(ImportWorkerTraceResponse {}))
+    }
+
     /// List files in a worker
     #[oai(
         path = "/:component_id/workers/:worker_name/files/:file_name",
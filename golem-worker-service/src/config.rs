@@ -34,6 +34,7 @@ pub struct WorkerServiceConfig {
     pub environment: String,
     pub tracing: TracingConfig,
     pub gateway_session_storage: GatewaySessionStorageConfig,
+    pub gateway_response_cache: GatewayResponseCacheConfig,
     pub db: DbConfig,
     pub component_service: ComponentServiceConfig,
     pub port: u16,
@@ -65,6 +66,7 @@ impl Default for WorkerServiceConfig {
                 foreign_keys: false,
             }),
             gateway_session_storage: GatewaySessionStorageConfig::default_redis(),
+            gateway_response_cache: GatewayResponseCacheConfig::default(),
             component_service: ComponentServiceConfig::default(),
             tracing: TracingConfig::local_dev("worker-service"),
             port: 9005,
@@ -129,6 +131,34 @@ impl GatewaySessionStorageConfig {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum GatewayResponseCacheConfig {
+    InMemory(GatewayResponseCacheInMemoryConfig),
+    Redis(RedisConfig),
+}
+
+impl Default for GatewayResponseCacheConfig {
+    fn default() -> Self {
+        Self::InMemory(GatewayResponseCacheInMemoryConfig::default())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GatewayResponseCacheInMemoryConfig {
+    pub max_capacity: usize,
+    pub eviction_period: Duration,
+}
+
+impl Default for GatewayResponseCacheInMemoryConfig {
+    fn default() -> Self {
+        Self {
+            max_capacity: 1024,
+            eviction_period: Duration::from_secs(60),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComponentServiceConfig {
     pub host: String,
@@ -23,6 +23,7 @@ use crate::gateway_binding::{
 use crate::gateway_binding::{HttpHandlerBindingCompiled, WorkerBindingCompiled};
 use crate::gateway_middleware::{
     HttpAuthenticationMiddleware, HttpCors, HttpMiddleware, HttpMiddlewares,
+    HttpTransformMiddleware,
 };
 use crate::gateway_security::SecuritySchemeReference;
 use crate::service::gateway::api_definition::ApiDefinitionError;
@@ -116,6 +117,17 @@ impl HttpApiDefinition {
                 http_middlewares.push(HttpMiddleware::authenticate_request(security_scheme));
             }
 
+            if let Some(transform) = &route.transform {
+                let transform =
+                    HttpTransformMiddleware::from_http_transform(transform).map_err(|error| {
+                        ApiDefinitionError::ValidationError(ValidationErrors {
+                            errors: vec![format!("{} {}: {}", route.method, route.path, error)],
+                        })
+                    })?;
+
+                http_middlewares.push(HttpMiddleware::transform(transform));
+            }
+
             routes.push(Route {
                 method: route.method,
                 path: route.path,
@@ -559,6 +571,26 @@ impl CompiledRoute {
             _ => None,
         }
     }
+
+    // Warnings of the Rib script mapping the response, found when the route was compiled
+    pub fn rib_warnings(&self) -> Vec<String> {
+        let warnings = match &self.binding {
+            GatewayBindingCompiled::Worker(worker_binding) => {
+                &worker_binding.response_compiled.rib_warnings
+            }
+            GatewayBindingCompiled::FileServer(file_server_binding) => {
+                &file_server_binding.response_compiled.rib_warnings
+            }
+            GatewayBindingCompiled::HttpHandler(_) | GatewayBindingCompiled::Static(_) => {
+                return vec![];
+            }
+        };
+
+        warnings
+            .iter()
+            .map(|warning| format!("{} {}: {}", self.method, self.path, warning))
+            .collect()
+    }
 }
 
 impl TryFrom<CompiledRoute> for golem_api_grpc::proto::golem::apidefinition::CompiledHttpRoute {
@@ -15,6 +15,7 @@
 use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern, Route};
 use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
 use crate::gateway_binding::GatewayBinding;
+use crate::gateway_middleware::HttpTransform;
 use crate::gateway_security::SecuritySchemeReference;
 
 // HttpApiDefinitionRequest corresponds to the user facing http api definition.
@@ -31,13 +32,15 @@ pub struct HttpApiDefinitionRequest {
 // In a RouteRequest, security is defined at the outer level
 // Also this security has minimal information (and avoid details such as client-id, secret etc).
 // When `RouteRequest` is converted to `Route`, this security is pushed as middleware in the binding
-// along with fetching more details about the security scheme
+// along with fetching more details about the security scheme.
+// Similarly, the transform is pushed as a middleware once its Rib scripts are compiled
 #[derive(Debug, Clone, PartialEq)]
 pub struct RouteRequest {
     pub method: MethodPattern,
     pub path: AllPathPatterns,
     pub binding: GatewayBinding,
     pub security: Option<SecuritySchemeReference>,
+    pub transform: Option<HttpTransform>,
 }
 
 impl From<Route> for RouteRequest {
@@ -47,12 +50,18 @@ impl From<Route> for RouteRequest {
             .clone()
             .and_then(|x| x.get_http_authentication_middleware());
 
+        let transform_middleware = value
+            .middlewares
+            .as_ref()
+            .and_then(|x| x.get_transform_middleware());
+
         RouteRequest {
             method: value.method,
             path: value.path,
             binding: value.binding,
             security: security_middleware
                 .map(|x| SecuritySchemeReference::from(x.security_scheme_with_metadata)),
+            transform: transform_middleware.map(HttpTransform::from),
         }
     }
 }
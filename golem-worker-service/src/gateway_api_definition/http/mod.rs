@@ -19,5 +19,6 @@ pub use oas_api_definition::*;
 mod api_definition;
 mod api_definition_request;
 mod oas_api_definition;
+mod oas_api_definition_export;
 mod path_pattern_parser;
 mod place_holder_parser;
@@ -16,6 +16,10 @@ use crate::gateway_api_definition::http::HttpApiDefinitionRequest;
 use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
 use crate::service::gateway::BoxConversionContext;
 use internal::*;
+pub(crate) use internal::{
+    GOLEM_API_DEFINITION_ID_EXTENSION, GOLEM_API_DEFINITION_VERSION, GOLEM_API_GATEWAY_BINDING,
+    GOLEM_API_GATEWAY_TRANSFORM,
+};
 use openapiv3::OpenAPI;
 use poem_openapi::registry::{MetaSchema, MetaSchemaRef};
 use poem_openapi::types::{ParseError, ParseFromJSON, ParseFromYAML, ParseResult};
@@ -123,9 +127,12 @@ mod internal {
     use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern, RouteRequest};
 
     use crate::gateway_binding::{
-        GatewayBinding, HttpHandlerBinding, ResponseMapping, StaticBinding, WorkerBinding,
+        GatewayBinding, HttpHandlerBinding, ResponseCachePolicy, ResponseMapping, StaticBinding,
+        WorkerBinding,
+    };
+    use crate::gateway_middleware::{
+        CorsPreflightExpr, HttpCors, HttpTransform, SecurityHeaders, StrictTransportSecurity,
     };
-    use crate::gateway_middleware::{CorsPreflightExpr, HttpCors};
     use crate::gateway_security::{SecuritySchemeIdentifier, SecuritySchemeReference};
     use crate::service::gateway::BoxConversionContext;
     use golem_common::model::component::VersionedComponentId;
@@ -134,14 +141,17 @@ mod internal {
     use openapiv3::{OpenAPI, Operation, Paths, ReferenceOr};
     use rib::Expr;
     use serde_json::Value;
+    use std::time::Duration;
 
-    pub(super) const GOLEM_API_DEFINITION_ID_EXTENSION: &str = "x-golem-api-definition-id";
-    pub(super) const GOLEM_API_DEFINITION_VERSION: &str = "x-golem-api-definition-version";
+    pub(crate) const GOLEM_API_DEFINITION_ID_EXTENSION: &str = "x-golem-api-definition-id";
+    pub(crate) const GOLEM_API_DEFINITION_VERSION: &str = "x-golem-api-definition-version";
 
     // Legacy extension for worker bridge
     pub(super) const GOLEM_WORKER_GATEWAY_EXTENSION_LEGACY: &str = "x-golem-worker-bridge";
 
-    pub(super) const GOLEM_API_GATEWAY_BINDING: &str = "x-golem-api-gateway-binding";
+    pub(crate) const GOLEM_API_GATEWAY_BINDING: &str = "x-golem-api-gateway-binding";
+
+    pub(crate) const GOLEM_API_GATEWAY_TRANSFORM: &str = "x-golem-api-gateway-transform";
 
     pub(super) fn get_root_extension_str(
         open_api: &OpenAPI,
@@ -225,6 +235,12 @@ mod internal {
             security_scheme_identifier: SecuritySchemeIdentifier::new(x),
         });
 
+        let transform = method_operation
+            .extensions
+            .get(GOLEM_API_GATEWAY_TRANSFORM)
+            .map(get_transform)
+            .transpose()?;
+
         let worker_gateway_info_optional = method_operation
             .extensions
             // TO keep backward compatibility with the old extension
@@ -244,6 +260,7 @@ mod internal {
                             path: path_pattern.clone(),
                             binding: GatewayBinding::static_binding(binding),
                             security,
+                            transform,
                         })
                     }
 
@@ -255,6 +272,7 @@ mod internal {
                             method,
                             binding: GatewayBinding::Default(Box::new(binding)),
                             security,
+                            transform,
                         })
                     }
                     (GatewayBindingType::FileServer, _) => {
@@ -265,6 +283,7 @@ mod internal {
                             method,
                             binding: GatewayBinding::Default(Box::new(binding)),
                             security,
+                            transform,
                         })
                     }
                     (GatewayBindingType::HttpHandler, _) => {
@@ -275,6 +294,7 @@ mod internal {
                             method,
                             binding: GatewayBinding::HttpHandler(Box::new(binding)),
                             security,
+                            transform,
                         })
                     }
                     (GatewayBindingType::CorsPreflight, method) => {
@@ -292,6 +312,7 @@ mod internal {
                         method,
                         binding: GatewayBinding::static_binding(binding),
                         security,
+                        transform,
                     })
                 } else {
                     Err(format!(
@@ -319,6 +340,7 @@ mod internal {
             idempotency_key: get_idempotency_key(gateway_binding_value)?,
             response_mapping: get_response_mapping(gateway_binding_value)?,
             invocation_context: get_invocation_context(gateway_binding_value)?,
+            response_cache_policy: get_response_cache_policy(gateway_binding_value)?,
         };
 
         Ok(binding)
@@ -468,6 +490,112 @@ mod internal {
         }
     }
 
+    pub(super) fn get_response_cache_policy(
+        gateway_binding_value: &Value,
+    ) -> Result<Option<ResponseCachePolicy>, String> {
+        if let Some(response_cache) = gateway_binding_value.get("response-cache") {
+            let ttl_seconds = response_cache
+                .get("ttl-seconds")
+                .ok_or("No ttl-seconds found in response-cache")?
+                .as_u64()
+                .ok_or("ttl-seconds in response-cache is not a u64")?;
+
+            let cache_key = response_cache
+                .get("key")
+                .map(|key| {
+                    let key_expr = key
+                        .as_str()
+                        .ok_or("key in response-cache is not a string")?;
+                    rib::from_string(key_expr).map_err(|err| err.to_string())
+                })
+                .transpose()?;
+
+            let vary = match response_cache.get("vary") {
+                Some(Value::Array(headers)) => headers
+                    .iter()
+                    .map(|header| {
+                        header
+                            .as_str()
+                            .map(|header| header.to_string())
+                            .ok_or("vary in response-cache is not a list of strings".to_string())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+                Some(_) => {
+                    return Err("vary in response-cache is not a list of strings".to_string())
+                }
+                None => vec![],
+            };
+
+            Ok(Some(ResponseCachePolicy::new(
+                Duration::from_secs(ttl_seconds),
+                cache_key,
+                vary,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(super) fn get_transform(transform_value: &Value) -> Result<HttpTransform, String> {
+        let get_rib = |key: &str| {
+            transform_value
+                .get(key)
+                .map(|value| {
+                    let expr = value
+                        .as_str()
+                        .ok_or(format!("{key} in transform is not a string"))?;
+                    rib::from_string(expr).map_err(|err| err.to_string())
+                })
+                .transpose()
+        };
+
+        let strict_transport_security = transform_value
+            .get("strict-transport-security")
+            .map(|hsts| {
+                let max_age_seconds = hsts
+                    .get("max-age")
+                    .ok_or("No max-age found in strict-transport-security")?
+                    .as_u64()
+                    .ok_or("max-age in strict-transport-security is not a u64")?;
+
+                let flag = |key: &str| {
+                    hsts.get(key)
+                        .map(|value| {
+                            value.as_bool().ok_or(format!(
+                                "{key} in strict-transport-security is not a boolean"
+                            ))
+                        })
+                        .transpose()
+                        .map(|value| value.unwrap_or(false))
+                };
+
+                Ok::<_, String>(StrictTransportSecurity {
+                    max_age_seconds,
+                    include_subdomains: flag("include-subdomains")?,
+                    preload: flag("preload")?,
+                })
+            })
+            .transpose()?;
+
+        let content_security_policy = transform_value
+            .get("content-security-policy")
+            .map(|csp| {
+                csp.as_str()
+                    .map(|csp| csp.to_string())
+                    .ok_or("content-security-policy in transform is not a string".to_string())
+            })
+            .transpose()?;
+
+        Ok(HttpTransform {
+            request: get_rib("request")?,
+            response: get_rib("response")?,
+            security_headers: SecurityHeaders {
+                strict_transport_security,
+                content_security_policy,
+            },
+        })
+    }
+
     pub(super) fn get_path_pattern(path: &str) -> Result<AllPathPatterns, String> {
         AllPathPatterns::parse(path)
     }
@@ -481,8 +609,10 @@ mod tests {
 
     use super::*;
     use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern, RouteRequest};
-    use crate::gateway_binding::{GatewayBinding, StaticBinding};
-    use crate::gateway_middleware::HttpCors;
+    use crate::gateway_binding::{GatewayBinding, ResponseCachePolicy, StaticBinding};
+    use crate::gateway_middleware::{
+        HttpCors, HttpTransform, SecurityHeaders, StrictTransportSecurity,
+    };
     use crate::service::gateway::{ComponentView, ConversionContext};
     use async_trait::async_trait;
     use golem_common::model::component::VersionedComponentId;
@@ -598,6 +728,7 @@ mod tests {
                 HttpCors::default(),
             )),
             security: None,
+            transform: None,
         }
     }
 
@@ -609,6 +740,7 @@ mod tests {
             method: MethodPattern::Options,
             binding: GatewayBinding::static_binding(StaticBinding::from_http_cors(cors_preflight)),
             security: None,
+            transform: None,
         }
     }
 
@@ -645,4 +777,98 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    async fn test_get_route_with_response_cache_policy() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "binding-type": "default",
+                    "response" : "{}",
+                    "component-name": "foobar",
+                    "response-cache": {
+                        "ttl-seconds": 60,
+                        "key": "request.path.user-id",
+                        "vary": ["Accept-Language"]
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/{user-id}").unwrap();
+
+        let result =
+            get_route_from_path_item("get", &path_item, &path_pattern, &TestConversionCtx.boxed())
+                .await
+                .unwrap();
+
+        let expected = ResponseCachePolicy::new(
+            std::time::Duration::from_secs(60),
+            Some(rib::from_string("request.path.user-id").unwrap()),
+            vec!["accept-language".to_string()],
+        );
+
+        match result.binding {
+            GatewayBinding::Default(worker_binding) => {
+                assert_eq!(worker_binding.response_cache_policy, Some(expected))
+            }
+            other => panic!("Expected a worker binding, found {other:?}"),
+        }
+    }
+
+    #[test]
+    async fn test_get_route_with_transform() {
+        let path_item = Operation {
+            extensions: vec![
+                (
+                    "x-golem-api-gateway-binding".to_string(),
+                    json!({
+                        "binding-type": "default",
+                        "response" : "{}",
+                        "component-name": "foobar"
+                    }),
+                ),
+                (
+                    "x-golem-api-gateway-transform".to_string(),
+                    json!({
+                        "request": "{remove-headers: [\"cookie\"]}",
+                        "strict-transport-security": {
+                            "max-age": 31536000,
+                            "include-subdomains": true
+                        },
+                        "content-security-policy": "default-src 'self'"
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/{user-id}").unwrap();
+
+        let result =
+            get_route_from_path_item("get", &path_item, &path_pattern, &TestConversionCtx.boxed())
+                .await
+                .unwrap();
+
+        let expected = HttpTransform {
+            request: Some(rib::from_string(r#"{remove-headers: ["cookie"]}"#).unwrap()),
+            response: None,
+            security_headers: SecurityHeaders {
+                strict_transport_security: Some(StrictTransportSecurity {
+                    max_age_seconds: 31536000,
+                    include_subdomains: true,
+                    preload: false,
+                }),
+                content_security_policy: Some("default-src 'self'".to_string()),
+            },
+        };
+
+        assert_eq!(result.transform, Some(expected));
+    }
 }
@@ -0,0 +1,1206 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_api_definition::http::oas_api_definition::{
+    GOLEM_API_DEFINITION_ID_EXTENSION, GOLEM_API_DEFINITION_VERSION, GOLEM_API_GATEWAY_BINDING,
+    GOLEM_API_GATEWAY_TRANSFORM,
+};
+use crate::gateway_api_definition::http::{
+    CompiledHttpApiDefinition, CompiledRoute, OpenApiHttpApiDefinition,
+};
+use crate::gateway_api_deployment::ApiSiteString;
+use crate::service::gateway::BoxConversionContext;
+use internal::*;
+use openapiv3::{Info, OpenAPI, Paths, ReferenceOr, Server};
+use poem_openapi::types::ToJSON;
+use serde_json::Value;
+
+const OPEN_API_VERSION: &str = "3.0.0";
+
+impl OpenApiHttpApiDefinition {
+    // Render a compiled API definition as an OpenAPI document.
+    // The golem extensions are kept in the output, such that the exported document
+    // can be imported back using `create_or_update_open_api`.
+    pub async fn from_compiled_http_api_definition(
+        definition: &CompiledHttpApiDefinition,
+        conversion_context: &BoxConversionContext<'_>,
+    ) -> Result<OpenApiHttpApiDefinition, String> {
+        let mut builder = OpenApiBuilder::default();
+
+        for route in &definition.routes {
+            builder.add_route(route, conversion_context).await?;
+        }
+
+        let mut open_api = builder.build(Info {
+            title: definition.id.0.clone(),
+            version: definition.version.0.clone(),
+            ..Default::default()
+        });
+
+        open_api.extensions.insert(
+            GOLEM_API_DEFINITION_ID_EXTENSION.to_string(),
+            Value::String(definition.id.0.clone()),
+        );
+        open_api.extensions.insert(
+            GOLEM_API_DEFINITION_VERSION.to_string(),
+            Value::String(definition.version.0.clone()),
+        );
+
+        Ok(OpenApiHttpApiDefinition(open_api))
+    }
+
+    // Render all the API definitions deployed to a site as a single OpenAPI document.
+    // As the routes of a deployment can originate from multiple API definitions,
+    // the result doesn't have the root level golem extensions, and cannot be imported back as is.
+    pub async fn from_api_deployment(
+        site: &ApiSiteString,
+        definitions: &[CompiledHttpApiDefinition],
+        conversion_context: &BoxConversionContext<'_>,
+    ) -> Result<OpenApiHttpApiDefinition, String> {
+        let mut builder = OpenApiBuilder::default();
+
+        for definition in definitions {
+            for route in &definition.routes {
+                builder.add_route(route, conversion_context).await?;
+            }
+        }
+
+        let version = definitions
+            .iter()
+            .map(|definition| format!("{}@{}", definition.id, definition.version))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut open_api = builder.build(Info {
+            title: site.to_string(),
+            version,
+            ..Default::default()
+        });
+
+        open_api.servers = vec![Server {
+            url: format!("http://{site}"),
+            ..Default::default()
+        }];
+
+        Ok(OpenApiHttpApiDefinition(open_api))
+    }
+}
+
+impl ToJSON for OpenApiHttpApiDefinition {
+    fn to_json(&self) -> Option<Value> {
+        serde_json::to_value(&self.0).ok()
+    }
+}
+
+#[derive(Default)]
+struct OpenApiBuilder {
+    paths: Paths,
+    components: openapiv3::Components,
+}
+
+impl OpenApiBuilder {
+    async fn add_route(
+        &mut self,
+        route: &CompiledRoute,
+        conversion_context: &BoxConversionContext<'_>,
+    ) -> Result<(), String> {
+        // Similar to the API definition response, auth call back routes
+        // are internal to the gateway and are not exposed to the users
+        if route.binding.is_static_auth_call_back_binding() {
+            return Ok(());
+        }
+
+        let mut operation = get_operation(route, conversion_context).await?;
+
+        if let Some(auth_middleware) = route.get_security_middleware() {
+            let (name, security_scheme) = get_security_scheme(&auth_middleware);
+
+            let scopes = auth_middleware
+                .get_scopes()
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect::<Vec<_>>();
+
+            operation.security = Some(vec![vec![(name.clone(), scopes)].into_iter().collect()]);
+
+            self.components
+                .security_schemes
+                .insert(name, ReferenceOr::Item(security_scheme));
+        }
+
+        if let Some(transform) = route
+            .middlewares
+            .as_ref()
+            .and_then(|middlewares| middlewares.get_transform_middleware())
+        {
+            operation.extensions.insert(
+                GOLEM_API_GATEWAY_TRANSFORM.to_string(),
+                get_transform_extension(&transform),
+            );
+        }
+
+        let path = get_open_api_path(&route.path);
+
+        let path_item = self
+            .paths
+            .paths
+            .entry(path.clone())
+            .or_insert_with(|| ReferenceOr::Item(Default::default()));
+
+        match path_item {
+            ReferenceOr::Item(path_item) => set_operation(path_item, &route.method, operation)
+                .map_err(|_| {
+                    format!(
+                        "Multiple routes found for method {} and path {}",
+                        route.method, path
+                    )
+                }),
+            ReferenceOr::Reference { .. } => {
+                Err(format!("Unexpected reference found for path {path}"))
+            }
+        }
+    }
+
+    fn build(self, info: Info) -> OpenAPI {
+        let components = if self.components.security_schemes.is_empty() {
+            None
+        } else {
+            Some(self.components)
+        };
+
+        OpenAPI {
+            openapi: OPEN_API_VERSION.to_string(),
+            info,
+            paths: self.paths,
+            components,
+            ..Default::default()
+        }
+    }
+}
+
+mod internal {
+    use super::GOLEM_API_GATEWAY_BINDING;
+    use crate::gateway_api_definition::http::{
+        AllPathPatterns, CompiledRoute, MethodPattern, PathPattern,
+    };
+    use crate::gateway_binding::{
+        GatewayBindingCompiled, ResponseCachePolicyCompiled, StaticBinding,
+    };
+    use crate::gateway_middleware::{
+        CorsPreflightExpr, HttpAuthenticationMiddleware, HttpCors, HttpTransformMiddleware,
+    };
+    use crate::service::gateway::BoxConversionContext;
+    use golem_common::model::component::VersionedComponentId;
+    use golem_common::model::GatewayBindingType;
+    use golem_wasm_ast::analysis::AnalysedType;
+    use openapiv3::{
+        AdditionalProperties, ArrayType, BooleanType, Content, Header, HeaderStyle, IntegerFormat,
+        IntegerType, MediaType, NumberFormat, NumberType, ObjectType, Operation, Parameter,
+        ParameterData, ParameterSchemaOrContent, PathItem, PathStyle, QueryStyle, ReferenceOr,
+        RequestBody, Response, Responses, Schema, SchemaData, SchemaKind, SecurityScheme,
+        StatusCode, StringFormat, StringType, Type, VariantOrUnknownOrEmpty,
+    };
+    use rib::{Expr, RibInputTypeInfo, RibOutputTypeInfo};
+    use serde_json::{Map, Value};
+
+    const APPLICATION_JSON: &str = "application/json";
+    const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
+
+    // OpenAPI has no notion of catch-all path variables, and therefore
+    // `{+var}` is rendered as `{var}`
+    pub(super) fn get_open_api_path(path: &AllPathPatterns) -> String {
+        let mut result = String::new();
+
+        for pattern in path.path_patterns.iter() {
+            result.push('/');
+            match pattern {
+                PathPattern::Literal(literal) => result.push_str(literal.0.as_str()),
+                PathPattern::Var(var) | PathPattern::CatchAllVar(var) => {
+                    result.push('{');
+                    result.push_str(var.key_name.as_str());
+                    result.push('}');
+                }
+            }
+        }
+
+        if result.is_empty() {
+            result.push('/');
+        }
+
+        result
+    }
+
+    pub(super) fn set_operation(
+        path_item: &mut PathItem,
+        method: &MethodPattern,
+        operation: Operation,
+    ) -> Result<(), ()> {
+        let slot = match method {
+            MethodPattern::Get => &mut path_item.get,
+            MethodPattern::Post => &mut path_item.post,
+            MethodPattern::Delete => &mut path_item.delete,
+            MethodPattern::Put => &mut path_item.put,
+            MethodPattern::Patch => &mut path_item.patch,
+            MethodPattern::Options => &mut path_item.options,
+            MethodPattern::Trace => &mut path_item.trace,
+            MethodPattern::Head => &mut path_item.head,
+            // CONNECT is not representable in OpenAPI 3.0, and is kept only as an extension
+            MethodPattern::Connect => {
+                path_item.extensions.insert(
+                    "x-golem-connect".to_string(),
+                    serde_json::to_value(operation).map_err(|_| ())?,
+                );
+                return Ok(());
+            }
+        };
+
+        if slot.is_some() {
+            Err(())
+        } else {
+            *slot = Some(operation);
+            Ok(())
+        }
+    }
+
+    pub(super) fn get_security_scheme(
+        auth_middleware: &HttpAuthenticationMiddleware,
+    ) -> (String, SecurityScheme) {
+        let security_scheme = &auth_middleware.security_scheme_with_metadata;
+
+        let name = security_scheme
+            .security_scheme
+            .scheme_identifier()
+            .to_string();
+
+        let issuer = security_scheme.provider_metadata.issuer().url().to_string();
+
+        let open_id_connect_url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+
+        (
+            name,
+            SecurityScheme::OpenIDConnect {
+                open_id_connect_url,
+                description: Some(format!(
+                    "{} identity provider",
+                    security_scheme.security_scheme.provider_type()
+                )),
+                extensions: Default::default(),
+            },
+        )
+    }
+
+    pub(super) async fn get_operation(
+        route: &CompiledRoute,
+        conversion_context: &BoxConversionContext<'_>,
+    ) -> Result<Operation, String> {
+        let mut operation = Operation {
+            operation_id: Some(get_operation_id(&route.method, &route.path)),
+            ..Default::default()
+        };
+
+        let cors = route
+            .middlewares
+            .as_ref()
+            .and_then(|middlewares| middlewares.get_cors_middleware());
+
+        match &route.binding {
+            GatewayBindingCompiled::Worker(worker_binding) => {
+                let mut rib_inputs = vec![&worker_binding.response_compiled.rib_input];
+                rib_inputs.extend(
+                    worker_binding
+                        .idempotency_key_compiled
+                        .as_ref()
+                        .map(|compiled| &compiled.rib_input),
+                );
+                rib_inputs.extend(
+                    worker_binding
+                        .invocation_context_compiled
+                        .as_ref()
+                        .map(|compiled| &compiled.rib_input),
+                );
+                rib_inputs.extend(
+                    worker_binding
+                        .response_cache_policy_compiled
+                        .as_ref()
+                        .and_then(|policy| policy.cache_key_compiled.as_ref())
+                        .map(|compiled| &compiled.rib_input),
+                );
+
+                set_request(&mut operation, &route.path, &rib_inputs);
+
+                operation.responses = get_worker_responses(
+                    worker_binding.response_compiled.rib_output.as_ref(),
+                    APPLICATION_JSON,
+                    cors.as_ref(),
+                );
+
+                let mut binding = get_binding_extension(
+                    GatewayBindingType::Default,
+                    &worker_binding.component_id,
+                    conversion_context,
+                )
+                .await?;
+
+                binding.insert(
+                    "response".to_string(),
+                    rib_text(&worker_binding.response_compiled.response_mapping_expr),
+                );
+
+                if let Some(idempotency_key) = &worker_binding.idempotency_key_compiled {
+                    binding.insert(
+                        "idempotency-key".to_string(),
+                        rib_text(&idempotency_key.idempotency_key),
+                    );
+                }
+
+                if let Some(invocation_context) = &worker_binding.invocation_context_compiled {
+                    binding.insert(
+                        "invocation-context".to_string(),
+                        rib_text(&invocation_context.invocation_context),
+                    );
+                }
+
+                if let Some(response_cache) = &worker_binding.response_cache_policy_compiled {
+                    binding.insert(
+                        "response-cache".to_string(),
+                        get_response_cache_extension(response_cache),
+                    );
+                }
+
+                operation.extensions.insert(
+                    GOLEM_API_GATEWAY_BINDING.to_string(),
+                    Value::Object(binding),
+                );
+            }
+
+            GatewayBindingCompiled::FileServer(file_server_binding) => {
+                let mut rib_inputs = vec![&file_server_binding.response_compiled.rib_input];
+                rib_inputs.extend(
+                    file_server_binding
+                        .worker_name_compiled
+                        .as_ref()
+                        .map(|compiled| &compiled.rib_input_type_info),
+                );
+                rib_inputs.extend(
+                    file_server_binding
+                        .idempotency_key_compiled
+                        .as_ref()
+                        .map(|compiled| &compiled.rib_input),
+                );
+
+                set_request(&mut operation, &route.path, &rib_inputs);
+
+                operation.responses =
+                    get_worker_responses(None, APPLICATION_OCTET_STREAM, cors.as_ref());
+
+                let mut binding = get_binding_extension(
+                    GatewayBindingType::FileServer,
+                    &file_server_binding.component_id,
+                    conversion_context,
+                )
+                .await?;
+
+                binding.insert(
+                    "response".to_string(),
+                    rib_text(&file_server_binding.response_compiled.response_mapping_expr),
+                );
+
+                if let Some(worker_name) = &file_server_binding.worker_name_compiled {
+                    binding.insert(
+                        "worker-name".to_string(),
+                        rib_text(&worker_name.worker_name),
+                    );
+                }
+
+                if let Some(idempotency_key) = &file_server_binding.idempotency_key_compiled {
+                    binding.insert(
+                        "idempotency-key".to_string(),
+                        rib_text(&idempotency_key.idempotency_key),
+                    );
+                }
+
+                operation.extensions.insert(
+                    GOLEM_API_GATEWAY_BINDING.to_string(),
+                    Value::Object(binding),
+                );
+            }
+
+            GatewayBindingCompiled::HttpHandler(http_handler_binding) => {
+                let mut rib_inputs = vec![];
+                rib_inputs.extend(
+                    http_handler_binding
+                        .worker_name_compiled
+                        .as_ref()
+                        .map(|compiled| &compiled.rib_input_type_info),
+                );
+                rib_inputs.extend(
+                    http_handler_binding
+                        .idempotency_key_compiled
+                        .as_ref()
+                        .map(|compiled| &compiled.rib_input),
+                );
+
+                set_request(&mut operation, &route.path, &rib_inputs);
+
+                // The request and response are passed through to the http handler as is,
+                // and hence there are no schemas for them
+                let mut response = Response {
+                    description: "Response of the HTTP handler".to_string(),
+                    ..Default::default()
+                };
+
+                add_cors_response_headers(&mut response, cors.as_ref());
+
+                operation.responses = Responses {
+                    default: Some(ReferenceOr::Item(response)),
+                    ..Default::default()
+                };
+
+                let mut binding = get_binding_extension(
+                    GatewayBindingType::HttpHandler,
+                    &http_handler_binding.component_id,
+                    conversion_context,
+                )
+                .await?;
+
+                if let Some(worker_name) = &http_handler_binding.worker_name_compiled {
+                    binding.insert(
+                        "worker-name".to_string(),
+                        rib_text(&worker_name.worker_name),
+                    );
+                }
+
+                if let Some(idempotency_key) = &http_handler_binding.idempotency_key_compiled {
+                    binding.insert(
+                        "idempotency-key".to_string(),
+                        rib_text(&idempotency_key.idempotency_key),
+                    );
+                }
+
+                operation.extensions.insert(
+                    GOLEM_API_GATEWAY_BINDING.to_string(),
+                    Value::Object(binding),
+                );
+            }
+
+            GatewayBindingCompiled::Static(StaticBinding::HttpCorsPreflight(cors_preflight)) => {
+                set_request(&mut operation, &route.path, &[]);
+
+                operation.summary = Some("CORS preflight".to_string());
+
+                let mut response = Response {
+                    description: "CORS preflight response".to_string(),
+                    ..Default::default()
+                };
+
+                add_cors_preflight_response_headers(&mut response, cors_preflight);
+
+                operation.responses = Responses {
+                    responses: vec![(StatusCode::Code(200), ReferenceOr::Item(response))]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                };
+
+                let mut binding = Map::new();
+                binding.insert(
+                    "binding-type".to_string(),
+                    serde_json::to_value(GatewayBindingType::CorsPreflight)
+                        .map_err(|err| err.to_string())?,
+                );
+                binding.insert(
+                    "response".to_string(),
+                    rib_text(&CorsPreflightExpr::from_cors(cors_preflight).0),
+                );
+
+                operation.extensions.insert(
+                    GOLEM_API_GATEWAY_BINDING.to_string(),
+                    Value::Object(binding),
+                );
+            }
+
+            GatewayBindingCompiled::Static(StaticBinding::HttpAuthCallBack(_)) => {}
+        }
+
+        Ok(operation)
+    }
+
+    fn get_operation_id(method: &MethodPattern, path: &AllPathPatterns) -> String {
+        let mut segments = vec![method.to_string().to_lowercase()];
+
+        for pattern in path.path_patterns.iter() {
+            match pattern {
+                PathPattern::Literal(literal) => segments.push(literal.0.clone()),
+                PathPattern::Var(var) | PathPattern::CatchAllVar(var) => {
+                    segments.push(format!("by-{}", var.key_name))
+                }
+            }
+        }
+
+        segments
+            .into_iter()
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    async fn get_binding_extension(
+        binding_type: GatewayBindingType,
+        component_id: &VersionedComponentId,
+        conversion_context: &BoxConversionContext<'_>,
+    ) -> Result<Map<String, Value>, String> {
+        let component_view = conversion_context
+            .component_by_id(&component_id.component_id)
+            .await?;
+
+        let mut binding = Map::new();
+
+        binding.insert(
+            "binding-type".to_string(),
+            serde_json::to_value(binding_type).map_err(|err| err.to_string())?,
+        );
+        binding.insert(
+            "component-name".to_string(),
+            Value::String(component_view.name.0),
+        );
+        binding.insert(
+            "component-version".to_string(),
+            Value::from(component_id.version),
+        );
+
+        Ok(binding)
+    }
+
+    fn get_response_cache_extension(response_cache: &ResponseCachePolicyCompiled) -> Value {
+        let mut extension = Map::new();
+
+        extension.insert(
+            "ttl-seconds".to_string(),
+            Value::from(response_cache.ttl.as_secs()),
+        );
+
+        if let Some(cache_key) = &response_cache.cache_key_compiled {
+            extension.insert("key".to_string(), rib_text(&cache_key.cache_key));
+        }
+
+        if !response_cache.vary.is_empty() {
+            extension.insert("vary".to_string(), Value::from(response_cache.vary.clone()));
+        }
+
+        Value::Object(extension)
+    }
+
+    pub(super) fn get_transform_extension(transform: &HttpTransformMiddleware) -> Value {
+        let mut extension = Map::new();
+
+        if let Some(request) = &transform.request_transform {
+            extension.insert("request".to_string(), rib_text(&request.transform));
+        }
+
+        if let Some(response) = &transform.response_transform {
+            extension.insert("response".to_string(), rib_text(&response.transform));
+        }
+
+        if let Some(hsts) = &transform.security_headers.strict_transport_security {
+            let mut hsts_extension = Map::new();
+            hsts_extension.insert("max-age".to_string(), Value::from(hsts.max_age_seconds));
+            hsts_extension.insert(
+                "include-subdomains".to_string(),
+                Value::from(hsts.include_subdomains),
+            );
+            hsts_extension.insert("preload".to_string(), Value::from(hsts.preload));
+
+            extension.insert(
+                "strict-transport-security".to_string(),
+                Value::Object(hsts_extension),
+            );
+        }
+
+        if let Some(csp) = &transform.security_headers.content_security_policy {
+            extension.insert(
+                "content-security-policy".to_string(),
+                Value::String(csp.clone()),
+            );
+        }
+
+        Value::Object(extension)
+    }
+
+    fn rib_text(expr: &Expr) -> Value {
+        Value::String(expr.to_string())
+    }
+
+    // Collects the types of `request.path`, `request.query`, `request.headers` and `request.body`
+    // from all the Rib scripts of a binding. Each Rib script is compiled separately,
+    // and therefore the same lookup can exist in multiple of them
+    fn get_request_field_types(
+        rib_inputs: &[&RibInputTypeInfo],
+        field_names: &[&str],
+    ) -> Vec<(String, AnalysedType)> {
+        let mut result: Vec<(String, AnalysedType)> = vec![];
+
+        for rib_input in rib_inputs {
+            if let Some(AnalysedType::Record(request)) = rib_input.get("request") {
+                for field in request.fields.iter() {
+                    if field_names.contains(&field.name.as_str()) {
+                        match &field.typ {
+                            AnalysedType::Record(record) => {
+                                for nested in record.fields.iter() {
+                                    if !result.iter().any(|(name, _)| name == &nested.name) {
+                                        result.push((nested.name.clone(), nested.typ.clone()));
+                                    }
+                                }
+                            }
+                            other => {
+                                if !result.iter().any(|(name, _)| name == &field.name) {
+                                    result.push((field.name.clone(), other.clone()));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn get_request_body_type(rib_inputs: &[&RibInputTypeInfo]) -> Option<AnalysedType> {
+        rib_inputs
+            .iter()
+            .find_map(|rib_input| match rib_input.get("request") {
+                Some(AnalysedType::Record(request)) => request
+                    .fields
+                    .iter()
+                    .find(|field| field.name == "body")
+                    .map(|field| field.typ.clone()),
+                _ => None,
+            })
+    }
+
+    fn set_request(
+        operation: &mut Operation,
+        path: &AllPathPatterns,
+        rib_inputs: &[&RibInputTypeInfo],
+    ) {
+        let path_types = get_request_field_types(rib_inputs, &["path"]);
+        let query_types = get_request_field_types(rib_inputs, &["query"]);
+        let header_types = get_request_field_types(rib_inputs, &["headers", "header"]);
+
+        for pattern in path.path_patterns.iter() {
+            if let PathPattern::Var(var) | PathPattern::CatchAllVar(var) = pattern {
+                let schema = find_type(&path_types, &var.key_name)
+                    .map(analysed_type_to_schema)
+                    .unwrap_or_else(string_schema);
+
+                operation
+                    .parameters
+                    .push(ReferenceOr::Item(Parameter::Path {
+                        parameter_data: parameter_data(&var.key_name, true, schema),
+                        style: PathStyle::Simple,
+                    }));
+            }
+        }
+
+        // Query parameters that are looked up in Rib are required at runtime,
+        // while the ones that are only part of the path pattern are optional
+        for query in path.query_params.iter() {
+            let typ = find_type(&query_types, &query.key_name);
+            let required = typ.is_some();
+            let schema = typ
+                .map(analysed_type_to_schema)
+                .unwrap_or_else(string_schema);
+
+            operation
+                .parameters
+                .push(ReferenceOr::Item(Parameter::Query {
+                    parameter_data: parameter_data(&query.key_name, required, schema),
+                    allow_reserved: false,
+                    style: QueryStyle::Form,
+                    allow_empty_value: None,
+                }));
+        }
+
+        for (name, typ) in header_types.iter() {
+            operation
+                .parameters
+                .push(ReferenceOr::Item(Parameter::Header {
+                    parameter_data: parameter_data(name, true, analysed_type_to_schema(typ)),
+                    style: HeaderStyle::Simple,
+                }));
+        }
+
+        if let Some(body_type) = get_request_body_type(rib_inputs) {
+            operation.request_body = Some(ReferenceOr::Item(RequestBody {
+                content: json_content(APPLICATION_JSON, analysed_type_to_schema(&body_type)),
+                required: true,
+                ..Default::default()
+            }));
+        }
+    }
+
+    fn find_type<'a>(types: &'a [(String, AnalysedType)], name: &str) -> Option<&'a AnalysedType> {
+        types
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, typ)| typ)
+    }
+
+    fn parameter_data(name: &str, required: bool, schema: Schema) -> ParameterData {
+        ParameterData {
+            name: name.to_string(),
+            description: None,
+            required,
+            deprecated: None,
+            format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)),
+            example: None,
+            examples: Default::default(),
+            explode: None,
+            extensions: Default::default(),
+        }
+    }
+
+    fn json_content(content_type: &str, schema: Schema) -> Content {
+        vec![(
+            content_type.to_string(),
+            MediaType {
+                schema: Some(ReferenceOr::Item(schema)),
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect()
+    }
+
+    // The response mapping of a worker binding is expected to be a record
+    // with optional `status`, `headers` and `body` fields. If it's not a record of this shape,
+    // the whole result of the response mapping is the response body
+    fn get_worker_responses(
+        rib_output: Option<&RibOutputTypeInfo>,
+        content_type: &str,
+        cors: Option<&HttpCors>,
+    ) -> Responses {
+        let mut response = Response {
+            description: "Response of the worker binding".to_string(),
+            ..Default::default()
+        };
+
+        add_cors_response_headers(&mut response, cors);
+
+        let (has_status, body_schema) = match rib_output.map(|output| &output.analysed_type) {
+            Some(AnalysedType::Record(record))
+                if record
+                    .fields
+                    .iter()
+                    .any(|field| ["status", "headers", "body"].contains(&field.name.as_str())) =>
+            {
+                for field in record.fields.iter() {
+                    if field.name == "headers" {
+                        if let AnalysedType::Record(header_record) = &field.typ {
+                            for header in header_record.fields.iter() {
+                                response.headers.insert(
+                                    header.name.clone(),
+                                    ReferenceOr::Item(header_of(
+                                        analysed_type_to_schema(&header.typ),
+                                        true,
+                                    )),
+                                );
+                            }
+                        }
+                    }
+                }
+
+                let has_status = record.fields.iter().any(|field| field.name == "status");
+
+                let body_schema = record
+                    .fields
+                    .iter()
+                    .find(|field| field.name == "body")
+                    .map(|field| analysed_type_to_schema(&field.typ));
+
+                (has_status, body_schema)
+            }
+            Some(other) => (false, Some(analysed_type_to_schema(other))),
+            None => (false, Some(binary_schema())),
+        };
+
+        if let Some(schema) = body_schema {
+            response.content = json_content(content_type, schema);
+        }
+
+        let response = ReferenceOr::Item(response);
+
+        let mut responses = Responses::default();
+
+        // When the status code is computed by the response mapping, it is only known at runtime
+        if has_status {
+            responses.default = Some(response);
+        } else {
+            responses.responses.insert(StatusCode::Code(200), response);
+        }
+
+        responses.responses.insert(
+            StatusCode::Code(400),
+            ReferenceOr::Item(Response {
+                description: "Invalid request".to_string(),
+                ..Default::default()
+            }),
+        );
+
+        responses.responses.insert(
+            StatusCode::Code(500),
+            ReferenceOr::Item(Response {
+                description: "Failed to invoke the worker".to_string(),
+                ..Default::default()
+            }),
+        );
+
+        responses
+    }
+
+    fn header_of(schema: Schema, required: bool) -> Header {
+        Header {
+            description: None,
+            style: HeaderStyle::Simple,
+            required,
+            deprecated: None,
+            format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)),
+            example: None,
+            examples: Default::default(),
+            extensions: Default::default(),
+        }
+    }
+
+    fn string_header(value: Option<String>) -> ReferenceOr<Header> {
+        let mut header = header_of(string_schema(), true);
+        header.example = value.map(Value::String);
+        ReferenceOr::Item(header)
+    }
+
+    // Headers added to the response of a route by the CORS middleware
+    fn add_cors_response_headers(response: &mut Response, cors: Option<&HttpCors>) {
+        if let Some(cors) = cors {
+            response.headers.insert(
+                "Access-Control-Allow-Origin".to_string(),
+                string_header(Some(cors.get_allow_origin())),
+            );
+
+            if let Some(expose_headers) = cors.get_expose_headers() {
+                response.headers.insert(
+                    "Access-Control-Expose-Headers".to_string(),
+                    string_header(Some(expose_headers)),
+                );
+            }
+
+            if let Some(allow_credentials) = cors.get_allow_credentials() {
+                response.headers.insert(
+                    "Access-Control-Allow-Credentials".to_string(),
+                    string_header(Some(allow_credentials.to_string())),
+                );
+            }
+        }
+    }
+
+    fn add_cors_preflight_response_headers(response: &mut Response, cors: &HttpCors) {
+        add_cors_response_headers(response, Some(cors));
+
+        response.headers.insert(
+            "Access-Control-Allow-Methods".to_string(),
+            string_header(Some(cors.get_allow_methods())),
+        );
+        response.headers.insert(
+            "Access-Control-Allow-Headers".to_string(),
+            string_header(Some(cors.get_allow_headers())),
+        );
+
+        if let Some(max_age) = cors.get_max_age() {
+            response.headers.insert(
+                "Access-Control-Max-Age".to_string(),
+                string_header(Some(max_age.to_string())),
+            );
+        }
+    }
+
+    fn schema_of(schema_kind: SchemaKind) -> Schema {
+        Schema {
+            schema_data: SchemaData::default(),
+            schema_kind,
+        }
+    }
+
+    fn string_schema() -> Schema {
+        schema_of(SchemaKind::Type(Type::String(StringType::default())))
+    }
+
+    fn binary_schema() -> Schema {
+        schema_of(SchemaKind::Type(Type::String(StringType {
+            format: VariantOrUnknownOrEmpty::Item(StringFormat::Binary),
+            ..Default::default()
+        })))
+    }
+
+    fn integer_schema(
+        format: Option<IntegerFormat>,
+        minimum: Option<i64>,
+        maximum: Option<i64>,
+    ) -> Schema {
+        schema_of(SchemaKind::Type(Type::Integer(IntegerType {
+            format: format
+                .map(VariantOrUnknownOrEmpty::Item)
+                .unwrap_or(VariantOrUnknownOrEmpty::Empty),
+            minimum,
+            maximum,
+            ..Default::default()
+        })))
+    }
+
+    fn object_schema(fields: Vec<(String, Schema)>, required: Vec<String>) -> Schema {
+        schema_of(SchemaKind::Type(Type::Object(ObjectType {
+            properties: fields
+                .into_iter()
+                .map(|(name, schema)| (name, ReferenceOr::Item(Box::new(schema))))
+                .collect(),
+            required,
+            additional_properties: Some(AdditionalProperties::Any(false)),
+            ..Default::default()
+        })))
+    }
+
+    fn array_schema(items: Schema, length: Option<usize>) -> Schema {
+        schema_of(SchemaKind::Type(Type::Array(ArrayType {
+            items: Some(ReferenceOr::Item(Box::new(items))),
+            min_items: length,
+            max_items: length,
+            unique_items: false,
+        })))
+    }
+
+    // The schema follows the JSON representation of WIT values
+    // accepted by the gateway (See `ValueAndType::parse_with_type`)
+    pub(super) fn analysed_type_to_schema(analysed_type: &AnalysedType) -> Schema {
+        let mut schema = match analysed_type {
+            AnalysedType::Bool(_) => {
+                schema_of(SchemaKind::Type(Type::Boolean(BooleanType::default())))
+            }
+            AnalysedType::S8(_) => integer_schema(
+                Some(IntegerFormat::Int32),
+                Some(i8::MIN as i64),
+                Some(i8::MAX as i64),
+            ),
+            AnalysedType::U8(_) => {
+                integer_schema(Some(IntegerFormat::Int32), Some(0), Some(u8::MAX as i64))
+            }
+            AnalysedType::S16(_) => integer_schema(
+                Some(IntegerFormat::Int32),
+                Some(i16::MIN as i64),
+                Some(i16::MAX as i64),
+            ),
+            AnalysedType::U16(_) => {
+                integer_schema(Some(IntegerFormat::Int32), Some(0), Some(u16::MAX as i64))
+            }
+            AnalysedType::S32(_) => integer_schema(Some(IntegerFormat::Int32), None, None),
+            AnalysedType::U32(_) => {
+                integer_schema(Some(IntegerFormat::Int64), Some(0), Some(u32::MAX as i64))
+            }
+            AnalysedType::S64(_) => integer_schema(Some(IntegerFormat::Int64), None, None),
+            AnalysedType::U64(_) => integer_schema(Some(IntegerFormat::Int64), Some(0), None),
+            AnalysedType::F32(_) => schema_of(SchemaKind::Type(Type::Number(NumberType {
+                format: VariantOrUnknownOrEmpty::Item(NumberFormat::Float),
+                ..Default::default()
+            }))),
+            AnalysedType::F64(_) => schema_of(SchemaKind::Type(Type::Number(NumberType {
+                format: VariantOrUnknownOrEmpty::Item(NumberFormat::Double),
+                ..Default::default()
+            }))),
+            AnalysedType::Chr(_) => schema_of(SchemaKind::Type(Type::String(StringType {
+                min_length: Some(1),
+                max_length: Some(1),
+                ..Default::default()
+            }))),
+            AnalysedType::Str(_) => string_schema(),
+            AnalysedType::List(list) => array_schema(analysed_type_to_schema(&list.inner), None),
+            AnalysedType::Tuple(tuple) => {
+                let items = tuple
+                    .items
+                    .iter()
+                    .map(|item| ReferenceOr::Item(analysed_type_to_schema(item)))
+                    .collect::<Vec<_>>();
+
+                let mut schema = array_schema(
+                    schema_of(SchemaKind::AnyOf { any_of: items }),
+                    Some(tuple.items.len()),
+                );
+
+                schema.schema_data.description = Some("tuple".to_string());
+                schema
+            }
+            AnalysedType::Record(record) => object_schema(
+                record
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.clone(), analysed_type_to_schema(&field.typ)))
+                    .collect(),
+                record
+                    .fields
+                    .iter()
+                    .filter(|field| !matches!(field.typ, AnalysedType::Option(_)))
+                    .map(|field| field.name.clone())
+                    .collect(),
+            ),
+            AnalysedType::Option(option) => {
+                let mut schema = analysed_type_to_schema(&option.inner);
+                schema.schema_data.nullable = true;
+                schema
+            }
+            AnalysedType::Enum(enum_type) => {
+                schema_of(SchemaKind::Type(Type::String(StringType {
+                    enumeration: enum_type.cases.iter().cloned().map(Some).collect(),
+                    ..Default::default()
+                })))
+            }
+            AnalysedType::Flags(flags) => {
+                let flag = schema_of(SchemaKind::Type(Type::String(StringType {
+                    enumeration: flags.names.iter().cloned().map(Some).collect(),
+                    ..Default::default()
+                })));
+
+                schema_of(SchemaKind::Type(Type::Array(ArrayType {
+                    items: Some(ReferenceOr::Item(Box::new(flag))),
+                    min_items: None,
+                    max_items: None,
+                    unique_items: true,
+                })))
+            }
+            AnalysedType::Variant(variant) => {
+                let cases = variant
+                    .cases
+                    .iter()
+                    .map(|case| match &case.typ {
+                        Some(typ) => ReferenceOr::Item(object_schema(
+                            vec![(case.name.clone(), analysed_type_to_schema(typ))],
+                            vec![case.name.clone()],
+                        )),
+                        None => ReferenceOr::Item(schema_of(SchemaKind::Type(Type::String(
+                            StringType {
+                                enumeration: vec![Some(case.name.clone())],
+                                ..Default::default()
+                            },
+                        )))),
+                    })
+                    .collect();
+
+                schema_of(SchemaKind::OneOf { one_of: cases })
+            }
+            AnalysedType::Result(result) => {
+                let case = |name: &str, typ: &Option<Box<AnalysedType>>| {
+                    let inner = typ
+                        .as_ref()
+                        .map(|typ| analysed_type_to_schema(typ))
+                        .unwrap_or_else(|| {
+                            let mut schema = schema_of(SchemaKind::Any(Default::default()));
+                            schema.schema_data.nullable = true;
+                            schema
+                        });
+
+                    ReferenceOr::Item(object_schema(
+                        vec![(name.to_string(), inner)],
+                        vec![name.to_string()],
+                    ))
+                };
+
+                schema_of(SchemaKind::OneOf {
+                    one_of: vec![case("ok", &result.ok), case("err", &result.err)],
+                })
+            }
+            AnalysedType::Handle(_) => {
+                let mut schema = string_schema();
+                schema.schema_data.description = Some("resource handle".to_string());
+                schema
+            }
+        };
+
+        if let Some(name) = analysed_type.name() {
+            schema.schema_data.title = Some(name.to_string());
+        }
+
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::internal::*;
+    use crate::gateway_api_definition::http::AllPathPatterns;
+    use golem_wasm_ast::analysis::analysed_type::{
+        case, field, list, option, record, str, u32, u64, unit_case, variant,
+    };
+    use openapiv3::{SchemaKind, Type};
+    use test_r::test;
+
+    #[test]
+    fn open_api_path_from_path_pattern() {
+        let path = AllPathPatterns::parse("/foo/{user-id}/bar/{+rest}?{q}").unwrap();
+        assert_eq!(get_open_api_path(&path), "/foo/{user-id}/bar/{rest}");
+    }
+
+    #[test]
+    fn open_api_path_of_root() {
+        let path = AllPathPatterns::parse("/").unwrap();
+        assert_eq!(get_open_api_path(&path), "/");
+    }
+
+    #[test]
+    fn schema_of_record() {
+        let typ = record(vec![
+            field("name", str()),
+            field("age", u32()),
+            field("email", option(str())),
+            field("tags", list(str())),
+        ]);
+
+        let schema = analysed_type_to_schema(&typ);
+
+        match schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) => {
+                assert_eq!(
+                    object.properties.keys().cloned().collect::<Vec<_>>(),
+                    vec!["name", "age", "email", "tags"]
+                );
+                assert_eq!(object.required, vec!["name", "age", "tags"]);
+            }
+            other => panic!("Unexpected schema {other:?}"),
+        }
+    }
+
+    #[test]
+    fn schema_of_variant() {
+        let typ = variant(vec![case("found", u64()), unit_case("not-found")]);
+
+        let schema = analysed_type_to_schema(&typ);
+
+        match schema.schema_kind {
+            SchemaKind::OneOf { one_of } => assert_eq!(one_of.len(), 2),
+            other => panic!("Unexpected schema {other:?}"),
+        }
+    }
+
+    #[test]
+    fn schema_of_option_is_nullable() {
+        let schema = analysed_type_to_schema(&option(u64()));
+        assert!(schema.schema_data.nullable);
+    }
+}
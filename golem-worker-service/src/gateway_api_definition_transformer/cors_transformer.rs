@@ -141,6 +141,7 @@ mod tests {
             idempotency_key: None,
             response_mapping: ResponseMapping(Expr::literal("")),
             invocation_context: None,
+            response_cache_policy: None,
         };
 
         Route {
@@ -160,6 +161,7 @@ mod tests {
             idempotency_key: None,
             response_mapping: ResponseMapping(Expr::literal("")),
             invocation_context: None,
+            response_cache_policy: None,
         };
 
         Route {
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod traffic_split;
+
+pub use traffic_split::*;
+
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use golem_common::model::auth::Namespace;
 use poem_openapi::{NewType, Object};
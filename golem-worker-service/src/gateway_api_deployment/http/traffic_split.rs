@@ -0,0 +1,121 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
+use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
+use crate::gateway_rib_compiler::DefaultWorkerServiceRibCompiler;
+use crate::gateway_rib_compiler::WorkerServiceRibCompiler;
+use golem_wasm_ast::analysis::AnalysedType;
+use rib::{Expr, RibByteCode, RibInputTypeInfo};
+use std::fmt::Display;
+
+// A deployment level policy routing a share of the requests of a site, that would be served by the
+// deployed (stable) version of an API definition, to a newer (canary) version of the same definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrafficSplit {
+    pub api_definition_id: ApiDefinitionId,
+    pub canary_version: ApiVersion,
+    // Percentage of the requests (0 to 100) routed to the canary version
+    pub percentage: u8,
+    // Requests having this header or cookie value are always routed to the canary version
+    pub header: Option<TrafficSplitMatch>,
+    pub cookie: Option<TrafficSplitMatch>,
+    // A Rib expression over the request that evaluates to the worker name, such that
+    // every request to a worker is routed to the same version. Defaults to the name of
+    // the worker invoked by the binding of the route
+    pub sticky_key: Option<Expr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrafficSplitMatch {
+    pub name: String,
+    pub value: String,
+}
+
+// A traffic split along with the canary version of the API definition,
+// as required by the gateway to route the requests of a site
+#[derive(Debug, Clone)]
+pub struct CompiledTrafficSplit {
+    pub api_definition_id: ApiDefinitionId,
+    pub canary_definition: CompiledHttpApiDefinition,
+    pub percentage: u8,
+    pub header: Option<TrafficSplitMatch>,
+    pub cookie: Option<TrafficSplitMatch>,
+    pub sticky_key_compiled: Option<StickyKeyCompiled>,
+}
+
+impl CompiledTrafficSplit {
+    pub fn new(
+        traffic_split: TrafficSplit,
+        canary_definition: CompiledHttpApiDefinition,
+    ) -> Result<Self, String> {
+        let sticky_key_compiled = traffic_split
+            .sticky_key
+            .as_ref()
+            .map(StickyKeyCompiled::from_sticky_key)
+            .transpose()?;
+
+        Ok(CompiledTrafficSplit {
+            api_definition_id: traffic_split.api_definition_id,
+            canary_definition,
+            percentage: traffic_split.percentage,
+            header: traffic_split.header,
+            cookie: traffic_split.cookie,
+            sticky_key_compiled,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StickyKeyCompiled {
+    pub sticky_key: Expr,
+    pub compiled_sticky_key: RibByteCode,
+    pub rib_input: RibInputTypeInfo,
+}
+
+impl StickyKeyCompiled {
+    // The variant is selected before the route is resolved against the selected version,
+    // and hence the sticky key cannot depend on the request body or on component exports
+    pub fn from_sticky_key(sticky_key: &Expr) -> Result<Self, String> {
+        let compiled = DefaultWorkerServiceRibCompiler::compile(sticky_key, &[])
+            .map_err(|err| format!("Invalid sticky key: {err}"))?;
+
+        if let Some(AnalysedType::Record(request)) = compiled.rib_input_type_info.get("request") {
+            if request.fields.iter().any(|field| field.name == "body") {
+                return Err("Sticky key cannot refer to the request body".to_string());
+            }
+        }
+
+        Ok(StickyKeyCompiled {
+            sticky_key: sticky_key.clone(),
+            compiled_sticky_key: compiled.byte_code,
+            rib_input: compiled.rib_input_type_info,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrafficSplitVariant {
+    Stable,
+    Canary,
+}
+
+impl Display for TrafficSplitVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrafficSplitVariant::Stable => write!(f, "stable"),
+            TrafficSplitVariant::Canary => write!(f, "canary"),
+        }
+    }
+}
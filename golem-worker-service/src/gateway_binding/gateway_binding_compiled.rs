@@ -14,7 +14,8 @@
 
 use crate::gateway_binding::{
     FileServerBinding, FileServerBindingCompiled, GatewayBinding, IdempotencyKeyCompiled,
-    ResponseMappingCompiled, WorkerBinding, WorkerBindingCompiled, WorkerNameCompiled,
+    ResponseCachePolicyCompiled, ResponseMappingCompiled, WorkerBinding, WorkerBindingCompiled,
+    WorkerNameCompiled,
 };
 use crate::gateway_binding::{InvocationContextCompiled, StaticBinding};
 use golem_api_grpc::proto::golem::apidefinition::GatewayBindingType as ProtoGatewayBindingType;
@@ -135,6 +136,8 @@ impl TryFrom<GatewayBindingCompiled>
                         invocation_context: None,
                         compiled_invocation_context_expr: None,
                         invocation_context_rib_input: None,
+                        response_cache_policy: None,
+                        response_rib_warnings: vec![],
                     },
                 )
             }
@@ -240,6 +243,7 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
                         .response_rib_output
                         .map(RibOutputTypeInfo::try_from)
                         .transpose()?,
+                    rib_warnings: value.response_rib_warnings,
                 };
 
                 let binding_type = value
@@ -247,12 +251,18 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
                     .unwrap_or(ProtoGatewayBindingType::Default.into());
 
                 if binding_type == 0 {
+                    let response_cache_policy_compiled = value
+                        .response_cache_policy
+                        .map(ResponseCachePolicyCompiled::try_from)
+                        .transpose()?;
+
                     Ok(GatewayBindingCompiled::Worker(Box::new(
                         WorkerBindingCompiled {
                             component_id,
                             idempotency_key_compiled,
                             response_compiled,
                             invocation_context_compiled,
+                            response_cache_policy_compiled,
                         },
                     )))
                 } else {
@@ -395,6 +405,8 @@ mod internal {
             .worker_calls
             .map(|x| x.into());
 
+        let response_rib_warnings = worker_binding.response_compiled.rib_warnings;
+
         let binding_type = match binding_type {
             GatewayBindingType::Default => 0,
             GatewayBindingType::FileServer => 1,
@@ -421,6 +433,8 @@ mod internal {
                 invocation_context,
                 compiled_invocation_context_expr,
                 invocation_context_rib_input,
+                response_cache_policy: None,
+                response_rib_warnings,
             },
         )
     }
@@ -473,6 +487,13 @@ mod internal {
             .worker_calls
             .map(|x| x.into());
 
+        let response_rib_warnings = worker_binding.response_compiled.rib_warnings;
+
+        let response_cache_policy = worker_binding
+            .response_cache_policy_compiled
+            .map(|x| x.try_into())
+            .transpose()?;
+
         let binding_type = match binding_type {
             GatewayBindingType::Default => 0,
             GatewayBindingType::FileServer => 1,
@@ -499,6 +520,8 @@ mod internal {
                 invocation_context,
                 compiled_invocation_context_expr,
                 invocation_context_rib_input,
+                response_cache_policy,
+                response_rib_warnings,
             },
         )
     }
@@ -555,6 +578,8 @@ mod internal {
                 invocation_context: None,
                 compiled_invocation_context_expr: None,
                 invocation_context_rib_input: None,
+                response_cache_policy: None,
+                response_rib_warnings: vec![],
             },
         )
     }
@@ -14,10 +14,12 @@
 
 mod gateway_binding_compiled;
 mod http_handler_binding;
+mod response_cache_policy;
 mod static_binding;
 mod worker_binding;
 
 pub(crate) use self::http_handler_binding::*;
+pub(crate) use self::response_cache_policy::*;
 pub(crate) use self::worker_binding::*;
 pub(crate) use crate::gateway_execution::gateway_binding_resolver::*;
 use crate::gateway_rib_compiler::DefaultWorkerServiceRibCompiler;
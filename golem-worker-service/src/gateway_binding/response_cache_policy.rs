@@ -0,0 +1,203 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_rib_compiler::DefaultWorkerServiceRibCompiler;
+use crate::gateway_rib_compiler::WorkerServiceRibCompiler;
+use rib::{Expr, RibByteCode, RibCompilationError, RibInputTypeInfo};
+use std::time::Duration;
+
+// A per-route policy for caching successful responses of a worker binding in the gateway.
+// The cache key is a pure Rib expression over the request (path, query and headers),
+// and if it is missing, the request path along with its query string is used as the key.
+// Responses are further partitioned by the values of the `vary` request headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseCachePolicy {
+    pub ttl: Duration,
+    pub cache_key: Option<Expr>,
+    pub vary: Vec<String>,
+}
+
+impl ResponseCachePolicy {
+    pub fn new(ttl: Duration, cache_key: Option<Expr>, vary: Vec<String>) -> Self {
+        ResponseCachePolicy {
+            ttl,
+            cache_key,
+            vary: vary
+                .into_iter()
+                .map(|header| header.trim().to_lowercase())
+                .filter(|header| !header.is_empty())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseCachePolicyCompiled {
+    pub ttl: Duration,
+    pub cache_key_compiled: Option<CacheKeyCompiled>,
+    pub vary: Vec<String>,
+}
+
+impl ResponseCachePolicyCompiled {
+    pub fn from_response_cache_policy(
+        response_cache_policy: &ResponseCachePolicy,
+    ) -> Result<Self, RibCompilationError> {
+        let cache_key_compiled = response_cache_policy
+            .cache_key
+            .as_ref()
+            .map(CacheKeyCompiled::from_cache_key)
+            .transpose()?;
+
+        Ok(ResponseCachePolicyCompiled {
+            ttl: response_cache_policy.ttl,
+            cache_key_compiled,
+            vary: response_cache_policy.vary.clone(),
+        })
+    }
+}
+
+impl From<ResponseCachePolicyCompiled> for ResponseCachePolicy {
+    fn from(value: ResponseCachePolicyCompiled) -> Self {
+        ResponseCachePolicy {
+            ttl: value.ttl,
+            cache_key: value.cache_key_compiled.map(|compiled| compiled.cache_key),
+            vary: value.vary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheKeyCompiled {
+    pub cache_key: Expr,
+    pub compiled_cache_key: RibByteCode,
+    pub rib_input: RibInputTypeInfo,
+}
+
+impl CacheKeyCompiled {
+    // Cache keys are computed before any worker is invoked,
+    // and hence they cannot depend on component exports
+    pub fn from_cache_key(cache_key: &Expr) -> Result<Self, RibCompilationError> {
+        let cache_key_compiled = DefaultWorkerServiceRibCompiler::compile(cache_key, &[])?;
+
+        Ok(CacheKeyCompiled {
+            cache_key: cache_key.clone(),
+            compiled_cache_key: cache_key_compiled.byte_code,
+            rib_input: cache_key_compiled.rib_input_type_info,
+        })
+    }
+}
+
+impl TryFrom<ResponseCachePolicyCompiled>
+    for golem_api_grpc::proto::golem::apidefinition::ResponseCachePolicy
+{
+    type Error = String;
+
+    fn try_from(value: ResponseCachePolicyCompiled) -> Result<Self, Self::Error> {
+        let (cache_key, compiled_cache_key_expr, cache_key_rib_input) =
+            match value.cache_key_compiled {
+                Some(x) => (
+                    Some(x.cache_key.into()),
+                    Some(x.compiled_cache_key.try_into()?),
+                    Some(x.rib_input.into()),
+                ),
+                None => (None, None, None),
+            };
+
+        Ok(
+            golem_api_grpc::proto::golem::apidefinition::ResponseCachePolicy {
+                ttl_seconds: value.ttl.as_secs(),
+                vary: value.vary,
+                cache_key,
+                compiled_cache_key_expr,
+                cache_key_rib_input,
+            },
+        )
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::ResponseCachePolicy>
+    for ResponseCachePolicyCompiled
+{
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::ResponseCachePolicy,
+    ) -> Result<Self, Self::Error> {
+        let cache_key_compiled = match (
+            value.cache_key,
+            value.compiled_cache_key_expr,
+            value.cache_key_rib_input,
+        ) {
+            (Some(cache_key), Some(compiled_cache_key), Some(rib_input)) => {
+                Some(CacheKeyCompiled {
+                    cache_key: Expr::try_from(cache_key)?,
+                    compiled_cache_key: RibByteCode::try_from(compiled_cache_key)?,
+                    rib_input: RibInputTypeInfo::try_from(rib_input)?,
+                })
+            }
+            _ => None,
+        };
+
+        Ok(ResponseCachePolicyCompiled {
+            ttl: Duration::from_secs(value.ttl_seconds),
+            cache_key_compiled,
+            vary: value.vary,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_r::test;
+
+    #[test]
+    fn vary_headers_are_normalised() {
+        let policy = ResponseCachePolicy::new(
+            Duration::from_secs(10),
+            None,
+            vec![
+                " Accept-Language".to_string(),
+                "".to_string(),
+                "X-Tenant ".to_string(),
+            ],
+        );
+
+        assert_eq!(
+            policy.vary,
+            vec!["accept-language".to_string(), "x-tenant".to_string()]
+        );
+    }
+
+    #[test]
+    fn response_cache_policy_proto_roundtrip() {
+        let cache_key = rib::from_string(r#""${request.path.user-id}""#).unwrap();
+
+        let policy = ResponseCachePolicy::new(
+            Duration::from_secs(30),
+            Some(cache_key),
+            vec!["accept".to_string()],
+        );
+
+        let compiled = ResponseCachePolicyCompiled::from_response_cache_policy(&policy).unwrap();
+
+        let proto: golem_api_grpc::proto::golem::apidefinition::ResponseCachePolicy =
+            compiled.clone().try_into().unwrap();
+
+        let decoded = ResponseCachePolicyCompiled::try_from(proto).unwrap();
+
+        assert_eq!(decoded, compiled);
+        assert_eq!(ResponseCachePolicy::from(decoded), policy);
+    }
+}
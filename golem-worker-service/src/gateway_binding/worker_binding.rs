@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{IdempotencyKeyCompiled, InvocationContextCompiled, WorkerNameCompiled};
+use super::{
+    IdempotencyKeyCompiled, InvocationContextCompiled, ResponseCachePolicy,
+    ResponseCachePolicyCompiled, WorkerNameCompiled,
+};
+use crate::gateway_execution::request::RichRequest;
+use crate::gateway_execution::request_validation::{validate_request, RequestValidationError};
 use crate::gateway_rib_compiler::DefaultWorkerServiceRibCompiler;
 use crate::gateway_rib_compiler::WorkerServiceRibCompiler;
 use golem_common::model::component::VersionedComponentId;
@@ -107,6 +112,7 @@ pub struct WorkerBinding {
     pub idempotency_key: Option<Expr>,
     pub response_mapping: ResponseMapping,
     pub invocation_context: Option<Expr>,
+    pub response_cache_policy: Option<ResponseCachePolicy>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -115,6 +121,7 @@ pub struct WorkerBindingCompiled {
     pub idempotency_key_compiled: Option<IdempotencyKeyCompiled>,
     pub response_compiled: ResponseMappingCompiled,
     pub invocation_context_compiled: Option<InvocationContextCompiled>,
+    pub response_cache_policy_compiled: Option<ResponseCachePolicyCompiled>,
 }
 
 impl WorkerBindingCompiled {
@@ -139,14 +146,47 @@ impl WorkerBindingCompiled {
             )?),
             None => None,
         };
+        let response_cache_policy_compiled = gateway_worker_binding
+            .response_cache_policy
+            .as_ref()
+            .map(ResponseCachePolicyCompiled::from_response_cache_policy)
+            .transpose()?;
 
         Ok(WorkerBindingCompiled {
             component_id: gateway_worker_binding.component_id.clone(),
             idempotency_key_compiled,
             response_compiled,
             invocation_context_compiled,
+            response_cache_policy_compiled,
         })
     }
+
+    // Validates the parts of the request used by any of the Rib scripts of the binding,
+    // such that a malformed request is rejected before invoking the worker
+    pub async fn validate_request(
+        &self,
+        request: &mut RichRequest,
+    ) -> Result<(), RequestValidationError> {
+        let mut rib_inputs = vec![&self.response_compiled.rib_input];
+
+        if let Some(idempotency_key_compiled) = &self.idempotency_key_compiled {
+            rib_inputs.push(&idempotency_key_compiled.rib_input);
+        }
+
+        if let Some(invocation_context_compiled) = &self.invocation_context_compiled {
+            rib_inputs.push(&invocation_context_compiled.rib_input);
+        }
+
+        if let Some(cache_key_compiled) = self
+            .response_cache_policy_compiled
+            .as_ref()
+            .and_then(|policy| policy.cache_key_compiled.as_ref())
+        {
+            rib_inputs.push(&cache_key_compiled.rib_input);
+        }
+
+        validate_request(request, &rib_inputs).await
+    }
 }
 
 impl From<WorkerBindingCompiled> for WorkerBinding {
@@ -164,6 +204,9 @@ impl From<WorkerBindingCompiled> for WorkerBinding {
             invocation_context: worker_binding
                 .invocation_context_compiled
                 .map(|compiled| compiled.invocation_context),
+            response_cache_policy: worker_binding
+                .response_cache_policy_compiled
+                .map(ResponseCachePolicy::from),
         }
     }
 }
@@ -180,6 +223,8 @@ pub struct ResponseMappingCompiled {
     pub worker_calls: Option<WorkerFunctionsInRib>,
     // Optional to keep backward compatibility
     pub rib_output: Option<RibOutputTypeInfo>,
+    // Warnings of the lint pass, kept to be reported when the API definition is deployed
+    pub rib_warnings: Vec<String>,
 }
 
 impl ResponseMappingCompiled {
@@ -196,6 +241,11 @@ impl ResponseMappingCompiled {
             rib_input: response_compiled.rib_input_type_info,
             worker_calls: response_compiled.worker_invoke_calls,
             rib_output: response_compiled.rib_output_type_info,
+            rib_warnings: response_compiled
+                .warnings
+                .iter()
+                .map(|warning| warning.to_string())
+                .collect(),
         })
     }
 }
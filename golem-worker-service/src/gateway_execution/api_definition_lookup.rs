@@ -13,12 +13,15 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
-use crate::gateway_api_deployment::ApiSiteString;
+use crate::gateway_api_deployment::{ApiSiteString, CompiledTrafficSplit};
 use crate::service::gateway::api_deployment::{ApiDeploymentError, ApiDeploymentService};
 use async_trait::async_trait;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
 use golem_common::SafeDisplay;
+use rib::RibInterpreterLimits;
 use tracing::error;
 
 // To lookup the set of API Definitions based on an incoming input.
@@ -31,6 +34,22 @@ pub trait HttpApiDefinitionsLookup: Send + Sync {
         &self,
         host: &ApiSiteString,
     ) -> Result<Vec<CompiledHttpApiDefinition>, ApiDefinitionLookupError>;
+
+    // The traffic splits routing a share of the requests of a site to canary versions
+    async fn get_traffic_splits(
+        &self,
+        _host: &ApiSiteString,
+    ) -> Result<Vec<CompiledTrafficSplit>, ApiDefinitionLookupError> {
+        Ok(vec![])
+    }
+
+    // The limits on the resources used by the Rib scripts serving the requests of a site
+    async fn get_rib_limits(
+        &self,
+        _host: &ApiSiteString,
+    ) -> Result<RibInterpreterLimits, ApiDefinitionLookupError> {
+        Ok(RibInterpreterLimits::default())
+    }
 }
 
 pub enum ApiDefinitionLookupError {
@@ -47,13 +66,66 @@ impl SafeDisplay for ApiDefinitionLookupError {
     }
 }
 
+// Caches the traffic splits and the Rib limits of the sites, which are looked up for every request
+// to the API gateway. The API deployment service invalidates the entries of a site when its
+// deployment changes, while the expiry of the entries picks up the changes made through
+// other instances of the service.
+#[derive(Clone)]
+pub struct ApiSiteLookupCache {
+    traffic_splits: Cache<ApiSiteString, (), Vec<CompiledTrafficSplit>, ApiDeploymentError>,
+    rib_limits: Cache<ApiSiteString, (), RibInterpreterLimits, ApiDeploymentError>,
+}
+
+impl ApiSiteLookupCache {
+    pub fn new() -> Self {
+        Self {
+            traffic_splits: Cache::new(
+                Some(1024),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::OlderThan {
+                    ttl: Duration::from_secs(30),
+                    period: Duration::from_secs(5),
+                },
+                "api_site_traffic_splits",
+            ),
+            rib_limits: Cache::new(
+                Some(1024),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::OlderThan {
+                    ttl: Duration::from_secs(30),
+                    period: Duration::from_secs(5),
+                },
+                "api_site_rib_limits",
+            ),
+        }
+    }
+
+    pub fn invalidate(&self, site: &ApiSiteString) {
+        self.traffic_splits.remove(site);
+        self.rib_limits.remove(site);
+    }
+}
+
+impl Default for ApiSiteLookupCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub struct DefaultHttpApiDefinitionLookup {
     deployment_service: Arc<dyn ApiDeploymentService>,
+    cache: ApiSiteLookupCache,
 }
 
 impl DefaultHttpApiDefinitionLookup {
-    pub fn new(deployment_service: Arc<dyn ApiDeploymentService>) -> Self {
-        Self { deployment_service }
+    pub fn new(
+        deployment_service: Arc<dyn ApiDeploymentService>,
+        cache: ApiSiteLookupCache,
+    ) -> Self {
+        Self {
+            deployment_service,
+            cache,
+        }
     }
 }
 
@@ -79,4 +151,40 @@ impl HttpApiDefinitionsLookup for DefaultHttpApiDefinitionLookup {
 
         Ok(http_api_defs)
     }
+
+    async fn get_traffic_splits(
+        &self,
+        host: &ApiSiteString,
+    ) -> Result<Vec<CompiledTrafficSplit>, ApiDefinitionLookupError> {
+        self.cache
+            .traffic_splits
+            .get_or_insert_simple(host, async || {
+                self.deployment_service
+                    .get_all_traffic_splits_by_site(host)
+                    .await
+            })
+            .await
+            .map_err(|err| {
+                error!("Failed to lookup traffic splits: {}", err);
+                ApiDefinitionLookupError::ApiDeploymentError(err)
+            })
+    }
+
+    async fn get_rib_limits(
+        &self,
+        host: &ApiSiteString,
+    ) -> Result<RibInterpreterLimits, ApiDefinitionLookupError> {
+        self.cache
+            .rib_limits
+            .get_or_insert_simple(host, async || {
+                self.deployment_service
+                    .get_all_rib_limits_by_site(host)
+                    .await
+            })
+            .await
+            .map_err(|err| {
+                error!("Failed to lookup Rib limits: {}", err);
+                ApiDefinitionLookupError::ApiDeploymentError(err)
+            })
+    }
 }
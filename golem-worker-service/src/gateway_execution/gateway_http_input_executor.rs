@@ -20,21 +20,34 @@ use super::request::{
 };
 use super::to_response::GatewayHttpResult;
 use super::WorkerDetails;
-use crate::gateway_api_deployment::ApiSiteString;
+use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
+use crate::gateway_api_deployment::{
+    ApiSiteString, CompiledTrafficSplit, StickyKeyCompiled, TrafficSplitVariant,
+};
 use crate::gateway_binding::{
-    resolve_gateway_binding, FileServerBindingCompiled, GatewayBindingCompiled,
+    resolve_gateway_binding, CacheKeyCompiled, FileServerBindingCompiled, GatewayBindingCompiled,
     HttpHandlerBindingCompiled, IdempotencyKeyCompiled, InvocationContextCompiled,
-    ResponseMappingCompiled, StaticBinding, WorkerBindingCompiled, WorkerNameCompiled,
+    ResponseCachePolicyCompiled, ResponseMappingCompiled, StaticBinding, WorkerBindingCompiled,
+    WorkerNameCompiled,
 };
 use crate::gateway_execution::api_definition_lookup::{
     ApiDefinitionLookupError, HttpApiDefinitionsLookup,
 };
 use crate::gateway_execution::auth_call_back_binding_handler::AuthCallBackBindingHandler;
 use crate::gateway_execution::file_server_binding_handler::FileServerBindingHandler;
+use crate::gateway_execution::gateway_response_cache::{
+    principal_of, CachedResponse, GatewayResponseCacheStore, ResponseCacheKey,
+};
 use crate::gateway_execution::gateway_session::GatewaySessionStore;
 use crate::gateway_execution::to_response::{GatewayHttpError, ToHttpResponse};
 use crate::gateway_execution::to_response_failure::ToHttpResponseFromSafeDisplay;
-use crate::gateway_middleware::{HttpMiddlewares, MiddlewareError, MiddlewareSuccess};
+use crate::gateway_execution::traffic_split::{
+    invoked_worker_name, is_canary_requested, select_variant, TrafficSplitDecision,
+};
+use crate::gateway_middleware::{
+    response_rib_input, HttpMiddlewares, MiddlewareError, MiddlewareSuccess, RequestTransformation,
+    ResponseTransformation, TransformCompiled,
+};
 use crate::gateway_rib_interpreter::WorkerServiceRibInterpreter;
 use crate::gateway_security::{IdentityProvider, SecuritySchemeWithProviderMetadata};
 use crate::http_invocation_context::{extract_request_attributes, invocation_context_from_request};
@@ -52,13 +65,14 @@ use golem_wasm_ast::analysis::analysed_type::record;
 use golem_wasm_ast::analysis::{AnalysedType, NameTypePair};
 use golem_wasm_rpc::json::ValueAndTypeJsonExtensions;
 use golem_wasm_rpc::{IntoValue, IntoValueAndType, ValueAndType};
-use http::StatusCode;
+use http::header::{CACHE_CONTROL, SET_COOKIE};
+use http::{Method, StatusCode};
 use poem::Body;
-use rib::{RibInput, RibInputTypeInfo, RibResult, TypeName};
+use rib::{RibInput, RibInputTypeInfo, RibInterpreterLimits, RibResult, TypeName};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use tracing::error;
+use tracing::{error, warn};
 
 #[async_trait]
 pub trait GatewayHttpInputExecutor: Send + Sync {
@@ -73,6 +87,7 @@ pub struct DefaultGatewayInputExecutor {
     pub api_definition_lookup_service: Arc<dyn HttpApiDefinitionsLookup>,
     pub gateway_session_store: GatewaySessionStore,
     pub identity_provider: Arc<dyn IdentityProvider>,
+    pub response_cache: GatewayResponseCacheStore,
 }
 
 impl DefaultGatewayInputExecutor {
@@ -84,6 +99,7 @@ impl DefaultGatewayInputExecutor {
         api_definition_lookup_service: Arc<dyn HttpApiDefinitionsLookup>,
         gateway_session_store: GatewaySessionStore,
         identity_provider: Arc<dyn IdentityProvider>,
+        response_cache: GatewayResponseCacheStore,
     ) -> Self {
         Self {
             evaluator,
@@ -93,6 +109,7 @@ impl DefaultGatewayInputExecutor {
             api_definition_lookup_service,
             gateway_session_store,
             identity_provider,
+            response_cache,
         }
     }
 
@@ -101,12 +118,14 @@ impl DefaultGatewayInputExecutor {
         namespace: Namespace,
         request: &mut RichRequest,
         binding: WorkerBindingCompiled,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<RibResult> {
         let WorkerBindingCompiled {
             response_compiled,
             component_id,
             idempotency_key_compiled,
             invocation_context_compiled,
+            ..
         } = binding;
 
         let worker_detail = self
@@ -116,11 +135,128 @@ impl DefaultGatewayInputExecutor {
                 idempotency_key_compiled,
                 component_id,
                 invocation_context_compiled,
+                rib_limits,
             )
             .await?;
 
-        self.execute_response_mapping_script(namespace, response_compiled, request, worker_detail)
+        self.execute_response_mapping_script(
+            namespace,
+            response_compiled,
+            request,
+            worker_detail,
+            rib_limits,
+        )
+        .await
+    }
+
+    // Serves the response from the cache if possible, otherwise invokes the worker
+    // and caches its response if it was successful.
+    async fn handle_worker_binding_with_response_cache(
+        &self,
+        site: ApiSiteString,
+        namespace: Namespace,
+        request: &mut RichRequest,
+        binding: WorkerBindingCompiled,
+        response_cache_policy: ResponseCachePolicyCompiled,
+        rib_limits: &RibInterpreterLimits,
+    ) -> poem::Response {
+        let cache_key = match self
+            .evaluate_response_cache_key(site, &response_cache_policy, request, rib_limits)
+            .await
+        {
+            Ok(cache_key) => cache_key,
+            Err(err) => return err.to_response(request, &self.gateway_session_store).await,
+        };
+
+        if !has_cache_control_directive(&request.underlying, "no-cache") {
+            match self.response_cache.get(&cache_key).await {
+                Ok(Some(cached_response)) => {
+                    return cached_response
+                        .to_response(request.underlying.headers(), &response_cache_policy.vary);
+                }
+                Ok(None) => {}
+                Err(err) => warn!("Failed to get cached response: {}", err.to_safe_string()),
+            }
+        }
+
+        let response = self
+            .handle_worker_binding(namespace, request, binding, rib_limits)
             .await
+            .to_response(request, &self.gateway_session_store)
+            .await;
+
+        // Responses setting cookies are specific to the client, and never cached
+        if !response.status().is_success() || response.headers().contains_key(SET_COOKIE) {
+            return response;
+        }
+
+        match CachedResponse::from_response(response, response_cache_policy.ttl).await {
+            Ok(cached_response) => {
+                let response = cached_response
+                    .to_response(request.underlying.headers(), &response_cache_policy.vary);
+
+                if let Err(err) = self.response_cache.insert(cache_key, cached_response).await {
+                    warn!("Failed to cache response: {}", err.to_safe_string());
+                }
+
+                response
+            }
+            Err(response) => response,
+        }
+    }
+
+    async fn evaluate_response_cache_key(
+        &self,
+        site: ApiSiteString,
+        response_cache_policy: &ResponseCachePolicyCompiled,
+        request: &mut RichRequest,
+        rib_limits: &RibInterpreterLimits,
+    ) -> GatewayHttpResult<ResponseCacheKey> {
+        let uri = request.underlying.uri().clone();
+
+        let cache_key = match &response_cache_policy.cache_key_compiled {
+            Some(CacheKeyCompiled {
+                compiled_cache_key,
+                rib_input,
+                ..
+            }) => {
+                let rib_input: RibInput = resolve_rib_input(request, rib_input).await?;
+
+                rib::interpret_pure_with_limits(
+                    compiled_cache_key.clone(),
+                    rib_input,
+                    None,
+                    rib_limits.clone(),
+                )
+                .await
+                .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
+                .get_literal()
+                .ok_or(GatewayHttpError::BadRequest(
+                    "Response cache key is not a Rib expression that resolves to a literal"
+                        .to_string(),
+                ))?
+                .as_string()
+            }
+            None => uri
+                .path_and_query()
+                .map(|path_and_query| path_and_query.to_string())
+                .unwrap_or_else(|| uri.path().to_string()),
+        };
+
+        let route = format!("{} {}", request.underlying.method(), uri.path());
+
+        // Responses of secured routes may depend on the caller, so they are only shared between
+        // requests of the same principal
+        let principal = principal_of(request.auth_data(), request.underlying.headers());
+
+        Ok(ResponseCacheKey::new(
+            site,
+            cache_key,
+            &route,
+            &response_cache_policy.vary,
+            request.underlying.headers(),
+            principal.as_deref(),
+        ))
     }
 
     async fn handle_http_handler_binding(
@@ -128,6 +264,7 @@ impl DefaultGatewayInputExecutor {
         namespace: &Namespace,
         request: &mut RichRequest,
         binding: HttpHandlerBindingCompiled,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<HttpHandlerBindingResult> {
         let HttpHandlerBindingCompiled {
             component_id,
@@ -143,6 +280,7 @@ impl DefaultGatewayInputExecutor {
                 idempotency_key_compiled,
                 component_id,
                 None,
+                rib_limits,
             )
             .await?;
 
@@ -169,6 +307,7 @@ impl DefaultGatewayInputExecutor {
         namespace: Namespace,
         request: &mut RichRequest,
         binding: FileServerBindingCompiled,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<FileServerBindingSuccess> {
         let FileServerBindingCompiled {
             component_id: version_component_id,
@@ -187,6 +326,7 @@ impl DefaultGatewayInputExecutor {
                 idempotency_key_compiled,
                 version_component_id,
                 None,
+                rib_limits,
             )
             .await?;
 
@@ -199,6 +339,7 @@ impl DefaultGatewayInputExecutor {
                 response_compiled,
                 request,
                 worker_detail,
+                rib_limits,
             )
             .await?;
 
@@ -233,6 +374,7 @@ impl DefaultGatewayInputExecutor {
         &self,
         script: WorkerNameCompiled,
         request: &mut RichRequest,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<String> {
         let WorkerNameCompiled {
             compiled_worker_name,
@@ -242,14 +384,19 @@ impl DefaultGatewayInputExecutor {
 
         let rib_input: RibInput = resolve_rib_input(request, &rib_input_type_info).await?;
 
-        let result = rib::interpret_pure(compiled_worker_name, rib_input, None)
-            .await
-            .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
-            .get_literal()
-            .ok_or(GatewayHttpError::BadRequest(
-                "Worker name is not a Rib expression that resolves to String".to_string(),
-            ))?
-            .as_string();
+        let result = rib::interpret_pure_with_limits(
+            compiled_worker_name,
+            rib_input,
+            None,
+            rib_limits.clone(),
+        )
+        .await
+        .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
+        .get_literal()
+        .ok_or(GatewayHttpError::BadRequest(
+            "Worker name is not a Rib expression that resolves to String".to_string(),
+        ))?
+        .as_string();
 
         Ok(result)
     }
@@ -258,6 +405,7 @@ impl DefaultGatewayInputExecutor {
         &self,
         script: IdempotencyKeyCompiled,
         request: &mut RichRequest,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<IdempotencyKey> {
         let IdempotencyKeyCompiled {
             compiled_idempotency_key,
@@ -267,14 +415,19 @@ impl DefaultGatewayInputExecutor {
 
         let rib_input: RibInput = resolve_rib_input(request, &rib_input).await?;
 
-        let value = rib::interpret_pure(compiled_idempotency_key, rib_input, None)
-            .await
-            .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
-            .get_literal()
-            .ok_or(GatewayHttpError::BadRequest(
-                "Idempotency key is not a Rib expression that resolves to String".to_string(),
-            ))?
-            .as_string();
+        let value = rib::interpret_pure_with_limits(
+            compiled_idempotency_key,
+            rib_input,
+            None,
+            rib_limits.clone(),
+        )
+        .await
+        .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
+        .get_literal()
+        .ok_or(GatewayHttpError::BadRequest(
+            "Idempotency key is not a Rib expression that resolves to String".to_string(),
+        ))?
+        .as_string();
 
         Ok(IdempotencyKey::new(value))
     }
@@ -283,6 +436,7 @@ impl DefaultGatewayInputExecutor {
         &self,
         script: InvocationContextCompiled,
         request: &mut RichRequest,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<(Option<TraceId>, HashMap<String, ValueAndType>)> {
         let InvocationContextCompiled {
             compiled_invocation_context,
@@ -292,13 +446,18 @@ impl DefaultGatewayInputExecutor {
 
         let rib_input: RibInput = resolve_rib_input(request, &rib_input).await?;
 
-        let value = rib::interpret_pure(compiled_invocation_context, rib_input, None)
-            .await
-            .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
-            .get_record()
-            .ok_or(GatewayHttpError::BadRequest(
-                "Invocation context must be a Rib expression that resolves to record".to_string(),
-            ))?;
+        let value = rib::interpret_pure_with_limits(
+            compiled_invocation_context,
+            rib_input,
+            None,
+            rib_limits.clone(),
+        )
+        .await
+        .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))?
+        .get_record()
+        .ok_or(GatewayHttpError::BadRequest(
+            "Invocation context must be a Rib expression that resolves to record".to_string(),
+        ))?;
         let record: HashMap<String, ValueAndType> = HashMap::from_iter(value);
 
         let trace_id = record
@@ -349,10 +508,11 @@ impl DefaultGatewayInputExecutor {
         idempotency_key_compiled: Option<IdempotencyKeyCompiled>,
         component_id: VersionedComponentId,
         invocation_context_compiled: Option<InvocationContextCompiled>,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<WorkerDetails> {
         let worker_name = if let Some(worker_name_compiled) = worker_name_compiled {
             let result = self
-                .evaluate_worker_name_rib_script(worker_name_compiled, request)
+                .evaluate_worker_name_rib_script(worker_name_compiled, request, rib_limits)
                 .await?;
             Some(result)
         } else {
@@ -364,7 +524,7 @@ impl DefaultGatewayInputExecutor {
         // If neither are available, the worker-executor will later generate an idempotency key.
         let idempotency_key = if let Some(idempotency_key_compiled) = idempotency_key_compiled {
             let result = self
-                .evaluate_idempotency_key_rib_script(idempotency_key_compiled, request)
+                .evaluate_idempotency_key_rib_script(idempotency_key_compiled, request, rib_limits)
                 .await?;
             Some(result)
         } else {
@@ -384,7 +544,11 @@ impl DefaultGatewayInputExecutor {
             let trace_context_headers = TraceContextHeaders::parse(request.underlying.headers());
 
             let (user_defined_trace_id, user_defined_span) = self
-                .evaluate_invocation_context_rib_script(invocation_context_compiled, request)
+                .evaluate_invocation_context_rib_script(
+                    invocation_context_compiled,
+                    request,
+                    rib_limits,
+                )
                 .await?;
 
             match (trace_context_headers, &user_defined_trace_id) {
@@ -444,6 +608,7 @@ impl DefaultGatewayInputExecutor {
         compiled_response_mapping: ResponseMappingCompiled,
         request: &mut RichRequest,
         worker_detail: WorkerDetails,
+        rib_limits: &RibInterpreterLimits,
     ) -> GatewayHttpResult<RibResult> {
         let WorkerDetails {
             invocation_context,
@@ -466,6 +631,7 @@ impl DefaultGatewayInputExecutor {
                 response_mapping_compiled,
                 rib_input,
                 namespace,
+                rib_limits.clone(),
             )
             .await
             .map_err(GatewayHttpError::EvaluationError)
@@ -475,6 +641,7 @@ impl DefaultGatewayInputExecutor {
         &self,
         mut request: RichRequest,
         middlewares: &Option<HttpMiddlewares>,
+        rib_limits: &RibInterpreterLimits,
     ) -> Result<RichRequest, poem::Response> {
         if let Some(middlewares) = middlewares {
             let input_middleware_result = middlewares
@@ -512,7 +679,18 @@ impl DefaultGatewayInputExecutor {
 
             match input_middleware_result {
                 Ok(MiddlewareSuccess::Redirect(response)) => Err(response)?,
-                Ok(MiddlewareSuccess::PassThrough { .. }) => Ok(request),
+                Ok(MiddlewareSuccess::PassThrough { .. }) => {
+                    match middlewares
+                        .get_transform_middleware()
+                        .and_then(|transform| transform.request_transform)
+                    {
+                        Some(request_transform) => {
+                            self.apply_request_transform(request, &request_transform, rib_limits)
+                                .await
+                        }
+                        None => Ok(request),
+                    }
+                }
                 Err(err) => {
                     let response = err.to_response_from_safe_display(|error| match error {
                         MiddlewareError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -526,58 +704,107 @@ impl DefaultGatewayInputExecutor {
             Ok(request)
         }
     }
-}
 
-#[async_trait]
-impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
-    async fn execute_http_request(&self, request: poem::Request) -> poem::Response {
-        let authority = match authority_from_request(&request) {
-            Ok(success) => success,
-            Err(err) => {
-                return poem::Response::builder()
-                    .status(StatusCode::BAD_REQUEST)
-                    .body(Body::from_string(err));
-            }
-        };
+    // Rewrites the request with the result of the request transform of the route,
+    // unless the transform short-circuits the request with a redirect
+    async fn apply_request_transform(
+        &self,
+        mut request: RichRequest,
+        request_transform: &TransformCompiled,
+        rib_limits: &RibInterpreterLimits,
+    ) -> Result<RichRequest, poem::Response> {
+        let result = evaluate_transform(&mut request, request_transform, None, rib_limits)
+            .await
+            .and_then(|result| {
+                RequestTransformation::from_rib_result(result)
+                    .map_err(GatewayHttpError::InternalError)
+            })
+            .and_then(|transformation| {
+                transformation
+                    .apply(&mut request)
+                    .map_err(GatewayHttpError::InternalError)
+            });
 
-        let possible_api_definitions = self
-            .api_definition_lookup_service
-            .get(&ApiSiteString(authority.clone()))
-            .await;
+        match result {
+            Ok(None) => Ok(request),
+            Ok(Some(redirect)) => Err(redirect),
+            Err(err) => Err(err.to_response(&request, &self.gateway_session_store).await),
+        }
+    }
 
-        let possible_api_definitions = match possible_api_definitions {
-            Ok(api_defs) => api_defs,
-            Err(api_defs_lookup_error) => {
-                error!(
-                    "API request host: {} - error: {}",
-                    authority,
-                    api_defs_lookup_error.to_safe_string()
-                );
+    async fn maybe_apply_middlewares_out(
+        &self,
+        mut response: poem::Response,
+        request: &mut RichRequest,
+        middlewares: &Option<HttpMiddlewares>,
+        rib_limits: &RibInterpreterLimits,
+    ) -> poem::Response {
+        if let Some(middlewares) = middlewares {
+            if let Some(response_transform) = middlewares
+                .get_transform_middleware()
+                .and_then(|transform| transform.response_transform)
+            {
+                response = self
+                    .apply_response_transform(response, request, &response_transform, rib_limits)
+                    .await;
+            }
 
-                return api_defs_lookup_error
-                    .to_response_from_safe_display(get_status_code_from_api_lookup_error);
+            let result = middlewares.process_middleware_out(&mut response).await;
+            match result {
+                Ok(_) => response,
+                Err(err) => {
+                    err.to_response_from_safe_display(|_| StatusCode::INTERNAL_SERVER_ERROR)
+                }
             }
-        };
+        } else {
+            response
+        }
+    }
 
-        let resolved_route_entry = if let Some(resolved_route_entry) =
-            resolve_gateway_binding(possible_api_definitions, &request).await
+    // A failing response transform does not turn the response of the binding into an error,
+    // the response is returned untransformed instead
+    async fn apply_response_transform(
+        &self,
+        mut response: poem::Response,
+        request: &mut RichRequest,
+        response_transform: &TransformCompiled,
+        rib_limits: &RibInterpreterLimits,
+    ) -> poem::Response {
+        let result = match evaluate_transform(
+            request,
+            response_transform,
+            Some(&response),
+            rib_limits,
+        )
+        .await
         {
-            resolved_route_entry
-        } else {
-            return poem::Response::builder()
-                .status(StatusCode::NOT_FOUND)
-                .body(Body::from_string("Route not found".to_string()));
+            Ok(result) => ResponseTransformation::from_rib_result(result)
+                .and_then(|transformation| transformation.apply(&mut response)),
+            Err(err) => Err(transform_error_message(err)),
         };
 
+        if let Err(err) = result {
+            warn!("Response transform failed, returning the untransformed response: {err}");
+        }
+
+        response
+    }
+
+    async fn execute_resolved(
+        &self,
+        authority: String,
+        resolved: SplitResolvedRouteEntryResult,
+        rib_limits: RibInterpreterLimits,
+    ) -> poem::Response {
         let SplitResolvedRouteEntryResult {
             namespace,
             binding,
             middlewares,
             rich_request,
-        } = split_resolved_route_entry(request, resolved_route_entry);
+        } = resolved;
 
         let mut rich_request = match self
-            .maybe_apply_middlewares_in(rich_request, &middlewares)
+            .maybe_apply_middlewares_in(rich_request, &middlewares, &rib_limits)
             .await
         {
             Ok(req) => req,
@@ -609,15 +836,64 @@ impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
             }
 
             GatewayBindingCompiled::Worker(resolved_worker_binding) => {
-                let result = self
-                    .handle_worker_binding(namespace, &mut rich_request, *resolved_worker_binding)
-                    .await;
+                if let Err(err) = resolved_worker_binding
+                    .validate_request(&mut rich_request)
+                    .await
+                {
+                    let response = GatewayHttpError::InvalidRequest(err)
+                        .to_response(&rich_request, &self.gateway_session_store)
+                        .await;
+
+                    return self
+                        .maybe_apply_middlewares_out(
+                            response,
+                            &mut rich_request,
+                            &middlewares,
+                            &rib_limits,
+                        )
+                        .await;
+                }
 
-                let response = result
-                    .to_response(&rich_request, &self.gateway_session_store)
-                    .await;
+                let response = match resolved_worker_binding
+                    .response_cache_policy_compiled
+                    .clone()
+                {
+                    Some(response_cache_policy)
+                        if is_cacheable_request(&rich_request.underlying) =>
+                    {
+                        self.handle_worker_binding_with_response_cache(
+                            ApiSiteString(authority),
+                            namespace,
+                            &mut rich_request,
+                            *resolved_worker_binding,
+                            response_cache_policy,
+                            &rib_limits,
+                        )
+                        .await
+                    }
+                    _ => {
+                        let result = self
+                            .handle_worker_binding(
+                                namespace,
+                                &mut rich_request,
+                                *resolved_worker_binding,
+                                &rib_limits,
+                            )
+                            .await;
 
-                maybe_apply_middlewares_out(response, &middlewares).await
+                        result
+                            .to_response(&rich_request, &self.gateway_session_store)
+                            .await
+                    }
+                };
+
+                self.maybe_apply_middlewares_out(
+                    response,
+                    &mut rich_request,
+                    &middlewares,
+                    &rib_limits,
+                )
+                .await
             }
 
             GatewayBindingCompiled::HttpHandler(http_handler_binding) => {
@@ -626,6 +902,7 @@ impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
                         &namespace,
                         &mut rich_request,
                         *http_handler_binding,
+                        &rib_limits,
                     )
                     .await;
 
@@ -633,7 +910,13 @@ impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
                     .to_response(&rich_request, &self.gateway_session_store)
                     .await;
 
-                maybe_apply_middlewares_out(response, &middlewares).await
+                self.maybe_apply_middlewares_out(
+                    response,
+                    &mut rich_request,
+                    &middlewares,
+                    &rib_limits,
+                )
+                .await
             }
 
             GatewayBindingCompiled::FileServer(resolved_file_server_binding) => {
@@ -642,6 +925,7 @@ impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
                         namespace,
                         &mut rich_request,
                         *resolved_file_server_binding,
+                        &rib_limits,
                     )
                     .await;
 
@@ -649,10 +933,291 @@ impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
                     .to_response(&rich_request, &self.gateway_session_store)
                     .await;
 
-                maybe_apply_middlewares_out(response, &middlewares).await
+                self.maybe_apply_middlewares_out(
+                    response,
+                    &mut rich_request,
+                    &middlewares,
+                    &rib_limits,
+                )
+                .await
             }
         }
     }
+
+    async fn get_traffic_splits(&self, site: &ApiSiteString) -> Vec<CompiledTrafficSplit> {
+        // The requests are still served by the deployed versions if traffic splits are unavailable
+        match self
+            .api_definition_lookup_service
+            .get_traffic_splits(site)
+            .await
+        {
+            Ok(traffic_splits) => traffic_splits,
+            Err(err) => {
+                error!(
+                    "API request host: {} - failed to lookup traffic splits: {}",
+                    site,
+                    err.to_safe_string()
+                );
+                vec![]
+            }
+        }
+    }
+
+    // Routes the request to the canary version of the API definition serving it,
+    // if the definition has a traffic split selecting the canary version for the request
+    async fn apply_traffic_splits(
+        &self,
+        api_definitions: Vec<CompiledHttpApiDefinition>,
+        traffic_splits: Vec<CompiledTrafficSplit>,
+        mut resolved: SplitResolvedRouteEntryResult,
+        rib_limits: &RibInterpreterLimits,
+    ) -> (SplitResolvedRouteEntryResult, Option<TrafficSplitDecision>) {
+        for traffic_split in traffic_splits {
+            let Some(stable_definition) = api_definitions
+                .iter()
+                .find(|def| def.id == traffic_split.api_definition_id)
+            else {
+                continue;
+            };
+
+            // Only the traffic split of the definition serving the request applies
+            if resolve_gateway_binding(
+                vec![stable_definition.clone()],
+                &resolved.rich_request.underlying,
+            )
+            .await
+            .is_none()
+            {
+                continue;
+            }
+
+            let variant = if is_canary_requested(
+                &resolved.rich_request,
+                traffic_split.header.as_ref(),
+                traffic_split.cookie.as_ref(),
+            ) {
+                TrafficSplitVariant::Canary
+            } else {
+                let sticky_key = match &traffic_split.sticky_key_compiled {
+                    Some(sticky_key) => {
+                        self.evaluate_sticky_key(sticky_key, &mut resolved.rich_request, rib_limits)
+                            .await
+                    }
+                    None => {
+                        self.evaluate_worker_name_of_binding(&mut resolved, rib_limits)
+                            .await
+                    }
+                };
+
+                select_variant(
+                    &traffic_split.api_definition_id,
+                    traffic_split.percentage,
+                    sticky_key.as_deref(),
+                )
+            };
+
+            if variant == TrafficSplitVariant::Canary {
+                let canary_definitions = api_definitions
+                    .iter()
+                    .filter(|def| def.id != traffic_split.api_definition_id)
+                    .cloned()
+                    .chain(std::iter::once(traffic_split.canary_definition))
+                    .collect::<Vec<_>>();
+
+                if let Some(canary_route_entry) =
+                    resolve_gateway_binding(canary_definitions, &resolved.rich_request.underlying)
+                        .await
+                {
+                    return (
+                        resolved.rich_request.reroute(canary_route_entry),
+                        Some(TrafficSplitDecision::new(
+                            traffic_split.api_definition_id,
+                            TrafficSplitVariant::Canary,
+                        )),
+                    );
+                }
+
+                // The route no longer exists in the canary version
+                tracing::debug!(
+                    "Route not found in canary version of API {}, serving the deployed version",
+                    traffic_split.api_definition_id
+                );
+            }
+
+            return (
+                resolved,
+                Some(TrafficSplitDecision::new(
+                    traffic_split.api_definition_id,
+                    TrafficSplitVariant::Stable,
+                )),
+            );
+        }
+
+        (resolved, None)
+    }
+
+    // The name of the worker serving the request, if the binding of the deployed version invokes
+    // a named one. The worker name of the binding is used as the sticky key of traffic splits
+    // without an explicit sticky key, such that a worker is not served by both versions.
+    async fn evaluate_worker_name_of_binding(
+        &self,
+        resolved: &mut SplitResolvedRouteEntryResult,
+        rib_limits: &RibInterpreterLimits,
+    ) -> Option<String> {
+        let (worker_name_compiled, response_compiled) = match &resolved.binding {
+            GatewayBindingCompiled::Worker(binding) => (None, Some(&binding.response_compiled)),
+            GatewayBindingCompiled::FileServer(binding) => (
+                binding.worker_name_compiled.clone(),
+                Some(&binding.response_compiled),
+            ),
+            GatewayBindingCompiled::HttpHandler(binding) => {
+                (binding.worker_name_compiled.clone(), None)
+            }
+            GatewayBindingCompiled::Static(_) => (None, None),
+        };
+
+        if let Some(worker_name_compiled) = worker_name_compiled {
+            return self
+                .evaluate_worker_name_rib_script(
+                    worker_name_compiled,
+                    &mut resolved.rich_request,
+                    rib_limits,
+                )
+                .await
+                .ok();
+        }
+
+        let response_compiled = response_compiled?.clone();
+        let rib_input = resolve_rib_input(&mut resolved.rich_request, &response_compiled.rib_input)
+            .await
+            .ok()?;
+
+        invoked_worker_name(
+            response_compiled.response_mapping_compiled,
+            rib_input,
+            rib_limits.clone(),
+        )
+        .await
+    }
+
+    async fn evaluate_sticky_key(
+        &self,
+        sticky_key: &StickyKeyCompiled,
+        request: &mut RichRequest,
+        rib_limits: &RibInterpreterLimits,
+    ) -> Option<String> {
+        // Requests without a sticky key are still served, by a randomly selected version
+        let Ok(rib_input) = resolve_rib_input(request, &sticky_key.rib_input).await else {
+            warn!("Failed to resolve the input of the sticky key of traffic split");
+            return None;
+        };
+
+        match rib::interpret_pure_with_limits(
+            sticky_key.compiled_sticky_key.clone(),
+            rib_input,
+            None,
+            rib_limits.clone(),
+        )
+        .await
+        {
+            Ok(result) => result.get_literal().map(|literal| literal.as_string()),
+            Err(err) => {
+                warn!(
+                    "Failed to evaluate the sticky key of traffic split: {}",
+                    err
+                );
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
+    async fn execute_http_request(&self, request: poem::Request) -> poem::Response {
+        let authority = match authority_from_request(&request) {
+            Ok(success) => success,
+            Err(err) => {
+                return poem::Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .body(Body::from_string(err));
+            }
+        };
+
+        let possible_api_definitions = self
+            .api_definition_lookup_service
+            .get(&ApiSiteString(authority.clone()))
+            .await;
+
+        let possible_api_definitions = match possible_api_definitions {
+            Ok(api_defs) => api_defs,
+            Err(api_defs_lookup_error) => {
+                error!(
+                    "API request host: {} - error: {}",
+                    authority,
+                    api_defs_lookup_error.to_safe_string()
+                );
+
+                return api_defs_lookup_error
+                    .to_response_from_safe_display(get_status_code_from_api_lookup_error);
+            }
+        };
+
+        let traffic_splits = self
+            .get_traffic_splits(&ApiSiteString(authority.clone()))
+            .await;
+
+        // Unlike traffic splits, the requests are not served if the limits are unavailable
+        let rib_limits = match self
+            .api_definition_lookup_service
+            .get_rib_limits(&ApiSiteString(authority.clone()))
+            .await
+        {
+            Ok(rib_limits) => rib_limits,
+            Err(err) => {
+                error!(
+                    "API request host: {} - failed to lookup Rib limits: {}",
+                    authority,
+                    err.to_safe_string()
+                );
+
+                return err.to_response_from_safe_display(get_status_code_from_api_lookup_error);
+            }
+        };
+
+        let api_definitions = if traffic_splits.is_empty() {
+            vec![]
+        } else {
+            possible_api_definitions.clone()
+        };
+
+        let resolved_route_entry = if let Some(resolved_route_entry) =
+            resolve_gateway_binding(possible_api_definitions, &request).await
+        {
+            resolved_route_entry
+        } else {
+            return poem::Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::from_string("Route not found".to_string()));
+        };
+
+        let resolved = split_resolved_route_entry(request, resolved_route_entry);
+
+        let (resolved, traffic_split_decision) = if traffic_splits.is_empty() {
+            (resolved, None)
+        } else {
+            self.apply_traffic_splits(api_definitions, traffic_splits, resolved, &rib_limits)
+                .await
+        };
+
+        let response = self.execute_resolved(authority, resolved, rib_limits).await;
+
+        if let Some(traffic_split_decision) = traffic_split_decision {
+            traffic_split_decision.record_response(&response);
+        }
+
+        response
+    }
 }
 
 async fn resolve_rib_input(
@@ -806,19 +1371,63 @@ async fn resolve_rib_input(
     }
 }
 
-async fn maybe_apply_middlewares_out(
-    mut response: poem::Response,
-    middlewares: &Option<HttpMiddlewares>,
-) -> poem::Response {
-    if let Some(middlewares) = middlewares {
-        let result = middlewares.process_middleware_out(&mut response).await;
-        match result {
-            Ok(_) => response,
-            Err(err) => err.to_response_from_safe_display(|_| StatusCode::INTERNAL_SERVER_ERROR),
-        }
-    } else {
-        response
+// Evaluates a transform of a route, where `response` is looked up only by response transforms
+async fn evaluate_transform(
+    request: &mut RichRequest,
+    transform: &TransformCompiled,
+    response: Option<&poem::Response>,
+    rib_limits: &RibInterpreterLimits,
+) -> GatewayHttpResult<Option<ValueAndType>> {
+    let mut rib_input = resolve_rib_input(request, &transform.rib_input).await?;
+
+    if let (Some(response), Some(response_type)) =
+        (response, transform.rib_input.types.get("response"))
+    {
+        let response_value =
+            response_rib_input(response, response_type).map_err(GatewayHttpError::InternalError)?;
+
+        rib_input
+            .input
+            .insert("response".to_string(), response_value);
     }
+
+    rib::interpret_pure_with_limits(
+        transform.compiled_transform.clone(),
+        rib_input,
+        None,
+        rib_limits.clone(),
+    )
+    .await
+    .map(|result| result.get_val())
+    .map_err(|err| GatewayHttpError::RibInterpretPureError(err.to_string()))
+}
+
+fn transform_error_message(err: GatewayHttpError) -> String {
+    match err {
+        GatewayHttpError::BadRequest(message)
+        | GatewayHttpError::InternalError(message)
+        | GatewayHttpError::RibInterpretPureError(message) => message,
+        GatewayHttpError::InvalidRequest(err) => err.to_safe_string(),
+        GatewayHttpError::RibInputTypeMismatch(err) => err.to_safe_string(),
+        GatewayHttpError::EvaluationError(err) => err.to_string(),
+        GatewayHttpError::HttpHandlerBindingError(err) => format!("{err:?}"),
+        GatewayHttpError::FileServerBindingError(err) => err.to_safe_string(),
+        GatewayHttpError::AuthorisationError(err) => err.to_safe_string(),
+    }
+}
+
+fn is_cacheable_request(request: &poem::Request) -> bool {
+    request.method() == Method::GET && !has_cache_control_directive(request, "no-store")
+}
+
+fn has_cache_control_directive(request: &poem::Request, directive: &str) -> bool {
+    request
+        .headers()
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|value| value.trim().eq_ignore_ascii_case(directive))
 }
 
 fn to_attribute_value(value: &ValueAndType) -> GatewayHttpResult<AttributeValue> {
@@ -852,6 +1461,9 @@ fn get_status_code_from_api_lookup_error(error: &ApiDefinitionLookupError) -> St
                 ApiDeploymentError::InternalConversionError { .. } => {
                     StatusCode::INTERNAL_SERVER_ERROR
                 }
+                ApiDeploymentError::TrafficSplitNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                ApiDeploymentError::InvalidTrafficSplit(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                ApiDeploymentError::RibLimitsNotFound(_, _) => StatusCode::NOT_FOUND,
             }
         }
         ApiDefinitionLookupError::UnknownSite(_) => StatusCode::NOT_FOUND,
@@ -0,0 +1,725 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_api_deployment::ApiSiteString;
+use async_trait::async_trait;
+use bytes::Bytes;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::redis::RedisPool;
+use golem_common::SafeDisplay;
+use http::header::{
+    HeaderName, HeaderValue, AUTHORIZATION, CACHE_CONTROL, ETAG, IF_NONE_MATCH, VARY,
+};
+use http::{HeaderMap, StatusCode};
+use poem::Body;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task;
+use tokio::time::interval;
+use tracing::{error, Instrument};
+
+#[async_trait]
+pub trait GatewayResponseCache: Send + Sync {
+    async fn get(
+        &self,
+        key: &ResponseCacheKey,
+    ) -> Result<Option<CachedResponse>, GatewayResponseCacheError>;
+
+    async fn insert(
+        &self,
+        key: ResponseCacheKey,
+        response: CachedResponse,
+    ) -> Result<(), GatewayResponseCacheError>;
+
+    // Removes every cached response of a site, or only the ones
+    // stored under the given (evaluated) cache key
+    async fn invalidate(
+        &self,
+        site: &ApiSiteString,
+        cache_key: Option<&str>,
+    ) -> Result<(), GatewayResponseCacheError>;
+}
+
+pub type GatewayResponseCacheStore = Arc<dyn GatewayResponseCache + Send + Sync>;
+
+#[derive(Debug, Clone)]
+pub enum GatewayResponseCacheError {
+    InternalError(String),
+}
+
+impl SafeDisplay for GatewayResponseCacheError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            GatewayResponseCacheError::InternalError(e) => format!("Internal error: {e}"),
+        }
+    }
+}
+
+// `cache_key` is the result of the cache key Rib script of the route (or the request path and query),
+// while `variant` distinguishes the routes, the values of the `vary` headers and the principals
+// sharing the same key.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct ResponseCacheKey {
+    pub site: ApiSiteString,
+    pub cache_key: String,
+    pub variant: String,
+}
+
+impl ResponseCacheKey {
+    pub fn new(
+        site: ApiSiteString,
+        cache_key: String,
+        route: &str,
+        vary: &[String],
+        request_headers: &HeaderMap,
+        principal: Option<&str>,
+    ) -> Self {
+        let mut variant = route.to_string();
+
+        if let Some(principal) = principal {
+            variant.push_str(&format!("\nprincipal:{principal}"));
+        }
+
+        for header in vary {
+            let value = request_headers
+                .get_all(header.as_str())
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            variant.push_str(&format!("\n{header}:{value}"));
+        }
+
+        ResponseCacheKey {
+            site,
+            cache_key,
+            variant,
+        }
+    }
+}
+
+// Identifies who a response has been produced for, so a cached response is never served to another
+// caller. Based on the claims of the authenticated session and the `Authorization` header, and
+// hashed so that no credentials end up in the cache.
+pub fn principal_of(auth_data: Option<&Value>, request_headers: &HeaderMap) -> Option<String> {
+    let claims = auth_data.map(|claims| match (claims.get("iss"), claims.get("sub")) {
+        (Some(issuer), Some(subject)) => format!("{issuer}/{subject}"),
+        _ => claims.to_string(),
+    });
+    let authorization = request_headers
+        .get_all(AUTHORIZATION)
+        .iter()
+        .map(|value| String::from_utf8_lossy(value.as_bytes()).to_string())
+        .collect::<Vec<_>>();
+
+    if claims.is_none() && authorization.is_empty() {
+        return None;
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(claims.unwrap_or_default());
+    for value in authorization {
+        hasher.update(b"\n");
+        hasher.update(value);
+    }
+    Some(format!("{:x}", hasher.finalize()))
+}
+
+#[derive(Debug, Clone, PartialEq, bincode::Encode, bincode::Decode)]
+pub struct CachedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, Vec<u8>)>,
+    pub body: Vec<u8>,
+    pub etag: String,
+    // Unix timestamp in seconds
+    pub expires_at: i64,
+}
+
+impl CachedResponse {
+    // Buffers the body of a response such that it can be stored in the cache.
+    // If reading the body fails, an internal server error response is returned instead.
+    pub async fn from_response(
+        mut response: poem::Response,
+        ttl: Duration,
+    ) -> Result<Self, poem::Response> {
+        let body = response.take_body().into_vec().await.map_err(|err| {
+            error!("Failed to read response body for caching: {}", err);
+            poem::Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::from_string(
+                    "Failed to read the response of the worker".to_string(),
+                ))
+        })?;
+
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| *name != ETAG && *name != CACHE_CONTROL)
+            .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+            .collect();
+
+        Ok(CachedResponse {
+            status: response.status().as_u16(),
+            headers,
+            etag: Self::etag_of(&body),
+            body,
+            expires_at: current_time() + ttl.as_secs() as i64,
+        })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= current_time()
+    }
+
+    // Converts to an HTTP response for the given request headers. If the request
+    // already holds the current version of the response (`If-None-Match`), the body is omitted.
+    pub fn to_response(&self, request_headers: &HeaderMap, vary: &[String]) -> poem::Response {
+        let max_age = (self.expires_at - current_time()).max(0);
+
+        let mut response = if self.matches_if_none_match(request_headers) {
+            poem::Response::builder()
+                .status(StatusCode::NOT_MODIFIED)
+                .body(Body::empty())
+        } else {
+            let mut response = poem::Response::builder()
+                .status(StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK))
+                .body(Body::from_vec(self.body.clone()));
+
+            for (name, value) in &self.headers {
+                if let (Ok(name), Ok(value)) = (
+                    HeaderName::from_bytes(name.as_bytes()),
+                    HeaderValue::from_bytes(value),
+                ) {
+                    response.headers_mut().append(name, value);
+                }
+            }
+
+            response
+        };
+
+        let headers = response.headers_mut();
+
+        if let Ok(etag) = HeaderValue::from_str(&self.etag) {
+            headers.insert(ETAG, etag);
+        }
+
+        if let Ok(cache_control) = HeaderValue::from_str(&format!("max-age={max_age}")) {
+            headers.insert(CACHE_CONTROL, cache_control);
+        }
+
+        if !vary.is_empty() {
+            if let Ok(vary) = HeaderValue::from_str(&vary.join(", ")) {
+                headers.insert(VARY, vary);
+            }
+        }
+
+        response
+    }
+
+    fn matches_if_none_match(&self, request_headers: &HeaderMap) -> bool {
+        request_headers
+            .get_all(IF_NONE_MATCH)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|tag| tag.trim())
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/") == self.etag)
+    }
+
+    fn etag_of(body: &[u8]) -> String {
+        // Stable across worker service instances and versions, unlike the std hasher
+        format!("\"{:x}-{:x}\"", body.len(), Sha256::digest(body))
+    }
+}
+
+fn current_time() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+type ResponseCache = Cache<ResponseCacheKey, (), CachedResponse, GatewayResponseCacheError>;
+
+#[derive(Clone)]
+pub struct InMemoryGatewayResponseCache {
+    cache: ResponseCache,
+}
+
+impl InMemoryGatewayResponseCache {
+    pub fn new(max_capacity: usize, eviction_period: Duration) -> Self {
+        let cache = Cache::new(
+            Some(max_capacity),
+            FullCacheEvictionMode::LeastRecentlyUsed(1),
+            BackgroundEvictionMode::None,
+            "gateway_response_cache",
+        );
+
+        Self::spawn_expiration_task(eviction_period, cache.clone());
+
+        Self { cache }
+    }
+
+    // Entries have a per-route TTL, which is not supported by the background eviction of `Cache`
+    fn spawn_expiration_task(eviction_period: Duration, cache: ResponseCache) {
+        task::spawn(
+            async move {
+                let mut eviction_interval = interval(eviction_period);
+
+                loop {
+                    eviction_interval.tick().await;
+
+                    let expired = cache
+                        .iter()
+                        .filter(|(_, response)| response.is_expired())
+                        .map(|(key, _)| key)
+                        .collect::<Vec<_>>();
+
+                    for key in expired {
+                        cache.remove(&key);
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+#[async_trait]
+impl GatewayResponseCache for InMemoryGatewayResponseCache {
+    async fn get(
+        &self,
+        key: &ResponseCacheKey,
+    ) -> Result<Option<CachedResponse>, GatewayResponseCacheError> {
+        match self.cache.get(key).await {
+            Some(response) if response.is_expired() => {
+                self.cache.remove(key);
+                Ok(None)
+            }
+            other => Ok(other),
+        }
+    }
+
+    async fn insert(
+        &self,
+        key: ResponseCacheKey,
+        response: CachedResponse,
+    ) -> Result<(), GatewayResponseCacheError> {
+        self.cache.remove(&key);
+        self.cache
+            .get_or_insert_simple(&key, async move || Ok(response))
+            .await
+            .map(|_| ())
+    }
+
+    async fn invalidate(
+        &self,
+        site: &ApiSiteString,
+        cache_key: Option<&str>,
+    ) -> Result<(), GatewayResponseCacheError> {
+        let keys = self
+            .cache
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|key| {
+                &key.site == site && cache_key.is_none_or(|cache_key| key.cache_key == cache_key)
+            })
+            .collect::<Vec<_>>();
+
+        for key in keys {
+            self.cache.remove(&key);
+        }
+
+        Ok(())
+    }
+}
+
+// Responses with the same cache key are stored in a single hash, with the variants as fields,
+// such that a cache key can be invalidated with a single delete. The site is enclosed in braces,
+// which cannot appear in a host, so that the keys of a site never match the pattern of another
+// one (such as `api.example.com` and `api.example.com.eu`, or a site with a port).
+#[derive(Clone)]
+pub struct RedisGatewayResponseCache {
+    redis: RedisPool,
+}
+
+impl RedisGatewayResponseCache {
+    pub fn new(redis: RedisPool) -> Self {
+        Self { redis }
+    }
+
+    fn redis_key(site: &ApiSiteString, cache_key: &str) -> String {
+        format!("gateway_response_cache:{{{}}}:{}", site.0, cache_key)
+    }
+
+    // Pattern matching all the keys of a site, with the glob special characters of the site escaped
+    fn redis_key_pattern(site: &ApiSiteString) -> String {
+        let mut escaped_site = String::with_capacity(site.0.len());
+        for c in site.0.chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                escaped_site.push('\\');
+            }
+            escaped_site.push(c);
+        }
+        format!("gateway_response_cache:{{{escaped_site}}}:*")
+    }
+}
+
+#[async_trait]
+impl GatewayResponseCache for RedisGatewayResponseCache {
+    async fn get(
+        &self,
+        key: &ResponseCacheKey,
+    ) -> Result<Option<CachedResponse>, GatewayResponseCacheError> {
+        let result: Option<Bytes> = self
+            .redis
+            .with("gateway_response_cache", "get")
+            .hget(
+                Self::redis_key(&key.site, &key.cache_key),
+                key.variant.as_str(),
+            )
+            .await
+            .map_err(|e| {
+                error!("Failed to get cached response from Redis: {}", e);
+                GatewayResponseCacheError::InternalError(e.to_string())
+            })?;
+
+        match result {
+            Some(bytes) => {
+                let response: CachedResponse = golem_common::serialization::deserialize(&bytes)
+                    .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+                if response.is_expired() {
+                    Ok(None)
+                } else {
+                    Ok(Some(response))
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn insert(
+        &self,
+        key: ResponseCacheKey,
+        response: CachedResponse,
+    ) -> Result<(), GatewayResponseCacheError> {
+        let redis_key = Self::redis_key(&key.site, &key.cache_key);
+        let ttl = (response.expires_at - current_time()).max(1);
+
+        let serialized = golem_common::serialization::serialize(&response)
+            .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+        self.redis
+            .with("gateway_response_cache", "insert")
+            .hset::<(), _, _>(&redis_key, (key.variant.as_str(), serialized))
+            .await
+            .map_err(|e| {
+                error!("Failed to insert cached response into Redis: {}", e);
+                GatewayResponseCacheError::InternalError(e.to_string())
+            })?;
+
+        // The expiry of a hash applies to all of its variants, while each variant
+        // is still checked against its own expiry when it is read
+        self.redis
+            .with("gateway_response_cache", "insert")
+            .expire::<(), _>(&redis_key, ttl)
+            .await
+            .map_err(|e| {
+                error!("Failed to set expiry on cached response in Redis: {}", e);
+                GatewayResponseCacheError::InternalError(e.to_string())
+            })
+    }
+
+    async fn invalidate(
+        &self,
+        site: &ApiSiteString,
+        cache_key: Option<&str>,
+    ) -> Result<(), GatewayResponseCacheError> {
+        let keys = match cache_key {
+            Some(cache_key) => vec![Self::redis_key(site, cache_key)],
+            None => {
+                let pattern = Self::redis_key_pattern(site);
+                let mut keys = vec![];
+                let mut cursor = 0;
+
+                loop {
+                    let (next_cursor, page) = self
+                        .redis
+                        .with("gateway_response_cache", "invalidate")
+                        .scan(&pattern, cursor, 100)
+                        .await
+                        .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+                    keys.extend(page);
+
+                    if next_cursor == 0 {
+                        break;
+                    }
+
+                    cursor = next_cursor;
+                }
+
+                keys
+            }
+        };
+
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        self.redis
+            .with("gateway_response_cache", "invalidate")
+            .del_many::<(), _>(keys)
+            .await
+            .map_err(|e| {
+                error!("Failed to invalidate cached responses in Redis: {}", e);
+                GatewayResponseCacheError::InternalError(e.to_string())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_r::test;
+
+    fn cached_response(body: &str, ttl: Duration) -> CachedResponse {
+        CachedResponse {
+            status: 200,
+            headers: vec![("content-type".to_string(), b"application/json".to_vec())],
+            body: body.as_bytes().to_vec(),
+            etag: CachedResponse::etag_of(body.as_bytes()),
+            expires_at: current_time() + ttl.as_secs() as i64,
+        }
+    }
+
+    fn cache_key(site: &str, cache_key: &str) -> ResponseCacheKey {
+        ResponseCacheKey::new(
+            ApiSiteString(site.to_string()),
+            cache_key.to_string(),
+            "GET /users/{user-id}",
+            &[],
+            &HeaderMap::new(),
+            None,
+        )
+    }
+
+    #[test]
+    fn vary_headers_are_part_of_the_variant() {
+        let mut english = HeaderMap::new();
+        english.insert("accept-language", HeaderValue::from_static("en"));
+
+        let mut german = HeaderMap::new();
+        german.insert("accept-language", HeaderValue::from_static("de"));
+
+        let vary = vec!["accept-language".to_string()];
+        let site = ApiSiteString("api.example.com".to_string());
+
+        let english_key = ResponseCacheKey::new(
+            site.clone(),
+            "user".to_string(),
+            "GET /",
+            &vary,
+            &english,
+            None,
+        );
+        let german_key =
+            ResponseCacheKey::new(site, "user".to_string(), "GET /", &vary, &german, None);
+
+        assert_eq!(english_key.cache_key, german_key.cache_key);
+        assert_ne!(english_key, german_key);
+    }
+
+    #[test]
+    fn principals_are_part_of_the_variant() {
+        let site = ApiSiteString("api.example.com".to_string());
+        let headers = HeaderMap::new();
+
+        let alice_claims =
+            serde_json::json!({ "iss": "https://idp", "sub": "alice", "nonce": "1" });
+        let alice_claims_of_another_session =
+            serde_json::json!({ "iss": "https://idp", "sub": "alice", "nonce": "2" });
+        let bob_claims = serde_json::json!({ "iss": "https://idp", "sub": "bob", "nonce": "1" });
+
+        let key = |claims: &Value| {
+            ResponseCacheKey::new(
+                site.clone(),
+                "profile".to_string(),
+                "GET /profile",
+                &[],
+                &headers,
+                principal_of(Some(claims), &headers).as_deref(),
+            )
+        };
+
+        assert_eq!(key(&alice_claims), key(&alice_claims_of_another_session));
+        assert_ne!(key(&alice_claims), key(&bob_claims));
+    }
+
+    #[test]
+    fn authorization_header_identifies_the_principal() {
+        let mut alice = HeaderMap::new();
+        alice.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer alice-token"),
+        );
+
+        let mut bob = HeaderMap::new();
+        bob.insert(AUTHORIZATION, HeaderValue::from_static("Bearer bob-token"));
+
+        let alice_principal = principal_of(None, &alice).unwrap();
+
+        assert_eq!(principal_of(None, &HeaderMap::new()), None);
+        assert_ne!(Some(alice_principal.clone()), principal_of(None, &bob));
+        assert!(!alice_principal.contains("alice-token"));
+    }
+
+    #[test]
+    fn if_none_match_returns_not_modified() {
+        let response = cached_response("{}", Duration::from_secs(60));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            IF_NONE_MATCH,
+            HeaderValue::from_str(&format!("\"other\", W/{}", response.etag)).unwrap(),
+        );
+
+        let not_modified = response.to_response(&headers, &[]);
+        let full = response.to_response(&HeaderMap::new(), &["accept".to_string()]);
+
+        assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(full.status(), StatusCode::OK);
+        assert_eq!(
+            full.headers().get(ETAG).unwrap().to_str().unwrap(),
+            response.etag
+        );
+        assert_eq!(full.headers().get(VARY).unwrap(), "accept");
+        assert_eq!(
+            full.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn redis_key_pattern_only_matches_the_keys_of_the_site() {
+        let site = ApiSiteString("api.example.com".to_string());
+
+        assert_eq!(
+            RedisGatewayResponseCache::redis_key(&site, "user:1"),
+            "gateway_response_cache:{api.example.com}:user:1"
+        );
+        assert_eq!(
+            RedisGatewayResponseCache::redis_key_pattern(&site),
+            "gateway_response_cache:{api.example.com}:*"
+        );
+        // The key of a site sharing the prefix does not start with the pattern's prefix
+        assert!(!RedisGatewayResponseCache::redis_key(
+            &ApiSiteString("api.example.com.eu".to_string()),
+            "user:1"
+        )
+        .starts_with("gateway_response_cache:{api.example.com}:"));
+        assert_eq!(
+            RedisGatewayResponseCache::redis_key_pattern(&ApiSiteString("a*b?[c]".to_string())),
+            "gateway_response_cache:{a\\*b\\?\\[c\\]}:*"
+        );
+    }
+
+    #[test]
+    fn etag_depends_on_body() {
+        assert_eq!(
+            CachedResponse::etag_of(b"hello"),
+            CachedResponse::etag_of(b"hello")
+        );
+        assert_ne!(
+            CachedResponse::etag_of(b"hello"),
+            CachedResponse::etag_of(b"world")
+        );
+    }
+
+    #[test]
+    async fn in_memory_cache_expires_and_invalidates() {
+        let cache = InMemoryGatewayResponseCache::new(16, Duration::from_secs(60));
+
+        cache
+            .insert(
+                cache_key("a.com", "user-1"),
+                cached_response("1", Duration::from_secs(60)),
+            )
+            .await
+            .unwrap();
+        cache
+            .insert(
+                cache_key("a.com", "user-2"),
+                cached_response("2", Duration::from_secs(60)),
+            )
+            .await
+            .unwrap();
+        cache
+            .insert(
+                cache_key("b.com", "user-1"),
+                cached_response("3", Duration::from_secs(60)),
+            )
+            .await
+            .unwrap();
+        cache
+            .insert(
+                cache_key("b.com", "expired"),
+                cached_response("4", Duration::from_secs(0)),
+            )
+            .await
+            .unwrap();
+
+        assert!(cache
+            .get(&cache_key("b.com", "expired"))
+            .await
+            .unwrap()
+            .is_none());
+
+        cache
+            .invalidate(&ApiSiteString("a.com".to_string()), Some("user-1"))
+            .await
+            .unwrap();
+
+        assert!(cache
+            .get(&cache_key("a.com", "user-1"))
+            .await
+            .unwrap()
+            .is_none());
+        assert!(cache
+            .get(&cache_key("a.com", "user-2"))
+            .await
+            .unwrap()
+            .is_some());
+
+        cache
+            .invalidate(&ApiSiteString("a.com".to_string()), None)
+            .await
+            .unwrap();
+
+        assert!(cache
+            .get(&cache_key("a.com", "user-2"))
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            cache
+                .get(&cache_key("b.com", "user-1"))
+                .await
+                .unwrap()
+                .map(|response| response.body),
+            Some(b"3".to_vec())
+        );
+    }
+}
@@ -17,14 +17,17 @@ pub mod auth_call_back_binding_handler;
 pub mod file_server_binding_handler;
 pub mod gateway_binding_resolver;
 pub mod gateway_http_input_executor;
+pub mod gateway_response_cache;
 pub mod gateway_session;
 mod gateway_worker_request_executor;
 mod http_content_type_mapper;
 pub mod http_handler_binding_handler;
 pub mod request;
+pub mod request_validation;
 pub mod router;
 pub mod to_response;
 pub mod to_response_failure;
+pub mod traffic_split;
 pub use gateway_worker_request_executor::*;
 
 use golem_common::model::auth::Namespace;
@@ -182,6 +182,15 @@ impl RichRequest {
         result
     }
 
+    /// Routes the request to another route entry, keeping the request body if it has already been read
+    pub fn reroute(self, entry: ResolvedRouteEntry) -> SplitResolvedRouteEntryResult {
+        let cached_request_body = self.cached_request_body;
+
+        let mut result = split_resolved_route_entry(self.underlying, entry);
+        result.rich_request.cached_request_body = cached_request_body;
+        result
+    }
+
     fn cached_request_body(&self) -> &Value {
         &self.cached_request_body
     }
@@ -0,0 +1,346 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_execution::request::RichRequest;
+use golem_common::SafeDisplay;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::json::ValueAndTypeJsonExtensions;
+use golem_wasm_rpc::ValueAndType;
+use http::StatusCode;
+use poem::Body;
+use rib::{RibInputTypeInfo, TypeName};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// All the fields of a request that doesn't conform to the types inferred from
+// the Rib scripts of a binding, collected before any worker is invoked
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RequestValidationError {
+    pub errors: Vec<InvalidRequestField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidRequestField {
+    // Path of the field as referred to in Rib, such as `request.body.user.age`
+    pub field: String,
+    // The WIT type the Rib scripts expect for the field
+    pub expected_type: String,
+    pub message: String,
+}
+
+impl RequestValidationError {
+    pub fn to_response(&self) -> poem::Response {
+        let body = serde_json::to_string(self).unwrap_or_else(|_| self.to_safe_string());
+
+        poem::Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .content_type("application/json")
+            .body(Body::from_string(body))
+    }
+}
+
+impl SafeDisplay for RequestValidationError {
+    fn to_safe_string(&self) -> String {
+        self.errors
+            .iter()
+            .map(|error| {
+                format!(
+                    "{}: {} (expected {})",
+                    error.field, error.message, error.expected_type
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// Validates the body, path, query and headers of the request against the `request` input
+// of each of the given Rib scripts. Every offending field is reported only once,
+// even if more than one script refers to it.
+pub async fn validate_request(
+    request: &mut RichRequest,
+    rib_inputs: &[&RibInputTypeInfo],
+) -> Result<(), RequestValidationError> {
+    let mut errors = InvalidRequestFields::default();
+
+    for rib_input in rib_inputs {
+        let Some(AnalysedType::Record(request_type)) = rib_input.types.get("request") else {
+            continue;
+        };
+
+        for field in request_type.fields.iter() {
+            match field.name.as_str() {
+                "body" => match request.request_body().await {
+                    Ok(body) => validate_json(body, &field.typ, "request.body", &mut errors),
+                    Err(err) => errors.push("request.body", &field.typ, err),
+                },
+                "path" => validate_primitives(
+                    &request.path_params(),
+                    &field.typ,
+                    "request.path",
+                    "path parameter",
+                    &mut errors,
+                ),
+                "query" => validate_primitives(
+                    &request.query_params(),
+                    &field.typ,
+                    "request.query",
+                    "query parameter",
+                    &mut errors,
+                ),
+                "headers" | "header" => {
+                    // Header names are case-insensitive, hence looked up by the names used in Rib
+                    let headers = match &field.typ {
+                        AnalysedType::Record(record) => record
+                            .fields
+                            .iter()
+                            .filter_map(|header| {
+                                request
+                                    .headers()
+                                    .get(header.name.as_str())
+                                    .and_then(|value| value.to_str().ok())
+                                    .map(|value| (header.name.clone(), value.to_string()))
+                            })
+                            .collect::<HashMap<_, _>>(),
+                        _ => HashMap::new(),
+                    };
+
+                    validate_primitives(
+                        &headers,
+                        &field.typ,
+                        &format!("request.{}", field.name),
+                        "header",
+                        &mut errors,
+                    )
+                }
+                // Anything else (such as auth) is not part of what the client sends
+                _ => {}
+            }
+        }
+    }
+
+    if errors.0.is_empty() {
+        Ok(())
+    } else {
+        Err(RequestValidationError { errors: errors.0 })
+    }
+}
+
+#[derive(Default)]
+struct InvalidRequestFields(Vec<InvalidRequestField>);
+
+impl InvalidRequestFields {
+    fn push(&mut self, field: &str, expected_type: &AnalysedType, message: String) {
+        if self.0.iter().all(|error| error.field != field) {
+            self.0.push(InvalidRequestField {
+                field: field.to_string(),
+                expected_type: type_name(expected_type),
+                message,
+            });
+        }
+    }
+}
+
+fn validate_primitives(
+    values: &HashMap<String, String>,
+    typ: &AnalysedType,
+    path: &str,
+    kind: &str,
+    errors: &mut InvalidRequestFields,
+) {
+    if let AnalysedType::Record(record) = typ {
+        for field in record.fields.iter() {
+            let field_path = format!("{}.{}", path, field.name);
+
+            match values.get(&field.name) {
+                Some(value) => {
+                    if let Err(message) = validate_primitive(value, &field.typ) {
+                        errors.push(&field_path, &field.typ, message);
+                    }
+                }
+                None => errors.push(&field_path, &field.typ, format!("missing {kind}")),
+            }
+        }
+    }
+}
+
+fn validate_primitive(value: &str, typ: &AnalysedType) -> Result<(), String> {
+    let valid = match typ {
+        AnalysedType::Str(_) => true,
+        AnalysedType::Bool(_) => is_parsable::<bool>(value),
+        AnalysedType::U8(_) => is_parsable::<u8>(value),
+        AnalysedType::U16(_) => is_parsable::<u16>(value),
+        AnalysedType::U32(_) => is_parsable::<u32>(value),
+        AnalysedType::U64(_) => is_parsable::<u64>(value),
+        AnalysedType::S8(_) => is_parsable::<i8>(value),
+        AnalysedType::S16(_) => is_parsable::<i16>(value),
+        AnalysedType::S32(_) => is_parsable::<i32>(value),
+        AnalysedType::S64(_) => is_parsable::<i64>(value),
+        AnalysedType::F32(_) => is_parsable::<f32>(value),
+        AnalysedType::F64(_) => is_parsable::<f64>(value),
+        _ => return Err("only primitive types are supported".to_string()),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("invalid value `{value}`"))
+    }
+}
+
+fn is_parsable<T: FromStr>(value: &str) -> bool {
+    value.parse::<T>().is_ok()
+}
+
+fn validate_json(value: &Value, typ: &AnalysedType, path: &str, errors: &mut InvalidRequestFields) {
+    match (typ, value) {
+        (AnalysedType::Record(record), Value::Object(fields)) => {
+            for field in record.fields.iter() {
+                let field_path = format!("{}.{}", path, field.name);
+
+                match fields.get(&field.name) {
+                    Some(value) => validate_json(value, &field.typ, &field_path, errors),
+                    None if matches!(field.typ, AnalysedType::Option(_)) => {}
+                    None => errors.push(&field_path, &field.typ, "missing field".to_string()),
+                }
+            }
+        }
+        (AnalysedType::List(list), Value::Array(items)) => {
+            for (index, item) in items.iter().enumerate() {
+                validate_json(item, &list.inner, &format!("{path}[{index}]"), errors);
+            }
+        }
+        (AnalysedType::Tuple(tuple), Value::Array(items)) if tuple.items.len() == items.len() => {
+            for (index, (typ, item)) in tuple.items.iter().zip(items).enumerate() {
+                validate_json(item, typ, &format!("{path}[{index}]"), errors);
+            }
+        }
+        (AnalysedType::Option(_), Value::Null) => {}
+        (AnalysedType::Option(option), value) => validate_json(value, &option.inner, path, errors),
+        (AnalysedType::Record(_) | AnalysedType::List(_) | AnalysedType::Tuple(_), value) => {
+            errors.push(path, typ, format!("found {}", json_kind(value)))
+        }
+        (typ, value) => {
+            if let Err(err) = ValueAndType::parse_with_type(value, typ) {
+                errors.push(path, typ, err.join(", "))
+            }
+        }
+    }
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn type_name(typ: &AnalysedType) -> String {
+    TypeName::try_from(typ.clone())
+        .map(|x| x.to_string())
+        .unwrap_or_else(|_| format!("{typ:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_wasm_ast::analysis::analysed_type::{field, list, option, record, str, u32};
+    use serde_json::json;
+    use test_r::test;
+
+    fn validate(value: Value, typ: &AnalysedType) -> Vec<InvalidRequestField> {
+        let mut errors = InvalidRequestFields::default();
+        validate_json(&value, typ, "request.body", &mut errors);
+        errors.0
+    }
+
+    fn user_type() -> AnalysedType {
+        record(vec![
+            field("name", str()),
+            field("age", u32()),
+            field("nickname", option(str())),
+            field("tags", list(str())),
+        ])
+    }
+
+    #[test]
+    fn valid_body_has_no_errors() {
+        let errors = validate(
+            json!({"name": "jon", "age": 30, "tags": ["a"], "extra": true}),
+            &user_type(),
+        );
+
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn every_offending_field_is_reported() {
+        let errors = validate(json!({"age": -1, "tags": ["a", 1]}), &user_type());
+
+        let fields = errors
+            .iter()
+            .map(|error| (error.field.as_str(), error.expected_type.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("request.body.name", "string"),
+                ("request.body.age", "u32"),
+                ("request.body.tags[1]", "string"),
+            ]
+        );
+    }
+
+    #[test]
+    fn body_of_wrong_shape_is_reported_at_the_root() {
+        let errors = validate(json!([1, 2]), &user_type());
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "request.body");
+        assert_eq!(errors[0].message, "found array");
+    }
+
+    #[test]
+    fn primitive_parameters_are_validated() {
+        let values = HashMap::from([("user-id".to_string(), "abc".to_string())]);
+        let typ = record(vec![field("user-id", u32()), field("store", str())]);
+
+        let mut errors = InvalidRequestFields::default();
+        validate_primitives(&values, &typ, "request.path", "path parameter", &mut errors);
+
+        assert_eq!(
+            errors.0,
+            vec![
+                InvalidRequestField {
+                    field: "request.path.user-id".to_string(),
+                    expected_type: "u32".to_string(),
+                    message: "invalid value `abc`".to_string(),
+                },
+                InvalidRequestField {
+                    field: "request.path.store".to_string(),
+                    expected_type: "string".to_string(),
+                    message: "missing path parameter".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -20,6 +20,7 @@ use crate::api::common::ApiEndpointError;
 use crate::gateway_execution::file_server_binding_handler::FileServerBindingError;
 use crate::gateway_execution::gateway_session::GatewaySessionStore;
 use crate::gateway_execution::request::RichRequest;
+use crate::gateway_execution::request_validation::RequestValidationError;
 use crate::gateway_execution::to_response_failure::ToHttpResponseFromSafeDisplay;
 use crate::gateway_middleware::HttpCors as CorsPreflight;
 use crate::gateway_rib_interpreter::RibRuntimeError;
@@ -57,6 +58,7 @@ pub type GatewayHttpResult<T> = Result<T, GatewayHttpError>;
 
 pub enum GatewayHttpError {
     BadRequest(String),
+    InvalidRequest(RequestValidationError),
     InternalError(String),
     RibInputTypeMismatch(RibInputTypeMismatch),
     EvaluationError(RibRuntimeError),
@@ -77,6 +79,7 @@ impl ToHttpResponse for GatewayHttpError {
             GatewayHttpError::BadRequest(e) => poem::Response::builder()
                 .status(StatusCode::BAD_REQUEST)
                 .body(Body::from_string(e)),
+            GatewayHttpError::InvalidRequest(err) => err.to_response(),
             GatewayHttpError::RibInputTypeMismatch(err) => {
                 err.to_response_from_safe_display(|_| StatusCode::BAD_REQUEST)
             }
@@ -0,0 +1,240 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_api_definition::ApiDefinitionId;
+use crate::gateway_api_deployment::{TrafficSplitMatch, TrafficSplitVariant};
+use crate::gateway_execution::request::RichRequest;
+use crate::metrics;
+use async_trait::async_trait;
+use golem_wasm_ast::analysis::AnalysedType;
+use rib::{
+    ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName, InstructionId,
+    RibByteCode, RibComponentFunctionInvoke, RibFunctionInvokeResult, RibInput,
+    RibInterpreterLimits,
+};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+// The version of an API definition (with a traffic split) selected to serve a request
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrafficSplitDecision {
+    pub api_definition_id: ApiDefinitionId,
+    pub variant: TrafficSplitVariant,
+}
+
+impl TrafficSplitDecision {
+    pub fn new(api_definition_id: ApiDefinitionId, variant: TrafficSplitVariant) -> Self {
+        metrics::record_traffic_split_request(&api_definition_id.0, &variant.to_string());
+
+        TrafficSplitDecision {
+            api_definition_id,
+            variant,
+        }
+    }
+
+    pub fn record_response(&self, response: &poem::Response) {
+        metrics::record_traffic_split_response(
+            &self.api_definition_id.0,
+            &self.variant.to_string(),
+            response.status().as_u16(),
+        );
+    }
+}
+
+// Requests can opt in to the canary version regardless of the percentage,
+// through the header or the cookie of the traffic split
+pub fn is_canary_requested(
+    request: &RichRequest,
+    header: Option<&TrafficSplitMatch>,
+    cookie: Option<&TrafficSplitMatch>,
+) -> bool {
+    let header_matches = header.is_some_and(|header| {
+        request
+            .headers()
+            .get(header.name.as_str())
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value == header.value)
+    });
+
+    let cookie_matches = cookie.is_some_and(|cookie| {
+        request
+            .get_cookie_values()
+            .get(cookie.name.as_str())
+            .is_some_and(|value| *value == cookie.value)
+    });
+
+    header_matches || cookie_matches
+}
+
+// Every request is assigned to one of 100 buckets, and the first `percentage` buckets
+// are served by the canary version. Requests with the same sticky key (by default the
+// name of the invoked worker) are always assigned to the same bucket, while the rest
+// (such as requests to ephemeral workers) are assigned to a random one.
+pub fn select_variant(
+    api_definition_id: &ApiDefinitionId,
+    percentage: u8,
+    sticky_key: Option<&str>,
+) -> TrafficSplitVariant {
+    let bucket = match sticky_key {
+        Some(sticky_key) => fnv1a(format!("{api_definition_id}:{sticky_key}").as_bytes()) % 100,
+        None => (Uuid::new_v4().as_u128() % 100) as u64,
+    };
+
+    if bucket < percentage as u64 {
+        TrafficSplitVariant::Canary
+    } else {
+        TrafficSplitVariant::Stable
+    }
+}
+
+// The name of the first worker a Rib script invokes, found by evaluating the script up to that
+// invocation, which is not performed. Used as the sticky key of traffic splits without an explicit
+// one, such that a worker is always served by the same version. Ephemeral workers have no name.
+pub async fn invoked_worker_name(
+    script: RibByteCode,
+    rib_input: RibInput,
+    limits: RibInterpreterLimits,
+) -> Option<String> {
+    let capture = Arc::new(WorkerNameCapture::default());
+
+    let _ = rib::interpret_with_limits(script, rib_input, capture.clone(), None, limits).await;
+
+    let worker_name = capture.worker_name.lock().unwrap().clone();
+    worker_name
+}
+
+#[derive(Default)]
+struct WorkerNameCapture {
+    worker_name: Mutex<Option<String>>,
+}
+
+#[async_trait]
+impl RibComponentFunctionInvoke for WorkerNameCapture {
+    async fn invoke(
+        &self,
+        _component_dependency_key: ComponentDependencyKey,
+        _instruction_id: &InstructionId,
+        worker_name: Option<EvaluatedWorkerName>,
+        _function_name: EvaluatedFqFn,
+        _args: EvaluatedFnArgs,
+        _return_type: Option<AnalysedType>,
+    ) -> RibFunctionInvokeResult {
+        *self.worker_name.lock().unwrap() = worker_name.map(|worker_name| worker_name.0);
+
+        // Stops the evaluation of the script
+        Err("The invoked worker has been determined".into())
+    }
+}
+
+// A hash that is stable across processes, such that all the instances of the gateway
+// select the same variant for a sticky key
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_r::test;
+
+    fn api_definition_id() -> ApiDefinitionId {
+        ApiDefinitionId("shopping-cart".to_string())
+    }
+
+    #[test]
+    fn no_request_is_routed_to_canary_with_zero_percentage() {
+        assert!((0..1000).all(|_| {
+            select_variant(&api_definition_id(), 0, None) == TrafficSplitVariant::Stable
+        }));
+    }
+
+    #[test]
+    fn all_requests_are_routed_to_canary_with_full_percentage() {
+        assert!((0..1000).all(|_| {
+            select_variant(&api_definition_id(), 100, None) == TrafficSplitVariant::Canary
+        }));
+    }
+
+    #[test]
+    fn sticky_key_always_selects_the_same_variant() {
+        for worker in 0..100 {
+            let sticky_key = format!("worker-{worker}");
+            let variant = select_variant(&api_definition_id(), 50, Some(&sticky_key));
+
+            assert!((0..10).all(|_| {
+                select_variant(&api_definition_id(), 50, Some(&sticky_key)) == variant
+            }));
+        }
+    }
+
+    #[test]
+    fn sticky_keys_are_split_by_percentage() {
+        let canary = (0..10000)
+            .filter(|worker| {
+                select_variant(&api_definition_id(), 20, Some(&format!("worker-{worker}")))
+                    == TrafficSplitVariant::Canary
+            })
+            .count();
+
+        assert!(
+            (1700..2300).contains(&canary),
+            "{canary} requests in canary"
+        );
+    }
+
+    #[test]
+    fn canary_is_requested_by_header_or_cookie() {
+        let header = TrafficSplitMatch {
+            name: "x-canary".to_string(),
+            value: "true".to_string(),
+        };
+
+        let cookie = TrafficSplitMatch {
+            name: "release".to_string(),
+            value: "canary".to_string(),
+        };
+
+        let by_header =
+            RichRequest::new(poem::Request::builder().header("X-Canary", "true").finish());
+        let by_cookie = RichRequest::new(
+            poem::Request::builder()
+                .header("Cookie", "session=abc; release=canary")
+                .finish(),
+        );
+        let by_other_value = RichRequest::new(
+            poem::Request::builder()
+                .header("X-Canary", "false")
+                .finish(),
+        );
+
+        assert!(is_canary_requested(
+            &by_header,
+            Some(&header),
+            Some(&cookie)
+        ));
+        assert!(is_canary_requested(
+            &by_cookie,
+            Some(&header),
+            Some(&cookie)
+        ));
+        assert!(!is_canary_requested(
+            &by_other_value,
+            Some(&header),
+            Some(&cookie)
+        ));
+        assert!(!is_canary_requested(&by_header, None, Some(&cookie)));
+    }
+}
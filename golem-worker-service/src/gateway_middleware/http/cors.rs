@@ -18,8 +18,18 @@ use bigdecimal::BigDecimal;
 use http::header::*;
 use http::Method;
 use poem_openapi::Object;
-use rib::{Expr, GetLiteralValue, RibCompiler, RibInput, TypeName};
+use rib::{Expr, GetLiteralValue, RibCompiler, RibInput, RibInterpreterLimits, TypeName};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// The pre-flight CORS response is evaluated when the API definition is created, before it is
+// deployed to any site, so instead of the Rib limits of a deployment a fixed budget applies
+fn cors_preflight_rib_limits() -> RibInterpreterLimits {
+    RibInterpreterLimits::default()
+        .with_max_instructions(10_000)
+        .with_max_value_size(64 * 1024)
+        .with_max_wall_time(Duration::from_secs(1))
+}
 
 // Make sure to store CORS headers as Vec<HeaderValue> and not as String
 // avoiding computation in the hot path
@@ -267,7 +277,12 @@ impl HttpCors {
             .map_err(|err| format!("Rib compilation for cors-preflight response. {err}"))?;
 
         let rib_input = RibInput::default();
-        let evaluate_rib = rib::interpret_pure(compiled_expr.byte_code, rib_input, None);
+        let evaluate_rib = rib::interpret_pure_with_limits(
+            compiled_expr.byte_code,
+            rib_input,
+            None,
+            cors_preflight_rib_limits(),
+        );
 
         let result = futures::executor::block_on(evaluate_rib).map_err(|err| {
             format!("Failed to evaluate Rib script to form pre-flight CORS {err}")
@@ -16,8 +16,10 @@ pub use authentication::*;
 pub use cors::*;
 pub use http_middleware::*;
 pub use middleware_error::*;
+pub use transform::*;
 
 mod authentication;
 mod cors;
 mod http_middleware;
 mod middleware_error;
+mod transform;
@@ -0,0 +1,941 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_execution::request::RichRequest;
+use crate::gateway_rib_compiler::DefaultWorkerServiceRibCompiler;
+use crate::gateway_rib_compiler::WorkerServiceRibCompiler;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{Value, ValueAndType};
+use http::header::{CONTENT_SECURITY_POLICY, LOCATION, STRICT_TRANSPORT_SECURITY};
+use http::uri::PathAndQuery;
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode, Uri};
+use poem::Body;
+use poem_openapi::Object;
+use rib::{CompilerOutput, Expr, RibByteCode, RibInputTypeInfo};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+// A declarative rewrite of the requests and responses of a route.
+//
+// The request transform is a Rib script over `request`, evaluated before the binding,
+// resulting in a record that sets or removes headers, rewrites the path or the body,
+// or short-circuits the request with a redirect. Example:
+//
+//   `{headers: {x-tenant: request.path.tenant}, remove-headers: ["cookie"], path: "/v2/users"}`
+//
+// The response transform is a Rib script over `request` and `response` (`status` and `headers`),
+// evaluated after the binding, resulting in a record that sets or removes headers,
+// or rewrites the status or the body of the response. The response headers are options,
+// `none` when the response does not have them. If the response transform fails,
+// the failure is logged and the response of the binding is returned untransformed.
+//
+// Security headers are added to every response of the route, unless the binding
+// or the response transform already set them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HttpTransform {
+    pub request: Option<Expr>,
+    pub response: Option<Expr>,
+    pub security_headers: SecurityHeaders,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SecurityHeaders {
+    pub strict_transport_security: Option<StrictTransportSecurity>,
+    pub content_security_policy: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct StrictTransportSecurity {
+    pub max_age_seconds: u64,
+    #[serde(default)]
+    #[oai(default)]
+    pub include_subdomains: bool,
+    #[serde(default)]
+    #[oai(default)]
+    pub preload: bool,
+}
+
+impl StrictTransportSecurity {
+    pub fn header_value(&self) -> String {
+        let mut value = format!("max-age={}", self.max_age_seconds);
+
+        if self.include_subdomains {
+            value.push_str("; includeSubDomains");
+        }
+
+        if self.preload {
+            value.push_str("; preload");
+        }
+
+        value
+    }
+}
+
+// The transform of a route, with its Rib scripts compiled and type checked
+// during API definition registration
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpTransformMiddleware {
+    pub request_transform: Option<TransformCompiled>,
+    pub response_transform: Option<TransformCompiled>,
+    pub security_headers: SecurityHeaders,
+}
+
+impl HttpTransformMiddleware {
+    pub fn from_http_transform(transform: &HttpTransform) -> Result<Self, String> {
+        let request_transform = transform
+            .request
+            .as_ref()
+            .map(TransformCompiled::from_request_transform)
+            .transpose()?;
+
+        let response_transform = transform
+            .response
+            .as_ref()
+            .map(TransformCompiled::from_response_transform)
+            .transpose()?;
+
+        Ok(HttpTransformMiddleware {
+            request_transform,
+            response_transform,
+            security_headers: transform.security_headers.clone(),
+        })
+    }
+
+    pub fn add_security_headers(&self, response: &mut poem::Response) {
+        let headers = response.headers_mut();
+
+        if let Some(hsts) = &self.security_headers.strict_transport_security {
+            if let Ok(value) = HeaderValue::from_str(&hsts.header_value()) {
+                headers.entry(STRICT_TRANSPORT_SECURITY).or_insert(value);
+            }
+        }
+
+        if let Some(csp) = &self.security_headers.content_security_policy {
+            if let Ok(value) = HeaderValue::from_str(csp) {
+                headers.entry(CONTENT_SECURITY_POLICY).or_insert(value);
+            }
+        }
+    }
+}
+
+impl From<HttpTransformMiddleware> for HttpTransform {
+    fn from(value: HttpTransformMiddleware) -> Self {
+        HttpTransform {
+            request: value.request_transform.map(|compiled| compiled.transform),
+            response: value.response_transform.map(|compiled| compiled.transform),
+            security_headers: value.security_headers,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformCompiled {
+    pub transform: Expr,
+    pub compiled_transform: RibByteCode,
+    pub rib_input: RibInputTypeInfo,
+}
+
+impl TransformCompiled {
+    // Transforms are evaluated by the gateway itself,
+    // and hence they cannot depend on component exports
+    pub fn from_request_transform(transform: &Expr) -> Result<Self, String> {
+        let compiled = DefaultWorkerServiceRibCompiler::compile(transform, &[])
+            .map_err(|err| format!("Invalid request transform: {err}"))?;
+
+        Self::from_compiler_output(
+            transform,
+            compiled,
+            "request",
+            &["request"],
+            REQUEST_TRANSFORM_FIELDS,
+        )
+    }
+
+    pub fn from_response_transform(transform: &Expr) -> Result<Self, String> {
+        let compiled = DefaultWorkerServiceRibCompiler::compile_with_response(transform, &[])
+            .map_err(|err| format!("Invalid response transform: {err}"))?;
+
+        if let Some(AnalysedType::Record(response)) = compiled.rib_input_type_info.get("response") {
+            if let Some(field) = response
+                .fields
+                .iter()
+                .find(|field| field.name != "status" && field.name != "headers")
+            {
+                return Err(format!(
+                    "Invalid response transform: unknown input response.{}",
+                    field.name
+                ));
+            }
+        }
+
+        Self::from_compiler_output(
+            transform,
+            compiled,
+            "response",
+            &["request", "response"],
+            RESPONSE_TRANSFORM_FIELDS,
+        )
+    }
+
+    fn from_compiler_output(
+        transform: &Expr,
+        compiled: CompilerOutput,
+        kind: &str,
+        allowed_inputs: &[&str],
+        output_fields: &[(&str, TransformField)],
+    ) -> Result<Self, String> {
+        if let Some(input) = compiled
+            .rib_input_type_info
+            .types
+            .keys()
+            .find(|input| !allowed_inputs.contains(&input.as_str()))
+        {
+            return Err(format!("Invalid {kind} transform: unknown input {input}"));
+        }
+
+        let output_type = compiled
+            .rib_output_type_info
+            .map(|output| output.analysed_type)
+            .ok_or(format!("Invalid {kind} transform: unknown result type"))?;
+
+        validate_output_type(&output_type, output_fields)
+            .map_err(|err| format!("Invalid {kind} transform: {err}"))?;
+
+        Ok(TransformCompiled {
+            transform: transform.clone(),
+            compiled_transform: compiled.byte_code,
+            rib_input: compiled.rib_input_type_info,
+        })
+    }
+}
+
+const REQUEST_TRANSFORM_FIELDS: &[(&str, TransformField)] = &[
+    ("headers", TransformField::Headers),
+    ("remove-headers", TransformField::HeaderNames),
+    ("path", TransformField::Text),
+    ("body", TransformField::Text),
+    ("redirect", TransformField::Text),
+    ("redirect-status", TransformField::StatusCode),
+];
+
+const RESPONSE_TRANSFORM_FIELDS: &[(&str, TransformField)] = &[
+    ("headers", TransformField::Headers),
+    ("remove-headers", TransformField::HeaderNames),
+    ("status", TransformField::StatusCode),
+    ("body", TransformField::Text),
+];
+
+// The fields of the record a transform results in.
+// Any of them can also be an option, and `none` leaves the request or response as it is.
+#[derive(Debug, Clone, Copy)]
+enum TransformField {
+    Text,
+    StatusCode,
+    // A record of header values, keyed by the header names
+    Headers,
+    HeaderNames,
+}
+
+impl TransformField {
+    fn accepts(&self, typ: &AnalysedType) -> bool {
+        match (self, typ) {
+            (_, AnalysedType::Option(option)) => self.accepts(&option.inner),
+            (TransformField::Text, AnalysedType::Str(_)) => true,
+            (TransformField::StatusCode, typ) => is_integer(typ),
+            (TransformField::Headers, AnalysedType::Record(record)) => record
+                .fields
+                .iter()
+                .all(|field| TransformField::Text.accepts(&field.typ)),
+            (TransformField::HeaderNames, AnalysedType::List(list)) => {
+                matches!(list.inner.as_ref(), AnalysedType::Str(_))
+            }
+            _ => false,
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            TransformField::Text => "a string",
+            TransformField::StatusCode => "an integer",
+            TransformField::Headers => "a record of strings",
+            TransformField::HeaderNames => "a list of strings",
+        }
+    }
+}
+
+fn is_integer(typ: &AnalysedType) -> bool {
+    matches!(
+        typ,
+        AnalysedType::U8(_)
+            | AnalysedType::U16(_)
+            | AnalysedType::U32(_)
+            | AnalysedType::U64(_)
+            | AnalysedType::S8(_)
+            | AnalysedType::S16(_)
+            | AnalysedType::S32(_)
+            | AnalysedType::S64(_)
+    )
+}
+
+fn validate_output_type(
+    typ: &AnalysedType,
+    output_fields: &[(&str, TransformField)],
+) -> Result<(), String> {
+    let AnalysedType::Record(record) = typ else {
+        return Err("the result is expected to be a record".to_string());
+    };
+
+    for field in record.fields.iter() {
+        let (_, expected) = output_fields
+            .iter()
+            .find(|(name, _)| *name == field.name)
+            .ok_or(format!(
+                "unknown field `{}`, expected one of {}",
+                field.name,
+                output_fields
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))?;
+
+        if !expected.accepts(&field.typ) {
+            return Err(format!(
+                "`{}` is expected to be {}",
+                field.name,
+                expected.description()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// The rewrites resulting from evaluating the request transform of a route
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RequestTransformation {
+    pub set_headers: Vec<(String, String)>,
+    pub remove_headers: Vec<String>,
+    pub path: Option<String>,
+    pub body: Option<String>,
+    pub redirect: Option<String>,
+    pub redirect_status: Option<u16>,
+}
+
+impl RequestTransformation {
+    pub fn from_rib_result(result: Option<ValueAndType>) -> Result<Self, String> {
+        let mut transformation = RequestTransformation::default();
+
+        for (name, value, typ) in output_fields(result)? {
+            match name.as_str() {
+                "headers" => transformation.set_headers = headers(&value, &typ),
+                "remove-headers" => transformation.remove_headers = header_names(&value),
+                "path" => transformation.path = text(&value),
+                "body" => transformation.body = text(&value),
+                "redirect" => transformation.redirect = text(&value),
+                "redirect-status" => transformation.redirect_status = status_code(&value)?,
+                _ => {}
+            }
+        }
+
+        Ok(transformation)
+    }
+
+    // Applies the rewrites to the request, unless it is short-circuited with
+    // a redirect, in which case the response of the redirect is returned
+    pub fn apply(self, request: &mut RichRequest) -> Result<Option<poem::Response>, String> {
+        if let Some(location) = self.redirect {
+            let status = StatusCode::from_u16(self.redirect_status.unwrap_or(302))
+                .map_err(|err| err.to_string())?;
+
+            if !status.is_redirection() {
+                return Err(format!("{status} is not a redirect status"));
+            }
+
+            let location = HeaderValue::from_str(&location)
+                .map_err(|err| format!("Invalid redirect location {location}: {err}"))?;
+
+            return Ok(Some(
+                poem::Response::builder()
+                    .status(status)
+                    .header(LOCATION, location)
+                    .finish(),
+            ));
+        }
+
+        rewrite_headers(
+            request.underlying.headers_mut(),
+            &self.remove_headers,
+            &self.set_headers,
+        )?;
+
+        if let Some(path) = self.path {
+            let uri = request.underlying.uri().clone();
+            *request.underlying.uri_mut() = rewrite_path(uri, &path)?;
+        }
+
+        if let Some(body) = self.body {
+            request.underlying.set_body(Body::from_string(body));
+        }
+
+        Ok(None)
+    }
+}
+
+// The rewrites resulting from evaluating the response transform of a route
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResponseTransformation {
+    pub set_headers: Vec<(String, String)>,
+    pub remove_headers: Vec<String>,
+    pub status: Option<u16>,
+    pub body: Option<String>,
+}
+
+impl ResponseTransformation {
+    pub fn from_rib_result(result: Option<ValueAndType>) -> Result<Self, String> {
+        let mut transformation = ResponseTransformation::default();
+
+        for (name, value, typ) in output_fields(result)? {
+            match name.as_str() {
+                "headers" => transformation.set_headers = headers(&value, &typ),
+                "remove-headers" => transformation.remove_headers = header_names(&value),
+                "status" => transformation.status = status_code(&value)?,
+                "body" => transformation.body = text(&value),
+                _ => {}
+            }
+        }
+
+        Ok(transformation)
+    }
+
+    // Leaves the response as it is if the transformation is invalid
+    pub fn apply(self, response: &mut poem::Response) -> Result<(), String> {
+        let status = self
+            .status
+            .map(|status| StatusCode::from_u16(status).map_err(|err| err.to_string()))
+            .transpose()?;
+
+        rewrite_headers(
+            response.headers_mut(),
+            &self.remove_headers,
+            &self.set_headers,
+        )?;
+
+        if let Some(status) = status {
+            response.set_status(status);
+        }
+
+        if let Some(body) = self.body {
+            response.set_body(Body::from_string(body));
+        }
+
+        Ok(())
+    }
+}
+
+// The status and the headers of a response, as looked up by `response` in a response transform
+pub fn response_rib_input(
+    response: &poem::Response,
+    response_type: &AnalysedType,
+) -> Result<ValueAndType, String> {
+    let AnalysedType::Record(record) = response_type else {
+        return Err("unsupported type for `response`".to_string());
+    };
+
+    let mut values = vec![];
+
+    for field in record.fields.iter() {
+        match (field.name.as_str(), &field.typ) {
+            ("status", typ) => values.push(integer(response.status().as_u16(), typ)?),
+            ("headers", AnalysedType::Record(headers)) => {
+                let mut header_values = vec![];
+
+                for header in headers.fields.iter() {
+                    let value = response
+                        .headers()
+                        .get(header.name.as_str())
+                        .and_then(|value| value.to_str().ok())
+                        .map(|value| Value::String(value.to_string()));
+
+                    match (&header.typ, value) {
+                        (AnalysedType::Option(_), value) => {
+                            header_values.push(Value::Option(value.map(Box::new)))
+                        }
+                        (_, Some(value)) => header_values.push(value),
+                        (_, None) => {
+                            return Err(format!("missing response header: {}", header.name))
+                        }
+                    }
+                }
+
+                values.push(Value::Record(header_values));
+            }
+            (name, _) => return Err(format!("unknown input response.{name}")),
+        }
+    }
+
+    Ok(ValueAndType::new(
+        Value::Record(values),
+        response_type.clone(),
+    ))
+}
+
+fn integer(value: u16, typ: &AnalysedType) -> Result<Value, String> {
+    match typ {
+        AnalysedType::U16(_) => Ok(Value::U16(value)),
+        AnalysedType::U32(_) => Ok(Value::U32(value as u32)),
+        AnalysedType::U64(_) => Ok(Value::U64(value as u64)),
+        AnalysedType::S32(_) => Ok(Value::S32(value as i32)),
+        AnalysedType::S64(_) => Ok(Value::S64(value as i64)),
+        _ => Err("response.status is expected to be an integer of at least 16 bits".to_string()),
+    }
+}
+
+fn output_fields(
+    result: Option<ValueAndType>,
+) -> Result<Vec<(String, Value, AnalysedType)>, String> {
+    match result {
+        Some(ValueAndType {
+            value: Value::Record(values),
+            typ: AnalysedType::Record(record),
+        }) => Ok(record
+            .fields
+            .into_iter()
+            .zip(values)
+            .map(|(field, value)| (field.name, value, field.typ))
+            .collect()),
+        // A transform that results in nothing leaves the request or response as it is
+        None => Ok(vec![]),
+        Some(_) => Err("Transform is expected to result in a record".to_string()),
+    }
+}
+
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Option(Some(value)) => text(value),
+        _ => None,
+    }
+}
+
+fn status_code(value: &Value) -> Result<Option<u16>, String> {
+    let status = match value {
+        Value::Option(Some(value)) => return status_code(value),
+        Value::U8(value) => *value as i64,
+        Value::U16(value) => *value as i64,
+        Value::U32(value) => *value as i64,
+        Value::U64(value) => i64::try_from(*value).unwrap_or(i64::MAX),
+        Value::S8(value) => *value as i64,
+        Value::S16(value) => *value as i64,
+        Value::S32(value) => *value as i64,
+        Value::S64(value) => *value,
+        _ => return Ok(None),
+    };
+
+    u16::try_from(status)
+        .map(Some)
+        .map_err(|_| format!("Invalid status code {status}"))
+}
+
+fn headers(value: &Value, typ: &AnalysedType) -> Vec<(String, String)> {
+    match (value, typ) {
+        (Value::Option(Some(value)), AnalysedType::Option(option)) => headers(value, &option.inner),
+        (Value::Record(values), AnalysedType::Record(record)) => record
+            .fields
+            .iter()
+            .zip(values)
+            .filter_map(|(field, value)| text(value).map(|value| (field.name.clone(), value)))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn header_names(value: &Value) -> Vec<String> {
+    match value {
+        Value::Option(Some(value)) => header_names(value),
+        Value::List(values) => values.iter().filter_map(text).collect(),
+        _ => vec![],
+    }
+}
+
+fn rewrite_headers(
+    headers: &mut HeaderMap,
+    remove_headers: &[String],
+    set_headers: &[(String, String)],
+) -> Result<(), String> {
+    // All the headers are validated before changing any of them
+    let set_headers = set_headers
+        .iter()
+        .map(|(name, value)| {
+            let name = HeaderName::from_str(name)
+                .map_err(|err| format!("Invalid header name {name}: {err}"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|err| format!("Invalid value for header {name}: {err}"))?;
+            Ok((name, value))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    for name in remove_headers {
+        headers.remove(name.as_str());
+    }
+
+    for (name, value) in set_headers {
+        headers.insert(name, value);
+    }
+
+    Ok(())
+}
+
+// Replaces the path of the uri, keeping its query
+fn rewrite_path(uri: Uri, path: &str) -> Result<Uri, String> {
+    let path_and_query = match uri.query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path.to_string(),
+    };
+
+    let mut parts = uri.into_parts();
+    parts.path_and_query = Some(
+        PathAndQuery::from_str(&path_and_query)
+            .map_err(|err| format!("Invalid path {path}: {err}"))?,
+    );
+
+    Uri::from_parts(parts).map_err(|err| format!("Invalid path {path}: {err}"))
+}
+
+impl TryFrom<HttpTransformMiddleware> for golem_api_grpc::proto::golem::apidefinition::Transform {
+    type Error = String;
+
+    fn try_from(value: HttpTransformMiddleware) -> Result<Self, Self::Error> {
+        Ok(golem_api_grpc::proto::golem::apidefinition::Transform {
+            request: value.request_transform.map(TryInto::try_into).transpose()?,
+            response: value
+                .response_transform
+                .map(TryInto::try_into)
+                .transpose()?,
+            strict_transport_security: value.security_headers.strict_transport_security.map(
+                |hsts| golem_api_grpc::proto::golem::apidefinition::StrictTransportSecurity {
+                    max_age_seconds: hsts.max_age_seconds,
+                    include_subdomains: hsts.include_subdomains,
+                    preload: hsts.preload,
+                },
+            ),
+            content_security_policy: value.security_headers.content_security_policy,
+        })
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::Transform> for HttpTransformMiddleware {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::Transform,
+    ) -> Result<Self, Self::Error> {
+        Ok(HttpTransformMiddleware {
+            request_transform: value.request.map(TryInto::try_into).transpose()?,
+            response_transform: value.response.map(TryInto::try_into).transpose()?,
+            security_headers: SecurityHeaders {
+                strict_transport_security: value.strict_transport_security.map(|hsts| {
+                    StrictTransportSecurity {
+                        max_age_seconds: hsts.max_age_seconds,
+                        include_subdomains: hsts.include_subdomains,
+                        preload: hsts.preload,
+                    }
+                }),
+                content_security_policy: value.content_security_policy,
+            },
+        })
+    }
+}
+
+impl TryFrom<TransformCompiled> for golem_api_grpc::proto::golem::apidefinition::TransformExpr {
+    type Error = String;
+
+    fn try_from(value: TransformCompiled) -> Result<Self, Self::Error> {
+        Ok(golem_api_grpc::proto::golem::apidefinition::TransformExpr {
+            expr: Some(value.transform.into()),
+            compiled_expr: Some(value.compiled_transform.try_into()?),
+            rib_input: Some(value.rib_input.into()),
+        })
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::TransformExpr> for TransformCompiled {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::TransformExpr,
+    ) -> Result<Self, Self::Error> {
+        Ok(TransformCompiled {
+            transform: Expr::try_from(value.expr.ok_or("Missing transform expr")?)?,
+            compiled_transform: RibByteCode::try_from(
+                value
+                    .compiled_expr
+                    .ok_or("Missing compiled transform expr")?,
+            )?,
+            rib_input: RibInputTypeInfo::try_from(
+                value.rib_input.ok_or("Missing transform rib input")?,
+            )?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_wasm_ast::analysis::analysed_type::{field, list, option, record, str, u16};
+    use test_r::test;
+
+    fn compile_request_transform(rib: &str) -> Result<TransformCompiled, String> {
+        TransformCompiled::from_request_transform(&rib::from_string(rib).unwrap())
+    }
+
+    #[test]
+    fn request_transform_is_type_checked() {
+        assert!(compile_request_transform(
+            r#"{headers: {x-tenant: request.path.tenant}, remove-headers: ["cookie"]}"#
+        )
+        .is_ok());
+
+        let unknown_field = compile_request_transform(r#"{method: "GET"}"#).unwrap_err();
+        assert!(unknown_field.contains("unknown field `method`"));
+
+        let not_a_record = compile_request_transform(r#""/v2/users""#).unwrap_err();
+        assert!(not_a_record.contains("expected to be a record"));
+    }
+
+    #[test]
+    fn request_transform_cannot_look_up_response() {
+        let result = compile_request_transform(r#"{headers: {x-etag: response.headers.etag}}"#);
+
+        assert!(result.is_err());
+
+        let response_transform = TransformCompiled::from_response_transform(
+            &rib::from_string(r#"{headers: {x-etag: response.headers.etag}}"#).unwrap(),
+        );
+
+        assert!(response_transform.is_ok());
+    }
+
+    #[test]
+    fn request_transformation_from_rib_result() {
+        let typ = record(vec![
+            field("headers", record(vec![field("x-tenant", str())])),
+            field("remove-headers", list(str())),
+            field("path", option(str())),
+        ]);
+
+        let value = Value::Record(vec![
+            Value::Record(vec![Value::String("acme".to_string())]),
+            Value::List(vec![Value::String("cookie".to_string())]),
+            Value::Option(Some(Box::new(Value::String("/v2/users".to_string())))),
+        ]);
+
+        let transformation =
+            RequestTransformation::from_rib_result(Some(ValueAndType::new(value, typ))).unwrap();
+
+        assert_eq!(
+            transformation,
+            RequestTransformation {
+                set_headers: vec![("x-tenant".to_string(), "acme".to_string())],
+                remove_headers: vec!["cookie".to_string()],
+                path: Some("/v2/users".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn request_transformation_rewrites_headers_and_path() {
+        let mut request = RichRequest::new(
+            poem::Request::builder()
+                .uri(Uri::from_static("http://localhost/users?limit=10"))
+                .header("Cookie", "session=abc")
+                .finish(),
+        );
+
+        let transformation = RequestTransformation {
+            set_headers: vec![("x-tenant".to_string(), "acme".to_string())],
+            remove_headers: vec!["cookie".to_string()],
+            path: Some("/v2/users".to_string()),
+            ..Default::default()
+        };
+
+        let redirect = transformation.apply(&mut request).unwrap();
+
+        assert!(redirect.is_none());
+        assert_eq!(request.underlying.uri().path(), "/v2/users");
+        assert_eq!(request.underlying.uri().query(), Some("limit=10"));
+        assert_eq!(request.headers().get("x-tenant").unwrap(), "acme");
+        assert!(request.headers().get("cookie").is_none());
+    }
+
+    #[test]
+    fn request_transformation_short_circuits_with_redirect() {
+        let mut request = RichRequest::new(poem::Request::builder().finish());
+
+        let transformation = RequestTransformation {
+            redirect: Some("https://golem.cloud/".to_string()),
+            redirect_status: Some(301),
+            ..Default::default()
+        };
+
+        let response = transformation.apply(&mut request).unwrap().unwrap();
+
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            response.headers().get(LOCATION).unwrap(),
+            "https://golem.cloud/"
+        );
+
+        let not_a_redirect = RequestTransformation {
+            redirect: Some("https://golem.cloud/".to_string()),
+            redirect_status: Some(200),
+            ..Default::default()
+        };
+
+        assert!(not_a_redirect.apply(&mut request).is_err());
+    }
+
+    #[test]
+    fn response_rib_input_has_status_and_headers() {
+        let response = poem::Response::builder()
+            .status(StatusCode::CREATED)
+            .header("ETag", "abc")
+            .finish();
+
+        let typ = record(vec![
+            field("status", u16()),
+            field("headers", record(vec![field("etag", str())])),
+        ]);
+
+        let input = response_rib_input(&response, &typ).unwrap();
+
+        assert_eq!(
+            input.value,
+            Value::Record(vec![
+                Value::U16(201),
+                Value::Record(vec![Value::String("abc".to_string())])
+            ])
+        );
+    }
+
+    #[test]
+    fn response_transform_looks_up_optional_headers() {
+        let transform = TransformCompiled::from_response_transform(
+            &rib::from_string(
+                r#"
+                  let etag = response.headers.etag;
+                  let value = match etag { some(value) => value, none => "missing" };
+                  {headers: {x-etag: value}}
+                "#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            transform.rib_input.types.get("response"),
+            Some(&record(vec![field(
+                "headers",
+                record(vec![field("etag", option(str()))])
+            )]))
+        );
+    }
+
+    #[test]
+    fn response_rib_input_with_missing_header() {
+        let response = poem::Response::builder().header("ETag", "abc").finish();
+
+        let optional_headers = record(vec![field(
+            "headers",
+            record(vec![
+                field("etag", option(str())),
+                field("location", option(str())),
+            ]),
+        )]);
+
+        let input = response_rib_input(&response, &optional_headers).unwrap();
+
+        assert_eq!(
+            input.value,
+            Value::Record(vec![Value::Record(vec![
+                Value::Option(Some(Box::new(Value::String("abc".to_string())))),
+                Value::Option(None)
+            ])])
+        );
+
+        let required_headers = record(vec![field(
+            "headers",
+            record(vec![field("location", str())]),
+        )]);
+
+        assert_eq!(
+            response_rib_input(&response, &required_headers),
+            Err("missing response header: location".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_response_transformation_leaves_the_response_as_it_is() {
+        let mut response = poem::Response::builder()
+            .status(StatusCode::OK)
+            .header("ETag", "abc")
+            .finish();
+
+        let transformation = ResponseTransformation {
+            set_headers: vec![("x-version".to_string(), "2".to_string())],
+            remove_headers: vec!["etag".to_string()],
+            status: Some(1000),
+            body: None,
+        };
+
+        assert!(transformation.apply(&mut response).is_err());
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get("etag").unwrap(), "abc");
+        assert!(response.headers().get("x-version").is_none());
+    }
+
+    #[test]
+    fn security_headers_do_not_override_the_response() {
+        let middleware = HttpTransformMiddleware {
+            request_transform: None,
+            response_transform: None,
+            security_headers: SecurityHeaders {
+                strict_transport_security: Some(StrictTransportSecurity {
+                    max_age_seconds: 31536000,
+                    include_subdomains: true,
+                    preload: false,
+                }),
+                content_security_policy: Some("default-src 'self'".to_string()),
+            },
+        };
+
+        let mut response = poem::Response::builder()
+            .header(CONTENT_SECURITY_POLICY, "default-src 'none'")
+            .finish();
+
+        middleware.add_security_headers(&mut response);
+
+        assert_eq!(
+            response.headers().get(STRICT_TRANSPORT_SECURITY).unwrap(),
+            "max-age=31536000; includeSubDomains"
+        );
+        assert_eq!(
+            response.headers().get(CONTENT_SECURITY_POLICY).unwrap(),
+            "default-src 'none'"
+        );
+    }
+}
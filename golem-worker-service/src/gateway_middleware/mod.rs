@@ -66,6 +66,9 @@ impl HttpMiddlewares {
                         }
                     }
                 }
+                // Transforms are evaluated with Rib once the rest of the middlewares pass through,
+                // as they may look up the auth details of the request
+                HttpMiddleware::Transform(_) => {}
             }
         }
 
@@ -84,6 +87,9 @@ impl HttpMiddlewares {
                     cors.add_header_in_response(response);
                 }
                 HttpMiddleware::AuthenticateRequest(_) => {}
+                HttpMiddleware::Transform(transform) => {
+                    transform.add_security_headers(response);
+                }
             }
         }
 
@@ -101,6 +107,10 @@ impl HttpMiddlewares {
     pub fn get_http_authentication_middleware(&self) -> Option<HttpAuthenticationMiddleware> {
         self.0.iter().find_map(|m| m.get_http_authentication())
     }
+
+    pub fn get_transform_middleware(&self) -> Option<HttpTransformMiddleware> {
+        self.0.iter().find_map(|m| m.get_transform())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -125,6 +135,11 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::Middleware> for HttpMi
             http_middlewares.push(HttpMiddleware::authenticate_request(auth))
         }
 
+        if let Some(transform) = value.transform {
+            let transform = HttpTransformMiddleware::try_from(transform)?;
+            http_middlewares.push(HttpMiddleware::transform(transform))
+        }
+
         Ok(HttpMiddlewares(http_middlewares))
     }
 }
@@ -134,6 +149,7 @@ impl TryFrom<HttpMiddlewares> for golem_api_grpc::proto::golem::apidefinition::M
     fn try_from(value: HttpMiddlewares) -> Result<Self, String> {
         let mut cors = None;
         let mut auth = None;
+        let mut transform = None;
 
         for http_middleware in value.0.iter() {
             match http_middleware {
@@ -143,12 +159,16 @@ impl TryFrom<HttpMiddlewares> for golem_api_grpc::proto::golem::apidefinition::M
                 HttpMiddleware::AuthenticateRequest(http_request_authentication) => {
                     auth = Some(golem_api_grpc::proto::golem::apidefinition::SecurityWithProviderMetadata::try_from(http_request_authentication.security_scheme_with_metadata.clone())?)
                 }
+                HttpMiddleware::Transform(http_transform) => {
+                    transform = Some(golem_api_grpc::proto::golem::apidefinition::Transform::try_from(http_transform.as_ref().clone())?)
+                }
             }
         }
 
         Ok(golem_api_grpc::proto::golem::apidefinition::Middleware {
             cors,
             http_authentication: auth,
+            transform,
         })
     }
 }
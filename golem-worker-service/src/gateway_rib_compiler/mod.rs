@@ -24,6 +24,13 @@ pub trait WorkerServiceRibCompiler {
         rib: &Expr,
         component_dependency: &[ComponentDependency],
     ) -> Result<CompilerOutput, RibCompilationError>;
+
+    // Compiles a Rib script that is evaluated after the response is formed,
+    // and can therefore look up `response` along with `request`
+    fn compile_with_response(
+        rib: &Expr,
+        component_dependency: &[ComponentDependency],
+    ) -> Result<CompilerOutput, RibCompilationError>;
 }
 
 pub struct DefaultWorkerServiceRibCompiler;
@@ -33,33 +40,59 @@ impl WorkerServiceRibCompiler for DefaultWorkerServiceRibCompiler {
         rib: &Expr,
         component_dependency: &[ComponentDependency],
     ) -> Result<CompilerOutput, RibCompilationError> {
-        let rib_input_spec = vec![
-            GlobalVariableTypeSpec::new(
-                "request",
-                Path::from_elems(vec!["path"]),
-                InferredType::string(),
-            ),
-            GlobalVariableTypeSpec::new(
-                "request",
-                Path::from_elems(vec!["query"]),
-                InferredType::string(),
-            ),
-            GlobalVariableTypeSpec::new(
-                "request",
-                Path::from_elems(vec!["headers"]),
-                InferredType::string(),
-            ),
-            GlobalVariableTypeSpec::new(
-                "request",
-                Path::from_elems(vec!["header"]),
-                InferredType::string(),
-            ),
-        ];
+        compile_with_input_spec(rib, component_dependency, request_input_spec())
+    }
 
-        let compiler_config = RibCompilerConfig::new(component_dependency.to_vec(), rib_input_spec);
+    fn compile_with_response(
+        rib: &Expr,
+        component_dependency: &[ComponentDependency],
+    ) -> Result<CompilerOutput, RibCompilationError> {
+        let mut rib_input_spec = request_input_spec();
 
-        let compiler = RibCompiler::new(compiler_config);
+        // A response does not necessarily have the headers the script looks up
+        rib_input_spec.push(GlobalVariableTypeSpec::new(
+            "response",
+            Path::from_elems(vec!["headers"]),
+            InferredType::option(InferredType::string()),
+        ));
 
-        compiler.compile(rib.clone())
+        compile_with_input_spec(rib, component_dependency, rib_input_spec)
     }
 }
+
+fn request_input_spec() -> Vec<GlobalVariableTypeSpec> {
+    vec![
+        GlobalVariableTypeSpec::new(
+            "request",
+            Path::from_elems(vec!["path"]),
+            InferredType::string(),
+        ),
+        GlobalVariableTypeSpec::new(
+            "request",
+            Path::from_elems(vec!["query"]),
+            InferredType::string(),
+        ),
+        GlobalVariableTypeSpec::new(
+            "request",
+            Path::from_elems(vec!["headers"]),
+            InferredType::string(),
+        ),
+        GlobalVariableTypeSpec::new(
+            "request",
+            Path::from_elems(vec!["header"]),
+            InferredType::string(),
+        ),
+    ]
+}
+
+fn compile_with_input_spec(
+    rib: &Expr,
+    component_dependency: &[ComponentDependency],
+    rib_input_spec: Vec<GlobalVariableTypeSpec>,
+) -> Result<CompilerOutput, RibCompilationError> {
+    let compiler_config = RibCompilerConfig::new(component_dependency.to_vec(), rib_input_spec);
+
+    let compiler = RibCompiler::new(compiler_config);
+
+    compiler.compile(rib.clone())
+}
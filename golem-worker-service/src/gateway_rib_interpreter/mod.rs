@@ -22,7 +22,8 @@ use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::ValueAndType;
 use rib::{
     ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName, InstructionId,
-    RibByteCode, RibComponentFunctionInvoke, RibFunctionInvokeResult, RibInput, RibResult,
+    RibByteCode, RibComponentFunctionInvoke, RibFunctionInvokeResult, RibInput,
+    RibInterpreterLimits, RibResult,
 };
 use std::fmt::Display;
 use std::sync::Arc;
@@ -37,6 +38,7 @@ use std::sync::Arc;
 pub trait WorkerServiceRibInterpreter: Send + Sync {
     // Evaluate a Rib byte against a specific worker.
     // RibByteCode may have actual function calls.
+    // The evaluation fails as soon as it exceeds any of the limits of the API deployment.
     async fn evaluate(
         &self,
         idempotency_key: Option<IdempotencyKey>,
@@ -44,6 +46,7 @@ pub trait WorkerServiceRibInterpreter: Send + Sync {
         rib_byte_code: RibByteCode,
         rib_input: RibInput,
         namespace: Namespace,
+        limits: RibInterpreterLimits,
     ) -> Result<RibResult, RibRuntimeError>;
 }
 
@@ -105,13 +108,15 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
         expr: RibByteCode,
         rib_input: RibInput,
         namespace: Namespace,
+        limits: RibInterpreterLimits,
     ) -> Result<RibResult, RibRuntimeError> {
         let worker_invoke_function =
             self.rib_invoke(idempotency_key, invocation_context, namespace);
 
-        let result = rib::interpret(expr, rib_input, worker_invoke_function, None)
-            .await
-            .map_err(|err| RibRuntimeError(err.to_string()))?;
+        let result =
+            rib::interpret_with_limits(expr, rib_input, worker_invoke_function, None, limits)
+                .await
+                .map_err(|err| RibRuntimeError(err.to_string()))?;
         Ok(result)
     }
 }
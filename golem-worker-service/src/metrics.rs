@@ -15,6 +15,37 @@
 use golem_common::golem_version;
 use golem_service_base::metrics::VERSION_INFO;
 use prometheus::*;
+use std::sync::LazyLock;
+
+pub static GATEWAY_TRAFFIC_SPLIT_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "gateway_traffic_split_requests_total",
+        "Number of requests routed by the traffic splits of API deployments",
+        &["api_definition_id", "variant"]
+    )
+    .unwrap()
+});
+
+pub static GATEWAY_TRAFFIC_SPLIT_RESPONSES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "gateway_traffic_split_responses_total",
+        "Number of responses to the requests routed by the traffic splits of API deployments",
+        &["api_definition_id", "variant", "status"]
+    )
+    .unwrap()
+});
+
+pub fn record_traffic_split_request(api_definition_id: &str, variant: &str) {
+    GATEWAY_TRAFFIC_SPLIT_REQUESTS
+        .with_label_values(&[api_definition_id, variant])
+        .inc();
+}
+
+pub fn record_traffic_split_response(api_definition_id: &str, variant: &str, status: u16) {
+    GATEWAY_TRAFFIC_SPLIT_RESPONSES
+        .with_label_values(&[api_definition_id, variant, &status.to_string()])
+        .inc();
+}
 
 pub fn register_all() -> Registry {
     VERSION_INFO.with_label_values(&[golem_version()]).inc();
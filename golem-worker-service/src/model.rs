@@ -173,6 +173,10 @@ pub struct ApiDeployment {
     pub project_id: ProjectId,
     pub site: ApiSite,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    // Warnings of the Rib scripts in the deployed API definitions, only set when deploying
+    #[serde(default)]
+    #[oai(default)]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
@@ -197,6 +201,118 @@ impl From<crate::gateway_api_deployment::ApiDeployment> for ApiDeployment {
             project_id: api_deployment.namespace.project_id.clone(),
             site: api_deployment.site.clone(),
             created_at: Some(api_deployment.created_at),
+            warnings: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDeploymentTrafficSplit {
+    pub api_definition_id: ApiDefinitionId,
+    pub canary_version: ApiVersion,
+    pub percentage: u8,
+    pub header: Option<TrafficSplitMatch>,
+    pub cookie: Option<TrafficSplitMatch>,
+    pub sticky_key: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TrafficSplitMatch {
+    pub name: String,
+    pub value: String,
+}
+
+impl TryFrom<ApiDeploymentTrafficSplit> for crate::gateway_api_deployment::TrafficSplit {
+    type Error = String;
+
+    fn try_from(value: ApiDeploymentTrafficSplit) -> Result<Self, Self::Error> {
+        let sticky_key = value
+            .sticky_key
+            .map(|sticky_key| rib::from_string(sticky_key).map_err(|err| err.to_string()))
+            .transpose()?;
+
+        Ok(Self {
+            api_definition_id: value.api_definition_id,
+            canary_version: value.canary_version,
+            percentage: value.percentage,
+            header: value.header.map(|header| header.into()),
+            cookie: value.cookie.map(|cookie| cookie.into()),
+            sticky_key,
+        })
+    }
+}
+
+impl From<crate::gateway_api_deployment::TrafficSplit> for ApiDeploymentTrafficSplit {
+    fn from(value: crate::gateway_api_deployment::TrafficSplit) -> Self {
+        Self {
+            api_definition_id: value.api_definition_id,
+            canary_version: value.canary_version,
+            percentage: value.percentage,
+            header: value.header.map(|header| header.into()),
+            cookie: value.cookie.map(|cookie| cookie.into()),
+            sticky_key: value.sticky_key.map(|sticky_key| sticky_key.to_string()),
+        }
+    }
+}
+
+/// Limits on the resources used by every Rib script evaluated to serve the requests of a site.
+/// A limit that is not set is not enforced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDeploymentRibLimits {
+    pub max_instructions: Option<u64>,
+    pub max_stack_size: Option<u64>,
+    /// Approximate size in bytes of any single value created by a script
+    pub max_value_size: Option<u64>,
+    pub max_function_invocations: Option<u64>,
+    pub max_wall_time_millis: Option<u64>,
+}
+
+impl From<ApiDeploymentRibLimits> for rib::RibInterpreterLimits {
+    fn from(value: ApiDeploymentRibLimits) -> Self {
+        Self {
+            max_instructions: value.max_instructions,
+            max_stack_size: value.max_stack_size.map(|x| x as usize),
+            max_value_size: value.max_value_size.map(|x| x as usize),
+            max_function_invocations: value.max_function_invocations,
+            max_wall_time: value
+                .max_wall_time_millis
+                .map(std::time::Duration::from_millis),
+        }
+    }
+}
+
+impl From<rib::RibInterpreterLimits> for ApiDeploymentRibLimits {
+    fn from(value: rib::RibInterpreterLimits) -> Self {
+        Self {
+            max_instructions: value.max_instructions,
+            max_stack_size: value.max_stack_size.map(|x| x as u64),
+            max_value_size: value.max_value_size.map(|x| x as u64),
+            max_function_invocations: value.max_function_invocations,
+            max_wall_time_millis: value.max_wall_time.map(|x| x.as_millis() as u64),
+        }
+    }
+}
+
+impl From<TrafficSplitMatch> for crate::gateway_api_deployment::TrafficSplitMatch {
+    fn from(value: TrafficSplitMatch) -> Self {
+        Self {
+            name: value.name,
+            value: value.value,
+        }
+    }
+}
+
+impl From<crate::gateway_api_deployment::TrafficSplitMatch> for TrafficSplitMatch {
+    fn from(value: crate::gateway_api_deployment::TrafficSplitMatch) -> Self {
+        Self {
+            name: value.name,
+            value: value.value,
         }
     }
 }
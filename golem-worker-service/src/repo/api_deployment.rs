@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::gateway_api_deployment::ApiSite;
+use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
+use crate::gateway_api_deployment::{ApiSite, ApiSiteString, TrafficSplit, TrafficSplitMatch};
 use crate::repo::api_definition::ApiDefinitionRecord;
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use async_trait::async_trait;
 use conditional_trait_gen::{trait_gen, when};
 use golem_service_base::db::{LabelledPoolTransaction, Pool};
 use golem_service_base::repo::RepoError;
+use rib::RibInterpreterLimits;
 use std::fmt::Display;
+use std::time::Duration;
 use tracing::{info_span, Instrument, Span};
 
 #[derive(sqlx::FromRow, Debug, Clone)]
@@ -52,6 +55,123 @@ impl ApiDeploymentRecord {
     }
 }
 
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct ApiDeploymentTrafficSplitRecord {
+    pub namespace: String,
+    pub site: String,
+    pub definition_id: String,
+    pub canary_version: String,
+    pub percentage: i32,
+    pub header_name: Option<String>,
+    pub header_value: Option<String>,
+    pub cookie_name: Option<String>,
+    pub cookie_value: Option<String>,
+    pub sticky_key: Option<String>,
+}
+
+impl ApiDeploymentTrafficSplitRecord {
+    pub fn new<Namespace: Display>(
+        namespace: Namespace,
+        site: &ApiSiteString,
+        traffic_split: &TrafficSplit,
+    ) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            site: site.to_string(),
+            definition_id: traffic_split.api_definition_id.0.clone(),
+            canary_version: traffic_split.canary_version.0.clone(),
+            percentage: traffic_split.percentage as i32,
+            header_name: traffic_split.header.as_ref().map(|x| x.name.clone()),
+            header_value: traffic_split.header.as_ref().map(|x| x.value.clone()),
+            cookie_name: traffic_split.cookie.as_ref().map(|x| x.name.clone()),
+            cookie_value: traffic_split.cookie.as_ref().map(|x| x.value.clone()),
+            sticky_key: traffic_split.sticky_key.as_ref().map(|x| x.to_string()),
+        }
+    }
+}
+
+impl TryFrom<ApiDeploymentTrafficSplitRecord> for TrafficSplit {
+    type Error = String;
+
+    fn try_from(value: ApiDeploymentTrafficSplitRecord) -> Result<Self, Self::Error> {
+        let percentage = u8::try_from(value.percentage)
+            .map_err(|_| format!("Invalid traffic split percentage: {}", value.percentage))?;
+
+        let sticky_key = value.sticky_key.map(rib::from_string).transpose()?;
+
+        Ok(TrafficSplit {
+            api_definition_id: ApiDefinitionId(value.definition_id),
+            canary_version: ApiVersion(value.canary_version),
+            percentage,
+            header: value
+                .header_name
+                .zip(value.header_value)
+                .map(|(name, value)| TrafficSplitMatch { name, value }),
+            cookie: value
+                .cookie_name
+                .zip(value.cookie_value)
+                .map(|(name, value)| TrafficSplitMatch { name, value }),
+            sticky_key,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct ApiDeploymentRibLimitsRecord {
+    pub namespace: String,
+    pub site: String,
+    pub max_instructions: Option<i64>,
+    pub max_stack_size: Option<i64>,
+    pub max_value_size: Option<i64>,
+    pub max_function_invocations: Option<i64>,
+    pub max_wall_time_millis: Option<i64>,
+}
+
+impl ApiDeploymentRibLimitsRecord {
+    pub fn new<Namespace: Display>(
+        namespace: Namespace,
+        site: &ApiSiteString,
+        limits: &RibInterpreterLimits,
+    ) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            site: site.to_string(),
+            max_instructions: limits.max_instructions.map(|x| x as i64),
+            max_stack_size: limits.max_stack_size.map(|x| x as i64),
+            max_value_size: limits.max_value_size.map(|x| x as i64),
+            max_function_invocations: limits.max_function_invocations.map(|x| x as i64),
+            max_wall_time_millis: limits.max_wall_time.map(|x| x.as_millis() as i64),
+        }
+    }
+}
+
+impl TryFrom<ApiDeploymentRibLimitsRecord> for RibInterpreterLimits {
+    type Error = String;
+
+    fn try_from(value: ApiDeploymentRibLimitsRecord) -> Result<Self, Self::Error> {
+        fn non_negative<T: TryFrom<i64>>(
+            name: &str,
+            value: Option<i64>,
+        ) -> Result<Option<T>, String> {
+            value
+                .map(|x| T::try_from(x).map_err(|_| format!("Invalid Rib limit {name}: {x}")))
+                .transpose()
+        }
+
+        Ok(RibInterpreterLimits {
+            max_instructions: non_negative("max_instructions", value.max_instructions)?,
+            max_stack_size: non_negative("max_stack_size", value.max_stack_size)?,
+            max_value_size: non_negative("max_value_size", value.max_value_size)?,
+            max_function_invocations: non_negative(
+                "max_function_invocations",
+                value.max_function_invocations,
+            )?,
+            max_wall_time: non_negative("max_wall_time_millis", value.max_wall_time_millis)?
+                .map(Duration::from_millis),
+        })
+    }
+}
+
 #[async_trait]
 pub trait ApiDeploymentRepo: Send + Sync {
     async fn create(
@@ -98,6 +218,49 @@ pub trait ApiDeploymentRepo: Send + Sync {
         &self,
         site: &str,
     ) -> Result<Vec<ApiDefinitionRecord>, RepoError>;
+
+    // There is at most one traffic split per API definition deployed to a site
+    async fn upsert_traffic_split(
+        &self,
+        record: &ApiDeploymentTrafficSplitRecord,
+    ) -> Result<(), RepoError>;
+
+    async fn delete_traffic_split(
+        &self,
+        namespace: &str,
+        site: &str,
+        definition_id: &str,
+    ) -> Result<bool, RepoError>;
+
+    async fn get_traffic_splits_by_site(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentTrafficSplitRecord>, RepoError>;
+
+    async fn get_all_traffic_splits_by_site(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentTrafficSplitRecord>, RepoError>;
+
+    // There is at most one set of Rib limits per site
+    async fn upsert_rib_limits(
+        &self,
+        record: &ApiDeploymentRibLimitsRecord,
+    ) -> Result<(), RepoError>;
+
+    async fn delete_rib_limits(&self, namespace: &str, site: &str) -> Result<bool, RepoError>;
+
+    async fn get_rib_limits(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentRibLimitsRecord>, RepoError>;
+
+    async fn get_all_rib_limits_by_site(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRibLimitsRecord>, RepoError>;
 }
 
 pub struct LoggedDeploymentRepo<Repo: ApiDeploymentRepo> {
@@ -116,6 +279,14 @@ impl<Repo: ApiDeploymentRepo> LoggedDeploymentRepo<Repo> {
             api_definition_id = api_definition_id
         )
     }
+
+    fn site_span(namespace: &str, site: &str) -> Span {
+        info_span!(
+            "API deployment repository",
+            namespace = namespace,
+            site = site
+        )
+    }
 }
 
 #[async_trait]
@@ -188,6 +359,75 @@ impl<Repo: ApiDeploymentRepo + Sync> ApiDeploymentRepo for LoggedDeploymentRepo<
     ) -> Result<Vec<ApiDefinitionRecord>, RepoError> {
         self.repo.get_all_definitions_by_site(site).await
     }
+
+    async fn upsert_traffic_split(
+        &self,
+        record: &ApiDeploymentTrafficSplitRecord,
+    ) -> Result<(), RepoError> {
+        self.repo
+            .upsert_traffic_split(record)
+            .instrument(Self::span(&record.namespace, &record.definition_id))
+            .await
+    }
+
+    async fn delete_traffic_split(
+        &self,
+        namespace: &str,
+        site: &str,
+        definition_id: &str,
+    ) -> Result<bool, RepoError> {
+        self.repo
+            .delete_traffic_split(namespace, site, definition_id)
+            .instrument(Self::span(namespace, definition_id))
+            .await
+    }
+
+    async fn get_traffic_splits_by_site(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentTrafficSplitRecord>, RepoError> {
+        self.repo.get_traffic_splits_by_site(namespace, site).await
+    }
+
+    async fn get_all_traffic_splits_by_site(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentTrafficSplitRecord>, RepoError> {
+        self.repo.get_all_traffic_splits_by_site(site).await
+    }
+
+    async fn upsert_rib_limits(
+        &self,
+        record: &ApiDeploymentRibLimitsRecord,
+    ) -> Result<(), RepoError> {
+        self.repo
+            .upsert_rib_limits(record)
+            .instrument(Self::site_span(&record.namespace, &record.site))
+            .await
+    }
+
+    async fn delete_rib_limits(&self, namespace: &str, site: &str) -> Result<bool, RepoError> {
+        self.repo
+            .delete_rib_limits(namespace, site)
+            .instrument(Self::site_span(namespace, site))
+            .await
+    }
+
+    async fn get_rib_limits(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentRibLimitsRecord>, RepoError> {
+        self.repo.get_rib_limits(namespace, site).await
+    }
+
+    async fn get_all_rib_limits_by_site(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRibLimitsRecord>, RepoError> {
+        self.repo.get_all_rib_limits_by_site(site).await
+    }
 }
 
 pub struct DbApiDeploymentRepo<DB: Pool> {
@@ -536,4 +776,187 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<golem_service_base::db::postgres:
             .fetch_all_as(query)
             .await
     }
+
+    async fn upsert_traffic_split(
+        &self,
+        record: &ApiDeploymentTrafficSplitRecord,
+    ) -> Result<(), RepoError> {
+        let query = sqlx::query(
+            r#"
+              INSERT INTO api_deployment_traffic_splits
+                (namespace, site, definition_id, canary_version, percentage, header_name, header_value, cookie_name, cookie_value, sticky_key)
+              VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+              ON CONFLICT (namespace, site, definition_id) DO UPDATE
+              SET canary_version = $4, percentage = $5, header_name = $6, header_value = $7, cookie_name = $8, cookie_value = $9, sticky_key = $10
+            "#,
+        )
+        .bind(record.namespace.clone())
+        .bind(record.site.clone())
+        .bind(record.definition_id.clone())
+        .bind(record.canary_version.clone())
+        .bind(record.percentage)
+        .bind(record.header_name.clone())
+        .bind(record.header_value.clone())
+        .bind(record.cookie_name.clone())
+        .bind(record.cookie_value.clone())
+        .bind(record.sticky_key.clone());
+
+        self.db_pool
+            .with_rw("api_deployment", "upsert_traffic_split")
+            .execute(query)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_traffic_split(
+        &self,
+        namespace: &str,
+        site: &str,
+        definition_id: &str,
+    ) -> Result<bool, RepoError> {
+        let query = sqlx::query(
+            "DELETE FROM api_deployment_traffic_splits WHERE namespace = $1 AND site = $2 AND definition_id = $3",
+        )
+        .bind(namespace)
+        .bind(site)
+        .bind(definition_id);
+
+        let result = self
+            .db_pool
+            .with_rw("api_deployment", "delete_traffic_split")
+            .execute(query)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_traffic_splits_by_site(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentTrafficSplitRecord>, RepoError> {
+        let query = sqlx::query_as::<_, ApiDeploymentTrafficSplitRecord>(
+            r#"
+                SELECT namespace, site, definition_id, canary_version, percentage, header_name, header_value, cookie_name, cookie_value, sticky_key
+                FROM api_deployment_traffic_splits
+                WHERE namespace = $1 AND site = $2
+                ORDER BY definition_id
+                "#,
+        )
+        .bind(namespace)
+        .bind(site);
+
+        self.db_pool
+            .with_ro("api_deployment", "get_traffic_splits_by_site")
+            .fetch_all_as(query)
+            .await
+    }
+
+    async fn get_all_traffic_splits_by_site(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentTrafficSplitRecord>, RepoError> {
+        let query = sqlx::query_as::<_, ApiDeploymentTrafficSplitRecord>(
+            r#"
+                SELECT namespace, site, definition_id, canary_version, percentage, header_name, header_value, cookie_name, cookie_value, sticky_key
+                FROM api_deployment_traffic_splits
+                WHERE site = $1
+                ORDER BY namespace, definition_id
+                "#,
+        )
+        .bind(site);
+
+        self.db_pool
+            .with_ro("api_deployment", "get_all_traffic_splits_by_site")
+            .fetch_all_as(query)
+            .await
+    }
+
+    async fn upsert_rib_limits(
+        &self,
+        record: &ApiDeploymentRibLimitsRecord,
+    ) -> Result<(), RepoError> {
+        let query = sqlx::query(
+            r#"
+              INSERT INTO api_deployment_rib_limits
+                (namespace, site, max_instructions, max_stack_size, max_value_size, max_function_invocations, max_wall_time_millis)
+              VALUES
+                ($1, $2, $3, $4, $5, $6, $7)
+              ON CONFLICT (namespace, site) DO UPDATE
+              SET max_instructions = $3, max_stack_size = $4, max_value_size = $5, max_function_invocations = $6, max_wall_time_millis = $7
+            "#,
+        )
+        .bind(record.namespace.clone())
+        .bind(record.site.clone())
+        .bind(record.max_instructions)
+        .bind(record.max_stack_size)
+        .bind(record.max_value_size)
+        .bind(record.max_function_invocations)
+        .bind(record.max_wall_time_millis);
+
+        self.db_pool
+            .with_rw("api_deployment", "upsert_rib_limits")
+            .execute(query)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_rib_limits(&self, namespace: &str, site: &str) -> Result<bool, RepoError> {
+        let query =
+            sqlx::query("DELETE FROM api_deployment_rib_limits WHERE namespace = $1 AND site = $2")
+                .bind(namespace)
+                .bind(site);
+
+        let result = self
+            .db_pool
+            .with_rw("api_deployment", "delete_rib_limits")
+            .execute(query)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_rib_limits(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentRibLimitsRecord>, RepoError> {
+        let query = sqlx::query_as::<_, ApiDeploymentRibLimitsRecord>(
+            r#"
+                SELECT namespace, site, max_instructions, max_stack_size, max_value_size, max_function_invocations, max_wall_time_millis
+                FROM api_deployment_rib_limits
+                WHERE namespace = $1 AND site = $2
+                "#,
+        )
+        .bind(namespace)
+        .bind(site);
+
+        self.db_pool
+            .with_ro("api_deployment", "get_rib_limits")
+            .fetch_optional_as(query)
+            .await
+    }
+
+    async fn get_all_rib_limits_by_site(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRibLimitsRecord>, RepoError> {
+        let query = sqlx::query_as::<_, ApiDeploymentRibLimitsRecord>(
+            r#"
+                SELECT namespace, site, max_instructions, max_stack_size, max_value_size, max_function_invocations, max_wall_time_millis
+                FROM api_deployment_rib_limits
+                WHERE site = $1
+                ORDER BY namespace
+                "#,
+        )
+        .bind(site);
+
+        self.db_pool
+            .with_ro("api_deployment", "get_all_rib_limits_by_site")
+            .fetch_all_as(query)
+            .await
+    }
 }
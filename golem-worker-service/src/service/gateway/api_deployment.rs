@@ -19,10 +19,13 @@ use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
 use crate::gateway_api_deployment::*;
 use crate::gateway_api_deployment::{ApiDeployment, ApiDeploymentRequest, ApiSite};
 use crate::gateway_binding::GatewayBindingCompiled;
+use crate::gateway_execution::api_definition_lookup::ApiSiteLookupCache;
 use crate::gateway_execution::router::{Router, RouterPattern};
 use crate::repo::api_definition::ApiDefinitionRepo;
 use crate::repo::api_deployment::ApiDeploymentRecord;
 use crate::repo::api_deployment::ApiDeploymentRepo;
+use crate::repo::api_deployment::ApiDeploymentRibLimitsRecord;
+use crate::repo::api_deployment::ApiDeploymentTrafficSplitRecord;
 use crate::service::component::ComponentService;
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use async_trait::async_trait;
@@ -32,7 +35,7 @@ use golem_common::model::component_constraint::FunctionConstraints;
 use golem_common::model::ComponentId;
 use golem_common::SafeDisplay;
 use golem_service_base::repo::RepoError;
-use rib::WorkerFunctionsInRib;
+use rib::{RibInterpreterLimits, WorkerFunctionsInRib};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
@@ -89,9 +92,69 @@ pub trait ApiDeploymentService: Send + Sync {
         auth_ctx: &AuthCtx,
         site: &ApiSiteString,
     ) -> Result<(), ApiDeploymentError>;
+
+    // Routes a share of the requests to an API definition deployed in a site
+    // to another (canary) version of the same definition
+    async fn set_traffic_split(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        traffic_split: TrafficSplit,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), ApiDeploymentError>;
+
+    async fn delete_traffic_split(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        api_definition_id: &ApiDefinitionId,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), ApiDeploymentError>;
+
+    async fn get_traffic_splits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<Vec<TrafficSplit>, ApiDeploymentError>;
+
+    /// Get all traffic splits of a site along with their canary
+    /// API definitions regardless of the namespace, mainly to serve
+    /// the http requests to API gateway
+    async fn get_all_traffic_splits_by_site(
+        &self,
+        site: &ApiSiteString,
+    ) -> Result<Vec<CompiledTrafficSplit>, ApiDeploymentError>;
+
+    // Limits the resources used by every Rib script evaluated to serve the requests of a site
+    async fn set_rib_limits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        limits: RibInterpreterLimits,
+    ) -> Result<(), ApiDeploymentError>;
+
+    async fn delete_rib_limits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<(), ApiDeploymentError>;
+
+    async fn get_rib_limits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<Option<RibInterpreterLimits>, ApiDeploymentError>;
+
+    /// Get the Rib limits of a site regardless of the namespace,
+    /// mainly to serve the http requests to API gateway.
+    /// A site without limits is unlimited.
+    async fn get_all_rib_limits_by_site(
+        &self,
+        site: &ApiSiteString,
+    ) -> Result<RibInterpreterLimits, ApiDeploymentError>;
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum ApiDeploymentError {
     #[error("Unknown API {1}/{2}")]
     ApiDefinitionNotFound(Namespace, ApiDefinitionId, ApiVersion),
@@ -107,6 +170,12 @@ pub enum ApiDeploymentError {
     InternalConversionError { what: String, error: String },
     #[error("Internal error: failed to create component constraints {0}")]
     ComponentConstraintCreateError(String),
+    #[error("Unknown traffic split of API {2} in {1}")]
+    TrafficSplitNotFound(Namespace, ApiSiteString, ApiDefinitionId),
+    #[error("Invalid traffic split: {0}")]
+    InvalidTrafficSplit(String),
+    #[error("Unknown Rib limits in {1}")]
+    RibLimitsNotFound(Namespace, ApiSiteString),
 }
 
 impl ApiDeploymentError {
@@ -134,6 +203,9 @@ impl SafeDisplay for ApiDeploymentError {
             ApiDeploymentError::InternalRepoError(inner) => inner.to_safe_string(),
             ApiDeploymentError::InternalConversionError { .. } => self.to_string(),
             ApiDeploymentError::ComponentConstraintCreateError(_) => self.to_string(),
+            ApiDeploymentError::TrafficSplitNotFound(_, _, _) => self.to_string(),
+            ApiDeploymentError::InvalidTrafficSplit(_) => self.to_string(),
+            ApiDeploymentError::RibLimitsNotFound(_, _) => self.to_string(),
         }
     }
 }
@@ -182,6 +254,7 @@ pub struct ApiDeploymentServiceDefault {
     pub deployment_repo: Arc<dyn ApiDeploymentRepo>,
     pub definition_repo: Arc<dyn ApiDefinitionRepo>,
     pub component_service: Arc<dyn ComponentService>,
+    pub site_lookup_cache: ApiSiteLookupCache,
 }
 
 impl ApiDeploymentServiceDefault {
@@ -189,11 +262,13 @@ impl ApiDeploymentServiceDefault {
         deployment_repo: Arc<dyn ApiDeploymentRepo>,
         definition_repo: Arc<dyn ApiDefinitionRepo>,
         component_service: Arc<dyn ComponentService>,
+        site_lookup_cache: ApiSiteLookupCache,
     ) -> Self {
         Self {
             deployment_repo,
             definition_repo,
             component_service,
+            site_lookup_cache,
         }
     }
 
@@ -389,6 +464,86 @@ impl ApiDeploymentServiceDefault {
 
         Ok(())
     }
+
+    async fn get_api_definition(
+        &self,
+        namespace: &str,
+        api_definition_id: &str,
+        version: &str,
+    ) -> Result<Option<CompiledHttpApiDefinition>, ApiDeploymentError> {
+        let record = self
+            .definition_repo
+            .get(namespace, api_definition_id, version)
+            .await?;
+
+        record
+            .map(|record| {
+                CompiledHttpApiDefinition::try_from(record)
+                    .map_err(|e| ApiDeploymentError::conversion_error("API definition record", e))
+            })
+            .transpose()
+    }
+
+    // Deletes the traffic split, and the component constraints of its canary version.
+    // The canary version is kept as not draft, as it may have already served requests.
+    async fn remove_traffic_split(
+        &self,
+        record: &ApiDeploymentTrafficSplitRecord,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), ApiDeploymentError> {
+        info!(namespace = %record.namespace,
+            "Remove traffic split - definition id: {}, canary version: {}",
+            record.definition_id, record.canary_version
+        );
+
+        self.deployment_repo
+            .delete_traffic_split(&record.namespace, &record.site, &record.definition_id)
+            .await?;
+
+        let canary_definition = self
+            .get_api_definition(
+                &record.namespace,
+                &record.definition_id,
+                &record.canary_version,
+            )
+            .await?;
+
+        if let Some(canary_definition) = canary_definition {
+            self.remove_component_constraints(vec![canary_definition], auth_ctx)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // Removes the traffic splits of the API definitions no longer deployed in the site
+    async fn remove_orphan_traffic_splits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), ApiDeploymentError> {
+        let deployed_definition_ids = self
+            .deployment_repo
+            .get_by_site(&namespace.to_string(), &site.to_string())
+            .await?
+            .into_iter()
+            .map(|record| record.definition_id)
+            .collect::<HashSet<_>>();
+
+        let traffic_splits = self
+            .deployment_repo
+            .get_traffic_splits_by_site(&namespace.to_string(), &site.to_string())
+            .await?;
+
+        for traffic_split in traffic_splits {
+            if !deployed_definition_ids.contains(&traffic_split.definition_id) {
+                self.remove_traffic_split(&traffic_split, auth_ctx).await?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -414,7 +569,12 @@ impl ApiDeploymentService for ApiDeploymentServiceDefault {
         .await?;
 
         self.finalize_deployment(deployment_request, auth_ctx, new_deployment)
-            .await
+            .await?;
+
+        self.site_lookup_cache
+            .invalidate(&ApiSiteString::from(&deployment_request.site));
+
+        Ok(())
     }
 
     async fn undeploy(
@@ -500,6 +660,12 @@ impl ApiDeploymentService for ApiDeploymentServiceDefault {
                 // 8. Set undeployed as draft
                 self.set_undeployed_as_draft(remove_deployment_records)
                     .await?;
+
+                // 9. Remove the traffic split if no version of the definition is left in the site
+                self.remove_orphan_traffic_splits(namespace, &site, auth_ctx)
+                    .await?;
+
+                self.site_lookup_cache.invalidate(&site);
             }
         }
 
@@ -693,7 +859,343 @@ impl ApiDeploymentService for ApiDeploymentServiceDefault {
             self.remove_component_constraints(existing_api_definitions, auth_ctx)
                 .await?;
 
+            self.remove_orphan_traffic_splits(namespace, site, auth_ctx)
+                .await?;
+
+            self.deployment_repo
+                .delete_rib_limits(&namespace.to_string(), &site.to_string())
+                .await?;
+
+            self.site_lookup_cache.invalidate(site);
+
+            Ok(())
+        }
+    }
+
+    async fn set_traffic_split(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        traffic_split: TrafficSplit,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), ApiDeploymentError> {
+        info!(namespace = %namespace,
+            "Set traffic split - definition id: {}, canary version: {}, percentage: {}",
+            traffic_split.api_definition_id, traffic_split.canary_version, traffic_split.percentage
+        );
+
+        if traffic_split.percentage > 100 {
+            return Err(ApiDeploymentError::InvalidTrafficSplit(format!(
+                "Percentage must be between 0 and 100, found {}",
+                traffic_split.percentage
+            )));
+        }
+
+        let existing_deployment_records = self
+            .deployment_repo
+            .get_by_site(&namespace.to_string(), &site.to_string())
+            .await?;
+
+        if existing_deployment_records.is_empty() {
+            return Err(ApiDeploymentError::ApiDeploymentNotFound(
+                namespace.clone(),
+                site.clone(),
+            ));
+        }
+
+        let stable_record = existing_deployment_records
+            .iter()
+            .find(|record| record.definition_id == traffic_split.api_definition_id.0)
+            .ok_or_else(|| {
+                ApiDeploymentError::InvalidTrafficSplit(format!(
+                    "API {} is not deployed in {}",
+                    traffic_split.api_definition_id, site
+                ))
+            })?;
+
+        if stable_record.definition_version == traffic_split.canary_version.0 {
+            return Err(ApiDeploymentError::InvalidTrafficSplit(format!(
+                "Canary version {} is the deployed version of API {}",
+                traffic_split.canary_version, traffic_split.api_definition_id
+            )));
+        }
+
+        let canary_definition = self
+            .get_api_definition(
+                &namespace.to_string(),
+                &traffic_split.api_definition_id.0,
+                &traffic_split.canary_version.0,
+            )
+            .await?
+            .ok_or_else(|| {
+                ApiDeploymentError::ApiDefinitionNotFound(
+                    namespace.clone(),
+                    traffic_split.api_definition_id.clone(),
+                    traffic_split.canary_version.clone(),
+                )
+            })?;
+
+        CompiledTrafficSplit::new(traffic_split.clone(), canary_definition.clone())
+            .map_err(ApiDeploymentError::InvalidTrafficSplit)?;
+
+        // The canary version replaces the deployed version for a share of the requests,
+        // and hence it shouldn't conflict with the other definitions deployed in the site
+        let other_deployed_apis = self
+            .get_definitions_by_site(namespace, site)
+            .await?
+            .into_iter()
+            .filter(|def| def.id != traffic_split.api_definition_id)
+            .collect::<Vec<_>>();
+
+        let deployed_auth_call_back_routes = other_deployed_apis
+            .iter()
+            .flat_map(|def| def.routes.iter())
+            .filter_map(|route| route.as_auth_callback_route())
+            .collect::<Vec<_>>();
+
+        let all_definitions = other_deployed_apis
+            .into_iter()
+            .chain(std::iter::once(
+                canary_definition
+                    .remove_auth_call_back_routes(deployed_auth_call_back_routes.as_slice()),
+            ))
+            .collect::<Vec<_>>();
+
+        self.check_for_conflicts(namespace, &all_definitions)?;
+
+        let existing_traffic_split = self
+            .deployment_repo
+            .get_traffic_splits_by_site(&namespace.to_string(), &site.to_string())
+            .await?
+            .into_iter()
+            .find(|record| record.definition_id == traffic_split.api_definition_id.0);
+
+        if let Some(existing_traffic_split) = existing_traffic_split {
+            if existing_traffic_split.canary_version != traffic_split.canary_version.0 {
+                self.remove_traffic_split(&existing_traffic_split, auth_ctx)
+                    .await?;
+            }
+        }
+
+        if canary_definition.draft {
+            self.definition_repo
+                .set_draft(
+                    &namespace.to_string(),
+                    &canary_definition.id.0,
+                    &canary_definition.version.0,
+                    false,
+                )
+                .await?;
+        }
+
+        let constraints = ComponentConstraints::from_api_definitions(&vec![canary_definition])?;
+
+        for (component_id, constraints) in constraints.constraints {
+            self.component_service
+                .create_or_update_constraints(&component_id, constraints, auth_ctx)
+                .await
+                .map_err(|err| {
+                    ApiDeploymentError::ComponentConstraintCreateError(err.to_safe_string())
+                })?;
+        }
+
+        self.deployment_repo
+            .upsert_traffic_split(&ApiDeploymentTrafficSplitRecord::new(
+                namespace,
+                site,
+                &traffic_split,
+            ))
+            .await?;
+
+        self.site_lookup_cache.invalidate(site);
+
+        Ok(())
+    }
+
+    async fn delete_traffic_split(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        api_definition_id: &ApiDefinitionId,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), ApiDeploymentError> {
+        info!(namespace = %namespace, "Delete traffic split - definition id: {}", api_definition_id);
+
+        let existing_traffic_split = self
+            .deployment_repo
+            .get_traffic_splits_by_site(&namespace.to_string(), &site.to_string())
+            .await?
+            .into_iter()
+            .find(|record| record.definition_id == api_definition_id.0)
+            .ok_or_else(|| {
+                ApiDeploymentError::TrafficSplitNotFound(
+                    namespace.clone(),
+                    site.clone(),
+                    api_definition_id.clone(),
+                )
+            })?;
+
+        self.remove_traffic_split(&existing_traffic_split, auth_ctx)
+            .await?;
+
+        self.site_lookup_cache.invalidate(site);
+
+        Ok(())
+    }
+
+    async fn get_traffic_splits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<Vec<TrafficSplit>, ApiDeploymentError> {
+        info!(namespace = %namespace, "Get traffic splits");
+
+        let records = self
+            .deployment_repo
+            .get_traffic_splits_by_site(&namespace.to_string(), &site.to_string())
+            .await?;
+
+        let mut values: Vec<TrafficSplit> = vec![];
+
+        for record in records {
+            values.push(record.try_into().map_err(|e| {
+                ApiDeploymentError::conversion_error("API deployment traffic split record", e)
+            })?);
+        }
+
+        Ok(values)
+    }
+
+    async fn get_all_traffic_splits_by_site(
+        &self,
+        site: &ApiSiteString,
+    ) -> Result<Vec<CompiledTrafficSplit>, ApiDeploymentError> {
+        let records = self
+            .deployment_repo
+            .get_all_traffic_splits_by_site(&site.to_string())
+            .await?;
+
+        let mut values: Vec<CompiledTrafficSplit> = vec![];
+
+        for record in records {
+            let canary_definition = self
+                .get_api_definition(
+                    &record.namespace,
+                    &record.definition_id,
+                    &record.canary_version,
+                )
+                .await?;
+
+            let Some(canary_definition) = canary_definition else {
+                error!(
+                    "Canary API definition {}/{} of the traffic split in {} is not found",
+                    record.definition_id, record.canary_version, site
+                );
+                continue;
+            };
+
+            let traffic_split = TrafficSplit::try_from(record).map_err(|e| {
+                ApiDeploymentError::conversion_error("API deployment traffic split record", e)
+            })?;
+
+            values.push(
+                CompiledTrafficSplit::new(traffic_split, canary_definition).map_err(|e| {
+                    ApiDeploymentError::conversion_error("API deployment traffic split", e)
+                })?,
+            );
+        }
+
+        Ok(values)
+    }
+
+    async fn set_rib_limits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        limits: RibInterpreterLimits,
+    ) -> Result<(), ApiDeploymentError> {
+        info!(namespace = %namespace, "Set Rib limits - site: {}, limits: {:?}", site, limits);
+
+        let existing_deployment_records = self
+            .deployment_repo
+            .get_by_site(&namespace.to_string(), &site.to_string())
+            .await?;
+
+        if existing_deployment_records.is_empty() {
+            return Err(ApiDeploymentError::ApiDeploymentNotFound(
+                namespace.clone(),
+                site.clone(),
+            ));
+        }
+
+        self.deployment_repo
+            .upsert_rib_limits(&ApiDeploymentRibLimitsRecord::new(namespace, site, &limits))
+            .await?;
+
+        self.site_lookup_cache.invalidate(site);
+
+        Ok(())
+    }
+
+    async fn delete_rib_limits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<(), ApiDeploymentError> {
+        info!(namespace = %namespace, "Delete Rib limits - site: {}", site);
+
+        let deleted = self
+            .deployment_repo
+            .delete_rib_limits(&namespace.to_string(), &site.to_string())
+            .await?;
+
+        if deleted {
+            self.site_lookup_cache.invalidate(site);
             Ok(())
+        } else {
+            Err(ApiDeploymentError::RibLimitsNotFound(
+                namespace.clone(),
+                site.clone(),
+            ))
+        }
+    }
+
+    async fn get_rib_limits(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<Option<RibInterpreterLimits>, ApiDeploymentError> {
+        info!(namespace = %namespace, "Get Rib limits");
+
+        let record = self
+            .deployment_repo
+            .get_rib_limits(&namespace.to_string(), &site.to_string())
+            .await?;
+
+        record
+            .map(|record| {
+                record.try_into().map_err(|e| {
+                    ApiDeploymentError::conversion_error("API deployment Rib limits record", e)
+                })
+            })
+            .transpose()
+    }
+
+    async fn get_all_rib_limits_by_site(
+        &self,
+        site: &ApiSiteString,
+    ) -> Result<RibInterpreterLimits, ApiDeploymentError> {
+        let records = self
+            .deployment_repo
+            .get_all_rib_limits_by_site(&site.to_string())
+            .await?;
+
+        // A site is deployed in a single namespace
+        match records.into_iter().next() {
+            Some(record) => record.try_into().map_err(|e| {
+                ApiDeploymentError::conversion_error("API deployment Rib limits record", e)
+            }),
+            None => Ok(RibInterpreterLimits::default()),
         }
     }
 }
@@ -146,6 +146,7 @@ mod tests {
                     idempotency_key: None,
                     response_mapping: ResponseMapping(Expr::literal("sample")),
                     invocation_context: None,
+                    response_cache_policy: None,
                 })),
                 middlewares: None,
             }
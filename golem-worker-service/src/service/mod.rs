@@ -21,15 +21,18 @@ pub mod gateway;
 pub mod worker;
 
 use crate::aws_config::AwsConfig;
-use crate::config::GatewaySessionStorageConfig;
 use crate::config::WorkerServiceConfig;
+use crate::config::{GatewayResponseCacheConfig, GatewaySessionStorageConfig};
 use crate::gateway_api_definition::http::HttpApiDefinition;
 use crate::gateway_execution::api_definition_lookup::{
-    DefaultHttpApiDefinitionLookup, HttpApiDefinitionsLookup,
+    ApiSiteLookupCache, DefaultHttpApiDefinitionLookup, HttpApiDefinitionsLookup,
 };
 use crate::gateway_execution::file_server_binding_handler::{
     DefaultFileServerBindingHandler, FileServerBindingHandler,
 };
+use crate::gateway_execution::gateway_response_cache::{
+    GatewayResponseCache, InMemoryGatewayResponseCache, RedisGatewayResponseCache,
+};
 use crate::gateway_execution::gateway_session::{
     GatewaySession, RedisGatewaySession, RedisGatewaySessionExpiration, SqliteGatewaySession,
     SqliteGatewaySessionExpiration,
@@ -101,6 +104,7 @@ pub struct Services {
     pub http_handler_binding_handler: Arc<dyn HttpHandlerBindingHandler>,
     pub security_scheme_service: Arc<dyn SecuritySchemeService>,
     pub gateway_session_store: Arc<dyn GatewaySession>,
+    pub gateway_response_cache: Arc<dyn GatewayResponseCache>,
 }
 
 impl Services {
@@ -192,6 +196,24 @@ impl Services {
             }
         };
 
+        let gateway_response_cache: Arc<dyn GatewayResponseCache> =
+            match &config.gateway_response_cache {
+                GatewayResponseCacheConfig::InMemory(in_memory_config) => {
+                    Arc::new(InMemoryGatewayResponseCache::new(
+                        in_memory_config.max_capacity,
+                        in_memory_config.eviction_period,
+                    ))
+                }
+
+                GatewayResponseCacheConfig::Redis(redis_config) => {
+                    let redis = RedisPool::configured(redis_config)
+                        .await
+                        .map_err(|e| e.to_string())?;
+
+                    Arc::new(RedisGatewayResponseCache::new(redis))
+                }
+            };
+
         let blob_storage: Arc<dyn BlobStorage> = match &config.blob_storage {
             BlobStorageConfig::S3(config) => Arc::new(
                 golem_service_base::storage::blob::s3::S3BlobStorage::new(config.clone()).await,
@@ -252,11 +274,14 @@ impl Services {
                 ApiDefinitionServiceConfig::default(),
             ));
 
+        let api_site_lookup_cache = ApiSiteLookupCache::new();
+
         let deployment_service: Arc<dyn ApiDeploymentService> =
             Arc::new(ApiDeploymentServiceDefault::new(
                 api_deployment_repo.clone(),
                 api_definition_repo.clone(),
                 component_service.clone(),
+                api_site_lookup_cache.clone(),
             ));
 
         let (domain_route, domain_register_service, certificate_manager) = if config.is_local_env()
@@ -389,9 +414,11 @@ impl Services {
             GatewayWorkerRequestExecutorDefault::new(worker_service.clone()),
         );
 
-        let http_request_api_definition_lookup_service = Arc::new(
-            DefaultHttpApiDefinitionLookup::new(deployment_service.clone()),
-        );
+        let http_request_api_definition_lookup_service =
+            Arc::new(DefaultHttpApiDefinitionLookup::new(
+                deployment_service.clone(),
+                api_site_lookup_cache.clone(),
+            ));
 
         let file_server_binding_handler: Arc<dyn FileServerBindingHandler> =
             Arc::new(DefaultFileServerBindingHandler::new(
@@ -422,6 +449,7 @@ impl Services {
             http_handler_binding_handler,
             security_scheme_service,
             gateway_session_store,
+            gateway_response_cache,
         })
     }
 }
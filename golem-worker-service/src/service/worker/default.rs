@@ -27,13 +27,15 @@ use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
     ActivatePluginRequest, CancelInvocationRequest, CompletePromiseRequest, ConnectWorkerRequest,
-    CreateWorkerRequest, DeactivatePluginRequest, ForkWorkerRequest, InterruptWorkerRequest,
-    InvokeAndAwaitWorkerJsonRequest, InvokeAndAwaitWorkerRequest, ResumeWorkerRequest,
-    RevertWorkerRequest, SearchOplogResponse, UpdateWorkerRequest,
+    CreateWorkerRequest, DeactivatePluginRequest, ExportWorkerTraceRequest, ForkWorkerRequest,
+    ImportWorkerTraceRequestHeader, InterruptWorkerRequest, InvokeAndAwaitWorkerJsonRequest,
+    InvokeAndAwaitWorkerRequest, ResumeWorkerRequest, RevertWorkerRequest, SearchOplogResponse,
+    UpdateWorkerRequest,
 };
 use golem_common::client::MultiTargetGrpcClient;
 use golem_common::model::auth::{Namespace, TokenSecret};
 use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::diff::OplogDiff;
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
 use golem_common::model::RetryConfig;
 use golem_common::model::{
@@ -45,6 +47,7 @@ use golem_service_base::clients::limit::LimitService;
 use golem_service_base::clients::project::ProjectService;
 use golem_service_base::clients::RemoteServiceConfig;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::grpc::import_worker_trace_request_chunks;
 use golem_service_base::model::RevertWorkerTarget;
 use golem_service_base::model::{GetOplogResponse, PublicOplogEntryWithIndex, ResourceLimits};
 use golem_service_base::service::routing_table::{HasRoutingTableService, RoutingTableService};
@@ -59,6 +62,9 @@ use tonic::Code;
 
 pub type WorkerResult<T> = Result<T, WorkerServiceError>;
 
+// The number of entries fetched at once when reading the whole oplog of a worker
+const OPLOG_DIFF_PAGE_SIZE: u64 = 1000;
+
 #[async_trait]
 pub trait WorkerService: Send + Sync {
     async fn create(
@@ -253,6 +259,15 @@ pub trait WorkerService: Send + Sync {
         namespace: Namespace,
     ) -> Result<GetOplogResponse, WorkerServiceError>;
 
+    async fn diff_oplogs(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+        other_worker_id: &WorkerId,
+        other_namespace: Namespace,
+        limit: usize,
+    ) -> Result<OplogDiff, WorkerServiceError>;
+
     async fn get_file_system_node(
         &self,
         worker_id: &TargetWorkerId,
@@ -289,6 +304,19 @@ pub trait WorkerService: Send + Sync {
         namespace: Namespace,
     ) -> WorkerResult<()>;
 
+    async fn export_worker_trace(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<u8>>;
+
+    async fn import_worker_trace(
+        &self,
+        worker_id: &WorkerId,
+        trace: Vec<u8>,
+        namespace: Namespace,
+    ) -> WorkerResult<()>;
+
     async fn revert_worker(
         &self,
         worker_id: &WorkerId,
@@ -359,6 +387,36 @@ impl WorkerServiceDefault {
         Ok(resource_limits)
     }
 
+    async fn get_whole_oplog(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> Result<Vec<PublicOplogEntry>, WorkerServiceError> {
+        let mut entries = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let response = self
+                .get_oplog(
+                    worker_id,
+                    OplogIndex::INITIAL,
+                    cursor,
+                    OPLOG_DIFF_PAGE_SIZE,
+                    namespace.clone(),
+                )
+                .await?;
+
+            entries.extend(response.entries.into_iter().map(|entry| entry.entry));
+
+            match response.next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(entries)
+    }
+
     async fn find_running_metadata_internal(
         &self,
         component_id: &ComponentId,
@@ -1268,6 +1326,22 @@ impl WorkerService for WorkerServiceDefault {
             .await
     }
 
+    async fn diff_oplogs(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+        other_worker_id: &WorkerId,
+        other_namespace: Namespace,
+        limit: usize,
+    ) -> Result<OplogDiff, WorkerServiceError> {
+        let left = self.get_whole_oplog(worker_id, namespace).await?;
+        let right = self
+            .get_whole_oplog(other_worker_id, other_namespace)
+            .await?;
+
+        OplogDiff::new(&left, &right, limit).map_err(WorkerServiceError::Internal)
+    }
+
     async fn get_file_system_node(
         &self,
         worker_id: &TargetWorkerId,
@@ -1538,6 +1612,94 @@ impl WorkerService for WorkerServiceDefault {
         Ok(())
     }
 
+    async fn export_worker_trace(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<u8>> {
+        let worker_id = worker_id.clone();
+        let mut stream = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "export_worker_trace",
+                move |worker_executor_client| {
+                    let worker_id = worker_id.clone();
+                    Box::pin(
+                        worker_executor_client.export_worker_trace(ExportWorkerTraceRequest {
+                            worker_id: Some(worker_id.into()),
+                            account_id: Some(namespace.account_id.clone().into()),
+                            project_id: Some(namespace.project_id.clone().into()),
+                        }),
+                    )
+                },
+                |response| Ok(WorkerStream::new(response.into_inner())),
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+
+        let mut trace = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            match chunk
+                .map_err(|_| WorkerServiceError::Internal("Stream error".to_string()))?
+                .result
+            {
+                Some(workerexecutor::v1::export_worker_trace_response::Result::Success(bytes)) => {
+                    trace.extend(bytes)
+                }
+                Some(workerexecutor::v1::export_worker_trace_response::Result::Failure(err)) => {
+                    let converted = WorkerExecutorError::try_from(err).map_err(|err| {
+                        WorkerServiceError::Internal(format!("Failed converting errors {err}"))
+                    })?;
+                    return Err(converted.into());
+                }
+                None => return Err(WorkerServiceError::Internal("Malformed chunk".to_string())),
+            }
+        }
+        Ok(trace)
+    }
+
+    async fn import_worker_trace(
+        &self,
+        worker_id: &WorkerId,
+        trace: Vec<u8>,
+        namespace: Namespace,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "import_worker_trace",
+            move |worker_executor_client| {
+                let chunks = import_worker_trace_request_chunks(
+                    ImportWorkerTraceRequestHeader {
+                        worker_id: Some(worker_id.clone().into()),
+                        account_id: Some(namespace.account_id.clone().into()),
+                        project_id: Some(namespace.project_id.clone().into()),
+                    },
+                    &trace,
+                );
+                Box::pin(worker_executor_client.import_worker_trace(tokio_stream::iter(chunks)))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ImportWorkerTraceResponse {
+                    result:
+                        Some(workerexecutor::v1::import_worker_trace_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::ImportWorkerTraceResponse {
+                    result:
+                        Some(workerexecutor::v1::import_worker_trace_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::ImportWorkerTraceResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn revert_worker(
         &self,
         worker_id: &WorkerId,
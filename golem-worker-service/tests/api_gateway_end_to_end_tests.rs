@@ -21,10 +21,16 @@ use golem_common::model::auth::Namespace;
 use golem_common::model::{AccountId, ComponentId, IdempotencyKey, ProjectId};
 use golem_service_base::model::ComponentName;
 use golem_worker_service::gateway_api_definition::http::RouteCompilationErrors;
+use golem_worker_service::gateway_api_deployment::{
+    ApiSiteString, CompiledTrafficSplit, TrafficSplit,
+};
 use golem_worker_service::gateway_execution::auth_call_back_binding_handler::DefaultAuthCallBack;
 use golem_worker_service::gateway_execution::gateway_http_input_executor::{
     DefaultGatewayInputExecutor, GatewayHttpInputExecutor,
 };
+use golem_worker_service::gateway_execution::gateway_response_cache::{
+    principal_of, GatewayResponseCacheStore, ResponseCacheKey,
+};
 use golem_worker_service::gateway_execution::gateway_session::{
     GatewaySession, GatewaySessionStore,
 };
@@ -38,7 +44,9 @@ use http::header::{LOCATION, ORIGIN};
 use http::{HeaderMap, HeaderValue, Method, StatusCode, Uri};
 use openidconnect::{ClientId, ClientSecret, RedirectUrl, Scope};
 use poem::{Request, Response};
+use rib::RibInterpreterLimits;
 use serde_json::{Number, Value as JsonValue};
+use std::collections::HashSet;
 use std::sync::Arc;
 use test_r::test;
 use url::Url;
@@ -59,6 +67,23 @@ async fn execute(
     api_specification: &HttpApiDefinition,
     session_store: &GatewaySessionStore,
     test_identity_provider: &TestIdentityProvider,
+) -> Response {
+    execute_with_response_cache(
+        api_request,
+        api_specification,
+        session_store,
+        test_identity_provider,
+        &internal::get_response_cache(),
+    )
+    .await
+}
+
+async fn execute_with_response_cache(
+    api_request: Request,
+    api_specification: &HttpApiDefinition,
+    session_store: &GatewaySessionStore,
+    test_identity_provider: &TestIdentityProvider,
+    response_cache: &GatewayResponseCacheStore,
 ) -> Response {
     // Compile the API definition
     let compiled = CompiledHttpApiDefinition::from_http_api_definition(
@@ -76,6 +101,7 @@ async fn execute(
         Arc::new(internal::TestApiDefinitionLookup::new(compiled)),
         Arc::clone(session_store),
         Arc::new(test_identity_provider.clone()),
+        Arc::clone(response_cache),
     );
 
     test_executor.execute_http_request(api_request).await
@@ -884,7 +910,86 @@ async fn test_api_def_with_invalid_request_body() {
     let body = response.into_body().into_string().await.unwrap();
 
     assert_eq!(status, StatusCode::BAD_REQUEST);
-    assert_eq!(body, "invalid http request body\ninvalid value for key foo_key: expected number, found string\nexpected request body: record { bar_key: string, foo_key: u32 }");
+    assert_eq!(
+        serde_json::from_str::<JsonValue>(&body).unwrap(),
+        serde_json::json!({
+            "errors": [
+                {
+                    "field": "request.body.foo_key",
+                    "expectedType": "u32",
+                    "message": "expected number, found string"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+async fn test_api_def_with_invalid_request_reports_all_fields() {
+    let empty_headers = HeaderMap::new();
+
+    let mut request_body: serde_json::Map<String, JsonValue> = serde_json::Map::new();
+
+    request_body.insert("foo_key".to_string(), JsonValue::String("1".to_string()));
+
+    let api_request = get_gateway_request(
+        "/foo/john",
+        None,
+        &empty_headers,
+        JsonValue::Object(request_body),
+    );
+
+    let response_mapping = r#"
+         let userid: u64 = request.path.user-id;
+         let worker = instance("shopping-cart-${userid}");
+         let param1 = request.body.foo_key;
+         let param2 = request.body.bar_key;
+         let response = worker.add-item(param1, param2);
+
+         response
+        "#;
+
+    let api_specification: HttpApiDefinition =
+        get_api_def_with_worker_binding("/foo/{user-id}", response_mapping).await;
+
+    let session_store = internal::get_session_store();
+
+    let response = execute(
+        api_request,
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+    )
+    .await;
+
+    let status = response.status();
+
+    let body = response.into_body().into_string().await.unwrap();
+    let body = serde_json::from_str::<JsonValue>(&body).unwrap();
+
+    let mut invalid_fields = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| {
+            (
+                error["field"].as_str().unwrap().to_string(),
+                error["expectedType"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    invalid_fields.sort();
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        invalid_fields,
+        vec![
+            ("request.body.bar_key".to_string(), "string".to_string()),
+            ("request.body.foo_key".to_string(), "u32".to_string()),
+            ("request.path.user-id".to_string(), "u64".to_string()),
+        ]
+    );
 }
 
 #[test]
@@ -2060,6 +2165,316 @@ async fn test_api_def_with_idempotency_key() {
     test_key(&headers, Some(IdempotencyKey::new("bar".to_string()))).await;
 }
 
+#[test]
+async fn test_api_def_with_response_cache() {
+    let expression = r#"
+            let x: u64 = request.path.cart-id;
+            let my-instance = instance("shopping-cart-${x}");
+            let response = my-instance.get-cart-contents("foo", "bar");
+            response
+            "#;
+
+    let api_specification: HttpApiDefinition = get_api_def_with_response_cache(
+        "/getcartcontent/{cart-id}",
+        expression,
+        r#"{ ttlSeconds: 60, key: '"cart-${request.path.cart-id}"', vary: [Accept-Language] }"#,
+    )
+    .await;
+
+    let session_store = internal::get_session_store();
+    let response_cache = internal::get_response_cache();
+
+    let mut headers = HeaderMap::new();
+    headers.insert("accept-language", HeaderValue::from_static("en"));
+
+    let response = execute_with_response_cache(
+        get_gateway_request("/getcartcontent/1", None, &headers, JsonValue::Null),
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+        &response_cache,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("vary").unwrap(), "accept-language");
+    assert_eq!(
+        response.headers().get("cache-control").unwrap(),
+        "max-age=60"
+    );
+
+    let etag = response
+        .headers()
+        .get("etag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let cache_key = ResponseCacheKey::new(
+        ApiSiteString("localhost".to_string()),
+        "cart-1".to_string(),
+        "GET /getcartcontent/1",
+        &["accept-language".to_string()],
+        &headers,
+        None,
+    );
+
+    let cached = response_cache.get(&cache_key).await.unwrap().unwrap();
+    assert_eq!(cached.etag, etag);
+
+    headers.insert("if-none-match", HeaderValue::from_str(&etag).unwrap());
+
+    let response = execute_with_response_cache(
+        get_gateway_request("/getcartcontent/1", None, &headers, JsonValue::Null),
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+        &response_cache,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    response_cache
+        .invalidate(&ApiSiteString("localhost".to_string()), Some("cart-1"))
+        .await
+        .unwrap();
+
+    assert!(response_cache.get(&cache_key).await.unwrap().is_none());
+}
+
+#[test]
+async fn test_api_def_with_response_cache_separates_principals() {
+    let expression = r#"
+            let x: u64 = request.path.cart-id;
+            let my-instance = instance("shopping-cart-${x}");
+            let response = my-instance.get-cart-contents("foo", "bar");
+            response
+            "#;
+
+    let api_specification: HttpApiDefinition = get_api_def_with_response_cache(
+        "/getcartcontent/{cart-id}",
+        expression,
+        r#"{ ttlSeconds: 60, key: '"cart-${request.path.cart-id}"' }"#,
+    )
+    .await;
+
+    let session_store = internal::get_session_store();
+    let response_cache = internal::get_response_cache();
+
+    let mut alice = HeaderMap::new();
+    alice.insert("authorization", HeaderValue::from_static("Bearer alice"));
+
+    let mut bob = HeaderMap::new();
+    bob.insert("authorization", HeaderValue::from_static("Bearer bob"));
+
+    let cache_key = |headers: &HeaderMap| {
+        ResponseCacheKey::new(
+            ApiSiteString("localhost".to_string()),
+            "cart-1".to_string(),
+            "GET /getcartcontent/1",
+            &[],
+            headers,
+            principal_of(None, headers).as_deref(),
+        )
+    };
+
+    let response = execute_with_response_cache(
+        get_gateway_request("/getcartcontent/1", None, &alice, JsonValue::Null),
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+        &response_cache,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response_cache
+        .get(&cache_key(&alice))
+        .await
+        .unwrap()
+        .is_some());
+
+    // The response cached for the first caller is not served to the second one
+    assert!(response_cache
+        .get(&cache_key(&bob))
+        .await
+        .unwrap()
+        .is_none());
+
+    let response = execute_with_response_cache(
+        get_gateway_request("/getcartcontent/1", None, &bob, JsonValue::Null),
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+        &response_cache,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response_cache
+        .get(&cache_key(&bob))
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+async fn test_api_def_with_traffic_split_is_sticky_to_worker_name() {
+    let stable_specification: HttpApiDefinition = get_api_def_version_with_worker_binding(
+        "0.0.1",
+        "/getcartcontent/{cart-id}",
+        r#"
+            let x: u64 = request.path.cart-id;
+            let my-instance = instance("shopping-cart-${x}");
+            let response = my-instance.get-cart-contents("stable", "bar");
+            response
+            "#,
+    )
+    .await;
+
+    let canary_specification: HttpApiDefinition = get_api_def_version_with_worker_binding(
+        "0.0.2",
+        "/getcartcontent/{cart-id}",
+        r#"
+            let x: u64 = request.path.cart-id;
+            let my-instance = instance("shopping-cart-${x}");
+            let response = my-instance.get-cart-contents("canary", "bar");
+            response
+            "#,
+    )
+    .await;
+
+    let compile = |api_specification: &HttpApiDefinition| {
+        CompiledHttpApiDefinition::from_http_api_definition(
+            api_specification,
+            &internal::get_component_metadata(),
+            &test_namespace(),
+        )
+        .expect("Failed to compile API definition")
+    };
+
+    // No sticky key is given, hence the split is sticky to the name of the invoked worker
+    let traffic_split = CompiledTrafficSplit::new(
+        TrafficSplit {
+            api_definition_id: stable_specification.id.clone(),
+            canary_version: canary_specification.version.clone(),
+            percentage: 50,
+            header: None,
+            cookie: None,
+            sticky_key: None,
+        },
+        compile(&canary_specification),
+    )
+    .unwrap();
+
+    let test_executor = DefaultGatewayInputExecutor::new(
+        internal::get_test_rib_interpreter(),
+        internal::get_test_file_server_binding_handler(),
+        Arc::new(DefaultAuthCallBack),
+        internal::get_test_http_handler_binding_handler(),
+        Arc::new(
+            internal::TestApiDefinitionLookup::new(compile(&stable_specification))
+                .with_traffic_split(traffic_split),
+        ),
+        internal::get_session_store(),
+        Arc::new(TestIdentityProvider::default()),
+        internal::get_response_cache(),
+    );
+
+    let mut variants = HashSet::new();
+
+    for cart_id in 0..20 {
+        let mut served_by = HashSet::new();
+
+        for _ in 0..5 {
+            let response = test_executor
+                .execute_http_request(get_gateway_request(
+                    &format!("/getcartcontent/{cart_id}"),
+                    None,
+                    &HeaderMap::new(),
+                    JsonValue::Null,
+                ))
+                .await;
+
+            let test_response = internal::get_details_from_response(response).await;
+
+            assert_eq!(
+                test_response.worker_name,
+                format!("shopping-cart-{cart_id}")
+            );
+            served_by.insert(
+                test_response.function_params[0]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+            );
+        }
+
+        // Every request to a worker is served by the same version
+        assert_eq!(served_by.len(), 1);
+        variants.extend(served_by);
+    }
+
+    // While the workers are split between the versions
+    assert_eq!(
+        variants,
+        HashSet::from(["stable".to_string(), "canary".to_string()])
+    );
+}
+
+#[test]
+async fn test_api_def_with_worker_name_exceeding_rib_limits() {
+    let api_specification = get_api_def_with_http_handler_binding(
+        "/carts/{cart-id}",
+        r#"
+            let x: u64 = request.path.cart-id;
+            "shopping-cart-${x}"
+        "#,
+    )
+    .await;
+
+    let compiled = CompiledHttpApiDefinition::from_http_api_definition(
+        &api_specification,
+        &internal::get_component_metadata(),
+        &test_namespace(),
+    )
+    .expect("Failed to compile API definition");
+
+    let test_executor = DefaultGatewayInputExecutor::new(
+        internal::get_test_rib_interpreter(),
+        internal::get_test_file_server_binding_handler(),
+        Arc::new(DefaultAuthCallBack),
+        internal::get_test_http_handler_binding_handler(),
+        Arc::new(
+            internal::TestApiDefinitionLookup::new(compiled)
+                .with_rib_limits(RibInterpreterLimits::default().with_max_instructions(2)),
+        ),
+        internal::get_session_store(),
+        Arc::new(TestIdentityProvider::default()),
+        internal::get_response_cache(),
+    );
+
+    // The worker name is not evaluated, hence the HTTP handler is never invoked
+    let response = test_executor
+        .execute_http_request(get_gateway_request(
+            "/carts/1",
+            None,
+            &HeaderMap::new(),
+            JsonValue::Null,
+        ))
+        .await;
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response
+        .into_body()
+        .into_string()
+        .await
+        .unwrap()
+        .contains("exceeded the limit of 2 instructions"));
+}
+
 fn get_gateway_request(
     base_path: &str,
     query_path: Option<&str>,
@@ -2134,11 +2549,19 @@ fn get_preflight_gateway_request(
 async fn get_api_def_with_worker_binding(
     path_pattern: &str,
     rib_expression: &str,
+) -> HttpApiDefinition {
+    get_api_def_version_with_worker_binding("0.0.1", path_pattern, rib_expression).await
+}
+
+async fn get_api_def_version_with_worker_binding(
+    version: &str,
+    path_pattern: &str,
+    rib_expression: &str,
 ) -> HttpApiDefinition {
     let yaml_string = format!(
         r#"
           id: users-api
-          version: 0.0.1
+          version: {version}
           createdAt: 2024-08-21T07:42:15.696Z
           routes:
           - method: Get
@@ -2175,6 +2598,94 @@ async fn get_api_def_with_worker_binding(
     .unwrap()
 }
 
+async fn get_api_def_with_http_handler_binding(
+    path_pattern: &str,
+    worker_name: &str,
+) -> HttpApiDefinition {
+    let yaml_string = format!(
+        r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          routes:
+          - method: Get
+            path: {path_pattern}
+            binding:
+              bindingType: http-handler
+              component:
+                name: test-component
+                version: 0
+              workerName: '${{{worker_name}}}'
+
+        "#
+    );
+
+    let http_api_definition_request: api::dto::HttpApiDefinitionRequest =
+        serde_yaml::from_str(yaml_string.as_str()).unwrap();
+
+    let core_request: gateway_api_definition::http::HttpApiDefinitionRequest =
+        http_api_definition_request
+            .into_core(&TestConversionContext.boxed())
+            .await
+            .unwrap();
+
+    let create_at: DateTime<Utc> = "2024-08-21T07:42:15.696Z".parse().unwrap();
+
+    HttpApiDefinition::from_http_api_definition_request(
+        &test_namespace(),
+        core_request,
+        create_at,
+        &security::get_test_security_scheme_service(TestIdentityProvider::default()),
+    )
+    .await
+    .unwrap()
+}
+
+async fn get_api_def_with_response_cache(
+    path_pattern: &str,
+    rib_expression: &str,
+    response_cache: &str,
+) -> HttpApiDefinition {
+    let yaml_string = format!(
+        r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          routes:
+          - method: Get
+            path: {path_pattern}
+            binding:
+              type: wit-worker
+              component:
+                name: test-component
+                version: 0
+              response: '${{{rib_expression}}}'
+              responseCache: {response_cache}
+
+        "#
+    );
+
+    let http_api_definition_request: api::dto::HttpApiDefinitionRequest =
+        serde_yaml::from_str(yaml_string.as_str()).unwrap();
+
+    let core_request: gateway_api_definition::http::HttpApiDefinitionRequest =
+        http_api_definition_request
+            .into_core(&TestConversionContext.boxed())
+            .await
+            .unwrap();
+
+    let create_at: DateTime<Utc> = "2024-08-21T07:42:15.696Z".parse().unwrap();
+
+    HttpApiDefinition::from_http_api_definition_request(
+        &test_namespace(),
+        core_request,
+        create_at,
+        &security::get_test_security_scheme_service(TestIdentityProvider::default()),
+    )
+    .await
+    .unwrap()
+}
+
 // https://swagger.io/docs/specification/v3_0/authentication/openid-connect-discovery/
 async fn get_api_def_with_security(
     path_pattern: &str,
@@ -2465,13 +2976,16 @@ mod internal {
     use golem_worker_service::gateway_api_definition::http::{
         CompiledHttpApiDefinition, ComponentDetails, ComponentMetadataDictionary,
     };
-    use golem_worker_service::gateway_api_deployment::ApiSiteString;
+    use golem_worker_service::gateway_api_deployment::{ApiSiteString, CompiledTrafficSplit};
     use golem_worker_service::gateway_execution::api_definition_lookup::{
         ApiDefinitionLookupError, HttpApiDefinitionsLookup,
     };
     use golem_worker_service::gateway_execution::file_server_binding_handler::{
         FileServerBindingHandler, FileServerBindingResult,
     };
+    use golem_worker_service::gateway_execution::gateway_response_cache::{
+        GatewayResponseCacheStore, InMemoryGatewayResponseCache,
+    };
     use golem_worker_service::gateway_execution::gateway_session::{
         DataKey, DataValue, GatewaySession, GatewaySessionError, GatewaySessionStore, SessionId,
     };
@@ -2493,7 +3007,7 @@ mod internal {
         ACCESS_CONTROL_MAX_AGE,
     };
     use poem::Response;
-    use rib::{ComponentDependencyKey, RibResult};
+    use rib::{ComponentDependencyKey, RibInterpreterLimits, RibResult};
     use serde_json::Value as JsonValue;
     use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
@@ -2501,11 +3015,27 @@ mod internal {
 
     pub struct TestApiDefinitionLookup {
         pub api_definition: CompiledHttpApiDefinition,
+        pub traffic_splits: Vec<CompiledTrafficSplit>,
+        pub rib_limits: RibInterpreterLimits,
     }
 
     impl TestApiDefinitionLookup {
         pub fn new(api_definition: CompiledHttpApiDefinition) -> Self {
-            Self { api_definition }
+            Self {
+                api_definition,
+                traffic_splits: vec![],
+                rib_limits: RibInterpreterLimits::default(),
+            }
+        }
+
+        pub fn with_traffic_split(mut self, traffic_split: CompiledTrafficSplit) -> Self {
+            self.traffic_splits.push(traffic_split);
+            self
+        }
+
+        pub fn with_rib_limits(mut self, rib_limits: RibInterpreterLimits) -> Self {
+            self.rib_limits = rib_limits;
+            self
         }
     }
 
@@ -2517,6 +3047,20 @@ mod internal {
         ) -> Result<Vec<CompiledHttpApiDefinition>, ApiDefinitionLookupError> {
             Ok(vec![self.api_definition.clone()])
         }
+
+        async fn get_traffic_splits(
+            &self,
+            _input: &ApiSiteString,
+        ) -> Result<Vec<CompiledTrafficSplit>, ApiDefinitionLookupError> {
+            Ok(self.traffic_splits.clone())
+        }
+
+        async fn get_rib_limits(
+            &self,
+            _input: &ApiSiteString,
+        ) -> Result<RibInterpreterLimits, ApiDefinitionLookupError> {
+            Ok(self.rib_limits.clone())
+        }
     }
 
     // This worker-request-executor simply returns the same response to any worker function
@@ -3104,6 +3648,13 @@ mod internal {
     pub fn get_session_store_with_zero_ttl() -> GatewaySessionStore {
         Arc::new(NoopTestSessionBackend)
     }
+
+    pub fn get_response_cache() -> GatewayResponseCacheStore {
+        Arc::new(InMemoryGatewayResponseCache::new(
+            16,
+            std::time::Duration::from_secs(60),
+        ))
+    }
 }
 
 pub mod security {
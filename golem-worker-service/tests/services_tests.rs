@@ -36,7 +36,12 @@ use golem_worker_service::api;
 use golem_worker_service::gateway_api_definition::http::HttpApiDefinition;
 use golem_worker_service::gateway_api_definition::http::HttpApiDefinitionRequest;
 use golem_worker_service::gateway_api_definition::{ApiDefinitionId, ApiVersion};
-use golem_worker_service::gateway_api_deployment::{ApiDeploymentRequest, ApiSite, ApiSiteString};
+use golem_worker_service::gateway_api_deployment::{
+    ApiDeploymentRequest, ApiSite, ApiSiteString, TrafficSplit,
+};
+use golem_worker_service::gateway_execution::api_definition_lookup::{
+    ApiSiteLookupCache, DefaultHttpApiDefinitionLookup, HttpApiDefinitionsLookup,
+};
 use golem_worker_service::gateway_execution::gateway_session::{
     DataKey, DataValue, GatewaySession, GatewaySessionError, RedisGatewaySession,
     RedisGatewaySessionExpiration, SessionId, SqliteGatewaySession, SqliteGatewaySessionExpiration,
@@ -83,6 +88,7 @@ use openidconnect::{
     IssuerUrl, JsonWebKeySetUrl, Nonce, RedirectUrl, RegistrationUrl, ResponseTypes, Scope,
     TokenUrl, UserInfoUrl,
 };
+use rib::RibInterpreterLimits;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -848,13 +854,20 @@ async fn test_services(
             ApiDefinitionServiceConfig::default(),
         ));
 
+    let api_site_lookup_cache = ApiSiteLookupCache::new();
+
     let deployment_service: Arc<dyn ApiDeploymentService> =
         Arc::new(ApiDeploymentServiceDefault::new(
             api_deployment_repo.clone(),
             api_definition_repo.clone(),
             component_service.clone(),
+            api_site_lookup_cache.clone(),
         ));
 
+    let api_definition_lookup: Arc<dyn HttpApiDefinitionsLookup> = Arc::new(
+        DefaultHttpApiDefinitionLookup::new(deployment_service.clone(), api_site_lookup_cache),
+    );
+
     let certificate_manager: Arc<dyn CertificateManager + Sync + Send> =
         Arc::new(InMemoryCertificateManager::default());
 
@@ -881,6 +894,13 @@ async fn test_services(
     test_delete_non_existing(definition_service.clone()).await;
     test_deployment(definition_service.clone(), deployment_service.clone()).await;
     test_deployment_conflict(definition_service.clone(), deployment_service.clone()).await;
+    test_deployment_traffic_split(definition_service.clone(), deployment_service.clone()).await;
+    test_deployment_rib_limits(
+        definition_service.clone(),
+        deployment_service.clone(),
+        api_definition_lookup.clone(),
+    )
+    .await;
     test_certificate_service(certificate_service).await;
     test_domain_service(domain_service).await;
 }
@@ -1172,6 +1192,288 @@ async fn test_deployment_conflict(
     );
 }
 
+async fn test_deployment_traffic_split(
+    definition_service: Arc<dyn ApiDefinitionService>,
+    deployment_service: Arc<dyn ApiDeploymentService>,
+) {
+    let id = Uuid::new_v4().to_string();
+
+    let stable = get_api_definition(
+        &id,
+        "0.0.1",
+        "/api/split/{user-id}",
+        "${ let worker = instance[golem:it](\"worker1\"); {body: worker.get-cart-contents(\"foo\")} }",
+        false,
+    ).await;
+    let canary = get_api_definition(
+        &id,
+        "0.0.2",
+        "/api/split/{user-id}",
+        "${ let worker = instance[golem:it](\"worker2\"); {body: worker.get-cart-contents(\"foo\")} }",
+        true,
+    ).await;
+
+    definition_service
+        .create(&stable, &test_namespace(), &test_auth_ctx())
+        .await
+        .unwrap();
+    definition_service
+        .create(&canary, &test_namespace(), &test_auth_ctx())
+        .await
+        .unwrap();
+
+    let site = ApiSiteString("test-split.com".to_string());
+
+    let traffic_split = TrafficSplit {
+        api_definition_id: stable.id.clone(),
+        canary_version: canary.version.clone(),
+        percentage: 20,
+        header: None,
+        cookie: None,
+        sticky_key: Some(rib::from_string(r#""${request.path.user-id}""#).unwrap()),
+    };
+
+    let result = deployment_service
+        .set_traffic_split(
+            &test_namespace(),
+            &site,
+            traffic_split.clone(),
+            &test_auth_ctx(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDeploymentError::ApiDeploymentNotFound(_, _))
+    ));
+
+    let deployment = get_api_deployment("test-split.com", None, vec![&stable.id.0]);
+    deployment_service
+        .deploy(&deployment, &test_auth_ctx())
+        .await
+        .unwrap();
+
+    let result = deployment_service
+        .set_traffic_split(
+            &test_namespace(),
+            &site,
+            TrafficSplit {
+                canary_version: stable.version.clone(),
+                ..traffic_split.clone()
+            },
+            &test_auth_ctx(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDeploymentError::InvalidTrafficSplit(_))
+    ));
+
+    let result = deployment_service
+        .set_traffic_split(
+            &test_namespace(),
+            &site,
+            TrafficSplit {
+                canary_version: ApiVersion("0.0.3".to_string()),
+                ..traffic_split.clone()
+            },
+            &test_auth_ctx(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDeploymentError::ApiDefinitionNotFound(_, _, _))
+    ));
+
+    deployment_service
+        .set_traffic_split(
+            &test_namespace(),
+            &site,
+            traffic_split.clone(),
+            &test_auth_ctx(),
+        )
+        .await
+        .unwrap();
+
+    let traffic_splits = deployment_service
+        .get_traffic_splits(&test_namespace(), &site)
+        .await
+        .unwrap();
+    assert_eq!(traffic_splits, vec![traffic_split.clone()]);
+
+    let compiled_traffic_splits = deployment_service
+        .get_all_traffic_splits_by_site(&site)
+        .await
+        .unwrap();
+    assert_eq!(compiled_traffic_splits.len(), 1);
+    assert_eq!(
+        compiled_traffic_splits[0].canary_definition.version,
+        canary.version
+    );
+    assert!(compiled_traffic_splits[0].sticky_key_compiled.is_some());
+
+    // The canary version can no longer be modified
+    let canary_definition = definition_service
+        .get(
+            &canary.id,
+            &canary.version,
+            &test_namespace(),
+            &test_auth_ctx(),
+        )
+        .await
+        .unwrap();
+    assert!(canary_definition.is_some_and(|x| !x.draft));
+
+    deployment_service
+        .delete_traffic_split(&test_namespace(), &site, &stable.id, &test_auth_ctx())
+        .await
+        .unwrap();
+
+    let traffic_splits = deployment_service
+        .get_traffic_splits(&test_namespace(), &site)
+        .await
+        .unwrap();
+    assert!(traffic_splits.is_empty());
+
+    let result = deployment_service
+        .delete_traffic_split(&test_namespace(), &site, &stable.id, &test_auth_ctx())
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDeploymentError::TrafficSplitNotFound(_, _, _))
+    ));
+
+    // Traffic splits are removed along with the deployment
+    deployment_service
+        .set_traffic_split(
+            &test_namespace(),
+            &site,
+            traffic_split.clone(),
+            &test_auth_ctx(),
+        )
+        .await
+        .unwrap();
+
+    deployment_service
+        .delete(&test_namespace(), &test_auth_ctx(), &site)
+        .await
+        .unwrap();
+
+    let traffic_splits = deployment_service
+        .get_traffic_splits(&test_namespace(), &site)
+        .await
+        .unwrap();
+    assert!(traffic_splits.is_empty());
+}
+
+async fn test_deployment_rib_limits(
+    definition_service: Arc<dyn ApiDefinitionService>,
+    deployment_service: Arc<dyn ApiDeploymentService>,
+    api_definition_lookup: Arc<dyn HttpApiDefinitionsLookup>,
+) {
+    let def = get_api_definition(
+        &Uuid::new_v4().to_string(),
+        "0.0.1",
+        "/api/limits/{user-id}",
+        "${ let worker = instance[golem:it](\"worker1\"); {body: worker.get-cart-contents(\"foo\")} }",
+        false,
+    ).await;
+
+    definition_service
+        .create(&def, &test_namespace(), &test_auth_ctx())
+        .await
+        .unwrap();
+
+    let site = ApiSiteString("test-limits.com".to_string());
+
+    let limits = RibInterpreterLimits::default()
+        .with_max_instructions(10000)
+        .with_max_function_invocations(2)
+        .with_max_wall_time(Duration::from_millis(500));
+
+    let result = deployment_service
+        .set_rib_limits(&test_namespace(), &site, limits.clone())
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDeploymentError::ApiDeploymentNotFound(_, _))
+    ));
+
+    let deployment = get_api_deployment("test-limits.com", None, vec![&def.id.0]);
+    deployment_service
+        .deploy(&deployment, &test_auth_ctx())
+        .await
+        .unwrap();
+
+    // A site without limits is unlimited
+    let site_limits = deployment_service
+        .get_all_rib_limits_by_site(&site)
+        .await
+        .unwrap();
+    assert_eq!(site_limits, RibInterpreterLimits::default());
+    assert!(matches!(
+        api_definition_lookup.get_rib_limits(&site).await,
+        Ok(site_limits) if site_limits == RibInterpreterLimits::default()
+    ));
+
+    deployment_service
+        .set_rib_limits(&test_namespace(), &site, limits.clone())
+        .await
+        .unwrap();
+
+    // The cached limits of the site are invalidated
+    assert!(matches!(
+        api_definition_lookup.get_rib_limits(&site).await,
+        Ok(site_limits) if site_limits == limits
+    ));
+
+    let result = deployment_service
+        .get_rib_limits(&test_namespace(), &site)
+        .await
+        .unwrap();
+    assert_eq!(result, Some(limits.clone()));
+
+    let site_limits = deployment_service
+        .get_all_rib_limits_by_site(&site)
+        .await
+        .unwrap();
+    assert_eq!(site_limits, limits);
+
+    deployment_service
+        .delete_rib_limits(&test_namespace(), &site)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        api_definition_lookup.get_rib_limits(&site).await,
+        Ok(site_limits) if site_limits == RibInterpreterLimits::default()
+    ));
+
+    let result = deployment_service
+        .delete_rib_limits(&test_namespace(), &site)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDeploymentError::RibLimitsNotFound(_, _))
+    ));
+
+    // Rib limits are removed along with the deployment
+    deployment_service
+        .set_rib_limits(&test_namespace(), &site, limits)
+        .await
+        .unwrap();
+
+    deployment_service
+        .delete(&test_namespace(), &test_auth_ctx(), &site)
+        .await
+        .unwrap();
+
+    let result = deployment_service
+        .get_rib_limits(&test_namespace(), &site)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+}
+
 async fn test_security_crud(security_scheme_service: Arc<dyn SecuritySchemeService>) {
     let security_identifier = SecuritySchemeIdentifier::new("test".to_string());
 
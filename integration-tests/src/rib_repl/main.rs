@@ -1,23 +1,45 @@
-use golem_rib_repl::{ComponentSource, RibRepl, RibReplConfig};
+use clap::Parser;
+use golem_rib_repl::{ComponentSource, JsonReplPrinter, ReplPrinter, RibRepl, RibReplConfig};
 use golem_test_framework::config::{
     EnvBasedTestDependencies, EnvBasedTestDependenciesConfig, TestDependencies,
 };
 use integration_tests::rib_repl::bootstrap::*;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::process::ExitCode;
 use std::sync::Arc;
+
+#[derive(Parser, Debug)]
+#[command(name = "rib-repl")]
+struct Args {
+    /// Name of the test component to load
+    #[arg(default_value = "shopping-cart")]
+    component_name: String,
+
+    /// Runs the rib script in the file non-interactively, or reads it from stdin if `-`
+    #[arg(long)]
+    script: Option<PathBuf>,
+
+    /// Prints the results and errors as JSON, a line per object
+    #[arg(long)]
+    json: bool,
+}
+
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
     let deps = EnvBasedTestDependencies::new(EnvBasedTestDependenciesConfig::new()).await;
 
-    // component name from args
-    let component_name = std::env::args()
-        .nth(1)
-        .unwrap_or_else(|| "shopping-cart".to_string());
+    let component_name = args.component_name;
 
     let mut rib_repl = RibRepl::bootstrap(RibReplConfig {
         history_file: None,
         dependency_manager: Arc::new(TestRibReplDependencyManager::new(deps.clone())),
         worker_function_invoke: Arc::new(TestRibReplWorkerFunctionInvoke::new(deps.clone())),
-        printer: None,
+        printer: args
+            .json
+            .then(|| Box::new(JsonReplPrinter) as Box<dyn ReplPrinter>),
         component_source: Some(ComponentSource {
             component_name: component_name.to_string(),
             source_path: deps
@@ -30,5 +52,23 @@ async fn main() {
     .await
     .expect("Failed to bootstrap REPL");
 
-    rib_repl.run().await
+    let result = match args.script {
+        Some(path) if path.as_os_str() == "-" => rib_repl.run_script(std::io::stdin().lock()).await,
+        Some(path) => match std::fs::File::open(&path) {
+            Ok(file) => rib_repl.run_script(BufReader::new(file)).await,
+            Err(err) => {
+                eprintln!("Failed to open {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => {
+            rib_repl.run().await;
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(_) => ExitCode::FAILURE,
+    }
 }
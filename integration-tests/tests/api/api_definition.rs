@@ -70,8 +70,10 @@ async fn create_and_get_api_security_scheme(deps: &EnvBasedTestDependencies) {
                 idempotency_key: None,
                 binding_type: Some(GatewayBindingType::Default),
                 invocation_context: None,
+                response_cache: None,
             },
             security: None,
+            transform: None,
         }],
     };
 
@@ -145,8 +147,10 @@ async fn get_api_definition_versions(deps: &EnvBasedTestDependencies) {
                 idempotency_key: None,
                 binding_type: Some(GatewayBindingType::Default),
                 invocation_context: None,
+                response_cache: None,
             },
             security: None,
+            transform: None,
         }],
     };
 
@@ -183,8 +187,10 @@ async fn get_api_definition_versions(deps: &EnvBasedTestDependencies) {
                     idempotency_key: None,
                     binding_type: Some(GatewayBindingType::Default),
                     invocation_context: None,
+                    response_cache: None,
                 },
                 security: None,
+                transform: None,
             },
             RouteRequestData {
                 method: MethodPattern::Patch,
@@ -213,8 +219,10 @@ async fn get_api_definition_versions(deps: &EnvBasedTestDependencies) {
                     idempotency_key: None,
                     binding_type: Some(GatewayBindingType::Default),
                     invocation_context: None,
+                    response_cache: None,
                 },
                 security: None,
+                transform: None,
             },
         ],
     };
@@ -337,8 +345,10 @@ async fn get_api_definition_all_versions(deps: &EnvBasedTestDependencies) {
                 idempotency_key: None,
                 binding_type: Some(GatewayBindingType::Default),
                 invocation_context: None,
+                response_cache: None,
             },
             security: None,
+            transform: None,
         }],
     };
 
@@ -379,8 +389,10 @@ async fn get_api_definition_all_versions(deps: &EnvBasedTestDependencies) {
                 idempotency_key: None,
                 binding_type: Some(GatewayBindingType::Default),
                 invocation_context: None,
+                response_cache: None,
             },
             security: None,
+            transform: None,
         }],
     };
 
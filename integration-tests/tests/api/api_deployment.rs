@@ -716,8 +716,10 @@ async fn create_api_definition_without_worker_calls(
                         idempotency_key: None,
                         binding_type: Some(GatewayBindingType::Default),
                         invocation_context: None,
+                        response_cache: None,
                     },
                     security: None,
+                    transform: None,
                 }],
             },
         )
@@ -768,8 +770,10 @@ async fn create_api_definition(
                         idempotency_key: None,
                         binding_type: Some(GatewayBindingType::Default),
                         invocation_context: None,
+                        response_cache: None,
                     },
                     security: None,
+                    transform: None,
                 }],
             },
         )
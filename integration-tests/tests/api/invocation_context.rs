@@ -152,8 +152,10 @@ async fn invocation_context_test(deps: &EnvBasedTestDependencies) {
                     "#
                     .to_string(),
                 ),
+                response_cache: None,
             },
             security: None,
+            transform: None,
         }],
     };
 